@@ -1,7 +1,10 @@
-use crate::sixel::{PreviewManager, TerminalCapability};
+use crate::backend::Backend;
+use crate::metadata::GenerationMetadata;
+use crate::progress;
+use crate::sixel::{is_image_path, PreviewManager, TerminalCapability};
 use crate::ui::screens::comparison::ComparisonState;
-use crate::zmq_client::ZmqClient;
-use std::path::PathBuf;
+use crate::zmq_client::ConnectionState;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 /// Represents the current screen in the application
@@ -17,6 +20,46 @@ pub enum Screen {
     Help,
 }
 
+/// Columns in the gallery grid view
+pub const GALLERY_GRID_COLUMNS: usize = 3;
+
+/// Direction the gallery grid selection moves
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Index reached by moving from `index` in a grid of `len` cells, `columns`
+/// wide (rows filled left to right).
+///
+/// Left/Right step one cell, wrapping between the ends of the gallery.
+/// Up/Down keep the column and wrap between the top and bottom rows; a
+/// column the short last row doesn't reach wraps to the row above it.
+pub fn grid_step(index: usize, len: usize, columns: usize, direction: GridDirection) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    let columns = columns.max(1);
+    match direction {
+        GridDirection::Left => (index + len - 1) % len,
+        GridDirection::Right => (index + 1) % len,
+        GridDirection::Up if index >= columns => index - columns,
+        GridDirection::Up => {
+            let bottom = (len - 1) / columns * columns + index % columns;
+            if bottom < len {
+                bottom
+            } else {
+                bottom.saturating_sub(columns)
+            }
+        }
+        GridDirection::Down if index + columns < len => index + columns,
+        GridDirection::Down => index % columns,
+    }
+}
+
 /// Job status tracking
 #[derive(Debug, Clone, PartialEq)]
 #[allow(dead_code)]
@@ -44,6 +87,12 @@ pub struct ActiveJob {
     pub prompt: String,
     pub status: JobStatus,
     pub preview_path: Option<PathBuf>,
+    /// When the backend last reported progress
+    pub last_progress_at: Option<Instant>,
+    /// Running, but no progress for a while (see `progress::STALL_TIMEOUT`)
+    pub stalled: bool,
+    /// Settings the job was requested with, for its image's sidecar
+    pub metadata: Option<GenerationMetadata>,
 }
 
 /// Main application state
@@ -64,6 +113,18 @@ pub struct App {
     /// Cursor position in input buffer
     pub cursor_pos: usize,
 
+    /// Negative prompt input (shown once focused with Ctrl+N)
+    pub negative_buffer: String,
+
+    /// Cursor position in the negative prompt
+    pub negative_cursor_pos: usize,
+
+    /// Typing goes to the negative prompt instead of the prompt
+    pub negative_focused: bool,
+
+    /// Where submitted prompts are saved (`None` disables saving)
+    pub last_prompt_path: Option<PathBuf>,
+
     /// Last render time (for FPS tracking)
     pub last_render: Instant,
 
@@ -91,11 +152,17 @@ pub struct App {
     /// Selected gallery image index
     pub selected_gallery_index: usize,
 
+    /// Show the gallery as a grid of thumbnails instead of preview + list
+    pub gallery_grid_view: bool,
+
     /// Comparison screen state (NEW)
     pub comparison_state: ComparisonState,
 
-    /// ZeroMQ client for backend communication
-    pub zmq_client: Option<ZmqClient>,
+    /// Backend client (the ZeroMQ client, or the demo backend with `--demo`)
+    pub zmq_client: Option<Box<dyn Backend>>,
+
+    /// Last seen backend connection state (`None` without a client)
+    pub backend_state: Option<ConnectionState>,
 
     /// Debug mode enabled
     pub debug_mode: bool,
@@ -105,6 +172,9 @@ pub struct App {
 
     /// Current preview tab (0=Preview, 1=Logs)
     pub preview_tab: usize,
+
+    /// Save the next rendered frame as a PNG (F12)
+    pub screenshot_requested: bool,
 }
 
 impl Default for App {
@@ -126,6 +196,10 @@ impl App {
             should_quit: false,
             input_buffer: String::new(),
             cursor_pos: 0,
+            negative_buffer: String::new(),
+            negative_cursor_pos: 0,
+            negative_focused: false,
+            last_prompt_path: None,
             last_render: Instant::now(),
             frame_count: 0,
             needs_redraw: true,
@@ -135,11 +209,14 @@ impl App {
             current_preview: None,
             gallery_images: Vec::new(),
             selected_gallery_index: 0,
+            gallery_grid_view: false,
             comparison_state: ComparisonState::new(),
             zmq_client: None,
+            backend_state: None,
             debug_mode: false,
             backend_logs: Vec::new(),
             preview_tab: 0, // Will be set to 1 (Logs) if debug_mode is enabled
+            screenshot_requested: false,
         }
     }
 
@@ -181,20 +258,90 @@ impl App {
         self.should_quit = true;
     }
 
-    /// Add character to input buffer
+    /// Add character to the focused input (prompt or negative prompt)
     pub fn input_char(&mut self, c: char) {
-        self.input_buffer.insert(self.cursor_pos, c);
-        self.cursor_pos += 1;
+        let (buffer, cursor) = self.focused_input();
+        buffer.insert(*cursor, c);
+        *cursor += 1;
         self.needs_redraw = true;
     }
 
     /// Delete character before cursor
     pub fn input_backspace(&mut self) {
-        if self.cursor_pos > 0 {
-            self.input_buffer.remove(self.cursor_pos - 1);
-            self.cursor_pos -= 1;
+        let (buffer, cursor) = self.focused_input();
+        if *cursor > 0 {
+            buffer.remove(*cursor - 1);
+            *cursor -= 1;
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Switch typing between the prompt and the negative prompt (Ctrl+N)
+    pub fn toggle_negative_focus(&mut self) {
+        self.negative_focused = !self.negative_focused;
+        self.needs_redraw = true;
+    }
+
+    /// Input buffer and cursor that typing currently goes to
+    fn focused_input(&mut self) -> (&mut String, &mut usize) {
+        if self.negative_focused {
+            (&mut self.negative_buffer, &mut self.negative_cursor_pos)
+        } else {
+            (&mut self.input_buffer, &mut self.cursor_pos)
+        }
+    }
+
+    /// Restore the prompt saved at `path` and save future prompts there.
+    pub fn load_last_prompt(&mut self, path: PathBuf) {
+        if let Some(prompt) = crate::last_prompt::load_last_prompt(&path) {
+            self.cursor_pos = prompt.len();
+            self.input_buffer = prompt;
             self.needs_redraw = true;
         }
+        self.last_prompt_path = Some(path);
+    }
+
+    /// Save a submitted prompt so the next session starts with it.
+    pub fn save_last_prompt(&self, prompt: &str) -> anyhow::Result<()> {
+        match &self.last_prompt_path {
+            Some(path) => crate::last_prompt::save_last_prompt(path, prompt),
+            None => Ok(()),
+        }
+    }
+
+    /// Record a finished job in its image's sidecar (`<image>.json`).
+    ///
+    /// Fields already in the sidecar (e.g. written by the backend) are kept;
+    /// missing ones are filled from the settings the job was requested with.
+    /// Images that don't exist locally are skipped.
+    pub fn save_job_metadata(
+        &self,
+        job_id: &str,
+        image_path: &Path,
+        duration_s: f32,
+    ) -> anyhow::Result<()> {
+        if !image_path.exists() {
+            return Ok(());
+        }
+
+        let job = self.active_jobs.iter().find(|j| j.job_id == job_id);
+        let requested = job.and_then(|j| j.metadata.clone()).unwrap_or_else(|| {
+            GenerationMetadata {
+                prompt: job.map(|j| j.prompt.clone()),
+                ..Default::default()
+            }
+        });
+
+        let mut metadata = GenerationMetadata::load(image_path)?.unwrap_or_default();
+        metadata.prompt = metadata.prompt.or(requested.prompt);
+        metadata.negative_prompt = metadata.negative_prompt.or(requested.negative_prompt);
+        metadata.seed = metadata.seed.or(requested.seed);
+        metadata.steps = metadata.steps.or(requested.steps);
+        metadata.cfg_scale = metadata.cfg_scale.or(requested.cfg_scale);
+        metadata.model = metadata.model.or(requested.model);
+        metadata.timestamp.get_or_insert_with(chrono::Utc::now);
+        metadata.duration_s.get_or_insert(duration_s);
+        metadata.save(image_path)
     }
 
     /// Clear input buffer
@@ -221,6 +368,13 @@ impl App {
         self.frame_count += 1;
         self.last_render = Instant::now();
         self.needs_redraw = false;
+        self.screenshot_requested = false;
+    }
+
+    /// Capture the next rendered frame to a PNG
+    pub fn request_screenshot(&mut self) {
+        self.screenshot_requested = true;
+        self.needs_redraw = true;
     }
 
     /// Check if redraw is needed
@@ -238,14 +392,71 @@ impl App {
         }
     }
 
+    /// Record a progress update from the backend.
+    ///
+    /// The displayed ETA is smoothed across updates and the job's stall flag
+    /// is cleared. Progress arriving after a job finished (updates and
+    /// responses come in on separate channels) is ignored.
+    pub fn record_job_progress(
+        &mut self,
+        job_id: &str,
+        stage: String,
+        progress: f32,
+        eta_s: f32,
+        now: Instant,
+    ) {
+        if let Some(job) = self.active_jobs.iter_mut().find(|j| j.job_id == job_id) {
+            if matches!(job.status, JobStatus::Complete { .. } | JobStatus::Failed { .. }) {
+                return;
+            }
+            let previous_eta = match job.status {
+                JobStatus::Running { eta_s, .. } => Some(eta_s),
+                _ => None,
+            };
+            job.status = JobStatus::Running {
+                stage,
+                progress,
+                eta_s: progress::smooth_eta(previous_eta, eta_s),
+            };
+            job.last_progress_at = Some(now);
+            job.stalled = false;
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Flag running jobs that haven't reported progress recently.
+    pub fn check_stalled_jobs(&mut self, now: Instant) {
+        for job in &mut self.active_jobs {
+            let stalled = matches!(job.status, JobStatus::Running { .. })
+                && progress::is_stalled(job.last_progress_at, now);
+            if stalled != job.stalled {
+                job.stalled = stalled;
+                self.needs_redraw = true;
+            }
+        }
+    }
+
     /// Add new job
     #[allow(dead_code)]
     pub fn add_job(&mut self, job_id: String, prompt: String) {
+        self.add_requested_job(job_id, prompt, None);
+    }
+
+    /// Add a new job with the settings it was requested with.
+    pub fn add_requested_job(
+        &mut self,
+        job_id: String,
+        prompt: String,
+        metadata: Option<GenerationMetadata>,
+    ) {
         self.active_jobs.push(ActiveJob {
             job_id,
             prompt,
             status: JobStatus::Queued,
             preview_path: None,
+            last_progress_at: None,
+            stalled: false,
+            metadata,
         });
         self.needs_redraw = true;
     }
@@ -297,6 +508,25 @@ impl App {
         }
     }
 
+    /// Switch between the preview + list layout and the thumbnail grid
+    pub fn toggle_gallery_grid(&mut self) {
+        self.gallery_grid_view = !self.gallery_grid_view;
+        self.needs_redraw = true;
+    }
+
+    /// Move the gallery grid selection one cell
+    pub fn gallery_grid_move(&mut self, direction: GridDirection) {
+        if !self.gallery_images.is_empty() {
+            self.selected_gallery_index = grid_step(
+                self.selected_gallery_index,
+                self.gallery_images.len(),
+                GALLERY_GRID_COLUMNS,
+                direction,
+            );
+            self.needs_redraw = true;
+        }
+    }
+
     /// Get selected gallery image
     pub fn selected_gallery_image(&self) -> Option<&PathBuf> {
         self.gallery_images.get(self.selected_gallery_index)
@@ -318,14 +548,9 @@ impl App {
         if let Ok(entries) = fs::read_dir(&abs_path) {
             for entry in entries.flatten() {
                 let path = entry.path();
-                if path.is_file() {
-                    if let Some(ext) = path.extension() {
-                        let ext_str = ext.to_string_lossy().to_lowercase();
-                        if ext_str == "png" || ext_str == "jpg" || ext_str == "jpeg" {
-                            // Path is now absolute from entry.path()
-                            self.add_to_gallery(path);
-                        }
-                    }
+                if path.is_file() && is_image_path(&path) {
+                    // Path is now absolute from entry.path()
+                    self.add_to_gallery(path);
                 }
             }
             // Sort gallery images by filename (most recent first based on timestamp in filename)
@@ -392,6 +617,25 @@ mod tests {
         assert_eq!(app.cursor_pos, 1);
     }
 
+    #[tokio::test]
+    async fn test_negative_prompt_focus_switch() {
+        let mut app = App::new();
+        app.input_char('a');
+        app.input_char('b');
+
+        app.toggle_negative_focus();
+        app.input_char('x');
+        assert_eq!(app.negative_buffer, "x");
+        assert_eq!(app.negative_cursor_pos, 1);
+
+        // The prompt cursor is where it was left
+        app.toggle_negative_focus();
+        app.input_backspace();
+        assert_eq!(app.input_buffer, "a");
+        assert_eq!(app.cursor_pos, 1);
+        assert_eq!(app.negative_buffer, "x");
+    }
+
     #[tokio::test]
     async fn test_clear_input() {
         let mut app = App::new();
@@ -439,6 +683,30 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_job_progress_smoothing_and_stall() {
+        let mut app = App::new();
+        app.add_job("job-001".to_string(), "test".to_string());
+        let start = Instant::now();
+
+        app.record_job_progress("job-001", "Sampling".to_string(), 0.1, 40.0, start);
+        app.record_job_progress("job-001", "Sampling".to_string(), 0.2, 10.0, start);
+        let JobStatus::Running { eta_s, .. } = app.active_jobs[0].status else {
+            panic!("Expected Running status");
+        };
+        assert!(eta_s > 10.0 && eta_s < 40.0);
+
+        app.check_stalled_jobs(start + progress::STALL_TIMEOUT);
+        assert!(!app.active_jobs[0].stalled);
+        app.check_stalled_jobs(start + progress::STALL_TIMEOUT * 2);
+        assert!(app.active_jobs[0].stalled);
+
+        // Progress resuming clears the flag
+        let later = start + progress::STALL_TIMEOUT * 2;
+        app.record_job_progress("job-001", "Sampling".to_string(), 0.3, 8.0, later);
+        assert!(!app.active_jobs[0].stalled);
+    }
+
     #[tokio::test]
     async fn test_gallery_navigation() {
         let mut app = App::new();
@@ -454,4 +722,59 @@ mod tests {
         app.gallery_prev();
         assert_eq!(app.selected_gallery_index, 1); // Wraps backward
     }
+
+    #[tokio::test]
+    async fn test_job_metadata_fills_sidecar_from_request() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = dir.path().join("knight.png");
+        std::fs::write(&image, b"png").unwrap();
+        GenerationMetadata {
+            seed: Some(7),
+            ..Default::default()
+        }
+        .save(&image)
+        .unwrap();
+
+        let mut app = App::new();
+        let requested = GenerationMetadata {
+            prompt: Some("pixel knight".to_string()),
+            seed: Some(1234),
+            steps: Some(25),
+            cfg_scale: Some(6.5),
+            model: Some("sdxl".to_string()),
+            ..Default::default()
+        };
+        app.add_requested_job("job-001".to_string(), "knight".to_string(), Some(requested));
+        app.save_job_metadata("job-001", &image, 3.0).unwrap();
+
+        let saved = GenerationMetadata::load(&image).unwrap().unwrap();
+        assert_eq!(saved.prompt.as_deref(), Some("pixel knight"));
+        assert_eq!(saved.seed, Some(7)); // Kept from the backend's sidecar
+        assert_eq!((saved.steps, saved.cfg_scale), (Some(25), Some(6.5)));
+        assert_eq!(saved.model.as_deref(), Some("sdxl"));
+        assert_eq!(saved.duration_s, Some(3.0));
+    }
+
+    #[test]
+    fn test_grid_step_wraps_rows_and_columns() {
+        use GridDirection::*;
+        // 3 columns, 7 images: rows 0-2, 3-5 and a short row holding 6
+        let step = |index, direction| grid_step(index, 7, 3, direction);
+
+        assert_eq!(step(1, Down), 4);
+        assert_eq!(step(4, Up), 1);
+        assert_eq!(step(2, Right), 3);
+        assert_eq!(step(3, Left), 2);
+        assert_eq!(step(6, Right), 0);
+        assert_eq!(step(0, Left), 6);
+
+        // Off the top: same column in the bottom row, or the row above it
+        assert_eq!(step(0, Up), 6);
+        assert_eq!(step(1, Up), 4);
+        // Off the bottom: same column in the top row
+        assert_eq!(step(6, Down), 0);
+        assert_eq!(step(5, Down), 2);
+
+        assert_eq!(grid_step(0, 0, 3, Down), 0);
+    }
 }
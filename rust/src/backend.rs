@@ -0,0 +1,120 @@
+//! # Generation Backend
+//!
+//! The request/poll interface both apps talk to the generation backend
+//! through. [`ZmqClient`] is the real thing; [`DemoBackend`](crate::demo::DemoBackend)
+//! fakes it for `--demo`.
+
+use anyhow::Result;
+
+use crate::messages::{ProgressUpdate, Request, Response};
+use crate::zmq_client::{ConnectionState, ZmqClient};
+
+/// A generation backend: send requests, poll for responses and progress.
+///
+/// Polling never blocks; the apps call the `try_recv_*` methods every frame.
+pub trait Backend: Send + std::fmt::Debug {
+    /// Send a request to the backend.
+    fn send_request(&self, request: Request) -> Result<()>;
+
+    /// Health of the connection, for status indicators.
+    fn connection_state(&self) -> ConnectionState;
+
+    /// Requests sent but not yet answered (or failed).
+    fn outstanding_requests(&self) -> usize;
+
+    /// Ask the backend for GPU/memory stats (answered with `Response::SystemStats`).
+    fn request_system_stats(&self) -> Result<()> {
+        self.send_request(Request::GetSystemStats)
+    }
+
+    /// Ask the backend to stop a queued or running job.
+    fn cancel_job(&self, job_id: &str) -> Result<()> {
+        self.send_request(Request::Cancel {
+            job_id: job_id.to_string(),
+        })
+    }
+
+    /// Ask the backend to download a model (progress arrives as
+    /// `ProgressUpdate::ModelDownload`).
+    fn download_model(&self, model: &str) -> Result<()> {
+        self.send_request(Request::DownloadModel {
+            model: model.to_string(),
+        })
+    }
+
+    /// Try to receive a response (non-blocking).
+    fn try_recv_response(&self) -> Option<Response>;
+
+    /// Try to receive a progress update (non-blocking).
+    fn try_recv_update(&self) -> Option<ProgressUpdate>;
+}
+
+impl Backend for ZmqClient {
+    fn send_request(&self, request: Request) -> Result<()> {
+        ZmqClient::send_request(self, request)
+    }
+
+    fn connection_state(&self) -> ConnectionState {
+        ZmqClient::connection_state(self)
+    }
+
+    fn outstanding_requests(&self) -> usize {
+        ZmqClient::outstanding_requests(self)
+    }
+
+    fn try_recv_response(&self) -> Option<Response> {
+        ZmqClient::try_recv_response(self)
+    }
+
+    fn try_recv_update(&self) -> Option<ProgressUpdate> {
+        ZmqClient::try_recv_update(self)
+    }
+}
+
+/// Test double that records the requests sent to it and replays queued
+/// responses and updates.
+#[cfg(all(test, feature = "bevy_migration_foundation"))]
+#[derive(Debug, Default)]
+pub(crate) struct RecordingBackend {
+    pub sent: std::sync::Arc<std::sync::Mutex<Vec<Request>>>,
+    /// Fail every send instead of recording it
+    pub fail_sends: bool,
+    /// Answer every request sent with a `Response::Error` carrying this
+    /// message, like a backend without a handler for it
+    pub reject_with: Option<String>,
+    /// Returned by `try_recv_response`, oldest first
+    pub responses: std::sync::Mutex<std::collections::VecDeque<Response>>,
+    /// Returned by `try_recv_update`, oldest first
+    pub updates: std::sync::Mutex<std::collections::VecDeque<ProgressUpdate>>,
+}
+
+#[cfg(all(test, feature = "bevy_migration_foundation"))]
+impl Backend for RecordingBackend {
+    fn send_request(&self, request: Request) -> Result<()> {
+        if self.fail_sends {
+            anyhow::bail!("backend unreachable");
+        }
+        self.sent.lock().unwrap().push(request);
+        if let Some(message) = &self.reject_with {
+            let message = message.clone();
+            self.responses.lock().unwrap().push_back(Response::Error { message });
+        }
+        Ok(())
+    }
+
+    fn connection_state(&self) -> ConnectionState {
+        ConnectionState::Connected
+    }
+
+    fn outstanding_requests(&self) -> usize {
+        0
+    }
+
+    fn try_recv_response(&self) -> Option<Response> {
+        self.responses.lock().unwrap().pop_front()
+    }
+
+    fn try_recv_update(&self) -> Option<ProgressUpdate> {
+        self.updates.lock().unwrap().pop_front()
+    }
+}
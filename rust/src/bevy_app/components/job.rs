@@ -19,7 +19,11 @@
 
 use bevy::prelude::*;
 use std::path::PathBuf;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+use crate::metadata::GenerationMetadata;
+use crate::progress;
+use crate::shutdown::JobHistoryEntry;
 
 /// Job entity component for tracking image generation jobs.
 #[derive(Component, Debug, Clone)]
@@ -28,15 +32,87 @@ pub struct Job {
     pub id: String,
     /// Generation prompt
     pub prompt: String,
+    /// Negative prompt, if any
+    pub negative_prompt: Option<String>,
+    /// Seed chosen at submission (from the Generation screen's seed mode)
+    pub seed: Option<u64>,
+    /// Steps chosen at submission (`None` for the configured default)
+    pub steps: Option<u32>,
+    /// CFG scale chosen at submission (`None` for the configured default)
+    pub cfg_scale: Option<f32>,
+    /// Base model active at submission (`None` for the configured default)
+    pub model: Option<String>,
+    /// LoRAs active at submission, with their strengths
+    pub loras: Vec<(String, f32)>,
+    /// Scheduling priority while queued locally
+    pub priority: Priority,
     /// Current job status
     pub status: JobStatus,
     /// Submission timestamp
     pub submitted_at: Instant,
+    /// When the job scheduler sent the job to the backend
+    pub sent_at: Option<Instant>,
+    /// When the job finished (see [`Job::finish`])
+    pub completed_at: Option<Instant>,
+    /// Settings the job was sent to the backend with (set by the job scheduler)
+    pub metadata: Option<GenerationMetadata>,
+    /// Batch the job was submitted in, if more than one image was requested
+    pub batch: Option<JobBatch>,
+    /// When the backend last reported progress
+    pub last_progress_at: Option<Instant>,
+    /// Generating, but no progress for a while (see `progress::STALL_TIMEOUT`)
+    pub stalled: bool,
+}
+
+/// A job's place in a batch submitted together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobBatch {
+    /// Shared by every job in the batch
+    pub id: String,
+    /// Position in the batch (0-based)
+    pub index: u32,
+    /// Number of jobs in the batch
+    pub size: u32,
+    /// Whether the batch is a seed sweep (`id` is then its sweep id)
+    pub sweep: bool,
+}
+
+/// Scheduling priority of a job. Higher-priority jobs are sent to the
+/// backend first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl Priority {
+    /// The next priority, wrapping from High back to Low.
+    pub fn cycle(self) -> Self {
+        match self {
+            Priority::Low => Priority::Normal,
+            Priority::Normal => Priority::High,
+            Priority::High => Priority::Low,
+        }
+    }
+}
+
+impl std::fmt::Display for Priority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Priority::Low => write!(f, "low"),
+            Priority::Normal => write!(f, "normal"),
+            Priority::High => write!(f, "high"),
+        }
+    }
 }
 
 /// Status of an image generation job.
 #[derive(Debug, Clone, PartialEq)]
 pub enum JobStatus {
+    /// Job held locally until a backend slot frees up (see `max_concurrent_jobs`)
+    QueuedLocally,
     /// Job submitted, waiting for backend acceptance
     Pending,
     /// Job accepted by backend and queued
@@ -72,9 +148,76 @@ impl Job {
         Self {
             id,
             prompt,
+            negative_prompt: None,
+            seed: None,
+            steps: None,
+            cfg_scale: None,
+            model: None,
+            loras: Vec::new(),
+            priority: Priority::Normal,
             status: JobStatus::Pending,
             submitted_at: Instant::now(),
+            sent_at: None,
+            completed_at: None,
+            metadata: None,
+            batch: None,
+            last_progress_at: None,
+            stalled: false,
+        }
+    }
+
+    /// Apply a progress update from the backend received at `now`.
+    ///
+    /// The job moves to Generating with its ETA smoothed across updates, and
+    /// the stall flag is cleared. Progress arriving after the job finished
+    /// (updates and responses come in on separate channels) is ignored.
+    pub fn record_progress(&mut self, stage: String, progress: f32, eta_s: f32, now: Instant) {
+        if !self.is_active() {
+            return;
+        }
+        let previous_eta = match self.status {
+            JobStatus::Generating { eta_s, .. } => Some(eta_s),
+            _ => None,
+        };
+        self.status = JobStatus::Generating {
+            stage,
+            progress,
+            eta_s: progress::smooth_eta(previous_eta, eta_s),
+        };
+        self.last_progress_at = Some(now);
+        self.stalled = false;
+    }
+
+    /// Move the job to a finished status (complete, failed or cancelled) at
+    /// `now`, which the queue cleanup counts from.
+    pub fn finish(&mut self, status: JobStatus, now: Instant) {
+        self.status = status;
+        self.completed_at = Some(now);
+    }
+
+    /// Update the stall flag for `now`, returning whether it changed.
+    pub fn check_stalled(&mut self, now: Instant) -> bool {
+        let stalled = matches!(self.status, JobStatus::Generating { .. })
+            && progress::is_stalled(self.last_progress_at, now);
+        let changed = stalled != self.stalled;
+        self.stalled = stalled;
+        changed
+    }
+
+    /// Fail the job if the backend has held it for `timeout` at `now`,
+    /// returning whether it timed out. Jobs never sent don't time out.
+    pub fn check_timeout(&mut self, timeout: Duration, now: Instant) -> bool {
+        let timed_out = self.is_in_flight()
+            && self
+                .sent_at
+                .is_some_and(|sent| now.saturating_duration_since(sent) >= timeout);
+        if timed_out {
+            let status = JobStatus::Failed {
+                error: "timeout".to_string(),
+            };
+            self.finish(status, now);
         }
+        timed_out
     }
 
     /// Check if job is complete.
@@ -101,16 +244,49 @@ impl Job {
     pub fn is_cancellable(&self) -> bool {
         matches!(
             self.status,
-            JobStatus::Pending | JobStatus::Queued | JobStatus::Generating { .. }
+            JobStatus::QueuedLocally
+                | JobStatus::Pending
+                | JobStatus::Queued
+                | JobStatus::Generating { .. }
         )
     }
 
+    /// Check if job has been sent to the backend and is not finished yet.
+    pub fn is_in_flight(&self) -> bool {
+        self.is_active() && !matches!(self.status, JobStatus::QueuedLocally)
+    }
+
     /// Get elapsed time since submission.
     pub fn elapsed(&self) -> std::time::Duration {
         self.submitted_at.elapsed()
     }
 }
 
+impl From<&Job> for JobHistoryEntry {
+    fn from(job: &Job) -> Self {
+        let (status, image_path, duration_s) = match &job.status {
+            JobStatus::QueuedLocally => ("queued_locally", None, None),
+            JobStatus::Pending => ("pending", None, None),
+            JobStatus::Queued => ("queued", None, None),
+            JobStatus::Generating { .. } => ("running", None, None),
+            JobStatus::Complete {
+                image_path,
+                duration_s,
+            } => ("complete", Some(image_path.clone()), Some(*duration_s)),
+            JobStatus::Failed { .. } => ("failed", None, None),
+            JobStatus::Cancelled => ("cancelled", None, None),
+        };
+
+        Self {
+            job_id: job.id.clone(),
+            prompt: job.prompt.clone(),
+            status: status.to_string(),
+            image_path,
+            duration_s,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,6 +334,42 @@ mod tests {
         assert!(!job.is_cancellable());
     }
 
+    #[test]
+    fn test_timeout_stamps_completed_at() {
+        let mut job = Job::new("job-001".to_string(), "test".to_string());
+        job.status = JobStatus::Pending;
+        let sent = Instant::now();
+        job.sent_at = Some(sent);
+        let now = sent + Duration::from_secs(120);
+
+        assert!(job.check_timeout(Duration::from_secs(60), now));
+        assert!(job.is_failed());
+        assert_eq!(job.completed_at, Some(now));
+    }
+
+    #[test]
+    fn test_stall_detection_with_simulated_clock() {
+        let mut job = Job::new("job-001".to_string(), "test".to_string());
+        let start = Instant::now();
+        let late = start + progress::STALL_TIMEOUT + std::time::Duration::from_secs(1);
+
+        // Not generating yet: never stalled
+        assert!(!job.check_stalled(late));
+
+        job.record_progress("Sampling".to_string(), 0.2, 30.0, start);
+        assert!(!job.check_stalled(start + progress::STALL_TIMEOUT));
+        assert!(job.check_stalled(late));
+        assert!(job.stalled);
+        assert!(!job.check_stalled(late)); // Unchanged
+
+        job.record_progress("Sampling".to_string(), 0.4, 10.0, late);
+        assert!(!job.stalled);
+        let JobStatus::Generating { eta_s, .. } = job.status else {
+            panic!("Expected Generating status");
+        };
+        assert_eq!(eta_s, progress::smooth_eta(Some(30.0), 10.0));
+    }
+
     #[test]
     fn test_job_elapsed_time() {
         let job = Job::new("job-001".to_string(), "test".to_string());
@@ -6,5 +6,5 @@
 pub mod job;
 pub mod preview;
 
-pub use job::{Job, JobStatus};
+pub use job::{Job, JobBatch, JobStatus, Priority};
 pub use preview::PreviewImage;
@@ -17,6 +17,7 @@
 
 use bevy::prelude::*;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 /// Preview image component (attached to job entities).
 #[derive(Component, Debug, Clone)]
@@ -25,6 +26,8 @@ pub struct PreviewImage {
     pub path: PathBuf,
     /// Bevy asset handle (populated by WS-06 Image Asset System)
     pub asset_handle: Option<Handle<Image>>,
+    /// When the asset load was started (for load progress display)
+    pub load_started: Option<Instant>,
 }
 
 impl PreviewImage {
@@ -33,9 +36,24 @@ impl PreviewImage {
         Self {
             path,
             asset_handle: None,
+            load_started: None,
         }
     }
 
+    /// Create a preview whose asset load has just been started.
+    pub fn loading(path: PathBuf, handle: Handle<Image>) -> Self {
+        Self {
+            path,
+            asset_handle: Some(handle),
+            load_started: Some(Instant::now()),
+        }
+    }
+
+    /// Time since the asset load was started.
+    pub fn load_elapsed(&self) -> Option<Duration> {
+        self.load_started.map(|started| started.elapsed())
+    }
+
     /// Check if the asset has been loaded.
     pub fn is_loaded(&self) -> bool {
         self.asset_handle.is_some()
@@ -0,0 +1,82 @@
+//! # Confirmation Events
+//!
+//! Carries out actions confirmed in the shared Yes/No dialog (see
+//! [`ConfirmModal`](crate::bevy_app::resources::ConfirmModal)).
+
+use bevy::prelude::*;
+
+use super::DeleteImage;
+use crate::bevy_app::resources::{ConfirmAction, ModelsState, SettingsState};
+
+/// Event sent when the user confirms an action.
+#[derive(Event, Debug, Clone)]
+pub struct ConfirmedAction(pub ConfirmAction);
+
+/// Event handler running confirmed actions.
+pub fn handle_confirmed_actions(
+    mut events: EventReader<ConfirmedAction>,
+    mut delete_images: EventWriter<DeleteImage>,
+    mut models: Option<ResMut<ModelsState>>,
+    mut settings: Option<ResMut<SettingsState>>,
+) {
+    for event in events.read() {
+        info!("Confirmed: {:?}", event.0);
+        match &event.0 {
+            ConfirmAction::DeleteImage(image_path) => {
+                delete_images.send(DeleteImage {
+                    image_path: image_path.clone(),
+                });
+            }
+            ConfirmAction::DeleteModel(name) => match models.as_deref_mut() {
+                Some(models) => {
+                    if models.delete_model(name) {
+                        info!("Model removed: {}", name);
+                    } else {
+                        warn!("Model {} is not downloaded", name);
+                    }
+                }
+                None => warn!("No models state - cannot delete {}", name),
+            },
+            ConfirmAction::ResetSettings => match settings.as_deref_mut() {
+                Some(settings) => settings.reset_to_defaults(),
+                None => warn!("No settings state - cannot reset"),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::app::App;
+
+    #[test]
+    fn test_confirmed_actions_are_dispatched() {
+        let mut app = App::new();
+        app.add_event::<ConfirmedAction>();
+        app.add_event::<DeleteImage>();
+        app.insert_resource(ModelsState::default());
+        let mut settings = SettingsState::default();
+        settings.generation.default_steps += 5;
+        app.insert_resource(settings);
+        app.add_systems(Update, handle_confirmed_actions);
+
+        app.world_mut()
+            .send_event(ConfirmedAction(ConfirmAction::DeleteModel("SDXL VAE".to_string())));
+        app.world_mut()
+            .send_event(ConfirmedAction(ConfirmAction::DeleteImage("a.png".into())));
+        app.world_mut().send_event(ConfirmedAction(ConfirmAction::ResetSettings));
+        app.update();
+
+        let models = app.world().resource::<ModelsState>();
+        let vae = models.models.iter().find(|m| m.name == "SDXL VAE").unwrap();
+        assert_ne!(vae.status, crate::bevy_app::resources::models::ModelStatus::Downloaded);
+        assert_eq!(
+            app.world().resource::<SettingsState>().generation.default_steps,
+            SettingsState::default().generation.default_steps
+        );
+        let deletes = app.world().resource::<Events<DeleteImage>>();
+        let paths: Vec<_> = deletes.get_cursor().read(deletes).map(|e| e.image_path.clone()).collect();
+        assert_eq!(paths, vec![std::path::PathBuf::from("a.png")]);
+    }
+}
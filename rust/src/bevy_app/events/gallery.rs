@@ -12,7 +12,8 @@ pub struct SelectNextImage;
 #[derive(Event, Debug, Clone)]
 pub struct SelectPreviousImage;
 
-/// Event to delete an image from gallery.
+/// Event to delete an image from gallery (moved to the trash, see
+/// [`GalleryState::delete_selected`](crate::bevy_app::resources::GalleryState::delete_selected)).
 #[derive(Event, Debug, Clone)]
 pub struct DeleteImage {
     pub image_path: std::path::PathBuf,
@@ -27,17 +28,27 @@ pub fn handle_gallery_events(
 ) {
     for _ in next_events.read() {
         gallery.select_next();
+        gallery.mark_manual_nav();
         info!("Gallery: selected next image");
     }
 
     for _ in prev_events.read() {
         gallery.select_previous();
+        gallery.mark_manual_nav();
         info!("Gallery: selected previous image");
     }
 
     for event in delete_events.read() {
         info!("Delete image requested: {:?}", event.image_path);
-        // TODO: Implement image deletion
+        let Some(index) = gallery.images.iter().position(|p| p == &event.image_path) else {
+            warn!("Gallery: {:?} is not in the gallery", event.image_path);
+            continue;
+        };
+        gallery.selected = index;
+        match gallery.delete_selected() {
+            Ok(trashed) => info!("Gallery: moved {:?} to {:?}", event.image_path, trashed),
+            Err(e) => warn!("Gallery: delete failed: {:#}", e),
+        }
     }
 }
 
@@ -9,6 +9,25 @@ use crate::messages::GenerationStage;
 #[derive(Event, Debug, Clone)]
 pub struct SubmitGenerationJob {
     pub prompt: String,
+    /// What the image should avoid (`None` for no negative prompt)
+    pub negative_prompt: Option<String>,
+    /// Images to generate, one job each (`None` for the default batch size)
+    pub batch_size: Option<u32>,
+    /// Seed of the first image; the rest count up from it (`None` picks one
+    /// from the Generation screen's seed mode)
+    pub base_seed: Option<u64>,
+    /// Denoising steps (`None` for the configured default)
+    pub steps: Option<u32>,
+    /// CFG scale (`None` for the configured default)
+    pub cfg_scale: Option<f32>,
+    /// Submit the batch as a seed sweep: its images are tagged with a shared
+    /// sweep id
+    pub sweep: bool,
+}
+
+/// Seeds for a batch of `size` images starting at `base`.
+pub fn batch_seeds(base: u64, size: u32) -> Vec<u64> {
+    (0..size.max(1) as u64).map(|i| base.wrapping_add(i)).collect()
 }
 
 /// Event when generation job completes.
@@ -51,38 +70,71 @@ pub fn handle_generation_events(
     mut job_tracker: ResMut<crate::bevy_app::resources::JobTracker>,
     mut job_query: Query<&mut crate::bevy_app::components::Job>,
     mut app_state: ResMut<crate::bevy_app::resources::AppState>,
+    mut input_buffer: Option<ResMut<crate::bevy_app::resources::InputBuffer>>,
+    settings: Option<Res<crate::bevy_app::resources::SettingsState>>,
+    models: Option<Res<crate::bevy_app::resources::ModelsState>>,
 ) {
     for event in submit_events.read() {
         info!("Generation job submitted: {}", event.prompt);
 
-        // Always create a job entity for UI feedback
-        let job_id = format!("job-{}", uuid::Uuid::new_v4());
-        commands.spawn(crate::bevy_app::components::Job::new(
-            job_id.clone(),
-            event.prompt.clone(),
-        ));
-        job_tracker.submit_job();
+        let batch_size = event
+            .batch_size
+            .or_else(|| settings.as_ref().map(|s| s.generation.default_batch_size))
+            .unwrap_or(1)
+            .max(1);
+        let base_seed = event.base_seed.unwrap_or_else(|| app_state.next_seed());
+        let batch_id = if event.sweep {
+            // Short, since it's also the images' gallery tag
+            format!("sweep-{}", &uuid::Uuid::new_v4().simple().to_string()[..8])
+        } else {
+            format!("batch-{}", uuid::Uuid::new_v4())
+        };
 
-        // Try to send to backend if available
-        if let Some(ref zmq_client) = zmq_client {
-            let client = zmq_client.0.lock();
-            let request = crate::messages::Request::Generate {
-                id: job_id.clone(),
-                prompt: event.prompt.clone(),
-                model: "sdxl".to_string(),
-                lora: None,
-                size: (1024, 1024),
-                steps: 30,
-                cfg_scale: 7.5,
-            };
-
-            if let Err(e) = client.send_request(request) {
-                error!("Failed to send generation request to backend: {}", e);
-            } else {
-                info!("Job {} sent to backend", job_id);
+        // Always create job entities for UI feedback; the job scheduler sends
+        // them to the backend once a slot is free
+        for (index, seed) in batch_seeds(base_seed, batch_size).into_iter().enumerate() {
+            let job_id = format!("job-{}", uuid::Uuid::new_v4());
+            let mut job =
+                crate::bevy_app::components::Job::new(job_id.clone(), event.prompt.clone());
+            job.negative_prompt = event.negative_prompt.clone();
+            job.seed = Some(seed);
+            job.steps = event.steps;
+            job.cfg_scale = event.cfg_scale;
+            // The model stack active on the Models screen
+            if let Some(models) = models.as_ref() {
+                job.model = models.active_base.clone();
+                job.loras = models.active_loras.clone();
+            }
+            job.status = crate::bevy_app::components::JobStatus::QueuedLocally;
+            if batch_size > 1 {
+                job.batch = Some(crate::bevy_app::components::JobBatch {
+                    id: batch_id.clone(),
+                    index: index as u32,
+                    size: batch_size,
+                    sweep: event.sweep,
+                });
+            }
+            job_tracker.enqueue(job_id, job.priority);
+            commands.spawn(job);
+            job_tracker.submit_job();
+        }
+        app_state.last_submitted_prompt = Some(event.prompt.clone());
+        let defaults = settings.as_ref().map(|s| &s.generation);
+        app_state.last_submission = Some(SubmitGenerationJob {
+            batch_size: Some(batch_size),
+            base_seed: Some(base_seed),
+            steps: event.steps.or(defaults.map(|d| d.default_steps)),
+            cfg_scale: event.cfg_scale.or(defaults.map(|d| d.default_cfg_scale)),
+            ..event.clone()
+        });
+
+        if let Some(ref mut input_buffer) = input_buffer {
+            if let Err(e) = input_buffer.save_last(&event.prompt) {
+                warn!("Failed to save last prompt: {:#}", e);
+            }
+            if let Err(e) = input_buffer.push_history(&event.prompt) {
+                warn!("Failed to save prompt history: {:#}", e);
             }
-        } else {
-            warn!("No backend connected - job {} created but will not be processed", job_id);
         }
     }
 
@@ -97,14 +149,27 @@ pub fn handle_generation_events(
     for event in cancel_events.read() {
         info!("Cancel job requested: {}", event.job_id);
 
+        // The job may have finished before the cancel arrived
+        let finished = job_query
+            .iter()
+            .any(|job| job.id == event.job_id && !job.is_active());
+        if finished {
+            debug!("Job {} already finished, ignoring cancel", event.job_id);
+            continue;
+        }
+
+        // Jobs still in the local queue never reached the backend
+        let held_locally = job_query.iter().any(|job| {
+            job.id == event.job_id
+                && matches!(job.status, crate::bevy_app::components::JobStatus::QueuedLocally)
+        });
+
         // Try to send cancel request to backend
-        if let Some(ref zmq_client) = zmq_client {
+        if held_locally {
+            debug!("Job {} was queued locally, nothing to cancel on backend", event.job_id);
+        } else if let Some(ref zmq_client) = zmq_client {
             let client = zmq_client.0.lock();
-            let request = crate::messages::Request::Cancel {
-                job_id: event.job_id.clone(),
-            };
-
-            if let Err(e) = client.send_request(request) {
+            if let Err(e) = client.cancel_job(&event.job_id) {
                 error!("Failed to send cancel request to backend: {}", e);
                 // Still mark as cancelled locally
             } else {
@@ -117,7 +182,10 @@ pub fn handle_generation_events(
         // Update job status locally
         for mut job in job_query.iter_mut() {
             if job.id == event.job_id {
-                job.status = crate::bevy_app::components::JobStatus::Cancelled;
+                job.finish(
+                    crate::bevy_app::components::JobStatus::Cancelled,
+                    std::time::Instant::now(),
+                );
                 info!("Job {} marked as cancelled locally", event.job_id);
                 app_state.request_redraw();
                 break;
@@ -143,6 +211,12 @@ mod tests {
 
         app.world_mut().send_event(SubmitGenerationJob {
             prompt: "test prompt".into(),
+            negative_prompt: None,
+            batch_size: None,
+            base_seed: None,
+            steps: None,
+            cfg_scale: None,
+            sweep: false,
         });
         app.update();
         // No assertion - just verify no panic (ZMQ client optional)
@@ -176,4 +250,84 @@ mod tests {
         assert_eq!(jobs.len(), 1);
         assert!(jobs[0].is_cancelled());
     }
+
+    #[test]
+    fn test_batch_submit_spawns_one_job_per_seed() {
+        let mut app = App::new();
+        app.add_event::<SubmitGenerationJob>();
+        app.add_event::<GenerationComplete>();
+        app.add_event::<CancelJob>();
+        app.insert_resource(crate::bevy_app::resources::JobTracker::default());
+        app.insert_resource(crate::bevy_app::resources::AppState::default());
+        app.add_systems(Update, handle_generation_events);
+
+        app.world_mut().send_event(SubmitGenerationJob {
+            prompt: "test prompt".into(),
+            negative_prompt: None,
+            batch_size: Some(4),
+            base_seed: Some(100),
+            steps: None,
+            cfg_scale: None,
+            sweep: false,
+        });
+        app.update();
+
+        let mut job_query = app.world_mut().query::<&crate::bevy_app::components::Job>();
+        let mut jobs: Vec<_> = job_query.iter(app.world()).collect();
+        jobs.sort_by_key(|job| job.batch.as_ref().map(|b| b.index));
+        assert_eq!(jobs.len(), 4);
+        let seeds: Vec<_> = jobs.iter().map(|job| job.seed.unwrap()).collect();
+        assert_eq!(seeds, vec![100, 101, 102, 103]);
+        let batch = jobs[0].batch.as_ref().unwrap();
+        assert_eq!((batch.index, batch.size), (0, 4));
+        assert!(jobs.iter().all(|job| job.batch.as_ref().unwrap().id == batch.id));
+        assert_eq!(app.world().resource::<crate::bevy_app::resources::JobTracker>().total_submitted, 4);
+    }
+
+    #[test]
+    fn test_cancel_sends_to_backend_unless_job_finished() {
+        use crate::backend::RecordingBackend;
+        use crate::bevy_app::components::{Job, JobStatus};
+        use crate::messages::Request;
+
+        let backend = RecordingBackend::default();
+        let sent = std::sync::Arc::clone(&backend.sent);
+        let mut app = App::new();
+        app.add_event::<SubmitGenerationJob>();
+        app.add_event::<GenerationComplete>();
+        app.add_event::<CancelJob>();
+        app.insert_resource(crate::bevy_app::resources::JobTracker::default());
+        app.insert_resource(crate::bevy_app::resources::AppState::default());
+        app.insert_resource(crate::bevy_app::systems::zmq::ZmqClientResource::new(backend));
+        app.add_systems(Update, handle_generation_events);
+
+        let mut running = Job::new("job-running".into(), "knight".into());
+        running.status = JobStatus::Generating {
+            stage: "Sampling".into(),
+            progress: 0.5,
+            eta_s: 3.0,
+        };
+        let mut done = Job::new("job-done".into(), "wizard".into());
+        let complete = JobStatus::Complete {
+            image_path: "outputs/job-done.png".into(),
+            duration_s: 4.0,
+        };
+        done.status = complete.clone();
+        let running = app.world_mut().spawn(running).id();
+        let done = app.world_mut().spawn(done).id();
+
+        for job_id in ["job-running", "job-done"] {
+            app.world_mut().send_event(CancelJob { job_id: job_id.into() });
+        }
+        app.update();
+
+        // Only the running job is cancelled; the completed one keeps its result
+        let sent = sent.lock().unwrap();
+        assert!(matches!(
+            sent.as_slice(),
+            [Request::Cancel { job_id }] if job_id == "job-running"
+        ));
+        assert!(app.world().get::<Job>(running).unwrap().is_cancelled());
+        assert_eq!(app.world().get::<Job>(done).unwrap().status, complete);
+    }
 }
@@ -2,11 +2,15 @@
 //!
 //! App-specific events for cross-system communication via Bevy's event bus.
 
+pub mod confirm;
 pub mod gallery;
 pub mod generation;
 pub mod navigation;
+pub mod report;
 
 // Re-export events
+pub use confirm::*;
 pub use gallery::*;
 pub use generation::*;
 pub use navigation::*;
+pub use report::*;
@@ -0,0 +1,99 @@
+//! # Report Events
+//!
+//! Exporting saved comparison results as a shareable HTML report, and the
+//! gallery as a ComfyUI batch.
+
+use anyhow::{Context, Result};
+use bevy::prelude::*;
+use std::path::{Path, PathBuf};
+
+use crate::bevy_app::resources::{GalleryState, SettingsState};
+use crate::comfyui_batch::{default_template, export_comfyui_batch};
+use crate::comparison::ComparisonResult;
+use crate::reports::{export_html, ReportBuilder};
+use crate::shutdown::ShutdownPaths;
+
+/// Event to export saved comparisons (`comparisons.json`) as HTML.
+#[derive(Event, Debug, Clone)]
+pub struct ExportReport;
+
+/// Event to export the gallery images shown (after the tag filter) as a
+/// ComfyUI workflow batch.
+#[derive(Event, Debug, Clone)]
+pub struct ExportComfyBatch;
+
+/// Event handler for report exports.
+///
+/// Reports are written to the output directory as
+/// `comparison-report-<timestamp>.html`.
+pub fn handle_export_report(
+    mut export_events: EventReader<ExportReport>,
+    settings: Option<Res<SettingsState>>,
+) {
+    for _ in export_events.read() {
+        let output_dir = settings
+            .as_ref()
+            .map(|s| s.paths.output_dir.clone())
+            .unwrap_or_else(|| PathBuf::from("output"));
+
+        match export_saved_comparisons(&ShutdownPaths::default().comparisons, &output_dir) {
+            Ok(path) => info!("Comparison report exported to {:?}", path),
+            Err(e) => warn!("Failed to export comparison report: {:#}", e),
+        }
+    }
+}
+
+/// Event handler for ComfyUI batch exports.
+///
+/// The template is `txt2img.json` in the workflows directory; batches are
+/// written to the output directory as `comfyui-batch-<timestamp>.json`.
+pub fn handle_export_comfy_batch(
+    mut export_events: EventReader<ExportComfyBatch>,
+    gallery: Option<Res<GalleryState>>,
+    settings: Option<Res<SettingsState>>,
+) {
+    for _ in export_events.read() {
+        let Some(gallery) = gallery.as_ref() else {
+            continue;
+        };
+        let images: Vec<PathBuf> = gallery
+            .visible_indices()
+            .into_iter()
+            .map(|index| gallery.images[index].clone())
+            .collect();
+        let paths = settings.as_ref().map(|s| s.paths.clone()).unwrap_or_default();
+        let out = paths.output_dir.join(format!(
+            "comfyui-batch-{}.json",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+
+        match export_comfyui_batch(&images, &default_template(&paths.workflows_dir), &out) {
+            Ok(count) => info!("Exported {} workflows to {:?}", count, out),
+            Err(e) => warn!("Failed to export ComfyUI batch: {:#}", e),
+        }
+    }
+}
+
+/// Write an HTML report of the comparisons saved at `comparisons_path`.
+fn export_saved_comparisons(comparisons_path: &Path, output_dir: &Path) -> Result<PathBuf> {
+    let comparisons: Vec<ComparisonResult> = if comparisons_path.exists() {
+        let content = std::fs::read_to_string(comparisons_path)
+            .with_context(|| format!("Failed to read {:?}", comparisons_path))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {:?}", comparisons_path))?
+    } else {
+        Vec::new()
+    };
+
+    let mut builder = ReportBuilder::new();
+    builder.add_comparisons(comparisons);
+
+    std::fs::create_dir_all(output_dir)
+        .with_context(|| format!("Failed to create output directory: {:?}", output_dir))?;
+    let path = output_dir.join(format!(
+        "comparison-report-{}.html",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    export_html(&builder.build(), &path)?;
+    Ok(path)
+}
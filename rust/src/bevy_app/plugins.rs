@@ -4,6 +4,9 @@ use bevy::prelude::*;
 use bevy::time::common_conditions::on_timer;
 use bevy_ratatui::RatatuiPlugins;
 
+use std::path::Path;
+
+use super::resources::SettingsState;
 use super::systems;
 use super::BevyAppConfig;
 
@@ -37,56 +40,175 @@ impl Plugin for DgxPixelsPlugin {
         // WS-02: State initialization
         app.add_systems(Startup, systems::init_app_state);
 
+        // Report pastes as one event so they can be told apart from typing
+        app.add_systems(Startup, systems::input::text_entry::enable_bracketed_paste);
+
         // WS-06: Image asset cache
         app.insert_resource(systems::assets::ImageCache::default());
 
         // T10: Gallery scan state for preview manager
         app.insert_resource(systems::assets::GalleryScanState::default());
 
-        // WS-07: Theme resource
-        app.insert_resource(super::resources::AppTheme::default());
-
         // WS-11: Comparison state resource
         app.insert_resource(super::resources::ComparisonState::default());
 
-        // WS-12: Models state resource
-        app.insert_resource(super::resources::ModelsState::default());
-
-        // T3: Settings state resource (needed by gallery screen)
-        app.insert_resource(super::resources::SettingsState::default());
+        // T3: Settings from config.toml, with the resources derived from them
+        let settings = load_settings(&SettingsState::config_path());
+        let zmq_config = settings.backend.zmq_config();
+        insert_settings(app, settings);
 
         // Queue state resource (for job navigation in Queue screen)
         app.insert_resource(super::resources::QueueState::default());
 
-        // T9: Sixel preview cache resource (for gallery image previews)
-        app.insert_resource(systems::assets::SixelPreviewCache::default());
+        // T9: Keep the Sixel preview cache's size and background in step with the settings
+        app.add_systems(
+            Update,
+            (
+                systems::assets::preview::apply_sixel_cache_limit,
+                systems::assets::preview::clear_sixel_cache_on_background_change,
+            )
+                .run_if(resource_changed::<SettingsState>),
+        );
+
+        // Reload settings when config.toml is edited outside the app
+        app.insert_resource(systems::config_reload::ConfigWatcher::default());
+        app.add_systems(
+            Update,
+            systems::config_reload::reload_changed_config
+                .run_if(on_timer(systems::config_reload::CONFIG_POLL_INTERVAL)),
+        );
+
+        // Warn when the output or cache directory can't be written
+        app.add_systems(
+            Update,
+            systems::path_check::check_writable_paths
+                .run_if(resource_changed::<SettingsState>),
+        );
+
+        // Warn when the backend host doesn't resolve (looked up off the UI thread)
+        app.add_systems(Update, systems::host_check::check_backend_host);
+
+        // Per-image generation metadata (sidecar files) for the gallery
+        app.insert_resource(systems::assets::SidecarCache::default());
 
         // T9: Sixel render state for tracking screen changes and cleanup
         app.insert_resource(systems::render::SixelRenderState::default());
-
-        // T8: ZeroMQ client for backend communication (optional - graceful degradation if backend offline)
-        match crate::zmq_client::ZmqClient::new_default() {
-            Ok(client) => {
-                info!("ZMQ client connected to backend");
-                app.insert_resource(systems::zmq::ZmqClientResource::new(client));
-            }
-            Err(e) => {
-                warn!("Failed to connect to backend - jobs will be created but not processed: {}", e);
+        app.insert_resource(systems::render::PendingSixelWrites::default());
+
+        // Help screen scroll position and search
+        app.init_resource::<super::resources::HelpState>();
+
+        // T8: ZeroMQ client for backend communication (optional - graceful degradation if backend offline).
+        // A backend inserted before the plugin (the demo backend) is kept.
+        if app.world().contains_resource::<systems::zmq::ZmqClientResource>() {
+            info!("Using the backend provided by the app");
+        } else {
+            match crate::zmq_client::ZmqClient::with_config(&zmq_config) {
+                Ok(client) => {
+                    info!("ZMQ client connected to backend");
+                    app.insert_resource(systems::zmq::ZmqClientResource::new(client));
+                }
+                Err(e) => {
+                    warn!("Failed to connect to backend - jobs will be created but not processed: {}", e);
+                }
             }
         }
 
         // T9: Sixel cleanup system (run FIRST in PreUpdate to clear before rendering)
         app.add_systems(PreUpdate, systems::render::clear_sixel_on_screen_change);
 
+        // Command palette (Ctrl+P): runs first and swallows keys while open
+        app.insert_resource(super::resources::CommandPalette::default());
+        app.add_systems(
+            PreUpdate,
+            systems::input::handle_palette_input
+                .after(bevy_ratatui::event::InputSet::EmitCrossterm),
+        );
+
+        // Shared Yes/No dialog (deletes, settings reset): swallows keys while open
+        app.insert_resource(super::resources::ConfirmModal::default());
+        app.add_systems(
+            PreUpdate,
+            systems::input::handle_confirm_input.after(systems::input::handle_palette_input),
+        );
+
+        // Toasts for backend errors (Ctrl+X dismisses them)
+        app.insert_resource(super::resources::Notifications::default());
+
+        // Seed sweep dialog (Alt+W on Generation): swallows keys while open
+        app.insert_resource(super::resources::SeedSweep::default());
+        app.add_systems(
+            PreUpdate,
+            systems::input::screens::handle_seed_sweep_input
+                .after(systems::input::handle_confirm_input),
+        );
+
+        // Prompt preset picker (t on Generation): swallows keys while open
+        app.add_systems(
+            PreUpdate,
+            systems::input::screens::handle_preset_picker_input
+                .after(systems::input::screens::handle_seed_sweep_input),
+        );
+
+        // Theme picker (t on Settings): swallows keys while open
+        app.insert_resource(super::resources::ThemePicker::default());
+        app.add_systems(
+            PreUpdate,
+            systems::input::screens::handle_theme_picker_input
+                .after(systems::input::handle_confirm_input),
+        );
+
+        // Directory browser (editing a path setting): swallows keys while open
+        app.insert_resource(super::resources::DirBrowser::default());
+        app.add_systems(
+            PreUpdate,
+            systems::input::screens::handle_dir_browser_input
+                .after(systems::input::handle_confirm_input),
+        );
+
+        // Seed controls (Alt+S, and [ ] with an empty prompt, on Generation)
+        app.add_systems(
+            PreUpdate,
+            systems::input::screens::handle_seed_input
+                .after(systems::input::screens::handle_preset_picker_input),
+        );
+
+        // Regenerate the last submission (Alt+R on Generation)
+        app.add_systems(
+            PreUpdate,
+            systems::input::screens::handle_regenerate_input
+                .after(systems::input::screens::handle_seed_input),
+        );
+
+        // Debug Logs tab scrolling (PageUp/PageDown, Home/End on Generation)
+        app.add_systems(
+            PreUpdate,
+            systems::input::screens::handle_log_scroll_input
+                .after(systems::input::screens::handle_regenerate_input),
+        );
+
+        // Steps / CFG scale controls (Up/Down, +/- on Generation with an empty prompt)
+        app.insert_resource(super::resources::GenParams::default());
+        app.add_systems(
+            PreUpdate,
+            systems::input::screens::handle_gen_param_input
+                .after(systems::input::screens::handle_log_scroll_input),
+        );
+
         // WS-03: Global input systems (run in PreUpdate schedule)
         // These systems handle cross-screen functionality like quit, help, and navigation
         app.add_systems(
             PreUpdate,
             (
-                systems::input::handle_keyboard_input,   // Global keys: q, ?, h
+                systems::input::handle_keyboard_input,   // Global keys: q, ?
                 systems::input::handle_navigation,       // Tab, numbers 1-8, Esc
                 systems::input::handle_text_input,       // Text entry on Generation screen
-            ),
+                systems::input::text_entry::handle_paste_input, // Bracketed paste (may submit)
+            )
+                .after(systems::input::handle_palette_input)
+                .after(systems::input::screens::handle_gen_param_input)
+                .after(systems::input::screens::handle_theme_picker_input)
+                .after(systems::input::screens::handle_dir_browser_input),
         );
 
         // WS-03: Screen-specific input handlers (run in PreUpdate after global handlers)
@@ -95,19 +217,84 @@ impl Plugin for DgxPixelsPlugin {
             PreUpdate,
             (
                 systems::input::screens::handle_generation_input,  // Enter, G, C, P, L
-                systems::input::screens::handle_gallery_input,     // Arrow keys, d, Home/End
-                systems::input::screens::handle_comparison_input,  // Arrow keys, a, d, Enter
+                systems::input::screens::handle_gallery_input      // Arrow keys, d, Home/End, focus
+                    .after(systems::input::handle_navigation),
+                systems::input::screens::handle_comparison_input  // Arrow keys, a, d, e, p, Enter
+                    .after(systems::input::handle_navigation),
                 systems::input::screens::handle_models_input,      // Arrow keys, Enter, d, i
                 systems::input::screens::handle_queue_input,       // Arrow keys, Home/End, c (cancel)
                 systems::input::screens::handle_monitor_input,     // r, p (refresh/pause)
-                systems::input::screens::settings::handle_settings_input, // Settings toggles
-                systems::input::screens::handle_help_input,        // Read-only screen
-            ),
+                systems::input::screens::settings::handle_settings_input // Ctrl+D changes view
+                    .after(systems::input::handle_navigation),
+                systems::input::screens::handle_help_input,        // Scrolling, / search
+            )
+                .after(systems::input::handle_palette_input)
+                .after(systems::input::screens::handle_gen_param_input)
+                .after(systems::input::screens::handle_theme_picker_input)
+                .after(systems::input::screens::handle_dir_browser_input),
+        );
+
+        // Flag generating jobs whose progress updates have stopped
+        app.add_systems(
+            Update,
+            systems::zmq::detect_stalled_jobs.run_if(on_timer(std::time::Duration::from_secs(1))),
+        );
+
+        // Fail jobs the backend has held past the generation timeout
+        app.init_resource::<systems::job_timeout::JobClock>();
+        app.add_systems(
+            Update,
+            systems::job_timeout::enforce_job_timeouts.run_if(on_timer(
+                std::time::Duration::from_secs(systems::job_timeout::TIMEOUT_CHECK_INTERVAL_SECS),
+            )),
+        );
+
+        // Debug Logs tab: follow the backend or frontend log file
+        app.init_resource::<systems::log_tail::LogTail>();
+        app.add_systems(
+            Update,
+            systems::log_tail::tail_log_files
+                .run_if(on_timer(systems::log_tail::LOG_POLL_INTERVAL)),
+        );
+
+        // Loading spinner for running jobs (advances only while one is active)
+        app.insert_resource(systems::spinner::Spinner::default());
+        app.add_systems(
+            Update,
+            systems::spinner::advance_spinner
+                .run_if(on_timer(systems::spinner::SPINNER_INTERVAL)),
+        );
+
+        // Auto-clear finished jobs from the queue (when enabled in settings)
+        app.add_systems(
+            Update,
+            systems::job_cleanup::auto_clear_completed_jobs.run_if(on_timer(
+                std::time::Duration::from_secs(systems::job_cleanup::CLEANUP_INTERVAL_SECS),
+            )),
+        );
+
+        // Graceful shutdown: quit -> AppExit, then persist state
+        app.insert_resource(systems::shutdown::ShutdownConfig::default());
+        app.add_systems(
+            Last,
+            (
+                systems::shutdown::exit_on_quit,
+                systems::shutdown::persist_on_exit,
+            )
+                .chain(),
         );
 
         // WS-05: ZeroMQ polling (run in PreUpdate before other systems)
         app.add_systems(PreUpdate, systems::zmq::poll_zmq);
 
+        // GPU/memory stats for the Monitor screen
+        app.insert_resource(super::resources::MonitorState::default());
+        app.add_systems(
+            Update,
+            systems::zmq::poll_system_stats
+                .run_if(on_timer(super::resources::MONITOR_POLL_INTERVAL)),
+        );
+
         // WS-06: Image asset loading systems
         app.add_systems(
             Update,
@@ -115,16 +302,21 @@ impl Plugin for DgxPixelsPlugin {
                 systems::assets::load_preview_images,
                 systems::assets::loader::load_gallery_images,
                 systems::assets::loader::check_asset_loading,
+                systems::assets::loader::retry_image_loads,
+                systems::assets::sidecar::load_gallery_sidecars,
             ),
         );
 
-        // T10: Preview manager - periodic gallery scan (every 2 seconds)
+        // T10: Preview manager - periodic background gallery scan (started
+        // every 2 seconds, results collected every frame)
         app.add_systems(
             Update,
-            systems::assets::scan_gallery_directory
-                .run_if(on_timer(std::time::Duration::from_secs(
-                    systems::assets::SCAN_INTERVAL_SECS,
-                ))),
+            (
+                systems::assets::apply_gallery_dirs
+                    .run_if(resource_changed::<SettingsState>),
+                systems::assets::scan_gallery_directory,
+            )
+                .chain(),
         );
 
         // T10: Preview loading status checker (runs every frame)
@@ -148,12 +340,19 @@ impl Plugin for DgxPixelsPlugin {
             (
                 systems::render::screens::render_generation_screen,
                 systems::render::screens::render_gallery_screen,
+                systems::render::screens::render_gallery_zoom
+                    .run_if(|zoom: Option<Res<super::resources::GalleryZoom>>| zoom.is_some_and(|z| z.active)),
                 systems::render::screens::render_comparison_screen,
                 systems::render::screens::render_models_screen,
                 systems::render::screens::render_queue_screen,
                 systems::render::screens::render_monitor_screen,
                 systems::render::screens::settings::render_settings_screen,
                 systems::render::screens::render_help_screen,
+                // Sixel images queued by the screens, after ratatui's flush
+                systems::render::flush_pending_sixel,
+                systems::render::render_confirm_modal,   // Overlay
+                systems::render::render_notifications,   // Overlay
+                systems::render::render_command_palette, // Overlay, drawn last
             ).chain()
              .run_if(bevy::prelude::resource_exists::<bevy_ratatui::terminal::RatatuiContext>),
         );
@@ -167,6 +366,9 @@ impl Plugin for DgxPixelsPlugin {
         app.add_event::<super::events::SelectNextImage>();
         app.add_event::<super::events::SelectPreviousImage>();
         app.add_event::<super::events::DeleteImage>();
+        app.add_event::<super::events::ExportReport>();
+        app.add_event::<super::events::ExportComfyBatch>();
+        app.add_event::<super::events::ConfirmedAction>();
 
         // Progress tracking events (NEW)
         app.add_event::<super::events::JobProgressUpdate>();
@@ -178,12 +380,79 @@ impl Plugin for DgxPixelsPlugin {
             (
                 super::events::handle_navigation_events,
                 super::events::handle_generation_events,
+                super::events::handle_confirmed_actions
+                    .before(super::events::handle_gallery_events),
                 super::events::handle_gallery_events,
+                super::events::handle_export_report,
+                super::events::handle_export_comfy_batch,
                 systems::zmq::handle_zmq_responses,
                 systems::zmq::handle_zmq_updates, // NEW: Handle progress updates
+                systems::job_scheduler::dispatch_queued_jobs // Sends held jobs as slots free
+                    .after(super::events::handle_generation_events),
+                // Shows comparison jobs' images in their panes
+                systems::comparison_jobs::track_comparison_jobs
+                    .after(systems::zmq::handle_zmq_responses),
             ),
         );
 
         info!("DgxPixelsPlugin initialized with T10 Preview Manager, progress tracking, and all 8 screens");
     }
 }
+
+/// Settings from the config file at `path`, or the defaults if it can't be
+/// read.
+fn load_settings(path: &Path) -> SettingsState {
+    SettingsState::load_from(path).unwrap_or_else(|e| {
+        warn!("Using default settings: {:#}", e);
+        SettingsState::default()
+    })
+}
+
+/// Insert `settings` with the resources configured by it: UI strings, theme
+/// and the Sixel preview cache.
+fn insert_settings(app: &mut App, settings: SettingsState) {
+    app.insert_resource(super::resources::Strings::load(&settings.ui.language));
+
+    // WS-07: Theme resource (the configured theme, or the built-in default)
+    app.insert_resource(super::resources::AppTheme::named(&settings.ui.theme));
+
+    // T9: Sixel preview cache resource (for gallery image previews)
+    let sixel_cache_mb = settings.ui.sixel_cache_mb as usize;
+    app.insert_resource(systems::assets::SixelPreviewCache::with_max_size_mb(sixel_cache_mb));
+
+    app.insert_resource(settings);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_are_read_from_the_config_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let mut saved = SettingsState::default();
+        saved.ui.sixel_cache_mb = 7;
+        saved.backend.zmq_port = 5599;
+        saved.save_to(&path).unwrap();
+
+        let mut app = App::new();
+        insert_settings(&mut app, load_settings(&path));
+
+        let settings = app.world().resource::<SettingsState>();
+        assert_eq!(settings.ui.sixel_cache_mb, 7);
+        assert_eq!(settings.backend.zmq_port, 5599);
+        let cache = app.world().resource::<systems::assets::SixelPreviewCache>();
+        assert_eq!(cache.stats().max_size_bytes, 7 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_unreadable_config_falls_back_to_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "not = [valid").unwrap();
+
+        let settings = load_settings(&path);
+        assert_eq!(settings.ui.sixel_cache_mb, SettingsState::default().ui.sixel_cache_mb);
+    }
+}
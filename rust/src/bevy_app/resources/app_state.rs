@@ -19,6 +19,62 @@ use bevy::prelude::*;
 use std::path::PathBuf;
 use std::time::Instant;
 
+use crate::bevy_app::events::SubmitGenerationJob;
+
+/// Lines PageUp/PageDown scroll the Logs tab by.
+pub const LOG_PAGE_LINES: usize = 10;
+
+/// How the seed for the next generation is chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeedMode {
+    /// A new random seed for every job
+    #[default]
+    Random,
+    /// The same seed for every job
+    Fixed(u64),
+}
+
+/// Log file shown on the debug Logs tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogSource {
+    /// The backend's `dgx-pixels-backend.log`
+    #[default]
+    Backend,
+    /// The TUI's own `dgx-pixels-tui.log`
+    Frontend,
+}
+
+impl LogSource {
+    /// The other source.
+    pub fn toggle(self) -> Self {
+        match self {
+            LogSource::Backend => LogSource::Frontend,
+            LogSource::Frontend => LogSource::Backend,
+        }
+    }
+
+    /// Name shown on the Logs tab.
+    pub fn label(self) -> &'static str {
+        match self {
+            LogSource::Backend => "Backend",
+            LogSource::Frontend => "Frontend",
+        }
+    }
+
+    /// Name of the log file.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            LogSource::Backend => "dgx-pixels-backend.log",
+            LogSource::Frontend => "dgx-pixels-tui.log",
+        }
+    }
+}
+
+/// A random seed, kept within `u32` so every backend sampler accepts it.
+pub fn random_seed() -> u64 {
+    uuid::Uuid::new_v4().as_u64_pair().0 & u64::from(u32::MAX)
+}
+
 /// Global application state resource.
 #[derive(Resource, Debug, Clone)]
 pub struct AppState {
@@ -40,12 +96,40 @@ pub struct AppState {
     /// Backend log lines (for debug mode)
     pub backend_logs: Vec<String>,
 
+    /// The TUI's own log lines (for debug mode)
+    pub frontend_logs: Vec<String>,
+
+    /// Log shown on the Logs tab (Ctrl+L switches)
+    pub log_source: LogSource,
+
+    /// Lines the Logs tab is scrolled up from the newest; 0 follows the tail
+    pub log_scroll: usize,
+
     /// Current preview tab (0=Preview, 1=Logs)
     pub preview_tab: usize,
 
     /// Currently displayed preview path
     pub current_preview: Option<PathBuf>,
 
+    /// Preview replaced by the most recent completion
+    pub previous_preview: Option<PathBuf>,
+
+    /// Show previous and current previews side by side (Alt+P)
+    pub pin_preview: bool,
+
+    /// Prompt of the most recent submission (for the prompt diff)
+    pub last_submitted_prompt: Option<String>,
+
+    /// Seed used for the next submission (`s` toggles, `[`/`]` adjust)
+    pub seed_mode: SeedMode,
+
+    /// Seed of the most recent submission
+    pub last_seed: Option<u64>,
+
+    /// Most recent submission, with its seed, steps and CFG scale filled in
+    /// (`R` sends it again)
+    pub last_submission: Option<SubmitGenerationJob>,
+
     /// Screen navigation history
     pub screen_history: Vec<super::Screen>,
 }
@@ -59,8 +143,17 @@ impl Default for AppState {
             frame_count: 0,
             debug_mode: false,
             backend_logs: Vec::new(),
+            frontend_logs: Vec::new(),
+            log_source: LogSource::Backend,
+            log_scroll: 0,
             preview_tab: 0,
             current_preview: None,
+            previous_preview: None,
+            pin_preview: false,
+            last_submitted_prompt: None,
+            seed_mode: SeedMode::Random,
+            last_seed: None,
+            last_submission: None,
             screen_history: Vec::new(),
         }
     }
@@ -110,12 +203,129 @@ impl AppState {
         }
     }
 
+    /// Show a newly completed image, keeping the one it replaces as previous.
+    pub fn set_completed_preview(&mut self, path: PathBuf) {
+        if self.current_preview.as_ref() != Some(&path) {
+            self.previous_preview = self.current_preview.replace(path);
+        }
+        self.needs_redraw = true;
+    }
+
+    /// Toggle the previous/current side-by-side preview.
+    pub fn toggle_pin_preview(&mut self) {
+        self.pin_preview = !self.pin_preview;
+        self.needs_redraw = true;
+    }
+
+    /// Switch between random and fixed seeds.
+    ///
+    /// Locking keeps the last used seed (so a result can be reproduced), or
+    /// picks a new one if nothing has been generated yet.
+    pub fn toggle_seed_mode(&mut self) {
+        self.seed_mode = match self.seed_mode {
+            SeedMode::Random => SeedMode::Fixed(self.last_seed.unwrap_or_else(random_seed)),
+            SeedMode::Fixed(_) => SeedMode::Random,
+        };
+        self.needs_redraw = true;
+    }
+
+    /// Change a fixed seed by `delta` (no effect on random seeds).
+    pub fn step_seed(&mut self, delta: i64) {
+        if let SeedMode::Fixed(seed) = self.seed_mode {
+            self.seed_mode = SeedMode::Fixed(seed.saturating_add_signed(delta));
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Concrete seed for a new job (a fresh one in random mode), remembered
+    /// as the last seed.
+    pub fn next_seed(&mut self) -> u64 {
+        let seed = match self.seed_mode {
+            SeedMode::Random => random_seed(),
+            SeedMode::Fixed(seed) => seed,
+        };
+        self.last_seed = Some(seed);
+        seed
+    }
+
+    /// The most recent submission again, to generate with the same prompt,
+    /// seed, steps and CFG scale.
+    ///
+    /// `None` (logged) if nothing has been submitted yet.
+    pub fn resubmit_last(&self) -> Option<SubmitGenerationJob> {
+        if self.last_submission.is_none() {
+            info!("Nothing submitted yet - nothing to regenerate");
+        }
+        self.last_submission.clone()
+    }
+
     /// Add backend log line (truncates to last 500 lines).
     pub fn add_backend_log(&mut self, line: String) {
-        self.backend_logs.push(line);
-        if self.backend_logs.len() > 500 {
-            self.backend_logs.remove(0);
+        self.add_log(LogSource::Backend, line);
+    }
+
+    /// Add a log line from `source` (truncates to last 500 lines).
+    pub fn add_log(&mut self, source: LogSource, line: String) {
+        let logs = match source {
+            LogSource::Backend => &mut self.backend_logs,
+            LogSource::Frontend => &mut self.frontend_logs,
+        };
+        logs.push(line);
+        if logs.len() > 500 {
+            logs.remove(0);
         }
+        let len = logs.len();
+        // Scrolled up: keep the same lines in view
+        if source == self.log_source && self.log_scroll > 0 {
+            self.log_scroll = (self.log_scroll + 1).min(len);
+        }
+        self.needs_redraw = true;
+    }
+
+    /// Log lines read so far from `source`.
+    pub fn logs(&self, source: LogSource) -> &[String] {
+        match source {
+            LogSource::Backend => &self.backend_logs,
+            LogSource::Frontend => &self.frontend_logs,
+        }
+    }
+
+    /// Show the other log on the Logs tab (if debug mode enabled).
+    pub fn toggle_log_source(&mut self) {
+        if self.debug_mode {
+            self.log_source = self.log_source.toggle();
+            self.log_scroll = 0;
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Whether the Logs tab shows the newest lines as they arrive.
+    pub fn is_following_logs(&self) -> bool {
+        self.log_scroll == 0
+    }
+
+    /// Scroll the Logs tab `lines` towards older lines (pauses following).
+    pub fn scroll_logs_up(&mut self, lines: usize) {
+        let len = self.logs(self.log_source).len();
+        self.log_scroll = self.log_scroll.saturating_add(lines).min(len);
+        self.needs_redraw = true;
+    }
+
+    /// Scroll the Logs tab `lines` towards newer lines (follows again at the end).
+    pub fn scroll_logs_down(&mut self, lines: usize) {
+        self.log_scroll = self.log_scroll.saturating_sub(lines);
+        self.needs_redraw = true;
+    }
+
+    /// Scroll the Logs tab to the oldest line.
+    pub fn scroll_logs_to_top(&mut self) {
+        self.log_scroll = self.logs(self.log_source).len();
+        self.needs_redraw = true;
+    }
+
+    /// Jump to the newest line and follow the log again.
+    pub fn follow_logs(&mut self) {
+        self.log_scroll = 0;
         self.needs_redraw = true;
     }
 }
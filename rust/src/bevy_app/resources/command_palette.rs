@@ -0,0 +1,193 @@
+//! # Command Palette Resource
+//!
+//! State for the Ctrl+P command palette: a fuzzy-filtered list of actions
+//! drawn over the current screen. Every action lives in [`palette_actions`],
+//! so adding one to the palette is a single entry there (plus its dispatch
+//! in the palette input handler if it needs a new [`PaletteCommand`]).
+
+use bevy::prelude::*;
+
+use super::Screen;
+
+/// What a palette entry does when chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteCommand {
+    /// Switch to a screen
+    GoTo(Screen),
+    /// Submit the current prompt
+    Generate,
+    /// Export saved comparisons as an HTML report
+    ExportReport,
+    /// Export the shown gallery images as a ComfyUI batch
+    ExportComfyBatch,
+    Quit,
+}
+
+/// An entry in the command palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteAction {
+    pub label: &'static str,
+    pub command: PaletteCommand,
+}
+
+/// All palette actions, in the order shown for an empty query.
+pub fn palette_actions() -> Vec<PaletteAction> {
+    let action = |label, command| PaletteAction { label, command };
+    vec![
+        action("Generate", PaletteCommand::Generate),
+        action("Go to Generation", PaletteCommand::GoTo(Screen::Generation)),
+        action("Go to Comparison", PaletteCommand::GoTo(Screen::Comparison)),
+        action("Go to Queue", PaletteCommand::GoTo(Screen::Queue)),
+        action("Go to Gallery", PaletteCommand::GoTo(Screen::Gallery)),
+        action("Go to Models", PaletteCommand::GoTo(Screen::Models)),
+        action("Go to Monitor", PaletteCommand::GoTo(Screen::Monitor)),
+        action("Open Settings", PaletteCommand::GoTo(Screen::Settings)),
+        action("Open Help", PaletteCommand::GoTo(Screen::Help)),
+        action("Export Report", PaletteCommand::ExportReport),
+        action("Export ComfyUI Batch", PaletteCommand::ExportComfyBatch),
+        action("Quit", PaletteCommand::Quit),
+    ]
+}
+
+/// How well `query` fuzzy-matches `label`, or `None` if it doesn't.
+///
+/// Every query character must appear in order (case-insensitive, spaces in
+/// the query ignored). Runs of consecutive characters and matches at word
+/// starts score higher; skipped characters cost a little.
+pub fn fuzzy_score(query: &str, label: &str) -> Option<i32> {
+    let label: Vec<char> = label.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous: Option<usize> = None;
+
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let i = (next..label.len()).find(|&i| label[i] == q)?;
+        score += 1;
+        if i > 0 && previous == Some(i - 1) {
+            score += 5;
+        }
+        if i == 0 || label[i - 1] == ' ' {
+            score += 3;
+        }
+        score -= (i - next) as i32;
+        previous = Some(i);
+        next = i + 1;
+    }
+
+    Some(score)
+}
+
+/// Command palette state.
+#[derive(Resource, Debug, Clone)]
+pub struct CommandPalette {
+    /// Whether the overlay is showing
+    pub open: bool,
+    /// Filter text typed into the palette
+    pub query: String,
+    /// Selected row in the filtered list
+    pub selected: usize,
+    /// Registered actions (see [`palette_actions`])
+    pub actions: Vec<PaletteAction>,
+    /// The palette handled this frame's keys (other input systems skip them)
+    pub input_captured: bool,
+    /// The overlay closed and the screen underneath must be fully redrawn
+    pub needs_repaint: bool,
+}
+
+impl Default for CommandPalette {
+    fn default() -> Self {
+        Self {
+            open: false,
+            query: String::new(),
+            selected: 0,
+            actions: palette_actions(),
+            input_captured: false,
+            needs_repaint: false,
+        }
+    }
+}
+
+impl CommandPalette {
+    /// Show the palette with an empty query.
+    pub fn open(&mut self) {
+        self.open = true;
+        self.query.clear();
+        self.selected = 0;
+    }
+
+    /// Hide the palette.
+    pub fn close(&mut self) {
+        self.open = false;
+        self.needs_repaint = true;
+    }
+
+    /// Actions matching the query, best match first (ties keep registry order).
+    pub fn filtered(&self) -> Vec<&PaletteAction> {
+        let mut matches: Vec<(i32, &PaletteAction)> = self
+            .actions
+            .iter()
+            .filter_map(|a| fuzzy_score(&self.query, a.label).map(|score| (score, a)))
+            .collect();
+        matches.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        matches.into_iter().map(|(_, a)| a).collect()
+    }
+
+    /// The highlighted action, if any match.
+    pub fn selected_action(&self) -> Option<&PaletteAction> {
+        self.filtered().get(self.selected).copied()
+    }
+
+    /// Add a character to the query.
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    /// Remove the last character of the query.
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    /// Highlight the next match.
+    pub fn select_next(&mut self) {
+        let count = self.filtered().len();
+        if self.selected + 1 < count {
+            self.selected += 1;
+        }
+    }
+
+    /// Highlight the previous match.
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_filter_ranks_best_match_first() {
+        let mut palette = CommandPalette::default();
+        assert_eq!(palette.filtered().len(), palette.actions.len());
+
+        for c in "gal".chars() {
+            palette.push_char(c);
+        }
+        assert_eq!(palette.filtered()[0].label, "Go to Gallery");
+
+        palette.query = "set".to_string();
+        assert_eq!(
+            palette.selected_action().unwrap().command,
+            PaletteCommand::GoTo(Screen::Settings)
+        );
+
+        palette.query = "exprt".to_string();
+        assert_eq!(palette.filtered()[0].label, "Export Report");
+
+        palette.query = "xyz".to_string();
+        assert!(palette.filtered().is_empty());
+        assert!(palette.selected_action().is_none());
+    }
+}
@@ -1,9 +1,20 @@
 //! # Comparison State Resource
 //!
-//! Manages side-by-side model comparison state and results.
+//! Manages side-by-side model comparison state and results. A comparison's
+//! setup (prompts, models and seed option, not its results) can be saved to
+//! and loaded from a TOML file as a [`ComparisonConfig`].
+//!
+//! A pane can also show a saved image as a reference (see
+//! [`ComparisonState::load_reference`]): it is never regenerated, and
+//! generating fills only the other pane, with the reference's prompt and seed.
 
+use anyhow::{Context, Result};
 use bevy::prelude::*;
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::bevy_app::systems::assets::sidecar::load_sidecar;
 
 /// Which pane is currently selected in the comparison view
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -12,8 +23,19 @@ pub enum ComparisonPane {
     Right,
 }
 
+impl ComparisonPane {
+    /// The pane on the other side
+    pub fn other(self) -> Self {
+        match self {
+            ComparisonPane::Left => ComparisonPane::Right,
+            ComparisonPane::Right => ComparisonPane::Left,
+        }
+    }
+}
+
 /// Comparison mode: single model vs. multi-model comparison
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ComparisonMode {
     /// Side-by-side dual comparison (default)
     Dual,
@@ -75,6 +97,89 @@ pub struct ComparisonState {
 
     /// Selected index in model list when browsing
     pub model_list_index: usize,
+
+    // === Per-Pane Prompt Overrides ===
+    /// Use `left_prompt`/`right_prompt` instead of the shared prompt
+    pub per_pane_prompts: bool,
+
+    /// Left pane prompt override (falls back to `prompt` when empty)
+    pub left_prompt: Option<String>,
+
+    /// Right pane prompt override (falls back to `prompt` when empty)
+    pub right_prompt: Option<String>,
+
+    /// Whether the prompt box is being edited
+    pub editing_prompt: bool,
+
+    /// Text being edited (committed to the shared or pane prompt on finish)
+    pub prompt_edit_buffer: String,
+
+    /// Show the difference of the two pane images in place of the panes
+    pub overlay_diff: bool,
+
+    /// Regenerate a pane with the other pane's seed (fair comparison)
+    pub use_same_seed: bool,
+
+    /// Models pinned to the (left, right) panes; a reset puts them back
+    /// instead of clearing the pane
+    pub pinned_models: (Option<String>, Option<String>),
+
+    /// Pane showing a saved image instead of a generated one
+    pub reference_pane: Option<ComparisonPane>,
+}
+
+/// Version written to saved comparison configs
+pub const COMPARISON_CONFIG_VERSION: u32 = 1;
+
+/// A comparison's setup, as saved to TOML
+///
+/// Missing fields take their defaults and unknown ones are ignored, so
+/// configs written by other versions still load.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ComparisonConfig {
+    pub version: u32,
+    pub mode: ComparisonMode,
+    pub prompt: String,
+    pub per_pane_prompts: bool,
+    pub left_prompt: Option<String>,
+    pub right_prompt: Option<String>,
+    pub left_model: Option<String>,
+    pub right_model: Option<String>,
+    pub pinned_left_model: Option<String>,
+    pub pinned_right_model: Option<String>,
+    /// Models of a multi comparison
+    pub models: Vec<String>,
+    pub use_same_seed: bool,
+}
+
+impl Default for ComparisonConfig {
+    fn default() -> Self {
+        Self {
+            version: COMPARISON_CONFIG_VERSION,
+            mode: ComparisonMode::Dual,
+            prompt: String::new(),
+            per_pane_prompts: false,
+            left_prompt: None,
+            right_prompt: None,
+            left_model: None,
+            right_model: None,
+            pinned_left_model: None,
+            pinned_right_model: None,
+            models: Vec::new(),
+            use_same_seed: true,
+        }
+    }
+}
+
+/// A single pane's generation request in dual mode
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaneSubmission {
+    pub pane: ComparisonPane,
+    pub model: String,
+    pub prompt: String,
+    /// Seed to generate with (`None` for a random one)
+    pub seed: Option<u64>,
 }
 
 /// Generation metadata for a completed comparison
@@ -114,6 +219,15 @@ impl Default for ComparisonState {
             available_models: Vec::new(),
             browsing_models: false,
             model_list_index: 0,
+            per_pane_prompts: false,
+            left_prompt: None,
+            right_prompt: None,
+            editing_prompt: false,
+            prompt_edit_buffer: String::new(),
+            overlay_diff: false,
+            use_same_seed: true,
+            pinned_models: (None, None),
+            reference_pane: None,
         }
     }
 }
@@ -170,10 +284,7 @@ impl ComparisonState {
 
     /// Switch to the other pane
     pub fn toggle_pane(&mut self) {
-        self.selected_pane = match self.selected_pane {
-            ComparisonPane::Left => ComparisonPane::Right,
-            ComparisonPane::Right => ComparisonPane::Left,
-        };
+        self.selected_pane = self.selected_pane.other();
     }
 
     /// Set model for currently selected pane
@@ -218,18 +329,161 @@ impl ComparisonState {
         }
     }
 
+    // === Per-Pane Prompt Methods ===
+
+    /// Toggle per-pane prompt overrides on or off
+    pub fn toggle_per_pane_prompts(&mut self) {
+        self.per_pane_prompts = !self.per_pane_prompts;
+    }
+
+    /// Prompt override stored for a pane (ignoring whether overrides are enabled)
+    pub fn pane_prompt_override(&self, pane: ComparisonPane) -> Option<&str> {
+        match pane {
+            ComparisonPane::Left => self.left_prompt.as_deref(),
+            ComparisonPane::Right => self.right_prompt.as_deref(),
+        }
+        .filter(|p| !p.trim().is_empty())
+    }
+
+    /// Prompt a pane will actually be generated with
+    pub fn effective_prompt(&self, pane: ComparisonPane) -> &str {
+        if self.per_pane_prompts {
+            if let Some(prompt) = self.pane_prompt_override(pane) {
+                return prompt;
+            }
+        }
+        &self.prompt
+    }
+
+    /// Prompt of the selected pane (`None` if it has none)
+    pub fn selected_pane_prompt(&self) -> Option<&str> {
+        Some(self.effective_prompt(self.selected_pane)).filter(|prompt| !prompt.trim().is_empty())
+    }
+
+    /// Whether the two panes would be generated with different prompts
+    pub fn prompts_differ(&self) -> bool {
+        self.effective_prompt(ComparisonPane::Left) != self.effective_prompt(ComparisonPane::Right)
+    }
+
+    /// Start editing the shared prompt, or the selected pane's override
+    pub fn start_prompt_edit(&mut self) {
+        self.prompt_edit_buffer = if self.per_pane_prompts {
+            self.pane_prompt_override(self.selected_pane)
+                .unwrap_or_default()
+                .to_string()
+        } else {
+            self.prompt.clone()
+        };
+        self.editing_prompt = true;
+    }
+
+    /// Apply the edit buffer (an empty pane override clears it)
+    pub fn finish_prompt_edit(&mut self) {
+        let text = std::mem::take(&mut self.prompt_edit_buffer);
+        if self.per_pane_prompts {
+            let value = if text.trim().is_empty() { None } else { Some(text) };
+            match self.selected_pane {
+                ComparisonPane::Left => self.left_prompt = value,
+                ComparisonPane::Right => self.right_prompt = value,
+            }
+        } else {
+            self.prompt = text;
+        }
+        self.editing_prompt = false;
+    }
+
+    /// Discard the edit buffer
+    pub fn cancel_prompt_edit(&mut self) {
+        self.prompt_edit_buffer.clear();
+        self.editing_prompt = false;
+    }
+
+    /// One request per pane, each with its own effective prompt
+    ///
+    /// Returns `None` if a pane has no model or no prompt.
+    pub fn dual_submissions(&self) -> Option<[PaneSubmission; 2]> {
+        Some([
+            self.pane_submission(ComparisonPane::Left)?,
+            self.pane_submission(ComparisonPane::Right)?,
+        ])
+    }
+
+    /// Request for one pane: its model and effective prompt
+    ///
+    /// Returns `None` if the pane has no model or no prompt.
+    fn pane_submission(&self, pane: ComparisonPane) -> Option<PaneSubmission> {
+        let prompt = self.effective_prompt(pane);
+        if prompt.trim().is_empty() {
+            return None;
+        }
+        let model = match pane {
+            ComparisonPane::Left => &self.left_model,
+            ComparisonPane::Right => &self.right_model,
+        };
+        Some(PaneSubmission {
+            pane,
+            model: model.clone()?,
+            prompt: prompt.to_string(),
+            seed: None,
+        })
+    }
+
     /// Start dual comparison generation
     pub fn start_dual_comparison(&mut self) {
-        if !self.prompt.is_empty()
-            && self.left_model.is_some()
-            && self.right_model.is_some() {
+        if self.dual_submissions().is_some() {
             self.is_running = true;
             // Clear previous results
             self.left_image = None;
             self.right_image = None;
             self.left_metadata = None;
             self.right_metadata = None;
+            self.reference_pane = None;
+        }
+    }
+
+    /// Whether a pane is still waiting for its image
+    pub fn pane_running(&self, pane: ComparisonPane) -> bool {
+        let image = match pane {
+            ComparisonPane::Left => &self.left_image,
+            ComparisonPane::Right => &self.right_image,
+        };
+        self.is_running && image.is_none()
+    }
+
+    /// Re-roll one pane, leaving the other pane's result alone
+    ///
+    /// Clears the pane's image and marks it running, returning the request to
+    /// generate it with: the pane's model and prompt, and (with
+    /// `use_same_seed`) the seed the other pane was generated with. Returns
+    /// `None` if the pane has no model or prompt, or holds the reference.
+    pub fn regenerate_pane(&mut self, pane: ComparisonPane) -> Option<PaneSubmission> {
+        if self.reference_pane == Some(pane) {
+            return None;
         }
+        let mut submission = self.pane_submission(pane)?;
+        let other_metadata = match pane {
+            ComparisonPane::Left => &self.right_metadata,
+            ComparisonPane::Right => &self.left_metadata,
+        };
+        if self.use_same_seed {
+            submission.seed = other_metadata.as_ref().and_then(|m| m.seed);
+        }
+
+        match pane {
+            ComparisonPane::Left => {
+                self.left_image = None;
+                self.left_job_id = None;
+                self.left_metadata = None;
+            }
+            ComparisonPane::Right => {
+                self.right_image = None;
+                self.right_job_id = None;
+                self.right_metadata = None;
+            }
+        }
+        self.overlay_diff = false;
+        self.is_running = true;
+        Some(submission)
     }
 
     /// Update left pane result
@@ -256,13 +510,153 @@ impl ComparisonState {
         self.right_metadata = Some(metadata);
     }
 
+    /// Note the job generating `pane`'s image, so its result lands there
+    pub fn set_pane_job(&mut self, pane: ComparisonPane, job_id: String) {
+        match pane {
+            ComparisonPane::Left => self.left_job_id = Some(job_id),
+            ComparisonPane::Right => self.right_job_id = Some(job_id),
+        }
+    }
+
+    /// Pane a job is generating the image for, if any
+    pub fn pane_for_job(&self, job_id: &str) -> Option<ComparisonPane> {
+        if self.left_job_id.as_deref() == Some(job_id) {
+            Some(ComparisonPane::Left)
+        } else if self.right_job_id.as_deref() == Some(job_id) {
+            Some(ComparisonPane::Right)
+        } else {
+            None
+        }
+    }
+
+    /// Show a finished job's image in its pane, stopping once both panes
+    /// have one
+    ///
+    /// Returns `false` if the job isn't one of this comparison's.
+    pub fn finish_pane_job(
+        &mut self,
+        job_id: &str,
+        image_path: PathBuf,
+        metadata: GenerationMetadata,
+    ) -> bool {
+        match self.pane_for_job(job_id) {
+            Some(ComparisonPane::Left) => {
+                self.update_left_result(job_id.to_string(), image_path, metadata)
+            }
+            Some(ComparisonPane::Right) => {
+                self.update_right_result(job_id.to_string(), image_path, metadata)
+            }
+            None => return false,
+        }
+        if self.is_comparison_complete() {
+            self.is_running = false;
+        }
+        true
+    }
+
+    /// Stop waiting for a job that failed or was cancelled
+    ///
+    /// Returns `false` if the job isn't one of this comparison's.
+    pub fn fail_pane_job(&mut self, job_id: &str) -> bool {
+        match self.pane_for_job(job_id) {
+            Some(ComparisonPane::Left) => self.left_job_id = None,
+            Some(ComparisonPane::Right) => self.right_job_id = None,
+            None => return false,
+        }
+        self.is_running = false;
+        true
+    }
+
+    /// Show a saved image in the selected pane as the reference
+    ///
+    /// The pane's seed and steps (and its model, and the shared prompt) are
+    /// taken from the image's sidecar when it has them. Nothing is
+    /// generated; the pane is marked as the reference so only the other pane
+    /// is generated against it.
+    pub fn load_reference(&mut self, path: &Path) -> Result<()> {
+        let size = image::image_dimensions(path)
+            .with_context(|| format!("Failed to read reference image {:?}", path))?;
+        let sidecar = load_sidecar(path).unwrap_or_default();
+        let metadata = GenerationMetadata {
+            size,
+            seed: sidecar.seed,
+            inference_time_s: sidecar.duration_s.unwrap_or_default(),
+            steps: sidecar.steps.unwrap_or_default(),
+        };
+
+        let pane = self.selected_pane;
+        let (image, job_id, pane_metadata, model) = match pane {
+            ComparisonPane::Left => (
+                &mut self.left_image,
+                &mut self.left_job_id,
+                &mut self.left_metadata,
+                &mut self.left_model,
+            ),
+            ComparisonPane::Right => (
+                &mut self.right_image,
+                &mut self.right_job_id,
+                &mut self.right_metadata,
+                &mut self.right_model,
+            ),
+        };
+        *image = Some(path.to_path_buf());
+        *job_id = None;
+        *pane_metadata = Some(metadata);
+        if sidecar.model.is_some() {
+            *model = sidecar.model;
+        }
+        if let Some(prompt) = sidecar.prompt {
+            self.prompt = prompt;
+        }
+
+        self.reference_pane = Some(pane);
+        self.is_running = false;
+        self.overlay_diff = false;
+        Ok(())
+    }
+
+    /// Whether `pane` shows the reference image
+    pub fn is_reference(&self, pane: ComparisonPane) -> bool {
+        self.reference_pane == Some(pane)
+    }
+
     /// Check if both comparisons are complete
     pub fn is_comparison_complete(&self) -> bool {
         self.left_image.is_some() && self.right_image.is_some()
     }
 
     /// Reset comparison results
+    ///
+    /// Panes go back to their pinned model; unpinned panes are cleared.
     pub fn reset_results(&mut self) {
+        self.clear_results();
+        self.left_model = self.pinned_models.0.clone();
+        self.right_model = self.pinned_models.1.clone();
+    }
+
+    /// Pin the selected pane's model, or unpin it if it's pinned
+    ///
+    /// Returns whether the pane is now pinned (a pane without a model can't be).
+    pub fn toggle_pin_selected_pane(&mut self) -> bool {
+        let model = self.get_selected_pane_model().cloned();
+        let pin = match self.selected_pane {
+            ComparisonPane::Left => &mut self.pinned_models.0,
+            ComparisonPane::Right => &mut self.pinned_models.1,
+        };
+        *pin = if pin.is_some() { None } else { model };
+        pin.is_some()
+    }
+
+    /// Whether `pane` has a pinned model
+    pub fn is_pinned(&self, pane: ComparisonPane) -> bool {
+        match pane {
+            ComparisonPane::Left => self.pinned_models.0.is_some(),
+            ComparisonPane::Right => self.pinned_models.1.is_some(),
+        }
+    }
+
+    /// Clear the generated images and stop, keeping the pane models
+    fn clear_results(&mut self) {
         self.left_image = None;
         self.right_image = None;
         self.left_job_id = None;
@@ -270,6 +664,27 @@ impl ComparisonState {
         self.left_metadata = None;
         self.right_metadata = None;
         self.is_running = false;
+        self.overlay_diff = false;
+        self.reference_pane = None;
+    }
+
+    /// Toggle the difference overlay (only once both panes have an image)
+    pub fn toggle_overlay_diff(&mut self) {
+        self.overlay_diff =
+            !self.overlay_diff && self.left_image.is_some() && self.right_image.is_some();
+    }
+
+    /// Start a fresh comparison with the same two models
+    ///
+    /// Clears the prompt (including pane overrides) and results, then opens
+    /// the prompt box so the new prompt can be typed straight away.
+    pub fn duplicate_with_new_prompt(&mut self) {
+        self.clear_results();
+        self.prompt.clear();
+        self.left_prompt = None;
+        self.right_prompt = None;
+        self.per_pane_prompts = false;
+        self.start_prompt_edit();
     }
 
     /// Populate available models from backend response
@@ -278,14 +693,94 @@ impl ComparisonState {
         self.model_list_index = 0;
     }
 
+    /// The current setup, for saving
+    pub fn to_config(&self) -> ComparisonConfig {
+        ComparisonConfig {
+            version: COMPARISON_CONFIG_VERSION,
+            mode: self.mode,
+            prompt: self.prompt.clone(),
+            per_pane_prompts: self.per_pane_prompts,
+            left_prompt: self.left_prompt.clone(),
+            right_prompt: self.right_prompt.clone(),
+            left_model: self.left_model.clone(),
+            right_model: self.right_model.clone(),
+            pinned_left_model: self.pinned_models.0.clone(),
+            pinned_right_model: self.pinned_models.1.clone(),
+            models: self.models.clone(),
+            use_same_seed: self.use_same_seed,
+        }
+    }
+
+    /// Replace the setup with `config`
+    ///
+    /// Results of the previous setup are cleared, since they no longer match.
+    pub fn apply_config(&mut self, config: &ComparisonConfig) {
+        self.clear_results();
+        self.cancel_prompt_edit();
+        self.browsing_models = false;
+        self.mode = config.mode;
+        self.prompt = config.prompt.clone();
+        self.per_pane_prompts = config.per_pane_prompts;
+        self.left_prompt = config.left_prompt.clone();
+        self.right_prompt = config.right_prompt.clone();
+        self.left_model = config.left_model.clone();
+        self.right_model = config.right_model.clone();
+        self.pinned_models = (
+            config.pinned_left_model.clone(),
+            config.pinned_right_model.clone(),
+        );
+        self.models = config.models.iter().take(3).cloned().collect();
+        self.selected_index = 0;
+        self.use_same_seed = config.use_same_seed;
+    }
+
+    /// Default config file path: `~/.config/dgx-pixels/comparison.toml`
+    pub fn config_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from(".config"))
+            .join("dgx-pixels")
+            .join("comparison.toml")
+    }
+
+    /// Save the current setup to `path`
+    pub fn save_config(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create config directory: {:?}", parent))?;
+        }
+
+        let contents = toml::to_string_pretty(&self.to_config())
+            .context("Failed to serialize comparison config")?;
+        fs::write(path, contents)
+            .with_context(|| format!("Failed to write comparison config: {:?}", path))?;
+
+        info!("Saved comparison config to {:?}", path);
+        Ok(())
+    }
+
+    /// Load the setup saved at `path`
+    pub fn load_config(&mut self, path: &Path) -> Result<()> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read comparison config: {:?}", path))?;
+        let config: ComparisonConfig = toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse comparison config: {:?}", path))?;
+
+        if config.version != COMPARISON_CONFIG_VERSION {
+            warn!(
+                "Comparison config {:?} is version {} (expected {}), loading what's known",
+                path, config.version, COMPARISON_CONFIG_VERSION
+            );
+        }
+        self.apply_config(&config);
+
+        info!("Loaded comparison config from {:?}", path);
+        Ok(())
+    }
+
     /// Check if ready to run comparison
     pub fn can_run_comparison(&self) -> bool {
         match self.mode {
-            ComparisonMode::Dual => {
-                !self.prompt.is_empty()
-                    && self.left_model.is_some()
-                    && self.right_model.is_some()
-            }
+            ComparisonMode::Dual => self.dual_submissions().is_some(),
             ComparisonMode::Multi => {
                 !self.prompt.is_empty() && !self.models.is_empty()
             }
@@ -406,4 +901,218 @@ mod tests {
         assert!(state.right_image.is_none());
         assert!(!state.is_running);
     }
+
+    #[test]
+    fn test_reset_keeps_pinned_models() {
+        let mut state = ComparisonState {
+            left_image: Some(PathBuf::from("/test/left.png")),
+            right_image: Some(PathBuf::from("/test/right.png")),
+            ..Default::default()
+        };
+        assert!(state.toggle_pin_selected_pane());
+        assert!(state.is_pinned(ComparisonPane::Left));
+        assert!(!state.is_pinned(ComparisonPane::Right));
+
+        // A different model picked later goes back to the pinned one
+        state.left_model = Some("Other Model".to_string());
+        state.reset_results();
+        assert_eq!(state.left_model.as_deref(), Some("SDXL Base 1.0"));
+        assert_eq!(state.right_model, None);
+        assert!(state.left_image.is_none());
+        assert!(state.right_image.is_none());
+
+        // Unpinned, the next reset clears the pane too
+        assert!(!state.toggle_pin_selected_pane());
+        state.reset_results();
+        assert_eq!(state.left_model, None);
+    }
+
+    #[test]
+    fn test_per_pane_prompts_submitted_independently() {
+        let mut state = ComparisonState {
+            prompt: "pixel art knight".to_string(),
+            ..Default::default()
+        };
+        state.toggle_per_pane_prompts();
+
+        state.selected_pane = ComparisonPane::Left;
+        state.start_prompt_edit();
+        state.prompt_edit_buffer = "pixel art knight, pixelsprite".to_string();
+        state.finish_prompt_edit();
+
+        state.selected_pane = ComparisonPane::Right;
+        state.start_prompt_edit();
+        state.prompt_edit_buffer = "pixel art knight, gameasset".to_string();
+        state.finish_prompt_edit();
+
+        let [left, right] = state.dual_submissions().expect("both panes ready");
+        assert_eq!(left.pane, ComparisonPane::Left);
+        assert_eq!(left.model, "SDXL Base 1.0");
+        assert_eq!(left.prompt, "pixel art knight, pixelsprite");
+        assert_eq!(right.pane, ComparisonPane::Right);
+        assert_eq!(right.prompt, "pixel art knight, gameasset");
+        assert!(state.prompts_differ());
+        // Shared prompt untouched by pane edits
+        assert_eq!(state.prompt, "pixel art knight");
+    }
+
+    #[test]
+    fn test_shared_prompt_used_when_overrides_empty() {
+        let mut state = ComparisonState {
+            prompt: "isometric castle".to_string(),
+            per_pane_prompts: true,
+            left_prompt: Some("   ".to_string()),
+            right_prompt: None,
+            ..Default::default()
+        };
+
+        let [left, right] = state.dual_submissions().unwrap();
+        assert_eq!(left.prompt, "isometric castle");
+        assert_eq!(right.prompt, "isometric castle");
+        assert!(!state.prompts_differ());
+
+        // Overrides are ignored while the toggle is off
+        state.per_pane_prompts = false;
+        state.left_prompt = Some("ignored".to_string());
+        assert_eq!(state.effective_prompt(ComparisonPane::Left), "isometric castle");
+    }
+
+    #[test]
+    fn test_regenerate_pane_resets_only_that_pane() {
+        let metadata = |seed| GenerationMetadata {
+            size: (1024, 1024),
+            seed: Some(seed),
+            inference_time_s: 3.0,
+            steps: 30,
+        };
+        let mut state = ComparisonState {
+            prompt: "pixel art knight".to_string(),
+            ..Default::default()
+        };
+        state.update_left_result("left-1".to_string(), PathBuf::from("left.png"), metadata(7));
+        state.update_right_result("right-1".to_string(), PathBuf::from("right.png"), metadata(9));
+
+        let submission = state.regenerate_pane(ComparisonPane::Right).expect("pane ready");
+        assert_eq!(submission.pane, ComparisonPane::Right);
+        assert_eq!(submission.model, "Pixel Art LoRA v1");
+        assert_eq!(submission.prompt, "pixel art knight");
+        // Same seed as the left pane for a fair comparison
+        assert_eq!(submission.seed, Some(7));
+
+        assert!(state.right_image.is_none());
+        assert!(state.right_job_id.is_none());
+        assert!(state.pane_running(ComparisonPane::Right));
+        assert_eq!(state.left_image, Some(PathBuf::from("left.png")));
+        assert_eq!(state.left_job_id.as_deref(), Some("left-1"));
+        assert!(!state.pane_running(ComparisonPane::Left));
+
+        // Without the flag the re-roll gets a fresh seed
+        state.use_same_seed = false;
+        let submission = state.regenerate_pane(ComparisonPane::Left).unwrap();
+        assert_eq!(submission.seed, None);
+        assert!(state.regenerate_pane(ComparisonPane::Left).is_some());
+
+        state.prompt.clear();
+        assert!(state.regenerate_pane(ComparisonPane::Left).is_none());
+    }
+
+    #[test]
+    fn test_overrides_allow_running_without_shared_prompt() {
+        let mut state = ComparisonState {
+            per_pane_prompts: true,
+            left_prompt: Some("left only".to_string()),
+            ..Default::default()
+        };
+        assert!(!state.can_run_comparison());
+
+        state.right_prompt = Some("right only".to_string());
+        assert!(state.can_run_comparison());
+    }
+
+    #[test]
+    fn test_config_round_trips_through_toml() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("comparison.toml");
+
+        let mut state = ComparisonState {
+            prompt: "knight".to_string(),
+            per_pane_prompts: true,
+            right_prompt: Some("mage".to_string()),
+            left_model: Some("model-a".to_string()),
+            right_model: None,
+            use_same_seed: false,
+            ..Default::default()
+        };
+        state.toggle_pin_selected_pane();
+        state.save_config(&path).unwrap();
+
+        let mut restored = ComparisonState {
+            left_image: Some(PathBuf::from("left.png")),
+            ..Default::default()
+        };
+        restored.load_config(&path).unwrap();
+        assert_eq!(restored.to_config(), state.to_config());
+        assert_eq!(restored.right_model, None);
+        assert_eq!(restored.pinned_models.0.as_deref(), Some("model-a"));
+        assert!(restored.left_image.is_none());
+    }
+
+    #[test]
+    fn test_config_ignores_unknown_fields() {
+        let config: ComparisonConfig = toml::from_str(
+            "version = 7\nprompt = \"knight\"\nleft_model = \"model-a\"\nsampler = \"euler\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.prompt, "knight");
+        assert_eq!(config.left_model.as_deref(), Some("model-a"));
+        assert!(config.use_same_seed);
+
+        let mut state = ComparisonState::default();
+        state.apply_config(&config);
+        assert_eq!(state.right_model, None);
+        assert_eq!(state.mode, ComparisonMode::Dual);
+    }
+
+    #[test]
+    fn test_load_reference_fills_pane_without_a_job() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("knight.png");
+        image::RgbaImage::new(64, 32).save(&path).unwrap();
+        crate::metadata::GenerationMetadata {
+            prompt: Some("pixel art knight".to_string()),
+            seed: Some(1234),
+            steps: Some(25),
+            model: Some("lora-v1".to_string()),
+            ..Default::default()
+        }
+        .save(&path)
+        .unwrap();
+
+        let mut state = ComparisonState {
+            selected_pane: ComparisonPane::Right,
+            ..Default::default()
+        };
+        state.load_reference(&path).unwrap();
+        assert_eq!(state.right_image.as_deref(), Some(path.as_path()));
+        assert_eq!(state.right_model.as_deref(), Some("lora-v1"));
+        assert_eq!(state.prompt, "pixel art knight");
+        let metadata = state.right_metadata.as_ref().unwrap();
+        assert_eq!((metadata.size, metadata.seed, metadata.steps), ((64, 32), Some(1234), 25));
+        // Nothing was submitted
+        assert!(state.right_job_id.is_none() && state.left_job_id.is_none());
+        assert!(!state.is_running);
+        assert!(state.is_reference(ComparisonPane::Right));
+
+        // Only the other pane generates, with the reference's seed
+        assert!(state.regenerate_pane(ComparisonPane::Right).is_none());
+        let submission = state.regenerate_pane(ComparisonPane::Left).unwrap();
+        assert_eq!(submission.seed, Some(1234));
+        assert_eq!(submission.prompt, "pixel art knight");
+        assert_eq!(state.right_image.as_deref(), Some(path.as_path()));
+        assert!(!state.pane_running(ComparisonPane::Right));
+
+        state.reset_results();
+        assert!(state.reference_pane.is_none());
+        assert!(state.load_reference(&dir.path().join("missing.png")).is_err());
+    }
 }
@@ -0,0 +1,74 @@
+//! # Confirm Modal Resource
+//!
+//! A Yes/No dialog shared by every screen for actions that are hard to undo
+//! (deleting images or models, resetting settings). A screen asks with
+//! [`ConfirmModal::ask`]; the modal's input system swallows keys while it's
+//! open and sends a `ConfirmedAction` event once the user says yes.
+
+use bevy::prelude::*;
+use std::path::PathBuf;
+
+/// What happens when the modal is confirmed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfirmAction {
+    /// Move a gallery image to the trash
+    DeleteImage(PathBuf),
+    /// Remove a downloaded model (by name)
+    DeleteModel(String),
+    /// Put every setting back to its default
+    ResetSettings,
+}
+
+/// State of the confirmation dialog.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ConfirmModal {
+    /// Whether the dialog is showing
+    pub open: bool,
+    /// Question shown in the dialog
+    pub message: String,
+    /// Action run on confirm (`None` while closed)
+    pub on_confirm: Option<ConfirmAction>,
+    /// The dialog closed and the screen underneath must be fully redrawn
+    pub needs_repaint: bool,
+}
+
+impl ConfirmModal {
+    /// Show the dialog asking `message`, running `action` if confirmed.
+    pub fn ask(&mut self, message: impl Into<String>, action: ConfirmAction) {
+        self.open = true;
+        self.message = message.into();
+        self.on_confirm = Some(action);
+    }
+
+    /// Close the dialog, returning the action to run.
+    pub fn confirm(&mut self) -> Option<ConfirmAction> {
+        let action = self.on_confirm.take();
+        self.cancel();
+        action
+    }
+
+    /// Close the dialog without running anything.
+    pub fn cancel(&mut self) {
+        self.open = false;
+        self.message.clear();
+        self.on_confirm = None;
+        self.needs_repaint = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confirm_returns_action_once() {
+        let mut modal = ConfirmModal::default();
+        modal.ask("Reset all settings?", ConfirmAction::ResetSettings);
+        assert!(modal.open);
+
+        assert_eq!(modal.confirm(), Some(ConfirmAction::ResetSettings));
+        assert!(!modal.open);
+        assert!(modal.message.is_empty());
+        assert_eq!(modal.confirm(), None);
+    }
+}
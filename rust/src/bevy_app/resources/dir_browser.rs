@@ -0,0 +1,160 @@
+//! # Directory Browser Resource
+//!
+//! A minimal directory picker for the path settings (output, cache and
+//! models directories), so they can be chosen instead of typed blind. It
+//! lists the subdirectories of [`DirBrowser::cwd`]; the Settings input
+//! system moves through them and accepts `cwd` as the setting's value.
+
+use bevy::prelude::*;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// State of the Settings screen's directory browser.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DirBrowser {
+    /// Whether the browser overlay is showing
+    pub open: bool,
+    /// Directory being listed
+    pub cwd: PathBuf,
+    /// Names of the subdirectories of `cwd`, sorted
+    pub entries: Vec<String>,
+    /// Highlighted entry
+    pub selected: usize,
+    /// Why `cwd` couldn't be listed (e.g. permission denied)
+    pub error: Option<String>,
+}
+
+impl DirBrowser {
+    /// Show the browser at `start`, or at its nearest existing ancestor if
+    /// it doesn't exist (yet).
+    pub fn open_at(&mut self, start: &Path) {
+        let mut cwd = start;
+        while !cwd.is_dir() {
+            match cwd.parent() {
+                Some(parent) => cwd = parent,
+                None => {
+                    cwd = Path::new(".");
+                    break;
+                }
+            }
+        }
+        // Absolute so Backspace can always go up
+        self.cwd = fs::canonicalize(cwd).unwrap_or_else(|_| cwd.to_path_buf());
+        self.refresh();
+        self.open = true;
+    }
+
+    /// Hide the browser.
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Re-read the subdirectories of `cwd`, selecting the first.
+    pub fn refresh(&mut self) {
+        self.selected = 0;
+        match list_subdirectories(&self.cwd) {
+            Ok(entries) => {
+                self.entries = entries;
+                self.error = None;
+            }
+            Err(e) => {
+                self.entries.clear();
+                self.error = Some(format!("Can't read {}: {}", self.cwd.display(), e));
+            }
+        }
+    }
+
+    /// List the highlighted subdirectory instead.
+    pub fn descend(&mut self) {
+        if let Some(name) = self.selected_entry() {
+            self.cwd = self.cwd.join(name);
+            self.refresh();
+        }
+    }
+
+    /// List the parent directory, with the one just left highlighted.
+    pub fn ascend(&mut self) {
+        let Some(parent) = self.cwd.parent().map(Path::to_path_buf) else {
+            return;
+        };
+        let left = self.cwd.file_name().map(|n| n.to_string_lossy().into_owned());
+        self.cwd = parent;
+        self.refresh();
+        if let Some(index) = left.and_then(|left| self.entries.iter().position(|e| *e == left)) {
+            self.selected = index;
+        }
+    }
+
+    /// Highlight the next subdirectory.
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.entries.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Highlight the previous subdirectory.
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// The highlighted subdirectory's name.
+    pub fn selected_entry(&self) -> Option<&str> {
+        self.entries.get(self.selected).map(String::as_str)
+    }
+}
+
+/// Names of the directories in `dir`, sorted.
+fn list_subdirectories(dir: &Path) -> io::Result<Vec<String>> {
+    let mut names: Vec<String> = fs::read_dir(dir)?
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descend_and_ascend() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = fs::canonicalize(dir.path()).unwrap();
+        fs::create_dir_all(root.join("sprites/knights")).unwrap();
+        fs::create_dir(root.join("archive")).unwrap();
+        fs::write(root.join("notes.txt"), b"").unwrap();
+
+        let mut browser = DirBrowser::default();
+        browser.open_at(&root.join("missing/output"));
+        assert!(browser.open);
+        assert_eq!(browser.cwd, root);
+        assert_eq!(browser.entries, vec!["archive", "sprites"]);
+
+        browser.select_next();
+        browser.descend();
+        assert_eq!(browser.cwd, root.join("sprites"));
+        assert_eq!(browser.entries, vec!["knights"]);
+
+        browser.ascend();
+        assert_eq!(browser.cwd, root);
+        assert_eq!(browser.selected_entry(), Some("sprites"));
+    }
+
+    #[test]
+    fn test_unreadable_directory_shows_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut browser = DirBrowser::default();
+        browser.open_at(dir.path());
+
+        browser.cwd = dir.path().join("gone");
+        browser.refresh();
+        assert!(browser.entries.is_empty());
+        assert!(browser.error.as_deref().is_some_and(|e| e.starts_with("Can't read")));
+
+        browser.ascend();
+        assert_eq!(browser.error, None);
+    }
+}
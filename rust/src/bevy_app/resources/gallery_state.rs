@@ -1,6 +1,12 @@
 //! # Gallery State Resource
 //!
-//! Manages the gallery of generated images and selection state.
+//! Manages the gallery of generated images and selection state. Images can
+//! be tagged (tags live in each image's metadata sidecar) and the gallery
+//! narrowed to one tag with [`GalleryState::set_filter_tag`]. Images can also
+//! be starred as favorites (kept in `~/.local/share/dgx-pixels/favorites.json`)
+//! and the gallery narrowed to those. Images picked into
+//! [`GalleryState::selected_set`] can be copied to a folder together with
+//! [`GalleryState::copy_selected_to`].
 //!
 //! ## Example
 //!
@@ -16,9 +22,151 @@
 //! }
 //! ```
 
+use anyhow::{bail, Context, Result};
 use bevy::prelude::*;
-use std::path::PathBuf;
-use std::time::SystemTime;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::metadata::sidecar_path;
+
+/// Folder (next to each image) that deleted images are moved into.
+pub const TRASH_DIR: &str = ".trash";
+
+/// How long after the user last moved the selection before new images may
+/// take it over (`ui.auto_select_newest`).
+pub const AUTO_SELECT_IDLE: Duration = Duration::from_secs(5);
+
+/// Images the thumbnail list shows at once (and PageUp/PageDown move by).
+pub const THUMBNAIL_WINDOW: usize = 10;
+
+/// Rectangular image region in pixel coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CropRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Crop selection on the currently selected image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CropSelection {
+    /// Selected region (always inside the image)
+    pub rect: CropRect,
+    /// Source image size (width, height)
+    pub image_size: (u32, u32),
+}
+
+impl CropSelection {
+    /// Start with a centered selection covering half of each dimension.
+    pub fn new(image_size: (u32, u32)) -> Self {
+        let (width, height) = image_size;
+        let rect = CropRect {
+            x: width / 4,
+            y: height / 4,
+            width: (width / 2).max(1).min(width),
+            height: (height / 2).max(1).min(height),
+        };
+        Self { rect, image_size }
+    }
+
+    /// Pixels moved or resized per key press (1/64 of the smaller side).
+    pub fn step(&self) -> u32 {
+        (self.image_size.0.min(self.image_size.1) / 64).max(1)
+    }
+
+    /// Move the selection by `dx`/`dy` steps, staying inside the image.
+    pub fn move_by(&mut self, dx: i32, dy: i32) {
+        let step = self.step() as i64;
+        let (width, height) = self.image_size;
+        self.rect.x = (self.rect.x as i64 + dx as i64 * step)
+            .clamp(0, (width - self.rect.width) as i64) as u32;
+        self.rect.y = (self.rect.y as i64 + dy as i64 * step)
+            .clamp(0, (height - self.rect.height) as i64) as u32;
+    }
+
+    /// Grow or shrink the selection by `dw`/`dh` steps (anchored top-left).
+    pub fn resize_by(&mut self, dw: i32, dh: i32) {
+        let step = self.step();
+        let (width, height) = self.image_size;
+        let min_w = step.min(width) as i64;
+        let min_h = step.min(height) as i64;
+        self.rect.width = (self.rect.width as i64 + dw as i64 * step as i64)
+            .clamp(min_w, (width - self.rect.x) as i64) as u32;
+        self.rect.height = (self.rect.height as i64 + dh as i64 * step as i64)
+            .clamp(min_h, (height - self.rect.y) as i64) as u32;
+    }
+}
+
+/// Labels of the metadata editor fields, in form order.
+pub const METADATA_FIELDS: [&str; 5] = ["Prompt", "Seed", "Steps", "CFG Scale", "Tags"];
+
+/// Metadata editor form for the selected image's sidecar.
+///
+/// Values are kept as typed and only validated on save.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MetadataForm {
+    /// Field values, in [`METADATA_FIELDS`] order (tags comma-separated)
+    pub values: [String; 5],
+    /// Field being edited
+    pub selected: usize,
+    /// Error from the last save attempt
+    pub error: Option<String>,
+}
+
+impl MetadataForm {
+    /// Move to the next field (wraps around).
+    pub fn next_field(&mut self) {
+        self.selected = (self.selected + 1) % METADATA_FIELDS.len();
+    }
+
+    /// Move to the previous field (wraps around).
+    pub fn previous_field(&mut self) {
+        self.selected = (self.selected + METADATA_FIELDS.len() - 1) % METADATA_FIELDS.len();
+    }
+
+    /// Type a character into the selected field.
+    pub fn push_char(&mut self, c: char) {
+        self.values[self.selected].push(c);
+        self.error = None;
+    }
+
+    /// Delete the last character of the selected field.
+    pub fn backspace(&mut self) {
+        self.values[self.selected].pop();
+        self.error = None;
+    }
+}
+
+/// What the gallery's one-line tag prompt is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagEntryMode {
+    /// Add a tag to the selected image
+    Add,
+    /// Show only images with a tag (empty clears the filter)
+    Filter,
+    /// Copy the picked images into a folder (the text is its path)
+    Export,
+}
+
+/// Tag being typed into the gallery's tag prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagEntry {
+    pub mode: TagEntryMode,
+    pub text: String,
+}
+
+/// An image moved to the trash, kept so the delete can be undone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashedImage {
+    /// Where the image was
+    pub original: PathBuf,
+    /// Where it is now
+    pub trashed: PathBuf,
+    /// Its position in the gallery
+    pub index: usize,
+}
 
 /// Gallery state resource.
 #[derive(Resource, Debug, Clone)]
@@ -29,6 +177,32 @@ pub struct GalleryState {
     pub selected: usize,
     /// Last time the gallery was updated (for change detection)
     pub last_updated: SystemTime,
+    /// Focus mode: selected image fills the whole terminal, no chrome
+    pub focus_mode: bool,
+    /// Grid view: images laid out in cells instead of preview + list
+    pub grid_view: bool,
+    /// Crop mode: selection on the current image (`None` when not cropping)
+    pub crop: Option<CropSelection>,
+    /// Metadata editor for the current image (`None` when not editing)
+    pub metadata: Option<MetadataForm>,
+    /// When the user last moved the selection (`None` if they haven't)
+    pub last_manual_nav: Option<Instant>,
+    /// Deleted images, most recent last
+    pub trash: Vec<TrashedImage>,
+    /// Tags per image, mirrored from the sidecars
+    pub tags: HashMap<PathBuf, Vec<String>>,
+    /// Only images with this tag are shown (`None` shows everything)
+    pub filter_tag: Option<String>,
+    /// Tag prompt (`None` when not typing a tag)
+    pub tag_entry: Option<TagEntry>,
+    /// Starred images
+    pub favorites: HashSet<PathBuf>,
+    /// Only favorites are shown
+    pub favorites_only: bool,
+    /// Where favorites are saved when they change (`None` keeps them in memory)
+    pub favorites_path: Option<PathBuf>,
+    /// Images picked for export
+    pub selected_set: HashSet<PathBuf>,
 }
 
 impl Default for GalleryState {
@@ -37,6 +211,19 @@ impl Default for GalleryState {
             images: Vec::new(),
             selected: 0,
             last_updated: SystemTime::now(),
+            focus_mode: false,
+            grid_view: false,
+            crop: None,
+            metadata: None,
+            last_manual_nav: None,
+            trash: Vec::new(),
+            tags: HashMap::new(),
+            filter_tag: None,
+            tag_entry: None,
+            favorites: HashSet::new(),
+            favorites_only: false,
+            favorites_path: None,
+            selected_set: HashSet::new(),
         }
     }
 }
@@ -54,39 +241,193 @@ impl GalleryState {
     pub fn remove_image(&mut self, path: &PathBuf) -> bool {
         if let Some(pos) = self.images.iter().position(|p| p == path) {
             self.images.remove(pos);
+            self.selected_set.remove(path);
             self.last_updated = SystemTime::now();
 
             // Adjust selection if needed
             if self.selected >= self.images.len() && !self.images.is_empty() {
                 self.selected = self.images.len() - 1;
             }
+            if self.images.is_empty() {
+                self.focus_mode = false;
+            }
+            self.crop = None;
+            self.metadata = None;
             true
         } else {
             false
         }
     }
 
+    /// Move the selected image, and its metadata sidecar if it has one, into
+    /// the [`TRASH_DIR`] folder next to it and drop it from the gallery.
+    /// Returns the trashed path.
+    pub fn delete_selected(&mut self) -> Result<PathBuf> {
+        let Some(original) = self.current_image().cloned() else {
+            bail!("No image selected");
+        };
+
+        let trash_dir = original
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join(TRASH_DIR);
+        std::fs::create_dir_all(&trash_dir)
+            .with_context(|| format!("Failed to create trash folder: {:?}", trash_dir))?;
+        let trashed = unique_path_in(&trash_dir, &original);
+        std::fs::rename(&original, &trashed)
+            .with_context(|| format!("Failed to move {:?} to trash", original))?;
+        let sidecar = sidecar_path(&original);
+        if sidecar.exists() {
+            if let Err(e) = std::fs::rename(&sidecar, sidecar_path(&trashed)) {
+                // Keep the image with its metadata
+                let _ = std::fs::rename(&trashed, &original);
+                return Err(e).with_context(|| format!("Failed to move {:?} to trash", sidecar));
+            }
+        }
+
+        let index = self.selected;
+        self.remove_image(&original);
+        self.trash.push(TrashedImage {
+            original,
+            trashed: trashed.clone(),
+            index,
+        });
+        Ok(trashed)
+    }
+
+    /// Restore the most recently deleted image (and its sidecar) and select
+    /// it. Returns the restored path, or `None` if nothing has been deleted.
+    pub fn undo_last_delete(&mut self) -> Result<Option<PathBuf>> {
+        let Some(entry) = self.trash.last().cloned() else {
+            return Ok(None);
+        };
+        let sidecar = sidecar_path(&entry.original);
+        let trashed_sidecar = sidecar_path(&entry.trashed);
+        let restore_sidecar = trashed_sidecar.exists();
+        if entry.original.exists() {
+            bail!("Cannot restore {:?}: a file with that name exists", entry.original);
+        }
+        if restore_sidecar && sidecar.exists() {
+            bail!("Cannot restore {:?}: a file with that name exists", sidecar);
+        }
+        std::fs::rename(&entry.trashed, &entry.original)
+            .with_context(|| format!("Failed to restore {:?}", entry.original))?;
+        if restore_sidecar {
+            std::fs::rename(&trashed_sidecar, &sidecar)
+                .with_context(|| format!("Failed to restore {:?}", sidecar))?;
+        }
+        self.trash.pop();
+
+        if !self.images.contains(&entry.original) {
+            let index = entry.index.min(self.images.len());
+            self.images.insert(index, entry.original.clone());
+            self.last_updated = SystemTime::now();
+        }
+        if let Some(index) = self.images.iter().position(|p| p == &entry.original) {
+            self.selected = index;
+        }
+        Ok(Some(entry.original))
+    }
+
     /// Select next image (wraps around).
+    ///
+    /// With a tag filter set, images without the tag are skipped.
     pub fn select_next(&mut self) {
-        if !self.images.is_empty() {
-            self.selected = (self.selected + 1) % self.images.len();
+        let len = self.images.len();
+        if let Some(next) = (1..=len)
+            .map(|step| (self.selected + step) % len)
+            .find(|&index| self.matches_filter(&self.images[index]))
+        {
+            self.selected = next;
         }
     }
 
     /// Select previous image (wraps around).
+    ///
+    /// With a tag filter set, images without the tag are skipped.
     pub fn select_previous(&mut self) {
-        if !self.images.is_empty() {
-            self.selected = if self.selected == 0 {
-                self.images.len() - 1
-            } else {
-                self.selected - 1
-            };
+        let len = self.images.len();
+        if let Some(previous) = (1..=len)
+            .map(|step| (self.selected + len - step) % len)
+            .find(|&index| self.matches_filter(&self.images[index]))
+        {
+            self.selected = previous;
+        }
+    }
+
+    /// Move the selection `window` images back, stopping at the first.
+    ///
+    /// Like the other jumps, only images passing the filters count.
+    pub fn page_up(&mut self, window: usize) {
+        let position = self.visible_position();
+        self.select_visible(|_| position.saturating_sub(window.max(1)));
+    }
+
+    /// Move the selection `window` images on, stopping at the last.
+    pub fn page_down(&mut self, window: usize) {
+        let position = self.visible_position();
+        self.select_visible(|len| (position + window.max(1)).min(len - 1));
+    }
+
+    /// Select the first image.
+    pub fn first(&mut self) {
+        self.select_visible(|_| 0);
+    }
+
+    /// Select the last image.
+    pub fn last(&mut self) {
+        self.select_visible(|len| len - 1);
+    }
+
+    /// Position of the selection among the images passing the filters (0 if
+    /// it's hidden).
+    pub fn visible_position(&self) -> usize {
+        self.visible_indices()
+            .iter()
+            .position(|&index| index == self.selected)
+            .unwrap_or(0)
+    }
+
+    /// Select the visible image at the position `pick` returns for the number
+    /// of visible images (does nothing if none are visible).
+    fn select_visible(&mut self, pick: impl FnOnce(usize) -> usize) {
+        let visible = self.visible_indices();
+        if !visible.is_empty() {
+            self.selected = visible[pick(visible.len())];
+        }
+    }
+
+    /// Record that the user moved the selection themselves.
+    pub fn mark_manual_nav(&mut self) {
+        self.last_manual_nav = Some(Instant::now());
+    }
+
+    /// Select a newly added image unless the user navigated within
+    /// [`AUTO_SELECT_IDLE`] of `now`. Returns whether the selection moved.
+    pub fn auto_select(&mut self, path: &Path, now: Instant) -> bool {
+        let idle = self
+            .last_manual_nav
+            .map_or(true, |at| now.saturating_duration_since(at) >= AUTO_SELECT_IDLE);
+        if !idle || self.is_cropping() || self.is_editing_metadata() {
+            return false;
+        }
+
+        match self.images.iter().position(|p| p == path) {
+            Some(index) => {
+                self.selected = index;
+                true
+            }
+            None => false,
         }
     }
 
     /// Get currently selected image path.
+    ///
+    /// `None` if the selection is hidden by the tag filter.
     pub fn current_image(&self) -> Option<&PathBuf> {
-        self.images.get(self.selected)
+        self.images
+            .get(self.selected)
+            .filter(|path| self.matches_filter(path))
     }
 
     /// Get total number of images.
@@ -103,14 +444,356 @@ impl GalleryState {
     pub fn clear(&mut self) {
         self.images.clear();
         self.selected = 0;
+        self.focus_mode = false;
+        self.crop = None;
+        self.metadata = None;
+        self.tags.clear();
+        self.tag_entry = None;
         self.last_updated = SystemTime::now();
     }
+
+    /// Enter focus mode (only if there is an image to show).
+    pub fn enter_focus_mode(&mut self) {
+        if !self.images.is_empty() {
+            self.focus_mode = true;
+        }
+    }
+
+    /// Exit focus mode and return to the normal gallery layout.
+    pub fn exit_focus_mode(&mut self) {
+        self.focus_mode = false;
+    }
+
+    /// Toggle between the preview + list layout and the grid view.
+    pub fn toggle_grid_view(&mut self) {
+        self.grid_view = !self.grid_view;
+    }
+
+    /// Enter crop mode for the current image (size in pixels).
+    pub fn start_crop(&mut self, image_size: (u32, u32)) {
+        if !self.images.is_empty() && image_size.0 > 0 && image_size.1 > 0 {
+            self.crop = Some(CropSelection::new(image_size));
+        }
+    }
+
+    /// Leave crop mode without cropping.
+    pub fn cancel_crop(&mut self) {
+        self.crop = None;
+    }
+
+    /// Whether crop mode is active.
+    pub fn is_cropping(&self) -> bool {
+        self.crop.is_some()
+    }
+
+    /// Open the metadata editor for the current image.
+    pub fn start_metadata_edit(&mut self, form: MetadataForm) {
+        if !self.images.is_empty() {
+            self.metadata = Some(form);
+        }
+    }
+
+    /// Close the metadata editor.
+    pub fn close_metadata_edit(&mut self) {
+        self.metadata = None;
+    }
+
+    /// Whether the metadata editor is open.
+    pub fn is_editing_metadata(&self) -> bool {
+        self.metadata.is_some()
+    }
+
+    /// Tags of an image.
+    pub fn tags_for(&self, path: &Path) -> &[String] {
+        self.tags.get(path).map_or(&[], Vec::as_slice)
+    }
+
+    /// Record an image's tags (e.g. after its sidecar was read or saved).
+    pub fn set_tags(&mut self, path: PathBuf, tags: Vec<String>) {
+        if tags.is_empty() {
+            self.tags.remove(&path);
+        } else {
+            self.tags.insert(path, tags);
+        }
+    }
+
+    /// Whether an image passes the tag filter (tags match ignoring case) and
+    /// the favorites filter.
+    pub fn matches_filter(&self, path: &Path) -> bool {
+        if self.favorites_only && !self.favorites.contains(path) {
+            return false;
+        }
+        match &self.filter_tag {
+            Some(tag) => self.tags_for(path).iter().any(|t| t.eq_ignore_ascii_case(tag)),
+            None => true,
+        }
+    }
+
+    /// Show only images tagged `tag`, or every image for `None`.
+    ///
+    /// The selection moves to the first matching image if the current one
+    /// is filtered out.
+    pub fn set_filter_tag(&mut self, tag: Option<String>) {
+        self.filter_tag = tag.map(|t| t.trim().to_string()).filter(|t| !t.is_empty());
+        self.keep_selection_visible();
+    }
+
+    /// Move the selection to the first shown image if the filters hide it.
+    fn keep_selection_visible(&mut self) {
+        let selected_visible = self
+            .images
+            .get(self.selected)
+            .is_some_and(|path| self.matches_filter(path));
+        if !selected_visible {
+            if let Some(index) = self.visible_indices().first() {
+                self.selected = *index;
+            }
+        }
+    }
+
+    /// Path of the favorites file.
+    pub fn default_favorites_path() -> PathBuf {
+        crate::shutdown::data_dir().join("favorites.json")
+    }
+
+    /// Load favorites from `path`, saving future changes there.
+    ///
+    /// A missing file gives no favorites.
+    pub fn load_favorites_from(&mut self, path: &Path) -> Result<()> {
+        self.favorites_path = Some(path.to_path_buf());
+        self.favorites = if path.exists() {
+            let content = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read favorites: {:?}", path))?;
+            serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse favorites: {:?}", path))?
+        } else {
+            HashSet::new()
+        };
+        Ok(())
+    }
+
+    /// Save favorites to `favorites_path` (if set), sorted so the file diffs cleanly.
+    pub fn save_favorites(&self) -> Result<()> {
+        let Some(path) = &self.favorites_path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {:?}", parent))?;
+        }
+        let mut favorites: Vec<&PathBuf> = self.favorites.iter().collect();
+        favorites.sort();
+        let json =
+            serde_json::to_string_pretty(&favorites).context("Failed to serialize favorites")?;
+        std::fs::write(path, json).with_context(|| format!("Failed to write favorites: {:?}", path))
+    }
+
+    /// Whether an image is a favorite.
+    pub fn is_favorite(&self, path: &Path) -> bool {
+        self.favorites.contains(path)
+    }
+
+    /// Star or unstar the current image and save the favorites.
+    ///
+    /// Returns whether the image is now a favorite (`false` with no image).
+    /// Unstarring in the favorites view moves the selection to a shown image.
+    pub fn toggle_favorite(&mut self) -> Result<bool> {
+        let Some(path) = self.current_image().cloned() else {
+            return Ok(false);
+        };
+        let favorite = if self.favorites.remove(&path) {
+            false
+        } else {
+            self.favorites.insert(path);
+            true
+        };
+        self.keep_selection_visible();
+        self.save_favorites()?;
+        Ok(favorite)
+    }
+
+    /// Pick the current image for export, or un-pick it.
+    ///
+    /// Returns whether the image is now picked (`false` with no image).
+    pub fn toggle_in_selected_set(&mut self) -> bool {
+        let Some(path) = self.current_image().cloned() else {
+            return false;
+        };
+        if self.selected_set.remove(&path) {
+            false
+        } else {
+            self.selected_set.insert(path);
+            true
+        }
+    }
+
+    /// Whether `path` is picked for export.
+    pub fn is_in_selected_set(&self, path: &Path) -> bool {
+        self.selected_set.contains(path)
+    }
+
+    /// Copy the picked images, and their sidecars, into `dest` (created if
+    /// needed), in gallery order.
+    ///
+    /// Names are kept; one already taken in `dest` gets a number, and its
+    /// sidecar is renamed to match. Returns the copies' paths.
+    pub fn copy_selected_to(&self, dest: &Path) -> Result<Vec<PathBuf>> {
+        std::fs::create_dir_all(dest)
+            .with_context(|| format!("Failed to create export folder: {:?}", dest))?;
+
+        let mut copied = Vec::new();
+        for source in self.images.iter().filter(|path| self.selected_set.contains(*path)) {
+            let target = unique_path_in(dest, source);
+            std::fs::copy(source, &target)
+                .with_context(|| format!("Failed to copy {:?} to {:?}", source, target))?;
+
+            let sidecar = sidecar_path(source);
+            if sidecar.exists() {
+                let target_sidecar = sidecar_path(&target);
+                std::fs::copy(&sidecar, &target_sidecar).with_context(|| {
+                    format!("Failed to copy {:?} to {:?}", sidecar, target_sidecar)
+                })?;
+            }
+            copied.push(target);
+        }
+        Ok(copied)
+    }
+
+    /// Switch between showing every image and only favorites.
+    pub fn toggle_favorites_only(&mut self) {
+        self.favorites_only = !self.favorites_only;
+        self.keep_selection_visible();
+    }
+
+    /// Indices into `images` of the images passing the filters.
+    pub fn visible_indices(&self) -> Vec<usize> {
+        (0..self.images.len())
+            .filter(|&index| self.matches_filter(&self.images[index]))
+            .collect()
+    }
+
+    /// Open the tag prompt.
+    pub fn start_tag_entry(&mut self, mode: TagEntryMode) {
+        let text = match mode {
+            TagEntryMode::Filter => self.filter_tag.clone().unwrap_or_default(),
+            TagEntryMode::Add | TagEntryMode::Export => String::new(),
+        };
+        let open = match mode {
+            TagEntryMode::Filter => true,
+            TagEntryMode::Add => self.current_image().is_some(),
+            TagEntryMode::Export => !self.selected_set.is_empty(),
+        };
+        if open {
+            self.tag_entry = Some(TagEntry { mode, text });
+        }
+    }
+
+    /// Close the tag prompt.
+    pub fn close_tag_entry(&mut self) {
+        self.tag_entry = None;
+    }
+
+    /// Whether the tag prompt is open.
+    pub fn is_entering_tag(&self) -> bool {
+        self.tag_entry.is_some()
+    }
+}
+
+/// Path for `original` in `dir`, numbered if the name (or its sidecar's) is
+/// taken.
+fn unique_path_in(dir: &Path, original: &Path) -> PathBuf {
+    let taken = |path: &Path| path.exists() || sidecar_path(path).exists();
+    let name = original.file_name().unwrap_or_default();
+    let candidate = dir.join(name);
+    if !taken(&candidate) {
+        return candidate;
+    }
+
+    let stem = original.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = original
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+    (1..)
+        .map(|n| dir.join(format!("{}-{}{}", stem, n, extension)))
+        .find(|path| !taken(path))
+        .expect("unbounded range")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_auto_select_only_when_idle() {
+        let mut gallery = GalleryState::default();
+        gallery.add_image(PathBuf::from("image1.png"));
+        gallery.add_image(PathBuf::from("image2.png"));
+
+        // Just navigated: a new image doesn't move the selection
+        gallery.mark_manual_nav();
+        gallery.add_image(PathBuf::from("image3.png"));
+        assert!(!gallery.auto_select(Path::new("image3.png"), Instant::now()));
+        assert_eq!(gallery.selected, 0);
+
+        // Idle long enough: it does
+        let later = Instant::now() + AUTO_SELECT_IDLE;
+        assert!(gallery.auto_select(Path::new("image3.png"), later));
+        assert_eq!(gallery.selected, 2);
+    }
+
+    fn gallery_of(count: usize) -> GalleryState {
+        let mut gallery = GalleryState::default();
+        for i in 0..count {
+            gallery.add_image(PathBuf::from(format!("image{}.png", i)));
+        }
+        gallery
+    }
+
+    #[test]
+    fn test_up_down_wrap_at_the_ends() {
+        let mut gallery = gallery_of(25);
+        gallery.select_previous();
+        assert_eq!(gallery.selected, 24);
+        gallery.select_next();
+        assert_eq!(gallery.selected, 0);
+    }
+
+    #[test]
+    fn test_page_and_home_end_clamp() {
+        let mut gallery = gallery_of(25);
+        gallery.page_down(THUMBNAIL_WINDOW);
+        assert_eq!(gallery.selected, 10);
+        gallery.page_down(THUMBNAIL_WINDOW);
+        gallery.page_down(THUMBNAIL_WINDOW);
+        // Paging past the end stops at the last image instead of wrapping
+        assert_eq!(gallery.selected, 24);
+        gallery.page_up(THUMBNAIL_WINDOW);
+        assert_eq!(gallery.selected, 14);
+        gallery.page_up(THUMBNAIL_WINDOW);
+        gallery.page_up(THUMBNAIL_WINDOW);
+        assert_eq!(gallery.selected, 0);
+
+        gallery.last();
+        assert_eq!(gallery.selected, 24);
+        gallery.first();
+        assert_eq!(gallery.selected, 0);
+
+        // With a filter, only the images it shows count
+        gallery.favorites = [5, 9, 20].map(|i| PathBuf::from(format!("image{}.png", i))).into();
+        gallery.toggle_favorites_only();
+        assert_eq!(gallery.selected, 5);
+        gallery.page_down(THUMBNAIL_WINDOW);
+        assert_eq!(gallery.selected, 20);
+        gallery.first();
+        assert_eq!(gallery.selected, 5);
+
+        let mut empty = GalleryState::default();
+        empty.page_down(THUMBNAIL_WINDOW);
+        empty.last();
+        assert_eq!(empty.selected, 0);
+    }
+
     #[test]
     fn test_default_gallery() {
         let gallery = GalleryState::default();
@@ -222,4 +905,202 @@ mod tests {
         assert_eq!(gallery.selected, 0);
         assert!(gallery.is_empty());
     }
+
+    #[test]
+    fn test_focus_mode() {
+        let mut gallery = GalleryState::default();
+        gallery.enter_focus_mode();
+        assert!(!gallery.focus_mode, "empty gallery has nothing to focus");
+
+        gallery.add_image(PathBuf::from("image1.png"));
+        gallery.enter_focus_mode();
+        assert!(gallery.focus_mode);
+
+        gallery.exit_focus_mode();
+        assert!(!gallery.focus_mode);
+
+        gallery.enter_focus_mode();
+        gallery.remove_image(&PathBuf::from("image1.png"));
+        assert!(!gallery.focus_mode);
+    }
+
+    #[test]
+    fn test_crop_selection_stays_inside_image() {
+        let mut crop = CropSelection::new((128, 64));
+        assert_eq!(
+            crop.rect,
+            CropRect {
+                x: 32,
+                y: 16,
+                width: 64,
+                height: 32
+            }
+        );
+        assert_eq!(crop.step(), 1);
+
+        crop.move_by(-1000, 1000);
+        assert_eq!((crop.rect.x, crop.rect.y), (0, 32));
+
+        crop.resize_by(1000, 1000);
+        assert_eq!((crop.rect.width, crop.rect.height), (128, 32));
+
+        crop.resize_by(-1000, -1000);
+        assert_eq!((crop.rect.width, crop.rect.height), (1, 1));
+    }
+
+    #[test]
+    fn test_crop_mode_requires_image() {
+        let mut gallery = GalleryState::default();
+        gallery.start_crop((64, 64));
+        assert!(!gallery.is_cropping());
+
+        gallery.add_image(PathBuf::from("/test/image1.png"));
+        gallery.start_crop((64, 64));
+        assert!(gallery.is_cropping());
+
+        gallery.clear();
+        assert!(!gallery.is_cropping());
+    }
+
+    #[test]
+    fn test_delete_last_image_clamps_selection_and_undo_restores() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut gallery = GalleryState::default();
+        for name in ["a.png", "b.png", "c.png"] {
+            let path = dir.path().join(name);
+            std::fs::write(&path, b"png").unwrap();
+            gallery.add_image(path);
+        }
+        let last = dir.path().join("c.png");
+        std::fs::write(sidecar_path(&last), "{}").unwrap();
+        gallery.selected = 2;
+
+        let trashed = gallery.delete_selected().unwrap();
+        assert_eq!(trashed, dir.path().join(TRASH_DIR).join("c.png"));
+        assert!(trashed.exists());
+        assert!(!last.exists());
+        // The sidecar goes with the image
+        assert!(sidecar_path(&trashed).exists());
+        assert!(!sidecar_path(&last).exists());
+        assert_eq!(gallery.len(), 2);
+        assert_eq!(gallery.selected, 1);
+
+        assert_eq!(gallery.undo_last_delete().unwrap(), Some(last.clone()));
+        assert!(last.exists());
+        assert!(sidecar_path(&last).exists());
+        assert!(!sidecar_path(&trashed).exists());
+        assert_eq!(gallery.images[2], last);
+        assert_eq!(gallery.selected, 2);
+
+        // Nothing left to undo
+        assert_eq!(gallery.undo_last_delete().unwrap(), None);
+    }
+
+    #[test]
+    fn test_copy_selected_to_folder() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut gallery = GalleryState::default();
+        for name in ["a.png", "b.png", "c.png"] {
+            let path = dir.path().join(name);
+            std::fs::write(&path, name).unwrap();
+            std::fs::write(sidecar_path(&path), format!("{{\"prompt\": \"{}\"}}", name)).unwrap();
+            gallery.add_image(path);
+        }
+        assert!(gallery.toggle_in_selected_set());
+        gallery.selected = 2;
+        assert!(gallery.toggle_in_selected_set());
+
+        // "a.png" is already in the export folder
+        let dest = dir.path().join("client");
+        std::fs::create_dir(&dest).unwrap();
+        std::fs::write(dest.join("a.png"), b"older").unwrap();
+
+        let copied = gallery.copy_selected_to(&dest).unwrap();
+        assert_eq!(copied, vec![dest.join("a-1.png"), dest.join("c.png")]);
+        assert_eq!(std::fs::read_to_string(dest.join("a-1.png")).unwrap(), "a.png");
+        assert!(std::fs::read_to_string(dest.join("a-1.json")).unwrap().contains("a.png"));
+        assert!(std::fs::read_to_string(dest.join("c.json")).unwrap().contains("c.png"));
+        assert_eq!(std::fs::read_to_string(dest.join("a.png")).unwrap(), "older");
+        assert!(!dest.join("b.png").exists());
+
+        // Removed images drop out of the selection
+        gallery.remove_image(&dir.path().join("c.png"));
+        assert!(!gallery.is_in_selected_set(&dir.path().join("c.png")));
+        assert_eq!(gallery.selected_set.len(), 1);
+    }
+
+    #[test]
+    fn test_tag_filter_narrows_navigation() {
+        let mut gallery = GalleryState::default();
+        for name in ["a.png", "b.png", "c.png", "d.png"] {
+            gallery.add_image(PathBuf::from(name));
+        }
+        gallery.set_tags(PathBuf::from("b.png"), vec!["knight".to_string()]);
+        gallery.set_tags(PathBuf::from("d.png"), vec!["Knight".to_string(), "idle".to_string()]);
+
+        // Selection jumps to the first match and navigation skips the rest
+        gallery.set_filter_tag(Some("knight".to_string()));
+        assert_eq!(gallery.visible_indices(), vec![1, 3]);
+        assert_eq!(gallery.current_image(), Some(&PathBuf::from("b.png")));
+        gallery.select_next();
+        assert_eq!(gallery.selected, 3);
+        gallery.select_next();
+        assert_eq!(gallery.selected, 1);
+        gallery.select_previous();
+        assert_eq!(gallery.selected, 3);
+
+        // A tag nobody has hides everything
+        gallery.set_filter_tag(Some("dragon".to_string()));
+        assert!(gallery.visible_indices().is_empty());
+        assert_eq!(gallery.current_image(), None);
+
+        // Clearing the filter shows every image again
+        gallery.set_filter_tag(None);
+        assert_eq!(gallery.visible_indices().len(), 4);
+        assert_eq!(gallery.current_image(), Some(&PathBuf::from("d.png")));
+        gallery.select_next();
+        assert_eq!(gallery.selected, 0);
+    }
+
+    #[test]
+    fn test_favorites_view_hides_others() {
+        let mut gallery = GalleryState::default();
+        for name in ["a.png", "b.png", "c.png"] {
+            gallery.add_image(PathBuf::from(name));
+        }
+        gallery.selected = 1;
+        assert!(gallery.toggle_favorite().unwrap());
+        assert!(gallery.is_favorite(Path::new("b.png")));
+
+        gallery.selected = 2;
+        gallery.toggle_favorites_only();
+        assert_eq!(gallery.visible_indices(), vec![1]);
+        assert_eq!(gallery.current_image(), Some(&PathBuf::from("b.png")));
+
+        // Unstarring the only favorite leaves nothing selectable
+        assert!(!gallery.toggle_favorite().unwrap());
+        assert!(gallery.visible_indices().is_empty());
+        assert_eq!(gallery.current_image(), None);
+
+        gallery.toggle_favorites_only();
+        assert_eq!(gallery.visible_indices().len(), 3);
+        assert_eq!(gallery.current_image(), Some(&PathBuf::from("b.png")));
+    }
+
+    #[test]
+    fn test_favorites_persist() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("favorites.json");
+
+        let mut gallery = GalleryState::default();
+        gallery.load_favorites_from(&path).unwrap();
+        assert!(gallery.favorites.is_empty());
+        gallery.add_image(PathBuf::from("a.png"));
+        gallery.toggle_favorite().unwrap();
+
+        let mut reloaded = GalleryState::default();
+        reloaded.load_favorites_from(&path).unwrap();
+        assert!(reloaded.is_favorite(Path::new("a.png")));
+        assert_eq!(reloaded.favorites.len(), 1);
+    }
 }
@@ -0,0 +1,160 @@
+//! # Gallery Zoom Resource
+//!
+//! Fullscreen zoom and pan over the selected gallery image (`z`). The image
+//! is shown at `scale` screen pixels per image pixel, with the visible window
+//! starting at (`offset_x`, `offset_y`) in image pixels.
+
+use bevy::prelude::*;
+
+/// Smallest zoom factor (zoomed out to a quarter size).
+pub const MIN_ZOOM: f32 = 0.25;
+
+/// Largest zoom factor.
+pub const MAX_ZOOM: f32 = 8.0;
+
+/// Factor applied by each `+`/`-` press.
+const ZOOM_STEP: f32 = 2.0;
+
+/// Distance panned per arrow key press, in screen pixels.
+const PAN_STEP_PX: f32 = 64.0;
+
+/// Zoom/pan state for the gallery's zoom view.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct GalleryZoom {
+    /// Whether the zoom view is showing
+    pub active: bool,
+    /// Screen pixels per image pixel (1.0 = actual size)
+    pub scale: f32,
+    /// Left edge of the visible window, in image pixels
+    pub offset_x: u32,
+    /// Top edge of the visible window, in image pixels
+    pub offset_y: u32,
+}
+
+impl Default for GalleryZoom {
+    fn default() -> Self {
+        Self {
+            active: false,
+            scale: 1.0,
+            offset_x: 0,
+            offset_y: 0,
+        }
+    }
+}
+
+impl GalleryZoom {
+    /// Show the zoom view at 1:1 from the image's top-left corner.
+    pub fn open(&mut self) {
+        *self = Self {
+            active: true,
+            ..Default::default()
+        };
+    }
+
+    /// Hide the zoom view.
+    pub fn close(&mut self) {
+        self.active = false;
+    }
+
+    /// Set the zoom factor, clamped to [`MIN_ZOOM`]..=[`MAX_ZOOM`].
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale.clamp(MIN_ZOOM, MAX_ZOOM);
+    }
+
+    /// Zoom in one step.
+    pub fn zoom_in(&mut self) {
+        self.set_scale(self.scale * ZOOM_STEP);
+    }
+
+    /// Zoom out one step.
+    pub fn zoom_out(&mut self) {
+        self.set_scale(self.scale / ZOOM_STEP);
+    }
+
+    /// Move the visible window by `dx`/`dy` pan steps.
+    ///
+    /// A step covers the same distance on screen at every zoom level. The
+    /// offset isn't bounded by the image here; [`clamp_offset`](Self::clamp_offset)
+    /// does that once the image and viewport sizes are known.
+    pub fn pan(&mut self, dx: i32, dy: i32) {
+        let step = (PAN_STEP_PX / self.scale).max(1.0) as i64;
+        let apply = |offset: u32, delta: i32| {
+            (offset as i64 + delta as i64 * step).clamp(0, u32::MAX as i64) as u32
+        };
+        self.offset_x = apply(self.offset_x, dx);
+        self.offset_y = apply(self.offset_y, dy);
+    }
+
+    /// Size of the visible window in image pixels.
+    ///
+    /// `image` and `viewport` are (width, height) in pixels. The window never
+    /// extends past the image.
+    pub fn window(&self, image: (u32, u32), viewport: (u32, u32)) -> (u32, u32) {
+        let fit = |viewport: u32, image: u32| {
+            ((viewport as f32 / self.scale).ceil() as u32).clamp(1, image.max(1))
+        };
+        (fit(viewport.0, image.0), fit(viewport.1, image.1))
+    }
+
+    /// Keep the visible window inside the image.
+    pub fn clamp_offset(&mut self, image: (u32, u32), viewport: (u32, u32)) {
+        let (width, height) = self.window(image, viewport);
+        self.offset_x = self.offset_x.min(image.0.saturating_sub(width));
+        self.offset_y = self.offset_y.min(image.1.saturating_sub(height));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_clamped_at_image_edges() {
+        let image = (512, 256);
+        let viewport = (128, 128);
+        let mut zoom = GalleryZoom::default();
+        zoom.open();
+
+        // Can't pan left/up past the origin
+        zoom.pan(-3, -3);
+        assert_eq!((zoom.offset_x, zoom.offset_y), (0, 0));
+
+        // Can't pan right/down past the last full window
+        zoom.pan(100, 100);
+        zoom.clamp_offset(image, viewport);
+        assert_eq!((zoom.offset_x, zoom.offset_y), (384, 128));
+
+        // Zooming in shrinks the window, so more of the image is reachable
+        zoom.zoom_in();
+        zoom.pan(100, 100);
+        zoom.clamp_offset(image, viewport);
+        assert_eq!(zoom.window(image, viewport), (64, 64));
+        assert_eq!((zoom.offset_x, zoom.offset_y), (448, 192));
+
+        // A window bigger than the image pins the offset to zero
+        zoom.set_scale(MIN_ZOOM);
+        zoom.clamp_offset(image, viewport);
+        assert_eq!(zoom.window(image, viewport), (512, 256));
+        assert_eq!((zoom.offset_x, zoom.offset_y), (0, 0));
+    }
+
+    #[test]
+    fn test_scale_bounds() {
+        let mut zoom = GalleryZoom::default();
+        for _ in 0..10 {
+            zoom.zoom_in();
+        }
+        assert_eq!(zoom.scale, MAX_ZOOM);
+        for _ in 0..10 {
+            zoom.zoom_out();
+        }
+        assert_eq!(zoom.scale, MIN_ZOOM);
+
+        zoom.set_scale(0.0);
+        assert_eq!(zoom.scale, MIN_ZOOM);
+
+        // Re-opening starts over at 1:1
+        zoom.open();
+        assert_eq!(zoom.scale, 1.0);
+    }
+}
@@ -0,0 +1,117 @@
+//! # Generation Parameters Resource
+//!
+//! Steps and CFG scale for the next job, adjusted inline on the Generation
+//! screen. Values the user hasn't touched follow the configured defaults; an
+//! adjusted value only applies to jobs submitted from this session and is
+//! never written back to `config.toml`.
+
+use bevy::prelude::*;
+
+use super::settings::GenerationSettings;
+
+/// Lowest and highest step count.
+pub const STEPS_RANGE: (u32, u32) = (10, 100);
+
+/// Lowest and highest CFG scale.
+pub const CFG_RANGE: (f32, f32) = (1.0, 20.0);
+
+/// CFG scale change per `+`/`-` press.
+pub const CFG_STEP: f32 = 0.5;
+
+/// A parameter that can be adjusted inline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenParamField {
+    Steps,
+    CfgScale,
+}
+
+/// Per-generation overrides of the generation defaults.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct GenParams {
+    /// Adjusted step count (`None` follows the default)
+    pub steps: Option<u32>,
+    /// Adjusted CFG scale (`None` follows the default)
+    pub cfg_scale: Option<f32>,
+    /// Field being adjusted (`None` while not adjusting)
+    pub selected: Option<GenParamField>,
+}
+
+impl GenParams {
+    /// Step count the next job uses.
+    pub fn steps_or(&self, defaults: &GenerationSettings) -> u32 {
+        self.steps.unwrap_or(defaults.default_steps)
+    }
+
+    /// CFG scale the next job uses.
+    pub fn cfg_scale_or(&self, defaults: &GenerationSettings) -> f32 {
+        self.cfg_scale.unwrap_or(defaults.default_cfg_scale)
+    }
+
+    /// Select the next field down (from none, the first).
+    pub fn select_next(&mut self) {
+        self.selected = Some(match self.selected {
+            None => GenParamField::Steps,
+            Some(_) => GenParamField::CfgScale,
+        });
+    }
+
+    /// Select the next field up; above the first, none.
+    pub fn select_previous(&mut self) {
+        self.selected = match self.selected {
+            Some(GenParamField::CfgScale) => Some(GenParamField::Steps),
+            _ => None,
+        };
+    }
+
+    /// Step the selected field up (`direction` > 0) or down, within its range.
+    ///
+    /// Returns false if no field is selected.
+    pub fn adjust(&mut self, direction: i32, defaults: &GenerationSettings) -> bool {
+        let sign = direction.signum();
+        match self.selected {
+            Some(GenParamField::Steps) => {
+                let steps = self.steps_or(defaults).saturating_add_signed(sign);
+                self.steps = Some(steps.clamp(STEPS_RANGE.0, STEPS_RANGE.1));
+            }
+            Some(GenParamField::CfgScale) => {
+                let cfg = self.cfg_scale_or(defaults) + CFG_STEP * sign as f32;
+                self.cfg_scale = Some(cfg.clamp(CFG_RANGE.0, CFG_RANGE.1));
+            }
+            None => return false,
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bevy_app::resources::SettingsState;
+
+    #[test]
+    fn test_adjust_clamps_to_range() {
+        let mut defaults = SettingsState::default().generation;
+        defaults.default_steps = 99;
+        defaults.default_cfg_scale = 1.5;
+        let mut params = GenParams::default();
+        assert!(!params.adjust(1, &defaults));
+        assert_eq!(params, GenParams::default());
+
+        params.select_next();
+        params.adjust(1, &defaults);
+        params.adjust(1, &defaults);
+        assert_eq!(params.steps, Some(100));
+
+        params.select_next();
+        assert_eq!(params.selected, Some(GenParamField::CfgScale));
+        params.adjust(-1, &defaults);
+        params.adjust(-1, &defaults);
+        assert_eq!(params.cfg_scale, Some(1.0));
+
+        // Adjusting never touches the defaults
+        assert_eq!(defaults.default_steps, 99);
+        params.select_previous();
+        params.select_previous();
+        assert_eq!(params.selected, None);
+    }
+}
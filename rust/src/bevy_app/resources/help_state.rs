@@ -1,6 +1,10 @@
 //! # Help State Resource
 //!
-//! Manages scrolling and display state for the Help screen.
+//! Manages scrolling, search and display state for the Help screen.
+//!
+//! The help content is a list of [`HELP_SECTIONS`], one per screen. `?`
+//! opens help scrolled to the section of the screen it was pressed on, and
+//! `/` filters the listed shortcuts by substring.
 //!
 //! ## Example
 //!
@@ -15,6 +19,168 @@
 
 use bevy::prelude::*;
 
+use super::screen_state::Screen;
+
+/// The shortcuts of one screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HelpSection {
+    /// Screen the shortcuts apply on (`Help` for the global ones)
+    pub screen: Screen,
+    /// Heading shown above the shortcuts
+    pub title: &'static str,
+    /// (keys, description) pairs
+    pub entries: &'static [(&'static str, &'static str)],
+}
+
+/// The help content, in display order.
+pub const HELP_SECTIONS: &[HelpSection] = &[
+    HelpSection {
+        screen: Screen::Help,
+        title: "NAVIGATION",
+        entries: &[
+            ("Tab", "Next screen"),
+            ("Shift+Tab", "Previous screen"),
+            ("1-8", "Go to a screen"),
+            ("q / Ctrl+C", "Quit application"),
+            ("Ctrl+P", "Command palette"),
+            ("Ctrl+X", "Dismiss notifications"),
+            ("?", "Help for the current screen"),
+            ("/", "Search help (on this screen)"),
+        ],
+    },
+    HelpSection {
+        screen: Screen::Generation,
+        title: "GENERATION SCREEN",
+        entries: &[
+            ("Type", "Enter prompt text"),
+            ("Enter", "Submit job for generation"),
+            ("Esc", "Clear prompt"),
+            ("Alt+S", "Switch between random and fixed seed"),
+            ("Alt+R", "Regenerate the last job"),
+            ("Alt+W", "Seed sweep of the last prompt"),
+            ("PgUp/PgDn, Home/End", "Scroll the Logs tab; End follows (debug)"),
+        ],
+    },
+    HelpSection {
+        screen: Screen::Comparison,
+        title: "COMPARISON SCREEN",
+        entries: &[
+            ("↑/↓", "Navigate model list"),
+            ("a / A", "Add model to comparison"),
+            ("d / D", "Remove selected model"),
+            ("Enter", "Generate with selected models"),
+            ("P", "Pin pane model across resets"),
+            ("Ctrl+S / Ctrl+O", "Save / load comparison setup"),
+            ("Enter (reference)", "Generate the other pane with the reference's seed"),
+        ],
+    },
+    HelpSection {
+        screen: Screen::Queue,
+        title: "QUEUE SCREEN",
+        entries: &[
+            ("↑/↓", "Navigate queue"),
+            ("c / C", "Cancel selected job"),
+            ("r / R", "Retry failed job"),
+        ],
+    },
+    HelpSection {
+        screen: Screen::Gallery,
+        title: "GALLERY SCREEN",
+        entries: &[
+            ("←/→", "Navigate gallery"),
+            ("PgUp/PgDn", "Jump 10 images"),
+            ("Home/End", "First/Last image"),
+            ("d / D", "Delete current image"),
+            ("*", "Toggle favorite"),
+            ("/", "Filter by tag"),
+            ("Space", "Pick image for export"),
+            ("e / E", "Export picked images"),
+            ("o", "Reveal in file manager"),
+            ("p", "Copy image path"),
+            ("v / V", "Compare a new generation against this image"),
+        ],
+    },
+    HelpSection {
+        screen: Screen::Models,
+        title: "MODELS SCREEN",
+        entries: &[
+            ("↑/↓", "Navigate models list"),
+            ("a / A", "Activate selected model"),
+            ("d / D", "Download model"),
+            ("r / R", "Remove selected model"),
+            ("i / I", "Toggle model info panel"),
+            ("/", "Filter models"),
+        ],
+    },
+    HelpSection {
+        screen: Screen::Monitor,
+        title: "MONITOR SCREEN",
+        entries: &[
+            ("r / R", "Refresh metrics (future)"),
+            ("p / P", "Pause auto-refresh (future)"),
+        ],
+    },
+    HelpSection {
+        screen: Screen::Settings,
+        title: "SETTINGS SCREEN",
+        entries: &[
+            ("Up / Down", "Select a setting"),
+            ("Enter", "Edit it (paths open a directory browser)"),
+            ("t / T", "Choose a theme"),
+            ("Ctrl+Z / Ctrl+Y", "Undo / redo a change"),
+            ("Ctrl+S / Ctrl+R", "Save to / reload from config.toml"),
+            ("Ctrl+D", "Show changed settings"),
+        ],
+    },
+];
+
+/// A line of help content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelpLine {
+    Blank,
+    Heading(&'static str),
+    /// Keys and what they do
+    Entry(&'static str, &'static str),
+}
+
+/// The help content as lines, keeping only the shortcuts whose keys or
+/// description contain `query` (ignoring case). Sections with no matching
+/// shortcut are left out.
+pub fn help_lines(query: &str) -> Vec<HelpLine> {
+    let query = query.to_lowercase();
+    let matches = |(keys, description): &(&str, &str)| {
+        query.is_empty()
+            || keys.to_lowercase().contains(&query)
+            || description.to_lowercase().contains(&query)
+    };
+
+    let mut lines = Vec::new();
+    for section in HELP_SECTIONS {
+        let entries: Vec<_> = section.entries.iter().filter(|e| matches(e)).collect();
+        if entries.is_empty() {
+            continue;
+        }
+        lines.push(HelpLine::Blank);
+        lines.push(HelpLine::Heading(section.title));
+        lines.push(HelpLine::Blank);
+        lines.extend(entries.iter().map(|(keys, description)| HelpLine::Entry(keys, description)));
+    }
+    lines
+}
+
+/// Line of the unfiltered content where `screen`'s section starts (its
+/// heading).
+pub fn section_start(screen: Screen) -> usize {
+    help_lines("")
+        .iter()
+        .position(|line| {
+            HELP_SECTIONS
+                .iter()
+                .any(|s| s.screen == screen && *line == HelpLine::Heading(s.title))
+        })
+        .unwrap_or(0)
+}
+
 /// Help screen state resource.
 #[derive(Resource, Debug, Clone, PartialEq, Eq)]
 pub struct HelpState {
@@ -24,6 +190,12 @@ pub struct HelpState {
     pub total_lines: usize,
     /// Visible lines in the viewport.
     pub visible_lines: usize,
+    /// Search text filtering the shortcuts (empty shows them all).
+    pub query: String,
+    /// Whether keys are typing into the search.
+    pub searching: bool,
+    /// Screen whose section help was last opened at.
+    pub section: Screen,
 }
 
 impl Default for HelpState {
@@ -32,11 +204,56 @@ impl Default for HelpState {
             scroll_offset: 0,
             total_lines: 0,
             visible_lines: 0,
+            query: String::new(),
+            searching: false,
+            section: Screen::Help,
         }
     }
 }
 
 impl HelpState {
+    /// Show the whole help, scrolled to `screen`'s section.
+    pub fn open_at(&mut self, screen: Screen) {
+        self.query.clear();
+        self.searching = false;
+        self.section = screen;
+        self.scroll_offset = section_start(screen);
+    }
+
+    /// The help content, filtered by the search.
+    pub fn lines(&self) -> Vec<HelpLine> {
+        help_lines(&self.query)
+    }
+
+    /// Start typing into the search.
+    pub fn start_search(&mut self) {
+        self.searching = true;
+    }
+
+    /// Add a character to the search.
+    pub fn push_query(&mut self, c: char) {
+        self.query.push(c);
+        self.scroll_offset = 0;
+    }
+
+    /// Remove the search's last character.
+    pub fn pop_query(&mut self) {
+        self.query.pop();
+        self.scroll_offset = 0;
+    }
+
+    /// Stop typing, keeping the filter.
+    pub fn finish_search(&mut self) {
+        self.searching = false;
+    }
+
+    /// Stop typing and show everything again.
+    pub fn clear_search(&mut self) {
+        self.searching = false;
+        self.query.clear();
+        self.scroll_offset = 0;
+    }
+
     /// Scroll down by the specified number of lines.
     pub fn scroll_down(&mut self, lines: usize) {
         let max_offset = self.total_lines.saturating_sub(self.visible_lines);
@@ -122,6 +339,7 @@ mod tests {
             scroll_offset: 0,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         state.scroll_down(5);
         assert_eq!(state.scroll_offset, 5);
@@ -133,6 +351,7 @@ mod tests {
             scroll_offset: 75,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         state.scroll_down(10);
         assert_eq!(state.scroll_offset, 80); // max is 100-20=80
@@ -144,6 +363,7 @@ mod tests {
             scroll_offset: 10,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         state.scroll_up(5);
         assert_eq!(state.scroll_offset, 5);
@@ -155,6 +375,7 @@ mod tests {
             scroll_offset: 3,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         state.scroll_up(5);
         assert_eq!(state.scroll_offset, 0);
@@ -166,6 +387,7 @@ mod tests {
             scroll_offset: 50,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         state.scroll_to_top();
         assert_eq!(state.scroll_offset, 0);
@@ -177,6 +399,7 @@ mod tests {
             scroll_offset: 0,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         state.scroll_to_bottom();
         assert_eq!(state.scroll_offset, 80);
@@ -188,6 +411,7 @@ mod tests {
             scroll_offset: 0,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         state.page_down();
         assert_eq!(state.scroll_offset, 19); // visible_lines - 1
@@ -199,6 +423,7 @@ mod tests {
             scroll_offset: 20,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         state.page_up();
         assert_eq!(state.scroll_offset, 1);
@@ -210,6 +435,7 @@ mod tests {
             scroll_offset: 90,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         state.update_viewport(30, 100);
         assert_eq!(state.visible_lines, 30);
@@ -222,6 +448,7 @@ mod tests {
             scroll_offset: 0,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         assert!(state.is_at_top());
 
@@ -229,6 +456,7 @@ mod tests {
             scroll_offset: 5,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         assert!(!state.is_at_top());
     }
@@ -239,6 +467,7 @@ mod tests {
             scroll_offset: 80,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         assert!(state.is_at_bottom());
 
@@ -246,6 +475,7 @@ mod tests {
             scroll_offset: 70,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         assert!(!state.is_at_bottom());
     }
@@ -256,6 +486,7 @@ mod tests {
             scroll_offset: 0,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         assert_eq!(state.scroll_percentage(), 0);
 
@@ -263,6 +494,7 @@ mod tests {
             scroll_offset: 40,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         assert_eq!(state.scroll_percentage(), 50); // 40/80 * 100
 
@@ -270,6 +502,7 @@ mod tests {
             scroll_offset: 80,
             total_lines: 100,
             visible_lines: 20,
+            ..Default::default()
         };
         assert_eq!(state.scroll_percentage(), 100);
     }
@@ -280,7 +513,44 @@ mod tests {
             scroll_offset: 0,
             total_lines: 10,
             visible_lines: 20,
+            ..Default::default()
         };
         assert_eq!(state.scroll_percentage(), 100);
     }
+
+    #[test]
+    fn test_search_filters_lines() {
+        let mut state = HelpState::default();
+        let all = state.lines().len();
+
+        state.start_search();
+        for c in "MODEL".chars() {
+            state.push_query(c);
+        }
+        let lines = state.lines();
+        assert!(lines.len() < all);
+        assert!(lines.contains(&HelpLine::Entry("i / I", "Toggle model info panel")));
+        assert!(!lines.contains(&HelpLine::Heading("QUEUE SCREEN")));
+        assert!(lines.iter().all(|line| match line {
+            HelpLine::Entry(keys, description) => {
+                format!("{} {}", keys, description).to_lowercase().contains("model")
+            }
+            _ => true,
+        }));
+
+        state.clear_search();
+        assert_eq!(state.lines().len(), all);
+    }
+
+    #[test]
+    fn test_open_at_scrolls_to_section() {
+        let mut state = HelpState {
+            query: "queue".to_string(),
+            ..Default::default()
+        };
+        state.open_at(Screen::Gallery);
+        assert_eq!(state.section, Screen::Gallery);
+        assert!(state.query.is_empty());
+        assert_eq!(state.lines()[state.scroll_offset], HelpLine::Heading("GALLERY SCREEN"));
+    }
 }
@@ -1,6 +1,10 @@
 //! # Input Buffer Resource
 //!
 //! Manages text input state including the input buffer and cursor position.
+//! Multi-line prompts are stored with embedded `\n` line breaks.
+//!
+//! Submitted prompts are also kept in a history that Up/Down step through
+//! while the prompt is empty; editing a recalled prompt leaves the history.
 //!
 //! ## Example
 //!
@@ -15,15 +19,33 @@
 //! }
 //! ```
 
+use anyhow::Result;
 use bevy::prelude::*;
+use std::path::{Path, PathBuf};
+
+use crate::last_prompt;
 
 /// Input buffer state resource.
 #[derive(Resource, Debug, Clone)]
 pub struct InputBuffer {
     /// Current text input
     pub text: String,
-    /// Cursor position (character index)
+    /// Cursor position (byte offset, always on a character boundary)
     pub cursor: usize,
+    /// Negative prompt (single line)
+    pub negative_buffer: String,
+    /// Cursor position in the negative prompt
+    pub negative_cursor: usize,
+    /// Typing goes to the negative prompt instead of the prompt
+    pub negative_focused: bool,
+    /// Where submitted prompts are saved (`None` disables saving)
+    pub last_prompt_path: Option<PathBuf>,
+    /// Submitted prompts, oldest first
+    pub prompt_history: Vec<String>,
+    /// History entry shown in the prompt (`None` while not browsing)
+    pub history_index: Option<usize>,
+    /// Where the history is saved (`None` disables saving)
+    pub history_path: Option<PathBuf>,
 }
 
 impl Default for InputBuffer {
@@ -31,103 +53,437 @@ impl Default for InputBuffer {
         Self {
             text: String::new(),
             cursor: 0,
+            negative_buffer: String::new(),
+            negative_cursor: 0,
+            negative_focused: false,
+            last_prompt_path: None,
+            prompt_history: Vec::new(),
+            history_index: None,
+            history_path: None,
         }
     }
 }
 
 impl InputBuffer {
+    /// Buffer holding the prompt saved last session (empty if none).
+    pub fn load_last() -> Self {
+        Self::load_last_from(&last_prompt::last_prompt_path())
+    }
+
+    /// Buffer holding the prompt saved at `path`, saving future prompts there.
+    ///
+    /// The prompt history is read from (and saved to) the same directory.
+    pub fn load_last_from(path: &Path) -> Self {
+        let text = last_prompt::load_last_prompt(path).unwrap_or_default();
+        let history_path = path.with_file_name(last_prompt::PROMPT_HISTORY_FILE);
+        Self {
+            cursor: text.len(),
+            text,
+            last_prompt_path: Some(path.to_path_buf()),
+            prompt_history: last_prompt::load_prompt_history(&history_path),
+            history_path: Some(history_path),
+            ..Default::default()
+        }
+    }
+
+    /// Save a submitted prompt so the next session starts with it.
+    pub fn save_last(&self, prompt: &str) -> Result<()> {
+        match &self.last_prompt_path {
+            Some(path) => last_prompt::save_last_prompt(path, prompt),
+            None => Ok(()),
+        }
+    }
+
+    /// Add a submitted prompt to the history and save it.
+    ///
+    /// Blank prompts and repeats of the newest entry aren't added; past
+    /// [`last_prompt::PROMPT_HISTORY_CAP`] the oldest entry is dropped.
+    pub fn push_history(&mut self, prompt: &str) -> Result<()> {
+        self.history_index = None;
+        let prompt = prompt.trim();
+        if prompt.is_empty() || self.prompt_history.last().is_some_and(|p| p == prompt) {
+            return Ok(());
+        }
+        if self.prompt_history.len() == last_prompt::PROMPT_HISTORY_CAP {
+            self.prompt_history.remove(0);
+        }
+        self.prompt_history.push(prompt.to_string());
+        match &self.history_path {
+            Some(path) => last_prompt::save_prompt_history(path, &self.prompt_history),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether Up should recall history: the prompt is empty or already
+    /// showing a history entry.
+    pub fn can_browse_history(&self) -> bool {
+        !self.negative_focused
+            && !self.prompt_history.is_empty()
+            && (self.text.is_empty() || self.history_index.is_some())
+    }
+
+    /// Show the next older history entry (Up); at the oldest, stays put.
+    pub fn history_prev(&mut self) {
+        let index = match self.history_index {
+            Some(index) => index.saturating_sub(1),
+            None if self.prompt_history.is_empty() => return,
+            None => self.prompt_history.len() - 1,
+        };
+        self.show_history(Some(index));
+    }
+
+    /// Show the next newer history entry (Down); past the newest, the prompt
+    /// is emptied and browsing ends.
+    pub fn history_next(&mut self) {
+        let Some(index) = self.history_index else {
+            return;
+        };
+        let next = index + 1;
+        self.show_history((next < self.prompt_history.len()).then_some(next));
+    }
+
+    /// Put history entry `index` (or nothing) in the prompt, cursor at the end.
+    fn show_history(&mut self, index: Option<usize>) {
+        self.text = index
+            .map(|i| self.prompt_history[i].clone())
+            .unwrap_or_default();
+        self.cursor = self.text.len();
+        self.history_index = index;
+    }
+
+    /// Text and cursor of the focused field, for an edit (which leaves the
+    /// history).
+    fn editing(&mut self) -> (&mut String, &mut usize) {
+        self.history_index = None;
+        self.focused()
+    }
+
+    /// Text and cursor of the focused field (prompt or negative prompt).
+    fn focused(&mut self) -> (&mut String, &mut usize) {
+        if self.negative_focused {
+            (&mut self.negative_buffer, &mut self.negative_cursor)
+        } else {
+            (&mut self.text, &mut self.cursor)
+        }
+    }
+
+    /// Switch typing between the prompt and the negative prompt (Ctrl+N).
+    ///
+    /// Each field keeps its own cursor.
+    pub fn toggle_negative_focus(&mut self) {
+        self.negative_focused = !self.negative_focused;
+    }
+
+    /// Negative prompt to submit (`None` when blank).
+    pub fn negative_prompt(&self) -> Option<String> {
+        let negative = self.negative_buffer.trim();
+        (!negative.is_empty()).then(|| negative.to_string())
+    }
+
     /// Insert character at cursor position.
     pub fn insert(&mut self, c: char) {
-        self.text.insert(self.cursor, c);
-        self.cursor += 1;
+        let (text, cursor) = self.editing();
+        text.insert(*cursor, c);
+        *cursor += c.len_utf8();
+    }
+
+    /// Insert text at cursor position (e.g. a paste).
+    pub fn insert_str(&mut self, insert: &str) {
+        let (text, cursor) = self.editing();
+        text.insert_str(*cursor, insert);
+        *cursor += insert.len();
     }
 
     /// Delete character before cursor (backspace).
     pub fn backspace(&mut self) {
-        if self.cursor > 0 {
-            self.text.remove(self.cursor - 1);
-            self.cursor -= 1;
+        let (text, cursor) = self.editing();
+        if let Some(c) = text[..*cursor].chars().next_back() {
+            *cursor -= c.len_utf8();
+            text.remove(*cursor);
         }
     }
 
     /// Delete character at cursor position (delete key).
     pub fn delete(&mut self) {
-        if self.cursor < self.text.len() {
-            self.text.remove(self.cursor);
+        let (text, cursor) = self.editing();
+        if *cursor < text.len() {
+            text.remove(*cursor);
         }
     }
 
     /// Move cursor left.
     pub fn move_left(&mut self) {
-        if self.cursor > 0 {
-            self.cursor -= 1;
+        let (text, cursor) = self.focused();
+        if let Some(c) = text[..*cursor].chars().next_back() {
+            *cursor -= c.len_utf8();
         }
     }
 
     /// Move cursor right.
     pub fn move_right(&mut self) {
-        if self.cursor < self.text.len() {
-            self.cursor += 1;
+        let (text, cursor) = self.focused();
+        if let Some(c) = text[*cursor..].chars().next() {
+            *cursor += c.len_utf8();
         }
     }
 
     /// Move cursor to start of input.
     pub fn move_to_start(&mut self) {
-        self.cursor = 0;
+        *self.focused().1 = 0;
     }
 
     /// Move cursor to end of input.
     pub fn move_to_end(&mut self) {
-        self.cursor = self.text.len();
+        let (text, cursor) = self.focused();
+        *cursor = text.len();
+    }
+
+    /// Move cursor to the start of the word before it (Ctrl+Left).
+    pub fn move_word_left(&mut self) {
+        let (text, cursor) = self.focused();
+        *cursor = word_start_before(text, *cursor);
+    }
+
+    /// Move cursor to the end of the word after it (Ctrl+Right).
+    pub fn move_word_right(&mut self) {
+        let (text, cursor) = self.focused();
+        *cursor = word_end_after(text, *cursor);
     }
 
     /// Delete word before cursor (Ctrl+W).
-    pub fn delete_word(&mut self) {
-        if self.cursor == 0 {
-            return;
-        }
+    pub fn delete_word_back(&mut self) {
+        let (text, cursor) = self.editing();
+        let start = word_start_before(text, *cursor);
+        text.replace_range(start..*cursor, "");
+        *cursor = start;
+    }
 
-        let text_before = &self.text[..self.cursor];
+    /// Clear the text between the start of the cursor's line and the
+    /// cursor (Ctrl+U).
+    pub fn clear_to_start(&mut self) {
+        let (text, cursor) = self.editing();
+        let line_start = text[..*cursor].rfind('\n').map_or(0, |i| i + 1);
+        text.replace_range(line_start..*cursor, "");
+        *cursor = line_start;
+    }
 
-        // Find the start of the word to delete
-        // Skip trailing whitespace first
-        let mut pos = text_before.len();
-        let chars: Vec<char> = text_before.chars().collect();
+    /// Clear the prompt and reset its cursor (the negative prompt is kept).
+    pub fn clear(&mut self) {
+        self.history_index = None;
+        self.text.clear();
+        self.cursor = 0;
+    }
 
-        // Skip trailing whitespace
-        while pos > 0 && chars[pos - 1].is_whitespace() {
-            pos -= 1;
+    /// Insert a line break at cursor position (Ctrl+Enter).
+    ///
+    /// The negative prompt is a single line, so this only affects the prompt.
+    pub fn insert_newline(&mut self) {
+        if !self.negative_focused {
+            self.insert('\n');
         }
+    }
 
-        // Delete word characters
-        while pos > 0 && !chars[pos - 1].is_whitespace() {
-            pos -= 1;
+    /// Check if the buffer spans more than one line.
+    pub fn is_multiline(&self) -> bool {
+        self.text.contains('\n')
+    }
+
+    /// Number of lines in the buffer (an empty buffer is one line).
+    pub fn line_count(&self) -> usize {
+        self.text.split('\n').count()
+    }
+
+    /// Cursor position as zero-based (line, column), the column counted in
+    /// characters.
+    pub fn cursor_line_col(&self) -> (usize, usize) {
+        let before = &self.text[..self.cursor];
+        let line = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (line, before[line_start..].chars().count())
+    }
+
+    /// Move cursor to the previous line, keeping the column where possible.
+    pub fn move_up(&mut self) {
+        if self.negative_focused {
+            return;
+        }
+        let (line, col) = self.cursor_line_col();
+        if line > 0 {
+            self.move_to_line_col(line - 1, col);
         }
+    }
 
-        // Remove from pos to cursor
-        self.text.replace_range(pos..self.cursor, "");
-        self.cursor = pos;
+    /// Move cursor to the next line, keeping the column where possible.
+    pub fn move_down(&mut self) {
+        if self.negative_focused {
+            return;
+        }
+        let (line, col) = self.cursor_line_col();
+        if line + 1 < self.line_count() {
+            self.move_to_line_col(line + 1, col);
+        }
     }
 
-    /// Clear all text before cursor (Ctrl+U).
-    pub fn delete_to_start(&mut self) {
-        if self.cursor > 0 {
-            self.text.replace_range(0..self.cursor, "");
-            self.cursor = 0;
+    /// Place cursor at a column on a line, clamped to the line length.
+    fn move_to_line_col(&mut self, target_line: usize, col: usize) {
+        let mut line_start = 0;
+        for (line, content) in self.text.split('\n').enumerate() {
+            if line == target_line {
+                let offset = content.char_indices().nth(col).map_or(content.len(), |(i, _)| i);
+                self.cursor = line_start + offset;
+                return;
+            }
+            line_start += content.len() + 1;
         }
     }
+}
 
-    /// Clear buffer and reset cursor.
-    pub fn clear(&mut self) {
-        self.text.clear();
-        self.cursor = 0;
+/// Kinds of character a word boundary falls between.
+#[derive(PartialEq, Eq)]
+enum CharClass {
+    Space,
+    Word,
+    Punctuation,
+}
+
+fn char_class(c: char) -> CharClass {
+    if c.is_whitespace() {
+        CharClass::Space
+    } else if c.is_alphanumeric() || c == '_' {
+        CharClass::Word
+    } else {
+        CharClass::Punctuation
+    }
+}
+
+/// Start of the word before `cursor`: whitespace is skipped, then a run of
+/// word characters or a run of punctuation.
+fn word_start_before(text: &str, cursor: usize) -> usize {
+    let mut chars = text[..cursor].char_indices().rev().peekable();
+    while chars.next_if(|(_, c)| char_class(*c) == CharClass::Space).is_some() {}
+    let Some(&(_, first)) = chars.peek() else {
+        return 0;
+    };
+    let class = char_class(first);
+    let mut start = cursor;
+    while let Some((i, _)) = chars.next_if(|(_, c)| char_class(*c) == class) {
+        start = i;
     }
+    start
+}
+
+/// End of the word after `cursor`, the mirror of [`word_start_before`].
+fn word_end_after(text: &str, cursor: usize) -> usize {
+    let mut chars = text[cursor..].char_indices().peekable();
+    while chars.next_if(|(_, c)| char_class(*c) == CharClass::Space).is_some() {}
+    let Some(&(_, first)) = chars.peek() else {
+        return text.len();
+    };
+    let class = char_class(first);
+    let mut end = cursor;
+    while let Some((i, c)) = chars.next_if(|(_, c)| char_class(*c) == class) {
+        end = cursor + i + c.len_utf8();
+    }
+    end
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_negative_focus_keeps_separate_cursors() {
+        let mut buffer = InputBuffer::default();
+        for c in "knight".chars() {
+            buffer.insert(c);
+        }
+        buffer.move_left();
+
+        buffer.toggle_negative_focus();
+        for c in "blurry".chars() {
+            buffer.insert(c);
+        }
+        buffer.move_to_start();
+        buffer.insert('!');
+        assert_eq!(buffer.negative_buffer, "!blurry");
+        assert_eq!(buffer.negative_cursor, 1);
+
+        // Back in the prompt, typing resumes where the prompt cursor was
+        buffer.toggle_negative_focus();
+        assert_eq!(buffer.cursor, 5);
+        buffer.insert('s');
+        assert_eq!(buffer.text, "knighst");
+        assert_eq!(buffer.negative_prompt().as_deref(), Some("!blurry"));
+
+        buffer.clear();
+        assert_eq!(buffer.negative_buffer, "!blurry");
+    }
+
+    #[test]
+    fn test_submitted_prompt_is_restored() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("last_prompt.txt");
+
+        let buffer = InputBuffer::load_last_from(&path);
+        assert_eq!(buffer.text, "");
+        buffer.save_last("pixel art knight").unwrap();
+
+        let restored = InputBuffer::load_last_from(&path);
+        assert_eq!(restored.text, "pixel art knight");
+        assert_eq!(restored.cursor, restored.text.len());
+    }
+
+    #[test]
+    fn test_history_cycles_and_stops_at_oldest() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("last_prompt.txt");
+        let mut buffer = InputBuffer::load_last_from(&path);
+        for prompt in ["knight", "wizard", "wizard", "dragon"] {
+            buffer.push_history(prompt).unwrap();
+        }
+        assert!(buffer.can_browse_history());
+
+        buffer.history_prev();
+        assert_eq!(buffer.text, "dragon");
+        assert_eq!(buffer.cursor, buffer.text.len());
+        buffer.history_prev();
+        buffer.history_prev();
+        assert_eq!(buffer.text, "knight");
+        // Up at the oldest entry stays put
+        buffer.history_prev();
+        assert_eq!(buffer.text, "knight");
+
+        buffer.history_next();
+        assert_eq!(buffer.text, "wizard");
+        buffer.history_next();
+        buffer.history_next();
+        assert_eq!(buffer.text, "");
+        assert_eq!(buffer.history_index, None);
+
+        // The history is saved, without the repeated entry
+        let restored = InputBuffer::load_last_from(&path);
+        assert_eq!(restored.prompt_history, vec!["knight", "wizard", "dragon"]);
+    }
+
+    #[test]
+    fn test_editing_leaves_history() {
+        let mut buffer = InputBuffer::default();
+        for n in 0..=last_prompt::PROMPT_HISTORY_CAP {
+            buffer.push_history(&format!("prompt {}", n)).unwrap();
+        }
+        assert_eq!(buffer.prompt_history.len(), last_prompt::PROMPT_HISTORY_CAP);
+        assert_eq!(buffer.prompt_history[0], "prompt 1");
+
+        buffer.history_prev();
+        buffer.insert('!');
+        assert_eq!(buffer.history_index, None);
+        assert_eq!(buffer.text, "prompt 100!");
+        // A non-empty, edited prompt keeps Up for moving between lines
+        assert!(!buffer.can_browse_history());
+        buffer.history_next();
+        assert_eq!(buffer.text, "prompt 100!");
+    }
+
     #[test]
     fn test_default_buffer() {
         let buffer = InputBuffer::default();
@@ -224,23 +580,73 @@ mod tests {
             buffer.insert(c);
         }
 
-        buffer.delete_word(); // Delete "test"
+        buffer.delete_word_back(); // Delete "test"
         assert_eq!(buffer.text, "hello world ");
 
-        buffer.delete_word(); // Delete "world "
+        buffer.delete_word_back(); // Delete "world "
         assert_eq!(buffer.text, "hello ");
 
-        buffer.delete_word(); // Delete "hello "
+        buffer.delete_word_back(); // Delete "hello "
         assert_eq!(buffer.text, "");
     }
 
+    #[test]
+    fn test_word_movement() {
+        let mut buffer = InputBuffer::default();
+        buffer.insert_str("pixel   knight, café-style");
+        let len = buffer.text.len();
+
+        // Word and punctuation runs are separate; runs of spaces are skipped
+        let mut stops = Vec::new();
+        while buffer.cursor > 0 {
+            buffer.move_word_left();
+            stops.push(buffer.cursor);
+        }
+        assert_eq!(stops, vec![len - 5, len - 6, 16, 14, 8, 0]);
+        buffer.move_word_left(); // Already at the start
+        assert_eq!(buffer.cursor, 0);
+
+        let mut stops = Vec::new();
+        while buffer.cursor < len {
+            buffer.move_word_right();
+            stops.push(buffer.cursor);
+        }
+        assert_eq!(stops, vec![5, 14, 15, len - 6, len - 5, len]);
+        buffer.move_word_right(); // Already at the end
+        assert_eq!(buffer.cursor, len);
+
+        // Character steps cross multi-byte characters whole
+        buffer.move_word_left();
+        buffer.move_word_left();
+        buffer.move_left();
+        assert_eq!(buffer.cursor, len - 8);
+        buffer.delete();
+        assert_eq!(buffer.text, "pixel   knight, caf-style");
+    }
+
+    #[test]
+    fn test_delete_word_back_stops_at_punctuation() {
+        let mut buffer = InputBuffer::default();
+        buffer.insert_str("a knight,   ");
+        buffer.delete_word_back();
+        assert_eq!(buffer.text, "a knight");
+        buffer.delete_word_back();
+        assert_eq!(buffer.text, "a ");
+
+        buffer.insert_str("knight");
+        buffer.move_word_left();
+        buffer.clear_to_start();
+        assert_eq!(buffer.text, "knight");
+        assert_eq!(buffer.cursor, 0);
+    }
+
     #[test]
     fn test_delete_word_at_start() {
         let mut buffer = InputBuffer::default();
         buffer.insert('h');
         buffer.insert('i');
         buffer.move_to_start();
-        buffer.delete_word(); // Should do nothing
+        buffer.delete_word_back(); // Should do nothing
         assert_eq!(buffer.text, "hi");
         assert_eq!(buffer.cursor, 0);
     }
@@ -254,18 +660,32 @@ mod tests {
 
         buffer.move_left();
         buffer.move_left();
-        buffer.delete_to_start(); // Delete "hello wor"
+        buffer.clear_to_start(); // Delete "hello wor"
         assert_eq!(buffer.text, "ld");
         assert_eq!(buffer.cursor, 0);
     }
 
+    #[test]
+    fn test_delete_to_start_of_line() {
+        let mut buffer = InputBuffer::default();
+        buffer.insert_str("pixel knight\nblurry, dark");
+        buffer.move_word_left();
+        buffer.clear_to_start();
+        assert_eq!(buffer.text, "pixel knight\ndark");
+        assert_eq!(buffer.cursor_line_col(), (1, 0));
+
+        // At the start of a line there's nothing before it to clear
+        buffer.clear_to_start();
+        assert_eq!(buffer.text, "pixel knight\ndark");
+    }
+
     #[test]
     fn test_delete_to_start_at_beginning() {
         let mut buffer = InputBuffer::default();
         buffer.insert('h');
         buffer.insert('i');
         buffer.move_to_start();
-        buffer.delete_to_start(); // Should do nothing
+        buffer.clear_to_start(); // Should do nothing
         assert_eq!(buffer.text, "hi");
         assert_eq!(buffer.cursor, 0);
     }
@@ -281,4 +701,81 @@ mod tests {
         assert_eq!(buffer.text, "");
         assert_eq!(buffer.cursor, 0);
     }
+
+    #[test]
+    fn test_insert_newline() {
+        let mut buffer = InputBuffer::default();
+        for c in "pixel knight".chars() {
+            buffer.insert(c);
+        }
+        buffer.insert_newline();
+        for c in "blurry".chars() {
+            buffer.insert(c);
+        }
+
+        assert_eq!(buffer.text, "pixel knight\nblurry");
+        assert!(buffer.is_multiline());
+        assert_eq!(buffer.line_count(), 2);
+        assert_eq!(buffer.cursor_line_col(), (1, 6));
+    }
+
+    #[test]
+    fn test_single_line_buffer() {
+        let buffer = InputBuffer::default();
+        assert!(!buffer.is_multiline());
+        assert_eq!(buffer.line_count(), 1);
+        assert_eq!(buffer.cursor_line_col(), (0, 0));
+    }
+
+    #[test]
+    fn test_cursor_movement_across_lines() {
+        let mut buffer = InputBuffer::default();
+        for c in "long first line\nab\nthird".chars() {
+            buffer.insert(c);
+        }
+        assert_eq!(buffer.cursor_line_col(), (2, 5));
+
+        // Column clamps to the shorter middle line
+        buffer.move_up();
+        assert_eq!(buffer.cursor_line_col(), (1, 2));
+
+        buffer.move_up();
+        assert_eq!(buffer.cursor_line_col(), (0, 2));
+
+        // Already on the first line
+        buffer.move_up();
+        assert_eq!(buffer.cursor_line_col(), (0, 2));
+
+        buffer.move_down();
+        buffer.move_down();
+        assert_eq!(buffer.cursor_line_col(), (2, 2));
+
+        // Already on the last line
+        buffer.move_down();
+        assert_eq!(buffer.cursor_line_col(), (2, 2));
+
+        // Left from the start of a line wraps to the end of the previous one
+        buffer.move_left();
+        buffer.move_left();
+        buffer.move_left();
+        assert_eq!(buffer.cursor_line_col(), (1, 2));
+    }
+
+    #[test]
+    fn test_cursor_movement_across_multibyte_lines() {
+        let mut buffer = InputBuffer::default();
+        for c in "abc\néx".chars() {
+            buffer.insert(c);
+        }
+        assert_eq!(buffer.cursor_line_col(), (1, 2));
+
+        buffer.move_up();
+        assert_eq!(buffer.cursor_line_col(), (0, 2));
+        buffer.move_left();
+        buffer.move_down();
+        // Lands after 'é', not inside it
+        assert_eq!(buffer.cursor_line_col(), (1, 1));
+        buffer.insert('!');
+        assert_eq!(buffer.text, "abc\né!x");
+    }
 }
@@ -1,6 +1,9 @@
 //! # Job Tracker Resource
 //!
-//! Tracks aggregate statistics about image generation jobs.
+//! Tracks aggregate statistics about image generation jobs, plus the send
+//! order of jobs still waiting in the local queue. Completed jobs are also
+//! appended to a JSON-lines log (`jobs.jsonl` in the data directory) for
+//! later analysis.
 //!
 //! ## Example
 //!
@@ -14,7 +17,87 @@
 //! }
 //! ```
 
+use anyhow::{Context, Result};
 use bevy::prelude::*;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::bevy_app::components::{Job, JobStatus, Priority};
+use crate::shutdown::JobHistoryEntry;
+
+/// Completed-jobs log, in the data directory.
+pub const JOBS_LOG_FILE: &str = "jobs.jsonl";
+
+/// One line of the completed-jobs log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobLogEntry {
+    pub job_id: String,
+    pub prompt: String,
+    pub seed: Option<u64>,
+    pub model: Option<String>,
+    pub duration_s: Option<f32>,
+    /// When the entry was made (the job's completion)
+    pub timestamp: DateTime<Utc>,
+}
+
+impl JobLogEntry {
+    /// Log entry for `job`, timestamped now.
+    pub fn from_job(job: &Job) -> Self {
+        let duration_s = match job.status {
+            JobStatus::Complete { duration_s, .. } => Some(duration_s),
+            _ => None,
+        };
+        Self {
+            job_id: job.id.clone(),
+            prompt: job.prompt.clone(),
+            seed: job.seed,
+            model: job.metadata.as_ref().and_then(|m| m.model.clone()),
+            duration_s,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// Read every entry of a completed-jobs log.
+pub fn read_jobs_jsonl(path: &Path) -> Result<Vec<JobLogEntry>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read jobs log: {:?}", path))?;
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("Invalid entry on line {} of {:?}", i + 1, path))
+        })
+        .collect()
+}
+
+fn append_entry(path: &Path, entry: &JobLogEntry) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {:?}", parent))?;
+    }
+    let mut line = serde_json::to_string(entry).context("Failed to serialize job log entry")?;
+    line.push('\n');
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+        .with_context(|| format!("Failed to append to jobs log: {:?}", path))
+}
+
+/// Finished jobs removed from the queue this session.
+///
+/// Kept so they are still persisted on shutdown after their entities are gone.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct JobHistory {
+    pub entries: Vec<JobHistoryEntry>,
+}
 
 /// Job tracking resource for aggregate statistics.
 #[derive(Resource, Debug, Clone, Default)]
@@ -25,9 +108,54 @@ pub struct JobTracker {
     pub total_completed: usize,
     /// Total jobs that failed
     pub total_failed: usize,
+    /// Locally queued job IDs in send order: grouped by priority (highest
+    /// first), user-ordered within each priority
+    pub pending: Vec<String>,
+    /// Priority of each pending job
+    priorities: HashMap<String, Priority>,
+    /// Where completed jobs are logged (`None` to not log them)
+    pub jobs_log: Option<PathBuf>,
+    /// Generation times reported for completed jobs (seconds), summed
+    total_duration_s: f32,
+    /// Number of completed jobs in `total_duration_s`
+    timed_jobs: usize,
 }
 
 impl JobTracker {
+    /// Tracker logging completed jobs to `path`.
+    pub fn with_jobs_log(path: impl Into<PathBuf>) -> Self {
+        Self {
+            jobs_log: Some(path.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Default completed-jobs log path.
+    pub fn default_jobs_log() -> PathBuf {
+        crate::shutdown::data_dir().join(JOBS_LOG_FILE)
+    }
+
+    /// Append `job` to the completed-jobs log at `path` as one JSON line.
+    pub fn append_jsonl(path: &Path, job: &Job) -> Result<()> {
+        append_entry(path, &JobLogEntry::from_job(job))
+    }
+
+    /// Log a completed job, if logging is on.
+    ///
+    /// The file is written on a background thread so a slow disk never holds
+    /// up a frame; a failed write is only logged.
+    pub fn log_completed(&self, job: &Job) {
+        let Some(path) = self.jobs_log.clone() else {
+            return;
+        };
+        let entry = JobLogEntry::from_job(job);
+        std::thread::spawn(move || {
+            if let Err(e) = append_entry(&path, &entry) {
+                warn!("Failed to log completed job {}: {:#}", entry.job_id, e);
+            }
+        });
+    }
+
     /// Record a new job submission.
     pub fn submit_job(&mut self) {
         self.total_submitted += 1;
@@ -38,6 +166,25 @@ impl JobTracker {
         self.total_completed += 1;
     }
 
+    /// Record how long a completed job took to generate.
+    pub fn record_duration(&mut self, duration_s: f32) {
+        if duration_s.is_finite() && duration_s >= 0.0 {
+            self.total_duration_s += duration_s;
+            self.timed_jobs += 1;
+        }
+    }
+
+    /// Average generation time of completed jobs, if any have finished.
+    pub fn avg_duration_s(&self) -> Option<f32> {
+        (self.timed_jobs > 0).then(|| self.total_duration_s / self.timed_jobs as f32)
+    }
+
+    /// Estimated wait (seconds) for a job with `position` jobs ahead of it,
+    /// assuming each takes the average generation time.
+    pub fn queue_eta(&self, position: usize) -> Option<f32> {
+        self.avg_duration_s().map(|avg| avg * position as f32)
+    }
+
     /// Record a job failure.
     pub fn fail_job(&mut self) {
         self.total_failed += 1;
@@ -50,6 +197,82 @@ impl JobTracker {
             .saturating_sub(self.total_failed)
     }
 
+    /// Add a job to the local queue, after pending jobs of the same or
+    /// higher priority.
+    pub fn enqueue(&mut self, job_id: String, priority: Priority) {
+        self.dequeue(&job_id);
+        let index = self
+            .pending
+            .iter()
+            .position(|id| self.priority_of(id) < priority)
+            .unwrap_or(self.pending.len());
+        self.priorities.insert(job_id.clone(), priority);
+        self.pending.insert(index, job_id);
+    }
+
+    /// Remove a job from the local queue (sent to the backend or cancelled).
+    pub fn dequeue(&mut self, job_id: &str) {
+        self.pending.retain(|id| id != job_id);
+        self.priorities.remove(job_id);
+    }
+
+    /// Position of a job in the send order, if it's still queued locally.
+    pub fn pending_position(&self, job_id: &str) -> Option<usize> {
+        self.pending.iter().position(|id| id == job_id)
+    }
+
+    /// Move a pending job one place earlier in the send order.
+    ///
+    /// Returns false if the job isn't pending or is already first among jobs
+    /// of its priority.
+    pub fn move_up(&mut self, job_id: &str) -> bool {
+        match self.pending_position(job_id) {
+            Some(i) if i > 0 && self.same_priority(i - 1, i) => {
+                self.pending.swap(i - 1, i);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Move a pending job one place later in the send order.
+    ///
+    /// Returns false if the job isn't pending or is already last among jobs
+    /// of its priority.
+    pub fn move_down(&mut self, job_id: &str) -> bool {
+        match self.pending_position(job_id) {
+            Some(i) if i + 1 < self.pending.len() && self.same_priority(i, i + 1) => {
+                self.pending.swap(i, i + 1);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Change a pending job's priority, moving it to the end of its new
+    /// priority group. Returns false if the job isn't pending.
+    pub fn set_priority(&mut self, job_id: &str, priority: Priority) -> bool {
+        if self.pending_position(job_id).is_none() {
+            return false;
+        }
+        self.enqueue(job_id.to_string(), priority);
+        true
+    }
+
+    /// Sort jobs for the Queue screen: jobs already sent (or finished) by
+    /// submission time, then locally queued jobs in send order.
+    pub fn sort_for_display(&self, jobs: &mut [&Job]) {
+        jobs.sort_by_key(|job| (self.pending_position(&job.id), job.submitted_at));
+    }
+
+    fn priority_of(&self, job_id: &str) -> Priority {
+        self.priorities.get(job_id).copied().unwrap_or_default()
+    }
+
+    fn same_priority(&self, a: usize, b: usize) -> bool {
+        self.priority_of(&self.pending[a]) == self.priority_of(&self.pending[b])
+    }
+
     /// Get success rate as percentage (0.0 - 100.0).
     pub fn success_rate(&self) -> f32 {
         let finished = self.total_completed + self.total_failed;
@@ -125,6 +348,85 @@ mod tests {
         assert_eq!(tracker.success_rate(), 75.0); // 3 out of 4
     }
 
+    #[test]
+    fn test_avg_duration_and_queue_eta() {
+        let mut tracker = JobTracker::default();
+        assert_eq!(tracker.avg_duration_s(), None);
+        assert_eq!(tracker.queue_eta(3), None);
+
+        tracker.record_duration(4.0);
+        tracker.record_duration(8.0);
+        tracker.record_duration(f32::NAN);
+        assert_eq!(tracker.avg_duration_s(), Some(6.0));
+
+        assert_eq!(tracker.queue_eta(0), Some(0.0));
+        assert_eq!(tracker.queue_eta(1), Some(6.0));
+        assert_eq!(tracker.queue_eta(3), Some(18.0));
+    }
+
+    #[test]
+    fn test_completed_jobs_appended_as_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join(JOBS_LOG_FILE);
+
+        for (n, seed) in [(1, 11), (2, 22), (3, 33)] {
+            let mut job = Job::new(format!("job-{}", n), format!("sprite {}", n));
+            job.seed = Some(seed);
+            job.status = JobStatus::Complete {
+                image_path: PathBuf::from(format!("outputs/job-{}.png", n)),
+                duration_s: 2.5,
+            };
+            JobTracker::append_jsonl(&path, &job).unwrap();
+        }
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 3);
+        let entries = read_jobs_jsonl(&path).unwrap();
+        let ids: Vec<&str> = entries.iter().map(|e| e.job_id.as_str()).collect();
+        assert_eq!(ids, vec!["job-1", "job-2", "job-3"]);
+        assert_eq!(entries[1].prompt, "sprite 2");
+        assert_eq!(entries[1].seed, Some(22));
+        assert_eq!(entries[2].duration_s, Some(2.5));
+    }
+
+    #[test]
+    fn test_reorder_stops_at_queue_and_priority_bounds() {
+        let mut tracker = JobTracker::default();
+        tracker.enqueue("a".to_string(), Priority::Normal);
+        tracker.enqueue("b".to_string(), Priority::Normal);
+        tracker.enqueue("c".to_string(), Priority::Low);
+
+        // Top job can't move up, bottom job can't move down
+        assert!(!tracker.move_up("a"));
+        assert!(!tracker.move_down("c"));
+        assert!(!tracker.move_up("missing"));
+
+        assert!(tracker.move_up("b"));
+        assert_eq!(tracker.pending, vec!["b", "a", "c"]);
+
+        // A low-priority job can't jump ahead of normal ones
+        assert!(!tracker.move_up("c"));
+        assert!(!tracker.move_down("a"));
+        assert_eq!(tracker.pending, vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_priority_sorts_pending_jobs() {
+        let mut tracker = JobTracker::default();
+        tracker.enqueue("low".to_string(), Priority::Low);
+        tracker.enqueue("normal".to_string(), Priority::Normal);
+        tracker.enqueue("high".to_string(), Priority::High);
+        tracker.enqueue("normal-2".to_string(), Priority::Normal);
+        assert_eq!(tracker.pending, vec!["high", "normal", "normal-2", "low"]);
+
+        // Raising a job puts it after the existing high-priority ones
+        assert!(tracker.set_priority("low", Priority::High));
+        assert_eq!(tracker.pending, vec!["high", "low", "normal", "normal-2"]);
+
+        tracker.dequeue("high");
+        assert!(!tracker.set_priority("high", Priority::Low));
+        assert_eq!(tracker.pending, vec!["low", "normal", "normal-2"]);
+    }
+
     #[test]
     fn test_success_rate_no_jobs() {
         let tracker = JobTracker::default();
@@ -6,25 +6,56 @@
 //! separation of concerns.
 
 pub mod app_state;
+pub mod command_palette;
+pub mod confirm_modal;
+pub mod dir_browser;
 pub mod comparison_state;
 pub mod gallery_state;
+pub mod gallery_zoom;
+pub mod gen_params;
 pub mod help_state;
 pub mod input_state;
 pub mod job_state;
 pub mod models;
+pub mod monitor_state;
+pub mod notifications;
+pub mod prompt_presets;
 pub mod queue_state;
 pub mod screen_state;
+pub mod seed_sweep;
 pub mod settings;
+pub mod strings;
 pub mod theme;
 
-pub use app_state::AppState;
+pub use app_state::{random_seed, AppState, LogSource, SeedMode, LOG_PAGE_LINES};
+pub use command_palette::{
+    fuzzy_score, palette_actions, CommandPalette, PaletteAction, PaletteCommand,
+};
 pub use comparison_state::ComparisonState;
-pub use gallery_state::GalleryState;
-pub use help_state::HelpState;
+pub use confirm_modal::{ConfirmAction, ConfirmModal};
+pub use dir_browser::DirBrowser;
+pub use gallery_state::{
+    CropRect, CropSelection, GalleryState, MetadataForm, TagEntry, TagEntryMode, TrashedImage,
+    AUTO_SELECT_IDLE, METADATA_FIELDS, THUMBNAIL_WINDOW, TRASH_DIR,
+};
+pub use gallery_zoom::GalleryZoom;
+pub use gen_params::{GenParamField, GenParams, CFG_RANGE, CFG_STEP, STEPS_RANGE};
+pub use help_state::{help_lines, section_start, HelpLine, HelpSection, HelpState, HELP_SECTIONS};
 pub use input_state::InputBuffer;
-pub use job_state::JobTracker;
+pub use job_state::{read_jobs_jsonl, JobHistory, JobLogEntry, JobTracker, JOBS_LOG_FILE};
+pub use crate::keybindings::{KeyAction, KeySpec, Keybindings};
 pub use models::ModelsState;
+pub use monitor_state::{
+    MonitorState, MONITOR_HISTORY_LEN, MONITOR_POLL_INTERVAL, MONITOR_STALE_AFTER,
+};
+pub use notifications::{
+    Notification, NotificationLevel, Notifications, NOTIFICATIONS_SHOWN, NOTIFICATION_CAPACITY,
+    NOTIFICATION_TTL,
+};
+pub use prompt_presets::{expand, placeholder_spans, PromptPreset, PromptPresets};
 pub use queue_state::QueueState;
 pub use screen_state::{CurrentScreen, Screen};
-pub use settings::SettingsState;
-pub use theme::AppTheme;
+pub use seed_sweep::{SeedSweep, SweepField, SWEEP_COUNT_RANGE};
+pub use settings::{SettingChange, SettingsState};
+pub use strings::Strings;
+pub use theme::{AppTheme, ThemeFile, ThemePicker, DEFAULT_THEME_NAME};
@@ -2,6 +2,8 @@
 //!
 //! Manages the state of available AI models (SDXL base models, LoRAs, VAEs)
 //! and handles model activation, download tracking, and metadata display.
+//! The active base model and LoRAs are saved to `active_models.json` in the
+//! data directory and restored at startup.
 //!
 //! ## Example
 //!
@@ -10,13 +12,22 @@
 //! use dgx_pixels_tui::bevy_app::resources::ModelsState;
 //!
 //! fn my_system(models: Res<ModelsState>) {
-//!     println!("Active model: {:?}", models.active_model);
+//!     println!("Active base model: {:?}", models.active_base);
+//!     println!("Active LoRAs: {:?}", models.active_loras);
 //!     println!("Available models: {}", models.models.len());
 //! }
 //! ```
 
+use anyhow::{Context, Result};
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Strength a LoRA starts at when activated.
+pub const DEFAULT_LORA_STRENGTH: f32 = 1.0;
+
+/// Allowed LoRA strength range.
+pub const LORA_STRENGTH_RANGE: std::ops::RangeInclusive<f32> = 0.0..=2.0;
 
 /// Models state resource tracking available AI models.
 #[derive(Resource, Debug, Clone)]
@@ -25,12 +36,39 @@ pub struct ModelsState {
     pub models: Vec<ModelInfo>,
     /// Currently selected index in the table.
     pub selected_index: usize,
-    /// Currently active model name (used for generation).
-    pub active_model: Option<String>,
+    /// Active base model name (only one at a time).
+    pub active_base: Option<String>,
+    /// Active LoRAs and their strengths, in activation order.
+    pub active_loras: Vec<(String, f32)>,
     /// Whether to show detailed metadata panel.
     pub show_metadata: bool,
     /// Scroll offset for model list.
     pub scroll_offset: usize,
+    /// Only models whose name or source contains this are listed (case-insensitive).
+    pub filter: String,
+    /// Whether keystrokes are going into `filter` (entered with `/`).
+    pub filter_editing: bool,
+    /// Model types hidden from the list.
+    pub hidden_types: Vec<ModelType>,
+    /// Where the active models are saved (not saved if unset).
+    pub active_path: Option<PathBuf>,
+}
+
+/// The active models, as saved between sessions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActiveModels {
+    /// Active base model name.
+    pub base: Option<String>,
+    /// Active LoRAs, in activation order.
+    pub loras: Vec<ActiveLora>,
+}
+
+/// An active LoRA and its strength.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActiveLora {
+    pub name: String,
+    pub strength: f32,
 }
 
 impl Default for ModelsState {
@@ -38,9 +76,14 @@ impl Default for ModelsState {
         Self {
             models: Self::default_models(),
             selected_index: 0,
-            active_model: Some("SDXL Base 1.0".to_string()),
+            active_base: Some("SDXL Base 1.0".to_string()),
+            active_loras: Vec::new(),
             show_metadata: false,
             scroll_offset: 0,
+            filter: String::new(),
+            filter_editing: false,
+            hidden_types: Vec::new(),
+            active_path: None,
         }
     }
 }
@@ -137,67 +180,302 @@ impl ModelsState {
         ]
     }
 
+    /// Indices of the models shown with the current filter and hidden types.
+    pub fn filtered_indices(&self) -> Vec<usize> {
+        let filter = self.filter.to_lowercase();
+        self.models
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| !self.hidden_types.contains(&m.model_type))
+            .filter(|(_, m)| {
+                m.name.to_lowercase().contains(&filter)
+                    || m.metadata.source.to_lowercase().contains(&filter)
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
     /// Navigate to next model in the list.
     pub fn next(&mut self) {
-        if !self.models.is_empty() {
-            self.selected_index = (self.selected_index + 1) % self.models.len();
+        let shown = self.filtered_indices();
+        if !shown.is_empty() {
+            self.selected_index = match shown.iter().position(|&i| i == self.selected_index) {
+                Some(pos) => shown[(pos + 1) % shown.len()],
+                None => shown[0],
+            };
             self.update_scroll();
         }
     }
 
     /// Navigate to previous model in the list.
     pub fn previous(&mut self) {
-        if !self.models.is_empty() {
-            self.selected_index = if self.selected_index == 0 {
-                self.models.len() - 1
-            } else {
-                self.selected_index - 1
+        let shown = self.filtered_indices();
+        if !shown.is_empty() {
+            self.selected_index = match shown.iter().position(|&i| i == self.selected_index) {
+                Some(0) | None => shown[shown.len() - 1],
+                Some(pos) => shown[pos - 1],
             };
             self.update_scroll();
         }
     }
 
-    /// Toggle model activation (for base models and LoRAs).
+    /// Select the first listed model.
+    pub fn select_first(&mut self) {
+        if let Some(&first) = self.filtered_indices().first() {
+            self.selected_index = first;
+        }
+    }
+
+    /// Select the last listed model.
+    pub fn select_last(&mut self) {
+        if let Some(&last) = self.filtered_indices().last() {
+            self.selected_index = last;
+        }
+    }
+
+    /// Add a character to the filter.
+    pub fn push_filter_char(&mut self, c: char) {
+        self.filter.push(c);
+        self.keep_selection_listed();
+    }
+
+    /// Remove the last character of the filter.
+    pub fn pop_filter_char(&mut self) {
+        self.filter.pop();
+        self.keep_selection_listed();
+    }
+
+    /// Clear the filter and stop editing it.
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+        self.filter_editing = false;
+        self.keep_selection_listed();
+    }
+
+    /// Show or hide models of one type.
+    pub fn toggle_type_visibility(&mut self, model_type: ModelType) {
+        if let Some(pos) = self.hidden_types.iter().position(|t| *t == model_type) {
+            self.hidden_types.remove(pos);
+        } else {
+            self.hidden_types.push(model_type);
+        }
+        self.keep_selection_listed();
+    }
+
+    /// Move the selection to the first listed model if the selected one is filtered out.
+    fn keep_selection_listed(&mut self) {
+        if !self.selection_listed() {
+            self.select_first();
+        }
+    }
+
+    /// Toggle activation of the selected model.
+    ///
+    /// Base models are single-select: activating one replaces the current
+    /// base. LoRAs stack, each at its own strength. Only downloaded models
+    /// can be activated; VAEs aren't selectable here.
     pub fn toggle_active(&mut self) {
-        if let Some(model) = self.models.get(self.selected_index) {
-            match model.status {
-                ModelStatus::Downloaded => {
-                    // Toggle activation
-                    if self.active_model.as_ref() == Some(&model.name) {
-                        self.active_model = None;
-                    } else {
-                        // For base models, only one can be active
-                        // For LoRAs, multiple can be active (TODO: support multiple)
-                        self.active_model = Some(model.name.clone());
-                    }
+        if !self.selection_listed() {
+            return;
+        }
+        let Some(model) = self.models.get(self.selected_index) else {
+            return;
+        };
+        if model.status != ModelStatus::Downloaded {
+            return;
+        }
+
+        match model.model_type {
+            ModelType::Base => {
+                if self.active_base.as_ref() == Some(&model.name) {
+                    self.active_base = None;
+                } else {
+                    self.active_base = Some(model.name.clone());
                 }
-                _ => {
-                    // Can't activate if not downloaded
+            }
+            ModelType::LoRA => {
+                if let Some(pos) = self.active_loras.iter().position(|(n, _)| n == &model.name) {
+                    self.active_loras.remove(pos);
+                } else {
+                    self.active_loras.push((model.name.clone(), DEFAULT_LORA_STRENGTH));
                 }
             }
+            ModelType::VAE => {}
         }
     }
 
-    /// Start downloading the selected model.
-    pub fn download_selected(&mut self) {
-        if let Some(model) = self.models.get_mut(self.selected_index) {
-            if model.status == ModelStatus::Available {
+    /// Change the strength of the selected LoRA by `delta` (if it's active).
+    pub fn adjust_lora_strength(&mut self, delta: f32) {
+        let Some(model) = self.models.get(self.selected_index) else {
+            return;
+        };
+        if let Some((_, strength)) = self.active_loras.iter_mut().find(|(n, _)| n == &model.name) {
+            // Round to avoid drift from repeated float steps
+            let adjusted = ((*strength + delta) * 100.0).round() / 100.0;
+            *strength = adjusted.clamp(*LORA_STRENGTH_RANGE.start(), *LORA_STRENGTH_RANGE.end());
+        }
+    }
+
+    /// Whether the named model is the active base or an active LoRA.
+    pub fn is_active(&self, name: &str) -> bool {
+        self.active_base.as_deref() == Some(name) || self.lora_strength(name).is_some()
+    }
+
+    /// Strength of the named LoRA, if it's active.
+    pub fn lora_strength(&self, name: &str) -> Option<f32> {
+        self.active_loras
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, strength)| *strength)
+    }
+
+    /// Default file for the active models (in the data directory).
+    pub fn default_active_path() -> PathBuf {
+        crate::shutdown::data_dir().join("active_models.json")
+    }
+
+    /// Restore the active models saved at `path`, saving future changes there.
+    ///
+    /// A missing file keeps the current activation. Saved models that aren't
+    /// downloaded any more (or changed type) are dropped, and strengths are
+    /// clamped to [`LORA_STRENGTH_RANGE`].
+    pub fn load_active(&mut self, path: &Path) -> Result<()> {
+        self.active_path = Some(path.to_path_buf());
+        if !path.exists() {
+            return Ok(());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read active models: {:?}", path))?;
+        let saved: ActiveModels = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse active models: {:?}", path))?;
+
+        let usable = |name: &str, model_type: ModelType| {
+            let found = self.models.iter().any(|m| {
+                m.name == name && m.model_type == model_type && m.status == ModelStatus::Downloaded
+            });
+            if !found {
+                warn!("Dropping saved active model {:?}: no longer available", name);
+            }
+            found
+        };
+        self.active_base = saved.base.filter(|name| usable(name, ModelType::Base));
+        self.active_loras = saved
+            .loras
+            .into_iter()
+            .filter(|lora| usable(&lora.name, ModelType::LoRA))
+            .map(|lora| {
+                let strength =
+                    lora.strength.clamp(*LORA_STRENGTH_RANGE.start(), *LORA_STRENGTH_RANGE.end());
+                (lora.name, strength)
+            })
+            .collect();
+        Ok(())
+    }
+
+    /// Save the active models to `active_path` (if set).
+    pub fn save_active(&self) -> Result<()> {
+        let Some(path) = &self.active_path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {:?}", parent))?;
+        }
+        let active = ActiveModels {
+            base: self.active_base.clone(),
+            loras: self
+                .active_loras
+                .iter()
+                .map(|(name, strength)| ActiveLora {
+                    name: name.clone(),
+                    strength: *strength,
+                })
+                .collect(),
+        };
+        let json =
+            serde_json::to_string_pretty(&active).context("Failed to serialize active models")?;
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write active models: {:?}", path))
+    }
+
+    /// Mark the selected model as downloading, if it's available.
+    ///
+    /// Returns whether it was; the caller asks the backend for the download.
+    pub fn download_selected(&mut self) -> bool {
+        if !self.selection_listed() {
+            return false;
+        }
+        match self.models.get_mut(self.selected_index) {
+            Some(model) if model.status == ModelStatus::Available => {
                 model.status = ModelStatus::Downloading(0);
-                // TODO: Send download request via ZeroMQ
+                true
             }
+            _ => false,
+        }
+    }
+
+    /// Mark the selected model as downloading again after its download
+    /// failed.
+    ///
+    /// Returns whether it had failed; the caller asks the backend for the
+    /// download.
+    pub fn retry_selected(&mut self) -> bool {
+        if !self.selection_listed() {
+            return false;
+        }
+        match self.models.get_mut(self.selected_index) {
+            Some(model) if model.status == ModelStatus::Failed => {
+                model.status = ModelStatus::Downloading(0);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Record download progress for the named model; at 100% it becomes
+    /// downloaded. Returns false for unknown or already downloaded models.
+    pub fn record_download_progress(&mut self, name: &str, percent: u8) -> bool {
+        match self.models.iter_mut().find(|m| m.name == name) {
+            Some(model) if model.status != ModelStatus::Downloaded => {
+                model.status = if percent >= 100 {
+                    ModelStatus::Downloaded
+                } else {
+                    ModelStatus::Downloading(percent)
+                };
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Mark the named model's download as failed (it can then be retried).
+    pub fn fail_download(&mut self, name: &str) -> bool {
+        match self.models.iter_mut().find(|m| m.name == name) {
+            Some(model) if matches!(model.status, ModelStatus::Downloading(_)) => {
+                model.status = ModelStatus::Failed;
+                true
+            }
+            _ => false,
         }
     }
 
     /// Delete the selected model.
     pub fn delete_selected(&mut self) {
-        if let Some(model) = self.models.get(self.selected_index) {
-            if model.status == ModelStatus::Downloaded {
+        if let Some(name) = self.selected_model().map(|m| m.name.clone()) {
+            self.delete_model(&name);
+        }
+    }
+
+    /// Delete the named model. Returns false unless it was downloaded.
+    pub fn delete_model(&mut self, name: &str) -> bool {
+        match self.models.iter_mut().find(|m| m.name == name) {
+            Some(model) if model.status == ModelStatus::Downloaded => {
                 // TODO: Implement actual deletion
                 // For now, just mark as available
-                if let Some(m) = self.models.get_mut(self.selected_index) {
-                    m.status = ModelStatus::Available;
-                }
+                model.status = ModelStatus::Available;
+                true
             }
+            _ => false,
         }
     }
 
@@ -211,9 +489,17 @@ impl ModelsState {
         // Will be used when implementing scrolling for large model lists
     }
 
-    /// Get the currently selected model.
+    /// Get the currently selected model (`None` if it's filtered out).
     pub fn selected_model(&self) -> Option<&ModelInfo> {
-        self.models.get(self.selected_index)
+        self.models
+            .get(self.selected_index)
+            .filter(|_| self.selection_listed())
+    }
+
+    /// Whether the selected model passes the filter (everything is filtered
+    /// out otherwise, and actions have nothing to act on).
+    fn selection_listed(&self) -> bool {
+        self.filtered_indices().contains(&self.selected_index)
     }
 
     /// Get memory usage statistics.
@@ -305,7 +591,8 @@ mod tests {
         let state = ModelsState::default();
         assert!(!state.models.is_empty());
         assert_eq!(state.selected_index, 0);
-        assert_eq!(state.active_model, Some("SDXL Base 1.0".to_string()));
+        assert_eq!(state.active_base, Some("SDXL Base 1.0".to_string()));
+        assert!(state.active_loras.is_empty());
     }
 
     #[test]
@@ -338,9 +625,109 @@ mod tests {
         let mut state = ModelsState::default();
         state.selected_index = 0; // SDXL Base 1.0
 
-        let initial_active = state.active_model.clone();
+        let initial_active = state.active_base.clone();
         state.toggle_active();
-        assert_ne!(state.active_model, initial_active);
+        assert_ne!(state.active_base, initial_active);
+    }
+
+    #[test]
+    fn test_base_is_single_select_and_loras_stack() {
+        let mut state = ModelsState::default();
+        let index_of = |state: &ModelsState, name: &str| {
+            state.models.iter().position(|m| m.name == name).unwrap()
+        };
+
+        // Downloaded refiner replaces the base
+        let refiner = index_of(&state, "SDXL Refiner 1.0");
+        state.models[refiner].status = ModelStatus::Downloaded;
+        state.selected_index = refiner;
+        state.toggle_active();
+        assert_eq!(state.active_base.as_deref(), Some("SDXL Refiner 1.0"));
+        assert!(!state.is_active("SDXL Base 1.0"));
+
+        // Two LoRAs stack without touching the base
+        let pixel = index_of(&state, "Pixel Art LoRA v1");
+        let retro = index_of(&state, "Retro Pixel LoRA");
+        state.models[retro].status = ModelStatus::Downloaded;
+        state.selected_index = pixel;
+        state.toggle_active();
+        state.selected_index = retro;
+        state.toggle_active();
+        assert_eq!(
+            state.active_loras,
+            vec![
+                ("Pixel Art LoRA v1".to_string(), DEFAULT_LORA_STRENGTH),
+                ("Retro Pixel LoRA".to_string(), DEFAULT_LORA_STRENGTH),
+            ]
+        );
+        assert_eq!(state.active_base.as_deref(), Some("SDXL Refiner 1.0"));
+
+        // Strength only changes for the selected LoRA, within range
+        for _ in 0..3 {
+            state.adjust_lora_strength(-0.1);
+        }
+        assert_eq!(state.lora_strength("Retro Pixel LoRA"), Some(0.7));
+        assert_eq!(state.lora_strength("Pixel Art LoRA v1"), Some(1.0));
+        state.adjust_lora_strength(5.0);
+        assert_eq!(state.lora_strength("Retro Pixel LoRA"), Some(2.0));
+
+        // Toggling again removes just that LoRA
+        state.selected_index = pixel;
+        state.toggle_active();
+        assert_eq!(state.active_loras.len(), 1);
+        assert!(!state.is_active("Pixel Art LoRA v1"));
+    }
+
+    #[test]
+    fn test_filter_by_substring_and_type() {
+        let mut state = ModelsState::default();
+        let names = |state: &ModelsState| -> Vec<String> {
+            state
+                .filtered_indices()
+                .into_iter()
+                .map(|i| state.models[i].name.clone())
+                .collect()
+        };
+
+        for c in "PIXEL".chars() {
+            state.push_filter_char(c);
+        }
+        assert_eq!(names(&state), vec!["Pixel Art LoRA v1", "Retro Pixel LoRA"]);
+
+        // Matches the source too
+        state.clear_filter();
+        for c in "civitai".chars() {
+            state.push_filter_char(c);
+        }
+        assert_eq!(names(&state), vec!["Retro Pixel LoRA"]);
+
+        state.clear_filter();
+        state.toggle_type_visibility(ModelType::LoRA);
+        assert_eq!(names(&state), vec!["SDXL Base 1.0", "SDXL Refiner 1.0", "SDXL VAE"]);
+        state.toggle_type_visibility(ModelType::LoRA);
+        assert_eq!(state.filtered_indices().len(), state.models.len());
+    }
+
+    #[test]
+    fn test_navigation_stays_within_filter() {
+        let mut state = ModelsState::default();
+        for c in "lora".chars() {
+            state.push_filter_char(c);
+        }
+        let shown = state.filtered_indices();
+        assert_eq!(shown.len(), 3);
+        // Selection jumped off the (filtered out) base model
+        assert_eq!(state.selected_index, shown[0]);
+
+        for _ in 0..10 {
+            state.next();
+            assert!(shown.contains(&state.selected_index));
+        }
+        state.select_first();
+        state.previous();
+        assert_eq!(state.selected_index, shown[2]);
+        state.next();
+        assert_eq!(state.selected_index, shown[0]);
     }
 
     #[test]
@@ -371,6 +758,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_download_updates_drive_status() {
+        let mut state = ModelsState::default();
+        let name = "SDXL Refiner 1.0";
+        state.selected_index = state.models.iter().position(|m| m.name == name).unwrap();
+        let status = |state: &ModelsState| state.selected_model().unwrap().status.clone();
+
+        state.download_selected();
+        assert!(state.record_download_progress(name, 35));
+        assert_eq!(status(&state), ModelStatus::Downloading(35));
+
+        // Only a failed download can be retried
+        assert!(!state.retry_selected());
+        assert!(state.fail_download(name));
+        assert_eq!(status(&state), ModelStatus::Failed);
+        assert!(state.retry_selected());
+        assert_eq!(status(&state), ModelStatus::Downloading(0));
+
+        assert!(state.record_download_progress(name, 100));
+        assert_eq!(status(&state), ModelStatus::Downloaded);
+        // Late updates don't undo a finished download
+        assert!(!state.record_download_progress(name, 80));
+        assert!(!state.fail_download(name));
+        assert_eq!(status(&state), ModelStatus::Downloaded);
+        assert!(!state.record_download_progress("No Such Model", 50));
+    }
+
     #[test]
     fn test_selected_model() {
         let state = ModelsState::default();
@@ -379,6 +793,61 @@ mod tests {
         assert_eq!(selected.unwrap().name, state.models[0].name);
     }
 
+    /// Default models with both LoRA downloads finished.
+    fn state_with_two_loras() -> ModelsState {
+        let mut state = ModelsState::default();
+        state.record_download_progress("Game Assets LoRA v2", 100);
+        state
+    }
+
+    #[test]
+    fn test_active_models_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("active_models.json");
+
+        let mut state = state_with_two_loras();
+        state.load_active(&path).unwrap();
+        state.active_base = Some("SDXL Base 1.0".to_string());
+        state.active_loras = vec![
+            ("Game Assets LoRA v2".to_string(), 0.6),
+            ("Pixel Art LoRA v1".to_string(), 1.4),
+        ];
+        state.save_active().unwrap();
+
+        let mut restored = state_with_two_loras();
+        restored.active_base = None;
+        restored.load_active(&path).unwrap();
+        assert_eq!(restored.active_base, state.active_base);
+        assert_eq!(restored.active_loras, state.active_loras);
+    }
+
+    #[test]
+    fn test_load_active_drops_removed_models() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("active_models.json");
+        std::fs::write(
+            &path,
+            r#"{"base": "SDXL Base 1.0", "loras": [
+                {"name": "Pixel Art LoRA v1", "strength": 5.0},
+                {"name": "Game Assets LoRA v2", "strength": 0.5},
+                {"name": "Deleted LoRA", "strength": 1.0}
+            ]}"#,
+        )
+        .unwrap();
+
+        let mut state = state_with_two_loras();
+        assert!(state.delete_model("Game Assets LoRA v2"));
+        state.load_active(&path).unwrap();
+        assert_eq!(state.active_base, Some("SDXL Base 1.0".to_string()));
+        // Strength clamped to the allowed range
+        assert_eq!(state.active_loras, vec![("Pixel Art LoRA v1".to_string(), 2.0)]);
+
+        // A missing file keeps the defaults
+        let mut state = ModelsState::default();
+        state.load_active(&dir.path().join("missing.json")).unwrap();
+        assert_eq!(state.active_base, Some("SDXL Base 1.0".to_string()));
+    }
+
     #[test]
     fn test_toggle_metadata() {
         let mut state = ModelsState::default();
@@ -0,0 +1,129 @@
+//! # Monitor State Resource
+//!
+//! Latest GPU/memory readings from the backend plus a short history of GPU
+//! utilization for the Monitor screen's sparkline. Stats are requested about
+//! once a second while the Monitor screen is shown; without a backend (or
+//! once it stops answering) the screen shows "N/A". A backend that answers a
+//! stats request with an error doesn't support them and isn't asked again
+//! until it reconnects. The backend connection state is kept here too, for
+//! the screen's status bar.
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::messages::SystemStats;
+use crate::zmq_client::ConnectionState;
+
+/// GPU utilization samples kept for the sparkline.
+pub const MONITOR_HISTORY_LEN: usize = 60;
+
+/// How often stats are requested from the backend.
+pub const MONITOR_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Readings older than this are treated as unavailable.
+pub const MONITOR_STALE_AFTER: Duration = Duration::from_secs(5);
+
+/// Wire name of the stats request, which a backend without a handler names
+/// in its error ("Unknown request type: get_system_stats").
+const STATS_REQUEST_TYPE: &str = "get_system_stats";
+
+/// System stats shown on the Monitor screen.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct MonitorState {
+    /// Most recent reading and when it arrived
+    latest: Option<(SystemStats, Instant)>,
+    /// GPU utilization (0-100), oldest first
+    pub gpu_history: VecDeque<f32>,
+    /// Backend connection state (`None` without a ZMQ client)
+    pub connection: Option<ConnectionState>,
+    /// A stats request was sent and its reply hasn't arrived
+    pub stats_pending: bool,
+    /// The backend answered a stats request with an error
+    pub stats_unsupported: bool,
+}
+
+impl MonitorState {
+    /// Record a reading from the backend.
+    pub fn record(&mut self, stats: SystemStats, now: Instant) {
+        self.stats_pending = false;
+        if self.gpu_history.len() == MONITOR_HISTORY_LEN {
+            self.gpu_history.pop_front();
+        }
+        self.gpu_history.push_back(stats.gpu_utilization);
+        self.latest = Some((stats, now));
+    }
+
+    /// Latest reading, unless there is none or it's gone stale.
+    pub fn current(&self, now: Instant) -> Option<&SystemStats> {
+        self.latest
+            .as_ref()
+            .filter(|(_, at)| now.duration_since(*at) < MONITOR_STALE_AFTER)
+            .map(|(stats, _)| stats)
+    }
+
+    /// Note a new connection state; a reconnected backend may support stats.
+    pub fn set_connection(&mut self, state: ConnectionState) {
+        if self.connection != Some(state) {
+            self.connection = Some(state);
+            self.stats_pending = false;
+            self.stats_unsupported = false;
+        }
+    }
+
+    /// Handle an error reply from the backend.
+    ///
+    /// Returns `true` if it answered a stats request (so it's not a failure
+    /// the user needs to see); stats aren't requested again. Errors that
+    /// don't name the stats request are left for the caller, even while one
+    /// is pending.
+    pub fn stats_error(&mut self, message: &str) -> bool {
+        if !self.stats_pending || !message.contains(STATS_REQUEST_TYPE) {
+            return false;
+        }
+        self.stats_pending = false;
+        self.stats_unsupported = true;
+        true
+    }
+
+    /// GPU utilization history as sparkline data (whole percent).
+    pub fn gpu_sparkline(&self) -> Vec<u64> {
+        self.gpu_history
+            .iter()
+            .map(|u| u.clamp(0.0, 100.0).round() as u64)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(gpu_utilization: f32) -> SystemStats {
+        SystemStats {
+            gpu_utilization,
+            vram_used_mb: 1024,
+            vram_total_mb: 4096,
+            temperature_c: 60.0,
+        }
+    }
+
+    #[test]
+    fn test_history_is_capped_and_readings_go_stale() {
+        let mut monitor = MonitorState::default();
+        let start = Instant::now();
+        assert!(monitor.current(start).is_none());
+
+        for i in 0..(MONITOR_HISTORY_LEN + 5) {
+            monitor.record(stats(i as f32), start);
+        }
+        assert_eq!(monitor.gpu_history.len(), MONITOR_HISTORY_LEN);
+        assert_eq!(monitor.gpu_sparkline()[0], 5);
+        assert_eq!(
+            monitor.current(start).unwrap().gpu_utilization,
+            (MONITOR_HISTORY_LEN + 4) as f32
+        );
+
+        assert!(monitor.current(start + MONITOR_STALE_AFTER).is_none());
+    }
+}
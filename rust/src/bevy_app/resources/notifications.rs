@@ -0,0 +1,163 @@
+//! # Notifications Resource
+//!
+//! Short messages ("toasts") shown in the corner of every screen, so backend
+//! errors reach the user instead of only the log. Systems push with
+//! [`Notifications::notify`]; the overlay shows the newest
+//! [`NOTIFICATIONS_SHOWN`] until each is [`NOTIFICATION_TTL`] old or the user
+//! dismisses them all.
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Notifications kept at most; pushing more drops the oldest.
+pub const NOTIFICATION_CAPACITY: usize = 20;
+
+/// How long a notification stays on screen.
+pub const NOTIFICATION_TTL: Duration = Duration::from_secs(5);
+
+/// Notifications shown at once (the newest).
+pub const NOTIFICATIONS_SHOWN: usize = 3;
+
+/// How serious a notification is (picks its color).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationLevel {
+    Info,
+    Warning,
+    Error,
+}
+
+/// One message for the user.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub level: NotificationLevel,
+    pub message: String,
+    pub created_at: Instant,
+}
+
+/// Recent notifications, oldest first.
+#[derive(Resource, Debug, Clone)]
+pub struct Notifications {
+    entries: VecDeque<Notification>,
+    capacity: usize,
+    /// A notification went away and the screen underneath must be redrawn
+    pub needs_repaint: bool,
+}
+
+impl Default for Notifications {
+    fn default() -> Self {
+        Self::with_capacity(NOTIFICATION_CAPACITY)
+    }
+}
+
+impl Notifications {
+    /// Keep at most `capacity` notifications.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            needs_repaint: false,
+        }
+    }
+
+    /// Show `message` to the user.
+    pub fn notify(&mut self, level: NotificationLevel, message: impl Into<String>) {
+        self.notify_at(level, message, Instant::now());
+    }
+
+    /// Show `message`, created at `now`.
+    pub fn notify_at(&mut self, level: NotificationLevel, message: impl Into<String>, now: Instant) {
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(Notification {
+            level,
+            message: message.into(),
+            created_at: now,
+        });
+    }
+
+    /// Show the message of a failed action (see [`crate::desktop`]) as an
+    /// error; a success shows nothing.
+    pub fn notify_failure<T>(&mut self, result: Result<T, String>) {
+        if let Err(message) = result {
+            self.notify(NotificationLevel::Error, message);
+        }
+    }
+
+    /// Drop notifications older than [`NOTIFICATION_TTL`] at `now`.
+    ///
+    /// Returns whether any were dropped.
+    pub fn expire(&mut self, now: Instant) -> bool {
+        let before = self.entries.len();
+        self.entries
+            .retain(|n| now.saturating_duration_since(n.created_at) < NOTIFICATION_TTL);
+        let expired = self.entries.len() != before;
+        self.needs_repaint |= expired;
+        expired
+    }
+
+    /// Remove every notification.
+    pub fn dismiss_all(&mut self) {
+        if !self.entries.is_empty() {
+            self.entries.clear();
+            self.needs_repaint = true;
+        }
+    }
+
+    /// The notifications to show, newest first.
+    pub fn visible(&self) -> impl Iterator<Item = &Notification> {
+        self.entries.iter().rev().take(NOTIFICATIONS_SHOWN)
+    }
+
+    /// Number of notifications held.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether there's nothing to show.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(notifications: &Notifications) -> Vec<&str> {
+        notifications.visible().map(|n| n.message.as_str()).collect()
+    }
+
+    #[test]
+    fn test_notifications_expire() {
+        let start = Instant::now();
+        let mut notifications = Notifications::default();
+        notifications.notify_at(NotificationLevel::Error, "first", start);
+        notifications.notify_at(NotificationLevel::Info, "second", start + Duration::from_secs(2));
+
+        assert!(!notifications.expire(start + Duration::from_secs(4)));
+        assert_eq!(messages(&notifications), vec!["second", "first"]);
+        assert!(!notifications.needs_repaint);
+
+        assert!(notifications.expire(start + NOTIFICATION_TTL));
+        assert_eq!(messages(&notifications), vec!["second"]);
+        assert!(notifications.needs_repaint);
+
+        notifications.dismiss_all();
+        assert!(notifications.is_empty());
+    }
+
+    #[test]
+    fn test_capacity_drops_oldest() {
+        let now = Instant::now();
+        let mut notifications = Notifications::with_capacity(4);
+        for message in ["a", "b", "c", "d", "e"] {
+            notifications.notify_at(NotificationLevel::Warning, message, now);
+        }
+
+        assert_eq!(notifications.len(), 4);
+        // "a" dropped; only the newest three are shown
+        assert_eq!(messages(&notifications), vec!["e", "d", "c"]);
+    }
+}
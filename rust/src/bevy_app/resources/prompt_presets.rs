@@ -0,0 +1,246 @@
+//! # Prompt Presets Resource
+//!
+//! Reusable prompt scaffolds loaded from `~/.config/dgx-pixels/presets.toml`
+//! (next to `config.toml`), plus the state of the Generation screen's preset
+//! picker (`t`). A template marks the parts to fill in with `{placeholder}`
+//! tokens:
+//!
+//! ```toml
+//! [[preset]]
+//! name = "Sprite"
+//! template = "16-bit {subject} sprite, transparent background"
+//! ```
+
+use anyhow::{Context, Result};
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use super::InputBuffer;
+
+/// A named prompt template.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct PromptPreset {
+    pub name: String,
+    /// Prompt text with `{placeholder}` tokens
+    pub template: String,
+}
+
+impl PromptPreset {
+    /// Names of the template's placeholders, in order of appearance.
+    pub fn placeholders(&self) -> Vec<&str> {
+        placeholder_spans(&self.template)
+            .into_iter()
+            .map(|span| &self.template[span.start + 1..span.end - 1])
+            .collect()
+    }
+}
+
+/// Layout of `presets.toml`.
+#[derive(Debug, Default, Deserialize)]
+struct PresetsFile {
+    #[serde(default)]
+    preset: Vec<PromptPreset>,
+}
+
+/// Byte ranges of the `{placeholder}` tokens in `template`, braces included.
+///
+/// A placeholder name is one or more letters, digits, `_` or `-`; other
+/// braces (e.g. `{}` or `{two words}`) are left alone as plain text.
+pub fn placeholder_spans(template: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut rest = 0;
+    while let Some(open) = template[rest..].find('{').map(|i| rest + i) {
+        let Some(close) = template[open + 1..].find(['{', '}']).map(|i| open + 1 + i) else {
+            break;
+        };
+        let name = &template[open + 1..close];
+        let valid = template[close..].starts_with('}')
+            && !name.is_empty()
+            && name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-');
+        if valid {
+            spans.push(open..close + 1);
+            rest = close + 1;
+        } else {
+            rest = close;
+        }
+    }
+    spans
+}
+
+/// Fill in `preset`'s placeholders from `vars`.
+///
+/// Placeholders without a value are kept as written.
+pub fn expand(preset: &PromptPreset, vars: &HashMap<String, String>) -> String {
+    let template = &preset.template;
+    let mut expanded = String::with_capacity(template.len());
+    let mut rest = 0;
+    for span in placeholder_spans(template) {
+        expanded.push_str(&template[rest..span.start]);
+        let name = &template[span.start + 1..span.end - 1];
+        match vars.get(name) {
+            Some(value) => expanded.push_str(value),
+            None => expanded.push_str(&template[span.clone()]),
+        }
+        rest = span.end;
+    }
+    expanded.push_str(&template[rest..]);
+    expanded
+}
+
+/// Loaded presets and the preset picker's state.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PromptPresets {
+    pub presets: Vec<PromptPreset>,
+    /// Whether the picker overlay is showing
+    pub picker_open: bool,
+    /// Highlighted preset in the picker
+    pub selected: usize,
+}
+
+impl PromptPresets {
+    /// Path of the presets file.
+    pub fn presets_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from(".config"))
+            .join("dgx-pixels")
+            .join("presets.toml")
+    }
+
+    /// Load presets from the presets file.
+    pub fn load() -> Result<Self> {
+        Self::load_from(&Self::presets_path())
+    }
+
+    /// Load presets from `path`; a missing file gives no presets.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            info!("Presets file not found, no prompt presets: {:?}", path);
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read presets file: {:?}", path))?;
+        let file: PresetsFile = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse presets file: {:?}", path))?;
+
+        info!("Loaded {} prompt presets from {:?}", file.preset.len(), path);
+        Ok(Self {
+            presets: file.preset,
+            ..Default::default()
+        })
+    }
+
+    /// Show the picker with the first preset highlighted.
+    pub fn open_picker(&mut self) {
+        self.picker_open = true;
+        self.selected = 0;
+    }
+
+    /// Hide the picker.
+    pub fn close_picker(&mut self) {
+        self.picker_open = false;
+    }
+
+    /// Highlight the next preset.
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.presets.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Highlight the previous preset.
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// The highlighted preset, if there are any.
+    pub fn selected_preset(&self) -> Option<&PromptPreset> {
+        self.presets.get(self.selected)
+    }
+
+    /// Put the highlighted preset's template in the prompt box.
+    ///
+    /// The first placeholder is taken out and the cursor left in its place,
+    /// so whatever is typed next fills it in. Returns `false` if there is no
+    /// preset to insert.
+    pub fn insert_selected(&self, input: &mut InputBuffer) -> bool {
+        let Some(preset) = self.selected_preset() else {
+            return false;
+        };
+        let mut text = preset.template.clone();
+        let cursor = match placeholder_spans(&text).first() {
+            Some(span) => {
+                text.replace_range(span.clone(), "");
+                span.start
+            }
+            None => text.len(),
+        };
+        input.text = text;
+        input.cursor = cursor;
+        input.negative_focused = false;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preset(template: &str) -> PromptPreset {
+        PromptPreset {
+            name: "Test".to_string(),
+            template: template.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_placeholder_detection_and_expand() {
+        let sprite = preset("16-bit {subject} sprite, {style} palette {} {two words}");
+        assert_eq!(sprite.placeholders(), vec!["subject", "style"]);
+        assert!(preset("no placeholders").placeholders().is_empty());
+        assert_eq!(preset("{{nested}}").placeholders(), vec!["nested"]);
+
+        let vars = HashMap::from([("subject".to_string(), "knight".to_string())]);
+        assert_eq!(
+            expand(&sprite, &vars),
+            "16-bit knight sprite, {style} palette {} {two words}"
+        );
+
+        let presets = PromptPresets {
+            presets: vec![sprite],
+            ..Default::default()
+        };
+        let mut input = InputBuffer::default();
+        assert!(presets.insert_selected(&mut input));
+        assert_eq!(input.text, "16-bit  sprite, {style} palette {} {two words}");
+        assert_eq!(input.cursor, "16-bit ".len());
+    }
+
+    #[test]
+    fn test_load_presets_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("presets.toml");
+
+        // Missing file: no presets, no error
+        let presets = PromptPresets::load_from(&path).unwrap();
+        assert!(presets.presets.is_empty());
+        assert!(presets.selected_preset().is_none());
+
+        std::fs::write(
+            &path,
+            "[[preset]]\nname = \"Sprite\"\ntemplate = \"16-bit {subject} sprite\"\n",
+        )
+        .unwrap();
+        let presets = PromptPresets::load_from(&path).unwrap();
+        assert_eq!(presets.presets, vec![PromptPreset {
+            name: "Sprite".to_string(),
+            template: "16-bit {subject} sprite".to_string(),
+        }]);
+
+        std::fs::write(&path, "[[preset]]\nname = 3\n").unwrap();
+        assert!(PromptPresets::load_from(&path).is_err());
+    }
+}
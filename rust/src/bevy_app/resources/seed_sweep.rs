@@ -0,0 +1,148 @@
+//! # Seed Sweep Resource
+//!
+//! The Generation screen's seed sweep dialog (Alt+W). A sweep submits the
+//! last prompt once per seed, `base` through `base + count - 1`, as a single
+//! batch; its images share a sweep id, which is also added to their tags so
+//! the gallery's tag filter shows them as one set.
+
+use anyhow::{Context, Result};
+use bevy::prelude::*;
+
+use crate::bevy_app::events::SubmitGenerationJob;
+
+/// Fewest and most jobs in a sweep.
+pub const SWEEP_COUNT_RANGE: (u32, u32) = (2, 16);
+
+/// A field of the sweep dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SweepField {
+    /// Number of jobs
+    #[default]
+    Count,
+    /// Seed of the first job
+    BaseSeed,
+}
+
+/// State of the seed sweep dialog.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct SeedSweep {
+    /// Whether the dialog is showing
+    pub open: bool,
+    /// Number of jobs to submit
+    pub count: u32,
+    /// Seed of the first job, as typed
+    pub base_seed: String,
+    /// Field being edited
+    pub field: SweepField,
+    /// Why the last submit was refused
+    pub error: Option<String>,
+}
+
+impl Default for SeedSweep {
+    fn default() -> Self {
+        Self {
+            open: false,
+            count: 4,
+            base_seed: String::new(),
+            field: SweepField::Count,
+            error: None,
+        }
+    }
+}
+
+impl SeedSweep {
+    /// Show the dialog starting at `base_seed`, keeping the last count.
+    pub fn open_at(&mut self, base_seed: u64) {
+        self.open = true;
+        self.base_seed = base_seed.to_string();
+        self.field = SweepField::Count;
+        self.error = None;
+    }
+
+    /// Hide the dialog.
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Edit the other field.
+    pub fn toggle_field(&mut self) {
+        self.field = match self.field {
+            SweepField::Count => SweepField::BaseSeed,
+            SweepField::BaseSeed => SweepField::Count,
+        };
+    }
+
+    /// Change the job count by `delta`, within [`SWEEP_COUNT_RANGE`].
+    pub fn adjust_count(&mut self, delta: i32) {
+        let count = self.count.saturating_add_signed(delta);
+        self.count = count.clamp(SWEEP_COUNT_RANGE.0, SWEEP_COUNT_RANGE.1);
+        self.error = None;
+    }
+
+    /// Type a digit into the base seed (other characters are ignored).
+    pub fn push_digit(&mut self, c: char) {
+        if c.is_ascii_digit() {
+            self.base_seed.push(c);
+            self.error = None;
+        }
+    }
+
+    /// Delete the last digit of the base seed.
+    pub fn backspace(&mut self) {
+        self.base_seed.pop();
+        self.error = None;
+    }
+
+    /// The sweep as a submission of `last`'s prompt and parameters.
+    ///
+    /// Fails if the base seed isn't a whole number.
+    pub fn submission(&self, last: &SubmitGenerationJob) -> Result<SubmitGenerationJob> {
+        let base_seed = self
+            .base_seed
+            .trim()
+            .parse::<u64>()
+            .context("Base seed must be a whole number")?;
+        Ok(SubmitGenerationJob {
+            batch_size: Some(self.count.clamp(SWEEP_COUNT_RANGE.0, SWEEP_COUNT_RANGE.1)),
+            base_seed: Some(base_seed),
+            sweep: true,
+            ..last.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sweep_submission() {
+        let last = SubmitGenerationJob {
+            prompt: "pixel knight".into(),
+            negative_prompt: Some("blurry".into()),
+            batch_size: Some(1),
+            base_seed: Some(7),
+            steps: Some(30),
+            cfg_scale: None,
+            sweep: false,
+        };
+        let mut sweep = SeedSweep::default();
+        sweep.open_at(7);
+        sweep.adjust_count(100);
+        assert_eq!(sweep.count, SWEEP_COUNT_RANGE.1);
+
+        sweep.toggle_field();
+        sweep.backspace();
+        sweep.push_digit('4');
+        sweep.push_digit('x');
+        let job = sweep.submission(&last).unwrap();
+        assert_eq!(job.batch_size, Some(16));
+        assert_eq!(job.base_seed, Some(4));
+        assert!(job.sweep);
+        assert_eq!(job.prompt, "pixel knight");
+        assert_eq!(job.steps, Some(30));
+
+        sweep.base_seed.clear();
+        assert!(sweep.submission(&last).is_err());
+    }
+}
@@ -3,7 +3,7 @@
 //! Manages application configuration settings across:
 //! - Generation defaults (model, steps, cfg_scale, size)
 //! - UI preferences (theme, fps_limit, auto_refresh)
-//! - Backend configuration (zmq_host, zmq_port, timeout)
+//! - Backend configuration (zmq_host, zmq_port, timeout, socket pattern/mode)
 //! - Paths (output_dir, cache_dir, models_dir)
 //!
 //! Settings are persisted to `~/.config/dgx-pixels/config.toml` and can be
@@ -11,9 +11,24 @@
 
 use anyhow::{Context, Result};
 use bevy::prelude::*;
+use ratatui::style::Color as RatatuiColor;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use super::AppTheme;
+use crate::bevy_app::systems::assets::palette::MAX_PALETTE_COLORS;
+use crate::bevy_app::systems::assets::preview::MAX_CACHE_SIZE_MB;
+use crate::bevy_app::systems::assets::render::{UnicodeRenderMode, UnicodeRenderOptions};
+use crate::bevy_app::systems::assets::sixel_renderer::MAX_SIXEL_COLORS;
+use crate::generation_request::GenerationDefaults;
+use crate::zmq_client::{RequestPattern, SocketMode, ZmqConfig};
+
+/// Number of settings addressable by index on the Settings screen.
+pub const SETTINGS_COUNT: usize = 37;
+
+/// Most edits kept for undo; older ones are dropped.
+pub const MAX_UNDO: usize = 50;
 
 /// Main settings resource.
 #[derive(Resource, Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +49,30 @@ pub struct SettingsState {
     #[serde(skip)]
     pub edit_buffer: String,
 
+    /// Whether settings changed since they were loaded or saved.
+    #[serde(skip)]
+    pub dirty: bool,
+
+    /// Whether the changed-settings (current vs default) view is open.
+    #[serde(skip)]
+    pub show_changes: bool,
+
+    /// Selected row in the changed-settings view.
+    #[serde(skip)]
+    pub changes_index: usize,
+
+    /// Why the last edit was rejected, shown on the Settings screen.
+    #[serde(skip)]
+    pub last_error: Option<String>,
+
+    /// Edits that can be undone, oldest first, as (setting index, previous value).
+    #[serde(skip)]
+    pub undo_stack: Vec<(usize, String)>,
+
+    /// Undone edits that can be redone, as (setting index, value to restore).
+    #[serde(skip)]
+    pub redo_stack: Vec<(usize, String)>,
+
     /// Generation-related settings.
     pub generation: GenerationSettings,
 
@@ -47,6 +86,16 @@ pub struct SettingsState {
     pub paths: PathSettings,
 }
 
+/// A setting whose value differs from its default.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingChange {
+    /// Setting index (as used by the Settings screen)
+    pub index: usize,
+    pub name: &'static str,
+    pub current: String,
+    pub default: String,
+}
+
 /// Settings for image generation defaults.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenerationSettings {
@@ -70,7 +119,10 @@ pub struct GenerationSettings {
 }
 
 /// Settings for UI behavior and appearance.
+///
+/// Missing fields fall back to defaults so older config files keep loading.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct UiSettings {
     /// Theme name (for future theming support).
     pub theme: String,
@@ -89,6 +141,51 @@ pub struct UiSettings {
 
     /// Preview image max height (pixels).
     pub preview_max_height: u32,
+
+    /// Show filenames under gallery grid thumbnails.
+    pub gallery_show_labels: bool,
+
+    /// Draw borders around gallery grid cells.
+    pub gallery_show_borders: bool,
+
+    /// Retries for preview images that are missing or unreadable when first loaded.
+    pub image_load_retries: u32,
+
+    /// Show generation time badges on gallery previews and grid cells.
+    pub gallery_show_generation_time: bool,
+
+    /// Remove finished jobs from the queue this many seconds after they end (off if unset).
+    pub auto_clear_completed_secs: Option<u32>,
+
+    /// Background that transparent pixels are drawn over in Sixel previews.
+    pub sixel_background: RatatuiColor,
+
+    /// Submit a pasted prompt immediately (bracketed paste only, never typing).
+    pub generate_on_paste: bool,
+
+    /// UI language; selects `~/.config/dgx-pixels/lang/<language>.toml` ("en" is built in).
+    pub language: String,
+
+    /// Highlight words changed since the last submitted prompt.
+    pub show_prompt_diff: bool,
+
+    /// Select newly discovered gallery images when the user isn't browsing.
+    pub auto_select_newest: bool,
+
+    /// Memory for cached Sixel previews (MB); least recently shown are dropped first.
+    pub sixel_cache_mb: u32,
+
+    /// Palette size for Sixel previews (2-256); fewer colors encode and draw faster.
+    pub sixel_colors: u32,
+
+    /// Characters for previews without Sixel: half_block, quarter_block or shade.
+    pub unicode_render_mode: UnicodeRenderMode,
+
+    /// Dither previews without Sixel (Floyd–Steinberg) for smoother gradients.
+    pub unicode_dither: bool,
+
+    /// Most common colors shown as swatches under the gallery preview (0 hides them).
+    pub palette_colors: u32,
 }
 
 /// Settings for backend connection.
@@ -105,6 +202,30 @@ pub struct BackendSettings {
 
     /// Number of retry attempts.
     pub retry_attempts: u32,
+
+    /// Request socket pattern (REQ/REP or DEALER/ROUTER).
+    #[serde(default)]
+    pub zmq_pattern: RequestPattern,
+
+    /// Whether sockets connect to the backend or bind for it to connect.
+    #[serde(default)]
+    pub zmq_socket_mode: SocketMode,
+
+    /// Most jobs sent to the backend at once; the rest wait in a local queue.
+    #[serde(default = "default_max_concurrent_jobs")]
+    pub max_concurrent_jobs: u32,
+
+    /// Fail jobs the backend hasn't finished this many seconds after they were sent (0 is off).
+    #[serde(default = "default_generation_timeout_secs")]
+    pub generation_timeout_secs: u32,
+}
+
+fn default_max_concurrent_jobs() -> u32 {
+    2
+}
+
+fn default_generation_timeout_secs() -> u32 {
+    600
 }
 
 /// Settings for file system paths.
@@ -121,15 +242,25 @@ pub struct PathSettings {
 
     /// Workflows directory (ComfyUI JSON templates).
     pub workflows_dir: PathBuf,
+
+    /// Directories the gallery scans for images (`../outputs` if empty).
+    #[serde(default)]
+    pub gallery_dirs: Vec<PathBuf>,
 }
 
 impl Default for SettingsState {
     fn default() -> Self {
         Self {
             selected_index: 0,
-            total_settings: 18, // Update if adding/removing settings
+            total_settings: SETTINGS_COUNT,
             is_editing: false,
             edit_buffer: String::new(),
+            dirty: false,
+            show_changes: false,
+            changes_index: 0,
+            last_error: None,
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
             generation: GenerationSettings::default(),
             ui: UiSettings::default(),
             backend: BackendSettings::default(),
@@ -160,6 +291,21 @@ impl Default for UiSettings {
             show_image_previews: true,
             preview_max_width: 512,
             preview_max_height: 512,
+            gallery_show_labels: true,
+            gallery_show_borders: true,
+            image_load_retries: 3,
+            gallery_show_generation_time: true,
+            auto_clear_completed_secs: None,
+            sixel_background: AppTheme::default().colors.background,
+            generate_on_paste: false,
+            language: "en".to_string(),
+            show_prompt_diff: true,
+            auto_select_newest: false,
+            sixel_cache_mb: MAX_CACHE_SIZE_MB as u32,
+            sixel_colors: MAX_SIXEL_COLORS as u32,
+            unicode_render_mode: UnicodeRenderMode::default(),
+            unicode_dither: false,
+            palette_colors: 8,
         }
     }
 }
@@ -171,6 +317,24 @@ impl Default for BackendSettings {
             zmq_port: 5555,
             timeout_secs: 30,
             retry_attempts: 3,
+            zmq_pattern: RequestPattern::default(),
+            zmq_socket_mode: SocketMode::default(),
+            max_concurrent_jobs: default_max_concurrent_jobs(),
+            generation_timeout_secs: default_generation_timeout_secs(),
+        }
+    }
+}
+
+impl BackendSettings {
+    /// ZeroMQ topology for these settings (updates use the next port up).
+    pub fn zmq_config(&self) -> ZmqConfig {
+        ZmqConfig {
+            req_addr: format!("tcp://{}:{}", self.zmq_host, self.zmq_port),
+            pub_addr: format!("tcp://{}:{}", self.zmq_host, self.zmq_port.saturating_add(1)),
+            pattern: self.zmq_pattern,
+            mode: self.zmq_socket_mode,
+            timeout: std::time::Duration::from_secs(self.timeout_secs.max(1) as u64),
+            retry_attempts: self.retry_attempts,
         }
     }
 }
@@ -185,10 +349,34 @@ impl Default for PathSettings {
             cache_dir: dgx_pixels_dir.join("cache"),
             models_dir: dgx_pixels_dir.join("models"),
             workflows_dir: dgx_pixels_dir.join("workflows"),
+            gallery_dirs: Vec::new(),
         }
     }
 }
 
+impl PathSettings {
+    /// Check that files can be written to `output_dir` and `cache_dir`.
+    ///
+    /// Missing directories are created, then a test file is written and
+    /// removed in each. The error names the directory that failed.
+    pub fn ensure_writable(&self) -> Result<()> {
+        for (name, dir) in [("Output", &self.output_dir), ("Cache", &self.cache_dir)] {
+            check_writable(dir)
+                .with_context(|| format!("{} directory {:?} is not writable", name, dir))?;
+        }
+        Ok(())
+    }
+}
+
+/// Create `dir` if needed and write (then remove) a test file in it.
+fn check_writable(dir: &Path) -> Result<()> {
+    fs::create_dir_all(dir).context("Failed to create it")?;
+    let probe = dir.join(format!(".dgx-pixels-write-test-{}", std::process::id()));
+    fs::write(&probe, b"").context("Failed to write a test file")?;
+    fs::remove_file(&probe).context("Failed to remove the test file")?;
+    Ok(())
+}
+
 impl SettingsState {
     /// Get the config file path.
     pub fn config_path() -> PathBuf {
@@ -200,14 +388,17 @@ impl SettingsState {
 
     /// Load settings from config file.
     pub fn load() -> Result<Self> {
-        let path = Self::config_path();
+        Self::load_from(&Self::config_path())
+    }
 
+    /// Load settings from the config file at `path` (defaults if it doesn't exist).
+    pub fn load_from(path: &Path) -> Result<Self> {
         if !path.exists() {
             info!("Config file not found, using defaults: {:?}", path);
             return Ok(Self::default());
         }
 
-        let content = fs::read_to_string(&path)
+        let content = fs::read_to_string(path)
             .with_context(|| format!("Failed to read config file: {:?}", path))?;
 
         let mut settings: Self = toml::from_str(&content)
@@ -215,9 +406,10 @@ impl SettingsState {
 
         // Initialize runtime fields
         settings.selected_index = 0;
-        settings.total_settings = 18;
+        settings.total_settings = SETTINGS_COUNT;
         settings.is_editing = false;
         settings.edit_buffer.clear();
+        settings.dirty = false;
 
         info!("Settings loaded from {:?}", path);
         Ok(settings)
@@ -225,23 +417,45 @@ impl SettingsState {
 
     /// Save settings to config file.
     pub fn save(&self) -> Result<()> {
-        let path = Self::config_path();
+        self.save_to(&Self::config_path())
+    }
 
+    /// Save settings to the config file at `path`.
+    pub fn save_to(&self, path: &Path) -> Result<()> {
         // Ensure config directory exists
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)
                 .with_context(|| format!("Failed to create config directory: {:?}", parent))?;
         }
 
-        let content = toml::to_string_pretty(self).context("Failed to serialize settings")?;
+        let content = self.to_toml()?;
 
-        fs::write(&path, content)
+        fs::write(path, content)
             .with_context(|| format!("Failed to write config file: {:?}", path))?;
 
         info!("Settings saved to {:?}", path);
         Ok(())
     }
 
+    /// Take the configured values from `loaded`, keeping the Settings
+    /// screen's navigation and edit state.
+    ///
+    /// Undo history is dropped, since its previous values no longer apply.
+    pub fn apply_loaded(&mut self, loaded: Self) {
+        self.generation = loaded.generation;
+        self.ui = loaded.ui;
+        self.backend = loaded.backend;
+        self.paths = loaded.paths;
+        self.dirty = false;
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+
+    /// Settings serialized as they are written to the config file.
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string_pretty(self).context("Failed to serialize settings")
+    }
+
     /// Reset settings to defaults.
     pub fn reset_to_defaults(&mut self) {
         let defaults = Self::default();
@@ -249,6 +463,7 @@ impl SettingsState {
         self.ui = defaults.ui;
         self.backend = defaults.backend;
         self.paths = defaults.paths;
+        self.dirty = true;
     }
 
     /// Navigate to next setting.
@@ -270,7 +485,12 @@ impl SettingsState {
         self.is_editing = true;
 
         // Populate edit buffer with current value
-        self.edit_buffer = match self.selected_index {
+        self.edit_buffer = self.setting_value(self.selected_index);
+    }
+
+    /// Current value of a setting, formatted as it is edited.
+    pub fn setting_value(&self, index: usize) -> String {
+        match index {
             0 => self.generation.default_model.clone(),
             1 => self.generation.default_steps.to_string(),
             2 => self.generation.default_cfg_scale.to_string(),
@@ -289,8 +509,55 @@ impl SettingsState {
             15 => self.backend.timeout_secs.to_string(),
             16 => self.backend.retry_attempts.to_string(),
             17 => self.paths.output_dir.to_string_lossy().to_string(),
+            18 => self.ui.gallery_show_labels.to_string(),
+            19 => self.ui.gallery_show_borders.to_string(),
+            20 => self.ui.image_load_retries.to_string(),
+            21 => self.ui.gallery_show_generation_time.to_string(),
+            22 => self
+                .ui
+                .auto_clear_completed_secs
+                .map_or_else(|| "off".to_string(), |secs| secs.to_string()),
+            23 => self.ui.sixel_background.to_string(),
+            24 => self.backend.max_concurrent_jobs.to_string(),
+            25 => self.ui.generate_on_paste.to_string(),
+            26 => self.ui.language.clone(),
+            27 => self.ui.show_prompt_diff.to_string(),
+            28 => self.ui.auto_select_newest.to_string(),
+            29 => self.ui.sixel_cache_mb.to_string(),
+            30 => self.paths.cache_dir.to_string_lossy().to_string(),
+            31 => self.paths.models_dir.to_string_lossy().to_string(),
+            32 => self.ui.sixel_colors.to_string(),
+            33 => self.ui.unicode_render_mode.to_string(),
+            34 => self.ui.unicode_dither.to_string(),
+            35 => match self.backend.generation_timeout_secs {
+                0 => "off".to_string(),
+                secs => secs.to_string(),
+            },
+            36 => match self.ui.palette_colors {
+                0 => "off".to_string(),
+                colors => colors.to_string(),
+            },
             _ => String::new(),
-        };
+        }
+    }
+
+    /// Options for previews drawn with Unicode characters (no Sixel).
+    pub fn unicode_render_options(&self) -> UnicodeRenderOptions {
+        UnicodeRenderOptions {
+            mode: self.ui.unicode_render_mode,
+            dither: self.ui.unicode_dither,
+        }
+    }
+
+    /// Defaults for generation requests that don't set a field.
+    pub fn generation_defaults(&self) -> GenerationDefaults {
+        GenerationDefaults {
+            model: self.generation.default_model.clone(),
+            size: self.generation.default_size,
+            steps: self.generation.default_steps,
+            cfg_scale: self.generation.default_cfg_scale,
+            ..Default::default()
+        }
     }
 
     /// Finish editing and apply the new value.
@@ -299,6 +566,67 @@ impl SettingsState {
             return Ok(());
         }
 
+        let index = self.selected_index;
+        let previous = self.setting_value(index);
+        let result = self.apply_edit();
+        self.last_error = result.as_ref().err().map(|e| format!("{:#}", e));
+        if result.is_ok() && self.setting_value(index) != previous {
+            push_capped(&mut self.undo_stack, (index, previous));
+            self.redo_stack.clear();
+        }
+        result
+    }
+
+    /// Undo the last edit, restoring the setting's previous value.
+    ///
+    /// Returns whether there was an edit to undo.
+    pub fn undo(&mut self) -> Result<bool> {
+        let Some((index, value)) = self.undo_stack.pop() else {
+            return Ok(false);
+        };
+        let current = self.setting_value(index);
+        if let Err(e) = self.set_value(index, &value) {
+            self.undo_stack.push((index, value));
+            return Err(e);
+        }
+        push_capped(&mut self.redo_stack, (index, current));
+        Ok(true)
+    }
+
+    /// Redo the last undone edit.
+    ///
+    /// Returns whether there was an edit to redo.
+    pub fn redo(&mut self) -> Result<bool> {
+        let Some((index, value)) = self.redo_stack.pop() else {
+            return Ok(false);
+        };
+        let current = self.setting_value(index);
+        if let Err(e) = self.set_value(index, &value) {
+            self.redo_stack.push((index, value));
+            return Err(e);
+        }
+        push_capped(&mut self.undo_stack, (index, current));
+        Ok(true)
+    }
+
+    /// Apply `value` to a setting as if it had been typed in, without
+    /// recording it for undo. Any edit in progress is discarded.
+    fn set_value(&mut self, index: usize, value: &str) -> Result<()> {
+        let selected = self.selected_index;
+        self.selected_index = index;
+        self.edit_buffer = value.to_string();
+        let result = self.apply_edit();
+        self.is_editing = false;
+        self.edit_buffer.clear();
+        self.selected_index = selected;
+        self.last_error = result.as_ref().err().map(|e| format!("{:#}", e));
+        result
+    }
+
+    /// Apply `edit_buffer` to the selected setting and stop editing.
+    ///
+    /// On error the setting and the edit are left as they were.
+    fn apply_edit(&mut self) -> Result<()> {
         let value = self.edit_buffer.trim();
 
         // Apply the edited value to the appropriate setting
@@ -342,8 +670,17 @@ impl SettingsState {
                 self.ui.preview_max_height =
                     value.parse().context("Invalid number for preview height")?
             }
-            13 => self.backend.zmq_host = value.to_string(),
-            14 => self.backend.zmq_port = value.parse().context("Invalid number for port")?,
+            13 => {
+                validate_host(value)?;
+                self.backend.zmq_host = value.to_string();
+            }
+            14 => {
+                let port: u16 = value.parse().context("Invalid number for port")?;
+                if port < 1024 {
+                    anyhow::bail!("Port must be between 1024 and 65535, got {}", port);
+                }
+                self.backend.zmq_port = port;
+            }
             15 => {
                 self.backend.timeout_secs = value.parse().context("Invalid number for timeout")?
             }
@@ -351,11 +688,94 @@ impl SettingsState {
                 self.backend.retry_attempts = value.parse().context("Invalid number for retries")?
             }
             17 => self.paths.output_dir = PathBuf::from(value),
+            18 => {
+                self.ui.gallery_show_labels =
+                    value.parse().context("Invalid boolean for gallery labels")?
+            }
+            19 => {
+                self.ui.gallery_show_borders =
+                    value.parse().context("Invalid boolean for gallery borders")?
+            }
+            20 => {
+                self.ui.image_load_retries =
+                    value.parse().context("Invalid number for image load retries")?
+            }
+            21 => {
+                self.ui.gallery_show_generation_time =
+                    value.parse().context("Invalid boolean for generation time badges")?
+            }
+            22 => {
+                self.ui.auto_clear_completed_secs = match value {
+                    "" | "off" => None,
+                    secs => Some(secs.parse().context("Invalid number of seconds for auto-clear")?),
+                }
+            }
+            23 => {
+                self.ui.sixel_background = value
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("Invalid color for Sixel background: {}", value))?
+            }
+            24 => {
+                self.backend.max_concurrent_jobs =
+                    value.parse().context("Invalid number for max concurrent jobs")?
+            }
+            25 => {
+                self.ui.generate_on_paste =
+                    value.parse().context("Invalid boolean for generate on paste")?
+            }
+            26 => self.ui.language = value.to_string(),
+            27 => {
+                self.ui.show_prompt_diff =
+                    value.parse().context("Invalid boolean for prompt diff")?
+            }
+            28 => {
+                self.ui.auto_select_newest =
+                    value.parse().context("Invalid boolean for auto-select newest")?
+            }
+            29 => {
+                let mb: u32 = value.parse().context("Invalid number for Sixel cache size")?;
+                if mb == 0 {
+                    anyhow::bail!("Sixel cache size must be at least 1 MB");
+                }
+                self.ui.sixel_cache_mb = mb;
+            }
+            30 => self.paths.cache_dir = PathBuf::from(value),
+            31 => self.paths.models_dir = PathBuf::from(value),
+            32 => {
+                let colors: u32 = value.parse().context("Invalid number for Sixel colors")?;
+                if !(2..=MAX_SIXEL_COLORS as u32).contains(&colors) {
+                    anyhow::bail!("Sixel colors must be between 2 and {}", MAX_SIXEL_COLORS);
+                }
+                self.ui.sixel_colors = colors;
+            }
+            33 => self.ui.unicode_render_mode = value.parse()?,
+            34 => {
+                self.ui.unicode_dither = value.parse().context("Invalid boolean for dithering")?
+            }
+            35 => {
+                self.backend.generation_timeout_secs = match value {
+                    "" | "off" => 0,
+                    secs => secs
+                        .parse()
+                        .context("Invalid number of seconds for generation timeout")?,
+                }
+            }
+            36 => {
+                let colors = match value {
+                    "" | "off" => 0,
+                    colors => colors.parse().context("Invalid number for palette colors")?,
+                };
+                if colors > MAX_PALETTE_COLORS {
+                    anyhow::bail!("Palette colors must be at most {}", MAX_PALETTE_COLORS);
+                }
+                self.ui.palette_colors = colors;
+            }
             _ => {}
         }
 
         self.is_editing = false;
         self.edit_buffer.clear();
+        self.dirty = true;
         Ok(())
     }
 
@@ -363,6 +783,7 @@ impl SettingsState {
     pub fn cancel_editing(&mut self) {
         self.is_editing = false;
         self.edit_buffer.clear();
+        self.last_error = None;
     }
 
     /// Toggle a boolean setting.
@@ -370,8 +791,18 @@ impl SettingsState {
         match self.selected_index {
             9 => self.ui.auto_refresh_gallery = !self.ui.auto_refresh_gallery,
             10 => self.ui.show_image_previews = !self.ui.show_image_previews,
-            _ => {}
+            18 => self.ui.gallery_show_labels = !self.ui.gallery_show_labels,
+            19 => self.ui.gallery_show_borders = !self.ui.gallery_show_borders,
+            21 => {
+                self.ui.gallery_show_generation_time = !self.ui.gallery_show_generation_time
+            }
+            25 => self.ui.generate_on_paste = !self.ui.generate_on_paste,
+            27 => self.ui.show_prompt_diff = !self.ui.show_prompt_diff,
+            28 => self.ui.auto_select_newest = !self.ui.auto_select_newest,
+            34 => self.ui.unicode_dither = !self.ui.unicode_dither,
+            _ => return,
         }
+        self.dirty = true;
     }
 
     /// Increment a numeric setting.
@@ -409,8 +840,33 @@ impl SettingsState {
             16 => {
                 self.backend.retry_attempts = self.backend.retry_attempts.saturating_add(1).min(10)
             }
-            _ => {}
+            20 => {
+                self.ui.image_load_retries = self.ui.image_load_retries.saturating_add(1).min(10)
+            }
+            24 => {
+                self.backend.max_concurrent_jobs =
+                    self.backend.max_concurrent_jobs.saturating_add(1).min(16)
+            }
+            29 => self.ui.sixel_cache_mb = self.ui.sixel_cache_mb.saturating_add(10).min(1024),
+            32 => {
+                self.ui.sixel_colors =
+                    self.ui.sixel_colors.saturating_mul(2).min(MAX_SIXEL_COLORS as u32)
+            }
+            33 => self.ui.unicode_render_mode = self.ui.unicode_render_mode.next(),
+            35 => {
+                self.backend.generation_timeout_secs =
+                    self.backend.generation_timeout_secs.saturating_add(60).min(7200)
+            }
+            36 => self.ui.palette_colors = (self.ui.palette_colors + 1).min(MAX_PALETTE_COLORS),
+            22 => {
+                self.ui.auto_clear_completed_secs = Some(match self.ui.auto_clear_completed_secs {
+                    Some(secs) => secs.saturating_add(30).min(3600),
+                    None => 30,
+                })
+            }
+            _ => return,
         }
+        self.dirty = true;
     }
 
     /// Decrement a numeric setting.
@@ -446,13 +902,38 @@ impl SettingsState {
             16 => {
                 self.backend.retry_attempts = self.backend.retry_attempts.saturating_sub(1).max(0)
             }
-            _ => {}
+            20 => self.ui.image_load_retries = self.ui.image_load_retries.saturating_sub(1),
+            24 => {
+                self.backend.max_concurrent_jobs =
+                    self.backend.max_concurrent_jobs.saturating_sub(1).max(1)
+            }
+            29 => self.ui.sixel_cache_mb = self.ui.sixel_cache_mb.saturating_sub(10).max(10),
+            32 => self.ui.sixel_colors = (self.ui.sixel_colors / 2).max(2),
+            33 => self.ui.unicode_render_mode = self.ui.unicode_render_mode.previous(),
+            35 => {
+                self.backend.generation_timeout_secs =
+                    self.backend.generation_timeout_secs.saturating_sub(60)
+            }
+            36 => self.ui.palette_colors = self.ui.palette_colors.saturating_sub(1),
+            22 => {
+                self.ui.auto_clear_completed_secs = self
+                    .ui
+                    .auto_clear_completed_secs
+                    .and_then(|secs| secs.checked_sub(30).filter(|&secs| secs > 0))
+            }
+            _ => return,
         }
+        self.dirty = true;
     }
 
     /// Get the name of the currently selected setting.
     pub fn selected_setting_name(&self) -> &'static str {
-        match self.selected_index {
+        Self::setting_name(self.selected_index)
+    }
+
+    /// Display name of a setting.
+    pub fn setting_name(index: usize) -> &'static str {
+        match index {
             0 => "Default Model",
             1 => "Default Steps",
             2 => "Default CFG Scale",
@@ -471,9 +952,177 @@ impl SettingsState {
             15 => "Timeout (seconds)",
             16 => "Retry Attempts",
             17 => "Output Directory",
+            18 => "Gallery Labels",
+            19 => "Gallery Borders",
+            20 => "Image Load Retries",
+            21 => "Gallery Generation Time",
+            22 => "Auto-clear Finished Jobs",
+            23 => "Sixel Background",
+            24 => "Max Concurrent Jobs",
+            25 => "Generate on Paste",
+            26 => "Language",
+            27 => "Prompt Diff",
+            28 => "Auto-select Newest Image",
+            29 => "Sixel Cache Size (MB)",
+            30 => "Cache Directory",
+            31 => "Models Directory",
+            32 => "Sixel Colors",
+            33 => "Unicode Render Mode",
+            34 => "Unicode Dithering",
+            35 => "Generation Timeout (seconds)",
+            36 => "Palette Colors",
             _ => "Unknown",
         }
     }
+
+    /// Whether a setting is a directory (edited with the directory browser).
+    pub fn is_path_setting(index: usize) -> bool {
+        matches!(index, 17 | 30 | 31)
+    }
+
+    /// Settings whose current value differs from the default.
+    pub fn changed_settings(&self) -> Vec<SettingChange> {
+        let defaults = Self::default();
+        (0..SETTINGS_COUNT)
+            .filter_map(|index| {
+                let current = self.setting_value(index);
+                let default = defaults.setting_value(index);
+                (current != default).then(|| SettingChange {
+                    index,
+                    name: Self::setting_name(index),
+                    current,
+                    default,
+                })
+            })
+            .collect()
+    }
+
+    /// Reset a single setting to its default value.
+    pub fn reset_setting(&mut self, index: usize) -> Result<()> {
+        let selected = self.selected_index;
+        self.selected_index = index;
+        self.edit_buffer = Self::default().setting_value(index);
+        self.is_editing = true;
+
+        let result = self.finish_editing();
+        if result.is_err() {
+            self.cancel_editing();
+        }
+        self.selected_index = selected;
+        result
+    }
+
+    /// Open or close the changed-settings view.
+    pub fn toggle_changes_view(&mut self) {
+        self.show_changes = !self.show_changes;
+        self.changes_index = 0;
+    }
+
+    /// Select the next row in the changed-settings view.
+    pub fn next_change(&mut self) {
+        let count = self.changed_settings().len();
+        if self.changes_index + 1 < count {
+            self.changes_index += 1;
+        }
+    }
+
+    /// Select the previous row in the changed-settings view.
+    pub fn previous_change(&mut self) {
+        self.changes_index = self.changes_index.saturating_sub(1);
+    }
+
+    /// Reset the setting selected in the changed-settings view.
+    pub fn reset_selected_change(&mut self) -> Result<()> {
+        let Some(change) = self.changed_settings().into_iter().nth(self.changes_index) else {
+            return Ok(());
+        };
+        self.reset_setting(change.index)?;
+
+        // Keep the selection on a remaining row
+        let remaining = self.changed_settings().len();
+        self.changes_index = self.changes_index.min(remaining.saturating_sub(1));
+        Ok(())
+    }
+
+    /// One-line description (with valid range) of the currently selected setting.
+    pub fn selected_setting_help(&self) -> &'static str {
+        match self.selected_index {
+            0 => "Model used for new generations (name as listed on the Models screen)",
+            1 => "Denoising steps per image; more is slower but more detailed (10-100, step 5)",
+            2 => "How closely images follow the prompt (1.0-20.0, step 0.5)",
+            3 => "Output width in pixels (256-2048, step 64)",
+            4 => "Output height in pixels (256-2048, step 64)",
+            5 => "Sampler used for denoising (e.g. euler, euler_a, dpmpp_2m)",
+            6 => "Images generated per job (1-10)",
+            7 => "Color theme name",
+            8 => "Maximum redraws per second (10-120, step 10)",
+            9 => "Add new generations to the gallery automatically (on/off)",
+            10 => "Show image previews in the terminal via Sixel (on/off)",
+            11 => "Largest preview width in pixels (128-1024, step 64)",
+            12 => "Largest preview height in pixels (128-1024, step 64)",
+            13 => "Host or IP address of the backend's ZeroMQ server",
+            14 => "Backend request port; updates use the next port up (1024-65535)",
+            15 => "Seconds to wait for a backend reply (5-300, step 5)",
+            16 => "Times a failed backend request is retried (0-10)",
+            17 => "Directory where generated images are saved",
+            18 => "Show filenames under gallery grid cells (on/off)",
+            19 => "Draw borders around gallery grid cells (on/off)",
+            20 => "Retries for preview images that fail to load (0-10)",
+            21 => "Show generation time badges in the gallery (on/off)",
+            22 => "Seconds before finished jobs leave the queue, or off (30-3600, step 30)",
+            23 => "Color behind transparent pixels in Sixel previews (name like Black or #RRGGBB)",
+            24 => "Jobs sent to the backend at once; extra jobs wait in a local queue (1-16)",
+            25 => "Submit a pasted prompt right away; typed text is never submitted (on/off)",
+            26 => "UI language code, e.g. en or es; applies on restart",
+            27 => "Highlight words changed since the last submitted prompt (on/off)",
+            28 => "Jump to new gallery images unless you navigated in the last 5 seconds (on/off)",
+            29 => "Memory kept for rendered image previews; least recently shown are dropped first",
+            30 => "Directory for temporary files",
+            31 => "Directory the backend loads models from",
+            32 => "Palette size for Sixel previews; fewer colors draw faster (2-256)",
+            33 => "Characters for previews without Sixel (half_block, quarter_block, shade)",
+            34 => "Dither previews without Sixel for smoother gradients (on/off)",
+            35 => "Seconds before an unfinished job fails and is cancelled, or off (step 60)",
+            36 => "Most common colors shown under the gallery preview, or off (0-16)",
+            _ => "",
+        }
+    }
+}
+
+/// Push onto an undo/redo stack, dropping the oldest entry past [`MAX_UNDO`].
+fn push_capped(stack: &mut Vec<(usize, String)>, entry: (usize, String)) {
+    stack.push(entry);
+    if stack.len() > MAX_UNDO {
+        stack.remove(0);
+    }
+}
+
+/// Check that `host` is an IP address or a well-formed hostname.
+///
+/// The name isn't looked up here: that would block the frame on DNS.
+/// `systems::host_check` looks it up in the background and warns if it
+/// doesn't resolve.
+fn validate_host(host: &str) -> Result<()> {
+    use std::net::IpAddr;
+
+    if host.parse::<IpAddr>().is_ok() {
+        return Ok(());
+    }
+
+    // RFC 1123 labels: letters, digits and inner hyphens, 1-63 chars each
+    let well_formed = !host.is_empty()
+        && host.len() <= 253
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    if !well_formed {
+        anyhow::bail!("Invalid host: {:?}", host);
+    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -488,6 +1137,52 @@ mod tests {
         assert_eq!(settings.backend.zmq_port, 5555);
     }
 
+    #[test]
+    fn test_every_setting_has_help() {
+        let mut settings = SettingsState::default();
+        for index in 0..SETTINGS_COUNT {
+            settings.selected_index = index;
+            assert!(
+                !settings.selected_setting_help().is_empty(),
+                "setting {} ({}) has no help",
+                index,
+                settings.selected_setting_name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_changed_settings_lists_only_changes() {
+        let mut settings = SettingsState::default();
+        assert!(settings.changed_settings().is_empty());
+
+        settings.generation.default_steps = 50;
+        settings.ui.fps_limit = 30;
+
+        let changes = settings.changed_settings();
+        assert_eq!(
+            changes,
+            vec![
+                SettingChange {
+                    index: 1,
+                    name: "Default Steps",
+                    current: "50".to_string(),
+                    default: "30".to_string(),
+                },
+                SettingChange {
+                    index: 8,
+                    name: "FPS Limit",
+                    current: "30".to_string(),
+                    default: "60".to_string(),
+                },
+            ]
+        );
+
+        settings.reset_setting(1).unwrap();
+        assert_eq!(settings.generation.default_steps, 30);
+        assert_eq!(settings.changed_settings().len(), 1);
+    }
+
     #[test]
     fn test_navigation() {
         let mut settings = SettingsState::default();
@@ -510,8 +1205,10 @@ mod tests {
         settings.selected_index = 9; // auto_refresh_gallery
 
         let initial = settings.ui.auto_refresh_gallery;
+        assert!(!settings.dirty);
         settings.toggle_boolean();
         assert_eq!(settings.ui.auto_refresh_gallery, !initial);
+        assert!(settings.dirty);
     }
 
     #[test]
@@ -547,6 +1244,97 @@ mod tests {
         assert_eq!(settings.generation.default_steps, 50);
     }
 
+    fn edit(settings: &mut SettingsState, index: usize, value: &str) {
+        settings.selected_index = index;
+        settings.start_editing();
+        settings.edit_buffer = value.to_string();
+        settings.finish_editing().unwrap();
+    }
+
+    #[test]
+    fn test_undo_redo_edit() {
+        let mut settings = SettingsState::default();
+        edit(&mut settings, 1, "50"); // default_steps
+
+        assert!(settings.undo().unwrap());
+        assert_eq!(settings.generation.default_steps, 30);
+        assert!(!settings.undo().unwrap());
+
+        assert!(settings.redo().unwrap());
+        assert_eq!(settings.generation.default_steps, 50);
+        assert!(!settings.redo().unwrap());
+
+        // A new edit after undoing drops the redo history
+        settings.undo().unwrap();
+        edit(&mut settings, 1, "40");
+        assert!(!settings.redo().unwrap());
+    }
+
+    #[test]
+    fn test_undo_multiple_fields_in_order() {
+        let mut settings = SettingsState::default();
+        edit(&mut settings, 1, "50"); // default_steps
+        edit(&mut settings, 8, "30"); // fps_limit
+        edit(&mut settings, 1, "70");
+        // Re-entering the same value isn't an edit
+        edit(&mut settings, 8, "30");
+        assert_eq!(settings.undo_stack.len(), 3);
+
+        settings.undo().unwrap();
+        assert_eq!((settings.generation.default_steps, settings.ui.fps_limit), (50, 30));
+        settings.undo().unwrap();
+        assert_eq!((settings.generation.default_steps, settings.ui.fps_limit), (50, 60));
+        settings.undo().unwrap();
+        assert_eq!((settings.generation.default_steps, settings.ui.fps_limit), (30, 60));
+        // Undo doesn't move the selection
+        assert_eq!(settings.selected_index, 8);
+
+        for steps in 0..MAX_UNDO + 10 {
+            edit(&mut settings, 1, &(steps + 100).to_string());
+        }
+        assert_eq!(settings.undo_stack.len(), MAX_UNDO);
+    }
+
+    #[test]
+    fn test_zmq_host_and_port_are_validated() {
+        let mut settings = SettingsState::default();
+        let host = settings.backend.zmq_host.clone();
+        let port = settings.backend.zmq_port;
+
+        settings.selected_index = 13; // zmq_host
+        settings.start_editing();
+        settings.edit_buffer = "not a host".to_string();
+        assert!(settings.finish_editing().is_err());
+        assert!(settings.is_editing);
+        assert_eq!(settings.backend.zmq_host, host);
+        assert!(settings.last_error.as_deref().unwrap().contains("Invalid host"));
+
+        settings.edit_buffer = "192.168.1.5".to_string();
+        settings.finish_editing().unwrap();
+        assert_eq!(settings.backend.zmq_host, "192.168.1.5");
+        assert_eq!(settings.last_error, None);
+
+        // Hostnames aren't looked up while editing, so one that doesn't resolve is kept
+        settings.start_editing();
+        settings.edit_buffer = "gpu-box.invalid".to_string();
+        settings.finish_editing().unwrap();
+        assert_eq!(settings.backend.zmq_host, "gpu-box.invalid");
+
+        settings.selected_index = 14; // zmq_port
+        settings.start_editing();
+        settings.edit_buffer = "999999".to_string();
+        assert!(settings.finish_editing().is_err());
+        assert!(settings.is_editing);
+        assert_eq!(settings.backend.zmq_port, port);
+        assert!(settings.last_error.is_some());
+
+        settings.edit_buffer = "80".to_string();
+        assert!(settings.finish_editing().is_err());
+        settings.edit_buffer = "6000".to_string();
+        settings.finish_editing().unwrap();
+        assert_eq!(settings.backend.zmq_port, 6000);
+    }
+
     #[test]
     fn test_cancel_editing() {
         let mut settings = SettingsState::default();
@@ -586,4 +1374,73 @@ mod tests {
         );
         assert_eq!(settings.ui.fps_limit, deserialized.ui.fps_limit);
     }
+
+    #[test]
+    fn test_old_config_without_gallery_options() {
+        let mut settings = toml::to_string(&SettingsState::default()).unwrap();
+        settings = settings
+            .lines()
+            .filter(|line| !line.starts_with("gallery_show_"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let loaded: SettingsState = toml::from_str(&settings).unwrap();
+        assert!(loaded.ui.gallery_show_labels);
+        assert!(loaded.ui.gallery_show_borders);
+    }
+
+    #[test]
+    fn test_backend_zmq_config() {
+        let mut backend = BackendSettings::default();
+        let config = backend.zmq_config();
+        assert_eq!(config, ZmqConfig::default());
+
+        backend.zmq_host = "*".to_string();
+        backend.zmq_pattern = RequestPattern::DealerRouter;
+        backend.zmq_socket_mode = SocketMode::Bind;
+        let config = backend.zmq_config();
+        assert_eq!(config.req_addr, "tcp://*:5555");
+        assert_eq!(config.pub_addr, "tcp://*:5556");
+        assert!(config.validate().is_ok());
+
+        let toml = toml::to_string(&backend).unwrap();
+        assert!(toml.contains("zmq_pattern = \"dealer_router\""));
+        assert!(toml.contains("zmq_socket_mode = \"bind\""));
+    }
+
+    #[test]
+    fn test_unwritable_output_dir_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut paths = PathSettings {
+            output_dir: dir.path().join("output"),
+            cache_dir: dir.path().join("cache"),
+            ..PathSettings::default()
+        };
+        paths.ensure_writable().unwrap();
+        assert!(paths.output_dir.is_dir());
+        assert_eq!(fs::read_dir(&paths.output_dir).unwrap().count(), 0);
+
+        // A file where a parent directory should be can't be written by anyone
+        let file = dir.path().join("not-a-dir");
+        fs::write(&file, "").unwrap();
+        paths.output_dir = file.join("output");
+        let message = format!("{:#}", paths.ensure_writable().unwrap_err());
+        assert!(message.starts_with("Output directory"), "{}", message);
+        assert!(message.contains("not-a-dir"), "{}", message);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let read_only = dir.path().join("read-only");
+            fs::create_dir(&read_only).unwrap();
+            fs::set_permissions(&read_only, fs::Permissions::from_mode(0o555)).unwrap();
+            paths.output_dir = read_only.clone();
+            // Permissions don't apply to root
+            if fs::write(read_only.join("probe"), "").is_err() {
+                let message = format!("{:#}", paths.ensure_writable().unwrap_err());
+                assert!(message.contains("Failed to write a test file"), "{}", message);
+            }
+            fs::set_permissions(&read_only, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+    }
 }
@@ -0,0 +1,117 @@
+//! # UI Strings Resource
+//!
+//! User-facing labels looked up by key, so the UI can be translated.
+//! Tables are flat TOML files of `key = "text"` in
+//! `~/.config/dgx-pixels/lang/<language>.toml`, selected by `ui.language`:
+//!
+//! ```toml
+//! "generation.generate_button" = " [G]enerar "
+//! ```
+//!
+//! Keys missing from a table (or a missing table) fall back to the built-in
+//! English text.
+
+use anyhow::{Context, Result};
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Built-in English labels.
+const ENGLISH: &[(&str, &str)] = &[
+    ("generation.prompt_title", " Prompt "),
+    ("generation.prompt_placeholder", "Enter your prompt here..."),
+    ("generation.negative_title", " Negative Prompt [Ctrl+N] "),
+    ("generation.negative_placeholder", "Things to avoid (e.g. blurry, text)..."),
+    ("generation.options_title", " Generation Options "),
+    ("generation.generate_button", " [G]enerate "),
+    ("generation.cancel_button", " [X] Cancel Job "),
+    ("generation.compare_button", " [C]ompare Models "),
+    ("generation.regenerate_button", " [R]egenerate "),
+    ("generation.recent_title", " Recent Generations "),
+    ("generation.no_recent", "No recent generations"),
+    ("generation.no_previous", "No previous image"),
+    ("generation.previous_title", " Previous "),
+    ("generation.current_title", " Current "),
+    ("generation.presets_title", " Prompt Presets "),
+    ("generation.no_presets", "No presets - add them to presets.toml"),
+    ("generation.sweep_title", " Seed Sweep "),
+];
+
+/// Localized UI labels for the selected language.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct Strings {
+    /// Translations; keys not listed use [`ENGLISH`]
+    table: HashMap<String, String>,
+}
+
+impl Strings {
+    /// Directory holding language tables.
+    pub fn lang_dir() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from(".config"))
+            .join("dgx-pixels")
+            .join("lang")
+    }
+
+    /// Strings for `language`, falling back to English if its table is
+    /// missing or unreadable.
+    pub fn load(language: &str) -> Self {
+        if language.is_empty() || language == "en" {
+            return Self::default();
+        }
+
+        let path = Self::lang_dir().join(format!("{}.toml", language));
+        match Self::load_from(&path) {
+            Ok(strings) => strings,
+            Err(e) => {
+                warn!("Using English UI strings: {:#}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Strings from the language table at `path`.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read language table: {:?}", path))?;
+        let table = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse language table: {:?}", path))?;
+        Ok(Self { table })
+    }
+
+    /// Label for `key`: the translation if there is one, else English.
+    ///
+    /// Unknown keys are returned as-is so they stand out in the UI.
+    pub fn t<'a>(&'a self, key: &'a str) -> &'a str {
+        self.table
+            .get(key)
+            .map(String::as_str)
+            .or_else(|| {
+                ENGLISH
+                    .iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, text)| *text)
+            })
+            .unwrap_or(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translation_overrides_and_falls_back_to_english() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("es.toml");
+        fs::write(&path, "\"generation.generate_button\" = \" [G]enerar \"\n").unwrap();
+
+        let strings = Strings::load_from(&path).unwrap();
+        assert_eq!(strings.t("generation.generate_button"), " [G]enerar ");
+        // Not in the table, so English
+        assert_eq!(strings.t("generation.options_title"), " Generation Options ");
+
+        assert_eq!(Strings::default().t("generation.generate_button"), " [G]enerate ");
+    }
+}
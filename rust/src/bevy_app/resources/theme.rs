@@ -1,9 +1,30 @@
 //! # Theme Resource
 //!
 //! Application theme as a Bevy resource for consistent styling across all render systems.
+//!
+//! Besides the built-in default, themes can be loaded from
+//! `~/.config/dgx-pixels/themes/<name>.toml`. Any color a theme file leaves
+//! out keeps its default; colors are ratatui names (`cyan`, `light-blue`),
+//! `#rrggbb` or a 0-255 palette index:
+//!
+//! ```toml
+//! header = "magenta"
+//! border = "#5f87af"
+//! highlight = "light-magenta"
+//! warning = "yellow"
+//! ```
+//!
+//! The Settings screen's theme picker (`t`) applies one live.
 
+use anyhow::{Context, Result};
 use bevy::prelude::*;
 use ratatui::style::{Color as RatatuiColor, Modifier, Style};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Name of the built-in theme (no file needed).
+pub const DEFAULT_THEME_NAME: &str = "Default";
 
 /// Application theme resource providing consistent colors and styles.
 #[derive(Resource, Clone, Debug)]
@@ -14,6 +35,12 @@ pub struct AppTheme {
 /// Theme color definitions matching the classic ui/theme.rs design.
 #[derive(Clone, Debug)]
 pub struct ThemeColors {
+    /// Header text color (Cyan)
+    pub header: RatatuiColor,
+    /// Border color (Cyan)
+    pub border: RatatuiColor,
+    /// Highlight/selection background (Cyan)
+    pub highlight: RatatuiColor,
     /// Primary color (Cyan) - Active elements, highlights
     pub primary: RatatuiColor,
     /// Secondary color (Yellow) - Warnings, notifications
@@ -36,6 +63,9 @@ impl Default for AppTheme {
     fn default() -> Self {
         Self {
             colors: ThemeColors {
+                header: RatatuiColor::Cyan,
+                border: RatatuiColor::Cyan,
+                highlight: RatatuiColor::Cyan,
                 primary: RatatuiColor::Cyan,
                 secondary: RatatuiColor::Yellow,
                 success: RatatuiColor::Green,
@@ -49,7 +79,100 @@ impl Default for AppTheme {
     }
 }
 
+/// Layout of a theme file; every color is optional.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeFile {
+    pub header: Option<String>,
+    pub border: Option<String>,
+    pub highlight: Option<String>,
+    pub text: Option<String>,
+    pub muted: Option<String>,
+    pub success: Option<String>,
+    pub error: Option<String>,
+    pub warning: Option<String>,
+}
+
+impl ThemeFile {
+    /// The default theme with this file's colors applied.
+    pub fn to_theme(&self) -> Result<AppTheme> {
+        let mut theme = AppTheme::default();
+        let colors = &mut theme.colors;
+        for (name, value, slot) in [
+            ("header", &self.header, &mut colors.header),
+            ("border", &self.border, &mut colors.border),
+            ("highlight", &self.highlight, &mut colors.highlight),
+            ("text", &self.text, &mut colors.text),
+            ("muted", &self.muted, &mut colors.muted),
+            ("success", &self.success, &mut colors.success),
+            ("error", &self.error, &mut colors.error),
+            ("warning", &self.warning, &mut colors.secondary),
+        ] {
+            if let Some(value) = value {
+                *slot = RatatuiColor::from_str(value)
+                    .map_err(|_| anyhow::anyhow!("Invalid {} color: {:?}", name, value))?;
+            }
+        }
+        Ok(theme)
+    }
+}
+
 impl AppTheme {
+    /// Directory theme files are read from.
+    pub fn themes_dir() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from(".config"))
+            .join("dgx-pixels")
+            .join("themes")
+    }
+
+    /// Load `<name>.toml` from the themes directory.
+    pub fn load_named(name: &str) -> Result<Self> {
+        Self::load_from(&Self::themes_dir().join(format!("{}.toml", name)))
+    }
+
+    /// Load a theme file.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read theme file: {:?}", path))?;
+        let file: ThemeFile = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse theme file: {:?}", path))?;
+        file.to_theme()
+            .with_context(|| format!("Invalid theme file: {:?}", path))
+    }
+
+    /// The theme called `name`, falling back to the built-in default if it
+    /// can't be loaded.
+    pub fn named(name: &str) -> Self {
+        Self::named_in(&Self::themes_dir(), name)
+    }
+
+    /// [`AppTheme::named`], reading theme files from `dir`.
+    pub fn named_in(dir: &Path, name: &str) -> Self {
+        if name.is_empty() || name.eq_ignore_ascii_case(DEFAULT_THEME_NAME) {
+            return Self::default();
+        }
+        Self::load_from(&dir.join(format!("{}.toml", name))).unwrap_or_else(|e| {
+            warn!("Failed to load theme {:?}, using the default: {:#}", name, e);
+            Self::default()
+        })
+    }
+
+    /// Names of the theme files in `dir`, sorted; a missing directory has none.
+    pub fn available_themes(dir: &Path) -> Vec<String> {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        let mut names: Vec<String> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+            .collect();
+        names.sort();
+        names
+    }
+
     /// Default text style
     pub fn text(&self) -> Style {
         Style::default().fg(self.colors.text)
@@ -58,7 +181,7 @@ impl AppTheme {
     /// Header style (bold cyan)
     pub fn header(&self) -> Style {
         Style::default()
-            .fg(self.colors.primary)
+            .fg(self.colors.header)
             .add_modifier(Modifier::BOLD)
     }
 
@@ -73,7 +196,7 @@ impl AppTheme {
     pub fn highlight(&self) -> Style {
         Style::default()
             .fg(RatatuiColor::Black)
-            .bg(self.colors.primary)
+            .bg(self.colors.highlight)
             .add_modifier(Modifier::BOLD)
     }
 
@@ -86,7 +209,7 @@ impl AppTheme {
 
     /// Border style (cyan)
     pub fn border(&self) -> Style {
-        Style::default().fg(self.colors.primary)
+        Style::default().fg(self.colors.border)
     }
 
     /// Input field style (white on black)
@@ -142,6 +265,50 @@ impl AppTheme {
     }
 }
 
+/// State of the Settings screen's theme picker.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ThemePicker {
+    /// Whether the picker overlay is showing
+    pub open: bool,
+    /// Theme names listed: the built-in default, then the theme files
+    pub themes: Vec<String>,
+    /// Highlighted theme
+    pub selected: usize,
+}
+
+impl ThemePicker {
+    /// Show the picker listing the themes in `dir`, with `current` highlighted.
+    pub fn open_in(&mut self, dir: &Path, current: &str) {
+        self.themes = std::iter::once(DEFAULT_THEME_NAME.to_string())
+            .chain(AppTheme::available_themes(dir))
+            .collect();
+        self.selected = self.themes.iter().position(|t| t == current).unwrap_or(0);
+        self.open = true;
+    }
+
+    /// Hide the picker.
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Highlight the next theme.
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.themes.len() {
+            self.selected += 1;
+        }
+    }
+
+    /// Highlight the previous theme.
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// The highlighted theme's name.
+    pub fn selected_theme(&self) -> Option<&str> {
+        self.themes.get(self.selected).map(String::as_str)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,4 +361,37 @@ mod tests {
         let disabled = theme.button_disabled();
         assert_eq!(disabled.fg, Some(RatatuiColor::DarkGray));
     }
+
+    #[test]
+    fn test_load_theme_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("dusk.toml"),
+            "header = \"magenta\"\nborder = \"#5f87af\"\nhighlight = \"light-magenta\"\nwarning = \"208\"\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("broken.toml"), "header = \"not-a-color\"\n").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "").unwrap();
+
+        assert_eq!(AppTheme::available_themes(dir.path()), vec!["broken", "dusk"]);
+
+        let theme = AppTheme::named_in(dir.path(), "dusk");
+        assert_eq!(theme.header().fg, Some(RatatuiColor::Magenta));
+        assert_eq!(theme.border().fg, Some(RatatuiColor::Rgb(0x5f, 0x87, 0xaf)));
+        assert_eq!(theme.highlight().bg, Some(RatatuiColor::LightMagenta));
+        assert_eq!(theme.warning().fg, Some(RatatuiColor::Indexed(208)));
+        // Colors the file leaves out keep their defaults
+        assert_eq!(theme.colors.text, RatatuiColor::White);
+
+        // Bad colors, bad TOML and missing files fall back to the default
+        assert!(AppTheme::load_from(&dir.path().join("broken.toml")).is_err());
+        let fallback = AppTheme::named_in(dir.path(), "broken");
+        assert_eq!(fallback.header().fg, Some(RatatuiColor::Cyan));
+        assert_eq!(AppTheme::named_in(dir.path(), "missing").colors.border, RatatuiColor::Cyan);
+
+        let mut picker = ThemePicker::default();
+        picker.open_in(dir.path(), "dusk");
+        assert_eq!(picker.themes, vec![DEFAULT_THEME_NAME, "broken", "dusk"]);
+        assert_eq!(picker.selected_theme(), Some("dusk"));
+    }
 }
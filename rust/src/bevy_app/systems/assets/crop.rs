@@ -0,0 +1,252 @@
+//! # Image Cropping
+//!
+//! Crops gallery images to a sprite region and draws the crop selection
+//! onto previews so it shows up in Sixel and Unicode renders alike. Also
+//! cuts out the visible window of the gallery zoom view.
+
+use anyhow::{Context, Result};
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension};
+use image::DynamicImage;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::bevy_app::resources::{CropRect, GalleryZoom};
+
+/// Outline color drawn around the crop selection (RGBA).
+const OUTLINE_COLOR: [u8; 4] = [255, 0, 255, 255];
+
+/// Crop `image` to `rect`.
+///
+/// The rectangle is clamped to the image bounds, so the result is never
+/// larger than the source.
+pub fn crop_image(image: &DynamicImage, rect: CropRect) -> DynamicImage {
+    image.crop_imm(rect.x, rect.y, rect.width, rect.height)
+}
+
+/// Crop the image at `source` and write the region next to it as a PNG.
+///
+/// The output is named `<stem>_crop_<w>x<h>.png`, with a numeric suffix if
+/// that file already exists. Returns the path written.
+pub fn crop_to_file(source: &Path, rect: CropRect) -> Result<PathBuf> {
+    let image = image::open(source)
+        .with_context(|| format!("Failed to open image for cropping: {:?}", source))?;
+
+    let cropped = crop_image(&image, rect);
+    let output = crop_output_path(source, cropped.width(), cropped.height());
+
+    cropped
+        .save_with_format(&output, image::ImageFormat::Png)
+        .with_context(|| format!("Failed to write cropped image: {:?}", output))?;
+
+    info!("Cropped {:?} to {:?}", source, output);
+    Ok(output)
+}
+
+/// Pick an unused file name for a crop of `source`.
+fn crop_output_path(source: &Path, width: u32, height: u32) -> PathBuf {
+    let dir = source.parent().unwrap_or_else(|| Path::new("."));
+    let stem = source
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "image".to_string());
+
+    let base = format!("{}_crop_{}x{}", stem, width, height);
+    let mut path = dir.join(format!("{}.png", base));
+    let mut n = 1;
+    while path.exists() {
+        path = dir.join(format!("{}_{}.png", base, n));
+        n += 1;
+    }
+    path
+}
+
+/// Copy of a (RGBA8) Bevy image with the crop selection outlined.
+///
+/// The outline is drawn inside the selection and scales with the image so it
+/// stays visible after the preview is downsized.
+pub fn image_with_crop_outline(image: &Image, rect: CropRect) -> Image {
+    let mut outlined = image.clone();
+    let (width, height) = (image.width(), image.height());
+    let thickness = (width.min(height) / 128).max(1);
+
+    let x_end = (rect.x + rect.width).min(width);
+    let y_end = (rect.y + rect.height).min(height);
+
+    for y in rect.y..y_end {
+        for x in rect.x..x_end {
+            let on_edge = x < rect.x + thickness
+                || x + thickness >= x_end
+                || y < rect.y + thickness
+                || y + thickness >= y_end;
+            if !on_edge {
+                continue;
+            }
+
+            let offset = ((y * width + x) * 4) as usize;
+            if let Some(pixel) = outlined.data.get_mut(offset..offset + 4) {
+                pixel.copy_from_slice(&OUTLINE_COLOR);
+            }
+        }
+    }
+
+    outlined
+}
+
+/// The part of a (RGBA8) Bevy image visible in the zoom view, scaled for display.
+///
+/// `viewport` is the display area in pixels. The window is taken at the
+/// zoom's offset and scaled by nearest neighbour so pixel art stays crisp;
+/// the result is never larger than `viewport`.
+pub fn zoomed_window(image: &Image, zoom: &GalleryZoom, viewport: (u32, u32)) -> Image {
+    let (width, height) = (image.width(), image.height());
+    let (window_w, window_h) = zoom.window((width, height), viewport);
+    let out_w = ((window_w as f32 * zoom.scale) as u32).clamp(1, viewport.0.max(1));
+    let out_h = ((window_h as f32 * zoom.scale) as u32).clamp(1, viewport.1.max(1));
+
+    let mut data = Vec::with_capacity((out_w * out_h * 4) as usize);
+    for y in 0..out_h {
+        let src_y = (zoom.offset_y + (y as f32 / zoom.scale) as u32).min(height.saturating_sub(1));
+        for x in 0..out_w {
+            let src_x = (zoom.offset_x + (x as f32 / zoom.scale) as u32).min(width.saturating_sub(1));
+            let offset = ((src_y * width + src_x) * 4) as usize;
+            match image.data.get(offset..offset + 4) {
+                Some(pixel) => data.extend_from_slice(pixel),
+                None => data.extend_from_slice(&[0, 0, 0, 0]),
+            }
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: out_w,
+            height: out_h,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        image.texture_descriptor.format,
+        image.asset_usage,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    /// 16x16 fixture where each pixel encodes its own coordinates.
+    fn fixture() -> DynamicImage {
+        DynamicImage::ImageRgba8(RgbaImage::from_fn(16, 16, |x, y| {
+            Rgba([x as u8 * 16, y as u8 * 16, 0, 255])
+        }))
+    }
+
+    #[test]
+    fn test_crop_image_region() {
+        let rect = CropRect {
+            x: 4,
+            y: 2,
+            width: 8,
+            height: 6,
+        };
+        let cropped = crop_image(&fixture(), rect).to_rgba8();
+
+        assert_eq!(cropped.dimensions(), (8, 6));
+        // Top-left corner is source pixel (4, 2)
+        assert_eq!(cropped.get_pixel(0, 0), &Rgba([64, 32, 0, 255]));
+        // Bottom-right corner is source pixel (11, 7)
+        assert_eq!(cropped.get_pixel(7, 5), &Rgba([176, 112, 0, 255]));
+    }
+
+    #[test]
+    fn test_crop_to_file_writes_new_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("knight.png");
+        fixture().save(&source).unwrap();
+
+        let rect = CropRect {
+            x: 0,
+            y: 0,
+            width: 4,
+            height: 4,
+        };
+        let first = crop_to_file(&source, rect).unwrap();
+        let second = crop_to_file(&source, rect).unwrap();
+
+        assert_eq!(first, dir.path().join("knight_crop_4x4.png"));
+        assert_eq!(second, dir.path().join("knight_crop_4x4_1.png"));
+        assert_eq!(image::image_dimensions(&first).unwrap(), (4, 4));
+    }
+
+    #[test]
+    fn test_crop_outline_marks_edges_only() {
+        use bevy::render::render_asset::RenderAssetUsages;
+        use bevy::render::render_resource::TextureFormat;
+
+        let image = Image::new_fill(
+            Extent3d {
+                width: 8,
+                height: 8,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[0, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        let rect = CropRect {
+            x: 2,
+            y: 2,
+            width: 4,
+            height: 4,
+        };
+        let outlined = image_with_crop_outline(&image, rect);
+
+        let pixel = |x: u32, y: u32| {
+            let offset = ((y * 8 + x) * 4) as usize;
+            &outlined.data[offset..offset + 4]
+        };
+        assert_eq!(pixel(2, 2), OUTLINE_COLOR);
+        assert_eq!(pixel(5, 5), OUTLINE_COLOR);
+        assert_eq!(pixel(3, 3), [0, 0, 0, 255]);
+        assert_eq!(pixel(0, 0), [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_zoomed_window_scales_visible_region() {
+        use bevy::render::render_asset::RenderAssetUsages;
+        use bevy::render::render_resource::TextureFormat;
+
+        let source = Image::new(
+            Extent3d {
+                width: 16,
+                height: 16,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            fixture().to_rgba8().into_raw(),
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        let zoom = GalleryZoom {
+            active: true,
+            scale: 2.0,
+            offset_x: 4,
+            offset_y: 2,
+        };
+        let zoomed = zoomed_window(&source, &zoom, (8, 8));
+
+        // A 4x4 window scaled 2x fills the 8x8 viewport
+        assert_eq!((zoomed.width(), zoomed.height()), (8, 8));
+        let pixel = |x: u32, y: u32| {
+            let offset = ((y * 8 + x) * 4) as usize;
+            &zoomed.data[offset..offset + 4]
+        };
+        // Source pixel (4, 2) covers the top-left 2x2 block
+        assert_eq!(pixel(0, 0), [64, 32, 0, 255]);
+        assert_eq!(pixel(1, 1), [64, 32, 0, 255]);
+        // Bottom-right is source pixel (7, 5)
+        assert_eq!(pixel(7, 7), [112, 80, 0, 255]);
+    }
+}
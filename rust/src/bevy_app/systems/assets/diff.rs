@@ -0,0 +1,95 @@
+//! # Image Difference
+//!
+//! Per-pixel difference of two (RGBA8) images for the Comparison screen's
+//! overlay mode: identical pixels are black and the brighter a pixel, the
+//! more the two images differ there.
+
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension};
+
+/// Grayscale absolute difference of `a` and `b`.
+///
+/// Images of different sizes are both resampled (nearest neighbour) to the
+/// smaller width and height. Each output pixel is the mean absolute
+/// difference of the RGB channels; alpha is ignored and the result is opaque.
+pub fn image_diff(a: &Image, b: &Image) -> Image {
+    let width = a.width().min(b.width()).max(1);
+    let height = a.height().min(b.height()).max(1);
+
+    let mut data = Vec::with_capacity((width * height * 4) as usize);
+    for y in 0..height {
+        for x in 0..width {
+            let pa = sample(a, x, y, (width, height));
+            let pb = sample(b, x, y, (width, height));
+            let total: u32 = (0..3).map(|c| pa[c].abs_diff(pb[c]) as u32).sum();
+            let value = (total / 3) as u8;
+            data.extend_from_slice(&[value, value, value, 255]);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        a.texture_descriptor.format,
+        a.asset_usage,
+    )
+}
+
+/// Pixel of `image` at (`x`, `y`) in a `size` resampling of it.
+fn sample(image: &Image, x: u32, y: u32, size: (u32, u32)) -> [u8; 4] {
+    let (width, height) = (image.width(), image.height());
+    let src_x = (x as u64 * width as u64 / size.0 as u64) as u32;
+    let src_y = (y as u64 * height as u64 / size.1 as u64) as u32;
+    let offset = ((src_y * width + src_x) * 4) as usize;
+    match image.data.get(offset..offset + 4) {
+        Some(pixel) => [pixel[0], pixel[1], pixel[2], pixel[3]],
+        None => [0, 0, 0, 0],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::render::render_asset::RenderAssetUsages;
+    use bevy::render::render_resource::TextureFormat;
+
+    fn image(width: u32, height: u32, pixels: &[[u8; 4]]) -> Image {
+        Image::new(
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            pixels.concat(),
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+
+    #[test]
+    fn test_image_diff() {
+        let black = [0, 0, 0, 255];
+        let white = [255, 255, 255, 255];
+        let red = [255, 0, 0, 255];
+
+        // Same size: unchanged pixels are black, the rest show how much they changed
+        let a = image(2, 1, &[black, white]);
+        let b = image(2, 1, &[black, red]);
+        let diff = image_diff(&a, &b);
+        assert_eq!((diff.width(), diff.height()), (2, 1));
+        assert_eq!(diff.data, [[0, 0, 0, 255], [170, 170, 170, 255]].concat());
+
+        // Different sizes: the larger is resampled to the smaller
+        let big = image(4, 2, &[white; 8]);
+        let small = image(2, 1, &[white, black]);
+        let diff = image_diff(&big, &small);
+        assert_eq!((diff.width(), diff.height()), (2, 1));
+        assert_eq!(diff.data, [[0, 0, 0, 255], [255, 255, 255, 255]].concat());
+    }
+}
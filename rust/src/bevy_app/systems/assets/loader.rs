@@ -3,36 +3,166 @@
 //! System to load images from the filesystem as Bevy assets and attach
 //! them to entities with PreviewImage components.
 
+use bevy::asset::{io::AssetReaderError, AssetLoadError, LoadState};
 use bevy::prelude::*;
-use std::path::PathBuf;
-use tracing::{debug, warn};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{debug, info, warn};
 
 use crate::bevy_app::components::{Job, JobStatus, PreviewImage};
-use crate::bevy_app::resources::GalleryState;
+use crate::bevy_app::resources::{GalleryState, SettingsState};
+
+/// Delay before the first retry; doubled per attempt.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+
+/// Upper bound on the delay between retries.
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(2);
+
+/// Fallback retry count when no settings resource exists.
+const DEFAULT_LOAD_RETRIES: u32 = 3;
+
+/// Retry bookkeeping for a preview image that failed to load.
+///
+/// Stays on the entity once attached so the attempt count is bounded across
+/// both missing files and asset server failures.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ImageLoadRetry {
+    /// Image being loaded
+    pub path: PathBuf,
+    /// Retries performed so far
+    pub attempts: u32,
+    /// Elapsed app time at which the next retry fires (`None` when idle)
+    pub retry_at: Option<Duration>,
+    /// Retries exhausted or the error was permanent
+    pub gave_up: bool,
+}
+
+impl ImageLoadRetry {
+    /// Create idle retry state for an image
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            attempts: 0,
+            retry_at: None,
+            gave_up: false,
+        }
+    }
+
+    fn schedule(&mut self, now: Duration) {
+        self.retry_at = Some(now + retry_delay(self.attempts));
+    }
+}
+
+/// Backoff delay before retry number `attempt` (0-based).
+pub fn retry_delay(attempt: u32) -> Duration {
+    RETRY_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(RETRY_MAX_DELAY)
+}
+
+/// Whether a load failure may be transient (file not there yet, I/O hiccup).
+///
+/// Decode errors and missing loaders will not fix themselves, so they fail
+/// immediately.
+pub fn is_retryable_load_error(err: &AssetLoadError) -> bool {
+    matches!(
+        err,
+        AssetLoadError::AssetReaderError(AssetReaderError::NotFound(_) | AssetReaderError::Io(_))
+    )
+}
+
+fn max_load_retries(settings: Option<&SettingsState>) -> u32 {
+    settings.map_or(DEFAULT_LOAD_RETRIES, |s| s.ui.image_load_retries)
+}
+
+fn attach_preview(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    entity: Entity,
+    path: &Path,
+) {
+    let handle: Handle<Image> = asset_server.load(path.to_path_buf());
+    commands
+        .entity(entity)
+        .insert(PreviewImage::loading(path.to_path_buf(), handle));
+}
 
 /// System to load preview images for completed jobs.
 ///
 /// Listens for jobs with Complete status and loads their images
-/// as Bevy assets, attaching PreviewImage components.
+/// as Bevy assets, attaching PreviewImage components. Images that are not on
+/// disk yet (still being written) are retried with backoff instead.
 pub fn load_preview_images(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    settings: Option<Res<SettingsState>>,
     job_query: Query<(Entity, &Job), Changed<Job>>,
 ) {
     for (entity, job) in job_query.iter() {
         if let JobStatus::Complete { image_path, .. } = &job.status {
+            if !image_path.exists() && max_load_retries(settings.as_deref()) > 0 {
+                debug!("Preview image not on disk yet, will retry: {:?}", image_path);
+                let mut retry = ImageLoadRetry::new(image_path.clone());
+                retry.schedule(time.elapsed());
+                commands.entity(entity).insert(retry);
+                continue;
+            }
+
             debug!("Loading preview image for job {:?}: {:?}", entity, image_path);
+            attach_preview(&mut commands, &asset_server, entity, image_path);
+        }
+    }
+}
+
+/// System to re-attempt scheduled image loads once their backoff expires.
+///
+/// After the last retry the image is handed to the asset server regardless,
+/// so a still-missing file ends up in the usual failed load state.
+pub fn retry_image_loads(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    settings: Option<Res<SettingsState>>,
+    mut retry_query: Query<(Entity, &mut ImageLoadRetry, Option<&PreviewImage>)>,
+) {
+    let max_retries = max_load_retries(settings.as_deref());
+    let now = time.elapsed();
 
-            // Load image as Bevy asset
-            let handle: Handle<Image> = asset_server.load(image_path.clone());
+    for (entity, mut retry, preview) in retry_query.iter_mut() {
+        match retry.retry_at {
+            Some(at) if now >= at => {}
+            _ => continue,
+        }
 
-            // Create or update PreviewImage component
-            let preview = PreviewImage {
-                path: image_path.clone(),
-                asset_handle: Some(handle),
-            };
+        retry.attempts += 1;
+        retry.retry_at = None;
 
-            commands.entity(entity).insert(preview);
+        match preview {
+            // Asset server failure: ask it to load again
+            Some(preview) => {
+                debug!(
+                    "Retrying image load ({}/{}): {:?}",
+                    retry.attempts, max_retries, preview.path
+                );
+                let _: Handle<Image> = asset_server.load(preview.path.clone());
+            }
+            // File wasn't on disk at first load
+            None if retry.path.exists() || retry.attempts >= max_retries => {
+                info!(
+                    "Loading preview image after {} retries: {:?}",
+                    retry.attempts, retry.path
+                );
+                let path = retry.path.clone();
+                attach_preview(&mut commands, &asset_server, entity, &path);
+            }
+            None => {
+                debug!(
+                    "Preview image still missing ({}/{}): {:?}",
+                    retry.attempts, max_retries, retry.path
+                );
+                retry.schedule(now);
+            }
         }
     }
 }
@@ -70,10 +200,7 @@ pub fn load_gallery_images(
 
             let handle: Handle<Image> = asset_server.load(path.clone());
 
-            let preview = PreviewImage {
-                path: path.clone(),
-                asset_handle: Some(handle),
-            };
+            let preview = PreviewImage::loading(path.clone(), handle);
 
             // Spawn entity with preview component
             commands.spawn(preview);
@@ -81,18 +208,40 @@ pub fn load_gallery_images(
     }
 }
 
-/// System to check asset loading status and log warnings for failures.
+/// System to check asset loading status and schedule retries for failures.
+///
+/// Transient failures (see [`is_retryable_load_error`]) are retried up to
+/// `image_load_retries` times; anything else fails immediately.
 pub fn check_asset_loading(
+    mut commands: Commands,
     asset_server: Res<AssetServer>,
-    preview_query: Query<&PreviewImage>,
+    time: Res<Time>,
+    settings: Option<Res<SettingsState>>,
+    preview_query: Query<(Entity, &PreviewImage, Option<&ImageLoadRetry>)>,
 ) {
-    for preview in preview_query.iter() {
+    let max_retries = max_load_retries(settings.as_deref());
+
+    for (entity, preview, retry) in preview_query.iter() {
         if let Some(handle) = &preview.asset_handle {
             match asset_server.load_state(handle) {
-                bevy::asset::LoadState::Failed(err) => {
-                    warn!("Failed to load image {:?}: {:?}", preview.path, err);
+                LoadState::Failed(err) => {
+                    let mut retry = retry
+                        .cloned()
+                        .unwrap_or_else(|| ImageLoadRetry::new(preview.path.clone()));
+                    if retry.gave_up || retry.retry_at.is_some() {
+                        continue;
+                    }
+
+                    if is_retryable_load_error(&err) && retry.attempts < max_retries {
+                        debug!("Image load failed, scheduling retry: {:?}", preview.path);
+                        retry.schedule(time.elapsed());
+                    } else {
+                        warn!("Failed to load image {:?}: {:?}", preview.path, err);
+                        retry.gave_up = true;
+                    }
+                    commands.entity(entity).insert(retry);
                 }
-                bevy::asset::LoadState::Loaded => {
+                LoadState::Loaded => {
                     debug!("Successfully loaded image: {:?}", preview.path);
                 }
                 _ => {
@@ -127,16 +276,70 @@ mod tests {
     #[test]
     fn test_preview_image_with_handle() {
         let mut app = App::new();
-        app.add_plugins(AssetPlugin::default());
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()));
+        app.init_asset::<Image>();
 
         let asset_server = app.world().resource::<AssetServer>();
         let handle = asset_server.load("test.png");
 
-        let preview = PreviewImage {
-            path: PathBuf::from("test.png"),
-            asset_handle: Some(handle),
+        let preview = PreviewImage::loading(PathBuf::from("test.png"), handle);
+
+        assert!(preview.is_loaded());
+    }
+
+    #[test]
+    fn test_retry_delay_backoff() {
+        assert_eq!(retry_delay(0), Duration::from_millis(250));
+        assert_eq!(retry_delay(1), Duration::from_millis(500));
+        assert_eq!(retry_delay(2), Duration::from_secs(1));
+        assert_eq!(retry_delay(10), RETRY_MAX_DELAY);
+    }
+
+    #[test]
+    fn test_retryable_load_errors() {
+        let not_found =
+            AssetLoadError::AssetReaderError(AssetReaderError::NotFound(PathBuf::from("a.png")));
+        assert!(is_retryable_load_error(&not_found));
+
+        let http = AssetLoadError::AssetReaderError(AssetReaderError::HttpError(500));
+        assert!(!is_retryable_load_error(&http));
+    }
+
+    #[test]
+    fn test_missing_image_loaded_after_retry() {
+        let dir = tempfile::tempdir().unwrap();
+        let image_path = dir.path().join("job-1.png");
+
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()));
+        app.init_asset::<Image>();
+        app.init_resource::<Time>();
+        app.add_systems(Update, (load_preview_images, retry_image_loads).chain());
+
+        let mut job = Job::new("job-1".to_string(), "pixel art knight".to_string());
+        job.status = JobStatus::Complete {
+            image_path: image_path.clone(),
+            duration_s: 3.0,
         };
+        let entity = app.world_mut().spawn(job).id();
+
+        // File not written yet: a retry is scheduled instead of a failed preview
+        app.update();
+        assert!(app.world().get::<PreviewImage>(entity).is_none());
+        let retry = app.world().get::<ImageLoadRetry>(entity).unwrap();
+        assert_eq!(retry.attempts, 0);
+        assert!(retry.retry_at.is_some());
+
+        // File appears before the backoff expires
+        std::fs::write(&image_path, b"png").unwrap();
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(retry_delay(0));
+        app.update();
 
+        let preview = app.world().get::<PreviewImage>(entity).unwrap();
+        assert_eq!(preview.path, image_path);
         assert!(preview.is_loaded());
+        assert_eq!(app.world().get::<ImageLoadRetry>(entity).unwrap().attempts, 1);
     }
 }
@@ -7,10 +7,14 @@
 //!
 //! - **loader.rs**: System to load images from filesystem as Bevy assets
 //! - **cache.rs**: LRU cache management for loaded images
+//! - **crop.rs**: Gallery crop tool (region cropping and selection overlay)
+//! - **diff.rs**: Per-pixel difference of two images (Comparison overlay)
+//! - **palette.rs**: Dominant colors of an image (gallery palette swatches)
 //! - **render.rs**: Image rendering utilities for ratatui integration
 //! - **sixel_renderer.rs**: Sixel protocol encoding (T9)
 //! - **preview.rs**: Sixel preview caching system (T9)
 //! - **preview_loader.rs**: (T10) Automatic gallery directory scanning and preview management
+//! - **sidecar.rs**: Per-image generation metadata (`<image>.json`) and its cache
 //!
 //! ## Performance Targets
 //!
@@ -21,18 +25,28 @@
 //! - Directory scan: Every 2 seconds for new images
 
 pub mod cache;
+pub mod crop;
+pub mod diff;
 pub mod loader;
+pub mod palette;
 pub mod preview;
 pub mod preview_loader;
 pub mod render;
+pub mod sidecar;
 pub mod sixel_renderer;
 
 pub use cache::ImageCache;
+pub use crop::{crop_image, crop_to_file, zoomed_window};
+pub use diff::image_diff;
 pub use loader::load_preview_images;
-pub use preview::{SixelCacheEntry, SixelCacheStats, SixelPreviewCache};
+pub use palette::{dominant_colors, Rgb, MAX_PALETTE_COLORS};
+pub use preview::{get_or_render_iterm, SixelCacheEntry, SixelCacheStats, SixelPreviewCache};
 pub use preview_loader::{
-    check_preview_loading, preload_gallery_directory, scan_gallery_directory, GalleryScanState,
-    DEFAULT_GALLERY_DIR, SCAN_INTERVAL_SECS,
+    apply_gallery_dirs, check_preview_loading, preload_gallery_directory, scan_gallery_directory,
+    GalleryScanState, DEFAULT_GALLERY_DIR, SCAN_INTERVAL_SECS,
 };
 pub use render::render_image_to_ascii;
-pub use sixel_renderer::{render_image_sixel, supports_sixel, SixelRenderOptions};
+pub use sidecar::{ImageSidecar, SidecarCache};
+pub use sixel_renderer::{
+    render_image_sixel, supports_iterm, supports_sixel, SixelRenderOptions, CELL_PIXELS,
+};
@@ -0,0 +1,89 @@
+//! # Dominant Colors
+//!
+//! The most common colors of an (RGBA8) image, for the gallery's palette
+//! swatches. Pixels are grouped by the top 5 bits of each channel, so shades
+//! that differ only by compression noise count as one color; each group is
+//! reported as the average of its pixels. Mostly transparent pixels are
+//! skipped.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Most colors the palette swatches can show.
+pub const MAX_PALETTE_COLORS: u32 = 16;
+
+/// An opaque color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// The color as `#rrggbb`.
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// The `n` most common colors of `image` and how many pixels have each.
+///
+/// Sorted by pixel count, most common first (ties by color, so the order is
+/// stable).
+pub fn dominant_colors(image: &Image, n: usize) -> Vec<(Rgb, usize)> {
+    // Channel sums and pixel count per group
+    let mut groups: HashMap<(u8, u8, u8), ([u64; 3], usize)> = HashMap::new();
+    for pixel in image.data.chunks_exact(4) {
+        if pixel[3] < 128 {
+            continue;
+        }
+        let (sums, count) = groups
+            .entry((pixel[0] >> 3, pixel[1] >> 3, pixel[2] >> 3))
+            .or_default();
+        for (sum, &channel) in sums.iter_mut().zip(pixel) {
+            *sum += channel as u64;
+        }
+        *count += 1;
+    }
+
+    let mut colors: Vec<(Rgb, usize)> = groups
+        .into_values()
+        .map(|(sums, count)| {
+            let mean = |sum: u64| (sum / count as u64) as u8;
+            (Rgb(mean(sums[0]), mean(sums[1]), mean(sums[2])), count)
+        })
+        .collect();
+    colors.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    colors.truncate(n);
+    colors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::render::render_asset::RenderAssetUsages;
+    use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+    #[test]
+    fn test_dominant_colors_by_frequency() {
+        let red = [200, 10, 10, 255];
+        let blue = [10, 10, 200, 255];
+        let green = [10, 200, 10, 255];
+        let clear = [255, 255, 255, 0];
+        // A blue a shade off still counts as blue
+        let pixels = [red, blue, red, green, [12, 12, 202, 255], red, clear, clear, clear];
+        let image = Image::new(
+            Extent3d {
+                width: 3,
+                height: 3,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            pixels.concat(),
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+
+        let colors = dominant_colors(&image, 2);
+        assert_eq!(colors, vec![(Rgb(200, 10, 10), 3), (Rgb(11, 11, 201), 2)]);
+        assert_eq!(colors[0].0.hex(), "#c80a0a");
+        assert_eq!(dominant_colors(&image, 8).len(), 3);
+    }
+}
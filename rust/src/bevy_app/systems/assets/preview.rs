@@ -2,18 +2,25 @@
 //!
 //! Bevy ECS-based preview system with Sixel caching.
 //! Replaces the old PreviewManager with a Bevy resource-based approach.
+//! The cache is capped at `ui.sixel_cache_mb`; inserting past the cap drops
+//! the least recently accessed previews first. Previews are keyed by path and
+//! palette size, so changing `ui.sixel_colors` renders them afresh; changing
+//! `ui.sixel_background` clears the cache.
 
 use anyhow::Result;
 use bevy::prelude::*;
 use dashmap::DashMap;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::{debug, info, warn};
 
 use super::sixel_renderer::{render_image_sixel, SixelRenderOptions};
+use crate::bevy_app::resources::SettingsState;
+use crate::sixel::render_iterm_image;
 
-/// Maximum preview cache size in MB
+/// Default preview cache size in MB (`ui.sixel_cache_mb`)
 pub const MAX_CACHE_SIZE_MB: usize = 50;
 
 /// Sixel preview cache entry
@@ -29,65 +36,107 @@ pub struct SixelCacheEntry {
     pub last_access: Instant,
     /// Image dimensions
     pub dimensions: (u32, u32),
+    /// Palette size it was rendered with (0 for iTerm images, which have none)
+    pub colors: usize,
 }
 
+/// Palette size iTerm inline images are cached under
+const ITERM_COLORS: usize = 0;
+
 /// Sixel preview cache resource
 ///
 /// Stores pre-rendered Sixel strings to avoid re-encoding every frame.
 /// Uses DashMap for concurrent access from multiple systems.
 #[derive(Resource)]
 pub struct SixelPreviewCache {
-    /// Cache entries (path -> SixelCacheEntry)
-    cache: Arc<DashMap<PathBuf, SixelCacheEntry>>,
+    /// Cache entries ((path, colors) -> SixelCacheEntry)
+    cache: Arc<DashMap<(PathBuf, usize), SixelCacheEntry>>,
     /// Maximum cache size in bytes
-    max_size_bytes: usize,
+    max_size_bytes: Arc<parking_lot::RwLock<usize>>,
     /// Current cache size in bytes
     current_size: Arc<parking_lot::RwLock<usize>>,
+    /// Lookups answered from the cache
+    hits: Arc<AtomicU64>,
+    /// Lookups that found nothing
+    misses: Arc<AtomicU64>,
 }
 
 impl SixelPreviewCache {
     /// Create a new Sixel preview cache
     pub fn new() -> Self {
-        info!(
-            "Initializing Sixel preview cache with {}MB limit",
-            MAX_CACHE_SIZE_MB
-        );
+        Self::with_max_size_mb(MAX_CACHE_SIZE_MB)
+    }
+
+    /// Create a Sixel preview cache holding up to `max_size_mb` MB
+    pub fn with_max_size_mb(max_size_mb: usize) -> Self {
+        info!("Initializing Sixel preview cache with {}MB limit", max_size_mb);
 
+        Self::with_max_size_bytes(max_size_mb * 1024 * 1024)
+    }
+
+    /// Create a Sixel preview cache holding up to `max_size_bytes` bytes
+    pub fn with_max_size_bytes(max_size_bytes: usize) -> Self {
         Self {
             cache: Arc::new(DashMap::new()),
-            max_size_bytes: MAX_CACHE_SIZE_MB * 1024 * 1024,
+            max_size_bytes: Arc::new(parking_lot::RwLock::new(max_size_bytes)),
             current_size: Arc::new(parking_lot::RwLock::new(0)),
+            hits: Arc::new(AtomicU64::new(0)),
+            misses: Arc::new(AtomicU64::new(0)),
         }
     }
 
-    /// Get a cached Sixel entry
-    pub fn get(&self, path: &Path) -> Option<SixelCacheEntry> {
-        self.cache.get(path).map(|entry| {
+    /// Change the size limit, evicting LRU entries if the cache is now over it
+    pub fn set_max_size_bytes(&self, max_size_bytes: usize) {
+        *self.max_size_bytes.write() = max_size_bytes;
+        self.evict_if_needed(0);
+    }
+
+    /// Get the Sixel entry for `path` rendered with `colors` colors (marking
+    /// it as just accessed)
+    pub fn get(&self, path: &Path, colors: usize) -> Option<SixelCacheEntry> {
+        let key = (path.to_path_buf(), colors);
+        let entry = self.cache.get_mut(&key).map(|mut entry| {
             debug!("Sixel cache hit: {:?}", path);
-            let mut entry = entry.clone();
             entry.last_access = Instant::now();
-            entry
-        })
+            entry.clone()
+        });
+        let counter = if entry.is_some() { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        entry
     }
 
     /// Insert a Sixel entry into the cache
+    ///
+    /// Entries larger than the whole cache aren't kept.
     pub fn insert(&self, entry: SixelCacheEntry) {
         let size = entry.size_bytes;
         let path = entry.path.clone();
+        let key = (path.clone(), entry.colors);
+
+        // Replacing an entry frees its space first
+        if let Some((_, old)) = self.cache.remove(&key) {
+            let mut current = self.current_size.write();
+            *current = current.saturating_sub(old.size_bytes);
+        }
+
+        if size > *self.max_size_bytes.read() {
+            debug!("Sixel too large to cache: {:?} ({} bytes)", path, size);
+            return;
+        }
 
         // Evict if needed
         self.evict_if_needed(size);
 
         // Insert entry
-        self.cache.insert(path.clone(), entry);
+        self.cache.insert(key, entry);
         *self.current_size.write() += size;
 
         debug!("Cached Sixel: {:?} ({} bytes)", path, size);
     }
 
-    /// Check if entry exists in cache
-    pub fn contains(&self, path: &Path) -> bool {
-        self.cache.contains_key(path)
+    /// Check if an entry for `path` rendered with `colors` colors is cached
+    pub fn contains(&self, path: &Path, colors: usize) -> bool {
+        self.cache.contains_key(&(path.to_path_buf(), colors))
     }
 
     /// Clear all cache entries
@@ -102,15 +151,18 @@ impl SixelPreviewCache {
         SixelCacheStats {
             entries: self.cache.len(),
             size_bytes: *self.current_size.read(),
-            max_size_bytes: self.max_size_bytes,
+            max_size_bytes: *self.max_size_bytes.read(),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
         }
     }
 
     /// Evict LRU entries if needed
     fn evict_if_needed(&self, required_space: usize) {
         let current = *self.current_size.read();
+        let max_size_bytes = *self.max_size_bytes.read();
 
-        if current + required_space <= self.max_size_bytes {
+        if current + required_space <= max_size_bytes {
             return; // No eviction needed
         }
 
@@ -135,17 +187,17 @@ impl SixelPreviewCache {
         entries.sort_by_key(|(_, access, _)| *access);
 
         // Evict oldest until we have space
-        let target_size = self.max_size_bytes.saturating_sub(required_space);
+        let target_size = max_size_bytes.saturating_sub(required_space);
         let mut current_size = current;
 
-        for (path, _, size) in entries {
+        for (key, _, size) in entries {
             if current_size <= target_size {
                 break;
             }
 
-            self.cache.remove(&path);
+            self.cache.remove(&key);
             current_size = current_size.saturating_sub(size);
-            debug!("Evicted: {:?} ({} bytes)", path, size);
+            debug!("Evicted: {:?} ({} bytes)", key.0, size);
         }
 
         *self.current_size.write() = current_size;
@@ -158,12 +210,42 @@ impl Default for SixelPreviewCache {
     }
 }
 
+/// Keep the cache limit in step with `ui.sixel_cache_mb`
+pub fn apply_sixel_cache_limit(settings: Res<SettingsState>, cache: Res<SixelPreviewCache>) {
+    let max_size_bytes = settings.ui.sixel_cache_mb as usize * 1024 * 1024;
+    if cache.stats().max_size_bytes != max_size_bytes {
+        info!("Sixel preview cache limit set to {}MB", settings.ui.sixel_cache_mb);
+        cache.set_max_size_bytes(max_size_bytes);
+    }
+}
+
+/// Drop cached previews when `ui.sixel_background` changes
+///
+/// Transparent pixels are blended onto the background when a preview is
+/// rendered, so previews made with the old color would otherwise stay.
+pub fn clear_sixel_cache_on_background_change(
+    settings: Res<SettingsState>,
+    cache: Res<SixelPreviewCache>,
+    mut background: Local<Option<ratatui::style::Color>>,
+) {
+    let current = settings.ui.sixel_background;
+    if background.is_some_and(|previous| previous != current) {
+        info!("Sixel background changed to {}", current);
+        cache.clear();
+    }
+    *background = Some(current);
+}
+
 /// Cache statistics
 #[derive(Debug, Clone)]
 pub struct SixelCacheStats {
     pub entries: usize,
     pub size_bytes: usize,
     pub max_size_bytes: usize,
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that found nothing
+    pub misses: u64,
 }
 
 impl SixelCacheStats {
@@ -180,6 +262,16 @@ impl SixelCacheStats {
     pub fn size_mb(&self) -> f32 {
         self.size_bytes as f32 / (1024.0 * 1024.0)
     }
+
+    /// Fraction of lookups answered from the cache (0.0 before any lookup)
+    pub fn hit_rate(&self) -> f32 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f32 / lookups as f32
+        }
+    }
 }
 
 /// Bevy system to render images to Sixel and cache them
@@ -194,7 +286,7 @@ pub fn cache_image_as_sixel(
     options: SixelRenderOptions,
 ) -> Result<SixelCacheEntry> {
     // Check cache first
-    if let Some(entry) = cache.get(&path) {
+    if let Some(entry) = cache.get(&path, options.max_colors) {
         return Ok(entry);
     }
 
@@ -213,6 +305,7 @@ pub fn cache_image_as_sixel(
         size_bytes: sixel_data.len(),
         last_access: Instant::now(),
         dimensions: (image.width(), image.height()),
+        colors: options.max_colors,
     };
 
     // Cache it
@@ -232,7 +325,7 @@ pub fn get_or_render_sixel(
     options: &SixelRenderOptions,
 ) -> Result<String> {
     // Check cache
-    if let Some(entry) = cache.get(path) {
+    if let Some(entry) = cache.get(path, options.max_colors) {
         return Ok(entry.sixel_data);
     }
 
@@ -248,9 +341,38 @@ pub fn get_or_render_sixel(
     Ok(entry.sixel_data)
 }
 
+/// Get or encode an iTerm2 inline image preview of the file at `path`
+///
+/// On iTerm2 the cache holds these escapes instead of Sixel strings.
+/// Files over [`MAX_ITERM_IMAGE_BYTES`](crate::sixel::MAX_ITERM_IMAGE_BYTES) are an error (callers show a
+/// placeholder).
+pub fn get_or_render_iterm(
+    path: &Path,
+    dimensions: (u32, u32),
+    width: u16,
+    height: u16,
+    cache: &SixelPreviewCache,
+) -> Result<String> {
+    if let Some(entry) = cache.get(path, ITERM_COLORS) {
+        return Ok(entry.sixel_data);
+    }
+
+    let data = render_iterm_image(path, width, height)?;
+    cache.insert(SixelCacheEntry {
+        path: path.to_path_buf(),
+        sixel_data: data.clone(),
+        size_bytes: data.len(),
+        last_access: Instant::now(),
+        dimensions,
+        colors: ITERM_COLORS,
+    });
+    Ok(data)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bevy_app::systems::assets::sixel_renderer::MAX_SIXEL_COLORS;
 
     #[test]
     fn test_cache_creation() {
@@ -271,13 +393,16 @@ mod tests {
             size_bytes: 100,
             last_access: Instant::now(),
             dimensions: (64, 64),
+            colors: MAX_SIXEL_COLORS,
         };
 
         cache.insert(entry.clone());
 
-        let retrieved = cache.get(&path);
+        let retrieved = cache.get(&path, MAX_SIXEL_COLORS);
         assert!(retrieved.is_some());
         assert_eq!(retrieved.unwrap().sixel_data, "test_sixel_data");
+        // Rendered with a different palette: a separate entry
+        assert!(cache.get(&path, 64).is_none());
 
         let stats = cache.stats();
         assert_eq!(stats.entries, 1);
@@ -289,7 +414,7 @@ mod tests {
         let cache = SixelPreviewCache::new();
         let path = PathBuf::from("/test/image.png");
 
-        assert!(!cache.contains(&path));
+        assert!(!cache.contains(&path, MAX_SIXEL_COLORS));
 
         let entry = SixelCacheEntry {
             path: path.clone(),
@@ -297,10 +422,11 @@ mod tests {
             size_bytes: 10,
             last_access: Instant::now(),
             dimensions: (32, 32),
+            colors: MAX_SIXEL_COLORS,
         };
 
         cache.insert(entry);
-        assert!(cache.contains(&path));
+        assert!(cache.contains(&path, MAX_SIXEL_COLORS));
     }
 
     #[test]
@@ -318,6 +444,7 @@ mod tests {
             size_bytes: 1024 * 1024,
             last_access: Instant::now(),
             dimensions: (128, 128),
+            colors: MAX_SIXEL_COLORS,
         };
 
         cache.insert(entry);
@@ -327,6 +454,31 @@ mod tests {
         assert!(stats.usage_percent() > 0.0);
     }
 
+    #[test]
+    fn test_cache_hit_rate() {
+        let cache = SixelPreviewCache::new();
+        assert_eq!(cache.stats().hit_rate(), 0.0);
+
+        let path = PathBuf::from("/test.png");
+        assert!(cache.get(&path, MAX_SIXEL_COLORS).is_none());
+        cache.insert(SixelCacheEntry {
+            path: path.clone(),
+            sixel_data: "sixel".to_string(),
+            size_bytes: 5,
+            last_access: Instant::now(),
+            dimensions: (1, 1),
+            colors: MAX_SIXEL_COLORS,
+        });
+        for _ in 0..3 {
+            assert!(cache.get(&path, MAX_SIXEL_COLORS).is_some());
+        }
+        assert!(cache.get(Path::new("/other.png"), MAX_SIXEL_COLORS).is_none());
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (3, 2));
+        assert_eq!(stats.hit_rate(), 0.6);
+    }
+
     #[test]
     fn test_cache_clear() {
         let cache = SixelPreviewCache::new();
@@ -336,6 +488,7 @@ mod tests {
             size_bytes: 100,
             last_access: Instant::now(),
             dimensions: (64, 64),
+            colors: MAX_SIXEL_COLORS,
         };
 
         cache.insert(entry);
@@ -345,4 +498,82 @@ mod tests {
         assert_eq!(cache.stats().entries, 0);
         assert_eq!(cache.stats().size_bytes, 0);
     }
+
+    fn entry(path: &str, size_bytes: usize) -> SixelCacheEntry {
+        SixelCacheEntry {
+            path: PathBuf::from(path),
+            sixel_data: String::new(),
+            size_bytes,
+            last_access: Instant::now(),
+            dimensions: (64, 64),
+            colors: MAX_SIXEL_COLORS,
+        }
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recently_accessed_first() {
+        let cache = SixelPreviewCache::with_max_size_bytes(300);
+        cache.insert(entry("/a.png", 100));
+        cache.insert(entry("/b.png", 100));
+        cache.insert(entry("/c.png", 100));
+
+        // Touch the oldest so the middle one is now least recently used
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        assert!(cache.get(Path::new("/a.png"), MAX_SIXEL_COLORS).is_some());
+
+        cache.insert(entry("/d.png", 150));
+        assert!(cache.contains(Path::new("/a.png"), MAX_SIXEL_COLORS));
+        assert!(!cache.contains(Path::new("/b.png"), MAX_SIXEL_COLORS));
+        assert!(!cache.contains(Path::new("/c.png"), MAX_SIXEL_COLORS));
+        assert!(cache.contains(Path::new("/d.png"), MAX_SIXEL_COLORS));
+        let stats = cache.stats();
+        assert_eq!((stats.entries, stats.size_bytes), (2, 250));
+
+        // Replacing an entry doesn't count it twice
+        cache.insert(entry("/d.png", 150));
+        assert_eq!(cache.stats().size_bytes, 250);
+
+        // Larger than the whole cache: not kept, nothing evicted for it
+        cache.insert(entry("/huge.png", 301));
+        assert!(!cache.contains(Path::new("/huge.png"), MAX_SIXEL_COLORS));
+        assert_eq!(cache.stats().entries, 2);
+
+        // Shrinking the limit evicts down to it
+        cache.set_max_size_bytes(150);
+        assert!(!cache.contains(Path::new("/a.png"), MAX_SIXEL_COLORS));
+        assert_eq!(cache.stats().size_bytes, 150);
+    }
+
+    #[test]
+    fn test_cache_limit_follows_settings() {
+        let mut app = App::new();
+        let mut settings = SettingsState::default();
+        settings.ui.sixel_cache_mb = 8;
+        app.insert_resource(settings);
+        app.insert_resource(SixelPreviewCache::new());
+        app.add_systems(Update, apply_sixel_cache_limit);
+        app.update();
+
+        let stats = app.world().resource::<SixelPreviewCache>().stats();
+        assert_eq!(stats.max_size_bytes, 8 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_background_change_clears_cache() {
+        let mut app = App::new();
+        app.init_resource::<SettingsState>();
+        app.insert_resource(SixelPreviewCache::new());
+        app.add_systems(Update, clear_sixel_cache_on_background_change);
+        app.update();
+
+        let cached = |app: &App| app.world().resource::<SixelPreviewCache>().stats().entries;
+        app.world().resource::<SixelPreviewCache>().insert(entry("/a.png", 10));
+        app.update();
+        assert_eq!(cached(&app), 1);
+
+        app.world_mut().resource_mut::<SettingsState>().ui.sixel_background =
+            ratatui::style::Color::Rgb(255, 255, 255);
+        app.update();
+        assert_eq!(cached(&app), 0);
+    }
 }
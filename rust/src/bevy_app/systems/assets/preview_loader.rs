@@ -4,33 +4,41 @@
 //!
 //! ## Architecture
 //!
-//! This system periodically scans the gallery directory for new images,
-//! loads them via Bevy's AssetServer, and manages the preview cache.
+//! This system periodically scans the gallery directories for new images,
+//! loads them via Bevy's AssetServer, and manages the preview cache. The
+//! directories come from `paths.gallery_dirs` (`../outputs` if none are
+//! configured).
 //!
 //! ## Components
 //!
-//! - `scan_gallery_directory`: Periodic scan (every 2s) for new images
+//! - `scan_gallery_directory`: Periodic background scan (every 2s) for new
+//!   images, skipped while the directories are unmodified
+//! - `apply_gallery_dirs`: Keep the scanned directories in step with settings
 //! - `check_preview_loading`: Monitor asset loading status
 //! - Integration with `ImageCache` for LRU eviction
 //!
 //! ## Flow
 //!
-//! 1. Scan `../outputs` directory for PNG/JPG files
-//! 2. Compare with existing `GalleryState.images`
+//! 1. Scan each gallery directory for image files on a background thread,
+//!    merged newest first (unless no directory changed since the last scan)
+//! 2. Once the scan finishes, compare with existing `GalleryState.images`
 //! 3. For new images: spawn entities with `PreviewImage` components
 //! 4. `AssetServer` loads images asynchronously in background
 //! 5. `check_preview_loading` monitors load state
 //! 6. `ImageCache` evicts old entries after 5 minutes
 
 use bevy::prelude::*;
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::{Duration, SystemTime};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime};
 use tracing::{debug, info, warn};
 
 use crate::bevy_app::components::PreviewImage;
-use crate::bevy_app::resources::GalleryState;
+use crate::bevy_app::resources::{GalleryState, SettingsState};
 use crate::bevy_app::systems::assets::ImageCache;
+use crate::sixel::is_image_path;
 
 /// Default gallery directory (relative to workspace root).
 pub const DEFAULT_GALLERY_DIR: &str = "../outputs";
@@ -42,14 +50,22 @@ pub const SCAN_INTERVAL_SECS: u64 = 2;
 #[derive(Resource, Debug)]
 pub struct GalleryScanState {
     pub last_scan: SystemTime,
-    pub gallery_dir: PathBuf,
+    /// Directories scanned for images, without duplicates
+    pub gallery_dirs: Vec<PathBuf>,
+    /// Newest modification time of `gallery_dirs` when the last scan started
+    /// (`None` before the first scan)
+    pub last_dir_mtime: Option<SystemTime>,
+    /// Scan running on a background thread
+    pending: Option<JoinHandle<Vec<PathBuf>>>,
 }
 
 impl Default for GalleryScanState {
     fn default() -> Self {
         Self {
             last_scan: SystemTime::UNIX_EPOCH,
-            gallery_dir: PathBuf::from(DEFAULT_GALLERY_DIR),
+            gallery_dirs: vec![PathBuf::from(DEFAULT_GALLERY_DIR)],
+            last_dir_mtime: None,
+            pending: None,
         }
     }
 }
@@ -57,10 +73,34 @@ impl Default for GalleryScanState {
 impl GalleryScanState {
     /// Create with custom gallery directory.
     pub fn with_directory(mut self, dir: PathBuf) -> Self {
-        self.gallery_dir = dir;
+        self.set_dirs(vec![dir]);
         self
     }
 
+    /// Scan `dirs` (the default directory if empty) from now on.
+    ///
+    /// A changed list is scanned on the next run rather than after the
+    /// interval.
+    pub fn set_dirs(&mut self, dirs: Vec<PathBuf>) {
+        let mut unique = Vec::with_capacity(dirs.len());
+        for dir in dirs {
+            if !unique.contains(&dir) {
+                unique.push(dir);
+            }
+        }
+        if unique.is_empty() {
+            unique.push(PathBuf::from(DEFAULT_GALLERY_DIR));
+        }
+
+        if unique != self.gallery_dirs {
+            self.gallery_dirs = unique;
+            self.last_scan = SystemTime::UNIX_EPOCH;
+            self.last_dir_mtime = None;
+            // Results for the old directories are no longer wanted
+            self.pending = None;
+        }
+    }
+
     /// Check if it's time to scan again.
     pub fn should_scan(&self) -> bool {
         if let Ok(elapsed) = SystemTime::now().duration_since(self.last_scan) {
@@ -74,12 +114,75 @@ impl GalleryScanState {
     pub fn mark_scanned(&mut self) {
         self.last_scan = SystemTime::now();
     }
+
+    /// Newest modification time of the gallery directories.
+    ///
+    /// A directory's time changes when images are added to or removed from
+    /// it. Missing directories don't count.
+    pub fn dirs_mtime(&self) -> Option<SystemTime> {
+        self.gallery_dirs
+            .iter()
+            .filter_map(|dir| fs::metadata(dir).and_then(|m| m.modified()).ok())
+            .max()
+    }
+
+    /// Start scanning the gallery directories on a background thread.
+    ///
+    /// Does nothing if a scan is already running, or if no directory has
+    /// been modified since the last scan started. Returns whether a scan
+    /// was started.
+    pub fn start_scan(&mut self) -> bool {
+        if self.pending.is_some() {
+            return false;
+        }
+        let mtime = self.dirs_mtime();
+        if mtime.is_some() && mtime == self.last_dir_mtime {
+            return false;
+        }
+
+        // Read before scanning, so a change made during the scan is picked
+        // up by the next one
+        self.last_dir_mtime = mtime;
+        let dirs = self.gallery_dirs.clone();
+        self.pending = Some(std::thread::spawn(move || scan_image_directories(&dirs)));
+        true
+    }
+
+    /// Whether a background scan is running.
+    pub fn is_scanning(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Images found by the background scan, once it has finished.
+    ///
+    /// Returns `None` while it's still running (or if none was started).
+    pub fn take_finished_scan(&mut self) -> Option<Vec<PathBuf>> {
+        if !self.pending.as_ref()?.is_finished() {
+            return None;
+        }
+        match self.pending.take()?.join() {
+            Ok(images) => Some(images),
+            Err(_) => {
+                warn!("Gallery scan thread panicked");
+                // Scan again, even if nothing changed
+                self.last_dir_mtime = None;
+                None
+            }
+        }
+    }
+}
+
+/// Keep the scanned directories in step with `paths.gallery_dirs`.
+pub fn apply_gallery_dirs(settings: Res<SettingsState>, mut scan_state: ResMut<GalleryScanState>) {
+    scan_state.set_dirs(settings.paths.gallery_dirs.clone());
 }
 
-/// System to periodically scan gallery directory for new images.
+/// System to periodically scan the gallery directories for new images.
 ///
-/// Runs every 2 seconds to detect new images added by completed jobs.
-/// Updates GalleryState with newly discovered images.
+/// Every 2 seconds a scan is started on a background thread to detect new
+/// images added by completed jobs, unless the directories haven't been
+/// modified since the last one. Runs every frame so a finished scan's new
+/// images are added to GalleryState as soon as they're found.
 pub fn scan_gallery_directory(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -87,97 +190,74 @@ pub fn scan_gallery_directory(
     mut scan_state: ResMut<GalleryScanState>,
     existing_query: Query<&PreviewImage>,
     mut cache: ResMut<ImageCache>,
+    settings: Option<Res<SettingsState>>,
 ) {
-    // Check if it's time to scan
-    if !scan_state.should_scan() {
-        return;
-    }
-
-    // Convert relative path to absolute for scanning
-    let abs_gallery_dir = match fs::canonicalize(&scan_state.gallery_dir) {
-        Ok(abs_path) => abs_path,
-        Err(e) => {
-            debug!(
-                "Gallery directory does not exist or cannot be canonicalized: {:?} (error: {})",
-                scan_state.gallery_dir, e
-            );
+    let Some(found) = scan_state.take_finished_scan() else {
+        // Check if it's time to scan
+        if !scan_state.is_scanning() && scan_state.should_scan() {
+            if scan_state.start_scan() {
+                debug!("Scanning gallery directories: {:?}", scan_state.gallery_dirs);
+            }
             scan_state.mark_scanned();
-            return;
         }
+        return;
     };
 
-    debug!("Scanning gallery directory: {:?}", abs_gallery_dir);
-
     // Get project root for converting paths to relative
     let project_root = std::env::current_dir()
         .ok()
         .and_then(|cwd| cwd.parent().map(|p| p.to_path_buf()))
         .unwrap_or_else(|| std::path::PathBuf::from(".."));
 
-    // Scan directory
-    match scan_image_directory(&abs_gallery_dir) {
-        Ok(discovered_images) => {
-            let mut new_images = 0;
-
-            for abs_image_path in discovered_images {
-                // Convert absolute path to relative path for AssetServer
-                // AssetPlugin is configured with file_path="../" so we need "outputs/job-xxx.png"
-                let image_path = abs_image_path
-                    .strip_prefix(&project_root)
-                    .map(|p| p.to_path_buf())
-                    .unwrap_or_else(|_| {
-                        // Fallback: extract filename and prepend "outputs/"
-                        if let Some(filename) = abs_image_path.file_name() {
-                            std::path::PathBuf::from("outputs").join(filename)
-                        } else {
-                            abs_image_path.clone()
-                        }
-                    });
+    let default_dir = fs::canonicalize(DEFAULT_GALLERY_DIR).ok();
+    let mut new_images = 0;
+    let mut newest = None;
 
-                // Skip if already in gallery
-                if gallery.images.contains(&image_path) {
-                    continue;
-                }
+    for abs_image_path in found {
+        let image_path = asset_path(&abs_image_path, &project_root, default_dir.as_deref());
 
-                // Skip if already loaded as preview
-                let already_loaded = existing_query
-                    .iter()
-                    .any(|preview| preview.path == image_path);
+        // Skip if already in gallery
+        if gallery.images.contains(&image_path) {
+            continue;
+        }
 
-                if already_loaded {
-                    continue;
-                }
+        // Skip if already loaded as preview
+        let already_loaded = existing_query
+            .iter()
+            .any(|preview| preview.path == image_path);
 
-                debug!("New image discovered: {:?} (asset path: {:?})", abs_image_path, image_path);
+        if already_loaded {
+            continue;
+        }
 
-                // Add to gallery state (using relative path for AssetServer)
-                gallery.add_image(image_path.clone());
+        debug!("New image discovered: {:?} (asset path: {:?})", abs_image_path, image_path);
 
-                // Load image via AssetServer (relative to project root)
-                let handle: Handle<Image> = asset_server.load(image_path.clone());
+        // Add to gallery state (using relative path for AssetServer)
+        gallery.add_image(image_path.clone());
 
-                // Add to cache
-                cache.insert(image_path.clone(), handle.clone());
+        // Load image via AssetServer (relative to project root)
+        let handle: Handle<Image> = asset_server.load(image_path.clone());
 
-                // Spawn entity with PreviewImage component
-                commands.spawn(PreviewImage {
-                    path: image_path.clone(),
-                    asset_handle: Some(handle),
-                });
+        // Add to cache
+        cache.insert(image_path.clone(), handle.clone());
 
-                new_images += 1;
-            }
+        // Spawn entity with PreviewImage component
+        commands.spawn(PreviewImage::loading(image_path.clone(), handle));
 
-            if new_images > 0 {
-                info!("Loaded {} new images from gallery", new_images);
-            }
-        }
-        Err(e) => {
-            warn!("Failed to scan gallery directory: {}", e);
-        }
+        new_images += 1;
+        newest = Some(image_path);
+    }
+
+    if new_images > 0 {
+        info!("Loaded {} new images from gallery", new_images);
     }
 
-    scan_state.mark_scanned();
+    let auto_select = settings.is_some_and(|s| s.ui.auto_select_newest);
+    if let Some(path) = newest.filter(|_| auto_select) {
+        if gallery.auto_select(&path, Instant::now()) {
+            debug!("Auto-selected new gallery image: {:?}", path);
+        }
+    }
 }
 
 /// System to check preview image loading status.
@@ -224,7 +304,71 @@ pub fn check_preview_loading(
     }
 }
 
-/// Scan a directory for image files (PNG, JPG, JPEG, WebP).
+/// Path the AssetServer loads `abs_image_path` by.
+///
+/// AssetPlugin is configured with file_path="../", so images under the
+/// project root load by their relative path ("outputs/job-xxx.png"). Images
+/// in the default gallery directory fall back to "outputs/<name>"; any other
+/// image loads by its absolute path, which the asset root doesn't change.
+fn asset_path(abs_image_path: &Path, project_root: &Path, default_dir: Option<&Path>) -> PathBuf {
+    if let Ok(relative) = abs_image_path.strip_prefix(project_root) {
+        return relative.to_path_buf();
+    }
+    match abs_image_path.file_name() {
+        Some(filename) if abs_image_path.parent() == default_dir => {
+            PathBuf::from("outputs").join(filename)
+        }
+        _ => abs_image_path.to_path_buf(),
+    }
+}
+
+/// Scan every directory in `dirs` for image files.
+///
+/// Returns each image once (absolute paths), newest first by modification
+/// time. A directory that's missing or can't be read is skipped.
+fn scan_image_directories(dirs: &[PathBuf]) -> Vec<PathBuf> {
+    let mut seen = HashSet::new();
+    let mut images = Vec::new();
+
+    for dir in dirs {
+        // Convert relative path to absolute for scanning
+        let abs_dir = match fs::canonicalize(dir) {
+            Ok(abs_dir) => abs_dir,
+            Err(e) => {
+                debug!(
+                    "Gallery directory does not exist or cannot be canonicalized: {:?} (error: {})",
+                    dir, e
+                );
+                continue;
+            }
+        };
+
+        match scan_image_directory(&abs_dir) {
+            Ok(found) => {
+                for path in found {
+                    if seen.insert(path.clone()) {
+                        let modified = fs::metadata(&path)
+                            .and_then(|m| m.modified())
+                            .unwrap_or(SystemTime::UNIX_EPOCH);
+                        images.push((modified, path));
+                    }
+                }
+            }
+            Err(e) => warn!("Failed to scan gallery directory {:?}: {}", abs_dir, e),
+        }
+    }
+
+    images.sort_by(|a, b| b.cmp(a));
+    images.into_iter().map(|(_, path)| path).collect()
+}
+
+/// Extensions (lowercase) Bevy's image loader can't decode.
+///
+/// Bevy 0.15 has no AVIF loader, so AVIF files are left out of the scan even
+/// when the `avif` feature lets the classic gallery show them.
+const UNLOADABLE_EXTENSIONS: &[&str] = &["avif"];
+
+/// Scan a directory for image files (see [`IMAGE_EXTENSIONS`](crate::sixel::IMAGE_EXTENSIONS)).
 ///
 /// Returns a sorted list of image paths (newest first).
 fn scan_image_directory(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
@@ -234,16 +378,11 @@ fn scan_image_directory(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
         let entry = entry?;
         let path = entry.path();
 
-        if !path.is_file() {
-            continue;
-        }
-
-        // Check if file has image extension
-        if let Some(ext) = path.extension() {
-            let ext_str = ext.to_string_lossy().to_lowercase();
-            if matches!(ext_str.as_str(), "png" | "jpg" | "jpeg" | "webp") {
-                images.push(path);
-            }
+        let unloadable = path.extension().is_some_and(|ext| {
+            UNLOADABLE_EXTENSIONS.contains(&ext.to_string_lossy().to_lowercase().as_str())
+        });
+        if path.is_file() && is_image_path(&path) && !unloadable {
+            images.push(path);
         }
     }
 
@@ -275,19 +414,9 @@ pub fn preload_gallery_directory(
         .and_then(|cwd| cwd.parent().map(|p| p.to_path_buf()))
         .unwrap_or_else(|| std::path::PathBuf::from(".."));
 
+    let default_dir = fs::canonicalize(DEFAULT_GALLERY_DIR).ok();
     for abs_image_path in images {
-        // Convert absolute path to relative path for AssetServer
-        let image_path = abs_image_path
-            .strip_prefix(&project_root)
-            .map(|p| p.to_path_buf())
-            .unwrap_or_else(|_| {
-                // Fallback: extract filename and prepend "outputs/"
-                if let Some(filename) = abs_image_path.file_name() {
-                    std::path::PathBuf::from("outputs").join(filename)
-                } else {
-                    abs_image_path.clone()
-                }
-            });
+        let image_path = asset_path(&abs_image_path, &project_root, default_dir.as_deref());
 
         // Add to gallery (using relative path)
         gallery.add_image(image_path.clone());
@@ -299,10 +428,7 @@ pub fn preload_gallery_directory(
         cache.insert(image_path.clone(), handle.clone());
 
         // Spawn preview entity
-        commands.spawn(PreviewImage {
-            path: image_path,
-            asset_handle: Some(handle),
-        });
+        commands.spawn(PreviewImage::loading(image_path, handle));
     }
 
     info!("Preloaded {} images from directory: {:?}", count, abs_dir);
@@ -317,13 +443,114 @@ mod tests {
     fn test_scan_state_default() {
         let state = GalleryScanState::default();
         assert!(state.should_scan()); // First scan should always run
-        assert_eq!(state.gallery_dir, PathBuf::from(DEFAULT_GALLERY_DIR));
+        assert_eq!(state.gallery_dirs, vec![PathBuf::from(DEFAULT_GALLERY_DIR)]);
     }
 
     #[test]
     fn test_scan_state_custom_directory() {
         let state = GalleryScanState::default().with_directory(PathBuf::from("/custom/path"));
-        assert_eq!(state.gallery_dir, PathBuf::from("/custom/path"));
+        assert_eq!(state.gallery_dirs, vec![PathBuf::from("/custom/path")]);
+    }
+
+    #[test]
+    fn test_set_dirs_follows_settings() {
+        let mut app = App::new();
+        let mut settings = SettingsState::default();
+        settings.paths.gallery_dirs = vec![
+            PathBuf::from("/renders"),
+            PathBuf::from("/archive"),
+            PathBuf::from("/renders"),
+        ];
+        app.insert_resource(settings);
+        let mut state = GalleryScanState::default();
+        state.mark_scanned();
+        app.insert_resource(state);
+        app.add_systems(Update, apply_gallery_dirs);
+        app.update();
+
+        let state = app.world().resource::<GalleryScanState>();
+        assert_eq!(state.gallery_dirs, vec![PathBuf::from("/renders"), PathBuf::from("/archive")]);
+        // A new list is scanned right away
+        assert!(state.should_scan());
+
+        let mut state = GalleryScanState::default().with_directory(PathBuf::from("/renders"));
+        state.set_dirs(Vec::new());
+        assert_eq!(state.gallery_dirs, vec![PathBuf::from(DEFAULT_GALLERY_DIR)]);
+    }
+
+    #[test]
+    fn test_scan_image_directories_merges_newest_first() {
+        let outputs = tempfile::tempdir().unwrap();
+        let archive = tempfile::tempdir().unwrap();
+        let now = SystemTime::now();
+        let files = [
+            (outputs.path().join("job-1.png"), 30),
+            (archive.path().join("old.webp"), 60),
+            (outputs.path().join("job-2.png"), 10),
+            (archive.path().join("notes.txt"), 0),
+        ];
+        for (path, age_secs) in &files {
+            fs::write(path, b"").unwrap();
+            let mtime = now - Duration::from_secs(*age_secs);
+            fs::File::options().write(true).open(path).unwrap().set_modified(mtime).unwrap();
+        }
+
+        let dirs = vec![
+            outputs.path().to_path_buf(),
+            archive.path().join("removed"),
+            archive.path().to_path_buf(),
+            outputs.path().to_path_buf(),
+        ];
+        let names: Vec<String> = scan_image_directories(&dirs)
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, vec!["job-2.png", "job-1.png", "old.webp"]);
+    }
+
+    fn wait_for_scan(state: &mut GalleryScanState) -> Vec<PathBuf> {
+        for _ in 0..500 {
+            if let Some(found) = state.take_finished_scan() {
+                return found;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        panic!("gallery scan did not finish");
+    }
+
+    #[test]
+    fn test_no_rescan_while_directory_unmodified() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("job-1.png"), b"").unwrap();
+        let mut state = GalleryScanState::default().with_directory(dir.path().to_path_buf());
+
+        assert!(state.start_scan());
+        // Only one scan at a time
+        assert!(!state.start_scan());
+        assert_eq!(wait_for_scan(&mut state).len(), 1);
+        assert_eq!(state.last_dir_mtime, state.dirs_mtime());
+        assert!(state.last_dir_mtime.is_some());
+
+        assert!(!state.start_scan());
+        assert!(!state.is_scanning());
+        assert_eq!(state.take_finished_scan(), None);
+    }
+
+    #[test]
+    fn test_rescan_after_new_file() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("job-1.png"), b"").unwrap();
+        let mut state = GalleryScanState::default().with_directory(dir.path().to_path_buf());
+        assert!(state.start_scan());
+        wait_for_scan(&mut state);
+
+        fs::write(dir.path().join("job-2.png"), b"").unwrap();
+        // Coarse filesystem timestamps could leave the time unchanged
+        let later = state.last_dir_mtime.unwrap() + Duration::from_secs(1);
+        fs::File::open(dir.path()).unwrap().set_modified(later).unwrap();
+
+        assert!(state.start_scan());
+        assert_eq!(wait_for_scan(&mut state).len(), 2);
     }
 
     #[test]
@@ -349,7 +576,18 @@ mod tests {
 
     #[test]
     fn test_scan_image_directory_filters_extensions() {
-        // This test would need a temp directory with test files
-        // Skipping for now - would be tested in integration tests
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["a.png", "b.JPG", "c.webp", "d.avif", "e.json", "f.txt"] {
+            fs::write(dir.path().join(name), b"").unwrap();
+        }
+        fs::create_dir(dir.path().join("g.png")).unwrap();
+
+        let names: Vec<String> = scan_image_directory(dir.path())
+            .unwrap()
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        // AVIF is skipped with or without the `avif` feature
+        assert_eq!(names, vec!["c.webp", "b.JPG", "a.png"]);
     }
 }
@@ -2,9 +2,20 @@
 //!
 //! Converts Bevy Image assets to terminal-renderable formats.
 //! Provides ASCII/Unicode fallback for terminals without image support.
+//!
+//! Unicode previews draw in one of the [`UnicodeRenderMode`]s chosen by
+//! `ui.unicode_render_mode`; the colored modes pack two (half-block) or
+//! four (quarter-block) pixels into each cell with foreground and
+//! background colors. With `ui.unicode_dither`, colors are reduced to a
+//! 6x6x6 cube (shades to the five block levels) with Floyd–Steinberg error
+//! diffusion, which keeps gradients smooth on 256-color terminals.
 
 use bevy::prelude::*;
+use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 use tracing::{debug, warn};
 
 /// ASCII characters for image rendering (brightness levels).
@@ -85,55 +96,274 @@ fn brightness_to_ascii(brightness: u8) -> char {
     ASCII_CHARS[index]
 }
 
+/// Color levels per channel when dithering colored previews.
+const DITHER_COLOR_LEVELS: usize = 6;
+
+/// Quadrant characters indexed by which quarters are foreground (bit 0 top
+/// left, 1 top right, 2 bottom left, 3 bottom right).
+const QUADRANTS: [char; 16] = [
+    ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+];
+
+/// How Unicode previews draw an image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnicodeRenderMode {
+    /// `▀` cells, top pixel in the foreground and bottom in the background
+    #[default]
+    HalfBlock,
+    /// Quadrant cells, each 2x2 block split into two colors
+    QuarterBlock,
+    /// Monochrome shades (`░▒▓█`) by brightness
+    Shade,
+}
+
+impl UnicodeRenderMode {
+    /// Every mode, in the order the setting cycles through them.
+    pub const ALL: [UnicodeRenderMode; 3] = [
+        UnicodeRenderMode::HalfBlock,
+        UnicodeRenderMode::QuarterBlock,
+        UnicodeRenderMode::Shade,
+    ];
+
+    /// Name used in `config.toml`.
+    pub fn name(self) -> &'static str {
+        match self {
+            UnicodeRenderMode::HalfBlock => "half_block",
+            UnicodeRenderMode::QuarterBlock => "quarter_block",
+            UnicodeRenderMode::Shade => "shade",
+        }
+    }
+
+    /// The next mode, wrapping around.
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&m| m == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// The previous mode, wrapping around.
+    pub fn previous(self) -> Self {
+        let index = Self::ALL.iter().position(|&m| m == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+impl fmt::Display for UnicodeRenderMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for UnicodeRenderMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.name() == s.trim())
+            .ok_or_else(|| anyhow::anyhow!("Unknown Unicode render mode: {}", s))
+    }
+}
+
+/// Options for Unicode rendering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UnicodeRenderOptions {
+    /// Characters used for each cell
+    pub mode: UnicodeRenderMode,
+    /// Reduce colors with Floyd–Steinberg dithering
+    pub dither: bool,
+}
+
 /// Render image with Unicode block characters (better quality).
+///
+/// `width` and `height` are in cells.
 pub fn render_image_to_unicode(
     image: &Image,
     width: usize,
     height: usize,
+    options: &UnicodeRenderOptions,
 ) -> Vec<Line<'static>> {
     debug!(
-        "Rendering image to Unicode: {}x{} -> {}x{}",
+        "Rendering image to Unicode ({}): {}x{} -> {}x{}",
+        options.mode,
         image.width(),
         image.height(),
         width,
         height
     );
 
-    let mut lines = Vec::new();
+    match options.mode {
+        UnicodeRenderMode::HalfBlock => {
+            let mut pixels = sample_grid(image, width, height * 2);
+            if options.dither {
+                dither_floyd_steinberg(&mut pixels, width, height * 2, DITHER_COLOR_LEVELS);
+            }
+            (0..height)
+                .map(|y| {
+                    let top = &pixels[y * 2 * width..(y * 2 + 1) * width];
+                    let bottom = &pixels[(y * 2 + 1) * width..(y * 2 + 2) * width];
+                    let cells = top.iter().zip(bottom).map(|(&top, &bottom)| {
+                        Span::styled("▀", Style::default().fg(to_color(top)).bg(to_color(bottom)))
+                    });
+                    Line::from(cells.collect::<Vec<_>>())
+                })
+                .collect()
+        }
+        UnicodeRenderMode::QuarterBlock => {
+            let grid_width = width * 2;
+            let mut pixels = sample_grid(image, grid_width, height * 2);
+            if options.dither {
+                dither_floyd_steinberg(&mut pixels, grid_width, height * 2, DITHER_COLOR_LEVELS);
+            }
+            (0..height)
+                .map(|y| {
+                    let cells = (0..width).map(|x| {
+                        let top = y * 2 * grid_width + x * 2;
+                        let bottom = top + grid_width;
+                        let block = [
+                            pixels[top],
+                            pixels[top + 1],
+                            pixels[bottom],
+                            pixels[bottom + 1],
+                        ];
+                        quadrant_cell(&block)
+                    });
+                    Line::from(cells.collect::<Vec<_>>())
+                })
+                .collect()
+        }
+        UnicodeRenderMode::Shade => {
+            let mut pixels: Vec<[f32; 3]> = sample_grid(image, width, height)
+                .into_iter()
+                .map(|rgb| [luminance(rgb); 3])
+                .collect();
+            if options.dither {
+                dither_floyd_steinberg(&mut pixels, width, height, SHADE_BLOCKS.len());
+            }
+            pixels
+                .chunks(width.max(1))
+                .take(height)
+                .map(|row| {
+                    let line: String =
+                        row.iter().map(|p| brightness_to_block(p[0].round() as u8)).collect();
+                    Line::from(Span::raw(line))
+                })
+                .collect()
+        }
+    }
+}
 
-    let img_data = &image.data;
+/// A quadrant character for a 2x2 block (top left, top right, bottom left,
+/// bottom right): quarters brighter than the block's mean are foreground,
+/// the rest background, each drawn in its group's mean color.
+fn quadrant_cell(block: &[[f32; 3]; 4]) -> Span<'static> {
+    let mean = block.iter().map(|&p| luminance(p)).sum::<f32>() / 4.0;
+    let mut bits = 0;
+    let (mut fg, mut bg) = (Vec::new(), Vec::new());
+    for (i, &pixel) in block.iter().enumerate() {
+        if luminance(pixel) > mean {
+            bits |= 1 << i;
+            fg.push(pixel);
+        } else {
+            bg.push(pixel);
+        }
+    }
+
+    let mut style = Style::default().bg(to_color(mean_color(&bg)));
+    if !fg.is_empty() {
+        style = style.fg(to_color(mean_color(&fg)));
+    }
+    Span::styled(QUADRANTS[bits].to_string(), style)
+}
+
+/// Mean of `pixels` (black if there are none).
+fn mean_color(pixels: &[[f32; 3]]) -> [f32; 3] {
+    if pixels.is_empty() {
+        return [0.0; 3];
+    }
+    let mut sum = [0.0; 3];
+    for pixel in pixels {
+        for c in 0..3 {
+            sum[c] += pixel[c];
+        }
+    }
+    sum.map(|c| c / pixels.len() as f32)
+}
+
+/// Perceived brightness (0-255) of an RGB pixel.
+fn luminance(rgb: [f32; 3]) -> f32 {
+    rgb[0] * 0.299 + rgb[1] * 0.587 + rgb[2] * 0.114
+}
+
+fn to_color(rgb: [f32; 3]) -> Color {
+    let [r, g, b] = rgb.map(|c| c.round().clamp(0.0, 255.0) as u8);
+    Color::Rgb(r, g, b)
+}
+
+/// Nearest-neighbour resampling of `image` to `width` x `height` RGB pixels
+/// (row-major), with alpha composited over black.
+fn sample_grid(image: &Image, width: usize, height: usize) -> Vec<[f32; 3]> {
     let img_width = image.width() as usize;
     let img_height = image.height() as usize;
-
     let x_ratio = img_width as f32 / width as f32;
     let y_ratio = img_height as f32 / height as f32;
 
+    let mut pixels = Vec::with_capacity(width * height);
     for y in 0..height {
-        let mut chars = Vec::new();
-
         for x in 0..width {
             let sample_x = (x as f32 * x_ratio) as usize;
             let sample_y = (y as f32 * y_ratio) as usize;
-
-            let brightness = sample_pixel_brightness(img_data, sample_x, sample_y, img_width);
-            let block_char = brightness_to_block(brightness);
-
-            chars.push(block_char);
+            let idx = (sample_y * img_width + sample_x) * 4;
+            let pixel = match image.data.get(idx..idx + 4) {
+                Some(p) => {
+                    let alpha = p[3] as f32 / 255.0;
+                    [p[0] as f32 * alpha, p[1] as f32 * alpha, p[2] as f32 * alpha]
+                }
+                None => [0.0; 3],
+            };
+            pixels.push(pixel);
         }
-
-        let line_str: String = chars.into_iter().collect();
-        lines.push(Line::from(Span::raw(line_str)));
     }
+    pixels
+}
 
-    lines
+/// Reduce each channel of `pixels` (row-major, `width` x `height`) to
+/// `levels` evenly spaced values, diffusing the error Floyd–Steinberg style.
+fn dither_floyd_steinberg(pixels: &mut [[f32; 3]], width: usize, height: usize, levels: usize) {
+    let step = 255.0 / (levels.max(2) - 1) as f32;
+    for y in 0..height {
+        for x in 0..width {
+            let i = y * width + x;
+            let old = pixels[i];
+            let new = old.map(|c| ((c / step).round() * step).clamp(0.0, 255.0));
+            pixels[i] = new;
+
+            let error = [old[0] - new[0], old[1] - new[1], old[2] - new[2]];
+            let mut spread = |dx: isize, dy: usize, weight: f32| {
+                let nx = x as isize + dx;
+                if nx < 0 || nx as usize >= width || y + dy >= height {
+                    return;
+                }
+                let pixel = &mut pixels[(y + dy) * width + nx as usize];
+                for c in 0..3 {
+                    pixel[c] += error[c] * weight;
+                }
+            };
+            spread(1, 0, 7.0 / 16.0);
+            spread(-1, 1, 3.0 / 16.0);
+            spread(0, 1, 5.0 / 16.0);
+            spread(1, 1, 1.0 / 16.0);
+        }
+    }
 }
 
+/// Shade characters, darkest first.
+const SHADE_BLOCKS: &[char] = &[' ', '░', '▒', '▓', '█'];
+
 /// Convert brightness to Unicode block character.
 fn brightness_to_block(brightness: u8) -> char {
-    const BLOCKS: &[char] = &[' ', '░', '▒', '▓', '█'];
-
-    let index = (brightness as usize * (BLOCKS.len() - 1)) / 255;
-    BLOCKS[index]
+    let index = (brightness as usize * (SHADE_BLOCKS.len() - 1)) / 255;
+    SHADE_BLOCKS[index]
 }
 
 /// Render image info as text (fallback when image can't be loaded).
@@ -247,4 +477,78 @@ mod tests {
         let brightness = sample_pixel_brightness(&data, 0, 0, 1);
         assert_eq!(brightness, 0);
     }
+
+    fn gradient(width: u32, height: u32) -> Image {
+        use bevy::render::render_asset::RenderAssetUsages;
+        use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+        // Dark at the top, bright at the bottom
+        let data = (0..height)
+            .flat_map(|y| {
+                let value = (y * 255 / (height - 1)) as u8;
+                (0..width).flat_map(move |_| [value, value / 2, 255 - value, 255])
+            })
+            .collect();
+        Image::new(
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+
+    #[test]
+    fn test_half_block_uses_fg_and_bg() {
+        let image = gradient(4, 8);
+        let options = UnicodeRenderOptions::default();
+        let lines = render_image_to_unicode(&image, 4, 4, &options);
+        assert_eq!(lines.len(), 4);
+
+        for line in &lines {
+            assert_eq!(line.spans.len(), 4);
+            for span in &line.spans {
+                assert_eq!(span.content, "▀");
+                let (Some(fg), Some(bg)) = (span.style.fg, span.style.bg) else {
+                    panic!("cell without both colors: {:?}", span.style);
+                };
+                // Two rows of the vertical gradient per cell
+                assert_ne!(fg, bg);
+            }
+        }
+        assert_eq!(lines[0].spans[0].style.fg, Some(Color::Rgb(0, 0, 255)));
+        assert_eq!(lines[3].spans[0].style.bg, Some(Color::Rgb(255, 127, 0)));
+    }
+
+    #[test]
+    fn test_quarter_block_and_dithering() {
+        let image = gradient(2, 2);
+        let options = UnicodeRenderOptions {
+            mode: UnicodeRenderMode::QuarterBlock,
+            dither: false,
+        };
+        let lines = render_image_to_unicode(&image, 1, 1, &options);
+        // Bright bottom half in the foreground
+        assert_eq!(lines[0].spans[0].content, "▄");
+
+        let options = UnicodeRenderOptions {
+            mode: UnicodeRenderMode::HalfBlock,
+            dither: true,
+        };
+        let lines = render_image_to_unicode(&gradient(2, 16), 2, 8, &options);
+        for span in lines.iter().flat_map(|line| &line.spans) {
+            let Some(Color::Rgb(r, g, b)) = span.style.fg else {
+                panic!("no foreground: {:?}", span.style);
+            };
+            // Every channel lands on the 6-level cube
+            assert!([r, g, b].iter().all(|c| c % 51 == 0), "{:?}", (r, g, b));
+        }
+
+        assert_eq!("quarter_block".parse::<UnicodeRenderMode>().unwrap(), options.mode.next());
+        assert_eq!(UnicodeRenderMode::HalfBlock.previous(), UnicodeRenderMode::Shade);
+    }
 }
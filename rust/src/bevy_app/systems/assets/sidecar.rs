@@ -0,0 +1,257 @@
+//! # Image Sidecar Metadata
+//!
+//! Generation metadata stored next to each image as `<image>.json`
+//! (e.g. `outputs/job-123.png` -> `outputs/job-123.json`, see
+//! [`crate::metadata`]), plus a cache so render systems never touch the
+//! filesystem. The gallery's metadata editor reads and writes sidecars
+//! through [`MetadataForm`]; gallery tags are stored in the sidecar's `tags`.
+
+use anyhow::{Context, Result};
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tracing::{debug, warn};
+
+use crate::bevy_app::resources::{GalleryState, MetadataForm};
+pub use crate::metadata::sidecar_path;
+use crate::metadata::GenerationMetadata;
+
+/// Metadata recorded for a generated image.
+pub type ImageSidecar = GenerationMetadata;
+
+impl ImageSidecar {
+    /// Editor form pre-filled with this sidecar's editable fields.
+    pub fn to_form(&self) -> MetadataForm {
+        let opt = |v: Option<String>| v.unwrap_or_default();
+        MetadataForm {
+            values: [
+                opt(self.prompt.clone()),
+                opt(self.seed.map(|v| v.to_string())),
+                opt(self.steps.map(|v| v.to_string())),
+                opt(self.cfg_scale.map(|v| v.to_string())),
+                self.tags.join(", "),
+            ],
+            ..Default::default()
+        }
+    }
+
+    /// Apply an editor form, leaving fields it doesn't cover untouched.
+    ///
+    /// Empty fields clear the value. Fails without changing anything if a
+    /// numeric field doesn't parse.
+    pub fn apply_form(&mut self, form: &MetadataForm) -> Result<()> {
+        let [prompt, seed, steps, cfg_scale, tags] = &form.values;
+        fn non_empty(v: &str) -> Option<&str> {
+            Some(v.trim()).filter(|v| !v.is_empty())
+        }
+
+        let seed = non_empty(seed)
+            .map(|v| v.parse::<u64>().context("Seed must be a whole number"))
+            .transpose()?;
+        let steps = non_empty(steps)
+            .map(|v| v.parse::<u32>().context("Steps must be a whole number"))
+            .transpose()?;
+        let cfg_scale = non_empty(cfg_scale)
+            .map(|v| v.parse::<f32>().context("CFG scale must be a number"))
+            .transpose()?;
+
+        self.prompt = non_empty(prompt).map(str::to_string);
+        self.seed = seed;
+        self.steps = steps;
+        self.cfg_scale = cfg_scale;
+        self.tags = tags
+            .split(',')
+            .filter_map(non_empty)
+            .map(str::to_string)
+            .collect();
+        Ok(())
+    }
+}
+
+/// Read the sidecar for an image, if there is a readable one.
+pub fn load_sidecar(image_path: &Path) -> Option<ImageSidecar> {
+    GenerationMetadata::load(image_path).unwrap_or_else(|e| {
+        warn!("Ignoring malformed sidecar: {:#}", e);
+        None
+    })
+}
+
+/// Write the sidecar for an image.
+pub fn save_sidecar(image_path: &Path, sidecar: &ImageSidecar) -> Result<()> {
+    sidecar.save(image_path)
+}
+
+/// Apply an editor form to an image's sidecar and write it back.
+///
+/// Fields the form doesn't cover (e.g. generation time) are kept. Returns
+/// the saved sidecar.
+pub fn save_metadata_form(image_path: &Path, form: &MetadataForm) -> Result<ImageSidecar> {
+    let mut sidecar = load_sidecar(image_path).unwrap_or_default();
+    sidecar.apply_form(form)?;
+    save_sidecar(image_path, &sidecar)?;
+    Ok(sidecar)
+}
+
+/// Add a tag to an image's sidecar and write it back straight away.
+///
+/// The tag is trimmed; one the image already has (ignoring case) isn't
+/// added twice. Returns the saved sidecar.
+pub fn add_tag(image_path: &Path, tag: &str) -> Result<ImageSidecar> {
+    let tag = tag.trim();
+    if tag.is_empty() {
+        anyhow::bail!("Tag is empty");
+    }
+
+    let mut sidecar = load_sidecar(image_path).unwrap_or_default();
+    if !sidecar.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+        sidecar.tags.push(tag.to_string());
+        save_sidecar(image_path, &sidecar)?;
+    }
+    Ok(sidecar)
+}
+
+/// Sidecar metadata for gallery images, keyed by gallery path.
+///
+/// `None` entries remember images without a sidecar so they aren't re-read.
+#[derive(Resource, Debug, Default)]
+pub struct SidecarCache {
+    entries: HashMap<PathBuf, Option<ImageSidecar>>,
+}
+
+impl SidecarCache {
+    /// Sidecar for a gallery image (if loaded and present).
+    pub fn get(&self, path: &Path) -> Option<&ImageSidecar> {
+        self.entries.get(path).and_then(Option::as_ref)
+    }
+
+    /// Generation time for a gallery image, if known.
+    pub fn duration(&self, path: &Path) -> Option<f32> {
+        self.get(path).and_then(|s| s.duration_s)
+    }
+
+    /// Record metadata for an image (e.g. straight from a completed job).
+    pub fn insert(&mut self, path: PathBuf, sidecar: ImageSidecar) {
+        self.entries.insert(path, Some(sidecar));
+    }
+
+    /// Whether the image has been looked up already.
+    pub fn contains(&self, path: &Path) -> bool {
+        self.entries.contains_key(path)
+    }
+
+    /// Read the sidecar for an image unless it was looked up before.
+    pub fn load(&mut self, path: &Path) {
+        if !self.contains(path) {
+            self.entries.insert(path.to_path_buf(), load_sidecar(path));
+        }
+    }
+}
+
+/// System to read sidecars for gallery images that haven't been looked up yet.
+///
+/// Tags found in them are copied to the gallery for the tag filter.
+pub fn load_gallery_sidecars(mut gallery: ResMut<GalleryState>, mut cache: ResMut<SidecarCache>) {
+    if !gallery.is_changed() {
+        return;
+    }
+
+    let unseen: Vec<PathBuf> = gallery
+        .images
+        .iter()
+        .filter(|path| !cache.contains(path))
+        .cloned()
+        .collect();
+    for path in unseen {
+        debug!("Loading sidecar for {:?}", path);
+        cache.load(&path);
+        let tags = cache.get(&path).map(|s| s.tags.clone()).unwrap_or_default();
+        if !tags.is_empty() {
+            gallery.set_tags(path, tags);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_sidecar_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = dir.path().join("job-1.png");
+        let sidecar = ImageSidecar {
+            prompt: Some("pixel art knight".to_string()),
+            duration_s: Some(4.2),
+            ..Default::default()
+        };
+
+        save_sidecar(&image, &sidecar).unwrap();
+        assert!(dir.path().join("job-1.json").exists());
+        assert_eq!(load_sidecar(&image), Some(sidecar));
+    }
+
+    #[test]
+    fn test_cache_remembers_missing_sidecars() {
+        let dir = tempfile::tempdir().unwrap();
+        let with = dir.path().join("with.png");
+        let without = dir.path().join("without.png");
+        fs::write(sidecar_path(&with), r#"{"duration_s": 3.5, "seed": 42}"#).unwrap();
+
+        let mut cache = SidecarCache::default();
+        cache.load(&with);
+        cache.load(&without);
+
+        assert_eq!(cache.duration(&with), Some(3.5));
+        assert_eq!(cache.duration(&without), None);
+        assert!(cache.contains(&without));
+    }
+
+    #[test]
+    fn test_edited_metadata_saves_and_reloads() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = dir.path().join("job-1.png");
+        fs::write(sidecar_path(&image), r#"{"prompt": "knight", "duration_s": 2.5}"#).unwrap();
+
+        let mut form = load_sidecar(&image).unwrap().to_form();
+        form.values = [
+            "pixel art knight".to_string(),
+            "42".to_string(),
+            "30".to_string(),
+            "7.5".to_string(),
+            "hero, sprite,".to_string(),
+        ];
+        save_metadata_form(&image, &form).unwrap();
+
+        let reloaded = load_sidecar(&image).unwrap();
+        assert_eq!(reloaded.prompt.as_deref(), Some("pixel art knight"));
+        assert_eq!(reloaded.seed, Some(42));
+        assert_eq!(reloaded.steps, Some(30));
+        assert_eq!(reloaded.cfg_scale, Some(7.5));
+        assert_eq!(reloaded.tags, vec!["hero", "sprite"]);
+        // Not part of the form, so kept as it was
+        assert_eq!(reloaded.duration_s, Some(2.5));
+
+        // Invalid numbers are rejected and the file is left alone
+        form.values[2] = "thirty".to_string();
+        assert!(save_metadata_form(&image, &form).is_err());
+        assert_eq!(load_sidecar(&image).unwrap().steps, Some(30));
+    }
+
+    #[test]
+    fn test_add_tag_persists_immediately() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = dir.path().join("job-1.png");
+        fs::write(sidecar_path(&image), r#"{"prompt": "knight"}"#).unwrap();
+
+        add_tag(&image, " hero ").unwrap();
+        let sidecar = add_tag(&image, "HERO").unwrap();
+        assert_eq!(sidecar.tags, vec!["hero"]);
+        add_tag(&image, "sprite").unwrap();
+
+        let reloaded = load_sidecar(&image).unwrap();
+        assert_eq!(reloaded.tags, vec!["hero", "sprite"]);
+        assert_eq!(reloaded.prompt.as_deref(), Some("knight"));
+        assert!(add_tag(&image, "  ").is_err());
+    }
+}
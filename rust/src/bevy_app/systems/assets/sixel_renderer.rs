@@ -2,17 +2,32 @@
 //!
 //! Converts Bevy Image assets to Sixel protocol format for terminal display.
 //! Port of the original sixel/image_renderer.rs to work with Bevy ECS.
+//! Images are reduced to [`SixelRenderOptions::max_colors`] colors before
+//! encoding; smaller palettes encode and draw faster on slow terminals.
 
 use anyhow::{Context, Result};
 use bevy::prelude::*;
-use image::{imageops::FilterType, DynamicImage, RgbaImage};
+use image::{imageops::FilterType, DynamicImage, RgbImage, RgbaImage};
+use ratatui::style::Color;
+use std::collections::HashSet;
 use std::process::Command;
+use std::sync::OnceLock;
 use tempfile::NamedTempFile;
 use tracing::{debug, warn};
 
+use crate::bevy_app::resources::AppTheme;
+use crate::sixel::{detect_sixel_support, TerminalCapability};
+use crate::ui::theme::color_to_rgb;
+
 /// Maximum colors for Sixel (256 for best terminal compatibility)
 pub const MAX_SIXEL_COLORS: usize = 256;
 
+/// NeuQuant sampling factor (1 is slowest and best, 30 fastest)
+const QUANTIZE_SAMPLE_FACTOR: i32 = 10;
+
+/// Approximate size of a terminal cell in pixels (width, height)
+pub const CELL_PIXELS: (u32, u32) = (8, 16);
+
 /// Options for Sixel rendering
 #[derive(Debug, Clone)]
 pub struct SixelRenderOptions {
@@ -24,6 +39,10 @@ pub struct SixelRenderOptions {
     pub preserve_aspect: bool,
     /// Whether to use high quality resizing
     pub high_quality: bool,
+    /// Color transparent pixels are composited over (Sixel has no alpha)
+    pub background: Color,
+    /// Palette size (2 to [`MAX_SIXEL_COLORS`])
+    pub max_colors: usize,
 }
 
 impl Default for SixelRenderOptions {
@@ -33,6 +52,8 @@ impl Default for SixelRenderOptions {
             height: 20,
             preserve_aspect: true,
             high_quality: true,
+            background: AppTheme::default().colors.background,
+            max_colors: MAX_SIXEL_COLORS,
         }
     }
 }
@@ -48,6 +69,14 @@ pub fn supports_sixel() -> bool {
         .unwrap_or(false)
 }
 
+/// Check if the terminal shows iTerm2 inline images
+///
+/// Detected from the environment once; iTerm2 previews are tried before Sixel.
+pub fn supports_iterm() -> bool {
+    static ITERM: OnceLock<bool> = OnceLock::new();
+    *ITERM.get_or_init(|| detect_sixel_support() == TerminalCapability::Iterm)
+}
+
 /// Render a Bevy Image to Sixel string
 ///
 /// This is the main entry point for converting Bevy images to Sixel format.
@@ -70,18 +99,60 @@ pub fn render_image_sixel(
         options.height
     );
 
+    let prepared = prepare_for_sixel(image, options)?;
+    let sixel_data = encode_to_sixel(&prepared, options.max_colors)?;
+
+    debug!("Successfully encoded image to Sixel ({} bytes)", sixel_data.len());
+
+    Ok(sixel_data)
+}
+
+/// Resize, flatten and quantize `image` as the Sixel encoder gets it
+fn prepare_for_sixel(image: &Image, options: &SixelRenderOptions) -> Result<RgbImage> {
     // Convert Bevy Image to DynamicImage
     let dynamic_img = bevy_image_to_dynamic(image)?;
 
     // Resize if needed
     let resized = resize_image(dynamic_img, options)?;
 
-    // Convert to Sixel using img2sixel
-    let sixel_data = encode_to_sixel(&resized)?;
+    // Flatten transparency onto the background (img2sixel drops alpha)
+    let flattened = composite_over_background(&resized, color_to_rgb(options.background));
+    Ok(quantize_to_palette(flattened, options.max_colors))
+}
 
-    debug!("Successfully encoded image to Sixel ({} bytes)", sixel_data.len());
+/// Reduce an image to at most `max_colors` colors
+///
+/// Images already within the palette (most pixel art) are left alone, as is
+/// everything at [`MAX_SIXEL_COLORS`], where img2sixel's own palette applies.
+fn quantize_to_palette(mut img: RgbImage, max_colors: usize) -> RgbImage {
+    let max_colors = max_colors.clamp(2, MAX_SIXEL_COLORS);
+    if max_colors == MAX_SIXEL_COLORS || fits_palette(&img, max_colors) {
+        return img;
+    }
 
-    Ok(sixel_data)
+    debug!("Quantizing {}x{} image to {} colors", img.width(), img.height(), max_colors);
+    // NeuQuant works on RGBA
+    let rgba: Vec<u8> = img.pixels().flat_map(|p| [p[0], p[1], p[2], 255]).collect();
+    let palette = color_quant::NeuQuant::new(QUANTIZE_SAMPLE_FACTOR, max_colors, &rgba);
+    for pixel in img.pixels_mut() {
+        let [r, g, b] = pixel.0;
+        let mut mapped = [r, g, b, 255];
+        palette.map_pixel(&mut mapped);
+        pixel.0 = [mapped[0], mapped[1], mapped[2]];
+    }
+    img
+}
+
+/// Whether `img` has no more than `max_colors` distinct colors
+fn fits_palette(img: &RgbImage, max_colors: usize) -> bool {
+    let mut colors = HashSet::new();
+    for pixel in img.pixels() {
+        colors.insert(pixel.0);
+        if colors.len() > max_colors {
+            return false;
+        }
+    }
+    true
 }
 
 /// Convert Bevy Image to image crate's DynamicImage
@@ -104,9 +175,8 @@ fn resize_image(
 ) -> Result<DynamicImage> {
     let (orig_width, orig_height) = (img.width(), img.height());
 
-    // Terminal cells are approximately 8x16 pixels
-    let target_width = options.width as u32 * 8;
-    let target_height = options.height as u32 * 16;
+    let target_width = options.width as u32 * CELL_PIXELS.0;
+    let target_height = options.height as u32 * CELL_PIXELS.1;
 
     // Skip resize if already close to target size
     if orig_width <= target_width && orig_height <= target_height {
@@ -161,11 +231,23 @@ fn calculate_aspect_preserving_dimensions(
     }
 }
 
-/// Encode DynamicImage to Sixel using img2sixel command
-fn encode_to_sixel(img: &DynamicImage) -> Result<String> {
-    // Convert to RGB8 (Sixel doesn't need alpha)
-    let rgb_img = img.to_rgb8();
+/// Blend each pixel over `background` by its alpha, dropping the alpha channel.
+fn composite_over_background(img: &DynamicImage, background: [u8; 3]) -> RgbImage {
+    let rgba = img.to_rgba8();
+    RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+        let [r, g, b, a] = rgba.get_pixel(x, y).0;
+        let alpha = a as u16;
+        let blend = |fg: u8, bg: u8| ((fg as u16 * alpha + bg as u16 * (255 - alpha)) / 255) as u8;
+        image::Rgb([
+            blend(r, background[0]),
+            blend(g, background[1]),
+            blend(b, background[2]),
+        ])
+    })
+}
 
+/// Encode an RGB image to Sixel using img2sixel command
+fn encode_to_sixel(rgb_img: &RgbImage, max_colors: usize) -> Result<String> {
     // Create temporary file for PNG
     let temp_file = NamedTempFile::new()
         .context("Failed to create temporary file")?;
@@ -178,6 +260,8 @@ fn encode_to_sixel(img: &DynamicImage) -> Result<String> {
 
     // Execute img2sixel
     let output = Command::new("img2sixel")
+        .arg("-p")
+        .arg(max_colors.clamp(2, MAX_SIXEL_COLORS).to_string())
         .arg(temp_path)
         .output()
         .context("Failed to execute img2sixel. Is it installed and in PATH?")?;
@@ -204,6 +288,62 @@ mod tests {
         assert_eq!(opts.height, 20);
         assert!(opts.preserve_aspect);
         assert!(opts.high_quality);
+        assert_eq!(opts.background, AppTheme::default().colors.background);
+        assert_eq!(opts.max_colors, MAX_SIXEL_COLORS);
+    }
+
+    #[test]
+    fn test_transparency_composited_over_background() {
+        // Half-transparent mid-gray sprite
+        let sprite = DynamicImage::ImageRgba8(RgbaImage::from_pixel(
+            2,
+            2,
+            image::Rgba([100, 100, 100, 128]),
+        ));
+
+        let over_white = composite_over_background(&sprite, color_to_rgb(Color::Rgb(255, 255, 255)));
+        let over_black = composite_over_background(&sprite, color_to_rgb(Color::Black));
+
+        let white_px = over_white.get_pixel(0, 0).0;
+        let black_px = over_black.get_pixel(0, 0).0;
+        assert!(white_px.iter().zip(black_px.iter()).all(|(w, b)| w > b));
+        assert_eq!(black_px, [50, 50, 50]);
+    }
+
+    #[test]
+    fn test_render_at_reduced_palette() {
+        // Smooth gradient with far more than 256 colors
+        let data: Vec<u8> = (0..64u32 * 64)
+            .flat_map(|i| [(i % 64 * 4) as u8, (i / 64 * 4) as u8, (i % 7 * 36) as u8, 255])
+            .collect();
+        let image = Image::new(
+            bevy::render::render_resource::Extent3d {
+                width: 64,
+                height: 64,
+                depth_or_array_layers: 1,
+            },
+            bevy::render::render_resource::TextureDimension::D2,
+            data,
+            bevy::render::render_resource::TextureFormat::Rgba8UnormSrgb,
+            bevy::render::render_asset::RenderAssetUsages::default(),
+        );
+        let options = |max_colors| SixelRenderOptions { max_colors, ..Default::default() };
+
+        let full = prepare_for_sixel(&image, &options(256)).unwrap();
+        let reduced = prepare_for_sixel(&image, &options(16)).unwrap();
+        assert_ne!(full, reduced);
+        assert!(!fits_palette(&full, 256));
+        assert!(fits_palette(&reduced, 16));
+
+        // Skip encoding if img2sixel not installed
+        if !supports_sixel() {
+            return;
+        }
+        for max_colors in [16, 256] {
+            let sixel = render_image_sixel(&image, &options(max_colors)).unwrap();
+            assert!(sixel.starts_with("\x1bP"), "not a Sixel sequence: {:?}", sixel);
+            assert!(sixel.trim_end().ends_with("\x1b\\"), "unterminated Sixel: {:?}", sixel);
+        }
     }
 
     #[test]
@@ -243,7 +383,7 @@ mod tests {
             bevy::render::render_resource::TextureDimension::D2,
             data,
             bevy::render::render_resource::TextureFormat::Rgba8UnormSrgb,
-            bevy::render::texture::RenderAssetUsages::default(),
+            bevy::render::render_asset::RenderAssetUsages::default(),
         );
 
         let dynamic_img = bevy_image_to_dynamic(&bevy_img);
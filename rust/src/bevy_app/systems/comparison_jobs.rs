@@ -0,0 +1,89 @@
+//! # Comparison Job Tracking
+//!
+//! Shows the images of jobs queued by the Comparison screen in their panes.
+//! A pane stops waiting when its job fails or is cancelled.
+
+use bevy::prelude::*;
+
+use crate::bevy_app::components::{Job, JobStatus};
+use crate::bevy_app::resources::comparison_state::GenerationMetadata;
+use crate::bevy_app::resources::{AppState, ComparisonState, SettingsState};
+
+/// Update the comparison panes from jobs whose status changed.
+pub fn track_comparison_jobs(
+    jobs: Query<&Job, Changed<Job>>,
+    mut comparison: ResMut<ComparisonState>,
+    settings: Option<Res<SettingsState>>,
+    mut app_state: ResMut<AppState>,
+) {
+    for job in jobs.iter() {
+        if comparison.pane_for_job(&job.id).is_none() {
+            continue;
+        }
+        match &job.status {
+            JobStatus::Complete {
+                image_path,
+                duration_s,
+            } => {
+                let generation = settings.as_ref().map(|s| &s.generation);
+                // Sent without a size or steps, so with the configured defaults
+                let metadata = GenerationMetadata {
+                    size: generation.map_or((1024, 1024), |g| g.default_size),
+                    seed: job.seed,
+                    inference_time_s: *duration_s,
+                    steps: job
+                        .steps
+                        .or(job.metadata.as_ref().and_then(|m| m.steps))
+                        .or(generation.map(|g| g.default_steps))
+                        .unwrap_or_default(),
+                };
+                comparison.finish_pane_job(&job.id, image_path.clone(), metadata);
+                info!("Comparison: Job {} finished", job.id);
+            }
+            JobStatus::Failed { .. } | JobStatus::Cancelled => {
+                comparison.fail_pane_job(&job.id);
+                warn!("Comparison: Job {} didn't finish", job.id);
+            }
+            _ => continue,
+        }
+        app_state.request_redraw();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bevy_app::resources::comparison_state::ComparisonPane;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_finished_job_fills_its_pane() {
+        let mut comparison = ComparisonState {
+            is_running: true,
+            ..Default::default()
+        };
+        comparison.set_pane_job(ComparisonPane::Right, "job-right".to_string());
+
+        let mut app = App::new();
+        app.insert_resource(comparison);
+        app.init_resource::<AppState>();
+        app.add_systems(Update, track_comparison_jobs);
+        let mut job = Job::new("job-right".to_string(), "knight".to_string());
+        job.seed = Some(42);
+        let entity = app.world_mut().spawn(job).id();
+        app.update();
+        assert!(app.world().resource::<ComparisonState>().right_image.is_none());
+
+        app.world_mut().get_mut::<Job>(entity).unwrap().status = JobStatus::Complete {
+            image_path: PathBuf::from("outputs/job-right.png"),
+            duration_s: 2.5,
+        };
+        app.update();
+
+        let comparison = app.world().resource::<ComparisonState>();
+        assert_eq!(comparison.right_image, Some(PathBuf::from("outputs/job-right.png")));
+        let metadata = comparison.right_metadata.as_ref().unwrap();
+        assert_eq!((metadata.seed, metadata.inference_time_s), (Some(42), 2.5));
+        assert!(!comparison.pane_running(ComparisonPane::Right));
+    }
+}
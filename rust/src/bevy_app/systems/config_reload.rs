@@ -0,0 +1,216 @@
+//! # Config Hot-Reload System
+//!
+//! Picks up edits made to `config.toml` outside the app. The file's mtime is
+//! checked every [`CONFIG_POLL_INTERVAL`]; when it changes, the configured
+//! values in [`SettingsState`] are replaced by the file's. Saves made through
+//! [`ConfigWatcher::save`] record their own mtime so they aren't reloaded.
+//!
+//! While the Settings screen has unsaved edits the file isn't reloaded, as
+//! that would throw the edits away. A notification asks the user to choose:
+//! Ctrl+S on the Settings screen saves their edits over the file, Ctrl+R
+//! loads the file instead.
+
+use anyhow::Result;
+use bevy::prelude::*;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::bevy_app::resources::{NotificationLevel, Notifications, SettingsState};
+
+/// How often the config file's mtime is checked.
+pub const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The watched config file and the mtime it had when last read or written.
+#[derive(Resource, Debug, Clone)]
+pub struct ConfigWatcher {
+    pub path: PathBuf,
+    pub last_mtime: Option<SystemTime>,
+    /// The file changed while there were unsaved edits, and hasn't been
+    /// reloaded or saved over since
+    pub external_change: bool,
+}
+
+impl ConfigWatcher {
+    /// Watch `path`, treating its current contents as already loaded.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let last_mtime = modified(&path);
+        Self {
+            path,
+            last_mtime,
+            external_change: false,
+        }
+    }
+
+    /// Save `settings` to the watched file without triggering a reload.
+    pub fn save(&mut self, settings: &SettingsState) -> Result<()> {
+        settings.save_to(&self.path)?;
+        self.last_mtime = modified(&self.path);
+        self.external_change = false;
+        Ok(())
+    }
+
+    /// Replace the configured values in `settings` with the watched file's,
+    /// discarding unsaved edits.
+    pub fn reload(&mut self, settings: &mut SettingsState) -> Result<()> {
+        self.last_mtime = modified(&self.path);
+        let loaded = SettingsState::load_from(&self.path)?;
+        settings.apply_loaded(loaded);
+        self.external_change = false;
+        Ok(())
+    }
+}
+
+impl Default for ConfigWatcher {
+    fn default() -> Self {
+        Self::new(SettingsState::config_path())
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Reload settings when the config file's mtime has changed.
+///
+/// A file that fails to parse leaves the current settings in place; it's
+/// tried again after its next change. With unsaved edits nothing is
+/// reloaded; the user is notified (once per set of edits) instead.
+pub fn reload_changed_config(
+    mut watcher: ResMut<ConfigWatcher>,
+    mut settings: ResMut<SettingsState>,
+    mut notifications: Option<ResMut<Notifications>>,
+) {
+    let mtime = modified(&watcher.path);
+    if mtime == watcher.last_mtime {
+        return;
+    }
+    watcher.last_mtime = mtime;
+    if mtime.is_none() {
+        // Deleted: keep what we have rather than falling back to defaults
+        return;
+    }
+
+    if settings.dirty {
+        if !watcher.external_change {
+            watcher.external_change = true;
+            warn!("{:?} changed on disk; keeping unsaved settings", watcher.path);
+            if let Some(notifications) = notifications.as_mut() {
+                notifications.notify(
+                    NotificationLevel::Warning,
+                    "config.toml changed on disk. Kept your unsaved settings: \
+                     Ctrl+S on Settings saves them, Ctrl+R loads the file",
+                );
+            }
+        }
+        return;
+    }
+
+    match watcher.reload(&mut settings) {
+        Ok(()) => info!("Settings reloaded from {:?}", watcher.path),
+        Err(e) => warn!("Keeping current settings; failed to reload config: {:#}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    fn app_watching(path: &Path) -> App {
+        let mut app = App::new();
+        app.insert_resource(ConfigWatcher::new(path));
+        app.insert_resource(SettingsState::default());
+        app.add_systems(Update, reload_changed_config);
+        app
+    }
+
+    /// Write `content` to `path` with the given mtime, so tests don't depend
+    /// on the filesystem's timestamp resolution.
+    fn write_at(path: &Path, content: &str, mtime: SystemTime) {
+        std::fs::write(path, content).unwrap();
+        File::options().write(true).open(path).unwrap().set_modified(mtime).unwrap();
+    }
+
+    #[test]
+    fn test_external_edit_is_reloaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let mut app = app_watching(&path);
+        app.world_mut().resource_mut::<SettingsState>().selected_index = 8;
+
+        let mut edited = SettingsState::default();
+        edited.generation.default_steps = 45;
+        let first_edit = SystemTime::UNIX_EPOCH + Duration::from_secs(10);
+        write_at(&path, &edited.to_toml().unwrap(), first_edit);
+        app.update();
+
+        let settings = app.world().resource::<SettingsState>();
+        assert_eq!(settings.generation.default_steps, 45);
+        assert_eq!(settings.selected_index, 8);
+
+        // A broken edit keeps the last good settings
+        write_at(&path, "not = [valid", first_edit + Duration::from_secs(10));
+        app.update();
+        assert_eq!(app.world().resource::<SettingsState>().generation.default_steps, 45);
+    }
+
+    #[test]
+    fn test_own_save_is_not_reloaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let mut app = app_watching(&path);
+
+        let mut saved = SettingsState::default();
+        saved.ui.fps_limit = 30;
+        app.world_mut().resource_mut::<ConfigWatcher>().save(&saved).unwrap();
+
+        // Changed in the app since the save; a reload would undo this
+        app.world_mut().resource_mut::<SettingsState>().ui.fps_limit = 90;
+        app.update();
+        assert_eq!(app.world().resource::<SettingsState>().ui.fps_limit, 90);
+    }
+
+    #[test]
+    fn test_dirty_edits_survive_external_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let mut app = app_watching(&path);
+        app.init_resource::<Notifications>();
+        {
+            let mut settings = app.world_mut().resource_mut::<SettingsState>();
+            settings.selected_index = 1; // default_steps
+            settings.start_editing();
+            settings.edit_buffer = "50".to_string();
+            settings.finish_editing().unwrap();
+        }
+
+        let mut edited = SettingsState::default();
+        edited.ui.fps_limit = 30;
+        let edit_time = SystemTime::UNIX_EPOCH + Duration::from_secs(10);
+        write_at(&path, &edited.to_toml().unwrap(), edit_time);
+        app.update();
+        write_at(&path, &edited.to_toml().unwrap(), edit_time + Duration::from_secs(10));
+        app.update();
+
+        let settings = app.world().resource::<SettingsState>();
+        assert_eq!(settings.generation.default_steps, 50);
+        assert_eq!(settings.ui.fps_limit, SettingsState::default().ui.fps_limit);
+        assert!(settings.dirty);
+        assert_eq!(settings.undo_stack.len(), 1);
+        assert!(app.world().resource::<ConfigWatcher>().external_change);
+        // Told once, not on every change
+        assert_eq!(app.world().resource::<Notifications>().len(), 1);
+
+        // Choosing the file's settings drops the edits and their undo history
+        app.world_mut().resource_scope(|world, mut watcher: Mut<ConfigWatcher>| {
+            watcher.reload(&mut world.resource_mut::<SettingsState>()).unwrap();
+        });
+        let settings = app.world().resource::<SettingsState>();
+        assert_eq!(settings.ui.fps_limit, 30);
+        assert_eq!(settings.generation.default_steps, 30);
+        assert!(!settings.dirty);
+        assert!(settings.undo_stack.is_empty());
+        assert!(!app.world().resource::<ConfigWatcher>().external_change);
+    }
+}
@@ -0,0 +1,93 @@
+//! # Backend Host Check
+//!
+//! Warns when `backend.zmq_host` doesn't resolve. Editing the setting only
+//! checks that the host is well formed, as a lookup there would block the
+//! frame on DNS, so the name is looked up here on a background thread
+//! whenever it changes (edited on the Settings screen or reloaded from
+//! config.toml).
+
+use anyhow::Result;
+use bevy::prelude::*;
+use std::net::{IpAddr, ToSocketAddrs};
+use std::thread::JoinHandle;
+
+use crate::bevy_app::resources::{NotificationLevel, Notifications, SettingsState};
+
+/// Look up the backend host when it changes, and report a host that doesn't
+/// resolve once the lookup finishes.
+///
+/// Runs every frame so a finished lookup is reported straight away. Only
+/// one lookup runs at a time; a result for a host that has since changed is
+/// dropped.
+pub fn check_backend_host(
+    settings: Res<SettingsState>,
+    mut checked: Local<Option<String>>,
+    mut pending: Local<Option<(String, JoinHandle<Result<()>>)>>,
+    mut notifications: ResMut<Notifications>,
+) {
+    let host = &settings.backend.zmq_host;
+
+    if pending.as_ref().is_some_and(|(_, lookup)| lookup.is_finished()) {
+        if let Some((looked_up, lookup)) = pending.take() {
+            match lookup.join() {
+                Ok(Err(e)) if looked_up == *host => {
+                    warn!("{:#}", e);
+                    notifications.notify(NotificationLevel::Warning, format!("{:#}", e));
+                }
+                Ok(_) => {}
+                Err(_) => warn!("Backend host lookup thread panicked"),
+            }
+        }
+    }
+
+    if pending.is_some() || checked.as_ref() == Some(host) {
+        return;
+    }
+    *checked = Some(host.clone());
+    if host.parse::<IpAddr>().is_ok() {
+        return;
+    }
+
+    let looked_up = host.clone();
+    *pending = Some((host.clone(), std::thread::spawn(move || resolve_host(&looked_up))));
+}
+
+/// Check that `host` resolves to at least one address.
+fn resolve_host(host: &str) -> Result<()> {
+    let resolves = (host, 0).to_socket_addrs().is_ok_and(|mut a| a.next().is_some());
+    if !resolves {
+        anyhow::bail!("Backend host does not resolve: {}", host);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_notifies_once_for_a_host_that_does_not_resolve() {
+        let mut settings = SettingsState::default();
+        settings.backend.zmq_host = "gpu-box.invalid".to_string();
+
+        let mut app = App::new();
+        app.insert_resource(settings)
+            .init_resource::<Notifications>()
+            .add_systems(Update, check_backend_host);
+
+        // The lookup runs in the background, so the frame isn't held up
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while app.world().resource::<Notifications>().is_empty() && Instant::now() < deadline {
+            app.update();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        app.update();
+
+        let notifications = app.world().resource::<Notifications>();
+        assert_eq!(notifications.len(), 1);
+        let notification = notifications.visible().next().unwrap();
+        assert_eq!(notification.level, NotificationLevel::Warning);
+        assert_eq!(notification.message, "Backend host does not resolve: gpu-box.invalid");
+    }
+}
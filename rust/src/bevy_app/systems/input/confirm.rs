@@ -0,0 +1,105 @@
+//! # Confirm Modal Input
+//!
+//! Answers the shared Yes/No dialog. While it's open every key is swallowed so
+//! the screen underneath doesn't act on it.
+
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::*;
+use bevy_ratatui::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyEventKind};
+
+use crate::bevy_app::events::ConfirmedAction;
+use crate::bevy_app::resources::{AppState, ConfirmModal};
+
+/// Handle the confirmation dialog: y/Enter confirms, n/Esc cancels.
+pub fn handle_confirm_input(
+    mut key_events: ResMut<Events<KeyEvent>>,
+    mut key_cursor: Local<EventCursor<KeyEvent>>,
+    mut modal: ResMut<ConfirmModal>,
+    mut confirmed: EventWriter<ConfirmedAction>,
+    mut app_state: ResMut<AppState>,
+) {
+    let keys: Vec<KeyEvent> = key_cursor.read(&key_events).cloned().collect();
+    if !modal.open {
+        return;
+    }
+
+    for event in keys {
+        if event.kind == KeyEventKind::Release || !modal.open {
+            continue;
+        }
+        match event.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                if let Some(action) = modal.confirm() {
+                    confirmed.send(ConfirmedAction(action));
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                debug!("Confirmation cancelled: {}", modal.message);
+                modal.cancel();
+            }
+            _ => continue,
+        }
+        app_state.request_redraw();
+    }
+
+    key_events.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bevy_app::resources::ConfirmAction;
+    use crossterm::event::KeyModifiers;
+
+    fn app_with_modal() -> App {
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.add_event::<ConfirmedAction>();
+        app.init_resource::<ConfirmModal>();
+        app.init_resource::<AppState>();
+        app.add_systems(Update, handle_confirm_input);
+        app.world_mut()
+            .resource_mut::<ConfirmModal>()
+            .ask("Delete SDXL VAE?", ConfirmAction::DeleteModel("SDXL VAE".to_string()));
+        app
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.world_mut()
+            .send_event(KeyEvent(crossterm::event::KeyEvent::new(code, KeyModifiers::NONE)));
+        app.update();
+    }
+
+    fn confirmed(app: &App) -> Vec<ConfirmAction> {
+        let events = app.world().resource::<Events<ConfirmedAction>>();
+        events.get_cursor().read(events).map(|e| e.0.clone()).collect()
+    }
+
+    #[test]
+    fn test_confirm_dispatches_action() {
+        let mut app = app_with_modal();
+        // Unrelated keys leave the dialog up and never reach other systems
+        press(&mut app, KeyCode::Char('d'));
+        assert!(app.world().resource::<ConfirmModal>().open);
+        assert!(app.world().resource::<Events<KeyEvent>>().is_empty());
+
+        press(&mut app, KeyCode::Char('y'));
+        assert!(!app.world().resource::<ConfirmModal>().open);
+        assert_eq!(
+            confirmed(&app),
+            vec![ConfirmAction::DeleteModel("SDXL VAE".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_cancel_clears_modal() {
+        let mut app = app_with_modal();
+        press(&mut app, KeyCode::Esc);
+
+        let modal = app.world().resource::<ConfirmModal>();
+        assert!(!modal.open);
+        assert_eq!(modal.on_confirm, None);
+        assert!(confirmed(&app).is_empty());
+    }
+}
@@ -9,29 +9,61 @@ use crossterm::event::KeyCode;
 use crate::bevy_app::resources::*;
 
 /// Handle global keyboard input (quit, help, etc.).
+#[allow(clippy::too_many_arguments)]
 pub fn handle_keyboard_input(
     mut events: EventReader<KeyEvent>,
-    current_screen: Res<CurrentScreen>,
+    mut current_screen: ResMut<CurrentScreen>,
     mut app_state: ResMut<AppState>,
+    comparison: Option<Res<ComparisonState>>,
+    gallery: Option<Res<GalleryState>>,
+    models: Option<Res<ModelsState>>,
+    settings: Option<Res<SettingsState>>,
+    keybindings: Res<Keybindings>,
+    mut notifications: Option<ResMut<Notifications>>,
+    mut help: Option<ResMut<HelpState>>,
 ) {
+    let typing = match current_screen.0 {
+        Screen::Comparison => comparison.as_ref().is_some_and(|c| c.editing_prompt),
+        Screen::Gallery => gallery
+            .as_ref()
+            .is_some_and(|g| g.is_editing_metadata() || g.is_entering_tag()),
+        Screen::Models => models.as_ref().is_some_and(|m| m.filter_editing),
+        Screen::Help => help.as_ref().is_some_and(|h| h.searching),
+        Screen::Settings => settings.as_ref().is_some_and(|s| s.is_editing),
+        _ => false,
+    };
+
     for event in events.read() {
         match event.code {
-            // Quit on 'q' (except on Generation screen where it's typing)
-            KeyCode::Char('q') | KeyCode::Char('Q') => {
-                if current_screen.0 != Screen::Generation {
+            // Quit on 'q' (except where it's typing)
+            _ if keybindings.matches(KeyAction::Quit, event) => {
+                if current_screen.0 != Screen::Generation && !typing {
                     info!("Quit requested via 'q' key");
                     app_state.quit();
                 }
             }
 
-            // Help screen
-            KeyCode::Char('?') | KeyCode::Char('h') | KeyCode::Char('H') => {
-                // Help screen navigation handled by navigation system
+            // Clear the notification toasts
+            _ if keybindings.matches(KeyAction::DismissNotifications, event) => {
+                if let Some(notifications) = notifications.as_mut() {
+                    notifications.dismiss_all();
+                }
+            }
+
+            // Help, scrolled to the current screen's section (except where
+            // '?' is typing)
+            KeyCode::Char('?') if current_screen.0 != Screen::Generation && !typing => {
+                if let Some(help) = help.as_mut() {
+                    help.open_at(current_screen.0);
+                }
+                current_screen.0 = Screen::Help;
+                app_state.request_redraw();
+                info!("Opened help");
             }
 
             // Debug mode preview tab switching
             KeyCode::Char('t') | KeyCode::Char('T') => {
-                if app_state.debug_mode {
+                if app_state.debug_mode && !typing {
                     app_state.next_preview_tab();
                 }
             }
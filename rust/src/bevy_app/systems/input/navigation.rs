@@ -1,35 +1,81 @@
 //! # Navigation System
 //!
-//! Handles screen navigation via Tab, number keys, and Escape.
+//! Handles screen navigation via Tab (the `next_screen`/`previous_screen`
+//! keybindings), number keys, and Escape.
 
-use bevy::prelude::{info, EventReader, ResMut};
+use bevy::prelude::{info, EventReader, Res, ResMut};
 use bevy_ratatui::event::KeyEvent;
 use crossterm::event::KeyCode;
 
 use crate::bevy_app::resources::*;
 
 /// Handle screen navigation input.
+#[allow(clippy::too_many_arguments)]
 pub fn handle_navigation(
     mut events: EventReader<KeyEvent>,
     mut current_screen: ResMut<CurrentScreen>,
     mut app_state: ResMut<AppState>,
+    gallery: Option<Res<GalleryState>>,
+    gallery_zoom: Option<Res<GalleryZoom>>,
+    comparison: Option<Res<ComparisonState>>,
+    settings: Option<Res<SettingsState>>,
+    models: Option<Res<ModelsState>>,
+    help: Option<Res<HelpState>>,
+    keybindings: Res<Keybindings>,
 ) {
+    // Gallery focus, crop and zoom modes own Esc (handled by the gallery input system),
+    // as do the settings changes view and a help search
+    let screen_owns_esc = match current_screen.0 {
+        Screen::Gallery => {
+            gallery.as_ref().is_some_and(|g| g.focus_mode || g.is_cropping())
+                || gallery_zoom.as_ref().is_some_and(|z| z.active)
+        }
+        Screen::Settings => settings.as_ref().is_some_and(|s| s.show_changes),
+        Screen::Help => help.as_ref().is_some_and(|h| !h.query.is_empty()),
+        _ => false,
+    };
+
+    // Dual comparison uses 1/2 to regenerate a pane
+    let screen_owns_pane_keys = current_screen.0 == Screen::Comparison
+        && comparison.as_ref().is_some_and(|c| {
+            c.mode == comparison_state::ComparisonMode::Dual && !c.browsing_models
+        });
+
+    // Comparison prompt editing, the gallery metadata editor and tag prompt,
+    // the models filter, the help search and a setting being edited own every
+    // key (it's typing)
+    let typing = match current_screen.0 {
+        Screen::Comparison => comparison.as_ref().is_some_and(|c| c.editing_prompt),
+        Screen::Gallery => gallery
+            .as_ref()
+            .is_some_and(|g| g.is_editing_metadata() || g.is_entering_tag()),
+        Screen::Models => models.as_ref().is_some_and(|m| m.filter_editing),
+        Screen::Help => help.as_ref().is_some_and(|h| h.searching),
+        Screen::Settings => settings.as_ref().is_some_and(|s| s.is_editing),
+        _ => false,
+    };
+    if typing {
+        events.clear();
+        return;
+    }
+
     for event in events.read() {
         match event.code {
             // Tab navigation
-            KeyCode::Tab => {
+            _ if keybindings.matches(KeyAction::NextScreen, event) => {
                 current_screen.0 = current_screen.0.next();
                 app_state.request_redraw();
                 info!("Navigated to screen: {:?}", current_screen.0);
             }
 
-            KeyCode::BackTab => {
+            _ if keybindings.matches(KeyAction::PreviousScreen, event) => {
                 current_screen.0 = current_screen.0.previous();
                 app_state.request_redraw();
                 info!("Navigated to screen: {:?}", current_screen.0);
             }
 
             // Number key shortcuts (matching task requirements)
+            KeyCode::Char('1' | '2') if screen_owns_pane_keys => {}
             KeyCode::Char('1') => {
                 current_screen.0 = Screen::Generation;
                 app_state.request_redraw();
@@ -72,7 +118,7 @@ pub fn handle_navigation(
             }
 
             // Escape - navigate back (using history in future)
-            KeyCode::Esc => {
+            KeyCode::Esc if !screen_owns_esc => {
                 // For now, just go to Generation screen
                 // WS-08 will add proper screen history
                 current_screen.0 = Screen::Generation;
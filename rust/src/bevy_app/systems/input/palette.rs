@@ -0,0 +1,203 @@
+//! # Command Palette Input
+//!
+//! Ctrl+P opens the command palette on any screen (rebindable as
+//! `command_palette`). While it's open, typing filters the action list,
+//! Up/Down move the selection, Enter runs the selected action and Esc (or
+//! Ctrl+P again) closes it.
+//!
+//! Keys the palette handles are cleared from the event queue, so the other
+//! input systems (which run after this one) never see them.
+
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::*;
+use bevy_ratatui::event::{KeyEvent, PasteEvent};
+use crossterm::event::{KeyCode, KeyEventKind, KeyModifiers};
+
+use crate::bevy_app::events::{
+    ExportComfyBatch, ExportReport, NavigateToScreen, SubmitGenerationJob,
+};
+use crate::bevy_app::resources::{
+    AppState, CommandPalette, GenParams, InputBuffer, KeyAction, Keybindings, PaletteCommand,
+};
+
+/// Handle command palette keys, capturing input while it's open.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_palette_input(
+    mut key_events: ResMut<Events<KeyEvent>>,
+    mut key_cursor: Local<EventCursor<KeyEvent>>,
+    mut paste_events: ResMut<Events<PasteEvent>>,
+    mut paste_cursor: Local<EventCursor<PasteEvent>>,
+    mut palette: ResMut<CommandPalette>,
+    mut nav_events: EventWriter<NavigateToScreen>,
+    mut submit_events: EventWriter<SubmitGenerationJob>,
+    mut export_events: EventWriter<ExportReport>,
+    mut comfy_events: EventWriter<ExportComfyBatch>,
+    mut input_buffer: ResMut<InputBuffer>,
+    mut app_state: ResMut<AppState>,
+    keybindings: Res<Keybindings>,
+    gen_params: Option<Res<GenParams>>,
+) {
+    let keys: Vec<KeyEvent> = key_cursor.read(&key_events).cloned().collect();
+    let gen_params = gen_params.as_deref().cloned().unwrap_or_default();
+    let pastes: Vec<String> = paste_cursor
+        .read(&paste_events)
+        .map(|p| p.0.clone())
+        .collect();
+
+    let was_open = palette.open;
+    for event in keys {
+        if event.kind == KeyEventKind::Release {
+            continue;
+        }
+        let ctrl = event.modifiers.contains(KeyModifiers::CONTROL);
+        let palette_key = keybindings.matches(KeyAction::CommandPalette, &event);
+
+        if !palette.open {
+            if palette_key {
+                palette.open();
+                app_state.request_redraw();
+            }
+            continue;
+        }
+
+        match event.code {
+            KeyCode::Esc => palette.close(),
+            _ if palette_key => palette.close(),
+            KeyCode::Enter => {
+                let command = palette.selected_action().map(|a| a.command);
+                palette.close();
+                if let Some(command) = command {
+                    run_command(
+                        command,
+                        &mut nav_events,
+                        &mut submit_events,
+                        &mut export_events,
+                        &mut comfy_events,
+                        &mut input_buffer,
+                        &mut app_state,
+                        &gen_params,
+                    );
+                }
+            }
+            KeyCode::Up => palette.select_previous(),
+            KeyCode::Down | KeyCode::Tab => palette.select_next(),
+            KeyCode::Backspace => palette.pop_char(),
+            KeyCode::Char(c) if !ctrl => palette.push_char(c),
+            _ => {}
+        }
+        app_state.request_redraw();
+    }
+
+    if palette.open {
+        for text in pastes {
+            text.chars()
+                .filter(|c| !c.is_control())
+                .for_each(|c| palette.push_char(c));
+        }
+    }
+
+    palette.input_captured = was_open || palette.open;
+    if palette.input_captured {
+        key_events.clear();
+        paste_events.clear();
+    }
+}
+
+/// Carry out a palette action.
+#[allow(clippy::too_many_arguments)]
+fn run_command(
+    command: PaletteCommand,
+    nav_events: &mut EventWriter<NavigateToScreen>,
+    submit_events: &mut EventWriter<SubmitGenerationJob>,
+    export_events: &mut EventWriter<ExportReport>,
+    comfy_events: &mut EventWriter<ExportComfyBatch>,
+    input_buffer: &mut InputBuffer,
+    app_state: &mut AppState,
+    gen_params: &GenParams,
+) {
+    info!("Command palette: {:?}", command);
+    match command {
+        PaletteCommand::GoTo(screen) => {
+            nav_events.send(NavigateToScreen(screen));
+        }
+        PaletteCommand::Generate => {
+            if input_buffer.text.trim().is_empty() {
+                warn!("Cannot generate: prompt is empty");
+            } else {
+                submit_events.send(SubmitGenerationJob {
+                    prompt: input_buffer.text.clone(),
+                    negative_prompt: input_buffer.negative_prompt(),
+                    batch_size: None,
+                    base_seed: None,
+                    steps: gen_params.steps,
+                    cfg_scale: gen_params.cfg_scale,
+                    sweep: false,
+                });
+                input_buffer.clear();
+            }
+        }
+        PaletteCommand::ExportReport => {
+            export_events.send(ExportReport);
+        }
+        PaletteCommand::ExportComfyBatch => {
+            comfy_events.send(ExportComfyBatch);
+        }
+        PaletteCommand::Quit => app_state.quit(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bevy_app::resources::Screen;
+    use crossterm::event::KeyEventState;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent(crossterm::event::KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            state: KeyEventState::empty(),
+        })
+    }
+
+    #[test]
+    fn test_enter_dispatches_selected_action() {
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.add_event::<PasteEvent>();
+        app.add_event::<NavigateToScreen>();
+        app.add_event::<SubmitGenerationJob>();
+        app.add_event::<ExportReport>();
+        app.add_event::<ExportComfyBatch>();
+        app.init_resource::<CommandPalette>();
+        app.init_resource::<InputBuffer>();
+        app.init_resource::<AppState>();
+        app.init_resource::<Keybindings>();
+        app.add_systems(Update, handle_palette_input);
+
+        app.world_mut()
+            .send_event(key(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        app.update();
+        assert!(app.world().resource::<CommandPalette>().open);
+
+        for c in "gal".chars() {
+            app.world_mut()
+                .send_event(key(KeyCode::Char(c), KeyModifiers::empty()));
+        }
+        app.world_mut()
+            .send_event(key(KeyCode::Enter, KeyModifiers::empty()));
+        app.update();
+
+        let palette = app.world().resource::<CommandPalette>();
+        assert!(!palette.open);
+        assert!(palette.input_captured);
+
+        let nav = app.world().resource::<Events<NavigateToScreen>>();
+        let screens: Vec<Screen> = nav.get_cursor().read(nav).map(|e| e.0).collect();
+        assert_eq!(screens, vec![Screen::Gallery]);
+
+        // Keys the palette handled are not left for other input systems
+        assert!(app.world().resource::<Events<KeyEvent>>().is_empty());
+    }
+}
@@ -1,20 +1,29 @@
 //! # Comparison Screen Input Handler
 //!
 //! Handles keyboard input for the side-by-side model comparison screen.
+//! Generating a pane queues a job for it like any other; the job scheduler
+//! sends it with the pane's model, and its image is shown in the pane when
+//! it completes (see [`crate::bevy_app::systems::comparison_jobs`]).
 
 use bevy::prelude::*;
 use bevy_ratatui::event::KeyEvent;
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyModifiers};
 
+use crate::bevy_app::components::{Job, JobStatus};
 use crate::bevy_app::resources::{
-    comparison_state::ComparisonMode, ComparisonState, CurrentScreen, Screen,
+    comparison_state::{ComparisonMode, ComparisonPane, PaneSubmission}, random_seed,
+    ComparisonState, CurrentScreen, JobTracker, Notifications, Screen,
 };
+use crate::clipboard::{copy_or_log, TerminalClipboard};
 
 /// Handle input for the Comparison screen
 pub fn handle_comparison_input(
     mut events: EventReader<KeyEvent>,
     current_screen: Res<CurrentScreen>,
     mut comparison: ResMut<ComparisonState>,
+    mut notifications: Option<ResMut<Notifications>>,
+    mut job_tracker: Option<ResMut<JobTracker>>,
+    mut commands: Commands,
     // TODO: Add ZmqClient resource for model fetching when integrated
 ) {
     if current_screen.0 != Screen::Comparison {
@@ -22,22 +31,96 @@ pub fn handle_comparison_input(
     }
 
     for event in events.read() {
+        // Prompt editing captures all keys until Enter/Esc
+        if comparison.editing_prompt {
+            handle_prompt_edit_input(event.code, &mut comparison);
+            continue;
+        }
+
         // Handle model browser overlay input separately
         if comparison.browsing_models {
             handle_model_browser_input(event.code, &mut comparison);
             continue;
         }
 
+        // Ctrl+N: Same two models, new prompt
+        if comparison.mode == ComparisonMode::Dual
+            && event.code == KeyCode::Char('n')
+            && event.modifiers.contains(KeyModifiers::CONTROL)
+        {
+            if comparison.is_running {
+                warn!("Comparison: Cannot duplicate while running");
+            } else {
+                comparison.duplicate_with_new_prompt();
+                info!("Comparison: Duplicated comparison, enter a new prompt");
+            }
+            continue;
+        }
+
+        // Ctrl+S / Ctrl+O: Save / load the comparison setup
+        if event.modifiers.contains(KeyModifiers::CONTROL)
+            && matches!(event.code, KeyCode::Char('s') | KeyCode::Char('o'))
+        {
+            let path = ComparisonState::config_path();
+            if event.code == KeyCode::Char('s') {
+                if let Err(e) = comparison.save_config(&path) {
+                    warn!("Comparison: Failed to save setup: {:#}", e);
+                }
+            } else if comparison.is_running {
+                warn!("Comparison: Cannot load a setup while running");
+            } else if let Err(e) = comparison.load_config(&path) {
+                warn!("Comparison: Failed to load setup: {:#}", e);
+            }
+            continue;
+        }
+
         // Main comparison screen input
-        match comparison.mode {
-            ComparisonMode::Dual => handle_dual_mode_input(event.code, &mut comparison),
-            ComparisonMode::Multi => handle_multi_mode_input(event.code, &mut comparison),
+        let submissions = match comparison.mode {
+            ComparisonMode::Dual => {
+                handle_dual_mode_input(event.code, &mut comparison, notifications.as_deref_mut())
+            }
+            ComparisonMode::Multi => {
+                handle_multi_mode_input(event.code, &mut comparison);
+                Vec::new()
+            }
+        };
+        for submission in submissions {
+            let job_tracker = job_tracker.as_deref_mut();
+            queue_pane_job(&submission, &mut comparison, &mut commands, job_tracker);
         }
     }
 }
 
+/// Queue a job generating `submission`'s pane, remembering it as the pane's
+/// job. A pane without a seed gets a random one, so the seed is recorded.
+fn queue_pane_job(
+    submission: &PaneSubmission,
+    comparison: &mut ComparisonState,
+    commands: &mut Commands,
+    job_tracker: Option<&mut JobTracker>,
+) {
+    let job_id = format!("job-{}", uuid::Uuid::new_v4());
+    let mut job = Job::new(job_id.clone(), submission.prompt.clone());
+    job.model = Some(submission.model.clone());
+    job.seed = Some(submission.seed.unwrap_or_else(random_seed));
+    job.status = JobStatus::QueuedLocally;
+    if let Some(job_tracker) = job_tracker {
+        job_tracker.enqueue(job_id.clone(), job.priority);
+        job_tracker.submit_job();
+    }
+    commands.spawn(job);
+    debug!("Comparison: {:?} pane queued as {}", submission.pane, job_id);
+    comparison.set_pane_job(submission.pane, job_id);
+}
+
 /// Handle input when in dual comparison mode
-fn handle_dual_mode_input(code: KeyCode, comparison: &mut ComparisonState) {
+///
+/// Returns the panes to generate. A failed copy is shown in `notifications`.
+fn handle_dual_mode_input(
+    code: KeyCode,
+    comparison: &mut ComparisonState,
+    notifications: Option<&mut Notifications>,
+) -> Vec<PaneSubmission> {
     match code {
         // Tab: Switch between left and right pane
         KeyCode::Tab => {
@@ -64,20 +147,99 @@ fn handle_dual_mode_input(code: KeyCode, comparison: &mut ComparisonState) {
             }
         }
 
+        // 'e': Edit the shared prompt (or the selected pane's override)
+        KeyCode::Char('e') | KeyCode::Char('E') => {
+            comparison.start_prompt_edit();
+            debug!("Comparison: Editing prompt");
+        }
+
+        // 'o': Overlay the difference of the two images
+        KeyCode::Char('o') | KeyCode::Char('O') => {
+            comparison.toggle_overlay_diff();
+            debug!("Comparison: Difference overlay {}", comparison.overlay_diff);
+        }
+
+        // 'y': Copy the selected pane's prompt to the clipboard
+        KeyCode::Char('y') | KeyCode::Char('Y') => match comparison.selected_pane_prompt() {
+            Some(prompt) => {
+                let copied = copy_or_log(&mut TerminalClipboard, prompt, "prompt");
+                if let Some(notifications) = notifications {
+                    notifications.notify_failure(copied);
+                }
+            }
+            None => debug!("Comparison: No prompt to copy"),
+        },
+
+        // 'P': Pin (or unpin) the selected pane's model across resets
+        KeyCode::Char('P') => {
+            let pinned = comparison.toggle_pin_selected_pane();
+            info!(
+                "Comparison: {:?} pane model {}",
+                comparison.selected_pane,
+                if pinned { "pinned" } else { "unpinned" }
+            );
+        }
+
+        // 'p': Toggle per-pane prompt overrides
+        KeyCode::Char('p') => {
+            comparison.toggle_per_pane_prompts();
+            info!(
+                "Comparison: Per-pane prompts {}",
+                if comparison.per_pane_prompts { "enabled" } else { "disabled" }
+            );
+        }
+
+        // '1'/'2': Regenerate just the left/right pane
+        KeyCode::Char(c @ ('1' | '2')) => {
+            let pane = if c == '1' { ComparisonPane::Left } else { ComparisonPane::Right };
+            if comparison.is_reference(pane) {
+                warn!("Comparison: The {:?} pane is a reference and isn't regenerated", pane);
+                return Vec::new();
+            }
+            match comparison.regenerate_pane(pane) {
+                Some(submission) => {
+                    info!("Comparison: Regenerating {:?} pane", pane);
+                    debug!(
+                        "Comparison: {:?} pane -> {} with prompt {:?} and seed {:?}",
+                        submission.pane, submission.model, submission.prompt, submission.seed
+                    );
+                    return vec![submission];
+                }
+                None => warn!("Comparison: Cannot regenerate without prompt and model"),
+            }
+        }
+
+        // Enter with a reference: generate only the other pane
+        KeyCode::Enter if comparison.reference_pane.is_some() => {
+            return generate_against_reference(comparison).into_iter().collect();
+        }
+
         // Enter: Start comparison generation
         KeyCode::Enter => {
-            if comparison.can_run_comparison() && !comparison.is_running {
+            if let Some(mut submissions) =
+                comparison.dual_submissions().filter(|_| !comparison.is_running)
+            {
                 comparison.start_dual_comparison();
                 info!("Comparison: Starting dual comparison generation");
-                // TODO: Submit generation jobs to backend via ZMQ
-            } else if comparison.prompt.is_empty() {
+                let seed = comparison.use_same_seed.then(random_seed);
+                for submission in &mut submissions {
+                    submission.seed = seed;
+                    debug!(
+                        "Comparison: {:?} pane -> {} with prompt {:?}",
+                        submission.pane, submission.model, submission.prompt
+                    );
+                }
+                return submissions.into();
+            } else if comparison.effective_prompt(ComparisonPane::Left).is_empty()
+                || comparison.effective_prompt(ComparisonPane::Right).is_empty()
+            {
                 warn!("Comparison: Cannot run without prompt");
             } else {
                 warn!("Comparison: Cannot run without models selected");
             }
         }
 
-        // 'r' or 'c': Reset/clear comparison results
+        // 'r' or 'c': Reset/clear comparison results (and unpinned models)
         KeyCode::Char('r') | KeyCode::Char('R') | KeyCode::Char('c') | KeyCode::Char('C') => {
             comparison.reset_results();
             info!("Comparison: Reset comparison results");
@@ -108,6 +270,47 @@ fn handle_dual_mode_input(code: KeyCode, comparison: &mut ComparisonState) {
         // Ignore other keys
         _ => {}
     }
+    Vec::new()
+}
+
+/// Generate the pane next to the reference, with the reference's seed
+fn generate_against_reference(comparison: &mut ComparisonState) -> Option<PaneSubmission> {
+    let reference = comparison.reference_pane?;
+    if comparison.is_running {
+        debug!("Comparison: Already generating against the reference");
+        return None;
+    }
+    let submission = comparison.regenerate_pane(reference.other());
+    match &submission {
+        Some(submission) => {
+            info!("Comparison: Generating against the reference");
+            debug!(
+                "Comparison: {:?} pane -> {} with prompt {:?} and seed {:?}",
+                submission.pane, submission.model, submission.prompt, submission.seed
+            );
+        }
+        None => warn!("Comparison: Cannot run without prompt and model"),
+    }
+    submission
+}
+
+/// Handle input while editing the comparison prompt
+fn handle_prompt_edit_input(code: KeyCode, comparison: &mut ComparisonState) {
+    match code {
+        KeyCode::Char(c) => comparison.prompt_edit_buffer.push(c),
+        KeyCode::Backspace => {
+            comparison.prompt_edit_buffer.pop();
+        }
+        KeyCode::Enter => {
+            comparison.finish_prompt_edit();
+            info!("Comparison: Prompt updated");
+        }
+        KeyCode::Esc => {
+            comparison.cancel_prompt_edit();
+            debug!("Comparison: Prompt edit cancelled");
+        }
+        _ => {}
+    }
 }
 
 /// Handle input when browsing models
@@ -200,7 +403,6 @@ fn handle_multi_mode_input(code: KeyCode, comparison: &mut ComparisonState) {
 mod tests {
     use super::*;
     use bevy::app::App;
-    use crate::bevy_app::resources::comparison_state::ComparisonPane;
 
     #[test]
     fn test_comparison_input_compiles() {
@@ -225,12 +427,12 @@ mod tests {
         );
 
         // Send Tab key
-        let key_event = KeyEvent {
+        let key_event = KeyEvent(crossterm::event::KeyEvent {
             code: KeyCode::Tab,
             modifiers: crossterm::event::KeyModifiers::empty(),
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::empty(),
-        };
+        });
         app.world_mut().send_event(key_event);
         app.update();
 
@@ -254,12 +456,12 @@ mod tests {
             .browsing_models);
 
         // Send 'm' key
-        let key_event = KeyEvent {
+        let key_event = KeyEvent(crossterm::event::KeyEvent {
             code: KeyCode::Char('m'),
             modifiers: crossterm::event::KeyModifiers::empty(),
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::empty(),
-        };
+        });
         app.world_mut().send_event(key_event);
         app.update();
 
@@ -281,12 +483,12 @@ mod tests {
         app.add_systems(Update, handle_comparison_input);
 
         // Send 'r' key
-        let key_event = KeyEvent {
+        let key_event = KeyEvent(crossterm::event::KeyEvent {
             code: KeyCode::Char('r'),
             modifiers: crossterm::event::KeyModifiers::empty(),
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::empty(),
-        };
+        });
         app.world_mut().send_event(key_event);
         app.update();
 
@@ -322,12 +524,12 @@ mod tests {
         app.add_systems(Update, handle_comparison_input);
 
         // Send Down key
-        let key_event = KeyEvent {
+        let key_event = KeyEvent(crossterm::event::KeyEvent {
             code: KeyCode::Down,
             modifiers: crossterm::event::KeyModifiers::empty(),
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::empty(),
-        };
+        });
         app.world_mut().send_event(key_event);
         app.update();
 
@@ -357,12 +559,12 @@ mod tests {
         app.add_systems(Update, handle_comparison_input);
 
         // Send Enter key
-        let key_event = KeyEvent {
+        let key_event = KeyEvent(crossterm::event::KeyEvent {
             code: KeyCode::Enter,
             modifiers: crossterm::event::KeyModifiers::empty(),
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::empty(),
-        };
+        });
         app.world_mut().send_event(key_event);
         app.update();
 
@@ -382,12 +584,12 @@ mod tests {
         let initial_pane = app.world().resource::<ComparisonState>().selected_pane;
 
         // Send Tab key
-        let key_event = KeyEvent {
+        let key_event = KeyEvent(crossterm::event::KeyEvent {
             code: KeyCode::Tab,
             modifiers: crossterm::event::KeyModifiers::empty(),
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::empty(),
-        };
+        });
         app.world_mut().send_event(key_event);
         app.update();
 
@@ -397,4 +599,171 @@ mod tests {
             initial_pane
         );
     }
+
+    #[test]
+    fn test_per_pane_prompt_editing() {
+        let mut app = App::new();
+        app.insert_resource(CurrentScreen(Screen::Comparison));
+
+        app.insert_resource(ComparisonState {
+            prompt: "shared".to_string(),
+            ..Default::default()
+        });
+        app.add_event::<KeyEvent>();
+        app.add_systems(Update, handle_comparison_input);
+
+        let key = |code| {
+            KeyEvent(crossterm::event::KeyEvent::new(
+                code,
+                crossterm::event::KeyModifiers::empty(),
+            ))
+        };
+
+        // Enable overrides, switch to the right pane and type an override
+        for code in [KeyCode::Char('p'), KeyCode::Tab, KeyCode::Char('e')] {
+            app.world_mut().send_event(key(code));
+        }
+        for c in "alt".chars() {
+            app.world_mut().send_event(key(KeyCode::Char(c)));
+        }
+        app.world_mut().send_event(key(KeyCode::Enter));
+        app.update();
+
+        let comparison = app.world().resource::<ComparisonState>();
+        assert!(comparison.per_pane_prompts);
+        assert!(!comparison.editing_prompt);
+        assert_eq!(comparison.right_prompt.as_deref(), Some("alt"));
+        assert_eq!(comparison.effective_prompt(ComparisonPane::Left), "shared");
+        assert_eq!(comparison.effective_prompt(ComparisonPane::Right), "alt");
+    }
+
+    #[test]
+    fn test_number_key_regenerates_one_pane() {
+        let mut app = App::new();
+        app.insert_resource(CurrentScreen(Screen::Comparison));
+
+        let mut comparison = ComparisonState {
+            prompt: "knight".to_string(),
+            ..Default::default()
+        };
+        comparison.left_image = Some(std::path::PathBuf::from("left.png"));
+        comparison.right_image = Some(std::path::PathBuf::from("right.png"));
+        comparison.right_metadata = Some(
+            crate::bevy_app::resources::comparison_state::GenerationMetadata {
+                size: (1024, 1024),
+                seed: Some(7),
+                inference_time_s: 2.0,
+                steps: 30,
+            },
+        );
+        app.insert_resource(comparison);
+        app.init_resource::<JobTracker>();
+        app.add_event::<KeyEvent>();
+        app.add_systems(Update, handle_comparison_input);
+
+        app.world_mut().send_event(KeyEvent(crossterm::event::KeyEvent::new(
+            KeyCode::Char('1'),
+            KeyModifiers::empty(),
+        )));
+        app.update();
+
+        let comparison = app.world().resource::<ComparisonState>();
+        assert!(comparison.left_image.is_none());
+        assert!(comparison.pane_running(ComparisonPane::Left));
+        assert_eq!(comparison.right_image, Some(std::path::PathBuf::from("right.png")));
+        assert!(!comparison.pane_running(ComparisonPane::Right));
+
+        // A job is queued for the left pane only, with its model and the
+        // right pane's seed
+        let left_job_id = comparison.left_job_id.clone().unwrap();
+        let mut query = app.world_mut().query::<&Job>();
+        let jobs: Vec<&Job> = query.iter(app.world()).collect();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].id, left_job_id);
+        assert_eq!(jobs[0].prompt, "knight");
+        assert_eq!(jobs[0].model.as_deref(), Some("SDXL Base 1.0"));
+        assert_eq!(jobs[0].seed, Some(7));
+        assert_eq!(jobs[0].status, JobStatus::QueuedLocally);
+        let tracker = app.world().resource::<JobTracker>();
+        assert_eq!(tracker.pending_position(&left_job_id), Some(0));
+    }
+
+    #[test]
+    fn test_enter_with_a_reference_queues_the_other_pane() {
+        let mut app = App::new();
+        app.insert_resource(CurrentScreen(Screen::Comparison));
+
+        let mut comparison = ComparisonState {
+            prompt: "knight".to_string(),
+            reference_pane: Some(ComparisonPane::Right),
+            ..Default::default()
+        };
+        comparison.right_image = Some(std::path::PathBuf::from("reference.png"));
+        comparison.right_metadata = Some(
+            crate::bevy_app::resources::comparison_state::GenerationMetadata {
+                size: (1024, 1024),
+                seed: Some(1234),
+                inference_time_s: 0.0,
+                steps: 30,
+            },
+        );
+        app.insert_resource(comparison);
+        app.init_resource::<JobTracker>();
+        app.add_event::<KeyEvent>();
+        app.add_systems(Update, handle_comparison_input);
+
+        app.world_mut().send_event(KeyEvent(crossterm::event::KeyEvent::new(
+            KeyCode::Enter,
+            KeyModifiers::empty(),
+        )));
+        app.update();
+
+        let comparison = app.world().resource::<ComparisonState>();
+        let left_job_id = comparison.left_job_id.clone().unwrap();
+        assert!(comparison.right_job_id.is_none());
+        let mut query = app.world_mut().query::<&Job>();
+        let jobs: Vec<&Job> = query.iter(app.world()).collect();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].id, left_job_id);
+        assert_eq!(jobs[0].seed, Some(1234));
+        assert_eq!(jobs[0].status, JobStatus::QueuedLocally);
+    }
+
+    #[test]
+    fn test_ctrl_n_duplicates_comparison() {
+        let mut app = App::new();
+        app.insert_resource(CurrentScreen(Screen::Comparison));
+
+        let mut comparison = ComparisonState {
+            prompt: "knight".to_string(),
+            ..Default::default()
+        };
+        comparison.left_image = Some(std::path::PathBuf::from("left.png"));
+        comparison.right_image = Some(std::path::PathBuf::from("right.png"));
+        app.insert_resource(comparison);
+        app.add_event::<KeyEvent>();
+        app.add_systems(Update, handle_comparison_input);
+
+        app.world_mut().send_event(KeyEvent(crossterm::event::KeyEvent::new(
+            KeyCode::Char('n'),
+            KeyModifiers::CONTROL,
+        )));
+        // The prompt box has focus, so typing goes straight to the new prompt
+        for c in "mage".chars() {
+            app.world_mut().send_event(KeyEvent(crossterm::event::KeyEvent::new(
+                KeyCode::Char(c),
+                KeyModifiers::empty(),
+            )));
+        }
+        app.update();
+
+        let comparison = app.world().resource::<ComparisonState>();
+        assert_eq!(comparison.left_model.as_deref(), Some("SDXL Base 1.0"));
+        assert_eq!(comparison.right_model.as_deref(), Some("Pixel Art LoRA v1"));
+        assert!(comparison.prompt.is_empty());
+        assert!(comparison.left_image.is_none());
+        assert!(comparison.right_image.is_none());
+        assert!(comparison.editing_prompt);
+        assert_eq!(comparison.prompt_edit_buffer, "mage");
+    }
 }
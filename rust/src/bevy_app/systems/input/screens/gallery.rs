@@ -5,24 +5,62 @@
 
 use bevy::prelude::*;
 use bevy_ratatui::event::KeyEvent;
-use crossterm::event::KeyCode;
-
-use crate::bevy_app::events::{DeleteImage, SelectNextImage, SelectPreviousImage};
-use crate::bevy_app::resources::{CurrentScreen, GalleryState, Screen};
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::path::{Path, PathBuf};
+
+use crate::bevy_app::events::{SelectNextImage, SelectPreviousImage};
+use crate::bevy_app::resources::{
+    ComparisonState, ConfirmAction, ConfirmModal, CurrentScreen, GalleryState, GalleryZoom,
+    KeyAction, Keybindings, Notifications, Screen, TagEntryMode, THUMBNAIL_WINDOW,
+};
+use crate::bevy_app::systems::assets::crop_to_file;
+use crate::bevy_app::systems::assets::sidecar::{
+    add_tag, load_sidecar, save_metadata_form, SidecarCache,
+};
+use crate::clipboard::{copy_or_log, Clipboard, TerminalClipboard};
+use crate::file_manager::{open_or_log, Opener, SystemOpener};
 
 /// Gallery screen input handler.
 ///
 /// Handles:
-/// - Arrow keys: Navigate gallery
-/// - Enter: Toggle detail view (future)
-/// - d/D: Delete selected image
+/// - Arrow keys: Navigate gallery (wrapping at the ends)
+/// - PageUp/PageDown: Move a list's worth of images, Home/End: first/last
+/// - Enter/f: Enter focus mode (fullscreen preview)
+/// - Esc: Exit focus mode
+/// - g/G: Toggle grid view
+/// - c/C: Crop mode (arrows move, Shift+arrows resize, Enter saves, Esc cancels)
+/// - z: Zoom view (arrows pan, +/- change zoom, z/Esc exit)
+/// - m/M: Edit the selected image's metadata (Tab/arrows move, Enter saves, Esc cancels)
+/// - d/D: Delete selected image (moved to the trash once confirmed in the dialog)
+/// - u/U: Undo the last delete
+/// - +: Tag the selected image (saved to its sidecar on Enter)
+/// - /: Filter by tag (Enter on an empty prompt clears the filter)
+/// - *: Star/unstar the selected image as a favorite
+/// - F: Show only favorites (again to show everything)
+/// - y: Copy the selected image's prompt (from its sidecar) to the clipboard
+/// - o: Open the selected image's folder in the file manager
+/// - p: Copy the selected image's absolute path to the clipboard
+/// - Space: Pick/un-pick the selected image for export
+/// - e/E: Copy the picked images (and sidecars) into a folder typed at the prompt
+/// - v/V: Compare against the selected image (the Comparison screen's
+///   selected pane shows it as the reference)
+///
+/// Delete, undo, tagging, the tag filter and favorites use the `delete`,
+/// `undo_delete`, `add_tag`, `filter_tag`, `toggle_favorite` and
+/// `favorites_view` keybindings.
+#[allow(clippy::too_many_arguments)]
 pub fn handle_gallery_input(
     mut events: EventReader<KeyEvent>,
-    current_screen: Res<CurrentScreen>,
-    gallery: Res<GalleryState>,
+    mut current_screen: ResMut<CurrentScreen>,
+    mut gallery: ResMut<GalleryState>,
     mut select_next: EventWriter<SelectNextImage>,
     mut select_prev: EventWriter<SelectPreviousImage>,
-    mut delete: EventWriter<DeleteImage>,
+    mut confirm: ResMut<ConfirmModal>,
+    mut sidecars: Option<ResMut<SidecarCache>>,
+    mut zoom: Option<ResMut<GalleryZoom>>,
+    mut comparison: Option<ResMut<ComparisonState>>,
+    keybindings: Res<Keybindings>,
+    mut notifications: Option<ResMut<Notifications>>,
 ) {
     // Only handle input when on Gallery screen
     if current_screen.0 != Screen::Gallery {
@@ -30,7 +68,67 @@ pub fn handle_gallery_input(
     }
 
     for event in events.read() {
+        if gallery.is_editing_metadata() {
+            handle_metadata_input(event, &mut gallery, sidecars.as_deref_mut());
+            continue;
+        }
+
+        if gallery.is_entering_tag() {
+            handle_tag_input(event, &mut gallery, sidecars.as_deref_mut());
+            continue;
+        }
+
+        if gallery.is_cropping() {
+            handle_crop_input(event, &mut gallery);
+            continue;
+        }
+
+        if let Some(zoom) = zoom.as_deref_mut().filter(|zoom| zoom.active) {
+            handle_zoom_input(event, zoom);
+            continue;
+        }
+
+        // Focus mode only supports moving between images and leaving
+        if gallery.focus_mode {
+            match event.code {
+                KeyCode::Left | KeyCode::Up | KeyCode::Char('h') | KeyCode::Char('k') => {
+                    select_prev.send(SelectPreviousImage);
+                }
+                KeyCode::Right | KeyCode::Down | KeyCode::Char('l') | KeyCode::Char('j') => {
+                    select_next.send(SelectNextImage);
+                }
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('f') | KeyCode::Char('F') => {
+                    gallery.exit_focus_mode();
+                    debug!("Gallery: Exit focus mode");
+                }
+                _ => {}
+            }
+            continue;
+        }
+
         match event.code {
+            // Tag prompts (checked first so either can be bound to a gallery key)
+            _ if keybindings.matches(KeyAction::AddTag, event) => {
+                gallery.start_tag_entry(TagEntryMode::Add);
+                debug!("Gallery: Add tag");
+            }
+            _ if keybindings.matches(KeyAction::FilterTag, event) => {
+                gallery.start_tag_entry(TagEntryMode::Filter);
+                debug!("Gallery: Filter by tag");
+            }
+
+            // Favorites (before focus mode, which also takes f)
+            _ if keybindings.matches(KeyAction::ToggleFavorite, event) => {
+                match gallery.toggle_favorite() {
+                    Ok(favorite) => debug!("Gallery: Favorite {}", on_off(favorite)),
+                    Err(e) => warn!("Gallery: Failed to save favorites: {:#}", e),
+                }
+            }
+            _ if keybindings.matches(KeyAction::FavoritesView, event) => {
+                gallery.toggle_favorites_only();
+                debug!("Gallery: Favorites only {}", on_off(gallery.favorites_only));
+            }
+
             // Navigation: Arrow keys and vi-style keys
             KeyCode::Up | KeyCode::Left | KeyCode::Char('k') | KeyCode::Char('h') => {
                 select_prev.send(SelectPreviousImage);
@@ -41,51 +139,154 @@ pub fn handle_gallery_input(
                 debug!("Gallery: Navigate to next image");
             }
 
-            // Future: Enter for detail view toggle
-            KeyCode::Enter => {
-                debug!("Gallery: Detail view toggle (not yet implemented)");
+            // Focus mode: fullscreen preview of the selected image
+            KeyCode::Enter | KeyCode::Char('f') => {
+                gallery.enter_focus_mode();
+                debug!("Gallery: Enter focus mode");
+            }
+
+            // Grid view toggle
+            KeyCode::Char('g') | KeyCode::Char('G') => {
+                gallery.toggle_grid_view();
+                debug!("Gallery: Grid view {}", if gallery.grid_view { "on" } else { "off" });
+            }
+
+            // Crop mode on the main preview
+            KeyCode::Char('c') | KeyCode::Char('C') if !gallery.grid_view => {
+                if let Some(path) = gallery.current_image().cloned() {
+                    match image::image_dimensions(&path) {
+                        Ok(size) => {
+                            gallery.start_crop(size);
+                            debug!("Gallery: Enter crop mode for {:?}", path);
+                        }
+                        Err(e) => warn!("Gallery: Cannot crop {:?}: {}", path, e),
+                    }
+                }
+            }
+
+            // Zoom view of the current image
+            KeyCode::Char('z') if !gallery.is_empty() => {
+                if let Some(zoom) = zoom.as_deref_mut() {
+                    zoom.open();
+                    debug!("Gallery: Enter zoom view");
+                }
+            }
+
+            // Metadata editor for the current image's sidecar
+            KeyCode::Char('m') | KeyCode::Char('M') => {
+                if let Some(path) = gallery.current_image().cloned() {
+                    let form = load_sidecar(&path).unwrap_or_default().to_form();
+                    gallery.start_metadata_edit(form);
+                    debug!("Gallery: Edit metadata for {:?}", path);
+                }
+            }
+
+            // Copy the current image's prompt
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                let prompt = gallery
+                    .current_image()
+                    .and_then(|path| load_sidecar(path))
+                    .and_then(|sidecar| sidecar.prompt);
+                match prompt {
+                    Some(prompt) => {
+                        let copied = copy_or_log(&mut TerminalClipboard, &prompt, "prompt");
+                        if let Some(notifications) = notifications.as_mut() {
+                            notifications.notify_failure(copied);
+                        }
+                    }
+                    None => debug!("Gallery: Selected image has no prompt to copy"),
+                }
+            }
+
+            // Reveal the current image in the file manager
+            KeyCode::Char('o') => {
+                let opened = reveal_image(&gallery, &mut SystemOpener);
+                if let Some(notifications) = notifications.as_mut() {
+                    notifications.notify_failure(opened);
+                }
+            }
+
+            // Copy the current image's path (Ctrl+P is the command palette)
+            KeyCode::Char('p') if !event.modifiers.contains(KeyModifiers::CONTROL) => {
+                let copied = copy_image_path(&gallery, &mut TerminalClipboard);
+                if let Some(notifications) = notifications.as_mut() {
+                    notifications.notify_failure(copied);
+                }
+            }
+
+            // Pick images to export together
+            KeyCode::Char(' ') => {
+                let picked = gallery.toggle_in_selected_set();
+                debug!("Gallery: Picked {} ({} picked)", on_off(picked), gallery.selected_set.len());
+            }
+            KeyCode::Char('e') | KeyCode::Char('E') => {
+                if gallery.selected_set.is_empty() {
+                    debug!("Gallery: No images picked to export");
+                } else {
+                    gallery.start_tag_entry(TagEntryMode::Export);
+                }
+            }
+
+            // Generate against the current image on the Comparison screen
+            KeyCode::Char('v') | KeyCode::Char('V') => {
+                let (Some(path), Some(comparison)) =
+                    (gallery.current_image(), comparison.as_deref_mut())
+                else {
+                    continue;
+                };
+                if comparison.is_running {
+                    warn!("Gallery: Cannot load a reference while a comparison is running");
+                } else if let Err(e) = comparison.load_reference(path) {
+                    warn!("Gallery: Cannot compare against {:?}: {:#}", path, e);
+                } else {
+                    info!("Gallery: Comparing against {:?}", path);
+                    current_screen.0 = Screen::Comparison;
+                }
+            }
+
+            // Delete current image (asks for confirmation first)
+            _ if keybindings.matches(KeyAction::Delete, event) => {
+                if let Some(path) = gallery.current_image().cloned() {
+                    let name = path.file_name().unwrap_or_default().to_string_lossy();
+                    confirm.ask(
+                        format!("Delete {}?", name),
+                        ConfirmAction::DeleteImage(path.clone()),
+                    );
+                    info!("Gallery: Delete requested for {:?}", path);
+                }
             }
 
-            // Delete current image
-            KeyCode::Char('d') | KeyCode::Char('D') | KeyCode::Delete => {
-                if let Some(image_path) = gallery.current_image() {
-                    delete.send(DeleteImage {
-                        image_path: image_path.clone(),
-                    });
-                    info!("Gallery: Delete requested for {:?}", image_path);
+            // Restore the most recently deleted image
+            _ if keybindings.matches(KeyAction::UndoDelete, event) => {
+                match gallery.undo_last_delete() {
+                    Ok(Some(path)) => info!("Gallery: Restored {:?}", path),
+                    Ok(None) => debug!("Gallery: Nothing to undo"),
+                    Err(e) => warn!("Gallery: Undo failed: {:#}", e),
                 }
             }
 
             // Home/End for quick navigation
             KeyCode::Home => {
-                // Jump to first image by sending multiple prev events
-                // This is a simple approach; could be optimized with a JumpToFirst event
-                for _ in 0..gallery.selected {
-                    select_prev.send(SelectPreviousImage);
-                }
+                gallery.first();
+                gallery.mark_manual_nav();
                 debug!("Gallery: Jump to first image");
             }
             KeyCode::End => {
-                // Jump to last image by sending multiple next events
-                let remaining = gallery.len().saturating_sub(gallery.selected + 1);
-                for _ in 0..remaining {
-                    select_next.send(SelectNextImage);
-                }
+                gallery.last();
+                gallery.mark_manual_nav();
                 debug!("Gallery: Jump to last image");
             }
 
-            // Page Up/Down for faster navigation
+            // Page Up/Down for faster navigation (these stop at the ends)
             KeyCode::PageUp => {
-                for _ in 0..5 {
-                    select_prev.send(SelectPreviousImage);
-                }
-                debug!("Gallery: Page up (5 images)");
+                gallery.page_up(THUMBNAIL_WINDOW);
+                gallery.mark_manual_nav();
+                debug!("Gallery: Page up ({} images)", THUMBNAIL_WINDOW);
             }
             KeyCode::PageDown => {
-                for _ in 0..5 {
-                    select_next.send(SelectNextImage);
-                }
-                debug!("Gallery: Page down (5 images)");
+                gallery.page_down(THUMBNAIL_WINDOW);
+                gallery.mark_manual_nav();
+                debug!("Gallery: Page down ({} images)", THUMBNAIL_WINDOW);
             }
 
             // Ignore other keys (let main keyboard handler process them)
@@ -94,10 +295,218 @@ pub fn handle_gallery_input(
     }
 }
 
+/// Open the current image's folder with `opener`.
+///
+/// Fails with the message to show (see [`open_or_log`]), or `Ok(false)` with
+/// no image selected.
+fn reveal_image(gallery: &GalleryState, opener: &mut impl Opener) -> Result<bool, String> {
+    match gallery.current_image() {
+        Some(path) => open_or_log(opener, path).map(|()| true),
+        None => Ok(false),
+    }
+}
+
+/// Copy the current image's absolute path to `clipboard`.
+///
+/// Fails with the message to show (see [`copy_or_log`]), or `Ok(false)`
+/// with no image selected.
+fn copy_image_path(gallery: &GalleryState, clipboard: &mut impl Clipboard) -> Result<bool, String> {
+    match gallery.current_image() {
+        Some(path) => {
+            let path = absolute_path(path);
+            copy_or_log(clipboard, &path.to_string_lossy(), "image path").map(|()| true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// `path` resolved if it exists, otherwise joined onto the working directory.
+fn absolute_path(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| {
+        std::env::current_dir()
+            .map(|cwd| cwd.join(path))
+            .unwrap_or_else(|_| path.to_path_buf())
+    })
+}
+
+fn on_off(on: bool) -> &'static str {
+    if on {
+        "on"
+    } else {
+        "off"
+    }
+}
+
+/// Handle keys while the tag prompt is open.
+fn handle_tag_input(
+    event: &KeyEvent,
+    gallery: &mut GalleryState,
+    sidecars: Option<&mut SidecarCache>,
+) {
+    let Some(entry) = gallery.tag_entry.as_mut() else {
+        return;
+    };
+
+    match event.code {
+        KeyCode::Esc => gallery.close_tag_entry(),
+        KeyCode::Backspace => {
+            entry.text.pop();
+        }
+        KeyCode::Enter => {
+            let entry = entry.clone();
+            gallery.close_tag_entry();
+            match entry.mode {
+                TagEntryMode::Filter => {
+                    gallery.set_filter_tag(Some(entry.text));
+                    info!("Gallery: Tag filter {:?}", gallery.filter_tag);
+                }
+                TagEntryMode::Export => {
+                    let dest = entry.text.trim();
+                    if dest.is_empty() {
+                        return;
+                    }
+                    match gallery.copy_selected_to(Path::new(dest)) {
+                        Ok(copied) => {
+                            info!("Gallery: Exported {} images to {:?}", copied.len(), dest);
+                            gallery.selected_set.clear();
+                        }
+                        Err(e) => warn!("Gallery: Export to {:?} failed: {:#}", dest, e),
+                    }
+                }
+                TagEntryMode::Add => {
+                    let Some(path) = gallery.current_image().cloned() else {
+                        return;
+                    };
+                    if entry.text.trim().is_empty() {
+                        return;
+                    }
+                    match add_tag(&path, &entry.text) {
+                        Ok(sidecar) => {
+                            info!("Gallery: Tagged {:?} with {:?}", path, entry.text.trim());
+                            gallery.set_tags(path.clone(), sidecar.tags.clone());
+                            if let Some(sidecars) = sidecars {
+                                sidecars.insert(path, sidecar);
+                            }
+                        }
+                        Err(e) => warn!("Gallery: Failed to tag {:?}: {:#}", path, e),
+                    }
+                }
+            }
+        }
+        KeyCode::Char(c) if !event.modifiers.contains(KeyModifiers::CONTROL) => entry.text.push(c),
+        _ => {}
+    }
+}
+
+/// Handle keys while the zoom view is showing.
+fn handle_zoom_input(event: &KeyEvent, zoom: &mut GalleryZoom) {
+    match event.code {
+        KeyCode::Left | KeyCode::Char('h') => zoom.pan(-1, 0),
+        KeyCode::Right | KeyCode::Char('l') => zoom.pan(1, 0),
+        KeyCode::Up | KeyCode::Char('k') => zoom.pan(0, -1),
+        KeyCode::Down | KeyCode::Char('j') => zoom.pan(0, 1),
+        KeyCode::Char('+') | KeyCode::Char('=') => zoom.zoom_in(),
+        KeyCode::Char('-') => zoom.zoom_out(),
+        KeyCode::Esc | KeyCode::Char('z') => {
+            zoom.close();
+            debug!("Gallery: Exit zoom view");
+        }
+        _ => {}
+    }
+}
+
+/// Handle keys while a crop selection is active.
+fn handle_crop_input(event: &KeyEvent, gallery: &mut GalleryState) {
+    let resize = event.modifiers.contains(KeyModifiers::SHIFT);
+    let Some(crop) = gallery.crop.as_mut() else {
+        return;
+    };
+
+    let delta = match event.code {
+        KeyCode::Left => Some((-1, 0)),
+        KeyCode::Right => Some((1, 0)),
+        KeyCode::Up => Some((0, -1)),
+        KeyCode::Down => Some((0, 1)),
+        _ => None,
+    };
+
+    if let Some((dx, dy)) = delta {
+        if resize {
+            crop.resize_by(dx, dy);
+        } else {
+            crop.move_by(dx, dy);
+        }
+        return;
+    }
+
+    match event.code {
+        KeyCode::Enter => {
+            let rect = crop.rect;
+            if let Some(source) = gallery.current_image().cloned() {
+                match crop_to_file(&source, rect) {
+                    Ok(output) => {
+                        info!("Gallery: Saved crop {:?}", output);
+                        gallery.add_image(output);
+                    }
+                    Err(e) => warn!("Gallery: Crop failed: {:#}", e),
+                }
+            }
+            gallery.cancel_crop();
+        }
+        KeyCode::Esc => {
+            gallery.cancel_crop();
+            debug!("Gallery: Crop cancelled");
+        }
+        _ => {}
+    }
+}
+
+/// Handle keys while the metadata editor is open.
+fn handle_metadata_input(
+    event: &KeyEvent,
+    gallery: &mut GalleryState,
+    sidecars: Option<&mut SidecarCache>,
+) {
+    let path = gallery.current_image().cloned();
+    let Some(form) = gallery.metadata.as_mut() else {
+        return;
+    };
+
+    match event.code {
+        KeyCode::Esc => {
+            gallery.close_metadata_edit();
+            debug!("Gallery: Metadata edit cancelled");
+        }
+        KeyCode::Tab | KeyCode::Down => form.next_field(),
+        KeyCode::BackTab | KeyCode::Up => form.previous_field(),
+        KeyCode::Backspace => form.backspace(),
+        KeyCode::Enter => {
+            let Some(path) = path else {
+                gallery.close_metadata_edit();
+                return;
+            };
+            match save_metadata_form(&path, form) {
+                Ok(sidecar) => {
+                    info!("Gallery: Saved metadata for {:?}", path);
+                    gallery.set_tags(path.clone(), sidecar.tags.clone());
+                    if let Some(sidecars) = sidecars {
+                        sidecars.insert(path, sidecar);
+                    }
+                    gallery.close_metadata_edit();
+                }
+                Err(e) => form.error = Some(format!("{:#}", e)),
+            }
+        }
+        KeyCode::Char(c) if !event.modifiers.contains(KeyModifiers::CONTROL) => form.push_char(c),
+        _ => {}
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use bevy::app::App;
+    use crate::desktop::RecordingDesktop;
     use std::path::PathBuf;
 
     fn create_key_event(code: KeyCode) -> KeyEvent {
@@ -123,7 +532,8 @@ mod tests {
         app.add_event::<KeyEvent>();
         app.add_event::<SelectNextImage>();
         app.add_event::<SelectPreviousImage>();
-        app.add_event::<DeleteImage>();
+        app.init_resource::<ConfirmModal>();
+        app.init_resource::<Keybindings>();
 
         // Add system
         app.add_systems(Update, handle_gallery_input);
@@ -153,23 +563,26 @@ mod tests {
         app.add_event::<KeyEvent>();
         app.add_event::<SelectNextImage>();
         app.add_event::<SelectPreviousImage>();
-        app.add_event::<DeleteImage>();
+        app.init_resource::<ConfirmModal>();
+        app.init_resource::<Keybindings>();
 
         // Add system
         app.add_systems(Update, handle_gallery_input);
 
-        // Simulate 'd' key press
+        // Simulate 'd' key press: asks for confirmation first
         app.world_mut()
             .send_event(create_key_event(KeyCode::Char('d')));
 
         app.update();
 
-        // Verify DeleteImage event was sent
-        let mut delete_events = app.world_mut().resource_mut::<Events<DeleteImage>>();
-        let mut reader = delete_events.get_cursor();
-        let events: Vec<_> = reader.read(&delete_events).collect();
-        assert_eq!(events.len(), 1);
-        assert_eq!(events[0].image_path, PathBuf::from("/test/img1.png"));
+        // Verify the confirmation dialog will delete the selected image
+        let confirm = app.world().resource::<ConfirmModal>();
+        assert!(confirm.open);
+        assert_eq!(confirm.message, "Delete img1.png?");
+        assert_eq!(
+            confirm.on_confirm,
+            Some(ConfirmAction::DeleteImage(PathBuf::from("/test/img1.png")))
+        );
     }
 
     #[test]
@@ -184,7 +597,8 @@ mod tests {
         app.add_event::<KeyEvent>();
         app.add_event::<SelectNextImage>();
         app.add_event::<SelectPreviousImage>();
-        app.add_event::<DeleteImage>();
+        app.init_resource::<ConfirmModal>();
+        app.init_resource::<Keybindings>();
 
         // Add system
         app.add_systems(Update, handle_gallery_input);
@@ -216,7 +630,8 @@ mod tests {
         app.add_event::<KeyEvent>();
         app.add_event::<SelectNextImage>();
         app.add_event::<SelectPreviousImage>();
-        app.add_event::<DeleteImage>();
+        app.init_resource::<ConfirmModal>();
+        app.init_resource::<Keybindings>();
 
         // Add system
         app.add_systems(Update, handle_gallery_input);
@@ -227,10 +642,19 @@ mod tests {
 
         app.update();
 
-        // Verify 5 SelectNextImage events were sent
-        let mut next_events = app.world_mut().resource_mut::<Events<SelectNextImage>>();
-        let mut reader = next_events.get_cursor();
-        assert_eq!(reader.read(&next_events).count(), 5);
+        // Moved a whole list window
+        assert_eq!(app.world().resource::<GalleryState>().selected, THUMBNAIL_WINDOW);
+
+        // Paging past the end stops at the last image
+        for _ in 0..2 {
+            app.world_mut().send_event(create_key_event(KeyCode::PageDown));
+        }
+        app.update();
+        assert_eq!(app.world().resource::<GalleryState>().selected, 19);
+
+        app.world_mut().send_event(create_key_event(KeyCode::PageUp));
+        app.update();
+        assert_eq!(app.world().resource::<GalleryState>().selected, 9);
     }
 
     #[test]
@@ -248,7 +672,8 @@ mod tests {
         app.add_event::<KeyEvent>();
         app.add_event::<SelectNextImage>();
         app.add_event::<SelectPreviousImage>();
-        app.add_event::<DeleteImage>();
+        app.init_resource::<ConfirmModal>();
+        app.init_resource::<Keybindings>();
 
         // Add system
         app.add_systems(Update, handle_gallery_input);
@@ -288,17 +713,183 @@ mod tests {
         app.add_event::<KeyEvent>();
         app.add_event::<SelectNextImage>();
         app.add_event::<SelectPreviousImage>();
-        app.add_event::<DeleteImage>();
+        app.init_resource::<ConfirmModal>();
+        app.init_resource::<Keybindings>();
 
         // Add system
         app.add_systems(Update, handle_gallery_input);
 
-        // Test Home key - should send 5 prev events (to go from index 5 to 0)
+        // Test Home key - jumps straight from index 5 to 0
         app.world_mut().send_event(create_key_event(KeyCode::Home));
         app.update();
+        assert_eq!(app.world().resource::<GalleryState>().selected, 0);
 
-        let mut prev_events = app.world_mut().resource_mut::<Events<SelectPreviousImage>>();
-        let mut reader = prev_events.get_cursor();
-        assert_eq!(reader.read(&prev_events).count(), 5);
+        // End jumps to the last image
+        app.world_mut().send_event(create_key_event(KeyCode::End));
+        app.update();
+        assert_eq!(app.world().resource::<GalleryState>().selected, 9);
+    }
+
+    #[test]
+    fn test_focus_mode_enter_navigate_exit() {
+        let mut app = App::new();
+
+        app.insert_resource(CurrentScreen(Screen::Gallery));
+        let mut gallery = GalleryState::default();
+        gallery.add_image(PathBuf::from("/test/img1.png"));
+        gallery.add_image(PathBuf::from("/test/img2.png"));
+        app.insert_resource(gallery);
+
+        app.add_event::<KeyEvent>();
+        app.add_event::<SelectNextImage>();
+        app.add_event::<SelectPreviousImage>();
+        app.init_resource::<ConfirmModal>();
+        app.init_resource::<Keybindings>();
+
+        app.add_systems(Update, handle_gallery_input);
+
+        app.world_mut().send_event(create_key_event(KeyCode::Char('f')));
+        app.update();
+        assert!(app.world().resource::<GalleryState>().focus_mode);
+
+        // Delete is disabled while focused, navigation still works
+        app.world_mut().send_event(create_key_event(KeyCode::Char('d')));
+        app.world_mut().send_event(create_key_event(KeyCode::Right));
+        app.update();
+
+        assert!(!app.world().resource::<ConfirmModal>().open);
+        let next_events = app.world().resource::<Events<SelectNextImage>>();
+        assert_eq!(next_events.get_cursor().read(next_events).count(), 1);
+
+        app.world_mut().send_event(create_key_event(KeyCode::Esc));
+        app.update();
+        assert!(!app.world().resource::<GalleryState>().focus_mode);
+    }
+
+    #[test]
+    fn test_crop_mode_writes_cropped_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("sprite.png");
+        image::RgbaImage::new(64, 64).save(&source).unwrap();
+
+        let mut app = App::new();
+        app.insert_resource(CurrentScreen(Screen::Gallery));
+        let mut gallery = GalleryState::default();
+        gallery.add_image(source.clone());
+        app.insert_resource(gallery);
+
+        app.add_event::<KeyEvent>();
+        app.add_event::<SelectNextImage>();
+        app.add_event::<SelectPreviousImage>();
+        app.init_resource::<ConfirmModal>();
+        app.init_resource::<Keybindings>();
+        app.add_systems(Update, handle_gallery_input);
+
+        app.world_mut().send_event(create_key_event(KeyCode::Char('c')));
+        app.update();
+        let crop = app.world().resource::<GalleryState>().crop.unwrap();
+        assert_eq!((crop.rect.width, crop.rect.height), (32, 32));
+
+        // Shift+Left shrinks, plain arrows move; neither navigates the gallery
+        app.world_mut().send_event(KeyEvent(crossterm::event::KeyEvent::new(
+            KeyCode::Left,
+            KeyModifiers::SHIFT,
+        )));
+        app.world_mut().send_event(create_key_event(KeyCode::Right));
+        app.update();
+        let crop = app.world().resource::<GalleryState>().crop.unwrap();
+        assert_eq!((crop.rect.x, crop.rect.width), (17, 31));
+        let next_events = app.world().resource::<Events<SelectNextImage>>();
+        assert_eq!(next_events.get_cursor().read(next_events).count(), 0);
+
+        app.world_mut().send_event(create_key_event(KeyCode::Enter));
+        app.update();
+
+        let gallery = app.world().resource::<GalleryState>();
+        assert!(!gallery.is_cropping());
+        let output = dir.path().join("sprite_crop_31x32.png");
+        assert!(gallery.images.contains(&output));
+        assert_eq!(image::image_dimensions(&output).unwrap(), (31, 32));
+    }
+
+    #[test]
+    fn test_tag_and_filter_from_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let knight = dir.path().join("knight.png");
+        let slime = dir.path().join("slime.png");
+
+        let mut app = App::new();
+        app.insert_resource(CurrentScreen(Screen::Gallery));
+        let mut gallery = GalleryState::default();
+        gallery.add_image(knight.clone());
+        gallery.add_image(slime.clone());
+        app.insert_resource(gallery);
+
+        app.add_event::<KeyEvent>();
+        app.add_event::<SelectNextImage>();
+        app.add_event::<SelectPreviousImage>();
+        app.init_resource::<ConfirmModal>();
+        app.init_resource::<Keybindings>();
+        app.add_systems(Update, handle_gallery_input);
+
+        let type_keys = |app: &mut App, keys: &str| {
+            for c in keys.chars() {
+                app.world_mut().send_event(create_key_event(KeyCode::Char(c)));
+            }
+            app.world_mut().send_event(create_key_event(KeyCode::Enter));
+            app.update();
+        };
+
+        // Tag the first image; the sidecar is written straight away
+        type_keys(&mut app, "+hero");
+        assert_eq!(load_sidecar(&knight).unwrap().tags, vec!["hero"]);
+        assert_eq!(app.world().resource::<GalleryState>().tags_for(&knight), ["hero"]);
+
+        // Filtering on the tag leaves just that image
+        app.world_mut().resource_mut::<GalleryState>().selected = 1;
+        type_keys(&mut app, "/hero");
+        let gallery = app.world().resource::<GalleryState>();
+        assert_eq!(gallery.filter_tag.as_deref(), Some("hero"));
+        assert_eq!(gallery.visible_indices(), vec![0]);
+        assert_eq!(gallery.current_image(), Some(&knight));
+
+        // An empty filter clears it
+        app.world_mut().send_event(create_key_event(KeyCode::Char('/')));
+        app.world_mut().send_event(create_key_event(KeyCode::Backspace));
+        app.world_mut().send_event(create_key_event(KeyCode::Backspace));
+        app.world_mut().send_event(create_key_event(KeyCode::Backspace));
+        app.world_mut().send_event(create_key_event(KeyCode::Backspace));
+        app.world_mut().send_event(create_key_event(KeyCode::Enter));
+        app.update();
+        let gallery = app.world().resource::<GalleryState>();
+        assert_eq!(gallery.filter_tag, None);
+        assert_eq!(gallery.visible_indices().len(), 2);
+    }
+
+    #[test]
+    fn test_reveal_and_copy_path_of_selected_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = std::fs::canonicalize(dir.path()).unwrap();
+        let knight = root.join("knight.png");
+        std::fs::write(&knight, b"png").unwrap();
+
+        let mut gallery = GalleryState::default();
+        let mut recording = RecordingDesktop::default();
+        assert_eq!(reveal_image(&gallery, &mut recording), Ok(false));
+        assert_eq!(copy_image_path(&gallery, &mut recording), Ok(false));
+
+        gallery.add_image(knight.clone());
+        assert_eq!(reveal_image(&gallery, &mut recording), Ok(true));
+        assert_eq!(copy_image_path(&gallery, &mut recording), Ok(true));
+        assert_eq!(
+            recording.received,
+            vec![root.to_string_lossy().into_owned(), knight.to_string_lossy().into_owned()]
+        );
+
+        // A failure is shown to the user
+        let mut notifications = Notifications::default();
+        recording.fail = true;
+        notifications.notify_failure(copy_image_path(&gallery, &mut recording));
+        assert_eq!(notifications.len(), 1);
     }
 }
@@ -1,23 +1,366 @@
 //! # Generation Screen Input Handler
 //!
 //! Handles input events specific to the Generation screen.
-//! Primary interactions: Enter (submit job), Esc (clear input), G (generate), C (compare).
+//! Primary interactions: Enter (submit job), Esc (clear input), G (generate), C (compare),
+//! T (prompt presets), Alt+S / [ / ] (seed lock and adjust), Alt+R (regenerate
+//! the last submission), Alt+W (seed sweep of the last submission), Up/Down
+//! and + / - (select and adjust steps or CFG scale); the unmodified keys only
+//! while the prompt is empty, since otherwise they're just typed. G, X, T,
+//! Alt+S, Alt+R and Alt+W are the default keys and can be rebound (see
+//! `Keybindings`); one rebound to a plain letter also waits for an empty
+//! prompt. In debug mode, PageUp/PageDown and Home/End scroll the Logs tab.
 
+use bevy::ecs::event::EventCursor;
 use bevy::prelude::*;
 use bevy_ratatui::event::KeyEvent;
-use crossterm::event::{KeyCode, KeyModifiers};
+use crossterm::event::{KeyCode, KeyEventKind, KeyModifiers};
 
 use crate::bevy_app::{
     components::{Job, JobStatus},
     events::{CancelJob, SubmitGenerationJob},
-    resources::{AppState, CurrentScreen, InputBuffer, Screen},
+    resources::{
+        AppState, CurrentScreen, GenParams, InputBuffer, KeyAction, Keybindings, PromptPresets,
+        Screen, SeedMode, SeedSweep, SettingsState, SweepField, LOG_PAGE_LINES,
+    },
 };
 
+/// Handle the prompt preset picker.
+///
+/// `t` opens the picker while the prompt being typed is empty (otherwise
+/// it's just a letter). While it's open, Up/Down pick a preset, Enter puts
+/// it in the prompt box and Esc (or `t` again) closes it. Like the command
+/// palette, keys the picker handles are cleared so text entry and the other
+/// input systems never see them.
+pub fn handle_preset_picker_input(
+    mut key_events: ResMut<Events<KeyEvent>>,
+    mut key_cursor: Local<EventCursor<KeyEvent>>,
+    current_screen: Res<CurrentScreen>,
+    mut presets: ResMut<PromptPresets>,
+    mut input_buffer: ResMut<InputBuffer>,
+    mut app_state: ResMut<AppState>,
+    keybindings: Res<Keybindings>,
+) {
+    let keys: Vec<KeyEvent> = key_cursor.read(&key_events).cloned().collect();
+    if current_screen.0 != Screen::Generation {
+        if presets.picker_open {
+            presets.close_picker();
+        }
+        return;
+    }
+
+    let was_open = presets.picker_open;
+    for event in keys {
+        if event.kind == KeyEventKind::Release {
+            continue;
+        }
+        let picker_key = keybindings.matches(KeyAction::PromptPresets, &event);
+
+        if !presets.picker_open {
+            if picker_key && (!types_text(&event) || focused_field_empty(&input_buffer)) {
+                presets.open_picker();
+                app_state.request_redraw();
+            }
+            continue;
+        }
+
+        match event.code {
+            KeyCode::Esc => presets.close_picker(),
+            _ if picker_key => presets.close_picker(),
+            KeyCode::Enter => {
+                if presets.insert_selected(&mut input_buffer) {
+                    info!(
+                        "Prompt preset inserted: {}",
+                        presets.selected_preset().map_or("", |p| p.name.as_str())
+                    );
+                }
+                presets.close_picker();
+            }
+            KeyCode::Up => presets.select_previous(),
+            KeyCode::Down | KeyCode::Tab => presets.select_next(),
+            _ => {}
+        }
+        app_state.request_redraw();
+    }
+
+    if was_open || presets.picker_open {
+        key_events.clear();
+    }
+}
+
+/// Handle the seed sweep dialog.
+///
+/// Alt+W opens the dialog for the last submission (before anything is
+/// submitted it does nothing). While
+/// it's open, Tab switches between the job count and the base seed,
+/// Left/Right (or -/+) change the count, digits and Backspace edit the base
+/// seed, Enter submits one job per seed and Esc closes it. Keys the dialog
+/// handles are cleared, like the preset picker's.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_seed_sweep_input(
+    mut key_events: ResMut<Events<KeyEvent>>,
+    mut key_cursor: Local<EventCursor<KeyEvent>>,
+    current_screen: Res<CurrentScreen>,
+    input_buffer: Res<InputBuffer>,
+    presets: Option<Res<PromptPresets>>,
+    mut sweep: ResMut<SeedSweep>,
+    mut app_state: ResMut<AppState>,
+    keybindings: Res<Keybindings>,
+    mut submit_events: EventWriter<SubmitGenerationJob>,
+) {
+    let keys: Vec<KeyEvent> = key_cursor.read(&key_events).cloned().collect();
+    if current_screen.0 != Screen::Generation {
+        sweep.close();
+        return;
+    }
+    if presets.is_some_and(|p| p.picker_open) {
+        return;
+    }
+
+    let was_open = sweep.open;
+    let mut handled = false;
+    for event in keys {
+        if event.kind == KeyEventKind::Release {
+            continue;
+        }
+
+        if !sweep.open {
+            if keybindings.matches(KeyAction::SeedSweep, &event)
+                && (!types_text(&event) || focused_field_empty(&input_buffer))
+            {
+                match app_state.last_submission.as_ref().and_then(|last| last.base_seed) {
+                    Some(seed) => {
+                        sweep.open_at(seed);
+                        app_state.request_redraw();
+                    }
+                    None => debug!("Seed sweep: nothing submitted yet"),
+                }
+                handled = true;
+            }
+            continue;
+        }
+
+        match event.code {
+            KeyCode::Esc => sweep.close(),
+            KeyCode::Tab | KeyCode::BackTab | KeyCode::Up | KeyCode::Down => sweep.toggle_field(),
+            KeyCode::Left | KeyCode::Char('-') => sweep.adjust_count(-1),
+            KeyCode::Right | KeyCode::Char('+') | KeyCode::Char('=') => sweep.adjust_count(1),
+            KeyCode::Char(c) if sweep.field == SweepField::BaseSeed => sweep.push_digit(c),
+            KeyCode::Backspace if sweep.field == SweepField::BaseSeed => sweep.backspace(),
+            KeyCode::Enter => {
+                let Some(last) = app_state.last_submission.as_ref() else {
+                    sweep.close();
+                    continue;
+                };
+                match sweep.submission(last) {
+                    Ok(job) => {
+                        info!(
+                            "Seed sweep submitted: {} seeds from {:?}",
+                            sweep.count, job.base_seed
+                        );
+                        submit_events.send(job);
+                        sweep.close();
+                    }
+                    Err(e) => sweep.error = Some(format!("{:#}", e)),
+                }
+            }
+            _ => {}
+        }
+        app_state.request_redraw();
+    }
+
+    if was_open || sweep.open || handled {
+        key_events.clear();
+    }
+}
+
+/// Whether a key would be typed into the prompt (a character without Ctrl/Alt).
+fn types_text(event: &KeyEvent) -> bool {
+    matches!(event.code, KeyCode::Char(_))
+        && !event
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+}
+
+/// Whether the field being typed into (prompt or negative prompt) is empty.
+fn focused_field_empty(input_buffer: &InputBuffer) -> bool {
+    if input_buffer.negative_focused {
+        input_buffer.negative_buffer.is_empty()
+    } else {
+        input_buffer.text.is_empty()
+    }
+}
+
+/// Handle the seed controls.
+///
+/// Alt+S switches between random and fixed seeds and, with an empty prompt,
+/// `[`/`]` step a fixed seed down/up. Handled keys are cleared so text
+/// entry doesn't type them.
+pub fn handle_seed_input(
+    mut key_events: ResMut<Events<KeyEvent>>,
+    mut key_cursor: Local<EventCursor<KeyEvent>>,
+    current_screen: Res<CurrentScreen>,
+    input_buffer: Res<InputBuffer>,
+    mut app_state: ResMut<AppState>,
+    keybindings: Res<Keybindings>,
+) {
+    let keys: Vec<KeyEvent> = key_cursor.read(&key_events).cloned().collect();
+    if current_screen.0 != Screen::Generation {
+        return;
+    }
+    let field_empty = focused_field_empty(&input_buffer);
+
+    let mut handled = false;
+    for event in keys {
+        if event.kind == KeyEventKind::Release || (types_text(&event) && !field_empty) {
+            continue;
+        }
+        let fixed = matches!(app_state.seed_mode, SeedMode::Fixed(_));
+        let step = fixed && types_text(&event);
+        match event.code {
+            _ if keybindings.matches(KeyAction::ToggleSeed, &event) => {
+                app_state.toggle_seed_mode()
+            }
+            KeyCode::Char('[') if step => app_state.step_seed(-1),
+            KeyCode::Char(']') if step => app_state.step_seed(1),
+            _ => continue,
+        }
+        handled = true;
+    }
+
+    if handled {
+        key_events.clear();
+    }
+}
+
+/// Handle the regenerate key.
+///
+/// Alt+R submits the last job again with the same prompt, seed, steps and
+/// CFG scale; with nothing submitted yet it does nothing. The key is cleared
+/// so text entry doesn't type it.
+pub fn handle_regenerate_input(
+    mut key_events: ResMut<Events<KeyEvent>>,
+    mut key_cursor: Local<EventCursor<KeyEvent>>,
+    current_screen: Res<CurrentScreen>,
+    input_buffer: Res<InputBuffer>,
+    app_state: Res<AppState>,
+    keybindings: Res<Keybindings>,
+    mut submit_events: EventWriter<SubmitGenerationJob>,
+) {
+    let keys: Vec<KeyEvent> = key_cursor.read(&key_events).cloned().collect();
+    if current_screen.0 != Screen::Generation {
+        return;
+    }
+    let field_empty = focused_field_empty(&input_buffer);
+
+    let mut handled = false;
+    for event in keys {
+        if (types_text(&event) && !field_empty)
+            || !keybindings.matches(KeyAction::Regenerate, &event)
+        {
+            continue;
+        }
+        if let Some(job) = app_state.resubmit_last() {
+            info!("Regenerating: {}", job.prompt);
+            submit_events.send(job);
+        }
+        handled = true;
+    }
+
+    if handled {
+        key_events.clear();
+    }
+}
+
+/// Scroll the debug Logs tab.
+///
+/// PageUp/PageDown move a page, Home goes to the oldest line and End back to
+/// the newest. Scrolled up, the view stays put as lines arrive; End (or
+/// paging all the way down) follows the tail again. Handled keys are cleared
+/// so Home/End don't also move the prompt cursor.
+pub fn handle_log_scroll_input(
+    mut key_events: ResMut<Events<KeyEvent>>,
+    mut key_cursor: Local<EventCursor<KeyEvent>>,
+    current_screen: Res<CurrentScreen>,
+    mut app_state: ResMut<AppState>,
+) {
+    let keys: Vec<KeyEvent> = key_cursor.read(&key_events).cloned().collect();
+    if current_screen.0 != Screen::Generation || !app_state.debug_mode || app_state.preview_tab != 1
+    {
+        return;
+    }
+
+    let mut handled = false;
+    for event in keys {
+        if event.kind == KeyEventKind::Release {
+            continue;
+        }
+        match event.code {
+            KeyCode::PageUp => app_state.scroll_logs_up(LOG_PAGE_LINES),
+            KeyCode::PageDown => app_state.scroll_logs_down(LOG_PAGE_LINES),
+            KeyCode::Home => app_state.scroll_logs_to_top(),
+            KeyCode::End => app_state.follow_logs(),
+            _ => continue,
+        }
+        handled = true;
+    }
+
+    if handled {
+        key_events.clear();
+    }
+}
+
+/// Handle the inline steps / CFG scale controls.
+///
+/// With an empty prompt, Up/Down select a parameter (Up past the first one
+/// leaves the controls) and `+`/`-` adjust the selected one. The adjusted
+/// values go into [`GenParams`], not the saved settings. Handled keys are
+/// cleared so text entry doesn't see them.
+pub fn handle_gen_param_input(
+    mut key_events: ResMut<Events<KeyEvent>>,
+    mut key_cursor: Local<EventCursor<KeyEvent>>,
+    current_screen: Res<CurrentScreen>,
+    input_buffer: Res<InputBuffer>,
+    settings: Res<SettingsState>,
+    mut gen_params: ResMut<GenParams>,
+    mut app_state: ResMut<AppState>,
+) {
+    let keys: Vec<KeyEvent> = key_cursor.read(&key_events).cloned().collect();
+    if current_screen.0 != Screen::Generation || !focused_field_empty(&input_buffer) {
+        return;
+    }
+
+    let mut handled = false;
+    for event in keys {
+        if event.kind == KeyEventKind::Release {
+            continue;
+        }
+        let adjusting = gen_params.selected.is_some();
+        match event.code {
+            KeyCode::Down => gen_params.select_next(),
+            KeyCode::Up if adjusting => gen_params.select_previous(),
+            KeyCode::Char('+') | KeyCode::Char('=') if adjusting => {
+                gen_params.adjust(1, &settings.generation);
+            }
+            KeyCode::Char('-') if adjusting => {
+                gen_params.adjust(-1, &settings.generation);
+            }
+            _ => continue,
+        }
+        handled = true;
+        app_state.request_redraw();
+    }
+
+    if handled {
+        key_events.clear();
+    }
+}
+
 /// Handle input for Generation screen.
 ///
 /// This system only processes input when CurrentScreen is Screen::Generation.
 /// Text input (typing, backspace, cursor movement) is handled by the global
 /// text_entry system.
+#[allow(clippy::too_many_arguments)]
 pub fn handle_generation_input(
     mut key_events: EventReader<KeyEvent>,
     current_screen: Res<CurrentScreen>,
@@ -26,18 +369,34 @@ pub fn handle_generation_input(
     mut cancel_events: EventWriter<CancelJob>,
     mut app_state: ResMut<AppState>,
     jobs: Query<&Job>,
+    keybindings: Res<Keybindings>,
+    gen_params: Option<Res<GenParams>>,
 ) {
     if current_screen.0 != Screen::Generation {
         return;
     }
+    let gen_params = gen_params.as_deref().cloned().unwrap_or_default();
 
     for event in key_events.read() {
         match event.code {
+                KeyCode::Enter
+                    if event
+                        .modifiers
+                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+                {
+                    // Ctrl+Enter inserts a line break (handled by text_entry)
+                }
                 KeyCode::Enter => {
-                    // Submit generation job if input is not empty
+                    // Submit generation job if input is not empty (full multi-line text)
                     if !input_buffer.text.trim().is_empty() {
                         submit_events.send(SubmitGenerationJob {
                             prompt: input_buffer.text.clone(),
+                            negative_prompt: input_buffer.negative_prompt(),
+                            batch_size: None,
+                            base_seed: None,
+                            steps: gen_params.steps,
+                            cfg_scale: gen_params.cfg_scale,
+                            sweep: false,
                         });
                         input_buffer.clear();
                         app_state.request_redraw();
@@ -49,18 +408,24 @@ pub fn handle_generation_input(
                     input_buffer.clear();
                     app_state.request_redraw();
                 }
-                KeyCode::Char('g') | KeyCode::Char('G') => {
+                _ if keybindings.matches(KeyAction::Generate, event) => {
                     // Generate shortcut (same as Enter)
                     if !input_buffer.text.trim().is_empty() {
                         submit_events.send(SubmitGenerationJob {
                             prompt: input_buffer.text.clone(),
+                            negative_prompt: input_buffer.negative_prompt(),
+                            batch_size: None,
+                            base_seed: None,
+                            steps: gen_params.steps,
+                            cfg_scale: gen_params.cfg_scale,
+                            sweep: false,
                         });
                         input_buffer.clear();
                         app_state.request_redraw();
-                        info!("Generation job submitted via 'G' key");
+                        info!("Generation job submitted via the generate key");
                     }
                 }
-                KeyCode::Char('x') | KeyCode::Char('X') => {
+                _ if keybindings.matches(KeyAction::CancelJob, event) => {
                     // Cancel current running job (changed from 'c' to 'x' to avoid conflict with compare)
                     // Find the most recent active job
                     let active_jobs: Vec<&Job> = jobs.iter().filter(|j| j.is_active()).collect();
@@ -92,12 +457,26 @@ pub fn handle_generation_input(
                     info!("Comparison screen shortcut pressed (not yet implemented)");
                     // TODO: Send NavigateToScreen(Screen::Comparison) event when WS-10 is complete
                 }
+                KeyCode::Char('p') | KeyCode::Char('P')
+                    if event.modifiers.contains(KeyModifiers::ALT) =>
+                {
+                    // Alt+P: pin the previous image next to the current one
+                    // (Ctrl+P opens the command palette)
+                    app_state.toggle_pin_preview();
+                }
                 KeyCode::Char('p') | KeyCode::Char('P') => {
                     // Toggle preview tab (in debug mode)
                     if app_state.debug_mode {
                         app_state.set_preview_tab(0);
                     }
                 }
+                KeyCode::Char('l') | KeyCode::Char('L')
+                    if event.modifiers.contains(KeyModifiers::CONTROL) =>
+                {
+                    // Ctrl+L: switch the logs tab between backend and frontend logs
+                    // (in debug mode)
+                    app_state.toggle_log_source();
+                }
                 KeyCode::Char('l') | KeyCode::Char('L') => {
                     // Show logs tab (in debug mode)
                     if app_state.debug_mode {
@@ -122,15 +501,18 @@ pub fn handle_generation_input(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bevy_app::resources::PromptPreset;
     use bevy::app::App;
 
     fn create_test_app() -> App {
         let mut app = App::new();
+        app.add_event::<KeyEvent>();
         app.add_event::<SubmitGenerationJob>();
         app.add_event::<CancelJob>();
         app.init_resource::<CurrentScreen>();
         app.init_resource::<InputBuffer>();
         app.init_resource::<AppState>();
+        app.init_resource::<Keybindings>();
         app.add_systems(Update, handle_generation_input);
         app
     }
@@ -189,4 +571,287 @@ mod tests {
         // System should return early, input unchanged
         assert_eq!(app.world().resource::<InputBuffer>().text, "test");
     }
+
+    #[test]
+    fn test_enter_submits_complete_multiline_prompt() {
+        let mut app = create_test_app();
+        app.add_systems(Update, crate::bevy_app::systems::input::handle_text_input);
+
+        app.world_mut().resource_mut::<CurrentScreen>().0 = Screen::Generation;
+        {
+            let mut input = app.world_mut().resource_mut::<InputBuffer>();
+            for c in "pixel knight".chars() {
+                input.insert(c);
+            }
+        }
+
+        let key = |code, modifiers| KeyEvent(crossterm::event::KeyEvent::new(code, modifiers));
+
+        // Ctrl+Enter adds a line instead of submitting
+        app.world_mut()
+            .send_event(key(KeyCode::Enter, KeyModifiers::CONTROL));
+        app.update();
+        {
+            let input = app.world().resource::<InputBuffer>();
+            assert_eq!(input.text, "pixel knight\n");
+            let submitted = app.world().resource::<Events<SubmitGenerationJob>>();
+            assert_eq!(submitted.get_cursor().read(submitted).count(), 0);
+        }
+
+        app.world_mut()
+            .resource_mut::<InputBuffer>()
+            .text
+            .push_str("blurry, text");
+        app.world_mut()
+            .send_event(key(KeyCode::Enter, KeyModifiers::NONE));
+        app.update();
+
+        let submitted = app.world().resource::<Events<SubmitGenerationJob>>();
+        let prompts: Vec<_> = submitted
+            .get_cursor()
+            .read(submitted)
+            .map(|e| e.prompt.clone())
+            .collect();
+        assert_eq!(prompts, vec!["pixel knight\nblurry, text".to_string()]);
+        assert!(app.world().resource::<InputBuffer>().text.is_empty());
+    }
+
+    #[test]
+    fn test_preset_picker_inserts_template() {
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Generation));
+        app.init_resource::<InputBuffer>();
+        app.init_resource::<AppState>();
+        app.init_resource::<Keybindings>();
+        app.insert_resource(PromptPresets {
+            presets: vec![
+                PromptPreset {
+                    name: "Icon".to_string(),
+                    template: "{item} icon".to_string(),
+                },
+                PromptPreset {
+                    name: "Sprite".to_string(),
+                    template: "16-bit {subject} sprite".to_string(),
+                },
+            ],
+            ..Default::default()
+        });
+        app.add_systems(
+            Update,
+            (
+                handle_preset_picker_input,
+                crate::bevy_app::systems::input::handle_text_input,
+            )
+                .chain(),
+        );
+
+        let key = |code| KeyEvent(crossterm::event::KeyEvent::new(code, KeyModifiers::NONE));
+        app.world_mut().send_event(key(KeyCode::Char('t')));
+        app.update();
+        assert!(app.world().resource::<PromptPresets>().picker_open);
+        // The key opened the picker instead of being typed
+        assert!(app.world().resource::<InputBuffer>().text.is_empty());
+
+        app.world_mut().send_event(key(KeyCode::Down));
+        app.world_mut().send_event(key(KeyCode::Enter));
+        app.update();
+        app.world_mut().send_event(key(KeyCode::Char('c')));
+        app.world_mut().send_event(key(KeyCode::Char('t')));
+        app.update();
+
+        assert!(!app.world().resource::<PromptPresets>().picker_open);
+        // With a prompt typed, 't' is just a letter
+        assert_eq!(app.world().resource::<InputBuffer>().text, "16-bit ct sprite");
+    }
+
+    #[test]
+    fn test_adjusted_params_are_submitted() {
+        let mut app = create_test_app();
+        app.init_resource::<SettingsState>();
+        app.init_resource::<GenParams>();
+        app.add_systems(Update, handle_gen_param_input.before(handle_generation_input));
+        app.world_mut().resource_mut::<CurrentScreen>().0 = Screen::Generation;
+
+        let key = |code| KeyEvent(crossterm::event::KeyEvent::new(code, KeyModifiers::NONE));
+        // Steps up twice, then CFG scale down once
+        for code in [KeyCode::Down, KeyCode::Char('+'), KeyCode::Char('+')] {
+            app.world_mut().send_event(key(code));
+        }
+        app.world_mut().send_event(key(KeyCode::Down));
+        app.world_mut().send_event(key(KeyCode::Char('-')));
+        app.update();
+
+        let defaults = SettingsState::default().generation;
+        let params = app.world().resource::<GenParams>().clone();
+        assert_eq!(params.steps, Some(defaults.default_steps + 2));
+        assert_eq!(params.cfg_scale, Some(defaults.default_cfg_scale - 0.5));
+        // The saved defaults are untouched
+        let settings = app.world().resource::<SettingsState>();
+        assert_eq!(settings.generation.default_steps, defaults.default_steps);
+
+        app.world_mut().resource_mut::<InputBuffer>().text = "pixel knight".to_string();
+        app.world_mut().send_event(key(KeyCode::Enter));
+        app.update();
+
+        let submitted = app.world().resource::<Events<SubmitGenerationJob>>();
+        let job = submitted.get_cursor().read(submitted).last().unwrap().clone();
+        assert_eq!(job.steps, params.steps);
+        assert_eq!(job.cfg_scale, params.cfg_scale);
+    }
+
+    #[test]
+    fn test_seed_keys_toggle_and_step_with_empty_prompt() {
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Generation));
+        app.init_resource::<InputBuffer>();
+        app.init_resource::<AppState>();
+        app.init_resource::<Keybindings>();
+        app.add_systems(
+            Update,
+            (
+                handle_seed_input,
+                crate::bevy_app::systems::input::handle_text_input,
+            )
+                .chain(),
+        );
+        app.world_mut().resource_mut::<AppState>().last_seed = Some(100);
+
+        let key = |code| KeyEvent(crossterm::event::KeyEvent::new(code, KeyModifiers::NONE));
+        let alt_s =
+            || KeyEvent(crossterm::event::KeyEvent::new(KeyCode::Char('s'), KeyModifiers::ALT));
+        app.world_mut().send_event(alt_s());
+        app.update();
+        app.world_mut().send_event(key(KeyCode::Char(']')));
+        app.world_mut().send_event(key(KeyCode::Char(']')));
+        app.world_mut().send_event(key(KeyCode::Char('[')));
+        app.update();
+
+        // Locked to the last seed, then stepped; none of the keys were typed
+        assert_eq!(app.world().resource::<AppState>().seed_mode, SeedMode::Fixed(101));
+        assert!(app.world().resource::<InputBuffer>().text.is_empty());
+
+        app.world_mut().send_event(alt_s());
+        app.update();
+        assert_eq!(app.world().resource::<AppState>().seed_mode, SeedMode::Random);
+
+        // A prompt can start with 's'; Alt+S still works once one is typed
+        app.world_mut().send_event(key(KeyCode::Char('s')));
+        app.update();
+        assert_eq!(app.world().resource::<AppState>().seed_mode, SeedMode::Random);
+        assert_eq!(app.world().resource::<InputBuffer>().text, "s");
+        app.world_mut().send_event(alt_s());
+        app.update();
+        assert!(matches!(app.world().resource::<AppState>().seed_mode, SeedMode::Fixed(_)));
+        assert_eq!(app.world().resource::<InputBuffer>().text, "s");
+    }
+
+    #[test]
+    fn test_scrolling_logs_pauses_follow_until_end() {
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Generation));
+        app.init_resource::<InputBuffer>();
+        app.insert_resource(AppState {
+            debug_mode: true,
+            preview_tab: 1,
+            ..Default::default()
+        });
+        app.add_systems(
+            Update,
+            (
+                handle_log_scroll_input,
+                crate::bevy_app::systems::input::handle_text_input,
+            )
+                .chain(),
+        );
+        for i in 0..30 {
+            app.world_mut().resource_mut::<AppState>().add_backend_log(format!("line {}", i));
+        }
+        app.world_mut().resource_mut::<InputBuffer>().insert('a');
+
+        let key = |code| KeyEvent(crossterm::event::KeyEvent::new(code, KeyModifiers::NONE));
+        app.world_mut().send_event(key(KeyCode::PageUp));
+        app.update();
+        let state = app.world().resource::<AppState>();
+        assert!(!state.is_following_logs());
+        assert_eq!(state.log_scroll, LOG_PAGE_LINES);
+
+        // Paused: new lines don't move the view
+        app.world_mut().resource_mut::<AppState>().add_backend_log("line 30".into());
+        assert_eq!(app.world().resource::<AppState>().log_scroll, LOG_PAGE_LINES + 1);
+
+        app.world_mut().send_event(key(KeyCode::Home));
+        app.update();
+        assert_eq!(app.world().resource::<AppState>().log_scroll, 31);
+        // Home scrolled the logs, not the prompt cursor
+        assert_eq!(app.world().resource::<InputBuffer>().cursor, 1);
+
+        app.world_mut().send_event(key(KeyCode::End));
+        app.update();
+        assert!(app.world().resource::<AppState>().is_following_logs());
+    }
+
+    #[test]
+    fn test_regenerate_resubmits_last_params() {
+        use crate::bevy_app::components::Job;
+        use crate::bevy_app::events::{handle_generation_events, GenerationComplete};
+        use crate::bevy_app::resources::JobTracker;
+
+        let mut app = App::new();
+        app.add_event::<KeyEvent>()
+            .add_event::<SubmitGenerationJob>()
+            .add_event::<GenerationComplete>()
+            .add_event::<CancelJob>()
+            .insert_resource(CurrentScreen(Screen::Generation))
+            .init_resource::<InputBuffer>()
+            .init_resource::<AppState>()
+            .init_resource::<Keybindings>()
+            .init_resource::<SettingsState>()
+            .init_resource::<JobTracker>()
+            .add_systems(
+                Update,
+                (
+                    handle_regenerate_input,
+                    crate::bevy_app::systems::input::handle_text_input,
+                    handle_generation_events,
+                )
+                    .chain(),
+            );
+        let alt_r =
+            || KeyEvent(crossterm::event::KeyEvent::new(KeyCode::Char('r'), KeyModifiers::ALT));
+
+        // Nothing submitted yet: no job, and the key isn't typed
+        app.world_mut().send_event(alt_r());
+        app.update();
+        assert!(app.world().resource::<AppState>().last_submission.is_none());
+        assert!(app.world().resource::<InputBuffer>().text.is_empty());
+
+        app.world_mut().send_event(SubmitGenerationJob {
+            prompt: "pixel knight".into(),
+            negative_prompt: Some("blurry".into()),
+            batch_size: None,
+            base_seed: None,
+            steps: Some(40),
+            cfg_scale: None,
+            sweep: false,
+        });
+        app.update();
+        app.world_mut().send_event(alt_r());
+        app.update();
+        app.update();
+
+        let mut query = app.world_mut().query::<&Job>();
+        let jobs: Vec<&Job> = query.iter(app.world()).collect();
+        assert_eq!(jobs.len(), 2);
+        let default_cfg = SettingsState::default().generation.default_cfg_scale;
+        let params = |job: &Job| {
+            let cfg_scale = job.cfg_scale.unwrap_or(default_cfg);
+            (job.prompt.clone(), job.negative_prompt.clone(), job.seed, job.steps, cfg_scale)
+        };
+        // The random seed is repeated, and the default CFG scale is pinned
+        assert_eq!(params(jobs[0]), params(jobs[1]));
+        assert_eq!(jobs[0].steps, Some(40));
+    }
 }
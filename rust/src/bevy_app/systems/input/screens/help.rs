@@ -1,24 +1,53 @@
 use bevy::prelude::*;
 use bevy_ratatui::event::KeyEvent;
+use crossterm::event::{KeyCode, KeyModifiers};
 
-use crate::bevy_app::resources::{CurrentScreen, Screen};
+use crate::bevy_app::resources::{AppState, CurrentScreen, HelpState, Screen};
 
 /// Handle input for Help screen
 ///
-/// The Help screen is informational only - all navigation is handled by
-/// the main keyboard handler. This handler exists for consistency with
-/// other screens.
-pub fn handle_help_input(mut events: EventReader<KeyEvent>, current_screen: Res<CurrentScreen>) {
+/// Up/Down (or j/k), PgUp/PgDn and Home/End scroll the help. `/` starts a
+/// search: typing filters the shortcuts, Enter keeps the filter and Esc
+/// clears it (a kept filter is also cleared by Esc). Screen navigation
+/// (Tab/Shift+Tab/q) is handled by the main keyboard handler, except while
+/// typing into the search.
+pub fn handle_help_input(
+    mut events: EventReader<KeyEvent>,
+    current_screen: Res<CurrentScreen>,
+    mut help: ResMut<HelpState>,
+    mut app_state: ResMut<AppState>,
+) {
     if current_screen.0 != Screen::Help {
         return;
     }
 
     for event in events.read() {
+        if help.searching {
+            match event.code {
+                KeyCode::Esc => help.clear_search(),
+                KeyCode::Enter => help.finish_search(),
+                KeyCode::Backspace => help.pop_query(),
+                KeyCode::Char(c) if !event.modifiers.contains(KeyModifiers::CONTROL) => {
+                    help.push_query(c)
+                }
+                _ => continue,
+            }
+            app_state.request_redraw();
+            continue;
+        }
+
         match event.code {
-            // Help screen is read-only
-            // All navigation (Tab/Shift+Tab/q) is handled by main keyboard handler
-            _ => {}
+            KeyCode::Char('/') => help.start_search(),
+            KeyCode::Esc if !help.query.is_empty() => help.clear_search(),
+            KeyCode::Down | KeyCode::Char('j') => help.scroll_down(1),
+            KeyCode::Up | KeyCode::Char('k') => help.scroll_up(1),
+            KeyCode::PageDown => help.page_down(),
+            KeyCode::PageUp => help.page_up(),
+            KeyCode::Home => help.scroll_to_top(),
+            KeyCode::End => help.scroll_to_bottom(),
+            _ => continue,
         }
+        app_state.request_redraw();
     }
 }
 
@@ -27,11 +56,44 @@ mod tests {
     use super::*;
     use bevy::app::App;
 
+    fn press(app: &mut App, code: KeyCode) {
+        app.world_mut().send_event(KeyEvent(crossterm::event::KeyEvent::new(
+            code,
+            KeyModifiers::NONE,
+        )));
+        app.update();
+    }
+
     #[test]
     fn test_help_input_compiles() {
         let mut app = App::new();
         app.add_event::<KeyEvent>();
         app.insert_resource(CurrentScreen(Screen::Help));
+        app.init_resource::<HelpState>();
+        app.init_resource::<AppState>();
+        app.add_systems(Update, handle_help_input);
+    }
+
+    #[test]
+    fn test_slash_searches() {
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Help));
+        app.init_resource::<HelpState>();
+        app.init_resource::<AppState>();
         app.add_systems(Update, handle_help_input);
+
+        for code in [KeyCode::Char('/'), KeyCode::Char('t'), KeyCode::Char('a')] {
+            press(&mut app, code);
+        }
+        press(&mut app, KeyCode::Backspace);
+        press(&mut app, KeyCode::Char('h'));
+        press(&mut app, KeyCode::Enter);
+        let help = app.world().resource::<HelpState>();
+        assert_eq!(help.query, "th");
+        assert!(!help.searching);
+
+        press(&mut app, KeyCode::Esc);
+        assert!(app.world().resource::<HelpState>().query.is_empty());
     }
 }
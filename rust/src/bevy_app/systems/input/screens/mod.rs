@@ -11,9 +11,13 @@ pub mod settings;
 
 pub use comparison::handle_comparison_input;
 pub use gallery::handle_gallery_input;
-pub use generation::handle_generation_input;
+pub use generation::{
+    handle_gen_param_input, handle_generation_input, handle_log_scroll_input,
+    handle_preset_picker_input, handle_regenerate_input, handle_seed_input,
+    handle_seed_sweep_input,
+};
 pub use help::handle_help_input;
 pub use models::handle_models_input;
 pub use monitor::handle_monitor_input;
 pub use queue::handle_queue_input;
-pub use settings::handle_settings_input;
+pub use settings::{handle_dir_browser_input, handle_settings_input, handle_theme_picker_input};
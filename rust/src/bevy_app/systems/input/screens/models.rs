@@ -2,20 +2,65 @@ use bevy::prelude::*;
 use bevy_ratatui::event::KeyEvent;
 use crossterm::event::{KeyCode, KeyModifiers};
 
-use crate::bevy_app::resources::{CurrentScreen, ModelsState, Screen};
+use crate::bevy_app::resources::models::ModelType;
+use crate::bevy_app::resources::{ConfirmAction, ConfirmModal, CurrentScreen, ModelsState, Screen};
+use crate::bevy_app::systems::zmq::ZmqClientResource;
+
+/// LoRA strength change per +/- key press.
+const LORA_STRENGTH_STEP: f32 = 0.1;
 
 /// Handle input for Models screen
+///
+/// While the filter is being edited (`/`), characters go into it: Enter keeps
+/// the filter, Esc clears it. Navigation keys still move the selection.
+/// `d` downloads the selected model and `r` retries a failed download.
 pub fn handle_models_input(
     mut events: EventReader<KeyEvent>,
     current_screen: Res<CurrentScreen>,
     mut models_state: ResMut<ModelsState>,
+    mut confirm: ResMut<ConfirmModal>,
+    zmq_client: Option<Res<ZmqClientResource>>,
 ) {
     if current_screen.0 != Screen::Models {
         return;
     }
 
     for event in events.read() {
+        if models_state.filter_editing {
+            match event.code {
+                KeyCode::Char(c) => {
+                    models_state.push_filter_char(c);
+                    continue;
+                }
+                KeyCode::Backspace => {
+                    models_state.pop_filter_char();
+                    continue;
+                }
+                KeyCode::Enter => {
+                    models_state.filter_editing = false;
+                    continue;
+                }
+                KeyCode::Esc => {
+                    models_state.clear_filter();
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
         match event.code {
+            KeyCode::Char('/') => {
+                models_state.filter_editing = true;
+            }
+            KeyCode::Char('b') | KeyCode::Char('B') => {
+                models_state.toggle_type_visibility(ModelType::Base);
+            }
+            KeyCode::Char('l') | KeyCode::Char('L') => {
+                models_state.toggle_type_visibility(ModelType::LoRA);
+            }
+            KeyCode::Char('v') | KeyCode::Char('V') => {
+                models_state.toggle_type_visibility(ModelType::VAE);
+            }
             KeyCode::Down | KeyCode::Char('j') => {
                 models_state.next();
             }
@@ -33,22 +78,43 @@ pub fn handle_models_input(
                 }
             }
             KeyCode::Home => {
-                models_state.selected_index = 0;
+                models_state.select_first();
             }
             KeyCode::End => {
-                if !models_state.models.is_empty() {
-                    models_state.selected_index = models_state.models.len() - 1;
-                }
+                models_state.select_last();
             }
             KeyCode::Enter => {
                 models_state.toggle_active();
+                save_active(&models_state);
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                models_state.adjust_lora_strength(LORA_STRENGTH_STEP);
+                save_active(&models_state);
+            }
+            KeyCode::Char('-') => {
+                models_state.adjust_lora_strength(-LORA_STRENGTH_STEP);
+                save_active(&models_state);
             }
             KeyCode::Char('d') | KeyCode::Char('D') => {
-                models_state.download_selected();
+                if !models_state.download_selected() {
+                    continue;
+                }
+                request_download(&mut models_state, zmq_client.as_deref());
+            }
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                if !models_state.retry_selected() {
+                    continue;
+                }
+                request_download(&mut models_state, zmq_client.as_deref());
             }
             KeyCode::Delete => {
                 if event.modifiers.contains(KeyModifiers::SHIFT) {
-                    models_state.delete_selected();
+                    if let Some(model) = models_state.selected_model() {
+                        confirm.ask(
+                            format!("Delete {}?", model.name),
+                            ConfirmAction::DeleteModel(model.name.clone()),
+                        );
+                    }
                 }
             }
             KeyCode::Char('i') | KeyCode::Char('I') | KeyCode::Char(' ') => {
@@ -59,6 +125,34 @@ pub fn handle_models_input(
     }
 }
 
+/// Save the active models, logging (rather than failing on) errors.
+fn save_active(models_state: &ModelsState) {
+    if let Err(e) = models_state.save_active() {
+        warn!("Failed to save active models: {:#}", e);
+    }
+}
+
+/// Ask the backend to download the selected model.
+///
+/// Without a backend, or if the request can't be sent, the download fails
+/// straight away (and can be retried).
+fn request_download(models_state: &mut ModelsState, zmq_client: Option<&ZmqClientResource>) {
+    let Some(name) = models_state.selected_model().map(|model| model.name.clone()) else {
+        return;
+    };
+    let result = match zmq_client {
+        Some(client) => client.0.lock().download_model(&name),
+        None => Err(anyhow::anyhow!("backend not connected")),
+    };
+    match result {
+        Ok(()) => info!("Requested download of {}", name),
+        Err(e) => {
+            warn!("Failed to request download of {}: {:#}", name, e);
+            models_state.fail_download(&name);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,6 +164,99 @@ mod tests {
         app.add_event::<KeyEvent>();
         app.insert_resource(CurrentScreen(Screen::Models));
         app.insert_resource(ModelsState::default());
+        app.init_resource::<ConfirmModal>();
         app.add_systems(Update, handle_models_input);
     }
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.world_mut().send_event(KeyEvent(crossterm::event::KeyEvent::new(
+            code,
+            KeyModifiers::NONE,
+        )));
+        app.update();
+    }
+
+    #[test]
+    fn test_i_toggles_metadata_panel() {
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Models));
+        app.insert_resource(ModelsState::default());
+        app.init_resource::<ConfirmModal>();
+        app.add_systems(Update, handle_models_input);
+
+        press(&mut app, KeyCode::Char('i'));
+        assert!(app.world().resource::<ModelsState>().show_metadata);
+        press(&mut app, KeyCode::Char('i'));
+        assert!(!app.world().resource::<ModelsState>().show_metadata);
+    }
+
+    #[test]
+    fn test_filter_mode_captures_keys() {
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Models));
+        app.insert_resource(ModelsState::default());
+        app.init_resource::<ConfirmModal>();
+        app.add_systems(Update, handle_models_input);
+
+        press(&mut app, KeyCode::Char('/'));
+        for c in "vae".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        let state = app.world().resource::<ModelsState>();
+        assert_eq!(state.filter, "vae");
+        // 'v' went into the filter rather than hiding VAEs
+        assert!(state.hidden_types.is_empty());
+        assert_eq!(state.selected_model().unwrap().name, "SDXL VAE");
+
+        press(&mut app, KeyCode::Enter);
+        press(&mut app, KeyCode::Char('v'));
+        let state = app.world().resource::<ModelsState>();
+        assert!(!state.filter_editing);
+        assert_eq!(state.hidden_types, vec![ModelType::VAE]);
+        assert!(state.filtered_indices().is_empty());
+    }
+
+    #[test]
+    fn test_retry_sends_the_download_request() {
+        use crate::backend::RecordingBackend;
+        use crate::bevy_app::resources::models::ModelStatus;
+        use crate::messages::Request;
+
+        let failed_model = |app: &mut App| {
+            let mut state = ModelsState::default();
+            state.selected_index = state
+                .models
+                .iter()
+                .position(|m| m.status == ModelStatus::Available)
+                .unwrap();
+            let name = state.selected_model().unwrap().name.clone();
+            state.models[state.selected_index].status = ModelStatus::Failed;
+            app.insert_resource(state);
+            name
+        };
+
+        let backend = RecordingBackend::default();
+        let sent = backend.sent.clone();
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Models));
+        app.init_resource::<ConfirmModal>();
+        app.insert_resource(ZmqClientResource::new(backend));
+        app.add_systems(Update, handle_models_input);
+        let name = failed_model(&mut app);
+
+        press(&mut app, KeyCode::Char('r'));
+        assert_eq!(*sent.lock().unwrap(), vec![Request::DownloadModel { model: name }]);
+        let state = app.world().resource::<ModelsState>();
+        assert_eq!(state.selected_model().unwrap().status, ModelStatus::Downloading(0));
+
+        // Without a backend the download fails again
+        app.world_mut().remove_resource::<ZmqClientResource>();
+        failed_model(&mut app);
+        press(&mut app, KeyCode::Char('r'));
+        let state = app.world().resource::<ModelsState>();
+        assert_eq!(state.selected_model().unwrap().status, ModelStatus::Failed);
+    }
 }
@@ -49,12 +49,12 @@ mod tests {
         app.add_systems(Update, handle_monitor_input);
 
         // Send 'r' key
-        let key_event = KeyEvent {
+        let key_event = KeyEvent(crossterm::event::KeyEvent {
             code: KeyCode::Char('r'),
             modifiers: crossterm::event::KeyModifiers::empty(),
             kind: crossterm::event::KeyEventKind::Press,
             state: crossterm::event::KeyEventState::empty(),
-        };
+        });
         app.world_mut().send_event(key_event);
         app.update();
 
@@ -1,14 +1,15 @@
 //! # Queue Input Handler
 //!
-//! Handles keyboard input for the Queue screen, including job navigation and cancellation.
+//! Handles keyboard input for the Queue screen, including job navigation,
+//! reordering, priority and cancellation.
 
 use bevy::prelude::*;
 use bevy_ratatui::event::KeyEvent;
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyModifiers};
 
 use crate::bevy_app::components::Job;
 use crate::bevy_app::events::CancelJob;
-use crate::bevy_app::resources::{AppState, CurrentScreen, QueueState, Screen};
+use crate::bevy_app::resources::{AppState, CurrentScreen, JobTracker, QueueState, Screen};
 
 /// Handle input for Queue screen
 ///
@@ -16,21 +17,76 @@ use crate::bevy_app::resources::{AppState, CurrentScreen, QueueState, Screen};
 /// - Up/Down/k/j: Navigate job list
 /// - Home: Jump to first job
 /// - End: Jump to last job
-/// - c/C: Cancel selected job (if active)
+/// - Shift+Up/Shift+Down: Move selected locally queued job earlier/later
+/// - p/P: Cycle selected locally queued job's priority
+/// - c/x/Delete: Cancel selected job (if active)
 pub fn handle_queue_input(
     mut events: EventReader<KeyEvent>,
     current_screen: Res<CurrentScreen>,
     mut queue_state: ResMut<QueueState>,
     mut cancel_events: EventWriter<CancelJob>,
     mut app_state: ResMut<AppState>,
-    jobs: Query<&Job>,
+    mut job_tracker: Option<ResMut<JobTracker>>,
+    mut jobs: Query<&mut Job>,
 ) {
     if current_screen.0 != Screen::Queue {
         return;
     }
 
     for event in events.read() {
+        // Selected job ID, in the order the Queue screen shows jobs
+        let selected_id = || {
+            let mut all_jobs: Vec<&Job> = jobs.iter().collect();
+            match job_tracker.as_deref() {
+                Some(tracker) => tracker.sort_for_display(&mut all_jobs),
+                None => all_jobs.sort_by_key(|j| j.submitted_at),
+            }
+            all_jobs.get(queue_state.selected).map(|j| j.id.clone())
+        };
+        let shift = event.modifiers.contains(KeyModifiers::SHIFT);
+
         match event.code {
+            // Move selected job earlier in the send order
+            KeyCode::Up if shift => {
+                let moved = match (selected_id(), job_tracker.as_deref_mut()) {
+                    (Some(id), Some(tracker)) => tracker.move_up(&id),
+                    _ => false,
+                };
+                if moved {
+                    queue_state.select_previous();
+                    app_state.request_redraw();
+                }
+            }
+
+            // Move selected job later in the send order
+            KeyCode::Down if shift => {
+                let moved = match (selected_id(), job_tracker.as_deref_mut()) {
+                    (Some(id), Some(tracker)) => tracker.move_down(&id),
+                    _ => false,
+                };
+                if moved {
+                    queue_state.select_next();
+                    app_state.request_redraw();
+                }
+            }
+
+            // Cycle priority of the selected job
+            KeyCode::Char('p') | KeyCode::Char('P') => {
+                let (Some(id), Some(tracker)) = (selected_id(), job_tracker.as_deref_mut()) else {
+                    continue;
+                };
+                if let Some(mut job) = jobs.iter_mut().find(|j| j.id == id) {
+                    let priority = job.priority.cycle();
+                    if tracker.set_priority(&id, priority) {
+                        job.priority = priority;
+                        info!("Queue: Job {} priority set to {}", id, priority);
+                        app_state.request_redraw();
+                    } else {
+                        warn!("Queue: Job {} already sent, priority unchanged", id);
+                    }
+                }
+            }
+
             // Navigate up (previous job)
             KeyCode::Up | KeyCode::Char('k') => {
                 queue_state.select_previous();
@@ -59,14 +115,13 @@ pub fn handle_queue_input(
                 debug!("Queue: Jump to last job");
             }
 
-            // Cancel selected job
-            KeyCode::Char('c') | KeyCode::Char('C') => {
-                // Get all jobs sorted by submission time (matching render order)
-                let mut all_jobs: Vec<&Job> = jobs.iter().collect();
-                all_jobs.sort_by_key(|j| j.submitted_at);
-
-                // Get the selected job
-                if let Some(job) = all_jobs.get(queue_state.selected) {
+            // Cancel selected job (Ctrl+X dismisses notifications instead)
+            KeyCode::Char('c' | 'C' | 'x' | 'X') | KeyCode::Delete
+                if !event.modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                // Get the selected job (matching render order)
+                let selected = selected_id().and_then(|id| jobs.iter().find(|j| j.id == id));
+                if let Some(job) = selected {
                     // Only allow canceling active jobs
                     if job.is_active() {
                         cancel_events.send(CancelJob {
@@ -247,6 +302,34 @@ mod tests {
         assert_eq!(events[0].job_id, "job-001");
     }
 
+    #[test]
+    fn test_x_and_delete_cancel_but_not_ctrl_x() {
+        let mut app = App::new();
+        app.insert_resource(CurrentScreen(Screen::Queue));
+        let mut queue_state = QueueState::default();
+        queue_state.update_total(1);
+        app.insert_resource(queue_state);
+        app.insert_resource(AppState::default());
+        app.world_mut().spawn(Job::new("job-001".to_string(), "test prompt".to_string()));
+        app.add_event::<KeyEvent>();
+        app.add_event::<CancelJob>();
+        app.add_systems(Update, handle_queue_input);
+
+        let ctrl_x = KeyEvent(crossterm::event::KeyEvent::new(
+            KeyCode::Char('x'),
+            KeyModifiers::CONTROL,
+        ));
+        app.world_mut().send_event(create_key_event(KeyCode::Char('x')));
+        app.world_mut().send_event(create_key_event(KeyCode::Delete));
+        app.world_mut().send_event(ctrl_x);
+        app.update();
+
+        let cancel_events = app.world().resource::<Events<CancelJob>>();
+        let mut cursor = cancel_events.get_cursor();
+        let ids: Vec<_> = cursor.read(cancel_events).map(|e| e.job_id.as_str()).collect();
+        assert_eq!(ids, vec!["job-001", "job-001"]);
+    }
+
     #[test]
     fn test_no_cancel_for_completed_job() {
         let mut app = App::new();
@@ -283,6 +366,50 @@ mod tests {
         assert_eq!(reader.read(&cancel_events).count(), 0);
     }
 
+    #[test]
+    fn test_shift_up_moves_selected_job_earlier() {
+        let mut app = App::new();
+        app.insert_resource(CurrentScreen(Screen::Queue));
+        let mut queue_state = QueueState::default();
+        queue_state.update_total(3);
+        queue_state.selected = 2;
+        app.insert_resource(queue_state);
+        app.insert_resource(AppState::default());
+
+        let mut tracker = JobTracker::default();
+        for id in ["job-a", "job-b", "job-c"] {
+            let mut job = Job::new(id.to_string(), "test prompt".to_string());
+            job.status = JobStatus::QueuedLocally;
+            app.world_mut().spawn(job);
+            tracker.enqueue(id.to_string(), Default::default());
+        }
+        app.insert_resource(tracker);
+
+        app.add_event::<KeyEvent>();
+        app.add_event::<CancelJob>();
+        app.add_systems(Update, handle_queue_input);
+
+        let shift_up = KeyEvent(crossterm::event::KeyEvent::new(KeyCode::Up, KeyModifiers::SHIFT));
+        app.world_mut().send_event(shift_up.clone());
+        app.update();
+        assert_eq!(
+            app.world().resource::<JobTracker>().pending,
+            vec!["job-a", "job-c", "job-b"]
+        );
+        // Selection follows the moved job
+        assert_eq!(app.world().resource::<QueueState>().selected, 1);
+
+        // Already at the top: nothing moves
+        app.world_mut().resource_mut::<QueueState>().selected = 0;
+        app.world_mut().send_event(shift_up);
+        app.update();
+        assert_eq!(
+            app.world().resource::<JobTracker>().pending,
+            vec!["job-a", "job-c", "job-b"]
+        );
+        assert_eq!(app.world().resource::<QueueState>().selected, 0);
+    }
+
     #[test]
     fn test_no_input_on_other_screens() {
         let mut app = App::new();
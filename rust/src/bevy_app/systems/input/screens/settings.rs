@@ -1,28 +1,265 @@
+use bevy::ecs::event::EventCursor;
 use bevy::prelude::*;
 use bevy_ratatui::event::KeyEvent;
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyEventKind, KeyModifiers};
 
-use crate::bevy_app::resources::{AppState, CurrentScreen, Screen};
+use std::path::Path;
+
+use crate::bevy_app::resources::{
+    AppState, AppTheme, ConfirmAction, ConfirmModal, CurrentScreen, DirBrowser,
+    NotificationLevel, Notifications, Screen, SettingsState, ThemePicker,
+};
+use crate::bevy_app::systems::config_reload::ConfigWatcher;
+
+/// Handle the theme picker.
+///
+/// `t` opens the picker (outside the changes view, and unless a setting is
+/// being edited, where it's typed into the value), listing the built-in
+/// theme and the files in the themes directory. While it's open, Up/Down pick
+/// a theme, Enter applies it and records it as `ui.theme`, and Esc (or `t`
+/// again) closes it. Keys the picker handles are cleared so the other input
+/// systems never see them.
+pub fn handle_theme_picker_input(
+    mut key_events: ResMut<Events<KeyEvent>>,
+    mut key_cursor: Local<EventCursor<KeyEvent>>,
+    current_screen: Res<CurrentScreen>,
+    mut picker: ResMut<ThemePicker>,
+    mut theme: ResMut<AppTheme>,
+    mut settings: ResMut<SettingsState>,
+    mut app_state: ResMut<AppState>,
+) {
+    let keys: Vec<KeyEvent> = key_cursor.read(&key_events).cloned().collect();
+    if current_screen.0 != Screen::Settings {
+        if picker.open {
+            picker.close();
+        }
+        return;
+    }
+
+    let was_open = picker.open;
+    for event in keys {
+        if event.kind == KeyEventKind::Release {
+            continue;
+        }
+        let picker_key = matches!(event.code, KeyCode::Char('t') | KeyCode::Char('T'))
+            && !event.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+
+        if !picker.open {
+            if picker_key && !settings.show_changes && !settings.is_editing {
+                let current = settings.ui.theme.clone();
+                picker.open_in(&AppTheme::themes_dir(), &current);
+                app_state.request_redraw();
+            }
+            continue;
+        }
+
+        match event.code {
+            KeyCode::Esc => picker.close(),
+            _ if picker_key => picker.close(),
+            KeyCode::Enter => {
+                if let Some(name) = picker.selected_theme().map(str::to_string) {
+                    *theme = AppTheme::named(&name);
+                    if settings.ui.theme != name {
+                        settings.ui.theme = name;
+                        settings.dirty = true;
+                    }
+                    info!("Theme applied: {}", settings.ui.theme);
+                }
+                picker.close();
+            }
+            KeyCode::Up => picker.select_previous(),
+            KeyCode::Down | KeyCode::Tab => picker.select_next(),
+            _ => {}
+        }
+        app_state.request_redraw();
+    }
+
+    if was_open || picker.open {
+        key_events.clear();
+    }
+}
+
+/// Handle the directory browser.
+///
+/// Editing a path setting (output, cache or models directory) opens the
+/// browser at the value being edited. Up/Down pick a subdirectory, Enter
+/// lists it, Backspace goes up a level, `a` accepts the directory being
+/// listed as the setting's value and Esc cancels the edit. Keys are cleared
+/// while the browser is open so the other input systems never see them.
+pub fn handle_dir_browser_input(
+    mut key_events: ResMut<Events<KeyEvent>>,
+    mut key_cursor: Local<EventCursor<KeyEvent>>,
+    current_screen: Res<CurrentScreen>,
+    mut browser: ResMut<DirBrowser>,
+    mut settings: ResMut<SettingsState>,
+    mut app_state: ResMut<AppState>,
+) {
+    let keys: Vec<KeyEvent> = key_cursor.read(&key_events).cloned().collect();
+    let editing_path =
+        settings.is_editing && SettingsState::is_path_setting(settings.selected_index);
+    if current_screen.0 != Screen::Settings || !editing_path {
+        if browser.open {
+            browser.close();
+            app_state.request_redraw();
+        }
+        return;
+    }
+
+    if !browser.open {
+        browser.open_at(Path::new(settings.edit_buffer.trim()));
+        app_state.request_redraw();
+    }
+
+    for event in keys {
+        if event.kind == KeyEventKind::Release {
+            continue;
+        }
+        match event.code {
+            KeyCode::Up => browser.select_previous(),
+            KeyCode::Down | KeyCode::Tab => browser.select_next(),
+            KeyCode::Enter => browser.descend(),
+            KeyCode::Backspace => browser.ascend(),
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                settings.edit_buffer = browser.cwd.to_string_lossy().into_owned();
+                if let Err(e) = settings.finish_editing() {
+                    warn!("Failed to set directory: {:#}", e);
+                }
+                browser.close();
+            }
+            KeyCode::Esc => {
+                settings.cancel_editing();
+                browser.close();
+            }
+            _ => continue,
+        }
+        app_state.request_redraw();
+        if !browser.open {
+            break;
+        }
+    }
+
+    key_events.clear();
+}
 
 /// Handle input for Settings screen
 ///
-/// Currently read-only - settings are loaded from config file.
-/// Future enhancement: Add interactive editing with s/S to save.
+/// Up/Down select a setting and Enter edits it: type the new value, then
+/// Enter applies it and Esc cancels (path settings open the directory
+/// browser instead). Ctrl+D opens a view of the settings that differ from
+/// their defaults, where Up/Down select a row, 'r' resets it, 'a' resets
+/// every setting (after confirmation) and Esc (or Ctrl+D) closes the view.
+/// Ctrl+Z undoes the last edited value and Ctrl+Y redoes it. Ctrl+S saves the
+/// settings to the config file now, and Ctrl+R reloads them from it,
+/// discarding unsaved edits.
 pub fn handle_settings_input(
     mut events: EventReader<KeyEvent>,
     current_screen: Res<CurrentScreen>,
     mut app_state: ResMut<AppState>,
+    mut settings: ResMut<SettingsState>,
+    mut confirm: ResMut<ConfirmModal>,
+    mut watcher: Option<ResMut<ConfigWatcher>>,
+    mut notifications: Option<ResMut<Notifications>>,
 ) {
     if current_screen.0 != Screen::Settings {
         return;
     }
 
     for event in events.read() {
+        if event.code == KeyCode::Char('d') && event.modifiers.contains(KeyModifiers::CONTROL) {
+            settings.toggle_changes_view();
+            app_state.request_redraw();
+            continue;
+        }
+
+        let ctrl = event.modifiers.contains(KeyModifiers::CONTROL);
+        if ctrl && !settings.is_editing && matches!(event.code, KeyCode::Char('z' | 'y')) {
+            let result = if event.code == KeyCode::Char('z') {
+                settings.undo()
+            } else {
+                settings.redo()
+            };
+            if let Err(e) = result {
+                warn!("Failed to undo/redo setting: {:#}", e);
+            }
+            app_state.request_redraw();
+            continue;
+        }
+
+        if ctrl && !settings.is_editing && matches!(event.code, KeyCode::Char('s' | 'r')) {
+            let Some(watcher) = watcher.as_mut() else {
+                continue;
+            };
+            let (result, done) = if event.code == KeyCode::Char('s') {
+                (watcher.save(&settings), "Settings saved")
+            } else {
+                (watcher.reload(&mut settings), "Settings reloaded from config.toml")
+            };
+            let (level, message) = match result {
+                Ok(()) => {
+                    settings.dirty = false;
+                    (NotificationLevel::Info, done.to_string())
+                }
+                Err(e) => (NotificationLevel::Error, format!("{:#}", e)),
+            };
+            if let Some(notifications) = notifications.as_mut() {
+                notifications.notify(level, message);
+            }
+            app_state.request_redraw();
+            continue;
+        }
+
+        if !settings.show_changes {
+            if settings.is_editing {
+                edit_selected_setting(&mut settings, event);
+            } else {
+                match event.code {
+                    KeyCode::Up => settings.previous_setting(),
+                    KeyCode::Down => settings.next_setting(),
+                    KeyCode::Enter => settings.start_editing(),
+                    _ => continue,
+                }
+            }
+            app_state.request_redraw();
+            continue;
+        }
+
         match event.code {
-            // Settings screen is currently read-only
-            // Future: Add navigation (Up/Down), editing (Enter), save (s/S)
-            _ => {}
+            KeyCode::Up => settings.previous_change(),
+            KeyCode::Down => settings.next_change(),
+            KeyCode::Char('r') | KeyCode::Char('R') => {
+                if let Err(e) = settings.reset_selected_change() {
+                    warn!("Failed to reset setting: {:#}", e);
+                }
+            }
+            KeyCode::Char('a') | KeyCode::Char('A') => {
+                confirm.ask("Reset all settings to defaults?", ConfirmAction::ResetSettings);
+            }
+            KeyCode::Esc => settings.toggle_changes_view(),
+            _ => continue,
         }
+        app_state.request_redraw();
+    }
+}
+
+/// Apply a key to the value being edited: typing and Backspace change it,
+/// Enter applies it and Esc cancels the edit.
+fn edit_selected_setting(settings: &mut SettingsState, event: &KeyEvent) {
+    match event.code {
+        KeyCode::Enter => {
+            if let Err(e) = settings.finish_editing() {
+                warn!("Failed to set {}: {:#}", settings.selected_setting_name(), e);
+            }
+        }
+        KeyCode::Esc => settings.cancel_editing(),
+        KeyCode::Backspace => {
+            settings.edit_buffer.pop();
+        }
+        KeyCode::Char(c)
+            if !event.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+        {
+            settings.edit_buffer.push(c);
+        }
+        _ => {}
     }
 }
 
@@ -37,6 +274,235 @@ mod tests {
         app.add_event::<KeyEvent>();
         app.insert_resource(CurrentScreen(Screen::Settings));
         app.insert_resource(AppState::default());
+        app.insert_resource(SettingsState::default());
+        app.init_resource::<ConfirmModal>();
+        app.add_systems(Update, handle_settings_input);
+    }
+
+    #[test]
+    fn test_ctrl_z_undoes_setting_edit() {
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Settings));
+        app.insert_resource(AppState::default());
+        app.insert_resource(SettingsState::default());
+        app.init_resource::<ConfirmModal>();
         app.add_systems(Update, handle_settings_input);
+
+        {
+            let mut settings = app.world_mut().resource_mut::<SettingsState>();
+            settings.selected_index = 1; // default_steps
+            settings.start_editing();
+            settings.edit_buffer = "50".to_string();
+            settings.finish_editing().unwrap();
+        }
+
+        let ctrl = |c| {
+            KeyEvent(crossterm::event::KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL))
+        };
+        app.world_mut().send_event(ctrl('z'));
+        app.update();
+        assert_eq!(app.world().resource::<SettingsState>().generation.default_steps, 30);
+
+        app.world_mut().send_event(ctrl('y'));
+        app.update();
+        assert_eq!(app.world().resource::<SettingsState>().generation.default_steps, 50);
+    }
+
+    #[test]
+    fn test_ctrl_s_saves_through_watcher() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Settings));
+        app.insert_resource(AppState::default());
+        let mut settings = SettingsState::default();
+        settings.ui.fps_limit = 30;
+        settings.dirty = true;
+        app.insert_resource(settings);
+        app.init_resource::<ConfirmModal>();
+        app.insert_resource(ConfigWatcher {
+            external_change: true,
+            ..ConfigWatcher::new(&path)
+        });
+        app.add_systems(Update, handle_settings_input);
+
+        app.world_mut().send_event(KeyEvent(crossterm::event::KeyEvent::new(
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL,
+        )));
+        app.update();
+
+        assert!(!app.world().resource::<SettingsState>().dirty);
+        assert_eq!(SettingsState::load_from(&path).unwrap().ui.fps_limit, 30);
+        let watcher = app.world().resource::<ConfigWatcher>();
+        assert!(!watcher.external_change);
+        assert!(watcher.last_mtime.is_some());
+    }
+
+    #[test]
+    fn test_dir_browser_descends_and_accepts() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = std::fs::canonicalize(dir.path()).unwrap();
+        std::fs::create_dir_all(root.join("renders/pixel")).unwrap();
+        std::fs::create_dir(root.join("archive")).unwrap();
+
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Settings));
+        app.insert_resource(AppState::default());
+        app.insert_resource(SettingsState::default());
+        app.init_resource::<DirBrowser>();
+        app.add_systems(Update, handle_dir_browser_input);
+
+        {
+            let mut settings = app.world_mut().resource_mut::<SettingsState>();
+            settings.selected_index = 17; // output_dir
+            settings.start_editing();
+            settings.edit_buffer = root.to_string_lossy().into_owned();
+        }
+        app.update();
+        assert_eq!(app.world().resource::<DirBrowser>().entries, vec!["archive", "renders"]);
+
+        for code in [KeyCode::Down, KeyCode::Enter, KeyCode::Char('a')] {
+            app.world_mut()
+                .send_event(KeyEvent(crossterm::event::KeyEvent::new(code, KeyModifiers::NONE)));
+        }
+        app.update();
+
+        assert!(!app.world().resource::<DirBrowser>().open);
+        let settings = app.world().resource::<SettingsState>();
+        assert!(!settings.is_editing);
+        assert_eq!(settings.paths.output_dir, root.join("renders"));
+        assert_eq!(settings.undo_stack.len(), 1);
+        assert!(app.world().resource::<Events<KeyEvent>>().is_empty());
+    }
+
+    #[test]
+    fn test_enter_edits_the_selected_setting() {
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Settings));
+        app.insert_resource(AppState::default());
+        app.insert_resource(SettingsState::default());
+        app.init_resource::<ConfirmModal>();
+        app.add_systems(Update, handle_settings_input);
+
+        // default_steps (1): Enter, replace "30" with "45", Enter
+        let keys = [KeyCode::Down, KeyCode::Enter, KeyCode::Backspace, KeyCode::Backspace]
+            .into_iter()
+            .chain([KeyCode::Char('4'), KeyCode::Char('5'), KeyCode::Enter]);
+        for code in keys {
+            app.world_mut()
+                .send_event(KeyEvent(crossterm::event::KeyEvent::new(code, KeyModifiers::NONE)));
+        }
+        app.update();
+
+        let settings = app.world().resource::<SettingsState>();
+        assert_eq!(settings.selected_index, 1);
+        assert!(!settings.is_editing);
+        assert_eq!(settings.generation.default_steps, 45);
+        assert_eq!(settings.undo_stack.len(), 1);
+    }
+
+    #[test]
+    fn test_enter_on_a_path_setting_opens_the_browser() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = std::fs::canonicalize(dir.path()).unwrap();
+        std::fs::create_dir(root.join("renders")).unwrap();
+
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Settings));
+        app.insert_resource(AppState::default());
+        let mut settings = SettingsState {
+            selected_index: 17, // output_dir
+            ..Default::default()
+        };
+        settings.paths.output_dir = root.clone();
+        app.insert_resource(settings);
+        app.init_resource::<ConfirmModal>();
+        app.init_resource::<DirBrowser>();
+        app.add_systems(
+            Update,
+            (handle_dir_browser_input, handle_settings_input.after(handle_dir_browser_input)),
+        );
+
+        app.world_mut().send_event(KeyEvent(crossterm::event::KeyEvent::new(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+        )));
+        app.update();
+        assert!(app.world().resource::<SettingsState>().is_editing);
+        app.update();
+
+        let browser = app.world().resource::<DirBrowser>();
+        assert!(browser.open);
+        assert_eq!(browser.cwd, root);
+        assert_eq!(browser.entries, vec!["renders"]);
+    }
+
+    #[test]
+    fn test_theme_picker_applies_selected_theme() {
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Settings));
+        app.insert_resource(AppState::default());
+        app.insert_resource(SettingsState::default());
+        app.insert_resource(AppTheme::default());
+        app.init_resource::<ThemePicker>();
+        app.add_systems(Update, handle_theme_picker_input);
+
+        // A picker listing only the built-in theme, with a changed theme applied
+        app.world_mut().resource_mut::<ThemePicker>().themes = vec!["Default".to_string()];
+        app.world_mut().resource_mut::<ThemePicker>().open = true;
+        app.world_mut().resource_mut::<AppTheme>().colors.header = ratatui::style::Color::Red;
+        app.world_mut().resource_mut::<SettingsState>().ui.theme = "dusk".to_string();
+        app.world_mut().send_event(KeyEvent(crossterm::event::KeyEvent::new(
+            KeyCode::Enter,
+            KeyModifiers::NONE,
+        )));
+        app.update();
+
+        assert!(!app.world().resource::<ThemePicker>().open);
+        let theme = app.world().resource::<AppTheme>();
+        assert_eq!(theme.colors.header, ratatui::style::Color::Cyan);
+        let settings = app.world().resource::<SettingsState>();
+        assert_eq!(settings.ui.theme, "Default");
+        assert!(settings.dirty);
+        assert!(app.world().resource::<Events<KeyEvent>>().is_empty());
+    }
+
+    #[test]
+    fn test_t_is_typed_into_a_setting_being_edited() {
+        let mut app = App::new();
+        app.add_event::<KeyEvent>();
+        app.insert_resource(CurrentScreen(Screen::Settings));
+        app.insert_resource(AppState::default());
+        app.insert_resource(SettingsState {
+            selected_index: 13, // zmq_host
+            ..Default::default()
+        });
+        app.insert_resource(AppTheme::default());
+        app.init_resource::<ThemePicker>();
+        app.init_resource::<ConfirmModal>();
+        app.add_systems(
+            Update,
+            (handle_theme_picker_input, handle_settings_input.after(handle_theme_picker_input)),
+        );
+
+        app.world_mut().resource_mut::<SettingsState>().start_editing();
+        app.world_mut().resource_mut::<SettingsState>().edit_buffer.clear();
+        app.world_mut().send_event(KeyEvent(crossterm::event::KeyEvent::new(
+            KeyCode::Char('t'),
+            KeyModifiers::NONE,
+        )));
+        app.update();
+
+        assert!(!app.world().resource::<ThemePicker>().open);
+        let settings = app.world().resource::<SettingsState>();
+        assert!(settings.is_editing);
+        assert_eq!(settings.edit_buffer, "t");
     }
 }
@@ -5,16 +5,133 @@
 //! Supports:
 //! - Character input
 //! - Backspace/Delete
-//! - Cursor movement (Left/Right/Home/End)
+//! - Cursor movement (Left/Right/Up/Down/Home/End, Ctrl+Left/Right by word)
+//! - Line breaks for multi-line prompts (Ctrl+Enter or Alt+Enter)
 //! - Word deletion (Ctrl+W)
-//! - Clear to start (Ctrl+U)
+//! - Clear to start of line (Ctrl+U)
+//! - Negative prompt field (Ctrl+N switches focus)
+//! - Bracketed paste, optionally submitting the pasted prompt
+//!   (`ui.generate_on_paste`); on other screens pastes go to the text field
+//!   being edited
 
-use bevy::prelude::{info, EventReader, Res, ResMut};
-use bevy_ratatui::event::KeyEvent;
-use crossterm::event::{KeyCode, KeyModifiers};
+use bevy::prelude::{info, warn, Commands, EventReader, EventWriter, Res, ResMut, Resource};
+use bevy_ratatui::event::{KeyEvent, PasteEvent};
+use crossterm::event::{DisableBracketedPaste, EnableBracketedPaste, KeyCode, KeyModifiers};
+use crossterm::ExecutableCommand;
 
+use crate::bevy_app::events::SubmitGenerationJob;
 use crate::bevy_app::resources::*;
 
+/// Marks bracketed paste as enabled; disables it again when dropped.
+#[derive(Resource, Default)]
+pub struct BracketedPasteEnabled;
+
+impl Drop for BracketedPasteEnabled {
+    fn drop(&mut self) {
+        let _ = std::io::stdout().execute(DisableBracketedPaste);
+    }
+}
+
+/// Startup system: ask the terminal to report pastes as a single event.
+///
+/// Without this a paste arrives as ordinary key presses and can't be told
+/// apart from typing.
+pub fn enable_bracketed_paste(mut commands: Commands) {
+    match std::io::stdout().execute(EnableBracketedPaste) {
+        Ok(_) => commands.insert_resource(BracketedPasteEnabled),
+        Err(e) => warn!("Failed to enable bracketed paste: {}", e),
+    }
+}
+
+/// Insert pasted text into the text field being edited.
+///
+/// On the Generation screen that's the prompt (or the sweep dialog's base
+/// seed while it's open); with `ui.generate_on_paste` on, a pasted prompt is
+/// submitted straight away. Only paste events do this; typed input never
+/// submits. On other screens the paste goes to whichever field is taking
+/// typed input, with line breaks turned into spaces, and is dropped when no
+/// field is.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_paste_input(
+    mut events: EventReader<PasteEvent>,
+    current_screen: Res<CurrentScreen>,
+    mut settings: ResMut<SettingsState>,
+    mut input_buffer: ResMut<InputBuffer>,
+    mut submit_events: EventWriter<SubmitGenerationJob>,
+    mut app_state: ResMut<AppState>,
+    gen_params: Option<Res<GenParams>>,
+    mut sweep: Option<ResMut<SeedSweep>>,
+    mut comparison: Option<ResMut<ComparisonState>>,
+    mut gallery: Option<ResMut<GalleryState>>,
+    mut models: Option<ResMut<ModelsState>>,
+    mut help: Option<ResMut<HelpState>>,
+) {
+    for event in events.read() {
+        let text = event.0.replace("\r\n", "\n").replace('\r', "\n");
+
+        if current_screen.0 != Screen::Generation {
+            let line: String = text
+                .chars()
+                .map(|c| if c == '\n' { ' ' } else { c })
+                .filter(|c| !c.is_control())
+                .collect();
+            let chars = line.chars();
+            match current_screen.0 {
+                Screen::Comparison => match comparison.as_deref_mut() {
+                    Some(c) if c.editing_prompt => c.prompt_edit_buffer.extend(chars),
+                    _ => continue,
+                },
+                Screen::Gallery => match gallery.as_deref_mut() {
+                    Some(GalleryState { metadata: Some(form), .. }) => {
+                        chars.for_each(|c| form.push_char(c))
+                    }
+                    Some(GalleryState { tag_entry: Some(entry), .. }) => entry.text.extend(chars),
+                    _ => continue,
+                },
+                Screen::Settings if settings.is_editing => settings.edit_buffer.extend(chars),
+                Screen::Models => match models.as_deref_mut() {
+                    Some(m) if m.filter_editing => chars.for_each(|c| m.push_filter_char(c)),
+                    _ => continue,
+                },
+                Screen::Help => match help.as_deref_mut() {
+                    Some(h) if h.searching => chars.for_each(|c| h.push_query(c)),
+                    _ => continue,
+                },
+                _ => continue,
+            }
+            app_state.request_redraw();
+            continue;
+        }
+
+        if let Some(sweep) = sweep.as_deref_mut().filter(|s| s.open) {
+            if sweep.field == SweepField::BaseSeed {
+                text.chars().for_each(|c| sweep.push_digit(c));
+                app_state.request_redraw();
+            }
+            continue;
+        }
+
+        input_buffer.insert_str(&text);
+        app_state.request_redraw();
+
+        // Pasting into the negative prompt never submits
+        let submit = settings.ui.generate_on_paste && !input_buffer.negative_focused;
+        if submit && !input_buffer.text.trim().is_empty() {
+            submit_events.send(SubmitGenerationJob {
+                prompt: input_buffer.text.clone(),
+                negative_prompt: input_buffer.negative_prompt(),
+                batch_size: None,
+                base_seed: None,
+                steps: gen_params.as_ref().and_then(|p| p.steps),
+                cfg_scale: gen_params.as_ref().and_then(|p| p.cfg_scale),
+                sweep: false,
+            });
+            input_buffer.clear();
+            info!("Generation job submitted on paste");
+        }
+    }
+}
+
 /// Handle text input for prompt entry.
 pub fn handle_text_input(
     mut events: EventReader<KeyEvent>,
@@ -34,13 +151,19 @@ pub fn handle_text_input(
             // Character input
             KeyCode::Char('w') | KeyCode::Char('W') if modifiers.contains(KeyModifiers::CONTROL) => {
                 // Ctrl+W: Delete word before cursor
-                input_buffer.delete_word();
+                input_buffer.delete_word_back();
+                app_state.request_redraw();
+            }
+
+            KeyCode::Char('n') | KeyCode::Char('N') if modifiers.contains(KeyModifiers::CONTROL) => {
+                // Ctrl+N: switch between prompt and negative prompt
+                input_buffer.toggle_negative_focus();
                 app_state.request_redraw();
             }
 
             KeyCode::Char('u') | KeyCode::Char('U') if modifiers.contains(KeyModifiers::CONTROL) => {
-                // Ctrl+U: Clear all text before cursor
-                input_buffer.delete_to_start();
+                // Ctrl+U: Clear the line up to the cursor
+                input_buffer.clear_to_start();
                 app_state.request_redraw();
             }
 
@@ -62,6 +185,16 @@ pub fn handle_text_input(
             }
 
             // Cursor movement
+            KeyCode::Left if modifiers.contains(KeyModifiers::CONTROL) => {
+                input_buffer.move_word_left();
+                app_state.request_redraw();
+            }
+
+            KeyCode::Right if modifiers.contains(KeyModifiers::CONTROL) => {
+                input_buffer.move_word_right();
+                app_state.request_redraw();
+            }
+
             KeyCode::Left => {
                 input_buffer.move_left();
                 app_state.request_redraw();
@@ -82,6 +215,35 @@ pub fn handle_text_input(
                 app_state.request_redraw();
             }
 
+            // Empty prompt (or one recalled from history): step through history
+            KeyCode::Up if input_buffer.can_browse_history() => {
+                input_buffer.history_prev();
+                app_state.request_redraw();
+            }
+
+            KeyCode::Down if input_buffer.history_index.is_some() => {
+                input_buffer.history_next();
+                app_state.request_redraw();
+            }
+
+            KeyCode::Up => {
+                input_buffer.move_up();
+                app_state.request_redraw();
+            }
+
+            KeyCode::Down => {
+                input_buffer.move_down();
+                app_state.request_redraw();
+            }
+
+            // Ctrl+Enter: line break (Alt+Enter for terminals that can't report Ctrl+Enter)
+            KeyCode::Enter
+                if modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                input_buffer.insert_newline();
+                app_state.request_redraw();
+            }
+
             // Submit (handled by WS-08 event system)
             KeyCode::Enter => {
                 // TODO: WS-08 will handle job submission
@@ -96,3 +258,70 @@ pub fn handle_text_input(
 // Note: Unit tests for input systems require bevy_ratatui message system
 // which isn't easily mockable. Integration tests in tests/input_system.rs
 // provide coverage for these systems.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::prelude::{App, Events, Update};
+
+    fn paste(generate_on_paste: bool, text: &str) -> (String, Vec<String>) {
+        let mut settings = SettingsState::default();
+        settings.ui.generate_on_paste = generate_on_paste;
+
+        let mut app = App::new();
+        app.add_event::<PasteEvent>()
+            .add_event::<SubmitGenerationJob>()
+            .insert_resource(settings)
+            .insert_resource(CurrentScreen(Screen::Generation))
+            .init_resource::<InputBuffer>()
+            .init_resource::<AppState>()
+            .add_systems(Update, handle_paste_input);
+
+        app.world_mut().send_event(PasteEvent(text.to_string()));
+        app.update();
+
+        let submitted = app
+            .world_mut()
+            .resource_mut::<Events<SubmitGenerationJob>>()
+            .drain()
+            .map(|e| e.prompt)
+            .collect();
+        (app.world().resource::<InputBuffer>().text.clone(), submitted)
+    }
+
+    #[test]
+    fn test_paste_submits_only_with_generate_on_paste() {
+        let (buffer, submitted) = paste(true, "pixel art knight");
+        assert_eq!(buffer, "");
+        assert_eq!(submitted, vec!["pixel art knight"]);
+
+        let (buffer, submitted) = paste(false, "pixel art knight\r\nwith sword");
+        assert_eq!(buffer, "pixel art knight\nwith sword");
+        assert!(submitted.is_empty());
+    }
+
+    #[test]
+    fn test_paste_goes_to_the_active_field_off_generation() {
+        let mut app = App::new();
+        app.add_event::<PasteEvent>()
+            .add_event::<SubmitGenerationJob>()
+            .init_resource::<SettingsState>()
+            .insert_resource(CurrentScreen(Screen::Help))
+            .init_resource::<InputBuffer>()
+            .init_resource::<AppState>()
+            .insert_resource(HelpState { searching: true, ..Default::default() })
+            .add_systems(Update, handle_paste_input);
+
+        app.world_mut().send_event(PasteEvent("undo\nredo".to_string()));
+        app.update();
+        assert_eq!(app.world().resource::<HelpState>().query, "undo redo");
+        assert_eq!(app.world().resource::<InputBuffer>().text, "");
+
+        // Not searching: the paste is dropped rather than typed anywhere
+        app.world_mut().resource_mut::<HelpState>().searching = false;
+        app.world_mut().send_event(PasteEvent("more".to_string()));
+        app.update();
+        assert_eq!(app.world().resource::<HelpState>().query, "undo redo");
+        assert_eq!(app.world().resource::<InputBuffer>().text, "");
+    }
+}
@@ -0,0 +1,90 @@
+//! # Job Cleanup System
+//!
+//! Removes finished jobs from the queue once they have been done for
+//! `ui.auto_clear_completed_secs`. Removed jobs are kept in [`JobHistory`].
+
+use bevy::prelude::*;
+use std::time::{Duration, Instant};
+
+use crate::bevy_app::components::Job;
+use crate::bevy_app::resources::{JobHistory, SettingsState};
+
+/// How often finished jobs are checked.
+pub const CLEANUP_INTERVAL_SECS: u64 = 1;
+
+/// Despawn jobs finished for longer than the auto-clear threshold.
+///
+/// Active jobs are never touched.
+pub fn auto_clear_completed_jobs(
+    mut commands: Commands,
+    jobs: Query<(Entity, &Job)>,
+    settings: Res<SettingsState>,
+    mut history: ResMut<JobHistory>,
+) {
+    let Some(secs) = settings.ui.auto_clear_completed_secs else {
+        return;
+    };
+    let threshold = Duration::from_secs(secs as u64);
+    let now = Instant::now();
+
+    for (entity, job) in jobs.iter() {
+        let Some(completed_at) = job.completed_at.filter(|_| !job.is_active()) else {
+            continue;
+        };
+        if now.duration_since(completed_at) >= threshold {
+            debug!("Auto-clearing finished job {}", job.id);
+            history.entries.push(job.into());
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bevy_app::components::JobStatus;
+    use std::path::PathBuf;
+
+    fn finished_job(id: &str, finished_ago: Duration) -> Job {
+        let mut job = Job::new(id.to_string(), "knight".to_string());
+        job.status = JobStatus::Complete {
+            image_path: PathBuf::from("out.png"),
+            duration_s: 1.0,
+        };
+        job.completed_at = Instant::now().checked_sub(finished_ago);
+        job
+    }
+
+    #[test]
+    fn test_old_finished_jobs_are_cleared() {
+        let mut app = App::new();
+        let mut settings = SettingsState::default();
+        settings.ui.auto_clear_completed_secs = Some(60);
+        app.insert_resource(settings);
+        app.init_resource::<JobHistory>();
+        app.add_systems(Update, auto_clear_completed_jobs);
+
+        let old = app
+            .world_mut()
+            .spawn(finished_job("old", Duration::from_secs(120)))
+            .id();
+        let recent = app
+            .world_mut()
+            .spawn(finished_job("recent", Duration::from_secs(5)))
+            .id();
+        let active = app
+            .world_mut()
+            .spawn(Job::new("active".to_string(), "mage".to_string()))
+            .id();
+
+        app.update();
+
+        assert!(app.world().get_entity(old).is_err());
+        assert!(app.world().get_entity(recent).is_ok());
+        assert!(app.world().get_entity(active).is_ok());
+
+        let history = app.world().resource::<JobHistory>();
+        assert_eq!(history.entries.len(), 1);
+        assert_eq!(history.entries[0].job_id, "old");
+    }
+}
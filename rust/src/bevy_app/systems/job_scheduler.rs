@@ -0,0 +1,451 @@
+//! # Job Scheduler System
+//!
+//! Limits how many jobs are sent to the backend at once. Submitted jobs start
+//! as [`JobStatus::QueuedLocally`]; this system sends the next of them (in
+//! the [`JobTracker`] send order: highest priority first, then as arranged
+//! on the Queue screen) whenever fewer than `backend.max_concurrent_jobs`
+//! are in flight, so a large batch doesn't overwhelm a single-GPU backend.
+//! Jobs wait in the local queue while no backend is connected; a job the
+//! backend can't be sent fails rather than holding a slot.
+
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::time::Instant;
+
+use crate::bevy_app::components::{Job, JobStatus};
+use crate::bevy_app::resources::{AppState, JobTracker, SettingsState};
+use crate::bevy_app::systems::job_timeout::JobClock;
+use crate::bevy_app::systems::zmq::ZmqClientResource;
+use crate::generation_request::GenerationRequestBuilder;
+use crate::metadata::GenerationMetadata;
+
+/// Send locally queued jobs to the backend while there are free slots.
+///
+/// Slots free up as in-flight jobs complete, fail or are cancelled.
+pub fn dispatch_queued_jobs(
+    mut jobs: Query<&mut Job>,
+    settings: Res<SettingsState>,
+    zmq_client: Option<Res<ZmqClientResource>>,
+    mut job_tracker: ResMut<JobTracker>,
+    mut app_state: ResMut<AppState>,
+    clock: Option<Res<JobClock>>,
+    mut warned_no_backend: Local<bool>,
+) {
+    // Forget jobs that left the local queue some other way (e.g. cancelled)
+    let stale: Vec<String> = job_tracker
+        .pending
+        .iter()
+        .filter(|id| {
+            !jobs
+                .iter()
+                .any(|job| &job.id == *id && matches!(job.status, JobStatus::QueuedLocally))
+        })
+        .cloned()
+        .collect();
+    for id in stale {
+        job_tracker.dequeue(&id);
+    }
+
+    let Some(zmq_client) = zmq_client else {
+        if !job_tracker.pending.is_empty() && !*warned_no_backend {
+            warn!("No backend connected - jobs stay queued until one is");
+            *warned_no_backend = true;
+        }
+        return;
+    };
+
+    let limit = settings.backend.max_concurrent_jobs.max(1) as usize;
+    let in_flight = jobs.iter().filter(|job| job.is_in_flight()).count();
+    if in_flight >= limit {
+        return;
+    }
+
+    let mut queued: Vec<_> = jobs
+        .iter_mut()
+        .filter(|job| matches!(job.status, JobStatus::QueuedLocally))
+        .collect();
+    queued.sort_by_key(|job| {
+        (
+            job_tracker.pending_position(&job.id).unwrap_or(usize::MAX),
+            Reverse(job.priority),
+            job.submitted_at,
+        )
+    });
+
+    let defaults = settings.generation_defaults();
+    let now = clock.as_ref().map_or_else(Instant::now, |clock| clock.now());
+    let client = zmq_client.0.lock();
+    for mut job in queued.into_iter().take(limit - in_flight) {
+        let mut builder = GenerationRequestBuilder::new(job.prompt.clone())
+            .id(job.id.clone())
+            .model_stack(job.model.as_deref(), &job.loras);
+        if let Some(negative) = &job.negative_prompt {
+            builder = builder.negative_prompt(negative.clone());
+        }
+        if let Some(seed) = job.seed {
+            builder = builder.seed(seed);
+        }
+        if let Some(steps) = job.steps {
+            builder = builder.steps(steps);
+        }
+        if let Some(cfg_scale) = job.cfg_scale {
+            builder = builder.cfg_scale(cfg_scale);
+        }
+        let request = match builder.build(&defaults) {
+            Ok(request) => request,
+            Err(e) => {
+                warn!("Job {} rejected: {:#}", job.id, e);
+                let status = JobStatus::Failed {
+                    error: format!("{:#}", e),
+                };
+                job.finish(status, now);
+                job_tracker.dequeue(&job.id);
+                app_state.request_redraw();
+                continue;
+            }
+        };
+
+        let mut metadata = GenerationMetadata::from_request(&request);
+        let sweep_id = job.batch.as_ref().filter(|b| b.sweep).map(|b| b.id.clone());
+        if let (Some(metadata), Some(sweep_id)) = (metadata.as_mut(), sweep_id) {
+            // Tagged too, so the gallery's tag filter shows the sweep as a set
+            metadata.tags.push(sweep_id.clone());
+            metadata.sweep_id = Some(sweep_id);
+        }
+        job.metadata = metadata;
+        job_tracker.dequeue(&job.id);
+        match client.send_request(request) {
+            Ok(()) => {
+                info!("Job {} sent to backend", job.id);
+                job.status = JobStatus::Pending;
+                job.sent_at = Some(now);
+            }
+            Err(e) => {
+                error!("Failed to send job {} to backend: {:#}", job.id, e);
+                let status = JobStatus::Failed {
+                    error: format!("Failed to send to backend: {:#}", e),
+                };
+                job.finish(status, now);
+            }
+        }
+        app_state.request_redraw();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bevy_app::events::{
+        handle_generation_events, CancelJob, GenerationComplete, SubmitGenerationJob,
+    };
+    use crate::backend::RecordingBackend;
+    use crate::bevy_app::components::Priority;
+    use std::path::PathBuf;
+
+    fn submit(app: &mut App, prompt: &str) {
+        app.world_mut().send_event(SubmitGenerationJob {
+            prompt: prompt.to_string(),
+            negative_prompt: None,
+            batch_size: None,
+            base_seed: None,
+            steps: None,
+            cfg_scale: None,
+            sweep: false,
+        });
+    }
+
+    fn scheduler_app(backend: Option<RecordingBackend>) -> App {
+        let mut settings = SettingsState::default();
+        settings.backend.max_concurrent_jobs = 1;
+
+        let mut app = App::new();
+        app.add_event::<SubmitGenerationJob>()
+            .add_event::<GenerationComplete>()
+            .add_event::<CancelJob>()
+            .insert_resource(settings)
+            .insert_resource(JobTracker::default())
+            .insert_resource(AppState::default())
+            .add_systems(
+                Update,
+                (handle_generation_events, dispatch_queued_jobs).chain(),
+            );
+        if let Some(backend) = backend {
+            app.insert_resource(ZmqClientResource::new(backend));
+        }
+        app
+    }
+
+    fn status_counts(app: &mut App) -> (usize, usize) {
+        let mut query = app.world_mut().query::<&Job>();
+        let jobs: Vec<&Job> = query.iter(app.world()).collect();
+        let sent = jobs.iter().filter(|j| j.is_in_flight()).count();
+        let held = jobs
+            .iter()
+            .filter(|j| matches!(j.status, JobStatus::QueuedLocally))
+            .count();
+        (sent, held)
+    }
+
+    #[test]
+    fn test_limit_holds_extra_jobs_until_slots_free() {
+        let mut settings = SettingsState::default();
+        settings.backend.max_concurrent_jobs = 2;
+
+        let mut app = App::new();
+        app.add_event::<SubmitGenerationJob>()
+            .add_event::<GenerationComplete>()
+            .add_event::<CancelJob>()
+            .insert_resource(settings)
+            .insert_resource(JobTracker::default())
+            .insert_resource(AppState::default())
+            .insert_resource(ZmqClientResource::new(RecordingBackend::default()))
+            .add_systems(
+                Update,
+                (handle_generation_events, dispatch_queued_jobs).chain(),
+            );
+
+        for i in 0..5 {
+            app.world_mut().send_event(SubmitGenerationJob {
+                prompt: format!("sprite {}", i),
+                negative_prompt: None,
+                batch_size: None,
+                base_seed: None,
+                steps: None,
+                cfg_scale: None,
+                sweep: false,
+            });
+        }
+        app.update();
+        assert_eq!(status_counts(&mut app), (2, 3));
+
+        // Finish one sent job: the next queued job takes its slot
+        let mut query = app.world_mut().query::<&mut Job>();
+        let mut job = query
+            .iter_mut(app.world_mut())
+            .find(|j| j.is_in_flight())
+            .unwrap();
+        job.status = JobStatus::Complete {
+            image_path: PathBuf::from("out.png"),
+            duration_s: 1.0,
+        };
+        app.update();
+        assert_eq!(status_counts(&mut app), (2, 2));
+
+        // Fail another: one more is promoted
+        let mut query = app.world_mut().query::<&mut Job>();
+        let mut job = query
+            .iter_mut(app.world_mut())
+            .find(|j| j.is_in_flight())
+            .unwrap();
+        job.status = JobStatus::Failed {
+            error: "out of memory".to_string(),
+        };
+        app.update();
+        assert_eq!(status_counts(&mut app), (2, 1));
+    }
+
+    #[test]
+    fn test_random_seed_is_recorded_with_the_job() {
+        let mut app = App::new();
+        app.add_event::<SubmitGenerationJob>()
+            .add_event::<GenerationComplete>()
+            .add_event::<CancelJob>()
+            .insert_resource(SettingsState::default())
+            .insert_resource(JobTracker::default())
+            .insert_resource(AppState::default())
+            .insert_resource(ZmqClientResource::new(RecordingBackend::default()))
+            .add_systems(
+                Update,
+                (handle_generation_events, dispatch_queued_jobs).chain(),
+            );
+
+        app.world_mut().send_event(SubmitGenerationJob {
+            prompt: "sprite".to_string(),
+            negative_prompt: None,
+            batch_size: None,
+            base_seed: None,
+            steps: None,
+            cfg_scale: None,
+            sweep: false,
+        });
+        app.update();
+
+        let mut query = app.world_mut().query::<&Job>();
+        let job = query.single(app.world());
+        let seed = job.seed.expect("random seed materialized at submission");
+        assert_eq!(job.metadata.as_ref().and_then(|m| m.seed), Some(seed));
+        assert_eq!(app.world().resource::<AppState>().last_seed, Some(seed));
+    }
+
+    #[test]
+    fn test_seed_sweep_jobs_share_sweep_id() {
+        let mut settings = SettingsState::default();
+        settings.backend.max_concurrent_jobs = 8;
+
+        let mut app = App::new();
+        app.add_event::<SubmitGenerationJob>()
+            .add_event::<GenerationComplete>()
+            .add_event::<CancelJob>()
+            .insert_resource(settings)
+            .insert_resource(JobTracker::default())
+            .insert_resource(AppState::default())
+            .insert_resource(ZmqClientResource::new(RecordingBackend::default()))
+            .add_systems(
+                Update,
+                (handle_generation_events, dispatch_queued_jobs).chain(),
+            );
+
+        app.world_mut().send_event(SubmitGenerationJob {
+            prompt: "sprite".to_string(),
+            negative_prompt: None,
+            batch_size: Some(5),
+            base_seed: Some(40),
+            steps: None,
+            cfg_scale: None,
+            sweep: true,
+        });
+        app.update();
+
+        let mut query = app.world_mut().query::<&Job>();
+        let mut jobs: Vec<&Job> = query.iter(app.world()).collect();
+        jobs.sort_by_key(|job| job.seed);
+        let seeds: Vec<u64> = jobs.iter().map(|job| job.seed.unwrap()).collect();
+        assert_eq!(seeds, vec![40, 41, 42, 43, 44]);
+
+        let sweep_id = jobs[0].batch.as_ref().unwrap().id.clone();
+        assert!(sweep_id.starts_with("sweep-"));
+        for job in &jobs {
+            assert!(job.batch.as_ref().unwrap().sweep);
+            let metadata = job.metadata.as_ref().unwrap();
+            assert_eq!(metadata.sweep_id.as_ref(), Some(&sweep_id));
+            assert_eq!(metadata.tags, vec![sweep_id.clone()]);
+        }
+    }
+
+    #[test]
+    fn test_high_priority_jobs_are_sent_first() {
+        let mut settings = SettingsState::default();
+        settings.backend.max_concurrent_jobs = 1;
+
+        let mut app = App::new();
+        app.add_event::<SubmitGenerationJob>()
+            .add_event::<GenerationComplete>()
+            .add_event::<CancelJob>()
+            .insert_resource(settings)
+            .insert_resource(JobTracker::default())
+            .insert_resource(AppState::default())
+            .insert_resource(ZmqClientResource::new(RecordingBackend::default()))
+            .add_systems(Update, handle_generation_events);
+
+        for prompt in ["first", "second", "urgent"] {
+            app.world_mut().send_event(SubmitGenerationJob {
+                prompt: prompt.to_string(),
+                negative_prompt: None,
+                batch_size: None,
+                base_seed: None,
+                steps: None,
+                cfg_scale: None,
+                sweep: false,
+            });
+        }
+        app.update();
+
+        let mut query = app.world_mut().query::<&mut Job>();
+        let mut urgent = query
+            .iter_mut(app.world_mut())
+            .find(|j| j.prompt == "urgent")
+            .unwrap();
+        urgent.priority = Priority::High;
+        let urgent_id = urgent.id.clone();
+        app.world_mut()
+            .resource_mut::<JobTracker>()
+            .set_priority(&urgent_id, Priority::High);
+
+        app.add_systems(Update, dispatch_queued_jobs);
+        app.update();
+
+        let mut query = app.world_mut().query::<&Job>();
+        let sent: Vec<&str> = query
+            .iter(app.world())
+            .filter(|j| j.is_in_flight())
+            .map(|j| j.prompt.as_str())
+            .collect();
+        assert_eq!(sent, vec!["urgent"]);
+        assert!(app
+            .world()
+            .resource::<JobTracker>()
+            .pending_position(&urgent_id)
+            .is_none());
+    }
+
+    #[test]
+    fn test_failed_send_fails_job_and_frees_slot() {
+        let mut app = scheduler_app(Some(RecordingBackend {
+            fail_sends: true,
+            ..Default::default()
+        }));
+        submit(&mut app, "first");
+        submit(&mut app, "second");
+        app.update();
+
+        let mut query = app.world_mut().query::<&Job>();
+        let first = query.iter(app.world()).find(|j| j.prompt == "first").unwrap();
+        let JobStatus::Failed { error } = &first.status else {
+            panic!("expected the job to fail, got {:?}", first.status);
+        };
+        assert!(error.contains("unreachable"));
+        assert!(first.sent_at.is_none());
+
+        // The failed job doesn't hold the only slot
+        app.update();
+        let mut query = app.world_mut().query::<&Job>();
+        assert!(query
+            .iter(app.world())
+            .all(|j| matches!(j.status, JobStatus::Failed { .. })));
+        assert!(app.world().resource::<JobTracker>().pending.is_empty());
+    }
+
+    #[test]
+    fn test_jobs_wait_for_a_backend() {
+        let mut app = scheduler_app(None);
+        submit(&mut app, "sprite");
+        app.update();
+        app.update();
+        assert_eq!(status_counts(&mut app), (0, 1));
+
+        let backend = RecordingBackend::default();
+        let sent = std::sync::Arc::clone(&backend.sent);
+        app.insert_resource(ZmqClientResource::new(backend));
+        app.update();
+        assert_eq!(status_counts(&mut app), (1, 0));
+        assert_eq!(sent.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_jobs_are_sent_with_the_active_model_stack() {
+        use crate::bevy_app::resources::ModelsState;
+        use crate::messages::Request;
+
+        let backend = RecordingBackend::default();
+        let sent = std::sync::Arc::clone(&backend.sent);
+        let mut app = scheduler_app(Some(backend));
+        app.insert_resource(ModelsState {
+            active_base: Some("SDXL Base 1.0".to_string()),
+            active_loras: vec![
+                ("Pixel Art LoRA v1".to_string(), 0.8),
+                ("Retro Pixel LoRA".to_string(), 1.2),
+            ],
+            ..Default::default()
+        });
+        submit(&mut app, "sprite");
+        app.update();
+
+        let sent = sent.lock().unwrap();
+        let Some(Request::Generate { model, loras, .. }) = sent.first() else {
+            panic!("expected a generate request");
+        };
+        assert_eq!(model, "SDXL Base 1.0");
+        let stack: Vec<_> = loras.iter().map(|l| (l.name.as_str(), l.strength)).collect();
+        assert_eq!(stack, [("Pixel Art LoRA v1", 0.8), ("Retro Pixel LoRA", 1.2)]);
+    }
+}
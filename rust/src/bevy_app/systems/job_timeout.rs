@@ -0,0 +1,145 @@
+//! # Job Timeout System
+//!
+//! Fails jobs the backend has held for longer than
+//! `backend.generation_timeout_secs`, so a hung backend can't leave them
+//! running forever, and asks the backend to cancel them. Time is read from
+//! [`JobClock`], which tests advance instead of sleeping.
+
+use bevy::prelude::*;
+use std::time::{Duration, Instant};
+
+use crate::bevy_app::components::Job;
+use crate::bevy_app::resources::{AppState, SettingsState};
+use crate::bevy_app::systems::zmq::ZmqClientResource;
+
+/// How often in-flight jobs are checked.
+pub const TIMEOUT_CHECK_INTERVAL_SECS: u64 = 1;
+
+/// Time source for job timeouts: the system clock, plus however far it has
+/// been [advanced](JobClock::advance).
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct JobClock {
+    skew: Duration,
+}
+
+impl JobClock {
+    /// The current time.
+    pub fn now(&self) -> Instant {
+        Instant::now() + self.skew
+    }
+
+    /// Move the clock forward by `by`.
+    pub fn advance(&mut self, by: Duration) {
+        self.skew += by;
+    }
+}
+
+/// Fail in-flight jobs sent longer ago than the generation timeout.
+///
+/// Does nothing while the timeout is off (0).
+pub fn enforce_job_timeouts(
+    mut jobs: Query<&mut Job>,
+    settings: Res<SettingsState>,
+    clock: Res<JobClock>,
+    zmq_client: Option<Res<ZmqClientResource>>,
+    mut app_state: ResMut<AppState>,
+) {
+    let secs = settings.backend.generation_timeout_secs;
+    if secs == 0 {
+        return;
+    }
+    let timeout = Duration::from_secs(secs as u64);
+    let now = clock.now();
+
+    for mut job in jobs.iter_mut() {
+        // Only mark jobs that timed out, for `Changed<Job>` queries
+        if !job.bypass_change_detection().check_timeout(timeout, now) {
+            continue;
+        }
+        job.set_changed();
+        warn!("Job {} timed out after {}s", job.id, secs);
+
+        if let Some(ref zmq_client) = zmq_client {
+            let client = zmq_client.0.lock();
+            if let Err(e) = client.cancel_job(&job.id) {
+                error!("Failed to cancel timed out job {}: {}", job.id, e);
+            }
+        }
+        app_state.request_redraw();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::RecordingBackend;
+    use crate::bevy_app::components::JobStatus;
+    use crate::bevy_app::events::{
+        handle_generation_events, CancelJob, GenerationComplete, SubmitGenerationJob,
+    };
+    use crate::bevy_app::resources::JobTracker;
+    use crate::bevy_app::systems::job_scheduler::dispatch_queued_jobs;
+
+    fn statuses(app: &mut App) -> Vec<JobStatus> {
+        let mut query = app.world_mut().query::<&Job>();
+        query.iter(app.world()).map(|job| job.status.clone()).collect()
+    }
+
+    #[test]
+    fn test_job_fails_after_timeout() {
+        let mut settings = SettingsState::default();
+        settings.backend.generation_timeout_secs = 60;
+
+        let mut app = App::new();
+        app.add_event::<SubmitGenerationJob>()
+            .add_event::<GenerationComplete>()
+            .add_event::<CancelJob>()
+            .insert_resource(settings)
+            .insert_resource(JobTracker::default())
+            .insert_resource(AppState::default())
+            .insert_resource(ZmqClientResource::new(RecordingBackend::default()))
+            .init_resource::<JobClock>()
+            .add_systems(
+                Update,
+                (handle_generation_events, dispatch_queued_jobs, enforce_job_timeouts).chain(),
+            );
+
+        app.world_mut().send_event(SubmitGenerationJob {
+            prompt: "sprite".to_string(),
+            negative_prompt: None,
+            batch_size: None,
+            base_seed: None,
+            steps: None,
+            cfg_scale: None,
+            sweep: false,
+        });
+        app.update();
+        assert_eq!(statuses(&mut app), vec![JobStatus::Pending]);
+
+        app.world_mut().resource_mut::<JobClock>().advance(Duration::from_secs(59));
+        app.update();
+        assert_eq!(statuses(&mut app), vec![JobStatus::Pending]);
+
+        app.world_mut().resource_mut::<JobClock>().advance(Duration::from_secs(2));
+        app.update();
+        assert_eq!(
+            statuses(&mut app),
+            vec![JobStatus::Failed {
+                error: "timeout".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_jobs_not_sent_never_time_out() {
+        let mut job = Job::new("held".into(), "sprite".into());
+        job.status = JobStatus::QueuedLocally;
+        let now = Instant::now() + Duration::from_secs(3600);
+        assert!(!job.check_timeout(Duration::from_secs(60), now));
+
+        // Sent, but finished in time
+        job.sent_at = Some(Instant::now());
+        job.status = JobStatus::Cancelled;
+        assert!(!job.check_timeout(Duration::from_secs(60), now));
+    }
+}
@@ -0,0 +1,192 @@
+//! # Log Tail System
+//!
+//! Follows the log picked by `AppState::log_source` for the debug Logs tab:
+//! the backend's `../dgx-pixels-backend.log` or the TUI's own
+//! `dgx-pixels-tui.log`. Every [`LOG_POLL_INTERVAL`] in debug mode, lines
+//! appended to the selected file are added to that source's log lines. Only
+//! the selected file is read; each file's read position is kept, so
+//! switching back carries on where it left off. A file that doesn't exist
+//! yet is checked again on the next poll.
+
+use bevy::prelude::*;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::bevy_app::resources::{AppState, LogSource};
+
+/// How often the selected log file is checked for new lines.
+pub const LOG_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How much of the end of a log is read when starting to follow it.
+const INITIAL_TAIL_BYTES: u64 = 64 * 1024;
+
+/// The followed log files and how far each has been read.
+#[derive(Resource, Debug, Clone)]
+pub struct LogTail {
+    pub backend_path: PathBuf,
+    pub frontend_path: PathBuf,
+    /// Bytes of the backend log read so far (`None` until it's first opened)
+    backend_offset: Option<u64>,
+    /// Bytes of the frontend log read so far (`None` until it's first opened)
+    frontend_offset: Option<u64>,
+}
+
+impl Default for LogTail {
+    fn default() -> Self {
+        Self::with_paths(
+            Path::new("..").join(LogSource::Backend.file_name()),
+            LogSource::Frontend.file_name(),
+        )
+    }
+}
+
+impl LogTail {
+    /// Follow the given backend and frontend log files.
+    pub fn with_paths(backend: impl Into<PathBuf>, frontend: impl Into<PathBuf>) -> Self {
+        Self {
+            backend_path: backend.into(),
+            frontend_path: frontend.into(),
+            backend_offset: None,
+            frontend_offset: None,
+        }
+    }
+
+    /// Path of `source`'s log file.
+    pub fn path(&self, source: LogSource) -> &Path {
+        match source {
+            LogSource::Backend => &self.backend_path,
+            LogSource::Frontend => &self.frontend_path,
+        }
+    }
+
+    /// Complete lines added to `source`'s log since the last read.
+    ///
+    /// The first read starts near the end of the file, so a long log isn't
+    /// read in full. A missing file gives no lines; a file that got shorter
+    /// (truncated or replaced) is read again from the start.
+    pub fn read_new_lines(&mut self, source: LogSource) -> io::Result<Vec<String>> {
+        let mut file = match File::open(self.path(source)) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let len = file.metadata()?.len();
+        let offset = match source {
+            LogSource::Backend => self.backend_offset,
+            LogSource::Frontend => self.frontend_offset,
+        };
+        let start = match offset {
+            Some(offset) if offset <= len => offset,
+            Some(_) => 0,
+            None => len.saturating_sub(INITIAL_TAIL_BYTES),
+        };
+
+        file.seek(SeekFrom::Start(start))?;
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+
+        // Starting mid-file: drop the partial first line
+        let skip = match offset {
+            None if start > 0 => {
+                bytes.iter().position(|&b| b == b'\n').map_or(bytes.len(), |i| i + 1)
+            }
+            _ => 0,
+        };
+        // An unfinished last line is read once it's complete
+        let end = bytes.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1).max(skip);
+
+        let read_to = Some(start + end as u64);
+        match source {
+            LogSource::Backend => self.backend_offset = read_to,
+            LogSource::Frontend => self.frontend_offset = read_to,
+        }
+        Ok(String::from_utf8_lossy(&bytes[skip..end])
+            .lines()
+            .map(|line| line.trim_end().to_string())
+            .collect())
+    }
+}
+
+/// Add new lines from the selected log to its log lines (debug mode only).
+pub fn tail_log_files(mut tail: ResMut<LogTail>, mut app_state: ResMut<AppState>) {
+    if !app_state.debug_mode {
+        return;
+    }
+
+    let source = app_state.log_source;
+    match tail.read_new_lines(source) {
+        Ok(lines) => {
+            for line in lines {
+                app_state.add_log(source, line);
+            }
+        }
+        Err(e) => debug!("Failed to read {:?}: {}", tail.path(source), e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn append(path: &Path, text: &str) {
+        let mut file = File::options().create(true).append(true).open(path).unwrap();
+        file.write_all(text.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn test_toggling_source_switches_file_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let backend = dir.path().join("backend.log");
+        let frontend = dir.path().join("tui.log");
+        append(&backend, "INFO backend started\n");
+        append(&frontend, "INFO tui started\nWARN slow frame\n");
+
+        let mut app = App::new();
+        app.insert_resource(LogTail::with_paths(&backend, &frontend));
+        app.insert_resource(AppState {
+            debug_mode: true,
+            ..Default::default()
+        });
+        app.add_systems(Update, tail_log_files);
+        app.update();
+
+        let state = app.world().resource::<AppState>();
+        assert_eq!(state.backend_logs, vec!["INFO backend started"]);
+        assert!(state.frontend_logs.is_empty());
+
+        app.world_mut().resource_mut::<AppState>().toggle_log_source();
+        append(&backend, "INFO job done\n");
+        app.update();
+
+        let state = app.world().resource::<AppState>();
+        assert_eq!(state.log_source, LogSource::Frontend);
+        assert_eq!(state.frontend_logs, vec!["INFO tui started", "WARN slow frame"]);
+        // Not read while the frontend log is shown
+        assert_eq!(state.backend_logs.len(), 1);
+
+        app.world_mut().resource_mut::<AppState>().toggle_log_source();
+        app.update();
+        let state = app.world().resource::<AppState>();
+        assert_eq!(state.backend_logs, vec!["INFO backend started", "INFO job done"]);
+    }
+
+    #[test]
+    fn test_missing_log_is_read_once_created() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tui.log");
+        let mut tail = LogTail::with_paths(dir.path().join("backend.log"), &path);
+        assert!(tail.read_new_lines(LogSource::Frontend).unwrap().is_empty());
+
+        append(&path, "INFO first\nINFO part");
+        assert_eq!(tail.read_new_lines(LogSource::Frontend).unwrap(), vec!["INFO first"]);
+        append(&path, "ial\n");
+        assert_eq!(tail.read_new_lines(LogSource::Frontend).unwrap(), vec!["INFO partial"]);
+
+        // Rotated: start over
+        std::fs::write(&path, "INFO new\n").unwrap();
+        assert_eq!(tail.read_new_lines(LogSource::Frontend).unwrap(), vec!["INFO new"]);
+    }
+}
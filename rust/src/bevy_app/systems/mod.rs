@@ -4,8 +4,18 @@
 //! scheduled and executed by the Bevy ECS scheduler.
 
 pub mod assets;
+pub mod comparison_jobs;
+pub mod config_reload;
+pub mod host_check;
 pub mod input;
+pub mod job_cleanup;
+pub mod job_scheduler;
+pub mod job_timeout;
+pub mod log_tail;
+pub mod path_check;
 pub mod render;
+pub mod shutdown;
+pub mod spinner;
 pub mod state_init;
 pub mod zmq;
 
@@ -0,0 +1,61 @@
+//! # Writable Path Check
+//!
+//! Warns when `paths.output_dir` or `paths.cache_dir` can't be written, so
+//! finished jobs don't quietly fail to save their images and leave the
+//! gallery empty. Checked at startup and whenever either path changes
+//! (edited on the Settings screen or reloaded from config.toml).
+
+use bevy::prelude::*;
+use std::path::PathBuf;
+
+use crate::bevy_app::resources::{NotificationLevel, Notifications, SettingsState};
+
+/// Check the output and cache directories when they change.
+pub fn check_writable_paths(
+    settings: Res<SettingsState>,
+    mut checked: Local<Option<(PathBuf, PathBuf)>>,
+    mut notifications: ResMut<Notifications>,
+) {
+    let paths = (settings.paths.output_dir.clone(), settings.paths.cache_dir.clone());
+    if checked.as_ref() == Some(&paths) {
+        return;
+    }
+    *checked = Some(paths);
+
+    if let Err(e) = settings.paths.ensure_writable() {
+        warn!("{:#}", e);
+        notifications.notify(NotificationLevel::Error, format!("{:#}", e));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notifies_once_per_unwritable_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("file");
+        std::fs::write(&file, "").unwrap();
+
+        let mut settings = SettingsState::default();
+        settings.paths.output_dir = dir.path().join("output");
+        settings.paths.cache_dir = dir.path().join("cache");
+
+        let mut app = App::new();
+        app.insert_resource(settings)
+            .init_resource::<Notifications>()
+            .add_systems(Update, check_writable_paths);
+        app.update();
+        assert!(app.world().resource::<Notifications>().is_empty());
+
+        app.world_mut().resource_mut::<SettingsState>().paths.cache_dir = file.join("cache");
+        app.update();
+        app.update();
+        let notifications = app.world().resource::<Notifications>();
+        assert_eq!(notifications.len(), 1);
+        let notification = notifications.visible().next().unwrap();
+        assert_eq!(notification.level, NotificationLevel::Error);
+        assert!(notification.message.starts_with("Cache directory"));
+    }
+}
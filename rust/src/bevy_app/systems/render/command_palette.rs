@@ -0,0 +1,150 @@
+//! # Command Palette Overlay
+//!
+//! Draws the command palette centered over whatever screen is showing.
+//! Screens draw whole frames, so the overlay is written straight to the
+//! backend after them (like Sixel previews). Cells the screen leaves
+//! unchanged aren't redrawn, so the overlay stays put; when it closes the
+//! terminal is cleared so the next frame repaints everything underneath.
+
+use bevy::prelude::*;
+use bevy_ratatui::terminal::RatatuiContext;
+use ratatui::{
+    backend::Backend,
+    buffer::Buffer,
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+
+use crate::bevy_app::resources::{AppTheme, CommandPalette};
+
+/// Widest the palette gets, in columns.
+const PALETTE_WIDTH: u16 = 50;
+
+/// Most actions listed at once.
+const PALETTE_ROWS: u16 = 10;
+
+/// Draw the command palette over the current screen (runs after all screens).
+pub fn render_command_palette(
+    mut palette: ResMut<CommandPalette>,
+    theme: Res<AppTheme>,
+    mut ratatui: ResMut<RatatuiContext>,
+) {
+    if palette.needs_repaint {
+        palette.needs_repaint = false;
+        if let Err(e) = ratatui.clear() {
+            warn!("Failed to clear command palette: {}", e);
+        }
+    }
+    if !palette.open {
+        return;
+    }
+
+    let area = match ratatui.size() {
+        Ok(size) => Rect::new(0, 0, size.width, size.height),
+        Err(e) => {
+            warn!("Failed to read terminal size: {}", e);
+            return;
+        }
+    };
+
+    let mut buf = Buffer::empty(area);
+    let (popup, cursor) = draw_palette(&mut buf, area, &palette, &theme);
+
+    let backend = ratatui.backend_mut();
+    let result = backend
+        .draw(popup.positions().map(|p| (p.x, p.y, &buf[p])))
+        .and_then(|_| backend.set_cursor_position(cursor))
+        .and_then(|_| backend.flush());
+    if let Err(e) = result {
+        warn!("Failed to draw command palette: {}", e);
+    }
+}
+
+/// Render the palette into `buf`, returning its area and the query cursor.
+pub fn draw_palette(
+    buf: &mut Buffer,
+    area: Rect,
+    palette: &CommandPalette,
+    theme: &AppTheme,
+) -> (Rect, (u16, u16)) {
+    // Query row, separator and action rows inside the border
+    let width = PALETTE_WIDTH.min(area.width.saturating_sub(4)).max(10);
+    let height = (PALETTE_ROWS + 4).min(area.height);
+    let popup = Rect::new(
+        area.x + area.width.saturating_sub(width) / 2,
+        area.y + area.height.saturating_sub(height) / 3,
+        width.min(area.width),
+        height,
+    );
+
+    let actions = palette.filtered();
+    let visible = height.saturating_sub(4) as usize;
+    // Keep the selection in view
+    let first = palette.selected.saturating_sub(visible.saturating_sub(1));
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("> ", theme.highlight()),
+            Span::styled(palette.query.as_str(), theme.text()),
+        ]),
+        Line::from(Span::styled(
+            "─".repeat(popup.width.saturating_sub(2) as usize),
+            theme.border(),
+        )),
+    ];
+    if actions.is_empty() {
+        lines.push(Line::from(Span::styled("No matching commands", theme.muted())));
+    }
+    for (i, action) in actions.iter().enumerate().skip(first).take(visible) {
+        let style = if i == palette.selected {
+            theme.highlight()
+        } else {
+            theme.text()
+        };
+        let marker = if i == palette.selected { "> " } else { "  " };
+        lines.push(Line::from(Span::styled(format!("{}{}", marker, action.label), style)));
+    }
+
+    Clear.render(popup, buf);
+    Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Command Palette ")
+                .borders(Borders::ALL)
+                .border_style(theme.input_active()),
+        )
+        .render(popup, buf);
+
+    let cursor_x = (popup.x + 3 + palette.query.chars().count() as u16)
+        .min(popup.right().saturating_sub(2));
+    (popup, (cursor_x, popup.y + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette_is_centered_and_lists_matches() {
+        let area = Rect::new(0, 0, 100, 30);
+        let mut buf = Buffer::empty(area);
+        let mut palette = CommandPalette::default();
+        palette.open();
+        palette.push_char('q');
+
+        let (popup, cursor) = draw_palette(&mut buf, area, &palette, &AppTheme::default());
+        assert_eq!(popup.width, PALETTE_WIDTH);
+        assert_eq!(popup.x, (100 - PALETTE_WIDTH) / 2);
+        assert_eq!(cursor, (popup.x + 4, popup.y + 1));
+
+        let text: String = popup
+            .positions()
+            .map(|p| buf[p].symbol().to_string())
+            .collect();
+        assert!(text.contains("Command Palette"));
+        // Best match first and selected
+        assert!(text.contains("> Quit"));
+        assert!(text.contains("Go to Queue"));
+    }
+}
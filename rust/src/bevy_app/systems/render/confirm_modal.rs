@@ -0,0 +1,124 @@
+//! # Confirm Modal Overlay
+//!
+//! Draws the shared Yes/No dialog centered over the current screen, written
+//! straight to the backend after the screens like the command palette.
+
+use bevy::prelude::*;
+use bevy_ratatui::terminal::RatatuiContext;
+use ratatui::{
+    backend::Backend,
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+use crate::bevy_app::resources::{AppTheme, ConfirmModal};
+
+/// Widest the dialog gets, in columns.
+const MODAL_WIDTH: u16 = 50;
+
+/// Dialog height: message, gap and the Yes/No row inside the border.
+const MODAL_HEIGHT: u16 = 7;
+
+/// Draw the confirmation dialog over the current screen (runs after all screens).
+pub fn render_confirm_modal(
+    mut modal: ResMut<ConfirmModal>,
+    theme: Res<AppTheme>,
+    mut ratatui: ResMut<RatatuiContext>,
+) {
+    if modal.needs_repaint {
+        modal.needs_repaint = false;
+        if let Err(e) = ratatui.clear() {
+            warn!("Failed to clear confirmation dialog: {}", e);
+        }
+    }
+    if !modal.open {
+        return;
+    }
+
+    let area = match ratatui.size() {
+        Ok(size) => Rect::new(0, 0, size.width, size.height),
+        Err(e) => {
+            warn!("Failed to read terminal size: {}", e);
+            return;
+        }
+    };
+
+    let mut buf = Buffer::empty(area);
+    let popup = draw_confirm_modal(&mut buf, area, &modal, &theme);
+
+    let backend = ratatui.backend_mut();
+    let result = backend
+        .draw(popup.positions().map(|p| (p.x, p.y, &buf[p])))
+        .and_then(|_| backend.flush());
+    if let Err(e) = result {
+        warn!("Failed to draw confirmation dialog: {}", e);
+    }
+}
+
+/// Render the dialog into `buf`, returning its area.
+pub fn draw_confirm_modal(
+    buf: &mut Buffer,
+    area: Rect,
+    modal: &ConfirmModal,
+    theme: &AppTheme,
+) -> Rect {
+    let width = MODAL_WIDTH.min(area.width.saturating_sub(4)).max(10).min(area.width);
+    let height = MODAL_HEIGHT.min(area.height);
+    let popup = Rect::new(
+        area.x + area.width.saturating_sub(width) / 2,
+        area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    );
+
+    let lines = vec![
+        Line::from(Span::styled(modal.message.as_str(), theme.text())),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("[y] Yes", theme.highlight()),
+            Span::raw("    "),
+            Span::styled("[n] No", theme.muted()),
+        ]),
+    ];
+
+    Clear.render(popup, buf);
+    Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .title(" Confirm ")
+                .borders(Borders::ALL)
+                .border_style(theme.warning()),
+        )
+        .render(popup, buf);
+
+    popup
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bevy_app::resources::ConfirmAction;
+
+    #[test]
+    fn test_modal_is_centered_with_choices() {
+        let area = Rect::new(0, 0, 100, 30);
+        let mut buf = Buffer::empty(area);
+        let mut modal = ConfirmModal::default();
+        modal.ask("Reset all settings?", ConfirmAction::ResetSettings);
+
+        let popup = draw_confirm_modal(&mut buf, area, &modal, &AppTheme::default());
+        assert_eq!(popup, Rect::new(25, 11, MODAL_WIDTH, MODAL_HEIGHT));
+
+        let text: String = popup
+            .positions()
+            .map(|p| buf[p].symbol().to_string())
+            .collect();
+        assert!(text.contains("Reset all settings?"));
+        assert!(text.contains("[y] Yes"));
+        assert!(text.contains("[n] No"));
+    }
+}
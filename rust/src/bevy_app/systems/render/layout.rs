@@ -44,6 +44,40 @@ pub fn top_and_rest(top_height: u16, area: Rect) -> std::rc::Rc<[Rect]> {
         .split(area)
 }
 
+/// Split a grid cell into its image area and optional label row.
+///
+/// Borders take one cell on every side; the label (when enabled) takes the
+/// bottom row of what remains. If the cell is too small to hold both, the
+/// label is dropped in favour of the image.
+pub fn grid_cell_layout(cell: Rect, show_border: bool, show_label: bool) -> (Rect, Option<Rect>) {
+    let inner = if show_border {
+        Rect {
+            x: cell.x.saturating_add(1),
+            y: cell.y.saturating_add(1),
+            width: cell.width.saturating_sub(2),
+            height: cell.height.saturating_sub(2),
+        }
+    } else {
+        cell
+    };
+
+    if !show_label || inner.height < 2 {
+        return (inner, None);
+    }
+
+    let image = Rect {
+        height: inner.height - 1,
+        ..inner
+    };
+    let label = Rect {
+        y: inner.y + inner.height - 1,
+        height: 1,
+        ..inner
+    };
+
+    (image, Some(label))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,4 +116,36 @@ mod tests {
         // Bottom section gets remaining height (100 - 10 = 90)
         assert_eq!(split[1].height, 90);
     }
+
+    #[test]
+    fn test_grid_cell_layout_reserves_label_row() {
+        let cell = Rect::new(10, 5, 20, 8);
+
+        let (image, label) = grid_cell_layout(cell, true, true);
+        let label = label.expect("label row when labels are enabled");
+        assert_eq!(image, Rect::new(11, 6, 18, 5));
+        assert_eq!(label, Rect::new(11, 11, 18, 1));
+
+        let (image, label) = grid_cell_layout(cell, true, false);
+        assert!(label.is_none());
+        assert_eq!(image, Rect::new(11, 6, 18, 6));
+    }
+
+    #[test]
+    fn test_grid_cell_layout_without_borders() {
+        let cell = Rect::new(0, 0, 20, 8);
+
+        let (image, label) = grid_cell_layout(cell, false, true);
+        assert_eq!(image.height, 7);
+        assert_eq!(label.map(|l| l.y), Some(7));
+
+        let (image, label) = grid_cell_layout(cell, false, false);
+        assert_eq!(image, cell);
+        assert!(label.is_none());
+
+        // Too small for a label: the image keeps the row
+        let (image, label) = grid_cell_layout(Rect::new(0, 0, 20, 3), true, true);
+        assert_eq!(image.height, 1);
+        assert!(label.is_none());
+    }
 }
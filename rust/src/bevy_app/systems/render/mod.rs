@@ -1,13 +1,19 @@
 //! Rendering systems and utilities.
 
+pub mod command_palette;
+pub mod confirm_modal;
 mod dispatch;
 mod layout;
+pub mod notifications;
 pub mod screens;
 pub mod sixel_utils;
 mod widgets;
 
+pub use command_palette::render_command_palette;
+pub use confirm_modal::render_confirm_modal;
 pub use dispatch::render_dispatch;
 pub use layout::*;
+pub use notifications::render_notifications;
 pub use screens::*;
 pub use sixel_utils::*;
 pub use widgets::*;
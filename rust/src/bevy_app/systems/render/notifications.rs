@@ -0,0 +1,133 @@
+//! # Notification Overlay
+//!
+//! Draws the newest notifications stacked in the top-right corner, written
+//! straight to the backend after the screens like the confirmation dialog.
+//! Expired notifications are dropped here, once per frame.
+
+use bevy::prelude::*;
+use bevy_ratatui::terminal::RatatuiContext;
+use ratatui::{
+    backend::Backend,
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Widget},
+};
+use std::time::Instant;
+
+use crate::bevy_app::resources::{AppTheme, NotificationLevel, Notifications};
+
+/// Widest a notification gets, in columns.
+const TOAST_WIDTH: u16 = 44;
+
+/// Rows per notification: one line of text inside the border.
+const TOAST_HEIGHT: u16 = 3;
+
+/// Draw the notifications over the current screen (runs after all screens).
+pub fn render_notifications(
+    mut notifications: ResMut<Notifications>,
+    theme: Res<AppTheme>,
+    mut ratatui: ResMut<RatatuiContext>,
+) {
+    notifications.expire(Instant::now());
+    if notifications.needs_repaint {
+        notifications.needs_repaint = false;
+        if let Err(e) = ratatui.clear() {
+            warn!("Failed to clear notifications: {}", e);
+        }
+    }
+    if notifications.is_empty() {
+        return;
+    }
+
+    let area = match ratatui.size() {
+        Ok(size) => Rect::new(0, 0, size.width, size.height),
+        Err(e) => {
+            warn!("Failed to read terminal size: {}", e);
+            return;
+        }
+    };
+
+    let mut buf = Buffer::empty(area);
+    let drawn = draw_notifications(&mut buf, area, &notifications, &theme);
+
+    let backend = ratatui.backend_mut();
+    let result = backend
+        .draw(drawn.positions().map(|p| (p.x, p.y, &buf[p])))
+        .and_then(|_| backend.flush());
+    if let Err(e) = result {
+        warn!("Failed to draw notifications: {}", e);
+    }
+}
+
+/// Render the visible notifications into `buf`, newest at the top, returning
+/// the area they cover.
+pub fn draw_notifications(
+    buf: &mut Buffer,
+    area: Rect,
+    notifications: &Notifications,
+    theme: &AppTheme,
+) -> Rect {
+    let width = TOAST_WIDTH.min(area.width);
+    let x = area.x + area.width - width;
+    let mut y = area.y;
+
+    for notification in notifications.visible() {
+        if y + TOAST_HEIGHT > area.y + area.height {
+            break;
+        }
+        let toast = Rect::new(x, y, width, TOAST_HEIGHT);
+        let (title, style) = level_style(notification.level, theme);
+
+        Clear.render(toast, buf);
+        Paragraph::new(Line::styled(notification.message.as_str(), theme.text()))
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(style),
+            )
+            .render(toast, buf);
+        y += TOAST_HEIGHT;
+    }
+
+    Rect::new(x, area.y, width, y - area.y)
+}
+
+fn level_style(level: NotificationLevel, theme: &AppTheme) -> (&'static str, Style) {
+    match level {
+        NotificationLevel::Info => (" Info ", theme.highlight()),
+        NotificationLevel::Warning => (" Warning ", theme.warning()),
+        NotificationLevel::Error => (" Error ", theme.error()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_newest_notifications_stack_in_corner() {
+        let area = Rect::new(0, 0, 100, 30);
+        let mut buf = Buffer::empty(area);
+        let mut notifications = Notifications::default();
+        for n in 1..=4 {
+            let message = format!("Job {} failed", n);
+            notifications.notify(NotificationLevel::Error, message);
+        }
+
+        let drawn = draw_notifications(&mut buf, area, &notifications, &AppTheme::default());
+        assert_eq!(drawn, Rect::new(100 - TOAST_WIDTH, 0, TOAST_WIDTH, 3 * TOAST_HEIGHT));
+
+        let row = |y: u16| -> String {
+            (drawn.x..drawn.right()).map(|x| buf[(x, y)].symbol().to_string()).collect()
+        };
+        assert!(row(0).contains("Error"));
+        assert!(row(1).contains("Job 4 failed"));
+        assert!(row(7).contains("Job 2 failed"));
+        // Only the newest three fit the overlay
+        let text: String = (0..30).map(row).collect();
+        assert!(!text.contains("Job 1 failed"));
+    }
+}
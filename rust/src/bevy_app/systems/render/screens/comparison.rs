@@ -2,9 +2,12 @@
 //!
 //! Side-by-side model comparison with Sixel preview support.
 //! Allows users to compare outputs from different models/LoRAs with the same prompt.
+//! The overlay mode (`o`) shows the per-pixel difference of the two images
+//! in place of the panes.
 
 use bevy::prelude::*;
 use bevy_ratatui::terminal::RatatuiContext;
+use std::path::{Path, PathBuf};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     text::{Line, Span},
@@ -18,10 +21,53 @@ use crate::bevy_app::{
         comparison_state::{ComparisonMode, ComparisonPane},
         AppTheme, ComparisonState, CurrentScreen, Screen, SettingsState,
     },
-    systems::assets::{SixelPreviewCache, SixelRenderOptions, render_image_sixel, supports_sixel},
-    systems::render::sixel_utils::render_sixel_to_area,
+    systems::assets::{
+        image_diff, render_image_sixel, supports_sixel, SixelPreviewCache, SixelRenderOptions,
+    },
+    systems::assets::render::{calculate_ascii_dimensions, render_image_to_unicode},
+    systems::render::sixel_utils::{clear_all_sixel, PendingSixelWrites},
+    systems::spinner::Spinner,
 };
 
+/// The difference overlay, kept between frames.
+#[derive(Default)]
+pub struct DiffOverlay {
+    /// Whether the overlay was showing last frame
+    was_showing: bool,
+    /// Difference image: (left path, right path, image)
+    diff: Option<(PathBuf, PathBuf, Image)>,
+    /// Last Sixel render: (area size, Sixel data) for `diff`
+    sixel: Option<((u16, u16), String)>,
+}
+
+impl DiffOverlay {
+    /// Compute the difference of the two pane images, once per pair.
+    ///
+    /// Returns whether there is one (both images have loaded).
+    fn update(
+        &mut self,
+        comparison: &ComparisonState,
+        preview_query: &Query<&PreviewImage>,
+        images: Option<&Assets<Image>>,
+        asset_server: Option<&AssetServer>,
+    ) -> bool {
+        let (Some(left), Some(right)) = (&comparison.left_image, &comparison.right_image) else {
+            return false;
+        };
+        if matches!(&self.diff, Some((l, r, _)) if l == left && r == right) {
+            return true;
+        }
+
+        let pane_image = |path| loaded_image(path, preview_query, images?, asset_server?);
+        let (Some(left_image), Some(right_image)) = (pane_image(left), pane_image(right)) else {
+            return false;
+        };
+        self.diff = Some((left.clone(), right.clone(), image_diff(left_image, right_image)));
+        self.sixel = None;
+        true
+    }
+}
+
 /// Render the Comparison screen
 pub fn render_comparison_screen(
     current_screen: Res<CurrentScreen>,
@@ -32,11 +78,23 @@ pub fn render_comparison_screen(
     images: Option<Res<Assets<Image>>>,
     asset_server: Option<Res<AssetServer>>,
     sixel_cache: Option<Res<SixelPreviewCache>>,
+    spinner: Option<Res<Spinner>>,
+    mut diff_overlay: Local<DiffOverlay>,
+    mut pending_sixel: ResMut<PendingSixelWrites>,
     mut ratatui: ResMut<RatatuiContext>,
 ) {
     if current_screen.0 != Screen::Comparison {
         return;
     }
+    let spinner = spinner.as_deref().copied().unwrap_or_default().frame();
+
+    // The panes' Sixel images aren't in ratatui's buffer, so wipe them on toggle
+    if diff_overlay.was_showing != comparison.overlay_diff {
+        if let Err(e) = clear_all_sixel() {
+            warn!("Failed to clear Sixel on overlay toggle: {}", e);
+        }
+        diff_overlay.was_showing = comparison.overlay_diff;
+    }
 
     ratatui
         .draw(|frame| {
@@ -53,14 +111,20 @@ pub fn render_comparison_screen(
                     images.as_deref(),
                     asset_server.as_deref(),
                     sixel_cache.as_deref(),
+                    &mut pending_sixel,
+                    &mut diff_overlay,
+                    spinner,
                 ),
-                ComparisonMode::Multi => render_multi_comparison(frame, area, &comparison, &theme),
+                ComparisonMode::Multi => {
+                    render_multi_comparison(frame, area, &comparison, &theme, spinner)
+                }
             }
         })
         .expect("Failed to render comparison screen");
 }
 
 /// Render dual-pane side-by-side comparison
+#[allow(clippy::too_many_arguments)]
 fn render_dual_comparison(
     frame: &mut Frame,
     area: Rect,
@@ -69,8 +133,11 @@ fn render_dual_comparison(
     settings: &SettingsState,
     preview_query: &Query<&PreviewImage>,
     images: Option<&Assets<Image>>,
-    asset_server: Option<&AssetServer>>,
+    asset_server: Option<&AssetServer>,
     sixel_cache: Option<&SixelPreviewCache>,
+    pending_sixel: &mut PendingSixelWrites,
+    diff_overlay: &mut DiffOverlay,
+    spinner: &str,
 ) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -91,6 +158,19 @@ fn render_dual_comparison(
     // Model browsing overlay (if active)
     if comparison.browsing_models {
         render_model_browser(frame, chunks[2], comparison, theme);
+    } else if comparison.overlay_diff {
+        render_diff_overlay(
+            frame,
+            chunks[2],
+            comparison,
+            theme,
+            settings,
+            preview_query,
+            images,
+            asset_server,
+            pending_sixel,
+            diff_overlay,
+        );
     } else {
         // Dual preview panes
         render_dual_panes(
@@ -103,6 +183,8 @@ fn render_dual_comparison(
             images,
             asset_server,
             sixel_cache,
+            pending_sixel,
+            spinner,
         );
     }
 
@@ -127,33 +209,67 @@ fn render_prompt_input(
     comparison: &ComparisonState,
     theme: &AppTheme,
 ) {
-    let prompt_text = if comparison.prompt.is_empty() {
-        "[Enter shared prompt for comparison...]"
+    let prompt_display = if comparison.editing_prompt {
+        let target = match (comparison.per_pane_prompts, comparison.selected_pane) {
+            (false, _) => "Shared",
+            (true, ComparisonPane::Left) => "Left",
+            (true, ComparisonPane::Right) => "Right",
+        };
+        Line::from(vec![
+            Span::styled(format!("{} prompt: ", target), theme.highlight()),
+            Span::styled(comparison.prompt_edit_buffer.clone(), theme.text()),
+            Span::styled("_", theme.highlight()),
+        ])
+    } else if comparison.per_pane_prompts {
+        let pane_prompt = |pane| {
+            let prompt = comparison.effective_prompt(pane);
+            if prompt.is_empty() {
+                Span::styled("[empty]", theme.muted())
+            } else if comparison.pane_prompt_override(pane).is_some() {
+                Span::styled(prompt.to_string(), theme.text())
+            } else {
+                Span::styled(prompt.to_string(), theme.muted())
+            }
+        };
+        Line::from(vec![
+            Span::styled("L: ", theme.muted()),
+            pane_prompt(ComparisonPane::Left),
+            Span::styled("  R: ", theme.muted()),
+            pane_prompt(ComparisonPane::Right),
+        ])
+    } else if comparison.prompt.is_empty() {
+        Line::from(vec![
+            Span::styled("Prompt: ", theme.muted()),
+            Span::styled("[Press e to enter shared prompt for comparison...]", theme.muted()),
+        ])
     } else {
-        &comparison.prompt
+        Line::from(vec![
+            Span::styled("Prompt: ", theme.muted()),
+            Span::styled(comparison.prompt.clone(), theme.text()),
+        ])
     };
 
-    let prompt_display = Line::from(vec![
-        Span::styled("Prompt: ", theme.muted()),
-        Span::styled(
-            prompt_text,
-            if comparison.prompt.is_empty() {
-                theme.muted()
-            } else {
-                theme.text()
-            },
-        ),
-    ]);
+    let title = if comparison.per_pane_prompts {
+        " Prompts (per-pane) "
+    } else {
+        " Prompt "
+    };
 
     let paragraph = Paragraph::new(prompt_display).block(
         Block::default()
+            .title(title)
             .borders(Borders::ALL)
-            .border_style(theme.text()),
+            .border_style(if comparison.editing_prompt {
+                theme.highlight()
+            } else {
+                theme.text()
+            }),
     );
 
     frame.render_widget(paragraph, area);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_dual_panes(
     frame: &mut Frame,
     area: Rect,
@@ -162,10 +278,13 @@ fn render_dual_panes(
     settings: &SettingsState,
     preview_query: &Query<&PreviewImage>,
     images: Option<&Assets<Image>>,
-    asset_server: Option<&AssetServer>>,
+    asset_server: Option<&AssetServer>,
     sixel_cache: Option<&SixelPreviewCache>,
+    pending_sixel: &mut PendingSixelWrites,
+    spinner: &str,
 ) {
     // Split into left and right panes
+    let running = |pane| comparison.pane_running(pane).then_some(spinner);
     let panes = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
@@ -177,16 +296,20 @@ fn render_dual_panes(
         panes[0],
         ComparisonPane::Left,
         &comparison.left_model,
+        comparison.per_pane_prompts && comparison.pane_prompt_override(ComparisonPane::Left).is_some(),
+        comparison.is_pinned(ComparisonPane::Left),
+        comparison.is_reference(ComparisonPane::Left),
         &comparison.left_image,
         comparison.left_metadata.as_ref(),
         comparison.selected_pane == ComparisonPane::Left,
-        comparison.is_running,
+        running(ComparisonPane::Left),
         theme,
         settings,
         preview_query,
         images,
         asset_server,
         sixel_cache,
+        pending_sixel,
     );
 
     // Render right pane
@@ -195,35 +318,125 @@ fn render_dual_panes(
         panes[1],
         ComparisonPane::Right,
         &comparison.right_model,
+        comparison.per_pane_prompts && comparison.pane_prompt_override(ComparisonPane::Right).is_some(),
+        comparison.is_pinned(ComparisonPane::Right),
+        comparison.is_reference(ComparisonPane::Right),
         &comparison.right_image,
         comparison.right_metadata.as_ref(),
         comparison.selected_pane == ComparisonPane::Right,
-        comparison.is_running,
+        running(ComparisonPane::Right),
         theme,
         settings,
         preview_query,
         images,
         asset_server,
         sixel_cache,
+        pending_sixel,
     );
 }
 
+/// Render the difference of the two pane images over both panes.
+#[allow(clippy::too_many_arguments)]
+fn render_diff_overlay(
+    frame: &mut Frame,
+    area: Rect,
+    comparison: &ComparisonState,
+    theme: &AppTheme,
+    settings: &SettingsState,
+    preview_query: &Query<&PreviewImage>,
+    images: Option<&Assets<Image>>,
+    asset_server: Option<&AssetServer>,
+    pending_sixel: &mut PendingSixelWrites,
+    diff_overlay: &mut DiffOverlay,
+) {
+    let block = Block::default()
+        .title(" Difference (brighter = more different) - o: back to panes ")
+        .borders(Borders::ALL)
+        .border_style(theme.highlight());
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if !diff_overlay.update(comparison, preview_query, images, asset_server) {
+        render_loading_placeholder(frame, inner, theme);
+        return;
+    }
+    let DiffOverlay {
+        diff: Some((_, _, diff)),
+        sixel,
+        ..
+    } = diff_overlay
+    else {
+        return;
+    };
+
+    if settings.ui.show_image_previews && supports_sixel() {
+        let size = (inner.width, inner.height);
+        if !matches!(sixel, Some((cached, _)) if *cached == size) {
+            let options = SixelRenderOptions {
+                width: inner.width,
+                height: inner.height,
+                preserve_aspect: true,
+                high_quality: true,
+                background: settings.ui.sixel_background,
+                max_colors: settings.ui.sixel_colors as usize,
+            };
+            *sixel = match render_image_sixel(diff, &options) {
+                Ok(data) => Some((size, data)),
+                Err(e) => {
+                    warn!("Difference overlay Sixel rendering failed: {}", e);
+                    None
+                }
+            };
+        }
+        if let Some((_, data)) = sixel {
+            pending_sixel.queue(inner, data);
+            return;
+        }
+    }
+
+    let (width, height) =
+        calculate_ascii_dimensions(diff.width(), diff.height(), inner.width, inner.height);
+    let lines = render_image_to_unicode(diff, width, height, &settings.unicode_render_options());
+    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), inner);
+}
+
+/// The loaded Bevy image for `path`, if it has finished loading.
+fn loaded_image<'a>(
+    path: &Path,
+    preview_query: &Query<&PreviewImage>,
+    images: &'a Assets<Image>,
+    asset_server: &AssetServer,
+) -> Option<&'a Image> {
+    let preview = preview_query.iter().find(|p| p.path == path)?;
+    let handle = preview.asset_handle.as_ref()?;
+    match asset_server.load_state(handle) {
+        bevy::asset::LoadState::Loaded => images.get(handle),
+        _ => None,
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn render_pane(
     frame: &mut Frame,
     area: Rect,
     pane: ComparisonPane,
     model: &Option<String>,
+    has_prompt_override: bool,
+    pinned: bool,
+    // Shows a saved image, never regenerated
+    reference: bool,
     image_path: &Option<std::path::PathBuf>,
     metadata: Option<&crate::bevy_app::resources::comparison_state::GenerationMetadata>,
     is_selected: bool,
-    is_running: bool,
+    // Spinner frame, while the comparison is generating
+    running: Option<&str>,
     theme: &AppTheme,
     settings: &SettingsState,
     preview_query: &Query<&PreviewImage>,
     images: Option<&Assets<Image>>,
-    asset_server: Option<&AssetServer>>,
+    asset_server: Option<&AssetServer>,
     sixel_cache: Option<&SixelPreviewCache>,
+    pending_sixel: &mut PendingSixelWrites,
 ) {
     let pane_name = match pane {
         ComparisonPane::Left => "Left",
@@ -232,10 +445,15 @@ fn render_pane(
 
     let model_name = model.as_deref().unwrap_or("[No model selected]");
 
+    let override_marker = if has_prompt_override { " [own prompt]" } else { "" };
+    let pin_marker = if pinned { " 📌" } else { "" };
+    let reference_marker = if reference { " [reference, not regenerated]" } else { "" };
+    let markers = format!("{}{}{}", pin_marker, reference_marker, override_marker);
+
     let title = if is_selected {
-        format!(" {} > {} ", pane_name, model_name)
+        format!(" {} > {}{} ", pane_name, model_name, markers)
     } else {
-        format!(" {}: {} ", pane_name, model_name)
+        format!(" {}: {}{} ", pane_name, model_name, markers)
     };
 
     let border_style = if is_selected {
@@ -262,8 +480,8 @@ fn render_pane(
         .split(inner);
 
     // Render preview
-    if is_running {
-        render_generating_placeholder(frame, content_chunks[0], theme);
+    if let Some(spinner) = running {
+        render_generating_placeholder(frame, content_chunks[0], spinner, theme);
     } else if let Some(path) = image_path {
         render_preview_image(
             frame,
@@ -275,6 +493,7 @@ fn render_pane(
             images,
             asset_server,
             sixel_cache,
+            pending_sixel,
         );
     } else {
         render_empty_preview(frame, content_chunks[0], theme);
@@ -284,11 +503,11 @@ fn render_pane(
     render_metadata(frame, content_chunks[1], metadata, theme);
 }
 
-fn render_generating_placeholder(frame: &mut Frame, area: Rect, theme: &AppTheme) {
+fn render_generating_placeholder(frame: &mut Frame, area: Rect, spinner: &str, theme: &AppTheme) {
     let lines = vec![
         Line::from(""),
         Line::from(""),
-        Line::from(Span::styled("Generating...", theme.highlight())),
+        Line::from(Span::styled(format!("{} Generating...", spinner), theme.highlight())),
         Line::from(""),
         Line::from(Span::styled("Please wait", theme.muted())),
     ];
@@ -318,8 +537,9 @@ fn render_preview_image(
     settings: &SettingsState,
     preview_query: &Query<&PreviewImage>,
     images: Option<&Assets<Image>>,
-    asset_server: Option<&AssetServer>>,
+    asset_server: Option<&AssetServer>,
     sixel_cache: Option<&SixelPreviewCache>,
+    pending_sixel: &mut PendingSixelWrites,
 ) {
     // Try to find PreviewImage component for this path
     let preview = preview_query.iter().find(|p| &p.path == path);
@@ -337,6 +557,7 @@ fn render_preview_image(
                             theme,
                             settings,
                             sixel_cache,
+                            pending_sixel,
                         );
                         return;
                     }
@@ -370,6 +591,7 @@ fn render_preview_image(
     frame.render_widget(paragraph, area);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_image_with_sixel(
     frame: &mut Frame,
     area: Rect,
@@ -378,15 +600,16 @@ fn render_image_with_sixel(
     theme: &AppTheme,
     settings: &SettingsState,
     sixel_cache: Option<&SixelPreviewCache>,
+    pending_sixel: &mut PendingSixelWrites,
 ) {
     let use_sixel = settings.ui.show_image_previews && supports_sixel();
 
     if use_sixel && sixel_cache.is_some() {
         // Try Sixel rendering
         if let Some(cache) = sixel_cache {
-            if let Some(entry) = cache.get(path) {
+            if let Some(entry) = cache.get(path, settings.ui.sixel_colors as usize) {
                 // Render cached Sixel
-                let _ = render_sixel_to_area(area, &entry.sixel_data);
+                pending_sixel.queue(area, &entry.sixel_data);
                 return;
             } else {
                 // Generate and cache Sixel
@@ -395,6 +618,8 @@ fn render_image_with_sixel(
                     height: area.height.saturating_sub(4),
                     preserve_aspect: true,
                     high_quality: true,
+                    background: settings.ui.sixel_background,
+                    max_colors: settings.ui.sixel_colors as usize,
                 };
 
                 if let Ok(sixel_data) = render_image_sixel(image, &options) {
@@ -404,10 +629,11 @@ fn render_image_with_sixel(
                         size_bytes: sixel_data.len(),
                         last_access: std::time::Instant::now(),
                         dimensions: (image.width(), image.height()),
+                        colors: options.max_colors,
                     };
                     cache.insert(entry);
 
-                    let _ = render_sixel_to_area(area, &sixel_data);
+                    pending_sixel.queue(area, &sixel_data);
                     return;
                 }
             }
@@ -568,6 +794,14 @@ fn render_dual_controls(frame: &mut Frame, area: Rect, comparison: &ComparisonSt
         Span::raw(" Switch Pane  "),
         Span::styled("m", theme.highlight()),
         Span::raw(" Change Model  "),
+        Span::styled("e", theme.highlight()),
+        Span::raw(" Edit Prompt  "),
+        Span::styled("p", theme.highlight()),
+        Span::raw(if comparison.per_pane_prompts {
+            " Shared Prompt  "
+        } else {
+            " Per-pane Prompts  "
+        }),
         Span::styled(
             "Enter",
             if can_run {
@@ -583,7 +817,17 @@ fn render_dual_controls(frame: &mut Frame, area: Rect, comparison: &ComparisonSt
         }),
         Span::raw("  "),
         Span::styled("r", theme.highlight()),
-        Span::raw(" Reset"),
+        Span::raw(" Reset  "),
+        Span::styled("P", theme.highlight()),
+        Span::raw(" Pin Model  "),
+        Span::styled("1/2", theme.highlight()),
+        Span::raw(" Regenerate Pane  "),
+        Span::styled("o", theme.highlight()),
+        Span::raw(" Difference  "),
+        Span::styled("Ctrl+N", theme.highlight()),
+        Span::raw(" New Prompt  "),
+        Span::styled("Ctrl+S/O", theme.highlight()),
+        Span::raw(" Save/Load Setup"),
     ]);
 
     let paragraph = Paragraph::new(controls).block(
@@ -602,6 +846,7 @@ fn render_multi_comparison(
     area: Rect,
     comparison: &ComparisonState,
     theme: &AppTheme,
+    spinner: &str,
 ) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -631,7 +876,7 @@ fn render_multi_comparison(
     render_prompt_input(frame, chunks[2], comparison, theme);
 
     // Results area
-    render_multi_results(frame, chunks[3], comparison, theme);
+    render_multi_results(frame, chunks[3], comparison, spinner, theme);
 }
 
 fn render_multi_model_selection(
@@ -681,13 +926,19 @@ fn render_multi_model_selection(
     frame.render_widget(paragraph, area);
 }
 
-fn render_multi_results(frame: &mut Frame, area: Rect, comparison: &ComparisonState, theme: &AppTheme) {
+fn render_multi_results(
+    frame: &mut Frame,
+    area: Rect,
+    comparison: &ComparisonState,
+    spinner: &str,
+    theme: &AppTheme,
+) {
     if comparison.is_running {
         let lines = vec![
             Line::from(""),
             Line::from(""),
             Line::from(Span::styled(
-                "Generating images with all models...",
+                format!("{} Generating images with all models...", spinner),
                 theme.highlight(),
             )),
             Line::from(""),
@@ -4,27 +4,105 @@
 //! WS-06: Image asset loading integration.
 //! T9: Sixel rendering support for high-quality terminal image display.
 //! Displays a grid of generated images with detail view and navigation.
+//! Focus mode drops all chrome and renders the selected image fullscreen.
+//! The metadata editor replaces the body with a form for the image's sidecar.
+//! Zoom view has its own render system, [`render_gallery_zoom`]. Sixel images
+//! are queued in [`PendingSixelWrites`] and written after the frame is drawn.
+//! The selected image's most common colors are shown as swatches under the
+//! preview (`ui.palette_colors`).
 
 use bevy::prelude::*;
 use bevy_ratatui::terminal::RatatuiContext;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::Modifier,
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Widget},
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
     Frame,
 };
+use std::path::{Path, PathBuf};
 use tracing::{debug, warn};
 
 use crate::bevy_app::components::PreviewImage;
-use crate::bevy_app::resources::{AppTheme, CurrentScreen, GalleryState, Screen, SettingsState};
+use crate::bevy_app::resources::{
+    AppTheme, CropRect, CropSelection, CurrentScreen, GalleryState, GalleryZoom, MetadataForm,
+    Screen, SettingsState, TagEntryMode, METADATA_FIELDS, THUMBNAIL_WINDOW,
+};
+use crate::bevy_app::systems::assets::crop::{image_with_crop_outline, zoomed_window};
+use crate::bevy_app::systems::assets::palette::{dominant_colors, Rgb};
+use crate::bevy_app::systems::assets::sidecar::{ImageSidecar, SidecarCache};
 use crate::bevy_app::systems::assets::render::{
     calculate_ascii_dimensions, render_image_placeholder, render_image_to_unicode,
 };
 use crate::bevy_app::systems::assets::{
-    SixelPreviewCache, SixelRenderOptions, render_image_sixel, supports_sixel,
+    SixelPreviewCache, SixelRenderOptions, get_or_render_iterm, render_image_sixel,
+    supports_iterm, supports_sixel, CELL_PIXELS,
 };
-use crate::bevy_app::systems::render::sixel_utils::render_sixel_to_area;
+use crate::bevy_app::systems::render::layout::grid_cell_layout;
+use crate::bevy_app::systems::render::sixel_utils::{clear_all_sixel, PendingSixelWrites};
+use crate::bevy_app::systems::render::widgets::{indeterminate_progress_bar, truncate_with_ellipsis};
+
+/// Number of columns in the gallery grid view.
+const GRID_COLUMNS: usize = 3;
+
+/// Height of one grid cell in terminal rows (including border and label).
+const GRID_CELL_HEIGHT: u16 = 8;
+
+/// Fullscreen Sixel kept outside the shared preview cache.
+///
+/// The shared cache holds one (panel-sized) render per path, so focus mode
+/// keeps its own full-frame render and re-encodes when the image or the
+/// terminal size changes.
+#[derive(Default)]
+pub struct FocusSixel {
+    /// Focus mode state on the previous frame (to clear stale Sixel on toggle)
+    was_focused: bool,
+    /// Metadata editor state on the previous frame (the form covers the preview too)
+    was_editing: bool,
+    /// Zoom view state on the previous frame (drawn by `render_gallery_zoom`)
+    was_zoomed: bool,
+    /// Last fullscreen render: (image path, area size, Sixel data)
+    rendered: Option<(PathBuf, (u16, u16), String)>,
+}
+
+/// Crop mode preview with the selection outlined.
+///
+/// Re-rendered whenever the image, selection or panel size changes.
+#[derive(Default)]
+pub struct CropPreview {
+    /// Outlined copy of the image: (image path, selection, image)
+    outlined: Option<(PathBuf, CropRect, Image)>,
+    /// Last Sixel render: (area size, Sixel data) for the outlined image
+    sixel: Option<((u16, u16), String)>,
+}
+
+/// Dominant colors of the selected image.
+///
+/// Counted again only when the image asset or the number of colors changes.
+#[derive(Default)]
+pub struct PaletteSwatches {
+    /// Image asset the colors were counted for
+    image: Option<AssetId<Image>>,
+    /// Number of colors asked for
+    n: usize,
+    /// Most common colors, most common first
+    colors: Vec<(Rgb, usize)>,
+}
+
+impl PaletteSwatches {
+    /// The `n` most common colors of `image` (asset `id`).
+    fn colors(&mut self, id: AssetId<Image>, image: &Image, n: usize) -> &[(Rgb, usize)] {
+        if self.image != Some(id) || self.n != n {
+            self.image = Some(id);
+            self.n = n;
+            self.colors = dominant_colors(image, n);
+        }
+        &self.colors
+    }
+}
+
+/// Width of one palette swatch: color block, hex code and spacing.
+const SWATCH_WIDTH: u16 = 13;
 
 /// Main gallery screen render system.
 ///
@@ -33,6 +111,7 @@ use crate::bevy_app::systems::render::sixel_utils::render_sixel_to_area;
 /// - Right panel (30%): Thumbnail list of all images
 ///
 /// Supports both Sixel (high-quality) and Unicode fallback rendering.
+/// In focus mode the selected image takes the entire frame instead.
 pub fn render_gallery_screen(
     current_screen: Res<CurrentScreen>,
     gallery: Res<GalleryState>,
@@ -42,6 +121,12 @@ pub fn render_gallery_screen(
     images: Option<Res<Assets<Image>>>,
     asset_server: Option<Res<AssetServer>>,
     sixel_cache: Option<Res<SixelPreviewCache>>,
+    sidecars: Option<Res<SidecarCache>>,
+    zoom: Option<Res<GalleryZoom>>,
+    mut focus_sixel: Local<FocusSixel>,
+    mut crop_preview: Local<CropPreview>,
+    mut palette: Local<PaletteSwatches>,
+    mut pending_sixel: ResMut<PendingSixelWrites>,
     mut ratatui: ResMut<RatatuiContext>,
 ) {
     // Only render when on Gallery screen
@@ -49,6 +134,30 @@ pub fn render_gallery_screen(
         return;
     }
 
+    // Sixel output lives outside ratatui's buffer, so wipe it when the layout flips
+    if focus_sixel.was_focused != gallery.focus_mode {
+        if let Err(e) = clear_all_sixel() {
+            warn!("Failed to clear Sixel on focus mode change: {}", e);
+        }
+        focus_sixel.was_focused = gallery.focus_mode;
+    }
+    if focus_sixel.was_editing != gallery.is_editing_metadata() {
+        if let Err(e) = clear_all_sixel() {
+            warn!("Failed to clear Sixel on metadata editor toggle: {}", e);
+        }
+        focus_sixel.was_editing = gallery.is_editing_metadata();
+    }
+    let zoomed = zoom.is_some_and(|zoom| zoom.active);
+    if focus_sixel.was_zoomed != zoomed {
+        if let Err(e) = clear_all_sixel() {
+            warn!("Failed to clear Sixel on zoom view toggle: {}", e);
+        }
+        focus_sixel.was_zoomed = zoomed;
+    }
+    if zoomed {
+        return;
+    }
+
     ratatui
         .draw(|frame| {
             // Create main layout (header + body + status bar handled by dispatch)
@@ -56,6 +165,23 @@ pub fn render_gallery_screen(
 
             if gallery.is_empty() {
                 render_empty_gallery(frame, area, &theme);
+            } else if let Some(form) = &gallery.metadata {
+                render_metadata_editor(frame, area, form, gallery.current_image(), &theme);
+            } else if gallery.focus_mode {
+                render_focus_view(
+                    frame,
+                    area,
+                    &gallery,
+                    &theme,
+                    &settings,
+                    &preview_query,
+                    images.as_deref(),
+                    asset_server.as_deref(),
+                    &mut focus_sixel,
+                    &mut pending_sixel,
+                );
+            } else if gallery.grid_view {
+                render_thumbnail_grid(frame, area, &gallery, &theme, &settings, sidecars.as_deref());
             } else if let (Some(images), Some(asset_server)) = (images.as_ref(), asset_server.as_ref()) {
                 render_gallery_body(
                     frame,
@@ -67,6 +193,10 @@ pub fn render_gallery_screen(
                     images,
                     asset_server,
                     sixel_cache.as_deref(),
+                    sidecars.as_deref(),
+                    &mut crop_preview,
+                    &mut palette,
+                    &mut pending_sixel,
                 );
             } else {
                 // Assets not loaded yet, show loading message
@@ -76,6 +206,141 @@ pub fn render_gallery_screen(
         .ok(); // Ignore render errors for now
 }
 
+/// Last zoom view Sixel: (image path, zoom state, area size, Sixel data).
+#[derive(Default)]
+pub struct ZoomSixel {
+    rendered: Option<(PathBuf, GalleryZoom, (u16, u16), String)>,
+}
+
+/// Zoom view render system.
+///
+/// Runs only while [`GalleryZoom`] is active (the regular gallery render
+/// stands down). Shows the visible window of the selected image across the
+/// frame, with a one-line status bar for zoom level and position. The
+/// offset is clamped here, where the image and viewport sizes are known.
+#[allow(clippy::too_many_arguments)]
+pub fn render_gallery_zoom(
+    current_screen: Res<CurrentScreen>,
+    gallery: Res<GalleryState>,
+    mut zoom: ResMut<GalleryZoom>,
+    theme: Res<AppTheme>,
+    settings: Res<SettingsState>,
+    preview_query: Query<&PreviewImage>,
+    images: Option<Res<Assets<Image>>>,
+    asset_server: Option<Res<AssetServer>>,
+    mut zoom_sixel: Local<ZoomSixel>,
+    mut pending_sixel: ResMut<PendingSixelWrites>,
+    mut ratatui: ResMut<RatatuiContext>,
+) {
+    if current_screen.0 != Screen::Gallery {
+        return;
+    }
+
+    ratatui
+        .draw(|frame| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(1)])
+                .split(frame.area());
+            let (area, status_area) = (chunks[0], chunks[1]);
+
+            let Some(selected_path) = gallery.current_image() else {
+                render_empty_gallery(frame, frame.area(), &theme);
+                return;
+            };
+
+            let image = match (images.as_deref(), asset_server.as_deref()) {
+                (Some(images), Some(asset_server)) => {
+                    loaded_image(selected_path, &preview_query, images, asset_server)
+                }
+                _ => Err(None),
+            };
+            let image = match image {
+                Ok((image, _)) => image,
+                Err(error) => {
+                    let lines =
+                        preview_placeholder(selected_path, error.as_deref(), &preview_query, area, &theme);
+                    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), area);
+                    return;
+                }
+            };
+
+            let image_size = (image.width(), image.height());
+            let viewport = (
+                area.width as u32 * CELL_PIXELS.0,
+                area.height as u32 * CELL_PIXELS.1,
+            );
+            // Only write through on a real change, so change detection stays quiet
+            let mut clamped = zoom.clone();
+            clamped.clamp_offset(image_size, viewport);
+            if clamped != *zoom {
+                *zoom = clamped;
+            }
+
+            let status = format!(
+                " Zoom {:.2}x  {}x{} at ({}, {})  |  arrows: pan  +/-: zoom  z/Esc: exit ",
+                zoom.scale,
+                image_size.0,
+                image_size.1,
+                zoom.offset_x,
+                zoom.offset_y
+            );
+            frame.render_widget(Paragraph::new(Span::styled(status, theme.muted())), status_area);
+
+            let window = zoomed_window(image, &zoom, viewport);
+
+            if settings.ui.show_image_previews && supports_sixel() {
+                let size = (area.width, area.height);
+                let cached = matches!(
+                    &zoom_sixel.rendered,
+                    Some((path, cached_zoom, cached_size, _))
+                        if path == selected_path && *cached_zoom == *zoom && *cached_size == size
+                );
+
+                if !cached {
+                    let options = SixelRenderOptions {
+                        width: area.width,
+                        height: area.height,
+                        preserve_aspect: true,
+                        high_quality: false,
+                        background: settings.ui.sixel_background,
+                        max_colors: settings.ui.sixel_colors as usize,
+                    };
+                    // A smaller window won't cover the previous render, so clear it first
+                    if let Err(e) = clear_all_sixel() {
+                        warn!("Failed to clear Sixel before zoom redraw: {}", e);
+                    }
+                    match render_image_sixel(&window, &options) {
+                        Ok(data) => {
+                            zoom_sixel.rendered =
+                                Some((selected_path.clone(), zoom.clone(), size, data))
+                        }
+                        Err(e) => {
+                            warn!("Zoom view Sixel rendering failed, falling back to Unicode: {}", e);
+                            zoom_sixel.rendered = None;
+                        }
+                    }
+                }
+
+                if let Some((_, _, _, data)) = &zoom_sixel.rendered {
+                    frame.render_widget(SixelImageWidget::new(data, &mut pending_sixel), area);
+                    return;
+                }
+            }
+
+            let (width, height) = calculate_ascii_dimensions(
+                window.width(),
+                window.height(),
+                area.width,
+                area.height,
+            );
+            let options = settings.unicode_render_options();
+            let lines = render_image_to_unicode(&window, width, height, &options);
+            frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), area);
+        })
+        .ok();
+}
+
 /// Render empty gallery placeholder.
 fn render_empty_gallery(frame: &mut Frame, area: Rect, theme: &AppTheme) {
     let lines = vec![
@@ -105,6 +370,51 @@ fn render_empty_gallery(frame: &mut Frame, area: Rect, theme: &AppTheme) {
     frame.render_widget(paragraph, area);
 }
 
+/// Render the metadata editor form for the selected image.
+fn render_metadata_editor(
+    frame: &mut Frame,
+    area: Rect,
+    form: &MetadataForm,
+    path: Option<&PathBuf>,
+    theme: &AppTheme,
+) {
+    let name = path
+        .and_then(|p| p.file_name())
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let mut lines = vec![Line::from("")];
+    for (i, label) in METADATA_FIELDS.iter().enumerate() {
+        let selected = i == form.selected;
+        let marker = if selected { "▸ " } else { "  " };
+        let cursor = if selected { "_" } else { "" };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}{:<10} ", marker, label), theme.muted()),
+            Span::styled(
+                format!("{}{}", form.values[i], cursor),
+                if selected { theme.input_active() } else { theme.text() },
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    if let Some(error) = &form.error {
+        lines.push(Line::from(Span::styled(format!("  {}", error), theme.error())));
+    }
+    lines.push(Line::from(Span::styled(
+        "  Tab/↑↓: Field  Enter: Save  Esc: Cancel  (tags are comma-separated)",
+        theme.muted(),
+    )));
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(format!(" Metadata: {} ", name))
+            .borders(Borders::ALL)
+            .border_style(theme.highlight()),
+    );
+    frame.render_widget(paragraph, area);
+}
+
 /// Render loading placeholder when assets aren't ready yet.
 fn render_loading_gallery(frame: &mut Frame, area: Rect, theme: &AppTheme) {
     let lines = vec![
@@ -136,20 +446,16 @@ fn render_gallery_body(
     images: &Assets<Image>,
     asset_server: &AssetServer,
     sixel_cache: Option<&SixelPreviewCache>,
+    sidecars: Option<&SidecarCache>,
+    crop_preview: &mut CropPreview,
+    palette: &mut PaletteSwatches,
+    pending_sixel: &mut PendingSixelWrites,
 ) {
-    // Split into preview (left) and thumbnail list (right)
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(70), // Main preview
-            Constraint::Percentage(30), // Thumbnail list
-        ])
-        .margin(1)
-        .split(area);
+    let (preview_area, list_area) = gallery_layout(area, false);
 
     render_main_preview(
         frame,
-        chunks[0],
+        preview_area,
         gallery,
         theme,
         settings,
@@ -157,11 +463,254 @@ fn render_gallery_body(
         images,
         asset_server,
         sixel_cache,
+        sidecars,
+        crop_preview,
+        palette,
+        pending_sixel,
     );
-    render_thumbnail_list(frame, chunks[1], gallery, theme);
+    if let Some(list_area) = list_area {
+        // Generation details under the list when the image has a sidecar
+        let details = gallery
+            .current_image()
+            .and_then(|path| sidecars?.get(path))
+            .map(|metadata| metadata_lines(metadata, theme));
+        match details {
+            Some(details) => {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Min(5),
+                        Constraint::Length(details.len() as u16 + 2),
+                    ])
+                    .split(list_area);
+                render_thumbnail_list(frame, chunks[0], gallery, theme);
+                render_metadata_details(frame, chunks[1], details, theme);
+            }
+            None => render_thumbnail_list(frame, list_area, gallery, theme),
+        }
+    }
+}
+
+/// Lines describing how an image was generated (only the fields it has).
+fn metadata_lines(metadata: &ImageSidecar, theme: &AppTheme) -> Vec<Line<'static>> {
+    let field = |label: &str, value: String| {
+        Line::from(vec![
+            Span::styled(format!("{:<9}", label), theme.muted()),
+            Span::styled(value, theme.text()),
+        ])
+    };
+
+    let mut lines = Vec::new();
+    if let Some(prompt) = &metadata.prompt {
+        lines.push(field("Prompt", prompt.replace('\n', " ")));
+    }
+    if let Some(negative) = &metadata.negative_prompt {
+        lines.push(field("Negative", negative.clone()));
+    }
+    if let Some(model) = &metadata.model {
+        lines.push(field("Model", model.clone()));
+    }
+    if let Some(seed) = metadata.seed {
+        lines.push(field("Seed", seed.to_string()));
+    }
+    if let Some(steps) = metadata.steps {
+        lines.push(field("Steps", steps.to_string()));
+    }
+    if let Some(cfg_scale) = metadata.cfg_scale {
+        lines.push(field("CFG", format!("{:.1}", cfg_scale)));
+    }
+    if let Some(timestamp) = metadata.timestamp {
+        let local = timestamp.with_timezone(&chrono::Local);
+        lines.push(field("Created", local.format("%Y-%m-%d %H:%M").to_string()));
+    }
+    if let Some(sweep_id) = &metadata.sweep_id {
+        lines.push(field("Sweep", sweep_id.clone()));
+    }
+    if !metadata.tags.is_empty() {
+        lines.push(field("Tags", metadata.tags.join(", ")));
+    }
+    lines
+}
+
+/// Render the selected image's generation details.
+fn render_metadata_details(frame: &mut Frame, area: Rect, lines: Vec<Line<'static>>, theme: &AppTheme) {
+    let paragraph = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .title(" Details ")
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        );
+    frame.render_widget(paragraph, area);
+}
+
+/// Compute the preview area and (outside focus mode) the thumbnail list area.
+///
+/// Focus mode hands the whole frame to the preview; otherwise the frame is
+/// split 70/30 into preview and thumbnail list.
+fn gallery_layout(area: Rect, focus_mode: bool) -> (Rect, Option<Rect>) {
+    if focus_mode {
+        return (area, None);
+    }
+
+    // Split into preview (left) and thumbnail list (right)
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(70), // Main preview
+            Constraint::Percentage(30), // Thumbnail list
+        ])
+        .margin(1)
+        .split(area);
+
+    (chunks[0], Some(chunks[1]))
+}
+
+/// Render the selected image across the whole frame (focus mode).
+///
+/// No borders, titles or lists; the Sixel is re-encoded at full frame size
+/// for maximum detail, with the Unicode renderer as fallback.
+#[allow(clippy::too_many_arguments)]
+fn render_focus_view(
+    frame: &mut Frame,
+    area: Rect,
+    gallery: &GalleryState,
+    theme: &AppTheme,
+    settings: &SettingsState,
+    preview_query: &Query<&PreviewImage>,
+    images: Option<&Assets<Image>>,
+    asset_server: Option<&AssetServer>,
+    focus_sixel: &mut FocusSixel,
+    pending_sixel: &mut PendingSixelWrites,
+) {
+    let (area, _) = gallery_layout(area, true);
+
+    let Some(selected_path) = gallery.current_image() else {
+        return;
+    };
+
+    let image = match (images, asset_server) {
+        (Some(images), Some(asset_server)) => {
+            loaded_image(selected_path, preview_query, images, asset_server)
+        }
+        _ => Err(None),
+    };
+
+    let image = match image {
+        Ok((image, _)) => image,
+        Err(error) => {
+            let lines = preview_placeholder(selected_path, error.as_deref(), preview_query, area, theme);
+            let paragraph = Paragraph::new(lines).alignment(Alignment::Center);
+            frame.render_widget(paragraph, area);
+            return;
+        }
+    };
+
+    if settings.ui.show_image_previews && supports_sixel() {
+        let size = (area.width, area.height);
+        let cached = matches!(
+            &focus_sixel.rendered,
+            Some((path, cached_size, _)) if path == selected_path && *cached_size == size
+        );
+
+        if !cached {
+            let options = SixelRenderOptions {
+                width: area.width,
+                height: area.height,
+                preserve_aspect: true,
+                high_quality: true,
+                background: settings.ui.sixel_background,
+                max_colors: settings.ui.sixel_colors as usize,
+            };
+            match render_image_sixel(image, &options) {
+                Ok(data) => focus_sixel.rendered = Some((selected_path.clone(), size, data)),
+                Err(e) => {
+                    warn!("Focus mode Sixel rendering failed, falling back to Unicode: {}", e);
+                    focus_sixel.rendered = None;
+                }
+            }
+        }
+
+        if let Some((_, _, data)) = &focus_sixel.rendered {
+            frame.render_widget(SixelImageWidget::new(data, pending_sixel), area);
+            return;
+        }
+    }
+
+    let lines = render_unicode_preview(image, area, settings, theme);
+    let paragraph = Paragraph::new(lines).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
 }
 
-/// Render main preview panel.
+/// Placeholder for an image that can't be shown yet.
+///
+/// While loading, shows a progress bar with the time since the load started;
+/// otherwise the error from [`loaded_image`].
+fn preview_placeholder(
+    path: &Path,
+    error: Option<&str>,
+    preview_query: &Query<&PreviewImage>,
+    area: Rect,
+    theme: &AppTheme,
+) -> Vec<Line<'static>> {
+    if error.is_some() {
+        return render_image_placeholder(path, error);
+    }
+
+    let preview = preview_query.iter().find(|p| p.path == path);
+    loading_lines(path, preview, area.width, theme)
+}
+
+/// Loading placeholder lines: filename, progress bar and elapsed load time.
+fn loading_lines(
+    path: &Path,
+    preview: Option<&PreviewImage>,
+    width: u16,
+    theme: &AppTheme,
+) -> Vec<Line<'static>> {
+    let mut lines = render_image_placeholder(path, None);
+    if let Some(elapsed) = preview.and_then(PreviewImage::load_elapsed) {
+        // Replace the static "Loading..." with a live indicator
+        lines.pop();
+        let bar_width = (width / 2).clamp(4, 30);
+        lines.push(Line::from(Span::styled("Loading image...", theme.muted())));
+        lines.push(indeterminate_progress_bar(elapsed, bar_width, theme));
+    }
+    lines
+}
+
+/// Look up the loaded image asset (and its handle) for a gallery path.
+///
+/// `Err(None)` means the image is still loading; `Err(Some(msg))` carries a
+/// reason suitable for the placeholder.
+fn loaded_image<'a>(
+    path: &Path,
+    preview_query: &'a Query<&PreviewImage>,
+    images: &'a Assets<Image>,
+    asset_server: &AssetServer,
+) -> Result<(&'a Image, &'a Handle<Image>), Option<String>> {
+    let preview = preview_query
+        .iter()
+        .find(|p| p.path == path)
+        .ok_or_else(|| Some("Preview not loaded".to_string()))?;
+
+    let handle = preview
+        .asset_handle
+        .as_ref()
+        .ok_or_else(|| Some("No asset handle".to_string()))?;
+
+    match asset_server.load_state(handle) {
+        bevy::asset::LoadState::Loaded => images
+            .get(handle)
+            .map(|image| (image, handle))
+            .ok_or_else(|| Some("Image asset not found".to_string())),
+        bevy::asset::LoadState::Failed(err) => Err(Some(err.to_string())),
+        _ => Err(None),
+    }
+}
+
+/// Render main preview panel, with the palette swatches below it.
 fn render_main_preview(
     frame: &mut Frame,
     area: Rect,
@@ -172,69 +721,82 @@ fn render_main_preview(
     images: &Assets<Image>,
     asset_server: &AssetServer,
     sixel_cache: Option<&SixelPreviewCache>,
+    sidecars: Option<&SidecarCache>,
+    crop_preview: &mut CropPreview,
+    palette: &mut PaletteSwatches,
+    pending_sixel: &mut PendingSixelWrites,
 ) {
-    let block = Block::default()
-        .title(" Preview ")
-        .borders(Borders::ALL)
-        .border_style(theme.border());
+    let n = settings.ui.palette_colors as usize;
+    let colors = match gallery.current_image() {
+        Some(path) if n > 0 => loaded_image(path, preview_query, images, asset_server)
+            .ok()
+            .map(|(image, handle)| palette.colors(handle.id(), image, n)),
+        _ => None,
+    };
+    let area = match colors {
+        Some(colors) if !colors.is_empty() => {
+            let lines = palette_lines(colors, area.width.saturating_sub(2));
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(5), Constraint::Length(lines.len() as u16 + 2)])
+                .split(area);
+            render_palette(frame, chunks[1], lines, theme);
+            chunks[0]
+        }
+        _ => area,
+    };
+
+    let mut block = match &gallery.crop {
+        Some(crop) => Block::default()
+            .title(crop_title(crop))
+            .borders(Borders::ALL)
+            .border_style(theme.highlight()),
+        None => Block::default()
+            .title(" Preview ")
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    };
+
+    // Badge sits on the border so it never overlaps the (Sixel) image
+    if let Some(badge) = gallery_badge(gallery.current_image(), sidecars, settings, theme) {
+        block = block.title(badge);
+    }
 
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
     if let Some(selected_path) = gallery.current_image() {
-        // Find PreviewImage component for this path
-        let preview = preview_query
-            .iter()
-            .find(|p| &p.path == selected_path);
-
-        if let Some(preview) = preview {
-            // Check if image asset is loaded
-            if let Some(handle) = &preview.asset_handle {
-                match asset_server.load_state(handle) {
-                    bevy::asset::LoadState::Loaded => {
-                        // Image is loaded, render it
-                        if let Some(image) = images.get(handle) {
-                            render_image_with_sixel_support(
-                                frame,
-                                inner,
-                                image,
-                                handle.clone(),
-                                selected_path,
-                                theme,
-                                settings,
-                                sixel_cache,
-                            );
-                        } else {
-                            let lines = render_image_placeholder(
-                                selected_path,
-                                Some("Image asset not found"),
-                            );
-                            let paragraph = Paragraph::new(lines).alignment(Alignment::Center);
-                            frame.render_widget(paragraph, inner);
-                        }
-                    }
-                    bevy::asset::LoadState::Failed(err) => {
-                        let lines = render_image_placeholder(selected_path, Some(&err.to_string()));
-                        let paragraph = Paragraph::new(lines).alignment(Alignment::Center);
-                        frame.render_widget(paragraph, inner);
-                    }
-                    _ => {
-                        // Still loading
-                        let lines = render_image_placeholder(selected_path, None);
-                        let paragraph = Paragraph::new(lines).alignment(Alignment::Center);
-                        frame.render_widget(paragraph, inner);
-                    }
-                }
-            } else {
-                let lines = render_image_placeholder(selected_path, Some("No asset handle"));
+        match loaded_image(selected_path, preview_query, images, asset_server) {
+            Ok((image, handle)) => match &gallery.crop {
+                Some(crop) => render_crop_preview(
+                    frame,
+                    inner,
+                    image,
+                    selected_path,
+                    crop.rect,
+                    theme,
+                    settings,
+                    crop_preview,
+                    pending_sixel,
+                ),
+                None => render_image_with_sixel_support(
+                    frame,
+                    inner,
+                    image,
+                    handle.clone(),
+                    selected_path,
+                    theme,
+                    settings,
+                    sixel_cache,
+                    pending_sixel,
+                ),
+            },
+            Err(error) => {
+                let lines =
+                    preview_placeholder(selected_path, error.as_deref(), preview_query, inner, theme);
                 let paragraph = Paragraph::new(lines).alignment(Alignment::Center);
                 frame.render_widget(paragraph, inner);
             }
-        } else {
-            // No PreviewImage component found
-            let lines = render_image_placeholder(selected_path, Some("Preview not loaded"));
-            let paragraph = Paragraph::new(lines).alignment(Alignment::Center);
-            frame.render_widget(paragraph, inner);
         }
     } else {
         let lines = vec![
@@ -248,7 +810,122 @@ fn render_main_preview(
     }
 }
 
+/// Rows of color swatches (colored block and hex code) fitting `width`.
+fn palette_lines(colors: &[(Rgb, usize)], width: u16) -> Vec<Line<'static>> {
+    let per_row = (width / SWATCH_WIDTH).max(1) as usize;
+    colors
+        .chunks(per_row)
+        .map(|row| {
+            let spans = row.iter().flat_map(|(color, _)| {
+                [
+                    Span::styled("   ", Style::default().bg(Color::Rgb(color.0, color.1, color.2))),
+                    Span::raw(format!(" {}  ", color.hex())),
+                ]
+            });
+            Line::from(spans.collect::<Vec<_>>())
+        })
+        .collect()
+}
+
+/// Render the palette swatches under the preview.
+fn render_palette(frame: &mut Frame, area: Rect, lines: Vec<Line<'static>>, theme: &AppTheme) {
+    let paragraph = Paragraph::new(lines).style(theme.text()).block(
+        Block::default()
+            .title(" Palette ")
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Badge text for an image's generation time (e.g. "⏱ 4.2s").
+fn generation_time_badge(duration_s: Option<f32>) -> Option<String> {
+    duration_s.map(|d| format!(" ⏱ {:.1}s ", d))
+}
+
+/// Right-aligned generation time badge for a gallery image, if enabled and known.
+fn gallery_badge(
+    path: Option<&PathBuf>,
+    sidecars: Option<&SidecarCache>,
+    settings: &SettingsState,
+    theme: &AppTheme,
+) -> Option<Line<'static>> {
+    if !settings.ui.gallery_show_generation_time {
+        return None;
+    }
+
+    let duration = path.and_then(|p| sidecars?.duration(p));
+    generation_time_badge(duration)
+        .map(|badge| Line::from(Span::styled(badge, theme.muted())).alignment(Alignment::Right))
+}
+
+/// Panel title describing the crop selection and its keys.
+fn crop_title(crop: &CropSelection) -> String {
+    format!(
+        " Crop {}x{} at ({}, {}) - Arrows: Move  Shift+Arrows: Resize  Enter: Save  Esc: Cancel ",
+        crop.rect.width, crop.rect.height, crop.rect.x, crop.rect.y
+    )
+}
+
+/// Render the image with the crop selection outlined (Sixel or Unicode).
+#[allow(clippy::too_many_arguments)]
+fn render_crop_preview(
+    frame: &mut Frame,
+    area: Rect,
+    image: &Image,
+    path: &Path,
+    rect: CropRect,
+    theme: &AppTheme,
+    settings: &SettingsState,
+    crop_preview: &mut CropPreview,
+    pending_sixel: &mut PendingSixelWrites,
+) {
+    let stale = !matches!(
+        &crop_preview.outlined,
+        Some((cached_path, cached_rect, _)) if cached_path == path && *cached_rect == rect
+    );
+    if stale {
+        let outlined = image_with_crop_outline(image, rect);
+        crop_preview.outlined = Some((path.to_path_buf(), rect, outlined));
+        crop_preview.sixel = None;
+    }
+    let Some((_, _, outlined)) = &crop_preview.outlined else {
+        return;
+    };
+
+    if settings.ui.show_image_previews && supports_sixel() {
+        let size = (area.width, area.height);
+        if !matches!(&crop_preview.sixel, Some((cached_size, _)) if *cached_size == size) {
+            let options = SixelRenderOptions {
+                width: area.width.saturating_sub(4),
+                height: area.height.saturating_sub(4),
+                preserve_aspect: true,
+                high_quality: false,
+                background: settings.ui.sixel_background,
+                max_colors: settings.ui.sixel_colors as usize,
+            };
+            crop_preview.sixel = match render_image_sixel(outlined, &options) {
+                Ok(data) => Some((size, data)),
+                Err(e) => {
+                    warn!("Crop preview Sixel rendering failed, falling back to Unicode: {}", e);
+                    None
+                }
+            };
+        }
+
+        if let Some((_, data)) = &crop_preview.sixel {
+            frame.render_widget(SixelImageWidget::new(data, pending_sixel), area);
+            return;
+        }
+    }
+
+    let lines = render_unicode_preview(outlined, area, settings, theme);
+    let paragraph = Paragraph::new(lines).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
 /// Render image with Sixel support if available, fallback to Unicode.
+#[allow(clippy::too_many_arguments)]
 fn render_image_with_sixel_support(
     frame: &mut Frame,
     area: Rect,
@@ -258,7 +935,30 @@ fn render_image_with_sixel_support(
     theme: &AppTheme,
     settings: &SettingsState,
     sixel_cache: Option<&SixelPreviewCache>,
+    pending_sixel: &mut PendingSixelWrites,
 ) {
+    // iTerm2 inline images first, then Sixel, then Unicode blocks
+    if settings.ui.show_image_previews && supports_iterm() {
+        if let Some(cache) = sixel_cache {
+            match get_or_render_iterm(
+                path,
+                (image.width(), image.height()),
+                area.width.saturating_sub(4),
+                area.height.saturating_sub(4),
+                cache,
+            ) {
+                Ok(data) => frame.render_widget(SixelImageWidget::new(&data, pending_sixel), area),
+                Err(e) => {
+                    // Too large or unreadable: show it as Unicode blocks instead
+                    debug!("No iTerm2 preview for {:?}: {:#}", path, e);
+                    let lines = render_unicode_preview(image, area, settings, theme);
+                    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), area);
+                }
+            }
+            return;
+        }
+    }
+
     // Check if Sixel is enabled and supported
     let use_sixel = settings.ui.show_image_previews && supports_sixel();
 
@@ -269,25 +969,25 @@ fn render_image_with_sixel_support(
             handle,
             path,
             area,
-            theme,
+            settings,
             sixel_cache.unwrap(),
         ) {
             Ok(sixel_data) => {
                 // Render Sixel widget
-                let sixel_widget = SixelImageWidget::new(&sixel_data);
+                let sixel_widget = SixelImageWidget::new(&sixel_data, pending_sixel);
                 frame.render_widget(sixel_widget, area);
                 debug!("Rendered Sixel preview for {:?}", path);
             }
             Err(e) => {
                 warn!("Sixel rendering failed, falling back to Unicode: {}", e);
-                let lines = render_unicode_preview(image, area, theme);
+                let lines = render_unicode_preview(image, area, settings, theme);
                 let paragraph = Paragraph::new(lines).alignment(Alignment::Center);
                 frame.render_widget(paragraph, area);
             }
         }
     } else {
         // Fall back to Unicode block characters
-        let lines = render_unicode_preview(image, area, theme);
+        let lines = render_unicode_preview(image, area, settings, theme);
         let paragraph = Paragraph::new(lines).alignment(Alignment::Center);
         frame.render_widget(paragraph, area);
     }
@@ -299,11 +999,11 @@ fn render_sixel_preview(
     _handle: Handle<Image>,
     path: &std::path::Path,
     area: Rect,
-    _theme: &AppTheme,
+    settings: &SettingsState,
     cache: &SixelPreviewCache,
 ) -> anyhow::Result<String> {
     // Check cache first
-    if let Some(entry) = cache.get(path) {
+    if let Some(entry) = cache.get(path, settings.ui.sixel_colors as usize) {
         debug!("Sixel cache hit: {:?}", path);
         return Ok(entry.sixel_data);
     }
@@ -314,6 +1014,8 @@ fn render_sixel_preview(
         height: area.height.saturating_sub(4),
         preserve_aspect: true,
         high_quality: true,
+        background: settings.ui.sixel_background,
+        max_colors: settings.ui.sixel_colors as usize,
     };
 
     let sixel_data = render_image_sixel(image, &options)?;
@@ -325,6 +1027,7 @@ fn render_sixel_preview(
         size_bytes: sixel_data.len(),
         last_access: std::time::Instant::now(),
         dimensions: (image.width(), image.height()),
+        colors: options.max_colors,
     };
 
     cache.insert(entry);
@@ -333,7 +1036,12 @@ fn render_sixel_preview(
 }
 
 /// Render Unicode block character preview (fallback).
-fn render_unicode_preview(image: &Image, area: Rect, theme: &AppTheme) -> Vec<Line<'static>> {
+fn render_unicode_preview(
+    image: &Image,
+    area: Rect,
+    settings: &SettingsState,
+    theme: &AppTheme,
+) -> Vec<Line<'static>> {
     // Calculate dimensions for Unicode rendering
     let (width, height) = calculate_ascii_dimensions(
         image.width(),
@@ -343,7 +1051,8 @@ fn render_unicode_preview(image: &Image, area: Rect, theme: &AppTheme) -> Vec<Li
     );
 
     // Render image as Unicode block characters
-    let mut lines = render_image_to_unicode(image, width, height);
+    let mut lines =
+        render_image_to_unicode(image, width, height, &settings.unicode_render_options());
 
     // Add image info at bottom
     lines.push(Line::from(""));
@@ -356,34 +1065,77 @@ fn render_unicode_preview(image: &Image, area: Rect, theme: &AppTheme) -> Vec<Li
 }
 
 /// Sixel image widget for ratatui.
+///
+/// Queues the image instead of writing it, so it reaches the terminal after
+/// ratatui has flushed the frame rather than in the middle of it.
 struct SixelImageWidget<'a> {
     sixel_data: &'a str,
+    pending: &'a mut PendingSixelWrites,
 }
 
 impl<'a> SixelImageWidget<'a> {
-    fn new(sixel_data: &'a str) -> Self {
-        Self { sixel_data }
+    fn new(sixel_data: &'a str, pending: &'a mut PendingSixelWrites) -> Self {
+        Self { sixel_data, pending }
     }
 }
 
 impl<'a> Widget for SixelImageWidget<'a> {
     fn render(self, area: Rect, _buf: &mut ratatui::buffer::Buffer) {
-        debug!("SixelImageWidget rendering at {:?}", area);
-        debug!("Sixel data length: {} bytes", self.sixel_data.len());
+        debug!("SixelImageWidget queued at {:?} ({} bytes)", area, self.sixel_data.len());
+        self.pending.queue(area, self.sixel_data);
+    }
+}
 
-        // Use utility function for proper rendering and clearing
-        if let Err(e) = render_sixel_to_area(area, self.sixel_data) {
-            warn!("Failed to render Sixel to area {:?}: {}", area, e);
-        } else {
-            debug!("Sixel data rendered successfully to area {:?}", area);
-        }
+/// Marks favorite images in the list and grid.
+const FAVORITE_GLYPH: &str = "★";
+
+/// Marks images picked for export in the list.
+const PICKED_GLYPH: &str = "✓";
+
+/// Title for the image list/grid panel.
+///
+/// Shows the tag prompt while one is open, the active filters and how many
+/// images are picked for export.
+fn gallery_panel_title(gallery: &GalleryState, suffix: &str) -> String {
+    if let Some(entry) = &gallery.tag_entry {
+        let label = match entry.mode {
+            TagEntryMode::Add => "Add tag",
+            TagEntryMode::Filter => "Filter by tag",
+            TagEntryMode::Export => "Export to folder",
+        };
+        return format!(" {}: {}_ ", label, entry.text);
     }
+
+    let mut filters = Vec::new();
+    if gallery.favorites_only {
+        filters.push(format!("{} favorites", FAVORITE_GLYPH));
+    }
+    if let Some(tag) = &gallery.filter_tag {
+        filters.push(format!("tag: {}", tag));
+    }
+    let mut counts = if filters.is_empty() {
+        gallery.len().to_string()
+    } else {
+        format!("{} of {}, {}", gallery.visible_indices().len(), gallery.len(), filters.join(", "))
+    };
+    if !gallery.selected_set.is_empty() {
+        counts.push_str(&format!(", {} selected", gallery.selected_set.len()));
+    }
+    format!(" Images ({}){} ", counts, suffix)
+}
+
+/// Range of list positions to show: `window` of them, centred on `position`
+/// where possible and shifted at either end so the window stays full.
+fn thumbnail_window(position: usize, len: usize, window: usize) -> (usize, usize) {
+    let window = window.min(len);
+    let start = position.saturating_sub(window / 2).min(len - window);
+    (start, start + window)
 }
 
 /// Render thumbnail list panel.
 fn render_thumbnail_list(frame: &mut Frame, area: Rect, gallery: &GalleryState, theme: &AppTheme) {
     let block = Block::default()
-        .title(format!(" Images ({}) ", gallery.len()))
+        .title(gallery_panel_title(gallery, ""))
         .borders(Borders::ALL)
         .border_style(theme.border());
 
@@ -392,18 +1144,32 @@ fn render_thumbnail_list(frame: &mut Frame, area: Rect, gallery: &GalleryState,
 
     let mut lines = Vec::new();
 
-    // Show up to 10 images around the selected one
-    let start_idx = gallery.selected.saturating_sub(5);
-    let end_idx = (gallery.selected + 5).min(gallery.len());
+    // Show a window of images around the selected one (of those the filter shows)
+    let visible = gallery.visible_indices();
+    let (start, end) = thumbnail_window(gallery.visible_position(), visible.len(), THUMBNAIL_WINDOW);
+
+    if let (true, Some(tag)) = (visible.is_empty(), &gallery.filter_tag) {
+        lines.push(Line::from(Span::styled(
+            format!("No images tagged {:?}", tag),
+            theme.muted(),
+        )));
+    } else if visible.is_empty() && gallery.favorites_only {
+        lines.push(Line::from(Span::styled(
+            "No favorites yet (* stars an image)",
+            theme.muted(),
+        )));
+    }
 
-    for idx in start_idx..end_idx {
+    for &idx in &visible[start..end] {
         if let Some(path) = gallery.images.get(idx) {
             let filename = path
                 .file_name()
                 .and_then(|n| n.to_str())
                 .unwrap_or("unknown");
 
-            let prefix = if idx == gallery.selected { "> " } else { "  " };
+            let cursor = if idx == gallery.selected { ">" } else { " " };
+            let picked = if gallery.is_in_selected_set(path) { PICKED_GLYPH } else { " " };
+            let prefix = format!("{}{}", cursor, picked);
 
             let style = if idx == gallery.selected {
                 theme.highlight()
@@ -411,18 +1177,16 @@ fn render_thumbnail_list(frame: &mut Frame, area: Rect, gallery: &GalleryState,
                 theme.text()
             };
 
-            // Truncate filename if too long
-            let max_len = (inner.width as usize).saturating_sub(3);
-            let display_name = if filename.len() > max_len {
-                format!("{}...", &filename[..max_len.saturating_sub(3)])
-            } else {
-                filename.to_string()
-            };
+            // Truncate filename if too long (leaving room for the star)
+            let favorite = gallery.is_favorite(path);
+            let max_len = (inner.width as usize).saturating_sub(if favorite { 5 } else { 3 });
+            let display_name = truncate_with_ellipsis(filename, max_len);
 
-            lines.push(Line::from(vec![
-                Span::styled(prefix, style),
-                Span::styled(display_name, style),
-            ]));
+            let mut spans = vec![Span::styled(prefix, style), Span::styled(display_name, style)];
+            if favorite {
+                spans.push(Span::styled(format!(" {}", FAVORITE_GLYPH), theme.warning()));
+            }
+            lines.push(Line::from(spans));
         }
     }
 
@@ -437,12 +1201,138 @@ fn render_thumbnail_list(frame: &mut Frame, area: Rect, gallery: &GalleryState,
             "d: Delete",
             theme.muted().add_modifier(Modifier::ITALIC),
         )));
+        if !gallery.trash.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "u: Undo delete",
+                theme.muted().add_modifier(Modifier::ITALIC),
+            )));
+        }
+        lines.push(Line::from(Span::styled(
+            "+: Tag  /: Filter",
+            theme.muted().add_modifier(Modifier::ITALIC),
+        )));
+        lines.push(Line::from(Span::styled(
+            "*: Favorite  F: Favorites",
+            theme.muted().add_modifier(Modifier::ITALIC),
+        )));
     }
 
     let paragraph = Paragraph::new(lines);
     frame.render_widget(paragraph, inner);
 }
 
+/// Render the gallery as a grid of cells, scrolled to keep the selection visible.
+fn render_thumbnail_grid(
+    frame: &mut Frame,
+    area: Rect,
+    gallery: &GalleryState,
+    theme: &AppTheme,
+    settings: &SettingsState,
+    sidecars: Option<&SidecarCache>,
+) {
+    let block = Block::default()
+        .title(gallery_panel_title(gallery, " - Grid"))
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+
+    // Cells hold the images the tag filter shows, in gallery order
+    let visible = gallery.visible_indices();
+    let position = gallery.visible_position();
+    let visible_rows = ((inner.height / GRID_CELL_HEIGHT) as usize).max(1);
+    let first_row = (position / GRID_COLUMNS).saturating_sub(visible_rows - 1);
+    let cell_width = inner.width / GRID_COLUMNS as u16;
+
+    for row in 0..visible_rows {
+        let y = inner.y + row as u16 * GRID_CELL_HEIGHT;
+        let height = GRID_CELL_HEIGHT.min(inner.bottom().saturating_sub(y));
+
+        for col in 0..GRID_COLUMNS {
+            let Some(&idx) = visible.get((first_row + row) * GRID_COLUMNS + col) else {
+                return;
+            };
+            let Some(path) = gallery.images.get(idx) else {
+                return;
+            };
+
+            let cell = Rect::new(inner.x + col as u16 * cell_width, y, cell_width, height);
+            render_grid_cell(frame, cell, path, idx, idx == gallery.selected, theme, settings);
+
+            // Star in the cell's top-left corner (inside the border, if any)
+            if gallery.is_favorite(path) {
+                let inset = u16::from(settings.ui.gallery_show_borders);
+                if cell.width > inset * 2 && cell.height > inset * 2 {
+                    let corner = Rect::new(cell.x + inset, cell.y + inset, 1, 1);
+                    frame.render_widget(Span::styled(FAVORITE_GLYPH, theme.warning()), corner);
+                }
+            }
+
+            // Badge goes on the cell's top border, so it needs one
+            if settings.ui.gallery_show_borders && cell.width > 2 {
+                if let Some(badge) = gallery_badge(Some(path), sidecars, settings, theme) {
+                    let border = Rect::new(cell.x + 1, cell.y, cell.width - 2, 1);
+                    frame.render_widget(Paragraph::new(badge), border);
+                }
+            }
+        }
+    }
+}
+
+/// Render a single grid cell: optional border, image area and optional filename label.
+fn render_grid_cell(
+    frame: &mut Frame,
+    cell: Rect,
+    path: &Path,
+    idx: usize,
+    is_selected: bool,
+    theme: &AppTheme,
+    settings: &SettingsState,
+) {
+    let show_borders = settings.ui.gallery_show_borders;
+    let show_labels = settings.ui.gallery_show_labels;
+
+    let style = if is_selected {
+        theme.highlight()
+    } else {
+        theme.border()
+    };
+
+    if show_borders {
+        frame.render_widget(Block::default().borders(Borders::ALL).border_style(style), cell);
+    }
+
+    let (image_area, label_area) = grid_cell_layout(cell, show_borders, show_labels);
+
+    let marker = Paragraph::new(vec![
+        Line::from(""),
+        Line::from(Span::styled(format!("#{}", idx + 1), style)),
+    ])
+    .alignment(Alignment::Center);
+    frame.render_widget(marker, image_area);
+
+    if let Some(label_area) = label_area {
+        let filename = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown");
+        let label = truncate_with_ellipsis(filename, label_area.width as usize);
+        let label_style = if is_selected {
+            theme.highlight()
+        } else {
+            theme.muted()
+        };
+
+        let paragraph = Paragraph::new(Span::styled(label, label_style)).alignment(Alignment::Center);
+        frame.render_widget(paragraph, label_area);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -466,6 +1356,21 @@ mod tests {
         assert_eq!(gallery.selected, 0);
     }
 
+    #[test]
+    fn test_thumbnail_window_keeps_selection_visible() {
+        assert_eq!(thumbnail_window(10, 20, 10), (5, 15));
+        // Full window at either end
+        assert_eq!(thumbnail_window(0, 20, 10), (0, 10));
+        assert_eq!(thumbnail_window(19, 20, 10), (10, 20));
+        // Fewer images than the window
+        assert_eq!(thumbnail_window(2, 4, 10), (0, 4));
+        assert_eq!(thumbnail_window(0, 0, 10), (0, 0));
+        for position in 0..20 {
+            let (start, end) = thumbnail_window(position, 20, 10);
+            assert!((start..end).contains(&position));
+        }
+    }
+
     #[test]
     fn test_thumbnail_window_calculation() {
         let mut gallery = GalleryState::default();
@@ -512,4 +1417,165 @@ mod tests {
         assert_eq!(start_idx, 14);
         assert_eq!(end_idx, 20); // Clamped to gallery length
     }
+
+    #[test]
+    fn test_generation_time_badge_from_sidecar() {
+        use crate::bevy_app::systems::assets::ImageSidecar;
+        use ratatui::buffer::Buffer;
+
+        let timed = PathBuf::from("outputs/timed.png");
+        let untimed = PathBuf::from("outputs/untimed.png");
+        let mut sidecars = SidecarCache::default();
+        sidecars.insert(
+            timed.clone(),
+            ImageSidecar {
+                duration_s: Some(4.2),
+                ..Default::default()
+            },
+        );
+        let settings = SettingsState::default();
+        let theme = AppTheme::default();
+
+        let render = |path: &PathBuf| {
+            let area = Rect::new(0, 0, 30, 3);
+            let mut buf = Buffer::empty(area);
+            let mut block = Block::default().borders(Borders::ALL);
+            if let Some(badge) = gallery_badge(Some(path), Some(&sidecars), &settings, &theme) {
+                block = block.title(badge);
+            }
+            block.render(area, &mut buf);
+            (0..area.width)
+                .map(|x| buf[(x, 0)].symbol().to_string())
+                .collect::<String>()
+        };
+
+        assert!(render(&timed).contains("4.2s"));
+        assert!(!render(&untimed).contains('⏱'));
+        assert_eq!(generation_time_badge(None), None);
+    }
+
+    #[test]
+    fn test_loading_preview_shows_progress_and_elapsed() {
+        let path = PathBuf::from("outputs/big.png");
+        let mut preview = PreviewImage::new(path.clone());
+        preview.load_started = std::time::Instant::now().checked_sub(std::time::Duration::from_secs(3));
+
+        let text: String = loading_lines(&path, Some(&preview), 40, &AppTheme::default())
+            .iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(text.contains("Loading image..."));
+        assert!(text.contains('█'));
+        assert!(text.contains("3.0s"));
+    }
+
+    #[test]
+    fn test_focus_mode_uses_full_frame() {
+        let frame = Rect::new(0, 0, 120, 40);
+
+        let (preview, list) = gallery_layout(frame, true);
+        assert_eq!(preview, frame);
+        assert!(list.is_none());
+    }
+
+    #[test]
+    fn test_exiting_focus_mode_restores_split_layout() {
+        let frame = Rect::new(0, 0, 120, 40);
+        let mut gallery = GalleryState::default();
+        gallery.add_image(PathBuf::from("/test/img1.png"));
+
+        gallery.enter_focus_mode();
+        let (focused, _) = gallery_layout(frame, gallery.focus_mode);
+        assert_eq!(focused, frame);
+
+        gallery.exit_focus_mode();
+        let (preview, list) = gallery_layout(frame, gallery.focus_mode);
+        let list = list.expect("thumbnail list should be back");
+
+        assert!(preview.width < frame.width);
+        assert!(preview.x < list.x);
+        assert_eq!(preview.height, list.height);
+        assert_eq!(preview.height, frame.height - 2); // margin(1)
+    }
+
+    #[test]
+    fn test_details_panel_shows_sidecar_prompt() {
+        let metadata = ImageSidecar {
+            prompt: Some("pixel art knight".to_string()),
+            seed: Some(42),
+            model: Some("sd_xl_base_1.0".to_string()),
+            ..Default::default()
+        };
+        let theme = AppTheme::default();
+        let lines = metadata_lines(&metadata, &theme);
+        // Only the fields the sidecar has
+        assert_eq!(lines.len(), 3);
+
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(40, 6)).unwrap();
+        terminal
+            .draw(|frame| render_metadata_details(frame, frame.area(), lines, &theme))
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        assert!(text.contains("Details"));
+        assert!(text.contains("Prompt   pixel art knight"));
+        assert!(text.contains("Seed     42"));
+    }
+
+    #[test]
+    fn test_favorites_are_starred() {
+        let mut gallery = GalleryState::default();
+        gallery.add_image(PathBuf::from("/test/knight.png"));
+        gallery.add_image(PathBuf::from("/test/slime.png"));
+        gallery.toggle_favorite().unwrap();
+        let theme = AppTheme::default();
+
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(40, 12)).unwrap();
+        let mut draw = |gallery: &GalleryState| {
+            terminal
+                .draw(|frame| render_thumbnail_list(frame, frame.area(), gallery, &theme))
+                .unwrap();
+            terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect::<String>()
+        };
+
+        let text = draw(&gallery);
+        assert!(text.contains("knight.png ★"));
+        assert!(text.contains("slime.png"));
+        assert!(!text.contains("slime.png ★"));
+
+        gallery.toggle_favorites_only();
+        let text = draw(&gallery);
+        assert!(text.contains("1 of 2, ★ favorites"));
+        assert!(!text.contains("slime.png"));
+    }
+
+    #[test]
+    fn test_sixel_is_queued_then_flushed() {
+        let mut app = App::new();
+        app.init_resource::<PendingSixelWrites>();
+        app.add_systems(Update, crate::bevy_app::systems::render::flush_pending_sixel);
+
+        let mut terminal =
+            ratatui::Terminal::new(ratatui::backend::TestBackend::new(1, 1)).unwrap();
+        let mut pending = app.world_mut().resource_mut::<PendingSixelWrites>();
+        terminal
+            .draw(|frame| {
+                frame.render_widget(SixelImageWidget::new("", &mut pending), frame.area())
+            })
+            .unwrap();
+        // Drawing only queues; nothing reaches the terminal mid-frame
+        assert_eq!(pending.len(), 1);
+
+        app.update();
+        assert!(app.world().resource::<PendingSixelWrites>().is_empty());
+    }
 }
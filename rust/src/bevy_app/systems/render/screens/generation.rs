@@ -7,16 +7,21 @@ use bevy::prelude::*;
 use bevy_ratatui::terminal::RatatuiContext;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Modifier,
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
 use crate::bevy_app::{
     components::{Job, JobStatus, PreviewImage},
-    resources::{AppState, AppTheme, CurrentScreen, GalleryState, InputBuffer, Screen, SettingsState},
-    systems::assets::{SixelPreviewCache, SixelRenderOptions, render_image_sixel, supports_sixel},
-    systems::render::{sixel_utils::render_sixel_to_area, widgets::{progress_bar_with_eta}},
+    resources::{
+        AppState, AppTheme, CurrentScreen, GalleryState, GenParamField, GenParams, InputBuffer,
+        PromptPresets, Screen, SeedMode, SeedSweep, SettingsState, Strings, SweepField,
+    },
+    systems::assets::{SixelPreviewCache, SixelRenderOptions, get_or_render_iterm, render_image_sixel, supports_iterm, supports_sixel},
+    systems::render::{sixel_utils::PendingSixelWrites, widgets::{indeterminate_progress_bar, progress_bar_with_eta, prompt_diff_line, text_area_lines}},
+    systems::spinner::Spinner,
 };
 
 /// Render the Generation screen.
@@ -27,6 +32,7 @@ pub fn render_generation_screen(
     current_screen: Res<CurrentScreen>,
     input_buffer: Res<InputBuffer>,
     theme: Res<AppTheme>,
+    strings: Res<Strings>,
     app_state: Res<AppState>,
     gallery: Res<GalleryState>,
     settings: Res<SettingsState>,
@@ -34,11 +40,18 @@ pub fn render_generation_screen(
     preview_query: Query<&PreviewImage>,
     images: Option<Res<Assets<Image>>>,
     asset_server: Option<Res<AssetServer>>,
-    sixel_cache: Option<Res<SixelPreviewCache>>,
+    // Sixel previews, queued to be written after the frame is drawn
+    (sixel_cache, mut pending_sixel): (Option<Res<SixelPreviewCache>>, ResMut<PendingSixelWrites>),
+    // Dialogs drawn over the screen (grouped to stay within Bevy's parameter limit)
+    (presets, sweep): (Option<Res<PromptPresets>>, Option<Res<SeedSweep>>),
+    spinner: Option<Res<Spinner>>,
+    gen_params: Option<Res<GenParams>>,
 ) {
     if current_screen.0 != Screen::Generation {
         return;
     }
+    let gen_params = gen_params.as_deref().cloned().unwrap_or_default();
+    let spinner = spinner.as_deref().copied().unwrap_or_default().frame();
 
     trace!("Rendering generation screen");
 
@@ -47,6 +60,7 @@ pub fn render_generation_screen(
             frame,
             &input_buffer,
             &theme,
+            &strings,
             &app_state,
             &gallery,
             &settings,
@@ -55,7 +69,16 @@ pub fn render_generation_screen(
             images.as_deref(),
             asset_server.as_deref(),
             sixel_cache.as_deref(),
+            &mut pending_sixel,
+            spinner,
+            &gen_params,
         );
+        if let Some(presets) = presets.as_deref().filter(|p| p.picker_open) {
+            render_preset_picker(frame, frame.area(), presets, &theme, &strings);
+        }
+        if let Some(sweep) = sweep.as_deref().filter(|s| s.open) {
+            render_seed_sweep_dialog(frame, frame.area(), sweep, &theme, &strings);
+        }
     }) {
         error!("Failed to render generation screen: {:?}", e);
     }
@@ -66,6 +89,7 @@ fn render_frame(
     frame: &mut Frame,
     input_buffer: &InputBuffer,
     theme: &AppTheme,
+    strings: &Strings,
     app_state: &AppState,
     gallery: &GalleryState,
     settings: &SettingsState,
@@ -74,33 +98,186 @@ fn render_frame(
     images: Option<&Assets<Image>>,
     asset_server: Option<&AssetServer>,
     sixel_cache: Option<&SixelPreviewCache>,
+    pending_sixel: &mut PendingSixelWrites,
+    spinner: &str,
+    gen_params: &GenParams,
 ) {
+    // The negative prompt box appears once it's focused or has text (Ctrl+N)
+    let show_negative = input_buffer.negative_focused || !input_buffer.negative_buffer.is_empty();
+
+    let mut constraints = vec![Constraint::Length(prompt_box_height(input_buffer))]; // Prompt input
+    if show_negative {
+        constraints.push(Constraint::Length(3)); // Negative prompt
+    }
+    constraints.extend([
+        Constraint::Length(3), // Options row
+        Constraint::Min(8),    // Main content (controls + preview)
+        Constraint::Length(6), // Recent generations
+    ]);
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(5), // Prompt input
-            Constraint::Length(3), // Options row
-            Constraint::Min(8),    // Main content (controls + preview)
-            Constraint::Length(6), // Recent generations
-        ])
+        .constraints(constraints)
         .margin(1)
         .split(frame.area());
+    // Chunks after the prompt (and negative prompt, when shown)
+    let body = if show_negative { 2 } else { 1 };
 
-    render_prompt_input(frame, chunks[0], input_buffer, theme);
-    render_options_row(frame, chunks[1], theme);
+    let last_prompt = app_state
+        .last_submitted_prompt
+        .as_deref()
+        .filter(|_| settings.ui.show_prompt_diff);
+    render_prompt_input(frame, chunks[0], input_buffer, last_prompt, theme, strings);
+    if show_negative {
+        render_negative_prompt_input(frame, chunks[1], input_buffer, theme, strings);
+    }
+    render_options_row(frame, chunks[body], theme);
     render_main_content(
         frame,
-        chunks[2],
+        chunks[body + 1],
         app_state,
         settings,
         theme,
+        strings,
         jobs,
         preview_query,
         images,
         asset_server,
         sixel_cache,
+        pending_sixel,
+        spinner,
+        gen_params,
+    );
+    render_recent_generations(frame, chunks[body + 2], gallery, theme, strings);
+}
+
+/// Render the prompt preset picker centered over the screen.
+fn render_preset_picker(
+    frame: &mut Frame,
+    area: Rect,
+    presets: &PromptPresets,
+    theme: &AppTheme,
+    strings: &Strings,
+) {
+    let width = 60.min(area.width.saturating_sub(4)).max(10).min(area.width);
+    let height = (presets.presets.len().max(1) as u16 + 2).min(12).min(area.height);
+    let popup = Rect::new(
+        area.x + area.width.saturating_sub(width) / 2,
+        area.y + area.height.saturating_sub(height) / 3,
+        width,
+        height,
+    );
+
+    let visible = height.saturating_sub(2) as usize;
+    // Keep the selection in view
+    let first = presets.selected.saturating_sub(visible.saturating_sub(1));
+    let mut lines: Vec<Line> = presets
+        .presets
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(visible)
+        .map(|(i, preset)| {
+            let selected = i == presets.selected;
+            let marker = if selected { "> " } else { "  " };
+            let name_style = if selected { theme.highlight() } else { theme.text() };
+            Line::from(vec![
+                Span::styled(format!("{}{}", marker, preset.name), name_style),
+                Span::raw("  "),
+                Span::styled(preset.template.as_str(), theme.muted()),
+            ])
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            strings.t("generation.no_presets"),
+            theme.muted(),
+        )));
+    }
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .title(strings.t("generation.presets_title"))
+                .borders(Borders::ALL)
+                .border_style(theme.input_active()),
+        ),
+        popup,
+    );
+}
+
+/// Render the seed sweep dialog centered over the screen.
+fn render_seed_sweep_dialog(
+    frame: &mut Frame,
+    area: Rect,
+    sweep: &SeedSweep,
+    theme: &AppTheme,
+    strings: &Strings,
+) {
+    let width = 44.min(area.width);
+    let height = 7.min(area.height);
+    let popup = Rect::new(
+        area.x + area.width.saturating_sub(width) / 2,
+        area.y + area.height.saturating_sub(height) / 3,
+        width,
+        height,
+    );
+
+    let field = |label: &str, value: String, which: SweepField| {
+        let selected = sweep.field == which;
+        let marker = if selected { "> " } else { "  " };
+        let style = if selected { theme.highlight() } else { theme.text() };
+        Line::from(vec![
+            Span::styled(format!("{}{:<11}", marker, label), theme.muted()),
+            Span::styled(value, style),
+        ])
+    };
+    let seeds = match sweep.base_seed.parse::<u64>() {
+        Ok(base) => format!(
+            "  Seeds {} to {}",
+            base,
+            base.wrapping_add(sweep.count as u64 - 1)
+        ),
+        Err(_) => String::new(),
+    };
+    let lines = vec![
+        field("Jobs", format!("◀ {} ▶", sweep.count), SweepField::Count),
+        field("Base seed", sweep.base_seed.clone(), SweepField::BaseSeed),
+        match &sweep.error {
+            Some(error) => Line::from(Span::styled(format!("  {}", error), theme.error())),
+            None => Line::from(Span::styled(seeds, theme.muted())),
+        },
+        Line::from(""),
+        Line::from(Span::styled(
+            "  Enter: Submit  Tab: Field  Esc: Cancel",
+            theme.muted(),
+        )),
+    ];
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .title(strings.t("generation.sweep_title"))
+                .borders(Borders::ALL)
+                .border_style(theme.input_active()),
+        ),
+        popup,
     );
-    render_recent_generations(frame, chunks[3], gallery, theme);
+}
+
+/// Height of the prompt box: 5 rows normally, growing with multi-line prompts.
+fn prompt_box_height(input_buffer: &InputBuffer) -> u16 {
+    const MIN_HEIGHT: u16 = 5;
+    const MAX_HEIGHT: u16 = 10;
+
+    if !input_buffer.is_multiline() {
+        return MIN_HEIGHT;
+    }
+
+    // Lines plus top/bottom border
+    (input_buffer.line_count() as u16 + 2).clamp(MIN_HEIGHT, MAX_HEIGHT)
 }
 
 /// Render prompt input field with cursor.
@@ -108,29 +285,88 @@ fn render_prompt_input(
     frame: &mut Frame,
     area: Rect,
     input_buffer: &InputBuffer,
+    last_prompt: Option<&str>,
     theme: &AppTheme,
+    strings: &Strings,
 ) {
-    let block = Block::default()
-        .title(" Prompt ")
+    let title = strings.t("generation.prompt_title");
+    let title = if input_buffer.is_multiline() {
+        format!(
+            "{}({} lines, Ctrl+Enter: new line) ",
+            title,
+            input_buffer.line_count()
+        )
+    } else {
+        title.to_string()
+    };
+
+    let mut block = Block::default()
+        .title(title)
         .borders(Borders::ALL)
         .border_style(theme.border());
 
+    // Show what changed since the last submission along the bottom border
+    if let Some(last) = last_prompt {
+        let edited = !input_buffer.text.trim().is_empty()
+            && !input_buffer.text.split_whitespace().eq(last.split_whitespace());
+        if edited {
+            block = block.title_bottom(prompt_diff_line(last, &input_buffer.text, theme));
+        }
+    }
+
     let inner = block.inner(area);
 
-    let prompt_text = if input_buffer.text.is_empty() {
-        Span::styled("Enter your prompt here...", theme.muted())
-    } else {
-        Span::styled(&input_buffer.text, theme.text())
-    };
+    // Keep the cursor line visible when the prompt is taller than the box
+    let (cursor_line, cursor_col) = input_buffer.cursor_line_col();
+    let scroll = (cursor_line as u16).saturating_sub(inner.height.saturating_sub(1));
 
-    let paragraph = Paragraph::new(prompt_text).block(block);
+    let lines = text_area_lines(
+        &input_buffer.text,
+        strings.t("generation.prompt_placeholder"),
+        theme,
+    );
+    let paragraph = Paragraph::new(lines).block(block).scroll((scroll, 0));
     frame.render_widget(paragraph, area);
 
     // Show cursor at current position
-    if inner.width > 0 && inner.height > 0 {
-        let cursor_x = inner.x + (input_buffer.cursor as u16).min(inner.width - 1);
-        let cursor_y = inner.y;
-        frame.set_cursor(cursor_x, cursor_y);
+    if inner.width > 0 && inner.height > 0 && !input_buffer.negative_focused {
+        let cursor_x = inner.x + (cursor_col as u16).min(inner.width - 1);
+        let cursor_y = inner.y + (cursor_line as u16 - scroll).min(inner.height - 1);
+        frame.set_cursor_position((cursor_x, cursor_y));
+    }
+}
+
+/// Render the single-line negative prompt field.
+fn render_negative_prompt_input(
+    frame: &mut Frame,
+    area: Rect,
+    input_buffer: &InputBuffer,
+    theme: &AppTheme,
+    strings: &Strings,
+) {
+    let border = if input_buffer.negative_focused {
+        theme.input_active()
+    } else {
+        theme.border()
+    };
+    let block = Block::default()
+        .title(strings.t("generation.negative_title"))
+        .borders(Borders::ALL)
+        .border_style(border);
+    let inner = block.inner(area);
+
+    let lines = text_area_lines(
+        &input_buffer.negative_buffer,
+        strings.t("generation.negative_placeholder"),
+        theme,
+    );
+    // Scroll horizontally so the cursor stays visible
+    let scroll_x = (input_buffer.negative_cursor as u16).saturating_sub(inner.width.saturating_sub(1));
+    frame.render_widget(Paragraph::new(lines).block(block).scroll((0, scroll_x)), area);
+
+    if input_buffer.negative_focused && inner.width > 0 && inner.height > 0 {
+        let cursor_x = inner.x + (input_buffer.negative_cursor as u16 - scroll_x);
+        frame.set_cursor_position((cursor_x, inner.y));
     }
 }
 
@@ -153,11 +389,15 @@ fn render_main_content(
     app_state: &AppState,
     settings: &SettingsState,
     theme: &AppTheme,
+    strings: &Strings,
     jobs: &Query<&Job>,
     preview_query: &Query<&PreviewImage>,
     images: Option<&Assets<Image>>,
     asset_server: Option<&AssetServer>,
     sixel_cache: Option<&SixelPreviewCache>,
+    pending_sixel: &mut PendingSixelWrites,
+    spinner: &str,
+    gen_params: &GenParams,
 ) {
     let main_chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -167,41 +407,62 @@ fn render_main_content(
         ])
         .split(area);
 
-    render_controls(frame, main_chunks[0], app_state, theme, jobs);
+    render_controls(
+        frame,
+        main_chunks[0],
+        app_state,
+        settings,
+        gen_params,
+        theme,
+        strings,
+        jobs,
+        spinner,
+    );
     render_preview(
         frame,
         main_chunks[1],
         app_state,
         settings,
         theme,
+        strings,
         preview_query,
         images,
         asset_server,
         sixel_cache,
+        pending_sixel,
     );
 }
 
 /// Render generation controls and active job status with progress bar.
+#[allow(clippy::too_many_arguments)]
 fn render_controls(
     frame: &mut Frame,
     area: Rect,
     app_state: &AppState,
+    settings: &SettingsState,
+    gen_params: &GenParams,
     theme: &AppTheme,
+    strings: &Strings,
     jobs: &Query<&Job>,
+    spinner: &str,
 ) {
+    let defaults = &settings.generation;
     let mut lines = vec![
-        Line::from(vec![
-            Span::raw("Steps:       "),
-            Span::styled("30", theme.text()),
-        ]),
-        Line::from(vec![
-            Span::raw("CFG Scale:   "),
-            Span::styled("7.5", theme.text()),
-        ]),
-        Line::from(vec![
-            Span::raw("Seed:        "),
-            Span::styled("Random", theme.muted()),
-        ]),
+        param_line(
+            "Steps:       ",
+            gen_params.steps_or(defaults).to_string(),
+            GenParamField::Steps,
+            gen_params,
+            theme,
+        ),
+        param_line(
+            "CFG Scale:   ",
+            format!("{:.1}", gen_params.cfg_scale_or(defaults)),
+            GenParamField::CfgScale,
+            gen_params,
+            theme,
+        ),
+        seed_line(app_state, theme),
         Line::from(vec![
             Span::raw("Batch Size:  "),
             Span::styled("1", theme.text()),
@@ -216,11 +477,15 @@ fn render_controls(
     if let Some(job) = active_jobs.first() {
         lines.push(Line::from(""));
         lines.push(Line::from(vec![
+            Span::styled(format!("{} ", spinner), theme.highlight()),
             Span::styled("Active Job: ", theme.highlight()),
             Span::styled(&job.prompt[..job.prompt.len().min(30)], theme.text()),
         ]));
 
         match &job.status {
+            JobStatus::QueuedLocally => {
+                lines.push(Line::from(Span::styled("Status: Queued locally", theme.muted())));
+            }
             JobStatus::Pending => {
                 lines.push(Line::from(Span::styled("Status: Pending", theme.muted())));
             }
@@ -248,6 +513,12 @@ fn render_controls(
                 let progress_bar_width = 30; // Adjust based on available width
                 let progress_line = progress_bar_with_eta(*progress, progress_bar_width, *eta_s, theme);
                 lines.push(progress_line);
+                if job.stalled {
+                    lines.push(Line::from(Span::styled(
+                        "stalled? (no progress from the backend)",
+                        theme.warning(),
+                    )));
+                }
             }
             JobStatus::Complete { duration_s, .. } => {
                 lines.push(Line::from(vec![
@@ -272,21 +543,26 @@ fn render_controls(
     // Show cancel button if there's an active cancellable job, otherwise show generate
     if has_cancellable_job {
         lines.push(Line::from(vec![
-            Span::styled(" [X] Cancel Job ", theme.button()),
+            Span::styled(strings.t("generation.cancel_button"), theme.button()),
             Span::raw("  "),
-            Span::styled(" [C]ompare Models ", theme.button()),
+            Span::styled(strings.t("generation.compare_button"), theme.button()),
         ]));
     } else {
-        lines.push(Line::from(vec![
-            Span::styled(" [G]enerate ", theme.button()),
+        let mut buttons = vec![
+            Span::styled(strings.t("generation.generate_button"), theme.button()),
             Span::raw("  "),
-            Span::styled(" [C]ompare Models ", theme.button()),
-        ]));
+            Span::styled(strings.t("generation.compare_button"), theme.button()),
+        ];
+        if app_state.last_submission.is_some() {
+            buttons.push(Span::raw("  "));
+            buttons.push(Span::styled(strings.t("generation.regenerate_button"), theme.button()));
+        }
+        lines.push(Line::from(buttons));
     }
 
     let paragraph = Paragraph::new(lines).block(
         Block::default()
-            .title(" Generation Options ")
+            .title(strings.t("generation.options_title"))
             .borders(Borders::ALL)
             .border_style(theme.border()),
     );
@@ -294,6 +570,44 @@ fn render_controls(
     frame.render_widget(paragraph, area);
 }
 
+/// A steps / CFG scale row, highlighted while it's the one being adjusted.
+fn param_line(
+    label: &'static str,
+    value: String,
+    field: GenParamField,
+    gen_params: &GenParams,
+    theme: &AppTheme,
+) -> Line<'static> {
+    if gen_params.selected == Some(field) {
+        Line::from(vec![
+            Span::styled(label, theme.highlight()),
+            Span::styled(value, theme.highlight().add_modifier(Modifier::REVERSED)),
+            Span::styled(" (+/- adjust)", theme.muted()),
+        ])
+    } else {
+        Line::from(vec![Span::raw(label), Span::styled(value, theme.text())])
+    }
+}
+
+/// The controls' seed row: the locked seed, or "Random" plus the last one used.
+fn seed_line(app_state: &AppState, theme: &AppTheme) -> Line<'static> {
+    let mut spans = vec![Span::raw("Seed:        ")];
+    match app_state.seed_mode {
+        SeedMode::Fixed(seed) => {
+            spans.push(Span::styled(seed.to_string(), theme.text()));
+            spans.push(Span::styled(" (locked, [/] adjust)", theme.muted()));
+        }
+        SeedMode::Random => {
+            spans.push(Span::styled("Random", theme.muted()));
+            if let Some(seed) = app_state.last_seed {
+                spans.push(Span::styled(format!(" (last: {})", seed), theme.muted()));
+            }
+        }
+    }
+    spans.push(Span::styled(" [Alt+S]", theme.muted()));
+    Line::from(spans)
+}
+
 /// Render preview area with tabs support for debug mode.
 fn render_preview(
     frame: &mut Frame,
@@ -301,16 +615,24 @@ fn render_preview(
     app_state: &AppState,
     settings: &SettingsState,
     theme: &AppTheme,
+    strings: &Strings,
     preview_query: &Query<&PreviewImage>,
     images: Option<&Assets<Image>>,
     asset_server: Option<&AssetServer>,
     sixel_cache: Option<&SixelPreviewCache>,
+    pending_sixel: &mut PendingSixelWrites,
 ) {
     // Create title with tab support if debug mode
     let title_string = if app_state.debug_mode {
-        let tab_titles = vec!["Preview", "Backend Logs"];
+        let follow = if app_state.is_following_logs() { "following" } else { "paused" };
+        let logs_title = if app_state.preview_tab == 1 {
+            format!("{} Logs [{}]", app_state.log_source.label(), follow)
+        } else {
+            format!("{} Logs", app_state.log_source.label())
+        };
+        let tab_titles = ["Preview", logs_title.as_str()];
         format!(
-            " {} [Ctrl+Tab/P/L] ",
+            " {} [Ctrl+Tab/P/L, Ctrl+L: Source] ",
             tab_titles
                 .iter()
                 .enumerate()
@@ -322,8 +644,10 @@ fn render_preview(
                 .collect::<Vec<_>>()
                 .join(" │ ")
         )
+    } else if app_state.pin_preview {
+        " Preview [Alt+P: Unpin] ".to_string()
     } else {
-        " Preview ".to_string()
+        " Preview [Alt+P: Pin] ".to_string()
     };
 
     let block = Block::default()
@@ -336,19 +660,69 @@ fn render_preview(
 
     // Render content based on selected tab
     if app_state.debug_mode && app_state.preview_tab == 1 {
-        render_backend_logs(frame, inner, app_state, theme);
+        render_logs(frame, inner, app_state, theme);
     } else if let (Some(images), Some(asset_server)) = (images, asset_server) {
-        render_preview_content(
-            frame,
-            inner,
-            app_state,
-            settings,
-            theme,
-            preview_query,
-            images,
-            asset_server,
-            sixel_cache,
-        );
+        if app_state.pin_preview {
+            let panes = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(inner);
+
+            for (pane, title, path) in [
+                (
+                    panes[0],
+                    strings.t("generation.previous_title"),
+                    app_state.previous_preview.as_deref(),
+                ),
+                (
+                    panes[1],
+                    strings.t("generation.current_title"),
+                    app_state.current_preview.as_deref(),
+                ),
+            ] {
+                let pane_block = Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(theme.border());
+                let pane_inner = pane_block.inner(pane);
+                frame.render_widget(pane_block, pane);
+
+                if path.is_none() && pane == panes[0] {
+                    render_simple_placeholder(
+                        frame,
+                        pane_inner,
+                        theme,
+                        strings.t("generation.no_previous"),
+                    );
+                } else {
+                    render_preview_content(
+                        frame,
+                        pane_inner,
+                        path,
+                        settings,
+                        theme,
+                        preview_query,
+                        images,
+                        asset_server,
+                        sixel_cache,
+                        pending_sixel,
+                    );
+                }
+            }
+        } else {
+            render_preview_content(
+                frame,
+                inner,
+                app_state.current_preview.as_deref(),
+                settings,
+                theme,
+                preview_query,
+                images,
+                asset_server,
+                sixel_cache,
+                pending_sixel,
+            );
+        }
     } else {
         // Assets not loaded yet
         render_loading_preview(frame, inner, theme);
@@ -370,19 +744,20 @@ fn render_loading_preview(frame: &mut Frame, area: Rect, theme: &AppTheme) {
 fn render_preview_content(
     frame: &mut Frame,
     area: Rect,
-    app_state: &AppState,
+    preview_path: Option<&std::path::Path>,
     settings: &SettingsState,
     theme: &AppTheme,
     preview_query: &Query<&PreviewImage>,
     images: &Assets<Image>,
     asset_server: &AssetServer,
     sixel_cache: Option<&SixelPreviewCache>,
+    pending_sixel: &mut PendingSixelWrites,
 ) {
-    if let Some(preview_path) = &app_state.current_preview {
+    if let Some(preview_path) = preview_path {
         // Find PreviewImage component for this path
         let preview = preview_query
             .iter()
-            .find(|p| &p.path == preview_path);
+            .find(|p| p.path == preview_path);
 
         if let Some(preview) = preview {
             // Check if image asset is loaded
@@ -399,6 +774,7 @@ fn render_preview_content(
                                 theme,
                                 settings,
                                 sixel_cache,
+                                pending_sixel,
                             );
                         } else {
                             render_simple_placeholder(frame, area, theme, "Image asset not found");
@@ -409,7 +785,7 @@ fn render_preview_content(
                     }
                     _ => {
                         // Still loading
-                        render_simple_placeholder(frame, area, theme, "Loading image...");
+                        render_loading_placeholder(frame, area, theme, preview.load_elapsed());
                     }
                 }
             } else {
@@ -437,6 +813,7 @@ fn render_preview_content(
 }
 
 /// Render image with Sixel support or fallback to placeholder.
+#[allow(clippy::too_many_arguments)]
 fn render_sixel_or_placeholder(
     frame: &mut Frame,
     area: Rect,
@@ -445,14 +822,33 @@ fn render_sixel_or_placeholder(
     theme: &AppTheme,
     settings: &SettingsState,
     sixel_cache: Option<&SixelPreviewCache>,
+    pending_sixel: &mut PendingSixelWrites,
 ) {
+    // iTerm2 inline images first, then Sixel, then a text placeholder
+    if settings.ui.show_image_previews && supports_iterm() {
+        if let Some(cache) = sixel_cache {
+            let preview = get_or_render_iterm(
+                path,
+                (image.width(), image.height()),
+                area.width.saturating_sub(4),
+                area.height.saturating_sub(4),
+                cache,
+            );
+            match preview {
+                Ok(data) => pending_sixel.queue(area, &data),
+                Err(e) => render_simple_placeholder(frame, area, theme, &format!("{:#}", e)),
+            }
+            return;
+        }
+    }
+
     // Check if Sixel is enabled and supported
     let use_sixel = settings.ui.show_image_previews && supports_sixel();
 
     if use_sixel && sixel_cache.is_some() {
         // Try to render Sixel
         let cache = sixel_cache.unwrap();
-        let sixel_data = if let Some(entry) = cache.get(path) {
+        let sixel_data = if let Some(entry) = cache.get(path, settings.ui.sixel_colors as usize) {
             entry.sixel_data
         } else {
             // Render and cache
@@ -461,6 +857,8 @@ fn render_sixel_or_placeholder(
                 height: area.height.saturating_sub(4),
                 preserve_aspect: true,
                 high_quality: true,
+                background: settings.ui.sixel_background,
+                max_colors: settings.ui.sixel_colors as usize,
             };
 
             match render_image_sixel(image, &options) {
@@ -472,6 +870,7 @@ fn render_sixel_or_placeholder(
                         size_bytes: data.len(),
                         last_access: std::time::Instant::now(),
                         dimensions: (image.width(), image.height()),
+                        colors: options.max_colors,
                     };
                     cache.insert(entry);
                     data
@@ -483,12 +882,8 @@ fn render_sixel_or_placeholder(
             }
         };
 
-        // Render Sixel using utility function (handles proper positioning and clearing)
-        if let Err(e) = render_sixel_to_area(area, &sixel_data) {
-            warn!("Failed to render Sixel: {}", e);
-            render_simple_placeholder(frame, area, theme, &format!("Sixel render failed: {}", e));
-            return;
-        }
+        // Written once the frame is flushed (cleared and positioned there)
+        pending_sixel.queue(area, &sixel_data);
     } else {
         // Fallback: show image info
         let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("unknown");
@@ -503,6 +898,21 @@ fn render_sixel_or_placeholder(
 }
 
 /// Render a simple placeholder with a message.
+/// Loading message with a progress bar and the time since the load started.
+fn render_loading_placeholder(
+    frame: &mut Frame,
+    area: Rect,
+    theme: &AppTheme,
+    elapsed: Option<std::time::Duration>,
+) {
+    let mut lines = vec![Line::from(Span::styled("Loading image...", theme.muted()))];
+    if let Some(elapsed) = elapsed {
+        lines.push(indeterminate_progress_bar(elapsed, (area.width / 2).clamp(4, 30), theme));
+    }
+    let paragraph = Paragraph::new(lines).alignment(Alignment::Center);
+    frame.render_widget(paragraph, area);
+}
+
 fn render_simple_placeholder(frame: &mut Frame, area: Rect, theme: &AppTheme, message: &str) {
     let lines: Vec<Line> = message
         .lines()
@@ -512,24 +922,31 @@ fn render_simple_placeholder(frame: &mut Frame, area: Rect, theme: &AppTheme, me
     frame.render_widget(paragraph, area);
 }
 
-/// Render backend logs (debug mode, tab 1).
-fn render_backend_logs(frame: &mut Frame, area: Rect, app_state: &AppState, theme: &AppTheme) {
-    let lines: Vec<Line> = if app_state.backend_logs.is_empty() {
+/// Render the selected log, backend or frontend (debug mode, tab 1).
+fn render_logs(frame: &mut Frame, area: Rect, app_state: &AppState, theme: &AppTheme) {
+    let source = app_state.log_source;
+    let logs = app_state.logs(source);
+    let lines: Vec<Line> = if logs.is_empty() {
         vec![
             Line::from(""),
-            Line::from(Span::styled("No backend logs yet", theme.muted())),
+            Line::from(Span::styled(
+                format!("No {} logs yet", source.label().to_lowercase()),
+                theme.muted(),
+            )),
             Line::from(""),
             Line::from(Span::styled(
-                "Logs will appear here as the backend processes requests",
+                format!("Logs will appear here as {} is written", source.file_name()),
                 theme.muted(),
             )),
         ]
     } else {
-        // Show last N lines that fit in the area
+        // The N lines that fit, ending `log_scroll` lines before the newest
         let max_lines = area.height.saturating_sub(2) as usize;
-        let start_idx = app_state.backend_logs.len().saturating_sub(max_lines);
+        let scroll = app_state.log_scroll.min(logs.len().saturating_sub(max_lines));
+        let end_idx = logs.len() - scroll;
+        let start_idx = end_idx.saturating_sub(max_lines);
 
-        app_state.backend_logs[start_idx..]
+        logs[start_idx..end_idx]
             .iter()
             .map(|log_line| {
                 // Color code log levels
@@ -556,10 +973,11 @@ fn render_recent_generations(
     area: Rect,
     gallery: &GalleryState,
     theme: &AppTheme,
+    strings: &Strings,
 ) {
     let lines = if gallery.images.is_empty() {
         vec![Line::from(Span::styled(
-            "No recent generations",
+            strings.t("generation.no_recent"),
             theme.muted(),
         ))]
     } else {
@@ -579,7 +997,7 @@ fn render_recent_generations(
 
         if recent.is_empty() {
             vec![Line::from(Span::styled(
-                "No recent generations",
+                strings.t("generation.no_recent"),
                 theme.muted(),
             ))]
         } else {
@@ -589,7 +1007,7 @@ fn render_recent_generations(
 
     let paragraph = Paragraph::new(lines).block(
         Block::default()
-            .title(" Recent Generations ")
+            .title(strings.t("generation.recent_title"))
             .borders(Borders::ALL)
             .border_style(theme.border()),
     );
@@ -607,4 +1025,65 @@ mod tests {
         let mut app = bevy::app::App::new();
         app.add_systems(Update, render_generation_screen);
     }
+
+    #[test]
+    fn test_prompt_box_grows_when_multiline() {
+        let mut input = InputBuffer::default();
+        assert_eq!(prompt_box_height(&input), 5);
+
+        input.text = "a\nb\nc\nd\ne".to_string();
+        assert_eq!(prompt_box_height(&input), 7);
+
+        input.text = "x\n".repeat(20);
+        assert_eq!(prompt_box_height(&input), 10);
+    }
+
+    #[test]
+    fn test_preset_picker_lists_presets() {
+        let backend = ratatui::backend::TestBackend::new(80, 24);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        let mut presets = PromptPresets {
+            presets: vec![crate::bevy_app::resources::PromptPreset {
+                name: "Sprite".to_string(),
+                template: "16-bit {subject} sprite".to_string(),
+            }],
+            ..Default::default()
+        };
+        presets.open_picker();
+
+        terminal
+            .draw(|frame| {
+                render_preset_picker(
+                    frame,
+                    frame.area(),
+                    &presets,
+                    &AppTheme::default(),
+                    &Strings::default(),
+                )
+            })
+            .unwrap();
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|c| c.symbol())
+            .collect();
+        assert!(text.contains("Prompt Presets"));
+        assert!(text.contains("> Sprite  16-bit {subject} sprite"));
+    }
+
+    #[test]
+    fn test_seed_line_shows_active_seed() {
+        let theme = AppTheme::default();
+        let text = |app_state: &AppState| seed_line(app_state, &theme).to_string();
+        let mut app_state = AppState::default();
+        assert_eq!(text(&app_state), "Seed:        Random [Alt+S]");
+
+        let seed = app_state.next_seed();
+        assert_eq!(text(&app_state), format!("Seed:        Random (last: {}) [Alt+S]", seed));
+
+        app_state.seed_mode = SeedMode::Fixed(7);
+        assert_eq!(text(&app_state), "Seed:        7 (locked, [/] adjust) [Alt+S]");
+    }
 }
@@ -7,12 +7,13 @@ use ratatui::{
     Frame,
 };
 
-use crate::bevy_app::resources::{AppTheme, CurrentScreen, Screen};
+use crate::bevy_app::resources::{AppTheme, CurrentScreen, HelpLine, HelpState, Screen};
 
 /// Render the Help screen
 pub fn render_help_screen(
     current_screen: Res<CurrentScreen>,
     theme: Res<AppTheme>,
+    mut help: ResMut<HelpState>,
     mut ratatui: ResMut<RatatuiContext>,
 ) {
     if current_screen.0 != Screen::Help {
@@ -31,10 +32,10 @@ pub fn render_help_screen(
                 .split(frame.area());
 
             // Title
-            render_title(frame, chunks[0], &theme);
+            render_title(frame, chunks[0], &help, &theme);
 
             // Content
-            render_content(frame, chunks[1], &theme);
+            render_content(frame, chunks[1], &mut help, &theme);
 
             // Status bar
             render_status_bar(frame, chunks[2], &theme);
@@ -42,8 +43,14 @@ pub fn render_help_screen(
         .expect("Failed to render help screen");
 }
 
-fn render_title(frame: &mut Frame, area: Rect, theme: &AppTheme) {
-    let title = Paragraph::new(" Help").style(theme.header()).block(
+fn render_title(frame: &mut Frame, area: Rect, help: &HelpState, theme: &AppTheme) {
+    let mut spans = vec![Span::styled(" Help", theme.header())];
+    if help.searching || !help.query.is_empty() {
+        let cursor = if help.searching { "▏" } else { "" };
+        spans.push(Span::styled("   Search: ", theme.muted()));
+        spans.push(Span::styled(format!("{}{}", help.query, cursor), theme.text()));
+    }
+    let title = Paragraph::new(Line::from(spans)).block(
         Block::default()
             .borders(Borders::ALL)
             .border_style(theme.highlight()),
@@ -51,132 +58,42 @@ fn render_title(frame: &mut Frame, area: Rect, theme: &AppTheme) {
     frame.render_widget(title, area);
 }
 
-fn render_content(frame: &mut Frame, area: Rect, theme: &AppTheme) {
-    let lines = vec![
-        Line::from(""),
-        Line::from(vec![Span::styled("NAVIGATION", theme.highlight())]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  Tab          ", theme.muted()),
-            Span::raw("Next screen"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Shift+Tab    ", theme.muted()),
-            Span::raw("Previous screen"),
-        ]),
-        Line::from(vec![
-            Span::styled("  q / Ctrl+C   ", theme.muted()),
-            Span::raw("Quit application"),
-        ]),
-        Line::from(""),
-        Line::from(vec![Span::styled("GENERATION SCREEN", theme.highlight())]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  Type         ", theme.muted()),
-            Span::raw("Enter prompt text"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Enter        ", theme.muted()),
-            Span::raw("Submit job for generation"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Esc          ", theme.muted()),
-            Span::raw("Clear prompt"),
-        ]),
-        Line::from(""),
-        Line::from(vec![Span::styled("COMPARISON SCREEN", theme.highlight())]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  ↑/↓          ", theme.muted()),
-            Span::raw("Navigate model list"),
-        ]),
-        Line::from(vec![
-            Span::styled("  a / A        ", theme.muted()),
-            Span::raw("Add model to comparison"),
-        ]),
-        Line::from(vec![
-            Span::styled("  d / D        ", theme.muted()),
-            Span::raw("Remove selected model"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Enter        ", theme.muted()),
-            Span::raw("Generate with selected models"),
-        ]),
-        Line::from(""),
-        Line::from(vec![Span::styled("QUEUE SCREEN", theme.highlight())]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  ↑/↓          ", theme.muted()),
-            Span::raw("Navigate queue"),
-        ]),
-        Line::from(vec![
-            Span::styled("  c / C        ", theme.muted()),
-            Span::raw("Cancel selected job"),
-        ]),
-        Line::from(vec![
-            Span::styled("  r / R        ", theme.muted()),
-            Span::raw("Retry failed job"),
-        ]),
-        Line::from(""),
-        Line::from(vec![Span::styled("GALLERY SCREEN", theme.highlight())]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  ←/→          ", theme.muted()),
-            Span::raw("Navigate gallery"),
-        ]),
-        Line::from(vec![
-            Span::styled("  PgUp/PgDn    ", theme.muted()),
-            Span::raw("Jump 10 images"),
-        ]),
-        Line::from(vec![
-            Span::styled("  Home/End     ", theme.muted()),
-            Span::raw("First/Last image"),
-        ]),
-        Line::from(vec![
-            Span::styled("  d / D        ", theme.muted()),
-            Span::raw("Delete current image"),
-        ]),
-        Line::from(""),
-        Line::from(vec![Span::styled("MODELS SCREEN", theme.highlight())]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  ↑/↓          ", theme.muted()),
-            Span::raw("Navigate models list"),
-        ]),
-        Line::from(vec![
-            Span::styled("  a / A        ", theme.muted()),
-            Span::raw("Activate selected model"),
-        ]),
-        Line::from(vec![
-            Span::styled("  d / D        ", theme.muted()),
-            Span::raw("Download model"),
-        ]),
-        Line::from(vec![
-            Span::styled("  r / R        ", theme.muted()),
-            Span::raw("Remove selected model"),
-        ]),
-        Line::from(vec![
-            Span::styled("  i / I        ", theme.muted()),
-            Span::raw("Toggle model info panel"),
-        ]),
-        Line::from(""),
-        Line::from(vec![Span::styled("MONITOR SCREEN", theme.highlight())]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  r / R        ", theme.muted()),
-            Span::raw("Refresh metrics (future)"),
-        ]),
-        Line::from(vec![
-            Span::styled("  p / P        ", theme.muted()),
-            Span::raw("Pause auto-refresh (future)"),
-        ]),
-        Line::from(""),
-        Line::from(""),
-        Line::from(vec![Span::styled(
+/// The help lines as styled text.
+fn content_lines(help: &HelpState, theme: &AppTheme) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line> = help
+        .lines()
+        .into_iter()
+        .map(|line| match line {
+            HelpLine::Blank => Line::from(""),
+            HelpLine::Heading(title) => Line::from(Span::styled(title, theme.highlight())),
+            HelpLine::Entry(keys, description) => Line::from(vec![
+                Span::styled(format!("  {:<13}", keys), theme.muted()),
+                Span::raw(description),
+            ]),
+        })
+        .collect();
+
+    if help.query.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
             "For more information, visit: https://github.com/raibid-labs/dgx-pixels",
             theme.muted(),
-        )]),
-    ];
+        )));
+    } else if lines.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  No shortcuts match \"{}\"", help.query),
+            theme.muted(),
+        )));
+    }
+    lines
+}
+
+fn render_content(frame: &mut Frame, area: Rect, help: &mut HelpState, theme: &AppTheme) {
+    let lines = content_lines(help, theme);
+    // Inside the borders
+    help.update_viewport(area.height.saturating_sub(2) as usize, lines.len());
 
     let paragraph = Paragraph::new(lines)
         .block(
@@ -184,13 +101,15 @@ fn render_content(frame: &mut Frame, area: Rect, theme: &AppTheme) {
                 .borders(Borders::ALL)
                 .border_style(theme.text()),
         )
-        .style(theme.text());
+        .style(theme.text())
+        .scroll((help.scroll_offset.min(u16::MAX as usize) as u16, 0));
 
     frame.render_widget(paragraph, area);
 }
 
 fn render_status_bar(frame: &mut Frame, area: Rect, theme: &AppTheme) {
-    let status_text = "Use Tab/Shift+Tab to navigate screens | Press 'q' to quit";
+    let status_text =
+        "↑/↓ PgUp/PgDn Scroll | / Search | Tab/Shift+Tab Navigate screens | q Quit";
     let paragraph = Paragraph::new(status_text).style(theme.status_bar());
     frame.render_widget(paragraph, area);
 }
@@ -205,6 +124,26 @@ mod tests {
         let mut app = App::new();
         app.insert_resource(CurrentScreen(Screen::Help));
         app.insert_resource(AppTheme::default());
+        app.init_resource::<HelpState>();
         app.add_systems(Update, render_help_screen);
     }
+
+    #[test]
+    fn test_search_narrows_rendered_lines() {
+        let theme = AppTheme::default();
+        let mut help = HelpState::default();
+        let all = content_lines(&help, &theme).len();
+
+        help.query = "favorite".to_string();
+        let text: Vec<String> =
+            content_lines(&help, &theme).iter().map(|line| line.to_string()).collect();
+        assert!(text.len() < all);
+        assert!(text.contains(&"GALLERY SCREEN".to_string()));
+        assert!(text.iter().any(|line| line.contains("Toggle favorite")));
+        assert!(!text.iter().any(|line| line.contains("QUEUE")));
+
+        help.query = "no such key".to_string();
+        let text = content_lines(&help, &theme)[1].to_string();
+        assert!(text.contains("No shortcuts match"));
+    }
 }
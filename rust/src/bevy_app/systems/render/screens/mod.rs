@@ -10,7 +10,7 @@ pub mod queue;
 pub mod settings;
 
 pub use comparison::render_comparison_screen;
-pub use gallery::render_gallery_screen;
+pub use gallery::{render_gallery_screen, render_gallery_zoom};
 pub use generation::render_generation_screen;
 pub use help::render_help_screen;
 pub use models::render_models_screen;
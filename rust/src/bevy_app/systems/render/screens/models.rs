@@ -4,12 +4,16 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::Stylize,
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
     Frame,
 };
 
 use crate::bevy_app::resources::{AppTheme, CurrentScreen, ModelsState, Screen};
-use crate::bevy_app::resources::models::ModelStatus;
+use crate::bevy_app::resources::models::{ModelInfo, ModelStatus};
+use crate::bevy_app::systems::render::widgets::progress_bar;
+
+/// Width of the inline download gauge, in characters (inside its brackets).
+const DOWNLOAD_GAUGE_WIDTH: u16 = 8;
 
 /// Render the Models screen with model table and optional metadata panel
 pub fn render_models_screen(
@@ -87,25 +91,27 @@ fn render_models_table(
         Cell::from("Active").style(theme.highlight()),
     ]);
 
-    // Table rows
+    // Table rows (only models passing the filter)
     let rows: Vec<Row> = models_state
-        .models
-        .iter()
-        .enumerate()
+        .filtered_indices()
+        .into_iter()
+        .map(|idx| (idx, &models_state.models[idx]))
         .map(|(idx, model)| {
-            // Status indicator with color
-            let (status_text, status_style) = match &model.status {
-                ModelStatus::Downloaded => ("✅".to_string(), theme.success()),
-                ModelStatus::Available => ("⏳".to_string(), theme.warning()),
-                ModelStatus::Downloading(pct) => (format!("📊 {}%", pct), theme.highlight()),
-                ModelStatus::Failed => ("❌".to_string(), theme.error()),
+            // Status indicator with color (a gauge while downloading)
+            let status = match &model.status {
+                ModelStatus::Downloaded => Line::styled("✅", theme.success()),
+                ModelStatus::Available => Line::styled("⏳", theme.warning()),
+                ModelStatus::Downloading(pct) => {
+                    progress_bar(*pct as f32 / 100.0, DOWNLOAD_GAUGE_WIDTH, theme)
+                }
+                ModelStatus::Failed => Line::styled("❌ r:Retry", theme.error()),
             };
 
             // Active indicator
-            let active_text = if models_state.active_model.as_ref() == Some(&model.name) {
-                "✓"
-            } else {
-                ""
+            let active_text = match models_state.lora_strength(&model.name) {
+                Some(strength) => format!("✓ {:.2}", strength),
+                None if models_state.is_active(&model.name) => "✓".to_string(),
+                None => String::new(),
             };
 
             // Format size
@@ -115,7 +121,7 @@ fn render_models_table(
                 Cell::from(model.name.clone()),
                 Cell::from(model.model_type.to_string()),
                 Cell::from(size_text),
-                Cell::from(Span::styled(status_text, status_style)),
+                Cell::from(status),
                 Cell::from(active_text),
             ]);
 
@@ -141,9 +147,13 @@ fn render_models_table(
     .header(headers)
     .block(
         Block::default()
-            .title(" Models ")
+            .title(table_title(models_state))
             .borders(Borders::ALL)
-            .border_style(theme.text()),
+            .border_style(if models_state.filter_editing {
+                theme.input_active()
+            } else {
+                theme.text()
+            }),
     );
 
     frame.render_widget(table, area);
@@ -155,49 +165,13 @@ fn render_metadata_panel(
     models_state: &ModelsState,
     theme: &AppTheme,
 ) {
-    let selected_model = models_state.selected_model();
-
-    let lines = if let Some(model) = selected_model {
-        vec![
-            Line::from(vec![
-                Span::styled("Name: ", theme.highlight()),
-                Span::raw(&model.name),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("Version: ", theme.highlight()),
-                Span::raw(&model.metadata.version),
-            ]),
-            Line::from(vec![
-                Span::styled("Type: ", theme.highlight()),
-                Span::raw(model.model_type.to_string()),
-            ]),
-            Line::from(vec![
-                Span::styled("Parameters: ", theme.highlight()),
-                Span::raw(&model.metadata.parameters),
-            ]),
-            Line::from(vec![
-                Span::styled("Size: ", theme.highlight()),
-                Span::raw(format!("{} MB", model.size_mb)),
-            ]),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("License: ", theme.highlight()),
-                Span::raw(&model.metadata.license),
-            ]),
-            Line::from(vec![
-                Span::styled("Source: ", theme.highlight()),
-                Span::raw(&model.metadata.source),
-            ]),
-            Line::from(""),
-            Line::from(Span::styled("Description:", theme.highlight())),
-            Line::from(model.metadata.description.clone()),
-        ]
-    } else {
-        vec![Line::from("No model selected")]
+    let lines = match models_state.selected_model() {
+        Some(model) => metadata_lines(model, models_state, theme),
+        None => vec![Line::from("No model selected")],
     };
 
-    let paragraph = Paragraph::new(lines).block(
+    // Wrap so long descriptions stay inside the panel
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
         Block::default()
             .title(" Metadata ")
             .borders(Borders::ALL)
@@ -207,6 +181,50 @@ fn render_metadata_panel(
     frame.render_widget(paragraph, area);
 }
 
+/// Details of `model` for the metadata panel.
+fn metadata_lines<'a>(
+    model: &'a ModelInfo,
+    models_state: &ModelsState,
+    theme: &AppTheme,
+) -> Vec<Line<'a>> {
+    let field = |label: &'static str, value: String| {
+        Line::from(vec![Span::styled(label, theme.highlight()), Span::raw(value)])
+    };
+
+    vec![
+        field("Name: ", model.name.clone()),
+        Line::from(""),
+        field("Version: ", model.metadata.version.clone()),
+        field("Type: ", model.model_type.to_string()),
+        field("Parameters: ", model.metadata.parameters.clone()),
+        field("Size: ", disk_usage(model)),
+        field("Status: ", activation_state(model, models_state)),
+        Line::from(""),
+        field("License: ", model.metadata.license.clone()),
+        field("Source: ", model.metadata.source.clone()),
+        Line::from(""),
+        Line::from(Span::styled("Description:", theme.highlight())),
+        Line::from(model.metadata.description.as_str()),
+    ]
+}
+
+/// Space the model takes, or would take once downloaded.
+fn disk_usage(model: &ModelInfo) -> String {
+    match model.status {
+        ModelStatus::Downloaded => format!("{} MB on disk", model.size_mb),
+        _ => format!("{} MB to download", model.size_mb),
+    }
+}
+
+/// Whether the model is in use ("Active (strength 0.8)" for LoRAs).
+fn activation_state(model: &ModelInfo, models_state: &ModelsState) -> String {
+    match models_state.lora_strength(&model.name) {
+        Some(strength) => format!("Active (strength {:.1})", strength),
+        None if models_state.is_active(&model.name) => "Active".to_string(),
+        None => "Inactive".to_string(),
+    }
+}
+
 fn render_status_bar(frame: &mut Frame, area: Rect, models_state: &ModelsState, theme: &AppTheme) {
     let (downloaded_mb, total_mb) = models_state.memory_stats();
     let downloaded_gb = downloaded_mb as f64 / 1024.0;
@@ -232,9 +250,13 @@ fn render_status_bar(frame: &mut Frame, area: Rect, models_state: &ModelsState,
         Line::from(vec![
             Span::raw("↑/↓:Navigate | "),
             Span::raw("Enter:Activate | "),
+            Span::raw("+/-:LoRA Strength | "),
             Span::raw("d:Download | "),
+            Span::raw("r:Retry | "),
             Span::raw("Del:Remove | "),
-            Span::raw("i:Info"),
+            Span::raw("i:Info | "),
+            Span::raw("/:Filter | "),
+            Span::raw("b/l/v:Types"),
         ]),
     ];
 
@@ -250,6 +272,24 @@ fn render_status_bar(frame: &mut Frame, area: Rect, models_state: &ModelsState,
     frame.render_widget(paragraph, area);
 }
 
+/// Table title with the filter and any hidden model types
+fn table_title(models_state: &ModelsState) -> String {
+    let mut title = " Models ".to_string();
+    if models_state.filter_editing || !models_state.filter.is_empty() {
+        let cursor = if models_state.filter_editing { "_" } else { "" };
+        title.push_str(&format!("/{}{} ", models_state.filter, cursor));
+    }
+    if !models_state.hidden_types.is_empty() {
+        let hidden: Vec<String> = models_state
+            .hidden_types
+            .iter()
+            .map(|t| t.to_string())
+            .collect();
+        title.push_str(&format!("(hiding {}) ", hidden.join(", ")));
+    }
+    title
+}
+
 /// Format size in MB to human-readable format (MB or GB)
 fn format_size(size_mb: usize) -> String {
     if size_mb >= 1024 {
@@ -262,6 +302,7 @@ fn format_size(size_mb: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bevy_app::resources::models::ModelType;
     use bevy::app::App;
 
     #[test]
@@ -273,6 +314,39 @@ mod tests {
         app.add_systems(Update, render_models_screen);
     }
 
+    #[test]
+    fn test_table_title_shows_filter() {
+        let mut models_state = ModelsState::default();
+        assert_eq!(table_title(&models_state), " Models ");
+
+        models_state.filter_editing = true;
+        models_state.push_filter_char('p');
+        assert_eq!(table_title(&models_state), " Models /p_ ");
+
+        models_state.filter_editing = false;
+        models_state.toggle_type_visibility(ModelType::VAE);
+        assert_eq!(table_title(&models_state), " Models /p (hiding VAE) ");
+    }
+
+    #[test]
+    fn test_metadata_panel_shows_selected_model() {
+        let mut models_state = ModelsState::default();
+        models_state.next();
+        let model = models_state.selected_model().unwrap().clone();
+
+        let text: Vec<String> = metadata_lines(&model, &models_state, &AppTheme::default())
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(text[0], format!("Name: {}", model.name));
+        assert!(text.contains(&format!("Version: {}", model.metadata.version)));
+        assert!(text.contains(&format!("License: {}", model.metadata.license)));
+        // The refiner isn't downloaded or active
+        assert!(text.contains(&format!("Size: {} MB to download", model.size_mb)));
+        assert!(text.contains(&"Status: Inactive".to_string()));
+        assert_eq!(text.last().unwrap(), &model.metadata.description);
+    }
+
     #[test]
     fn test_format_size_mb() {
         assert_eq!(format_size(144), "144MB");
@@ -3,18 +3,21 @@ use bevy_ratatui::terminal::RatatuiContext;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, Paragraph},
+    widgets::{Block, Borders, Gauge, Paragraph, Sparkline},
     Frame,
 };
 
 use crate::bevy_app::components::Job;
-use crate::bevy_app::resources::{AppTheme, CurrentScreen, JobTracker, Screen};
+use crate::bevy_app::resources::{
+    AppTheme, CurrentScreen, JobTracker, MonitorState, Screen, MONITOR_HISTORY_LEN,
+};
 
 /// Render the Monitor screen
 pub fn render_monitor_screen(
     current_screen: Res<CurrentScreen>,
     jobs: Query<&Job>,
     job_tracker: Res<JobTracker>,
+    monitor: Res<MonitorState>,
     theme: Res<AppTheme>,
     mut ratatui: ResMut<RatatuiContext>,
 ) {
@@ -37,10 +40,10 @@ pub fn render_monitor_screen(
             render_title(frame, chunks[0], &theme);
 
             // Content
-            render_content(frame, chunks[1], &jobs, &job_tracker, &theme);
+            render_content(frame, chunks[1], &jobs, &job_tracker, &monitor, &theme);
 
             // Status bar
-            render_status_bar(frame, chunks[2], &job_tracker, &theme);
+            render_status_bar(frame, chunks[2], &job_tracker, &monitor, &theme);
         })
         .expect("Failed to render monitor screen");
 }
@@ -61,6 +64,7 @@ fn render_content(
     area: Rect,
     jobs: &Query<&Job>,
     job_tracker: &JobTracker,
+    monitor: &MonitorState,
     theme: &AppTheme,
 ) {
     let content_chunks = Layout::default()
@@ -77,7 +81,7 @@ fn render_content(
     render_job_statistics(frame, content_chunks[0], job_tracker, theme);
 
     // System metrics
-    render_system_metrics(frame, content_chunks[1], jobs, theme);
+    render_system_metrics(frame, content_chunks[1], jobs, monitor, theme);
 
     // Active jobs
     render_active_jobs(frame, content_chunks[2], jobs, theme);
@@ -127,7 +131,13 @@ fn render_job_statistics(
     frame.render_widget(paragraph, area);
 }
 
-fn render_system_metrics(frame: &mut Frame, area: Rect, jobs: &Query<&Job>, theme: &AppTheme) {
+fn render_system_metrics(
+    frame: &mut Frame,
+    area: Rect,
+    jobs: &Query<&Job>,
+    monitor: &MonitorState,
+    theme: &AppTheme,
+) {
     // Calculate job counts for queue pressure metric
     let total_jobs = jobs.iter().count();
     let queue_pressure = if total_jobs > 0 {
@@ -150,27 +160,50 @@ fn render_system_metrics(frame: &mut Frame, area: Rect, jobs: &Query<&Job>, them
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Queue pressure gauge
-            Constraint::Min(0),    // Other metrics
+            Constraint::Min(0),    // GPU metrics
         ])
         .split(area);
 
     frame.render_widget(gauge, inner_chunks[0]);
 
-    // System info (placeholder for future GPU metrics)
+    let metric_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(40), // Current readings
+            Constraint::Percentage(60), // GPU utilization history
+        ])
+        .split(inner_chunks[1]);
+
+    // Latest readings from the backend (N/A without one)
+    let stats = monitor.current(std::time::Instant::now());
+    let na = || "N/A".to_string();
+    let metric = |label: &'static str, value: String| {
+        let style = if stats.is_some() { theme.text() } else { theme.muted() };
+        Line::from(vec![
+            Span::styled(label, theme.muted()),
+            Span::styled(value, style),
+        ])
+    };
     let sys_lines = vec![
         Line::from(""),
-        Line::from(vec![
-            Span::styled("GPU Utilization:  ", theme.muted()),
-            Span::styled("[WS-XX: Not implemented]", theme.muted()),
-        ]),
-        Line::from(vec![
-            Span::styled("Memory Usage:     ", theme.muted()),
-            Span::styled("[WS-XX: Not implemented]", theme.muted()),
-        ]),
-        Line::from(vec![
-            Span::styled("Temperature:      ", theme.muted()),
-            Span::styled("[WS-XX: Not implemented]", theme.muted()),
-        ]),
+        metric(
+            "GPU Utilization:  ",
+            stats.map_or_else(na, |s| format!("{:.0}%", s.gpu_utilization)),
+        ),
+        metric(
+            "Memory Usage:     ",
+            stats.map_or_else(na, |s| {
+                format!(
+                    "{:.1} / {:.1} GB",
+                    s.vram_used_mb as f64 / 1024.0,
+                    s.vram_total_mb as f64 / 1024.0
+                )
+            }),
+        ),
+        metric(
+            "Temperature:      ",
+            stats.map_or_else(na, |s| format!("{:.0}°C", s.temperature_c)),
+        ),
     ];
 
     let paragraph = Paragraph::new(sys_lines).block(
@@ -180,7 +213,21 @@ fn render_system_metrics(frame: &mut Frame, area: Rect, jobs: &Query<&Job>, them
             .border_style(theme.text()),
     );
 
-    frame.render_widget(paragraph, inner_chunks[1]);
+    frame.render_widget(paragraph, metric_chunks[0]);
+
+    let history = monitor.gpu_sparkline();
+    let sparkline = Sparkline::default()
+        .block(
+            Block::default()
+                .title(format!(" GPU Utilization (last {}s) ", MONITOR_HISTORY_LEN))
+                .borders(Borders::ALL)
+                .border_style(theme.text()),
+        )
+        .data(&history)
+        .max(100)
+        .style(theme.highlight());
+
+    frame.render_widget(sparkline, metric_chunks[1]);
 }
 
 fn render_active_jobs(frame: &mut Frame, area: Rect, jobs: &Query<&Job>, theme: &AppTheme) {
@@ -189,7 +236,8 @@ fn render_active_jobs(frame: &mut Frame, area: Rect, jobs: &Query<&Job>, theme:
         .filter(|j| {
             matches!(
                 j.status,
-                crate::bevy_app::components::JobStatus::Pending
+                crate::bevy_app::components::JobStatus::QueuedLocally
+                    | crate::bevy_app::components::JobStatus::Pending
                     | crate::bevy_app::components::JobStatus::Queued
                     | crate::bevy_app::components::JobStatus::Generating { .. }
             )
@@ -210,6 +258,7 @@ fn render_active_jobs(frame: &mut Frame, area: Rect, jobs: &Query<&Job>, theme:
             };
 
             let status_text = match &job.status {
+                crate::bevy_app::components::JobStatus::QueuedLocally => "Queued locally",
                 crate::bevy_app::components::JobStatus::Pending => "Pending",
                 crate::bevy_app::components::JobStatus::Queued => "Queued",
                 crate::bevy_app::components::JobStatus::Generating { stage, .. } => stage.as_str(),
@@ -235,9 +284,19 @@ fn render_active_jobs(frame: &mut Frame, area: Rect, jobs: &Query<&Job>, theme:
     frame.render_widget(paragraph, area);
 }
 
-fn render_status_bar(frame: &mut Frame, area: Rect, job_tracker: &JobTracker, theme: &AppTheme) {
+fn render_status_bar(
+    frame: &mut Frame,
+    area: Rect,
+    job_tracker: &JobTracker,
+    monitor: &MonitorState,
+    theme: &AppTheme,
+) {
+    let backend = monitor
+        .connection
+        .map_or_else(|| "Offline".to_string(), |state| state.to_string());
     let status_text = format!(
-        "Active: {} | Completed: {} | Failed: {} | Success Rate: {:.1}%",
+        "Backend: {} | Active: {} | Completed: {} | Failed: {} | Success Rate: {:.1}%",
+        backend,
         job_tracker.active_jobs(),
         job_tracker.total_completed,
         job_tracker.total_failed,
@@ -258,6 +317,7 @@ mod tests {
         app.insert_resource(CurrentScreen(Screen::Monitor));
         app.insert_resource(AppTheme::default());
         app.insert_resource(JobTracker::default());
+        app.insert_resource(MonitorState::default());
         app.add_systems(Update, render_monitor_screen);
     }
 }
@@ -8,8 +8,10 @@ use ratatui::{
     Frame,
 };
 
-use crate::bevy_app::components::{Job, JobStatus};
-use crate::bevy_app::resources::{AppTheme, CurrentScreen, JobTracker, QueueState, Screen};
+use crate::bevy_app::components::{Job, JobStatus, Priority};
+use crate::bevy_app::resources::{
+    AppTheme, CurrentScreen, JobTracker, QueueState, Screen, SettingsState,
+};
 
 /// Render the Queue screen with scrollable job list and keyboard navigation
 pub fn render_queue_screen(
@@ -17,6 +19,7 @@ pub fn render_queue_screen(
     jobs: Query<&Job>,
     job_tracker: Res<JobTracker>,
     mut queue_state: ResMut<QueueState>,
+    settings: Res<SettingsState>,
     theme: Res<AppTheme>,
     mut ratatui: ResMut<RatatuiContext>,
 ) {
@@ -24,12 +27,13 @@ pub fn render_queue_screen(
         return;
     }
 
-    // Collect all jobs into a sorted vector
+    // Collect all jobs into a sorted vector (locally queued jobs last, in send order)
     let mut all_jobs: Vec<&Job> = jobs.iter().collect();
-    all_jobs.sort_by_key(|j| j.submitted_at);
+    job_tracker.sort_for_display(&mut all_jobs);
 
     // Update queue state with current job count
     queue_state.update_total(all_jobs.len());
+    let slots = settings.backend.max_concurrent_jobs.max(1) as usize;
 
     ratatui
         .draw(|frame| {
@@ -47,7 +51,15 @@ pub fn render_queue_screen(
             render_title(frame, chunks[0], all_jobs.len(), &theme);
 
             // Job list with selection highlighting
-            render_job_list(frame, chunks[1], &all_jobs, queue_state.selected, &theme);
+            render_job_list(
+                frame,
+                chunks[1],
+                &all_jobs,
+                queue_state.selected,
+                &job_tracker,
+                slots,
+                &theme,
+            );
 
             // Queue statistics
             render_queue_stats(frame, chunks[2], &job_tracker, &theme);
@@ -74,6 +86,8 @@ fn render_job_list(
     area: Rect,
     jobs: &[&Job],
     selected: usize,
+    job_tracker: &JobTracker,
+    slots: usize,
     theme: &AppTheme,
 ) {
     if jobs.is_empty() {
@@ -115,8 +129,21 @@ fn render_job_list(
 
             // Determine status icon, text and style
             let (status_icon, status_text, status_style) = match &job.status {
+                JobStatus::QueuedLocally if job.priority != Priority::Normal => (
+                    "⏸",
+                    format!("Queued ({})", job.priority),
+                    if job.priority == Priority::High {
+                        theme.warning()
+                    } else {
+                        theme.muted()
+                    },
+                ),
+                JobStatus::QueuedLocally => ("⏸", "Queued locally".to_string(), theme.muted()),
                 JobStatus::Pending => ("⏳", "Pending".to_string(), theme.muted()),
                 JobStatus::Queued => ("📋", "Queued".to_string(), theme.text()),
+                JobStatus::Generating { stage, progress, .. } if job.stalled => {
+                    ("⚠", format!("{} {:.0}% stalled?", stage, progress * 100.0), theme.warning())
+                }
                 JobStatus::Generating { stage, progress, .. } => {
                     ("🔄", format!("{} {:.0}%", stage, progress * 100.0), theme.highlight())
                 }
@@ -145,16 +172,23 @@ fn render_job_list(
             };
 
             // Create line with proper spacing
-            let line = Line::from(vec![
+            let mut spans = vec![
                 Span::styled(format!("{} ", status_icon), status_style),
                 Span::styled(format!("{:<8}", job_id), theme.muted()),
                 Span::raw(" │ "),
+                Span::styled(format!("{:<5}", batch_label(job)), theme.muted()),
+                Span::raw(" │ "),
                 Span::styled(format!("{:<50}", prompt_preview), theme.text()),
                 Span::raw(" │ "),
                 Span::styled(format!("{:<15}", status_text), status_style),
                 Span::raw(" │ "),
                 Span::styled(elapsed_str, theme.muted()),
-            ]);
+            ];
+            if let Some(estimate) = start_estimate(job_tracker, job, slots) {
+                spans.push(Span::raw(" │ "));
+                spans.push(Span::styled(estimate, theme.muted()));
+            }
+            let line = Line::from(spans);
 
             // Highlight selected item
             let item = if idx == selected {
@@ -181,6 +215,35 @@ fn render_job_list(
     frame.render_widget(list, area);
 }
 
+/// When a locally queued job should start and finish ("starts in ~12s, done
+/// in ~18s"), or None for jobs already sent.
+///
+/// Jobs only wait locally while all `slots` backend slots are busy, so the
+/// job starts once the jobs in flight finish plus one round for every
+/// `slots` jobs ahead of it in the send order, each round taking the
+/// session's average generation time.
+fn start_estimate(job_tracker: &JobTracker, job: &Job, slots: usize) -> Option<String> {
+    let position = job_tracker.pending_position(&job.id)?;
+    let rounds = position / slots.max(1) + 1;
+    Some(match (job_tracker.queue_eta(rounds), job_tracker.avg_duration_s()) {
+        (Some(start_s), Some(avg_s)) => {
+            format!("starts in ~{:.0}s, done in ~{:.0}s", start_s, start_s + avg_s)
+        }
+        _ => "starts: unknown".to_string(),
+    })
+}
+
+/// Position within its batch ("2/4"), or blank for a single job.
+///
+/// Jobs in a batch are spawned together, so they sit next to each other in
+/// the list and the label marks them as a group.
+fn batch_label(job: &Job) -> String {
+    match &job.batch {
+        Some(batch) => format!("{}/{}", batch.index + 1, batch.size),
+        None => String::new(),
+    }
+}
+
 fn render_queue_stats(frame: &mut Frame, area: Rect, job_tracker: &JobTracker, theme: &AppTheme) {
     // Calculate stats from tracker
     let total = job_tracker.total_submitted;
@@ -220,7 +283,7 @@ fn render_queue_stats(frame: &mut Frame, area: Rect, job_tracker: &JobTracker, t
 
 fn render_status_bar(frame: &mut Frame, area: Rect, job_tracker: &JobTracker, theme: &AppTheme) {
     let status_text = format!(
-        "Total: {} | Active: {} | Completed: {} | Failed: {} | [↑/↓] Navigate [Home/End] Jump [c] Cancel",
+        "Total: {} | Active: {} | Completed: {} | Failed: {} | [↑/↓] Navigate [Shift+↑/↓] Reorder [p] Priority [Home/End] Jump [c] Cancel",
         job_tracker.total_submitted,
         job_tracker.active_jobs(),
         job_tracker.total_completed,
@@ -234,6 +297,7 @@ fn render_status_bar(frame: &mut Frame, area: Rect, job_tracker: &JobTracker, th
 mod tests {
     use super::*;
     use bevy::app::App;
+    use crate::bevy_app::components::JobBatch;
 
     #[test]
     fn test_queue_screen_compiles() {
@@ -242,6 +306,44 @@ mod tests {
         app.insert_resource(AppTheme::default());
         app.insert_resource(JobTracker::default());
         app.insert_resource(QueueState::default());
+        app.insert_resource(SettingsState::default());
         app.add_systems(Update, render_queue_screen);
     }
+
+    #[test]
+    fn test_start_estimate() {
+        let mut tracker = JobTracker::default();
+        let sent = Job::new("sent".to_string(), "sprite".to_string());
+        let queued = Job::new("queued".to_string(), "sprite".to_string());
+        tracker.enqueue("first".to_string(), Priority::Normal);
+        tracker.enqueue("queued".to_string(), Priority::Normal);
+
+        assert_eq!(start_estimate(&tracker, &sent, 1), None);
+        assert_eq!(start_estimate(&tracker, &queued, 1).unwrap(), "starts: unknown");
+
+        tracker.record_duration(10.0);
+        assert_eq!(
+            start_estimate(&tracker, &queued, 1).unwrap(),
+            "starts in ~20s, done in ~30s"
+        );
+        // With two slots the job ahead runs alongside it
+        assert_eq!(
+            start_estimate(&tracker, &queued, 2).unwrap(),
+            "starts in ~10s, done in ~20s"
+        );
+    }
+
+    #[test]
+    fn test_batch_label() {
+        let mut job = Job::new("job-1".to_string(), "sprite".to_string());
+        assert_eq!(batch_label(&job), "");
+
+        job.batch = Some(JobBatch {
+            id: "batch-1".to_string(),
+            index: 1,
+            size: 4,
+            sweep: false,
+        });
+        assert_eq!(batch_label(&job), "2/4");
+    }
 }
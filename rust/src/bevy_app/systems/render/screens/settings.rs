@@ -3,16 +3,21 @@ use bevy_ratatui::terminal::RatatuiContext;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
 
-use crate::bevy_app::resources::{AppTheme, CurrentScreen, Screen};
+use crate::bevy_app::resources::{
+    AppTheme, CurrentScreen, DirBrowser, Screen, SettingsState, ThemePicker,
+};
 
 /// Render the Settings screen
 pub fn render_settings_screen(
     current_screen: Res<CurrentScreen>,
     theme: Res<AppTheme>,
+    settings: Res<SettingsState>,
+    picker: Option<Res<ThemePicker>>,
+    browser: Option<Res<DirBrowser>>,
     mut ratatui: ResMut<RatatuiContext>,
 ) {
     if current_screen.0 != Screen::Settings {
@@ -26,6 +31,7 @@ pub fn render_settings_screen(
                 .constraints([
                     Constraint::Length(3), // Title
                     Constraint::Min(0),    // Content
+                    Constraint::Length(3), // Help for the selected setting
                     Constraint::Length(1), // Status bar
                 ])
                 .split(frame.area());
@@ -33,11 +39,25 @@ pub fn render_settings_screen(
             // Title
             render_title(frame, chunks[0], &theme);
 
-            // Content
-            render_content(frame, chunks[1], &theme);
+            // Content (or the changed-settings view)
+            if settings.show_changes {
+                render_changes(frame, chunks[1], &settings, &theme);
+            } else {
+                render_content(frame, chunks[1], &settings, &theme);
+            }
+
+            // Help
+            render_help(frame, chunks[2], &settings, &theme);
 
             // Status bar
-            render_status_bar(frame, chunks[2], &theme);
+            render_status_bar(frame, chunks[3], &theme);
+
+            if let Some(picker) = picker.as_deref().filter(|p| p.open) {
+                render_theme_picker(frame, chunks[1], picker, &theme);
+            }
+            if let Some(browser) = browser.as_deref().filter(|b| b.open) {
+                render_dir_browser(frame, chunks[1], browser, &theme);
+            }
         })
         .expect("Failed to render settings screen");
 }
@@ -51,14 +71,15 @@ fn render_title(frame: &mut Frame, area: Rect, theme: &AppTheme) {
     frame.render_widget(title, area);
 }
 
-fn render_content(frame: &mut Frame, area: Rect, theme: &AppTheme) {
+fn render_content(frame: &mut Frame, area: Rect, settings: &SettingsState, theme: &AppTheme) {
     let lines = vec![
         Line::from(""),
         Line::from(vec![Span::styled("GENERAL SETTINGS", theme.highlight())]),
         Line::from(""),
         Line::from(vec![
             Span::raw("  Theme:        "),
-            Span::styled("Default", theme.text()),
+            Span::styled(settings.ui.theme.clone(), theme.text()),
+            Span::styled("  (t: change)", theme.muted()),
         ]),
         Line::from(vec![
             Span::raw("  Auto-save:    "),
@@ -116,7 +137,7 @@ fn render_content(frame: &mut Frame, area: Rect, theme: &AppTheme) {
         Line::from(""),
         Line::from(""),
         Line::from(vec![Span::styled(
-            "Note: Settings are read-only in this version",
+            "Up/Down: select a setting  Enter: edit it",
             theme.muted(),
         )]),
         Line::from(vec![Span::styled(
@@ -136,8 +157,164 @@ fn render_content(frame: &mut Frame, area: Rect, theme: &AppTheme) {
     frame.render_widget(paragraph, area);
 }
 
+/// List settings that differ from their defaults, current vs default.
+fn render_changes(frame: &mut Frame, area: Rect, settings: &SettingsState, theme: &AppTheme) {
+    let changes = settings.changed_settings();
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            format!("  {:<28} {:<24} {}", "SETTING", "CURRENT", "DEFAULT"),
+            theme.highlight(),
+        )]),
+        Line::from(""),
+    ];
+
+    if changes.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  All settings are at their defaults",
+            theme.muted(),
+        )));
+    }
+
+    for (i, change) in changes.iter().enumerate() {
+        let selected = i == settings.changes_index;
+        let style = if selected { theme.highlight() } else { theme.text() };
+        lines.push(Line::from(vec![
+            Span::styled(if selected { "> " } else { "  " }, style),
+            Span::styled(format!("{:<28} ", change.name), style),
+            Span::styled(format!("{:<24} ", change.current), theme.warning()),
+            Span::styled(change.default.clone(), theme.muted()),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(" Changed From Defaults ({}) ", changes.len()))
+            .borders(Borders::ALL)
+            .border_style(theme.highlight()),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Theme list popup, centred over the content area.
+fn render_theme_picker(frame: &mut Frame, area: Rect, picker: &ThemePicker, theme: &AppTheme) {
+    let width = 40.min(area.width);
+    let height = (picker.themes.len() as u16 + 2).min(12).min(area.height);
+    let popup = Rect::new(
+        area.x + area.width.saturating_sub(width) / 2,
+        area.y + area.height.saturating_sub(height) / 3,
+        width,
+        height,
+    );
+
+    let visible = height.saturating_sub(2) as usize;
+    // Keep the selection in view
+    let first = picker.selected.saturating_sub(visible.saturating_sub(1));
+    let lines: Vec<Line> = picker
+        .themes
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(visible)
+        .map(|(i, name)| {
+            let selected = i == picker.selected;
+            let marker = if selected { "> " } else { "  " };
+            let style = if selected { theme.highlight() } else { theme.text() };
+            Line::from(Span::styled(format!("{}{}", marker, name), style))
+        })
+        .collect();
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .title(" Theme (Enter: apply, Esc: close) ")
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        ),
+        popup,
+    );
+}
+
+/// Directory browser popup: the directory being listed, then its
+/// subdirectories (or why they couldn't be read).
+fn render_dir_browser(frame: &mut Frame, area: Rect, browser: &DirBrowser, theme: &AppTheme) {
+    let width = 60.min(area.width);
+    let rows = browser.entries.len().max(1) as u16;
+    let height = (rows + 3).min(16).min(area.height);
+    let popup = Rect::new(
+        area.x + area.width.saturating_sub(width) / 2,
+        area.y + area.height.saturating_sub(height) / 3,
+        width,
+        height,
+    );
+
+    let mut lines = vec![Line::from(Span::styled(
+        browser.cwd.display().to_string(),
+        theme.highlight(),
+    ))];
+    if let Some(error) = &browser.error {
+        lines.push(Line::from(Span::styled(error.as_str(), theme.error())));
+    } else if browser.entries.is_empty() {
+        lines.push(Line::from(Span::styled("  (no subdirectories)", theme.muted())));
+    }
+
+    let visible = height.saturating_sub(3) as usize;
+    // Keep the selection in view
+    let first = browser.selected.saturating_sub(visible.saturating_sub(1));
+    lines.extend(browser.entries.iter().enumerate().skip(first).take(visible).map(
+        |(i, name)| {
+            let selected = i == browser.selected;
+            let marker = if selected { "> " } else { "  " };
+            let style = if selected { theme.highlight() } else { theme.text() };
+            Line::from(Span::styled(format!("{}{}/", marker, name), style))
+        },
+    ));
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .title(" Enter: open  Backspace: up  a: accept  Esc: cancel ")
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        ),
+        popup,
+    );
+}
+
+fn render_help(frame: &mut Frame, area: Rect, settings: &SettingsState, theme: &AppTheme) {
+    if settings.show_changes {
+        let help = Paragraph::new(Span::styled(
+            "Up/Down: Select  r: Reset to default  a: Reset all  Esc/Ctrl+D: Close",
+            theme.muted(),
+        ))
+        .block(Block::default().borders(Borders::ALL).border_style(theme.border()));
+        frame.render_widget(help, area);
+        return;
+    }
+
+    // A rejected edit replaces the help text until it's fixed or cancelled;
+    // otherwise the value being edited does
+    let help = match &settings.last_error {
+        Some(error) => Span::styled(error.as_str(), theme.error()),
+        None if settings.is_editing => {
+            Span::styled(format!("{}_", settings.edit_buffer), theme.text())
+        }
+        None => Span::styled(settings.selected_setting_help(), theme.muted()),
+    };
+    let help = Paragraph::new(help).block(
+        Block::default()
+            .title(format!(" {} ", settings.selected_setting_name()))
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(help, area);
+}
+
 fn render_status_bar(frame: &mut Frame, area: Rect, theme: &AppTheme) {
-    let status_text = "Config: ~/.config/dgx-pixels/config.toml | Read-only mode";
+    let status_text = "Config: ~/.config/dgx-pixels/config.toml | Ctrl+S: save";
     let paragraph = Paragraph::new(status_text).style(theme.status_bar());
     frame.render_widget(paragraph, area);
 }
@@ -152,6 +329,7 @@ mod tests {
         let mut app = App::new();
         app.insert_resource(CurrentScreen(Screen::Settings));
         app.insert_resource(AppTheme::default());
+        app.insert_resource(SettingsState::default());
         app.add_systems(Update, render_settings_screen);
     }
 }
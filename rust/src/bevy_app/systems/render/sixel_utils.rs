@@ -2,10 +2,14 @@
 //!
 //! Utilities for rendering and managing Sixel graphics in the terminal.
 //! Handles coordinate calculation, clearing, and proper integration with ratatui.
+//!
+//! Render systems queue images in [`PendingSixelWrites`] while drawing;
+//! [`flush_pending_sixel`] writes them once ratatui has flushed the frame, so
+//! the two never interleave on stdout.
 
 use ratatui::layout::Rect;
 use std::io::{self, Write};
-use tracing::debug;
+use tracing::{debug, warn};
 
 /// Resource to track screen state for Sixel cleanup.
 #[derive(Debug, Clone, Default, bevy::prelude::Resource)]
@@ -21,6 +25,57 @@ impl SixelRenderState {
     }
 }
 
+/// Sixel images queued while the current frame is drawn.
+#[derive(Debug, Clone, Default, bevy::prelude::Resource)]
+pub struct PendingSixelWrites {
+    /// (area, Sixel data), in the order they were drawn
+    writes: Vec<(Rect, String)>,
+}
+
+impl PendingSixelWrites {
+    /// Queue `sixel_data` for `area`, replacing anything already queued there.
+    pub fn queue(&mut self, area: Rect, sixel_data: &str) {
+        self.writes.retain(|(queued, _)| *queued != area);
+        self.writes.push((area, sixel_data.to_string()));
+    }
+
+    /// Number of queued images.
+    pub fn len(&self) -> usize {
+        self.writes.len()
+    }
+
+    /// Whether nothing is queued.
+    pub fn is_empty(&self) -> bool {
+        self.writes.is_empty()
+    }
+
+    /// Write every queued image to `out`, clearing each area first, with one
+    /// flush at the end. The queue is empty afterwards, even on error.
+    ///
+    /// Returns how many images were written.
+    pub fn flush_to(&mut self, out: &mut impl Write) -> io::Result<usize> {
+        let writes = std::mem::take(&mut self.writes);
+        for (area, sixel_data) in &writes {
+            write_sixel(out, *area, sixel_data)?;
+        }
+        out.flush()?;
+        Ok(writes.len())
+    }
+}
+
+/// Bevy system to write the Sixel images queued during rendering.
+///
+/// Runs once per frame, after the screen render systems have drawn.
+pub fn flush_pending_sixel(mut pending: bevy::prelude::ResMut<PendingSixelWrites>) {
+    if pending.is_empty() {
+        return;
+    }
+    match pending.flush_to(&mut io::stdout().lock()) {
+        Ok(count) => debug!("Flushed {} queued Sixel images", count),
+        Err(e) => warn!("Failed to write queued Sixel images: {}", e),
+    }
+}
+
 /// Clear a specific area that may contain Sixel graphics.
 ///
 /// This uses terminal escape sequences to:
@@ -29,7 +84,17 @@ impl SixelRenderState {
 /// 3. Send DECSED (Selective Erase in Display) to clear Sixel graphics
 pub fn clear_sixel_area(area: Rect) -> io::Result<()> {
     let mut stdout = io::stdout();
+    write_clear(&mut stdout, area)?;
+    stdout.flush()?;
+
+    debug!("Cleared Sixel area at ({}, {}) size {}x{}",
+        area.x, area.y, area.width, area.height);
+
+    Ok(())
+}
 
+/// Write the escapes clearing `area` (see [`clear_sixel_area`]), unflushed.
+fn write_clear(out: &mut impl Write, area: Rect) -> io::Result<()> {
     // Convert ratatui coordinates (0-based) to terminal coordinates (1-based)
     let start_row = area.y.saturating_add(1);
     let start_col = area.x.saturating_add(1);
@@ -38,42 +103,21 @@ pub fn clear_sixel_area(area: Rect) -> io::Result<()> {
     for line in 0..area.height {
         let row = start_row.saturating_add(line);
         // Position cursor at start of line
-        write!(stdout, "\x1b[{};{}H", row, start_col)?;
+        write!(out, "\x1b[{};{}H", row, start_col)?;
         // Clear the line with spaces
-        write!(stdout, "{}", " ".repeat(area.width as usize))?;
+        write!(out, "{}", " ".repeat(area.width as usize))?;
     }
 
     // Send DECSED (Selective Erase in Display) - clears sixel graphics
     // ESC [ ? 2 J - Erase saved lines (including Sixel)
-    write!(stdout, "\x1b[?2J")?;
-
-    stdout.flush()?;
-
-    debug!("Cleared Sixel area at ({}, {}) size {}x{}",
-        area.x, area.y, area.width, area.height);
-
-    Ok(())
+    write!(out, "\x1b[?2J")
 }
 
-/// Render Sixel data to a specific terminal area.
-///
-/// This function:
-/// 1. Clears the target area first
-/// 2. Positions the cursor correctly
-/// 3. Writes the Sixel data
-/// 4. Flushes stdout
-///
-/// ## Coordinate System
-///
-/// Ratatui uses 0-based coordinates for layout areas.
-/// Terminal escape sequences use 1-based coordinates.
-/// This function handles the conversion.
-pub fn render_sixel_to_area(area: Rect, sixel_data: &str) -> io::Result<()> {
-    let mut stdout = io::stdout();
-
+/// Write `sixel_data` into `area`, clearing it first, unflushed.
+fn write_sixel(out: &mut impl Write, area: Rect, sixel_data: &str) -> io::Result<()> {
     // Clear the area first to remove any previous content
     // This is important for preventing ghosting when images change
-    clear_sixel_area(area)?;
+    write_clear(out, area)?;
 
     // Convert ratatui coordinates (0-based) to terminal coordinates (1-based)
     let row = area.y.saturating_add(1);
@@ -81,14 +125,8 @@ pub fn render_sixel_to_area(area: Rect, sixel_data: &str) -> io::Result<()> {
 
     // Position cursor and write Sixel data
     // ESC [ row ; col H - Cursor Position
-    write!(stdout, "\x1b[{};{}H", row, col)?;
-    write!(stdout, "{}", sixel_data)?;
-    stdout.flush()?;
-
-    debug!("Rendered Sixel to area at ({}, {}) size {}x{}",
-        area.x, area.y, area.width, area.height);
-
-    Ok(())
+    write!(out, "\x1b[{};{}H", row, col)?;
+    write!(out, "{}", sixel_data)
 }
 
 /// Clear all Sixel graphics from the terminal.
@@ -153,11 +191,22 @@ mod tests {
     }
 
     #[test]
-    fn test_render_sixel_to_area_returns_ok() {
-        // This test just verifies the function signature and basic execution
-        // We can't test actual terminal output in unit tests
-        let area = Rect::new(0, 0, 10, 10);
-        // This might fail in CI without a terminal, but that's okay
-        let _ = render_sixel_to_area(area, "test");
+    fn test_pending_writes_flush_in_order() {
+        let mut pending = PendingSixelWrites::default();
+        pending.queue(Rect::new(0, 0, 2, 1), "old");
+        pending.queue(Rect::new(4, 2, 2, 1), "second");
+        // Redrawing an area replaces its queued image
+        pending.queue(Rect::new(0, 0, 2, 1), "first");
+        assert_eq!(pending.len(), 2);
+
+        let mut out = Vec::new();
+        assert_eq!(pending.flush_to(&mut out).unwrap(), 2);
+        assert!(pending.is_empty());
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(!out.contains("old"));
+        let second = out.find("\x1b[3;5H  \x1b[?2J\x1b[3;5Hsecond").unwrap();
+        let first = out.find("\x1b[1;1Hfirst").unwrap();
+        assert!(second < first);
     }
 }
@@ -143,6 +143,139 @@ pub fn progress_bar_with_eta(progress: f32, width: u16, eta_s: f32, theme: &AppT
     ])
 }
 
+/// Create an indeterminate progress bar for work with unknown duration.
+///
+/// A short highlighted segment bounces across the bar, positioned from
+/// `elapsed` so it animates on each redraw; the elapsed time follows the bar.
+/// Renders e.g. `[░░░███░░░░░░] 1.5s`.
+pub fn indeterminate_progress_bar(
+    elapsed: std::time::Duration,
+    width: u16,
+    theme: &AppTheme,
+) -> Line<'static> {
+    let width = width.max(1) as usize;
+    let segment = (width / 4).max(1);
+    let travel = width - segment;
+
+    // One step every 100ms, back and forth
+    let step = (elapsed.as_millis() / 100) as usize;
+    let offset = if travel == 0 {
+        0
+    } else {
+        let phase = step % (travel * 2);
+        if phase <= travel {
+            phase
+        } else {
+            travel * 2 - phase
+        }
+    };
+
+    Line::from(vec![
+        Span::raw("["),
+        Span::styled("░".repeat(offset), theme.muted()),
+        Span::styled("█".repeat(segment), theme.highlight()),
+        Span::styled("░".repeat(travel - offset), theme.muted()),
+        Span::raw("]"),
+        Span::raw(" "),
+        Span::styled(format!("{:.1}s", elapsed.as_secs_f32()), theme.muted()),
+    ])
+}
+
+/// Truncate text to `max_chars` characters, ending with "..." when cut.
+///
+/// Counts characters rather than bytes so multi-byte filenames never split
+/// inside a UTF-8 sequence.
+pub fn truncate_with_ellipsis(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+
+    if max_chars <= 3 {
+        return text.chars().take(max_chars).collect();
+    }
+
+    let kept: String = text.chars().take(max_chars - 3).collect();
+    format!("{}...", kept)
+}
+
+/// Build the lines of a small multi-line text area.
+///
+/// Splits `text` on embedded `\n` so each prompt line gets its own row.
+/// Shows `placeholder` (muted) when the text is empty.
+pub fn text_area_lines<'a>(text: &'a str, placeholder: &'a str, theme: &AppTheme) -> Vec<Line<'a>> {
+    if text.is_empty() {
+        return vec![Line::from(Span::styled(placeholder, theme.muted()))];
+    }
+
+    text.split('\n')
+        .map(|line| Line::from(Span::styled(line, theme.text())))
+        .collect()
+}
+
+/// One word of a [`word_diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordChange<'a> {
+    /// In both prompts
+    Same(&'a str),
+    /// Only in the new prompt
+    Added(&'a str),
+    /// Only in the old prompt
+    Removed(&'a str),
+}
+
+/// Word-level diff from `old` to `new` (longest common subsequence of
+/// whitespace-separated words).
+pub fn word_diff<'a>(old: &'a str, new: &'a str) -> Vec<WordChange<'a>> {
+    let old: Vec<&str> = old.split_whitespace().collect();
+    let new: Vec<&str> = new.split_whitespace().collect();
+
+    // lcs[i][j] = common words in old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut changes = Vec::new();
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            changes.push(WordChange::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            changes.push(WordChange::Added(new[j]));
+            j += 1;
+        } else {
+            changes.push(WordChange::Removed(old[i]));
+            i += 1;
+        }
+    }
+    changes
+}
+
+/// One-line summary of prompt edits: added words green, removed words red
+/// and struck through, unchanged words muted.
+pub fn prompt_diff_line<'a>(old: &'a str, new: &'a str, theme: &AppTheme) -> Line<'a> {
+    let mut spans = vec![Span::raw(" ")];
+    for change in word_diff(old, new) {
+        spans.push(match change {
+            WordChange::Same(word) => Span::styled(word, theme.muted()),
+            WordChange::Added(word) => Span::styled(word, theme.success()),
+            WordChange::Removed(word) => {
+                Span::styled(word, theme.error().add_modifier(Modifier::CROSSED_OUT))
+            }
+        });
+        spans.push(Span::raw(" "));
+    }
+    Line::from(spans)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,4 +349,56 @@ mod tests {
         let bar = progress_bar_with_eta(0.1, 20, 7200.0, &theme);
         assert!(bar.spans.len() > 0);
     }
+
+    #[test]
+    fn test_text_area_lines() {
+        let theme = AppTheme::default();
+
+        assert_eq!(text_area_lines("", "Type here", &theme).len(), 1);
+        assert_eq!(text_area_lines("one line", "", &theme).len(), 1);
+
+        let lines = text_area_lines("positive\nnegative\n", "", &theme);
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[1].spans[0].content, "negative");
+    }
+
+    #[test]
+    fn test_truncate_with_ellipsis() {
+        assert_eq!(truncate_with_ellipsis("short.png", 20), "short.png");
+        assert_eq!(truncate_with_ellipsis("very_long_name.png", 10), "very_lo...");
+        assert_eq!(truncate_with_ellipsis("abcdef", 2), "ab");
+
+        // Multi-byte characters are never split
+        assert_eq!(truncate_with_ellipsis("日本語のファイル名.png", 6), "日本語...");
+    }
+
+    #[test]
+    fn test_word_diff_finds_added_and_removed_words() {
+        let changes = word_diff("pixel art knight with sword", "pixel art red knight with shield");
+
+        let added: Vec<&str> = changes
+            .iter()
+            .filter_map(|c| match c {
+                WordChange::Added(w) => Some(*w),
+                _ => None,
+            })
+            .collect();
+        let removed: Vec<&str> = changes
+            .iter()
+            .filter_map(|c| match c {
+                WordChange::Removed(w) => Some(*w),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(added, vec!["red", "shield"]);
+        assert_eq!(removed, vec!["sword"]);
+        assert_eq!(
+            changes.iter().filter(|c| matches!(c, WordChange::Same(_))).count(),
+            4
+        );
+        assert!(word_diff("same prompt", "same  prompt")
+            .iter()
+            .all(|c| matches!(c, WordChange::Same(_))));
+    }
 }
@@ -0,0 +1,129 @@
+//! # Shutdown Systems
+//!
+//! Turns a quit request into `AppExit` and persists state when the app exits
+//! (via quit or bevy_ratatui's Ctrl+C), using the shared
+//! [`crate::shutdown::shutdown`] routine.
+
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use tracing::{info, warn};
+
+use crate::bevy_app::components::Job;
+use crate::bevy_app::resources::{AppState, JobHistory, SettingsState};
+use crate::bevy_app::systems::config_reload::ConfigWatcher;
+use crate::shutdown::{shutdown, ShutdownPaths, ShutdownState};
+
+/// Where the Bevy app persists state on exit.
+#[derive(Resource, Debug, Clone)]
+pub struct ShutdownConfig {
+    pub paths: ShutdownPaths,
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        Self {
+            paths: ShutdownPaths {
+                settings: SettingsState::config_path(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Send `AppExit` once a quit has been requested.
+pub fn exit_on_quit(app_state: Res<AppState>, mut exit: EventWriter<AppExit>) {
+    if app_state.should_quit {
+        exit.send(AppExit::Success);
+    }
+}
+
+/// Persist job history (cleared and live jobs) and dirty settings when the app exits.
+///
+/// Runs in `Last` so it sees every `AppExit` sent during the frame. With a
+/// [`ConfigWatcher`], dirty settings are saved through it like the app's
+/// other settings writes.
+pub fn persist_on_exit(
+    mut exits: EventReader<AppExit>,
+    jobs: Query<&Job>,
+    history: Option<Res<JobHistory>>,
+    settings: Option<Res<SettingsState>>,
+    config: Option<Res<ShutdownConfig>>,
+    watcher: Option<ResMut<ConfigWatcher>>,
+    mut done: Local<bool>,
+) {
+    if exits.read().next().is_none() || *done {
+        return;
+    }
+    *done = true;
+
+    let mut dirty = settings.as_deref().filter(|s| s.dirty);
+    if let (Some(settings), Some(mut watcher)) = (dirty, watcher) {
+        if let Err(e) = watcher.save(settings) {
+            warn!("Shutdown: failed to save settings: {:#}", e);
+        }
+        dirty = None;
+    }
+    let dirty_settings = match dirty.map(SettingsState::to_toml) {
+        Some(Ok(content)) => Some(content),
+        Some(Err(e)) => {
+            warn!("Shutdown: failed to serialize settings: {:#}", e);
+            None
+        }
+        None => None,
+    };
+
+    let mut job_history = history.map(|h| h.entries.clone()).unwrap_or_default();
+    job_history.extend(jobs.iter().map(Into::into));
+
+    let state = ShutdownState {
+        job_history,
+        comparisons: Vec::new(),
+        dirty_settings,
+    };
+
+    let paths = config.map(|c| c.paths.clone()).unwrap_or_default();
+    let written = shutdown(&state, &paths);
+    info!("Persisted {} file(s) on exit", written.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bevy_app::components::JobStatus;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_exit_persists_jobs_and_dirty_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = ShutdownPaths::in_dir(dir.path());
+
+        let mut app = App::new();
+        app.add_event::<AppExit>();
+        app.insert_resource(ShutdownConfig {
+            paths: paths.clone(),
+        });
+        app.insert_resource(SettingsState {
+            dirty: true,
+            ..Default::default()
+        });
+        app.add_systems(Last, persist_on_exit);
+
+        let mut job = Job::new("job-1".to_string(), "knight".to_string());
+        job.status = JobStatus::Complete {
+            image_path: PathBuf::from("out.png"),
+            duration_s: 3.0,
+        };
+        app.world_mut().spawn(job);
+
+        // Nothing is written until the app exits
+        app.update();
+        assert!(!paths.job_history.exists());
+
+        app.world_mut().send_event(AppExit::Success);
+        app.update();
+
+        let history = std::fs::read_to_string(&paths.job_history).unwrap();
+        assert!(history.contains("job-1"));
+        assert!(paths.settings.exists());
+    }
+}
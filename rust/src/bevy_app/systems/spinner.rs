@@ -0,0 +1,88 @@
+//! # Loading Spinner
+//!
+//! A braille spinner shown next to work in progress (the Generation screen's
+//! active job, comparison panes that are generating). The frame advances
+//! every [`SPINNER_INTERVAL`], but only while something is running, so an
+//! idle app isn't redrawn ten times a second.
+
+use bevy::prelude::*;
+use std::time::Duration;
+
+use crate::bevy_app::components::Job;
+use crate::bevy_app::resources::{AppState, ComparisonState};
+
+/// Frames of the spinner, in order.
+pub const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Time between spinner frames (about 10fps).
+pub const SPINNER_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Current frame of the spinner.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct Spinner {
+    pub index: usize,
+}
+
+impl Spinner {
+    /// The frame to draw.
+    pub fn frame(&self) -> &'static str {
+        SPINNER_FRAMES[self.index % SPINNER_FRAMES.len()]
+    }
+
+    /// Move to the next frame, wrapping after the last.
+    pub fn advance(&mut self) {
+        self.index = (self.index + 1) % SPINNER_FRAMES.len();
+    }
+}
+
+/// Advance the spinner while a job or comparison is running.
+pub fn advance_spinner(
+    mut spinner: ResMut<Spinner>,
+    jobs: Query<&Job>,
+    comparison: Option<Res<ComparisonState>>,
+    app_state: Option<ResMut<AppState>>,
+) {
+    let running =
+        jobs.iter().any(Job::is_active) || comparison.is_some_and(|c| c.is_running);
+    if !running {
+        return;
+    }
+
+    spinner.advance();
+    if let Some(mut app_state) = app_state {
+        app_state.request_redraw();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::common_conditions::on_timer;
+
+    fn tick(app: &mut App) {
+        app.world_mut().resource_mut::<Time>().advance_by(SPINNER_INTERVAL);
+        app.update();
+    }
+
+    #[test]
+    fn test_spinner_advances_while_running_and_wraps() {
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.init_resource::<Spinner>();
+        app.add_systems(Update, advance_spinner.run_if(on_timer(SPINNER_INTERVAL)));
+
+        // Nothing running: the spinner stays put
+        tick(&mut app);
+        assert_eq!(app.world().resource::<Spinner>().index, 0);
+
+        app.world_mut().spawn(Job::new("job-1".to_string(), "pixel knight".to_string()));
+        tick(&mut app);
+        assert_eq!(app.world().resource::<Spinner>().frame(), SPINNER_FRAMES[1]);
+
+        for _ in 1..SPINNER_FRAMES.len() {
+            tick(&mut app);
+        }
+        assert_eq!(app.world().resource::<Spinner>().index, 0);
+        assert_eq!(app.world().resource::<Spinner>().frame(), SPINNER_FRAMES[0]);
+    }
+}
@@ -14,11 +14,36 @@ pub fn init_app_state(mut commands: Commands) {
     info!("Initializing application state resources");
 
     // Insert all resource defaults
-    commands.insert_resource(AppState::default());
+    // `--debug` reaches the app through the environment (see main.rs)
+    commands.insert_resource(AppState {
+        debug_mode: std::env::var("DGX_PIXELS_DEBUG").is_ok(),
+        ..Default::default()
+    });
     commands.insert_resource(CurrentScreen::default());
-    commands.insert_resource(InputBuffer::default());
-    commands.insert_resource(GalleryState::default());
-    commands.insert_resource(JobTracker::default());
+    // Start with the prompt submitted last session
+    commands.insert_resource(InputBuffer::load_last());
+    commands.insert_resource(PromptPresets::load().unwrap_or_else(|e| {
+        warn!("Failed to load prompt presets: {:#}", e);
+        PromptPresets::default()
+    }));
+    commands.insert_resource(Keybindings::load().unwrap_or_else(|e| {
+        warn!("Failed to load keybindings: {:#}", e);
+        Keybindings::default()
+    }));
+    let mut gallery = GalleryState::default();
+    if let Err(e) = gallery.load_favorites_from(&GalleryState::default_favorites_path()) {
+        warn!("Failed to load favorites: {:#}", e);
+    }
+    commands.insert_resource(gallery);
+    // Restore the models active last session
+    let mut models = ModelsState::default();
+    if let Err(e) = models.load_active(&ModelsState::default_active_path()) {
+        warn!("Failed to load active models: {:#}", e);
+    }
+    commands.insert_resource(models);
+    commands.insert_resource(GalleryZoom::default());
+    commands.insert_resource(JobTracker::with_jobs_log(JobTracker::default_jobs_log()));
+    commands.insert_resource(JobHistory::default());
 
     info!("Application state resources initialized");
 }
@@ -38,6 +63,7 @@ mod tests {
         assert!(app.world().contains_resource::<CurrentScreen>());
         assert!(app.world().contains_resource::<InputBuffer>());
         assert!(app.world().contains_resource::<GalleryState>());
+        assert!(app.world().contains_resource::<ModelsState>());
         assert!(app.world().contains_resource::<JobTracker>());
     }
 }
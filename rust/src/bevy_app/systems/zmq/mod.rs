@@ -14,16 +14,18 @@ use bevy::prelude::*;
 use parking_lot::Mutex;
 use std::sync::Arc;
 
-use crate::zmq_client::ZmqClient;
+use crate::backend::Backend;
 
-/// Thread-safe ZeroMQ client resource for Bevy.
+/// Thread-safe backend client resource for Bevy.
+///
+/// Holds the ZeroMQ client, or the demo backend with `--demo`.
 #[derive(Resource, Clone)]
-pub struct ZmqClientResource(pub Arc<Mutex<ZmqClient>>);
+pub struct ZmqClientResource(pub Arc<Mutex<Box<dyn Backend>>>);
 
 impl ZmqClientResource {
-    /// Create new resource from ZmqClient.
-    pub fn new(client: ZmqClient) -> Self {
-        Self(Arc::new(Mutex::new(client)))
+    /// Create new resource from a backend client (usually a `ZmqClient`).
+    pub fn new(client: impl Backend + 'static) -> Self {
+        Self(Arc::new(Mutex::new(Box::new(client))))
     }
 }
 
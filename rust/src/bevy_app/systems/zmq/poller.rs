@@ -5,16 +5,27 @@
 use bevy::prelude::*;
 
 use super::ZmqClientResource;
+use crate::bevy_app::components::{Job, JobStatus};
 use crate::bevy_app::events::{GenerationComplete, JobProgressUpdate, JobStarted};
+use crate::bevy_app::resources::{
+    CurrentScreen, JobTracker, ModelsState, MonitorState, NotificationLevel, Notifications,
+    Screen,
+};
 
 /// Poll ZMQ client for responses and updates.
 ///
 /// Runs in PreUpdate schedule to process backend messages before main logic.
+#[allow(clippy::too_many_arguments)]
 pub fn poll_zmq(
     zmq_client: Option<Res<ZmqClientResource>>,
     mut response_events: EventWriter<GenerationComplete>,
     mut progress_events: EventWriter<JobProgressUpdate>,
     mut started_events: EventWriter<JobStarted>,
+    mut monitor: Option<ResMut<MonitorState>>,
+    mut notifications: Option<ResMut<Notifications>>,
+    mut models: Option<ResMut<ModelsState>>,
+    mut jobs: Query<&mut Job>,
+    mut job_tracker: Option<ResMut<JobTracker>>,
 ) {
     let Some(zmq_client) = zmq_client else {
         return; // No ZMQ client configured
@@ -22,6 +33,14 @@ pub fn poll_zmq(
 
     let client = zmq_client.0.lock();
 
+    if let Some(ref mut monitor) = monitor {
+        let state = client.connection_state();
+        if monitor.connection != Some(state) {
+            info!("Backend connection: {}", state);
+            monitor.set_connection(state);
+        }
+    }
+
     // Poll for responses
     while let Some(response) = client.try_recv_response() {
         use crate::messages::Response;
@@ -54,7 +73,33 @@ pub fn poll_zmq(
             }
             Response::JobError { job_id, error } => {
                 error!("Job failed: {} - {}", job_id, error);
-                // TODO: Emit JobFailed event
+                if let Some(notifications) = notifications.as_mut() {
+                    let message = format!("Job {} failed: {}", short_id(&job_id), error);
+                    notifications.notify(NotificationLevel::Error, message);
+                }
+                // Failing the job frees its slot for the next queued one
+                if let Some(mut job) = jobs.iter_mut().find(|job| job.id == job_id) {
+                    job.finish(JobStatus::Failed { error }, std::time::Instant::now());
+                    if let Some(job_tracker) = job_tracker.as_mut() {
+                        job_tracker.fail_job();
+                    }
+                }
+            }
+            Response::Error { message }
+                if monitor.as_mut().is_some_and(|monitor| monitor.stats_error(&message)) =>
+            {
+                warn!("Backend doesn't provide system stats: {}", message);
+            }
+            Response::Error { message } => {
+                error!("Backend error: {}", message);
+                if let Some(notifications) = notifications.as_mut() {
+                    notifications.notify(NotificationLevel::Error, format!("Backend: {}", message));
+                }
+            }
+            Response::SystemStats(stats) => {
+                if let Some(monitor) = monitor.as_mut() {
+                    monitor.record(stats, std::time::Instant::now());
+                }
             }
             _ => {
                 debug!("Received other response: {:?}", response);
@@ -135,10 +180,61 @@ pub fn poll_zmq(
                 );
                 // Handled by Response::JobComplete
             }
+            ProgressUpdate::ModelDownload { model, percent } => {
+                debug!("Model {} download: {}%", model, percent);
+                if let Some(models) = models.as_mut() {
+                    if models.record_download_progress(&model, percent) && percent >= 100 {
+                        info!("Model {} downloaded", model);
+                    }
+                }
+            }
+            ProgressUpdate::ModelDownloadFailed { model, error } => {
+                warn!("Model {} download failed: {}", model, error);
+                if let Some(models) = models.as_mut() {
+                    models.fail_download(&model);
+                }
+                if let Some(notifications) = notifications.as_mut() {
+                    let message = format!("Download of {} failed: {}", model, error);
+                    notifications.notify(NotificationLevel::Error, message);
+                }
+            }
         }
     }
 }
 
+/// First 8 characters of a job ID, as the Queue screen shows it.
+fn short_id(job_id: &str) -> &str {
+    job_id.get(..8).unwrap_or(job_id)
+}
+
+/// Ask the backend for GPU/memory stats while the Monitor screen is shown.
+///
+/// Runs every [`MONITOR_POLL_INTERVAL`](crate::bevy_app::resources::MONITOR_POLL_INTERVAL);
+/// replies are recorded by [`poll_zmq`]. Skipped while earlier requests are
+/// still waiting (e.g. being retried), so requests don't pile up while the
+/// backend is unreachable, and once the backend has answered with an error.
+pub fn poll_system_stats(
+    zmq_client: Option<Res<ZmqClientResource>>,
+    current_screen: Res<CurrentScreen>,
+    mut monitor: ResMut<MonitorState>,
+) {
+    let Some(zmq_client) = zmq_client else {
+        return; // No backend: the Monitor screen shows N/A
+    };
+    if current_screen.0 != Screen::Monitor || monitor.stats_unsupported {
+        return;
+    }
+
+    let client = zmq_client.0.lock();
+    if client.outstanding_requests() > 0 {
+        return;
+    }
+    match client.request_system_stats() {
+        Ok(()) => monitor.stats_pending = true,
+        Err(e) => debug!("Failed to request system stats: {}", e),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -155,4 +251,161 @@ mod tests {
         // Should not panic without ZMQ client
         app.update();
     }
+
+    #[test]
+    fn test_model_download_updates_set_status() {
+        use crate::bevy_app::resources::models::ModelStatus;
+        use crate::messages::ProgressUpdate;
+
+        let updates = [
+            ProgressUpdate::ModelDownload {
+                model: "SDXL Refiner 1.0".to_string(),
+                percent: 60,
+            },
+            ProgressUpdate::ModelDownload {
+                model: "SDXL Refiner 1.0".to_string(),
+                percent: 100,
+            },
+            ProgressUpdate::ModelDownloadFailed {
+                model: "Game Assets LoRA v2".to_string(),
+                error: "disk full".to_string(),
+            },
+        ];
+        let backend = crate::backend::RecordingBackend {
+            updates: std::sync::Mutex::new(updates.into()),
+            ..Default::default()
+        };
+
+        let mut app = App::new();
+        app.add_event::<GenerationComplete>();
+        app.add_event::<JobProgressUpdate>();
+        app.add_event::<JobStarted>();
+        app.insert_resource(ZmqClientResource::new(backend));
+        app.init_resource::<ModelsState>();
+        app.init_resource::<Notifications>();
+        app.add_systems(Update, poll_zmq);
+        app.update();
+
+        let models = app.world().resource::<ModelsState>();
+        let status = |name: &str| {
+            models.models.iter().find(|m| m.name == name).unwrap().status.clone()
+        };
+        assert_eq!(status("SDXL Refiner 1.0"), ModelStatus::Downloaded);
+        assert_eq!(status("Game Assets LoRA v2"), ModelStatus::Failed);
+        assert_eq!(app.world().resource::<Notifications>().len(), 1);
+    }
+
+    #[test]
+    fn test_job_error_fails_the_job() {
+        use crate::backend::RecordingBackend;
+        use crate::messages::Response;
+
+        let backend = RecordingBackend::default();
+        backend.responses.lock().unwrap().push_back(Response::JobError {
+            job_id: "job-1".to_string(),
+            error: "out of memory".to_string(),
+        });
+
+        let mut app = App::new();
+        app.add_event::<GenerationComplete>();
+        app.add_event::<JobProgressUpdate>();
+        app.add_event::<JobStarted>();
+        app.insert_resource(ZmqClientResource::new(backend));
+        app.init_resource::<JobTracker>();
+        app.add_systems(Update, poll_zmq);
+        let mut job = Job::new("job-1".to_string(), "knight".to_string());
+        job.status = JobStatus::Generating {
+            stage: "sampling".to_string(),
+            progress: 0.5,
+            eta_s: 3.0,
+        };
+        let failed = app.world_mut().spawn(job).id();
+        let other = app
+            .world_mut()
+            .spawn(Job::new("job-2".to_string(), "mage".to_string()))
+            .id();
+        app.update();
+
+        let status = |entity| app.world().get::<Job>(entity).unwrap().status.clone();
+        assert_eq!(
+            status(failed),
+            JobStatus::Failed {
+                error: "out of memory".to_string()
+            }
+        );
+        assert_eq!(status(other), JobStatus::Pending);
+        assert_eq!(app.world().resource::<JobTracker>().total_failed, 1);
+        // Stamped as it fails, so the queue cleanup counts from now
+        assert!(app.world().get::<Job>(failed).unwrap().completed_at.is_some());
+        assert!(app.world().get::<Job>(other).unwrap().completed_at.is_none());
+    }
+
+    #[test]
+    fn test_stats_polled_on_monitor_until_rejected() {
+        let client = ZmqClientResource::new(crate::backend::RecordingBackend {
+            reject_with: Some("Unknown request type: get_system_stats".to_string()),
+            ..Default::default()
+        });
+        let mut app = App::new();
+        app.add_event::<GenerationComplete>();
+        app.add_event::<JobProgressUpdate>();
+        app.add_event::<JobStarted>();
+        app.insert_resource(client);
+        app.insert_resource(CurrentScreen(Screen::Generation));
+        app.init_resource::<MonitorState>();
+        app.init_resource::<Notifications>();
+        // Replies are read the frame after a request
+        app.add_systems(Update, (poll_zmq, poll_system_stats).chain());
+
+        // Not asked for off the Monitor screen
+        app.update();
+        assert!(!app.world().resource::<MonitorState>().stats_pending);
+
+        app.world_mut().resource_mut::<CurrentScreen>().0 = Screen::Monitor;
+        app.update();
+        assert!(app.world().resource::<MonitorState>().stats_pending);
+
+        app.update();
+        let monitor = app.world().resource::<MonitorState>();
+        assert!(monitor.stats_unsupported);
+        // ...and stats aren't requested again
+        assert!(!monitor.stats_pending);
+        // The rejection isn't shown as an error toast
+        assert!(app.world().resource::<Notifications>().is_empty());
+    }
+
+    #[test]
+    fn test_unrelated_errors_shown_while_stats_pending() {
+        use crate::backend::RecordingBackend;
+        use crate::messages::Response;
+
+        let backend = RecordingBackend::default();
+        backend.responses.lock().unwrap().push_back(Response::Error {
+            message: "Model not found: retro".to_string(),
+        });
+        let mut app = App::new();
+        app.add_event::<GenerationComplete>();
+        app.add_event::<JobProgressUpdate>();
+        app.add_event::<JobStarted>();
+        app.insert_resource(ZmqClientResource::new(backend));
+        app.init_resource::<MonitorState>();
+        app.init_resource::<Notifications>();
+        app.add_systems(Update, poll_zmq);
+        {
+            let mut monitor = app.world_mut().resource_mut::<MonitorState>();
+            monitor.connection = Some(crate::zmq_client::ConnectionState::Connected);
+            monitor.stats_pending = true;
+        }
+        app.update();
+
+        let monitor = app.world().resource::<MonitorState>();
+        assert!(monitor.stats_pending);
+        assert!(!monitor.stats_unsupported);
+        let notifications = app.world().resource::<Notifications>();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(
+            notifications.visible().next().unwrap().message,
+            "Backend: Model not found: retro"
+        );
+    }
 }
@@ -3,10 +3,12 @@
 //! Processes GenerationComplete events and updates Job entities.
 
 use bevy::prelude::*;
+use std::time::Instant;
 
 use crate::bevy_app::components::{Job, JobStatus};
 use crate::bevy_app::events::GenerationComplete;
 use crate::bevy_app::resources::{AppState, GalleryState, JobTracker};
+use crate::bevy_app::systems::assets::sidecar::{save_sidecar, ImageSidecar, SidecarCache};
 
 /// Handle job completion responses from backend.
 pub fn handle_zmq_responses(
@@ -15,6 +17,7 @@ pub fn handle_zmq_responses(
     mut gallery: ResMut<GalleryState>,
     mut job_tracker: ResMut<JobTracker>,
     mut app_state: ResMut<AppState>,
+    mut sidecars: Option<ResMut<SidecarCache>>,
 ) {
     for event in complete_events.read() {
         info!("Processing job completion: {}", event.job_id);
@@ -52,20 +55,45 @@ pub fn handle_zmq_responses(
         for mut job in job_query.iter_mut() {
             if job.id == event.job_id {
                 let duration_s = job.elapsed().as_secs_f32();
-                job.status = JobStatus::Complete {
+                let status = JobStatus::Complete {
                     image_path: gallery_path.clone(),
                     duration_s,
                 };
+                job.finish(status, Instant::now());
                 job_found = true;
 
+                // Record generation metadata (kept next to the image for later sessions)
+                let sidecar = ImageSidecar {
+                    timestamp: Some(chrono::Utc::now()),
+                    duration_s: Some(duration_s),
+                    ..job.metadata.clone().unwrap_or_else(|| ImageSidecar {
+                        prompt: Some(job.prompt.clone()),
+                        negative_prompt: job.negative_prompt.clone(),
+                        ..Default::default()
+                    })
+                };
+                if event.image_path.exists() {
+                    if let Err(e) = save_sidecar(&event.image_path, &sidecar) {
+                        warn!("Failed to write sidecar for {:?}: {:#}", event.image_path, e);
+                    }
+                }
+                if !sidecar.tags.is_empty() {
+                    gallery.set_tags(gallery_path.clone(), sidecar.tags.clone());
+                }
+                if let Some(sidecars) = sidecars.as_mut() {
+                    sidecars.insert(gallery_path.clone(), sidecar);
+                }
+
                 // Add to gallery with converted path
                 gallery.add_image(gallery_path.clone());
 
                 // Update tracker
                 job_tracker.complete_job();
+                job_tracker.record_duration(duration_s);
+                job_tracker.log_completed(&job);
 
-                // Request redraw
-                app_state.request_redraw();
+                // Show it in the preview pane (also requests a redraw)
+                app_state.set_completed_preview(gallery_path.clone());
 
                 info!("Job {} marked complete, added to gallery", event.job_id);
                 break;
@@ -109,4 +137,34 @@ mod tests {
         let mut job_query = app.world_mut().query::<&Job>();
         assert_eq!(job_query.iter(app.world()).count(), 1);
     }
+
+    #[test]
+    fn test_completions_shift_current_preview_to_previous() {
+        let mut app = App::new();
+        app.add_event::<GenerationComplete>();
+        app.insert_resource(GalleryState::default());
+        app.insert_resource(JobTracker::default());
+        app.insert_resource(AppState::default());
+        app.add_systems(Update, handle_zmq_responses);
+
+        for (job_id, path) in [("job-1", "outputs/job-1.png"), ("job-2", "outputs/job-2.png")] {
+            app.world_mut()
+                .spawn(Job::new(job_id.into(), "knight".into()));
+            app.world_mut().send_event(GenerationComplete {
+                job_id: job_id.into(),
+                image_path: PathBuf::from(path),
+            });
+            app.update();
+        }
+
+        let app_state = app.world().resource::<AppState>();
+        assert_eq!(
+            app_state.previous_preview,
+            Some(PathBuf::from("outputs/job-1.png"))
+        );
+        assert_eq!(
+            app_state.current_preview,
+            Some(PathBuf::from("outputs/job-2.png"))
+        );
+    }
 }
@@ -1,8 +1,10 @@
 //! # ZMQ Update Handler
 //!
-//! Processes progress updates from backend and updates Job entities.
+//! Processes progress updates from backend and updates Job entities, and
+//! flags generating jobs whose updates have stopped.
 
 use bevy::prelude::*;
+use std::time::Instant;
 
 use crate::bevy_app::components::{Job, JobStatus};
 use crate::bevy_app::events::{JobProgressUpdate, JobStarted};
@@ -50,12 +52,8 @@ pub fn handle_zmq_updates(
                 // Format stage name for display
                 let stage_name = format_stage(&event.stage, event.step, event.total_steps);
 
-                // Update job status to Generating
-                job.status = JobStatus::Generating {
-                    stage: stage_name,
-                    progress: event.percent,
-                    eta_s: event.eta_s,
-                };
+                // Update job status to Generating (ETA smoothed)
+                job.record_progress(stage_name, event.percent, event.eta_s, Instant::now());
 
                 job_found = true;
                 app_state.request_redraw();
@@ -88,6 +86,21 @@ pub fn handle_zmq_updates(
     }
 }
 
+/// Flag generating jobs that haven't reported progress recently.
+pub fn detect_stalled_jobs(mut job_query: Query<&mut Job>, mut app_state: ResMut<AppState>) {
+    let now = Instant::now();
+    for mut job in job_query.iter_mut() {
+        // Only mark jobs whose flag changed, for `Changed<Job>` queries
+        if job.bypass_change_detection().check_stalled(now) {
+            job.set_changed();
+            if job.stalled {
+                warn!("Job {} has not reported progress recently", job.id);
+            }
+            app_state.request_redraw();
+        }
+    }
+}
+
 /// Format stage name for display with step information.
 fn format_stage(stage: &GenerationStage, step: u32, total_steps: u32) -> String {
     let stage_name = match stage {
@@ -192,6 +205,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_detect_stalled_jobs() {
+        let mut app = App::new();
+        app.insert_resource(AppState::default());
+        app.add_systems(Update, detect_stalled_jobs);
+
+        let stale = Instant::now() - crate::progress::STALL_TIMEOUT * 2;
+        let mut stuck = Job::new("stuck".into(), "test prompt".into());
+        stuck.record_progress("Sampling".into(), 0.5, 10.0, stale);
+        let mut queued = Job::new("queued".into(), "test prompt".into());
+        queued.last_progress_at = Some(stale);
+        queued.status = JobStatus::Queued;
+        app.world_mut().spawn(stuck);
+        app.world_mut().spawn(queued);
+
+        app.update();
+
+        let mut job_query = app.world_mut().query::<&Job>();
+        for job in job_query.iter(app.world()) {
+            assert_eq!(job.stalled, job.id == "stuck");
+        }
+    }
+
     #[test]
     fn test_format_stage() {
         // Test sampling stage with step info
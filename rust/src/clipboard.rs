@@ -0,0 +1,88 @@
+//! # Clipboard
+//!
+//! Copying text (prompts, summaries) to the system clipboard. With the
+//! `clipboard` feature, text is sent to the terminal as an OSC 52 sequence,
+//! which works over SSH and without a display server in terminals that
+//! support it (kitty, WezTerm, iTerm2, foot, tmux with `set-clipboard on`).
+//! Without the feature, copying fails with an error, so headless builds
+//! need no clipboard support at all.
+//!
+//! A failed copy is never fatal: [`copy_or_log`] logs it and carries on.
+
+use anyhow::Result;
+
+use crate::desktop::try_or_warn;
+
+/// Somewhere text can be copied to.
+pub trait Clipboard {
+    /// Replace the clipboard contents with `text`.
+    fn set_text(&mut self, text: &str) -> Result<()>;
+}
+
+/// The terminal's clipboard (OSC 52).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TerminalClipboard;
+
+impl Clipboard for TerminalClipboard {
+    #[cfg(feature = "clipboard")]
+    fn set_text(&mut self, text: &str) -> Result<()> {
+        use anyhow::Context;
+        use base64::Engine;
+        use std::io::Write;
+
+        let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+        let mut stdout = std::io::stdout();
+        write!(stdout, "\x1b]52;c;{}\x07", encoded).context("Failed to write OSC 52 sequence")?;
+        stdout.flush().context("Failed to flush OSC 52 sequence")?;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "clipboard"))]
+    fn set_text(&mut self, _text: &str) -> Result<()> {
+        anyhow::bail!("Built without clipboard support (enable the `clipboard` feature)")
+    }
+}
+
+/// Copy `text` to the terminal's clipboard.
+pub fn copy_text(text: &str) -> Result<()> {
+    TerminalClipboard.set_text(text)
+}
+
+/// Copy `text` to `clipboard`, logging instead of failing.
+///
+/// `what` names the text in the log ("prompt"). Returns the message to show
+/// the user if it couldn't be copied.
+pub fn copy_or_log(clipboard: &mut impl Clipboard, text: &str, what: &str) -> Result<(), String> {
+    let action = format!("copy {} to the clipboard", what);
+    try_or_warn(&action, clipboard.set_text(text))
+}
+
+#[cfg(test)]
+impl Clipboard for crate::desktop::RecordingDesktop {
+    fn set_text(&mut self, text: &str) -> Result<()> {
+        self.record(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::desktop::RecordingDesktop;
+
+    #[test]
+    fn test_copy_without_clipboard_is_a_no_op() {
+        let mut unavailable = RecordingDesktop { fail: true, ..Default::default() };
+        let error = copy_or_log(&mut unavailable, "pixel knight", "prompt").unwrap_err();
+        assert_eq!(error, "Could not copy prompt to the clipboard: no display");
+
+        let mut clipboard = RecordingDesktop::default();
+        assert!(copy_or_log(&mut clipboard, "pixel knight", "prompt").is_ok());
+        assert_eq!(clipboard.received, vec!["pixel knight"]);
+    }
+
+    #[cfg(not(feature = "clipboard"))]
+    #[test]
+    fn test_terminal_clipboard_needs_feature() {
+        assert!(copy_text("pixel knight").is_err());
+    }
+}
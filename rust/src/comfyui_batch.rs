@@ -0,0 +1,192 @@
+//! # ComfyUI Batch Export
+//!
+//! Re-run gallery images in ComfyUI: each image's sidecar metadata (prompt,
+//! seed, steps, ...) is written into a copy of an API-format workflow
+//! template, and the copies are saved together as one batch file. Each batch
+//! entry is a `/prompt` request body, with the source image recorded under
+//! `extra_data`.
+//!
+//! The template's `KSampler` nodes get the seed, steps and CFG scale; the
+//! text nodes wired to their `positive`/`negative` inputs get the prompts.
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+use crate::metadata::GenerationMetadata;
+
+/// Template used when none is given, relative to the workflows directory.
+pub const DEFAULT_TEMPLATE: &str = "txt2img.json";
+
+/// Default template path inside `workflows_dir`.
+pub fn default_template(workflows_dir: &Path) -> PathBuf {
+    workflows_dir.join(DEFAULT_TEMPLATE)
+}
+
+/// Write a ComfyUI batch for `images` to `out`, filling `template` from each
+/// image's sidecar.
+///
+/// Images without metadata (or without a prompt) are skipped. Returns the
+/// number of workflows written.
+pub fn export_comfyui_batch(images: &[PathBuf], template: &Path, out: &Path) -> Result<usize> {
+    let content = fs::read_to_string(template)
+        .with_context(|| format!("Failed to read workflow template: {:?}", template))?;
+    let template: Value = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse workflow template: {:?}", template))?;
+    if !template.is_object() {
+        anyhow::bail!("Workflow template is not an API-format workflow (a JSON object of nodes)");
+    }
+
+    let mut batch = Vec::new();
+    for image in images {
+        let metadata = match GenerationMetadata::load(image) {
+            Ok(Some(metadata)) if metadata.prompt.is_some() => metadata,
+            Ok(_) => {
+                debug!("Skipping {:?}: no generation metadata", image);
+                continue;
+            }
+            Err(e) => {
+                debug!("Skipping {:?}: {:#}", image, e);
+                continue;
+            }
+        };
+
+        let mut workflow = template.clone();
+        fill_workflow(&mut workflow, &metadata);
+        batch.push(json!({
+            "prompt": workflow,
+            "extra_data": { "source_image": image.to_string_lossy() },
+        }));
+    }
+
+    if let Some(parent) = out.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {:?}", parent))?;
+    }
+    let json = serde_json::to_string_pretty(&batch).context("Failed to serialize batch")?;
+    fs::write(out, json).with_context(|| format!("Failed to write batch file: {:?}", out))?;
+    Ok(batch.len())
+}
+
+/// Set the sampler and prompt inputs of `workflow` from `metadata`.
+///
+/// Fields the metadata doesn't have keep the template's values.
+fn fill_workflow(workflow: &mut Value, metadata: &GenerationMetadata) {
+    let Some(nodes) = workflow.as_object_mut() else {
+        return;
+    };
+
+    let mut positive = Vec::new();
+    let mut negative = Vec::new();
+    for node in nodes.values_mut() {
+        if !class_type(node).is_some_and(|class| class.starts_with("KSampler")) {
+            continue;
+        }
+        let Some(inputs) = node.get_mut("inputs").and_then(Value::as_object_mut) else {
+            continue;
+        };
+        if let Some(seed) = metadata.seed {
+            let key = if inputs.contains_key("noise_seed") { "noise_seed" } else { "seed" };
+            inputs.insert(key.to_string(), json!(seed));
+        }
+        if let Some(steps) = metadata.steps {
+            inputs.insert("steps".to_string(), json!(steps));
+        }
+        if let Some(cfg) = metadata.cfg_scale {
+            inputs.insert("cfg".to_string(), json!(cfg));
+        }
+        positive.extend(linked_node(inputs.get("positive")));
+        negative.extend(linked_node(inputs.get("negative")));
+    }
+
+    // Without a sampler to follow, the first text node is the prompt
+    if positive.is_empty() {
+        let mut text_nodes: Vec<&String> = nodes
+            .iter()
+            .filter(|(_, node)| class_type(node) == Some("CLIPTextEncode"))
+            .map(|(id, _)| id)
+            .collect();
+        text_nodes.sort_by_key(|id| (id.parse::<u64>().unwrap_or(u64::MAX), id.to_string()));
+        positive.extend(text_nodes.first().map(|id| id.to_string()));
+    }
+
+    let mut set_text = |ids: &[String], text: &str| {
+        for id in ids {
+            if let Some(inputs) = nodes
+                .get_mut(id)
+                .and_then(|node| node.get_mut("inputs"))
+                .and_then(Value::as_object_mut)
+            {
+                inputs.insert("text".to_string(), json!(text));
+            }
+        }
+    };
+    if let Some(prompt) = &metadata.prompt {
+        set_text(&positive, prompt);
+    }
+    if let Some(negative_prompt) = &metadata.negative_prompt {
+        set_text(&negative, negative_prompt);
+    }
+}
+
+fn class_type(node: &Value) -> Option<&str> {
+    node.get("class_type").and_then(Value::as_str)
+}
+
+/// Node id of a link input (`["<node id>", <output index>]`).
+fn linked_node(input: Option<&Value>) -> Option<String> {
+    input?.get(0)?.as_str().map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEMPLATE: &str = r#"{
+        "1": {"class_type": "CLIPTextEncode", "inputs": {"text": "placeholder"}},
+        "2": {"class_type": "CLIPTextEncode", "inputs": {"text": "bad"}},
+        "3": {"class_type": "KSampler", "inputs": {
+            "seed": 0, "steps": 20, "cfg": 8.0,
+            "positive": ["1", 0], "negative": ["2", 0]
+        }}
+    }"#;
+
+    #[test]
+    fn test_batch_filled_from_sidecars() {
+        let dir = tempfile::tempdir().unwrap();
+        let template = dir.path().join("template.json");
+        fs::write(&template, TEMPLATE).unwrap();
+
+        let knight = dir.path().join("knight.png");
+        GenerationMetadata {
+            prompt: Some("pixel knight".to_string()),
+            seed: Some(1234),
+            steps: Some(35),
+            ..Default::default()
+        }
+        .save(&knight)
+        .unwrap();
+        let untracked = dir.path().join("untracked.png");
+
+        let out = dir.path().join("batch.json");
+        let written = export_comfyui_batch(&[knight.clone(), untracked], &template, &out).unwrap();
+        assert_eq!(written, 1);
+
+        let batch: Value = serde_json::from_str(&fs::read_to_string(&out).unwrap()).unwrap();
+        let entries = batch.as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        let workflow = &entries[0]["prompt"];
+        assert_eq!(workflow["1"]["inputs"]["text"], "pixel knight");
+        assert_eq!(workflow["3"]["inputs"]["seed"], 1234);
+        assert_eq!(workflow["3"]["inputs"]["steps"], 35);
+        // Not in the metadata, so the template's values stay
+        assert_eq!(workflow["3"]["inputs"]["cfg"], 8.0);
+        assert_eq!(workflow["2"]["inputs"]["text"], "bad");
+        assert_eq!(
+            entries[0]["extra_data"]["source_image"],
+            knight.to_string_lossy().as_ref()
+        );
+    }
+}
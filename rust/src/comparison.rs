@@ -3,11 +3,17 @@
 //! This module implements the killer feature of DGX-Pixels - the ability to
 //! compare multiple models (base SDXL vs custom LoRA) side-by-side to validate
 //! training improvements.
+//!
+//! Completed comparisons are kept in `~/.local/share/dgx-pixels/comparisons.json`
+//! so the history survives restarts.
 
+use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
 
 // ============================================================================
 // Core Data Structures
@@ -81,6 +87,10 @@ pub struct ComparisonJob {
     /// Job IDs for each model (parallel generation)
     pub job_ids: Vec<String>,
 
+    /// Per-model prompt overrides, parallel to `models` (`None` uses `params.prompt`)
+    #[serde(default)]
+    pub prompt_overrides: Vec<Option<String>>,
+
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
 
@@ -127,6 +137,10 @@ pub struct ModelResult {
 
     /// Error message if failed
     pub error: Option<String>,
+
+    /// Prompt used instead of the shared prompt, if overridden
+    #[serde(default)]
+    pub prompt_override: Option<String>,
 }
 
 /// Status of individual model result
@@ -154,6 +168,14 @@ pub struct ComparisonResult {
     /// User preference (index of preferred model, if voted)
     pub user_preference: Option<usize>,
 
+    /// Name of the preferred model when the vote was cast
+    #[serde(default)]
+    pub voted_model: Option<String>,
+
+    /// When the vote was last cast or changed
+    #[serde(default)]
+    pub voted_at: Option<DateTime<Utc>>,
+
     /// Notes from user
     pub notes: Option<String>,
 
@@ -177,6 +199,27 @@ impl ComparisonResult {
             .all(|r| r.status == ModelResultStatus::Complete)
     }
 
+    /// Prompt a given result was generated with
+    pub fn prompt_for(&self, result: &ModelResult) -> String {
+        result
+            .prompt_override
+            .clone()
+            .unwrap_or_else(|| self.params.prompt.clone())
+    }
+
+    /// Check if models were generated with different prompts
+    pub fn prompts_differ(&self) -> bool {
+        let mut prompts = self.results.iter().map(|r| {
+            r.prompt_override
+                .as_deref()
+                .unwrap_or(&self.params.prompt)
+        });
+        match prompts.next() {
+            Some(first) => prompts.any(|p| p != first),
+            None => false,
+        }
+    }
+
     /// Get average generation time
     #[allow(dead_code)]
     pub fn avg_generation_time(&self) -> Option<f32> {
@@ -208,6 +251,9 @@ pub struct ComparisonManager {
     /// Model results indexed by job_id
     #[allow(dead_code)]
     job_to_comparison: HashMap<String, String>,
+
+    /// Where completed comparisons are saved (`None` disables saving)
+    history_path: Option<PathBuf>,
 }
 
 impl Default for ComparisonManager {
@@ -224,9 +270,61 @@ impl ComparisonManager {
             active_comparisons: HashMap::new(),
             completed_comparisons: Vec::new(),
             job_to_comparison: HashMap::new(),
+            history_path: None,
         }
     }
 
+    /// Path of the comparison history file.
+    pub fn history_path() -> PathBuf {
+        crate::shutdown::data_dir().join("comparisons.json")
+    }
+
+    /// Manager holding the saved comparison history.
+    pub fn load() -> Result<Self> {
+        Self::load_from(&Self::history_path())
+    }
+
+    /// Manager holding the history saved at `path`, saving future comparisons there.
+    ///
+    /// A missing file gives an empty history.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let completed_comparisons = if path.exists() {
+            let content = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read comparison history: {:?}", path))?;
+            serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse comparison history: {:?}", path))?
+        } else {
+            Vec::new()
+        };
+
+        let manager = Self {
+            completed_comparisons,
+            history_path: Some(path.to_path_buf()),
+            ..Self::new()
+        };
+        info!(
+            "Loaded {} past comparisons from {:?}",
+            manager.completed_comparisons.len(),
+            path
+        );
+        Ok(manager)
+    }
+
+    /// Write the completed comparisons to the history file.
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.history_path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {:?}", parent))?;
+        }
+        let json = serde_json::to_string_pretty(&self.completed_comparisons)
+            .context("Failed to serialize comparison history")?;
+        fs::write(path, json)
+            .with_context(|| format!("Failed to write comparison history: {:?}", path))
+    }
+
     /// Create a new comparison job
     pub fn create_comparison(
         &mut self,
@@ -240,6 +338,7 @@ impl ComparisonManager {
             params,
             models,
             job_ids: Vec::new(),
+            prompt_overrides: Vec::new(),
             created_at: Utc::now(),
             status: ComparisonStatus::Initializing,
         };
@@ -265,6 +364,24 @@ impl ComparisonManager {
         }
     }
 
+    /// Set per-model prompt overrides for a comparison (parallel to its models)
+    pub fn set_prompt_overrides(&mut self, comparison_id: &str, overrides: Vec<Option<String>>) {
+        if let Some(job) = self.active_comparisons.get_mut(comparison_id) {
+            job.prompt_overrides = overrides
+                .into_iter()
+                .map(|o| o.filter(|p| !p.trim().is_empty()))
+                .collect();
+        }
+    }
+
+    /// Comparison a job belongs to and its slot (position among the models)
+    pub fn job_slot(&self, job_id: &str) -> Option<(&str, usize)> {
+        let comparison_id = self.job_to_comparison.get(job_id)?;
+        let job = self.active_comparisons.get(comparison_id)?;
+        let slot = job.job_ids.iter().position(|id| id == job_id)?;
+        Some((comparison_id.as_str(), slot))
+    }
+
     /// Update progress for a job
     pub fn update_job_progress(&mut self, job_id: &str, progress_percent: f32) {
         if let Some(comparison_id) = self.job_to_comparison.get(job_id) {
@@ -312,13 +429,15 @@ impl ComparisonManager {
                 .models
                 .iter()
                 .zip(job.job_ids.iter())
-                .map(|(model, job_id)| ModelResult {
+                .enumerate()
+                .map(|(idx, (model, job_id))| ModelResult {
                     model: model.clone(),
                     job_id: job_id.clone(),
                     image_path: None, // Will be filled by UI
                     duration_s: None, // Will be filled by UI
                     status: ModelResultStatus::Complete,
                     error: None,
+                    prompt_override: job.prompt_overrides.get(idx).cloned().flatten(),
                 })
                 .collect();
 
@@ -327,6 +446,8 @@ impl ComparisonManager {
                 params: job.params,
                 results,
                 user_preference: None,
+                voted_model: None,
+                voted_at: None,
                 notes: None,
                 completed_at: Utc::now(),
             };
@@ -337,6 +458,10 @@ impl ComparisonManager {
             for job_id in &job.job_ids {
                 self.job_to_comparison.remove(job_id);
             }
+
+            if let Err(e) = self.save() {
+                warn!("Failed to save comparison history: {:#}", e);
+            }
         }
     }
 
@@ -364,7 +489,7 @@ impl ComparisonManager {
         &self.completed_comparisons
     }
 
-    /// Set user preference for a comparison
+    /// Set user preference for a comparison (replacing any earlier vote)
     pub fn set_preference(
         &mut self,
         comparison_id: &str,
@@ -374,6 +499,8 @@ impl ComparisonManager {
         if let Some(result) = self.get_completed_mut(comparison_id) {
             if model_index < result.results.len() {
                 result.user_preference = Some(model_index);
+                result.voted_model = Some(result.results[model_index].model.name.clone());
+                result.voted_at = Some(Utc::now());
                 if let Some(n) = notes {
                     result.notes = Some(n);
                 }
@@ -383,6 +510,17 @@ impl ComparisonManager {
         false
     }
 
+    /// Vote for a model and save the history straight away
+    ///
+    /// Returns `Ok(false)` if there is no such comparison or model.
+    pub fn vote(&mut self, comparison_id: &str, model_index: usize) -> Result<bool> {
+        if !self.set_preference(comparison_id, model_index, None) {
+            return Ok(false);
+        }
+        self.save()?;
+        Ok(true)
+    }
+
     /// Get statistics across all comparisons
     pub fn get_statistics(&self) -> ComparisonStatistics {
         let total_comparisons = self.completed_comparisons.len();
@@ -401,10 +539,50 @@ impl ComparisonManager {
             total_comparisons,
             comparisons_with_preference: total_with_preference,
             model_wins,
+            elo_ratings: self.compute_elo(),
+        }
+    }
+
+    /// ELO rating per model from the user's preferences
+    ///
+    /// Comparisons are replayed in completion order. A preference counts as
+    /// a win for the chosen model against every other model in that
+    /// comparison. Models start at [`ELO_START`] and move by up to
+    /// [`ELO_K`] per game, so models never in a voted comparison keep the
+    /// starting rating.
+    pub fn compute_elo(&self) -> HashMap<String, f64> {
+        let mut ratings: HashMap<String, f64> = HashMap::new();
+
+        for result in &self.completed_comparisons {
+            for model_result in &result.results {
+                ratings.entry(model_result.model.name.clone()).or_insert(ELO_START);
+            }
+
+            let Some(winner) = result.winner().map(|m| m.name.clone()) else {
+                continue;
+            };
+            for loser in result.results.iter().map(|r| &r.model.name) {
+                if *loser == winner {
+                    continue;
+                }
+                let (w, l) = (ratings[&winner], ratings[loser]);
+                let expected = 1.0 / (1.0 + 10f64.powf((l - w) / 400.0));
+                let delta = ELO_K * (1.0 - expected);
+                ratings.insert(winner.clone(), w + delta);
+                ratings.insert(loser.clone(), l - delta);
+            }
         }
+
+        ratings
     }
 }
 
+/// Starting ELO rating for every model
+pub const ELO_START: f64 = 1500.0;
+
+/// ELO K-factor (largest rating change from one game)
+pub const ELO_K: f64 = 32.0;
+
 /// Statistics across all comparisons
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -412,6 +590,59 @@ pub struct ComparisonStatistics {
     pub total_comparisons: usize,
     pub comparisons_with_preference: usize,
     pub model_wins: HashMap<String, usize>,
+    /// ELO rating per model (see [`ComparisonManager::compute_elo`])
+    pub elo_ratings: HashMap<String, f64>,
+}
+
+impl ComparisonStatistics {
+    /// Share of comparisons where the user picked a winner (0-100)
+    pub fn preference_rate(&self) -> f32 {
+        if self.total_comparisons == 0 {
+            0.0
+        } else {
+            (self.comparisons_with_preference as f32 / self.total_comparisons as f32) * 100.0
+        }
+    }
+
+    /// Concise plain-text summary for quick sharing
+    ///
+    /// Models are listed by win count (most wins first, ties by name), then
+    /// by ELO rating (highest first).
+    pub fn summary_text(&self) -> String {
+        let mut lines = vec![
+            "DGX-Pixels comparison summary".to_string(),
+            format!("Total comparisons: {}", self.total_comparisons),
+            format!(
+                "With preference: {} ({:.1}%)",
+                self.comparisons_with_preference,
+                self.preference_rate()
+            ),
+        ];
+
+        let mut wins: Vec<_> = self.model_wins.iter().collect();
+        wins.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+        if wins.is_empty() {
+            lines.push("Wins: none recorded".to_string());
+        } else {
+            lines.push("Wins:".to_string());
+            for (model, count) in wins {
+                lines.push(format!("  {}: {}", model, count));
+            }
+        }
+
+        let mut ratings: Vec<_> = self.elo_ratings.iter().collect();
+        ratings.sort_by(|a, b| b.1.total_cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+        if !ratings.is_empty() {
+            lines.push("ELO ratings:".to_string());
+            for (model, rating) in ratings {
+                lines.push(format!("  {}: {:.0}", model, rating));
+            }
+        }
+
+        lines.join("\n")
+    }
 }
 
 // ============================================================================
@@ -488,6 +719,32 @@ mod tests {
         assert!(manager.get_completed(&comparison_id).is_some());
     }
 
+    #[test]
+    fn test_prompt_overrides_recorded_per_result() {
+        let mut manager = ComparisonManager::new();
+
+        let params = GenerationParams::default();
+        let models = vec![ModelConfig::default(), ModelConfig::default()];
+
+        let comparison_id = manager.create_comparison(params.clone(), models);
+        manager.set_prompt_overrides(
+            &comparison_id,
+            vec![None, Some("pixel art dragon, gameasset".to_string())],
+        );
+        manager.register_jobs(&comparison_id, vec!["job-1".to_string(), "job-2".to_string()]);
+        manager.complete_job("job-1", PathBuf::from("/tmp/1.png"), 3.0);
+        manager.complete_job("job-2", PathBuf::from("/tmp/2.png"), 3.5);
+
+        let result = manager.get_completed(&comparison_id).unwrap();
+        assert_eq!(result.results[0].prompt_override, None);
+        assert_eq!(
+            result.results[1].prompt_override.as_deref(),
+            Some("pixel art dragon, gameasset")
+        );
+        assert_eq!(result.prompt_for(&result.results[0]), params.prompt);
+        assert!(result.prompts_differ());
+    }
+
     #[test]
     fn test_user_preference() {
         let mut manager = ComparisonManager::new();
@@ -519,6 +776,13 @@ mod tests {
         let result = manager.get_completed(&comparison_id).unwrap();
         assert_eq!(result.user_preference, Some(1));
         assert_eq!(result.winner().unwrap().name, "Model B");
+        assert_eq!(result.voted_model.as_deref(), Some("Model B"));
+
+        // Voting again replaces the preference
+        assert!(manager.vote(&comparison_id, 0).unwrap());
+        let result = manager.get_completed(&comparison_id).unwrap();
+        assert_eq!(result.user_preference, Some(0));
+        assert_eq!(result.voted_model.as_deref(), Some("Model A"));
     }
 
     #[test]
@@ -556,4 +820,126 @@ mod tests {
         assert_eq!(stats.comparisons_with_preference, 2);
         assert_eq!(stats.model_wins.get("Model B"), Some(&2));
     }
+
+    #[test]
+    fn test_elo_ranks_consistent_winner_higher() {
+        let mut manager = ComparisonManager::new();
+        let model = |name: &str| ModelConfig {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        let mut run = |models: Vec<ModelConfig>, preferred: Option<usize>, i: usize| {
+            let cmp = manager.create_comparison(GenerationParams::default(), models);
+            let (a, b) = (format!("a{}", i), format!("b{}", i));
+            manager.register_jobs(&cmp, vec![a.clone(), b.clone()]);
+            manager.complete_job(&a, PathBuf::from("/tmp/a.png"), 3.0);
+            manager.complete_job(&b, PathBuf::from("/tmp/b.png"), 3.0);
+            if let Some(preferred) = preferred {
+                manager.set_preference(&cmp, preferred, None);
+            }
+        };
+
+        for i in 0..3 {
+            run(vec![model("Base"), model("LoRA")], Some(1), i);
+        }
+        // Compared, but never voted on
+        run(vec![model("Old"), model("Other")], None, 3);
+
+        let elo = manager.get_statistics().elo_ratings;
+        assert!(elo["LoRA"] > ELO_START);
+        assert!(elo["Base"] < ELO_START);
+        assert!((elo["LoRA"] + elo["Base"] - 2.0 * ELO_START).abs() < 1e-9);
+        assert_eq!(elo["Old"], ELO_START);
+        assert_eq!(elo["Other"], ELO_START);
+    }
+
+    #[test]
+    fn test_statistics_summary_text() {
+        let mut manager = ComparisonManager::new();
+        let models = vec![
+            ModelConfig {
+                name: "Base".to_string(),
+                ..Default::default()
+            },
+            ModelConfig {
+                name: "LoRA".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        // LoRA wins twice, Base once
+        for (i, preferred) in [1, 1, 0].into_iter().enumerate() {
+            let cmp = manager.create_comparison(GenerationParams::default(), models.clone());
+            let (a, b) = (format!("a{}", i), format!("b{}", i));
+            manager.register_jobs(&cmp, vec![a.clone(), b.clone()]);
+            manager.complete_job(&a, PathBuf::from("/tmp/a.png"), 3.0);
+            manager.complete_job(&b, PathBuf::from("/tmp/b.png"), 3.0);
+            manager.set_preference(&cmp, preferred, None);
+        }
+
+        let summary = manager.get_statistics().summary_text();
+        assert!(summary.contains("Total comparisons: 3"));
+        assert!(summary.contains("With preference: 3 (100.0%)"));
+        assert!(summary.contains("LoRA: 2"));
+        assert!(summary.contains("Base: 1"));
+        // Most wins listed first
+        assert!(summary.find("LoRA: 2").unwrap() < summary.find("Base: 1").unwrap());
+
+        let elo = manager.get_statistics().elo_ratings;
+        let lora = format!("LoRA: {:.0}", elo["LoRA"]);
+        let base = format!("Base: {:.0}", elo["Base"]);
+        let ratings = summary.find("ELO ratings:").unwrap();
+        assert!(ratings < summary.find(&lora).unwrap());
+        assert!(summary.find(&lora).unwrap() < summary.find(&base).unwrap());
+    }
+
+    #[test]
+    fn test_statistics_summary_text_empty() {
+        let summary = ComparisonManager::new().get_statistics().summary_text();
+        assert!(summary.contains("Total comparisons: 0"));
+        assert!(summary.contains("none recorded"));
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("comparisons.json");
+
+        // Missing file: empty history
+        let mut manager = ComparisonManager::load_from(&path).unwrap();
+        assert!(manager.get_all_completed().is_empty());
+
+        // Finalizing saves straight away
+        let models = vec![ModelConfig::default(), ModelConfig::default()];
+        let cmp = manager.create_comparison(GenerationParams::default(), models);
+        manager.register_jobs(&cmp, vec!["a".to_string(), "b".to_string()]);
+        manager.complete_job("a", PathBuf::from("/tmp/a.png"), 3.0);
+        manager.complete_job("b", PathBuf::from("/tmp/b.png"), 3.0);
+        assert!(path.exists());
+
+        manager.set_preference(&cmp, 1, Some("crisper".to_string()));
+        manager.save().unwrap();
+
+        let loaded = ComparisonManager::load_from(&path).unwrap();
+        let result = loaded.get_completed(&cmp).unwrap();
+        assert_eq!(result.user_preference, Some(1));
+        assert_eq!(result.notes.as_deref(), Some("crisper"));
+
+        let stats = loaded.get_statistics();
+        assert_eq!(stats.total_comparisons, 1);
+        assert_eq!(stats.comparisons_with_preference, 1);
+
+        // Votes are saved as they're cast, and can be changed
+        let mut manager = loaded;
+        assert!(manager.vote(&cmp, 0).unwrap());
+        assert!(!manager.vote(&cmp, 5).unwrap());
+        let reloaded = ComparisonManager::load_from(&path).unwrap();
+        let result = reloaded.get_completed(&cmp).unwrap();
+        assert_eq!(result.user_preference, Some(0));
+        assert_eq!(result.voted_model.as_deref(), Some(ModelConfig::default().name.as_str()));
+        assert!(result.voted_at.is_some());
+
+        fs::write(&path, "not json").unwrap();
+        assert!(ComparisonManager::load_from(&path).is_err());
+    }
 }
@@ -0,0 +1,300 @@
+//! # Demo Backend
+//!
+//! A stand-in for the generation backend (`--demo`), so the TUI can be tried
+//! without a GPU. Generation jobs report synthetic progress over
+//! [`DEMO_JOB_DURATION`] and finish with a bundled placeholder sprite copied
+//! into the output directory. Progress is worked out from the clock whenever
+//! the app polls, so there are no background threads.
+
+use anyhow::{Context, Result};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
+
+use crate::backend::Backend;
+use crate::messages::{
+    GenerationStage, ModelInfo, ModelType, ProgressUpdate, Request, Response, SystemStats,
+};
+use crate::zmq_client::ConnectionState;
+
+/// How long a demo job takes from submission to completion.
+pub const DEMO_JOB_DURATION: Duration = Duration::from_secs(3);
+
+/// Where demo images are written (the directory the gallery loads from).
+pub const DEMO_OUTPUT_DIR: &str = "../outputs";
+
+/// Progress steps reported per demo job.
+const DEMO_STEPS: u32 = 20;
+
+/// Image every demo job "generates".
+const PLACEHOLDER_PNG: &[u8] = include_bytes!("../assets/demo_placeholder.png");
+
+/// A generation job the demo backend is pretending to run.
+#[derive(Debug)]
+struct DemoJob {
+    id: String,
+    submitted: Instant,
+    /// Progress steps reported so far
+    steps_reported: u32,
+    /// Whether `JobStarted` has been sent
+    started: bool,
+}
+
+/// Messages waiting to be polled, plus the jobs in flight.
+#[derive(Debug, Default)]
+struct DemoState {
+    jobs: Vec<DemoJob>,
+    responses: VecDeque<Response>,
+    updates: VecDeque<ProgressUpdate>,
+}
+
+/// Fake backend that answers requests locally.
+#[derive(Debug)]
+pub struct DemoBackend {
+    output_dir: PathBuf,
+    job_duration: Duration,
+    state: Mutex<DemoState>,
+}
+
+impl DemoBackend {
+    /// Demo backend writing its images to `output_dir`.
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            output_dir: output_dir.into(),
+            job_duration: DEMO_JOB_DURATION,
+            state: Mutex::new(DemoState::default()),
+        }
+    }
+
+    /// Use a different job length (e.g. zero in tests).
+    pub fn with_job_duration(mut self, duration: Duration) -> Self {
+        self.job_duration = duration;
+        self
+    }
+
+    /// Copy the placeholder image to `<output_dir>/<job_id>.png`.
+    ///
+    /// Returns the absolute path, as the real backend reports it.
+    fn write_image(&self, job_id: &str) -> Result<PathBuf> {
+        std::fs::create_dir_all(&self.output_dir)
+            .with_context(|| format!("Failed to create output directory: {:?}", self.output_dir))?;
+        let dir = self.output_dir.canonicalize().unwrap_or_else(|_| self.output_dir.clone());
+        let path = dir.join(format!("{}.png", job_id));
+        std::fs::write(&path, PLACEHOLDER_PNG)
+            .with_context(|| format!("Failed to write demo image: {:?}", path))?;
+        Ok(path)
+    }
+
+    /// Queue the progress each job has made by `now`, finishing any that are done.
+    fn advance(&self, state: &mut DemoState, now: Instant) {
+        let mut finished = Vec::new();
+        for job in &mut state.jobs {
+            if !job.started {
+                job.started = true;
+                state.updates.push_back(ProgressUpdate::JobStarted {
+                    job_id: job.id.clone(),
+                    timestamp: SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map_or(0, |d| d.as_secs()),
+                });
+            }
+
+            let elapsed = now.saturating_duration_since(job.submitted);
+            let fraction = if self.job_duration.is_zero() {
+                1.0
+            } else {
+                (elapsed.as_secs_f32() / self.job_duration.as_secs_f32()).min(1.0)
+            };
+            let target = (fraction * DEMO_STEPS as f32) as u32;
+            while job.steps_reported < target {
+                job.steps_reported += 1;
+                let done = job.steps_reported as f32 / DEMO_STEPS as f32;
+                state.updates.push_back(ProgressUpdate::Progress {
+                    job_id: job.id.clone(),
+                    stage: demo_stage(done),
+                    step: job.steps_reported,
+                    total_steps: DEMO_STEPS,
+                    percent: done * 100.0,
+                    eta_s: self.job_duration.as_secs_f32() * (1.0 - done),
+                });
+            }
+
+            if job.steps_reported >= DEMO_STEPS {
+                finished.push(job.id.clone());
+            }
+        }
+
+        for job_id in finished {
+            state.jobs.retain(|job| job.id != job_id);
+            let response = match self.write_image(&job_id) {
+                Ok(path) => {
+                    info!("Demo job {} complete: {:?}", job_id, path);
+                    Response::JobComplete {
+                        job_id,
+                        image_path: path.to_string_lossy().to_string(),
+                        duration_s: self.job_duration.as_secs_f32(),
+                    }
+                }
+                Err(e) => {
+                    warn!("Demo job {} failed: {:#}", job_id, e);
+                    Response::JobError {
+                        job_id,
+                        error: format!("{:#}", e),
+                    }
+                }
+            };
+            state.responses.push_back(response);
+        }
+    }
+
+    fn with_state<T>(&self, f: impl FnOnce(&mut DemoState) -> T) -> T {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        self.advance(&mut state, Instant::now());
+        f(&mut state)
+    }
+}
+
+/// Stage shown for a job `done` (0-1) of the way through.
+fn demo_stage(done: f32) -> GenerationStage {
+    if done < 0.15 {
+        GenerationStage::LoadingModels
+    } else if done < 0.9 {
+        GenerationStage::Sampling
+    } else {
+        GenerationStage::Decoding
+    }
+}
+
+impl Backend for DemoBackend {
+    fn send_request(&self, request: Request) -> Result<()> {
+        self.with_state(|state| {
+            let response = match request {
+                Request::Generate { id, .. } => {
+                    info!("Demo job accepted: {}", id);
+                    state.jobs.push(DemoJob {
+                        id: id.clone(),
+                        submitted: Instant::now(),
+                        steps_reported: 0,
+                        started: false,
+                    });
+                    Response::JobAccepted {
+                        job_id: id,
+                        estimated_time_s: self.job_duration.as_secs_f32(),
+                    }
+                }
+                Request::Cancel { job_id } => {
+                    state.jobs.retain(|job| job.id != job_id);
+                    Response::JobCancelled { job_id }
+                }
+                Request::ListModels => Response::ModelList {
+                    models: vec![ModelInfo {
+                        name: "demo-model".to_string(),
+                        path: "demo/demo-model.safetensors".to_string(),
+                        model_type: ModelType::Checkpoint,
+                        size_mb: 0,
+                    }],
+                },
+                Request::Status => Response::StatusInfo {
+                    version: "demo".to_string(),
+                    queue_size: state.jobs.len() as u32,
+                    active_jobs: state.jobs.len().min(1) as u32,
+                    uptime_s: 0,
+                },
+                Request::Ping => Response::Pong,
+                Request::DownloadModel { model } => {
+                    // Nothing to fetch: the download finishes straight away
+                    let update = ProgressUpdate::ModelDownload { model, percent: 100 };
+                    state.updates.push_back(update);
+                    Response::Pong
+                }
+                Request::GetSystemStats => Response::SystemStats(SystemStats {
+                    gpu_utilization: if state.jobs.is_empty() { 2.0 } else { 97.0 },
+                    vram_used_mb: if state.jobs.is_empty() { 512 } else { 18_432 },
+                    vram_total_mb: 131_072,
+                    temperature_c: if state.jobs.is_empty() { 38.0 } else { 71.0 },
+                }),
+            };
+            state.responses.push_back(response);
+        });
+        Ok(())
+    }
+
+    fn connection_state(&self) -> ConnectionState {
+        ConnectionState::Connected
+    }
+
+    fn outstanding_requests(&self) -> usize {
+        0
+    }
+
+    fn try_recv_response(&self) -> Option<Response> {
+        self.with_state(|state| state.responses.pop_front())
+    }
+
+    fn try_recv_update(&self) -> Option<ProgressUpdate> {
+        self.with_state(|state| state.updates.pop_front())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{App, JobStatus};
+    use crate::generation_request::GenerationRequestBuilder;
+
+    fn generate(id: &str) -> Request {
+        GenerationRequestBuilder::new("pixel knight")
+            .id(id.to_string())
+            .build(&Default::default())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_demo_job_reports_progress_then_completes() {
+        let dir = tempfile::tempdir().unwrap();
+        let backend = DemoBackend::new(dir.path()).with_job_duration(Duration::from_secs(60));
+        backend.send_request(generate("job-1")).unwrap();
+
+        assert!(matches!(
+            backend.try_recv_response(),
+            Some(Response::JobAccepted { job_id, .. }) if job_id == "job-1"
+        ));
+        assert!(matches!(
+            backend.try_recv_update(),
+            Some(ProgressUpdate::JobStarted { .. })
+        ));
+        // Barely started: no progress yet and nothing written
+        assert_eq!(backend.try_recv_update(), None);
+        assert_eq!(backend.try_recv_response(), None);
+
+        backend.send_request(Request::Cancel { job_id: "job-1".to_string() }).unwrap();
+        assert_eq!(
+            backend.try_recv_response(),
+            Some(Response::JobCancelled { job_id: "job-1".to_string() })
+        );
+        assert!(std::fs::read_dir(dir.path()).unwrap().next().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_demo_job_completes_into_gallery() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut app = App::new();
+        app.zmq_client = Some(Box::new(
+            DemoBackend::new(dir.path()).with_job_duration(Duration::ZERO),
+        ));
+
+        app.add_job("job-1".to_string(), "pixel knight".to_string());
+        app.zmq_client.as_ref().unwrap().send_request(generate("job-1")).unwrap();
+        crate::poll_backend(&mut app);
+
+        let image = dir.path().canonicalize().unwrap().join("job-1.png");
+        assert_eq!(std::fs::read(&image).unwrap(), PLACEHOLDER_PNG);
+        assert!(app.gallery_images.contains(&image));
+        assert!(matches!(
+            &app.active_jobs[0].status,
+            JobStatus::Complete { image_path, .. } if *image_path == image
+        ));
+    }
+}
@@ -0,0 +1,62 @@
+//! # Desktop Integration
+//!
+//! What [`crate::clipboard`] and [`crate::file_manager`] share: both depend
+//! on the user's terminal or desktop, and either may be missing (headless
+//! builds, no display, an unsupported terminal), so a failure is logged and
+//! reported instead of being fatal.
+
+use anyhow::Result;
+use tracing::{info, warn};
+
+/// Log the outcome of `action` ("copy the prompt to the clipboard"),
+/// warning instead of failing.
+///
+/// Returns the message to show the user when it failed.
+pub fn try_or_warn(action: &str, result: Result<()>) -> Result<(), String> {
+    match result {
+        Ok(()) => {
+            info!("Done: {}", action);
+            Ok(())
+        }
+        Err(e) => {
+            let message = format!("Could not {}: {:#}", action, e);
+            warn!("{}", message);
+            Err(message)
+        }
+    }
+}
+
+/// Test double for the clipboard and file manager: records the text copied
+/// and the folders opened, in order, or fails every call with `fail` set.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct RecordingDesktop {
+    pub received: Vec<String>,
+    pub fail: bool,
+}
+
+#[cfg(test)]
+impl RecordingDesktop {
+    /// Record `value`, or fail if the desktop is set to be unavailable.
+    pub fn record(&mut self, value: String) -> Result<()> {
+        if self.fail {
+            anyhow::bail!("no display");
+        }
+        self.received.push(value);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failure_is_reported_not_fatal() {
+        assert_eq!(try_or_warn("copy the prompt", Ok(())), Ok(()));
+        assert_eq!(
+            try_or_warn("copy the prompt", Err(anyhow::anyhow!("no display"))),
+            Err("Could not copy the prompt: no display".to_string())
+        );
+    }
+}
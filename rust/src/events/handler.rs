@@ -35,11 +35,31 @@ impl EventHandler {
             return;
         }
 
-        if key_match(&key, KeyCode::Char('?')) || key_match(&key, KeyCode::Char('h')) {
+        if key_match(&key, KeyCode::F(12)) {
+            app.request_screenshot();
+            return;
+        }
+
+        // 'h' opens the history on the Comparison screen
+        let help_on_h = app.current_screen != Screen::Comparison;
+        if key_match(&key, KeyCode::Char('?')) || (help_on_h && key_match(&key, KeyCode::Char('h'))) {
             app.navigate_to(Screen::Help);
             return;
         }
 
+        // Comparison results take 1-3 as votes
+        if let KeyCode::Char(c @ '1'..='3') = key.code {
+            use crate::ui::screens::comparison::ComparisonMode;
+
+            let showing_results = app.current_screen == Screen::Comparison
+                && matches!(app.comparison_state.mode, ComparisonMode::Results { .. });
+            if showing_results {
+                app.comparison_state.vote(c as usize - '1' as usize);
+                app.needs_redraw = true;
+                return;
+            }
+        }
+
         // Screen navigation number keys work on ALL screens (including input screens)
         match key.code {
             KeyCode::Char('1') => {
@@ -116,6 +136,10 @@ impl EventHandler {
             {
                 app.set_preview_tab(1);
             }
+            // Ctrl+N: switch between prompt and negative prompt
+            KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.toggle_negative_focus();
+            }
             KeyCode::Char(c) => app.input_char(c),
             KeyCode::Backspace => app.input_backspace(),
             _ => {}
@@ -123,7 +147,8 @@ impl EventHandler {
     }
 
     fn trigger_generation(app: &mut App) {
-        use crate::messages::Request;
+        use crate::generation_request::{GenerationDefaults, GenerationRequestBuilder};
+        use crate::metadata::GenerationMetadata;
         use tracing::{info, warn};
 
         if app.input_buffer.trim().is_empty() {
@@ -147,21 +172,27 @@ impl EventHandler {
 
         info!("Triggering generation: {}", prompt);
 
-        let request = Request::Generate {
-            id: job_id.clone(),
-            prompt: prompt.clone(),
-            model: "sd_xl_base_1.0".to_string(),
-            lora: None,
-            size: (1024, 1024),
-            steps: 30,
-            cfg_scale: 7.5,
+        let request = match GenerationRequestBuilder::new(prompt.clone())
+            .id(job_id.clone())
+            .negative_prompt(app.negative_buffer.clone())
+            .build(&GenerationDefaults::default())
+        {
+            Ok(request) => request,
+            Err(e) => {
+                warn!("Cannot generate: {:#}", e);
+                return;
+            }
         };
 
+        let metadata = GenerationMetadata::from_request(&request);
         if let Some(ref client) = app.zmq_client {
             match client.send_request(request) {
                 Ok(_) => {
                     info!("Generation request sent: {}", job_id);
-                    app.add_job(job_id, prompt);
+                    if let Err(e) = app.save_last_prompt(&prompt) {
+                        warn!("Failed to save last prompt: {:#}", e);
+                    }
+                    app.add_requested_job(job_id, prompt, metadata);
                     app.input_buffer.clear();
                     app.cursor_pos = 0;
                     app.needs_redraw = true;
@@ -174,22 +205,107 @@ impl EventHandler {
     }
 
     fn handle_comparison_keys(app: &mut App, key: crossterm::event::KeyEvent) {
+        use crate::ui::screens::comparison::ComparisonMode;
+        use crossterm::event::KeyModifiers;
+
+        let in_history = matches!(app.comparison_state.mode, ComparisonMode::History { .. });
+
         match key.code {
+            // Ctrl+Y: share a quick summary of the comparison statistics
+            KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Self::share_comparison_summary(app);
+            }
+            // H: past comparisons (with an empty prompt; otherwise it's typed)
+            KeyCode::Char('h') if in_history || app.input_buffer.is_empty() => {
+                app.comparison_state.toggle_history();
+                app.needs_redraw = true;
+            }
+            KeyCode::Up if in_history => {
+                app.comparison_state.move_history_selection(-1);
+                app.needs_redraw = true;
+            }
+            KeyCode::Down if in_history => {
+                app.comparison_state.move_history_selection(1);
+                app.needs_redraw = true;
+            }
+            KeyCode::Enter if in_history => {
+                app.comparison_state.open_selected_history();
+                app.needs_redraw = true;
+            }
+            // The prompt isn't showing
+            _ if in_history => {}
+            KeyCode::Enter if app.comparison_state.mode == ComparisonMode::Setup => {
+                Self::start_comparison(app);
+            }
             KeyCode::Char(c) => app.input_char(c),
             KeyCode::Backspace => app.input_backspace(),
             _ => {}
         }
     }
 
+    /// Compare the selected models on the typed prompt (or the last one).
+    fn start_comparison(app: &mut App) {
+        use tracing::{info, warn};
+
+        let prompt = app.input_buffer.trim();
+        if !prompt.is_empty() {
+            app.comparison_state.params.prompt = prompt.to_string();
+        }
+        let Some(client) = app.zmq_client.as_deref() else {
+            warn!("Cannot compare: backend not connected");
+            return;
+        };
+        match app.comparison_state.start_comparison(client) {
+            Ok(comparison_id) => {
+                info!("Comparison started: {}", comparison_id);
+                app.input_buffer.clear();
+                app.cursor_pos = 0;
+            }
+            Err(e) => warn!("Cannot compare: {:#}", e),
+        }
+        app.needs_redraw = true;
+    }
+
+    /// Copy the statistics summary to the clipboard, or write it to a file
+    /// when built without the `clipboard` feature.
+    fn share_comparison_summary(app: &mut App) {
+        use tracing::{info, warn};
+
+        let stats = app.comparison_state.comparison_manager.get_statistics();
+
+        #[cfg(feature = "clipboard")]
+        let result = crate::reports::copy_statistics_summary(&stats)
+            .map(|_| "clipboard".to_string());
+
+        #[cfg(not(feature = "clipboard"))]
+        let result = {
+            let path = dirs::home_dir()
+                .unwrap_or_else(|| std::path::PathBuf::from("."))
+                .join(".local/share/dgx-pixels/comparison_summary.txt");
+            crate::reports::export_statistics_summary(&stats, &path)
+                .map(|_| path.display().to_string())
+        };
+
+        match result {
+            Ok(target) => info!("Comparison summary written to {}", target),
+            Err(e) => warn!("Failed to share comparison summary: {}", e),
+        }
+        app.needs_redraw = true;
+    }
+
     fn handle_queue_keys(_app: &mut App, _key: crossterm::event::KeyEvent) {
         // TODO: Implement queue-specific keys
     }
 
     fn handle_gallery_keys(app: &mut App, key: crossterm::event::KeyEvent) {
+        use crate::app::GridDirection;
         use tracing::info;
         info!("Gallery key event: {:?}", key);
 
         match key.code {
+            KeyCode::Char('g') => app.toggle_gallery_grid(),
+            KeyCode::Up if app.gallery_grid_view => app.gallery_grid_move(GridDirection::Up),
+            KeyCode::Down if app.gallery_grid_view => app.gallery_grid_move(GridDirection::Down),
             KeyCode::Left => {
                 info!("Arrow Left: calling gallery_prev()");
                 app.gallery_prev();
@@ -269,6 +385,55 @@ mod tests {
         assert_eq!(app.current_screen, Screen::Generation);
     }
 
+    #[tokio::test]
+    async fn test_h_opens_comparison_history() {
+        use crate::ui::screens::comparison::ComparisonMode;
+
+        let mut app = App::new();
+        EventHandler::handle(&mut app, AppEvent::Key(KeyEvent::from(KeyCode::Char('2'))));
+        EventHandler::handle(&mut app, AppEvent::Key(KeyEvent::from(KeyCode::Char('h'))));
+        assert_eq!(app.current_screen, Screen::Comparison);
+        assert_eq!(app.comparison_state.mode, ComparisonMode::History { selected: 0 });
+
+        // Typing is ignored while the history is showing; 'h' closes it
+        EventHandler::handle(&mut app, AppEvent::Key(KeyEvent::from(KeyCode::Char('x'))));
+        EventHandler::handle(&mut app, AppEvent::Key(KeyEvent::from(KeyCode::Char('h'))));
+        assert_eq!(app.comparison_state.mode, ComparisonMode::Setup);
+        assert!(app.input_buffer.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_number_keys_vote_in_comparison_results() {
+        use crate::comparison::{GenerationParams, ModelConfig};
+        use crate::ui::screens::comparison::ComparisonMode;
+
+        let mut app = App::new();
+        let manager = &mut app.comparison_state.comparison_manager;
+        let models = vec![ModelConfig::default(), ModelConfig::default()];
+        let id = manager.create_comparison(GenerationParams::default(), models);
+        manager.register_jobs(&id, vec!["a".to_string(), "b".to_string()]);
+        manager.complete_job("a", std::path::PathBuf::from("/tmp/a.png"), 1.0);
+        manager.complete_job("b", std::path::PathBuf::from("/tmp/b.png"), 1.0);
+        EventHandler::handle(&mut app, AppEvent::Key(KeyEvent::from(KeyCode::Char('2'))));
+        app.comparison_state.mode = ComparisonMode::Results {
+            comparison_id: id.clone(),
+        };
+
+        let preference = |app: &App| {
+            let manager = &app.comparison_state.comparison_manager;
+            manager.get_completed(&id).unwrap().user_preference
+        };
+        EventHandler::handle(&mut app, AppEvent::Key(KeyEvent::from(KeyCode::Char('2'))));
+        assert_eq!(app.current_screen, Screen::Comparison);
+        assert_eq!(preference(&app), Some(1));
+
+        // Changing the vote; a model that isn't there is ignored
+        EventHandler::handle(&mut app, AppEvent::Key(KeyEvent::from(KeyCode::Char('1'))));
+        EventHandler::handle(&mut app, AppEvent::Key(KeyEvent::from(KeyCode::Char('3'))));
+        assert_eq!(preference(&app), Some(0));
+        assert_eq!(app.current_screen, Screen::Comparison);
+    }
+
     #[tokio::test]
     async fn test_help_screen() {
         let mut app = App::new();
@@ -0,0 +1,106 @@
+//! # File Manager
+//!
+//! Revealing an image in the OS file manager. With the `file_manager`
+//! feature, the image's folder is opened with `xdg-open` (Linux and other
+//! Unixes), `open` (macOS) or `explorer` (Windows). Without the feature,
+//! opening fails with an error, so headless builds never spawn anything.
+//!
+//! A failed open is never fatal: [`open_or_log`] logs it and carries on.
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+use crate::desktop::try_or_warn;
+
+/// Something that can show a folder to the user.
+pub trait Opener {
+    /// Open `folder` in a file manager.
+    fn open(&mut self, folder: &Path) -> Result<()>;
+}
+
+/// The platform's file manager.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemOpener;
+
+impl Opener for SystemOpener {
+    #[cfg(feature = "file_manager")]
+    fn open(&mut self, folder: &Path) -> Result<()> {
+        use anyhow::Context;
+        use std::process::{Command, Stdio};
+
+        let program = if cfg!(target_os = "macos") {
+            "open"
+        } else if cfg!(windows) {
+            "explorer"
+        } else {
+            "xdg-open"
+        };
+        let mut child = Command::new(program)
+            .arg(folder)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .with_context(|| format!("Failed to run {}", program))?;
+        // Reap it in the background; the file manager outlives the opener
+        std::thread::spawn(move || child.wait());
+        Ok(())
+    }
+
+    #[cfg(not(feature = "file_manager"))]
+    fn open(&mut self, _folder: &Path) -> Result<()> {
+        anyhow::bail!("Built without file manager support (enable the `file_manager` feature)")
+    }
+}
+
+/// The folder holding `path` (`path` itself if it is a folder).
+pub fn containing_folder(path: &Path) -> PathBuf {
+    if path.is_dir() {
+        return path.to_path_buf();
+    }
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    }
+}
+
+/// Open the folder holding `path` in the platform's file manager.
+pub fn open_in_file_manager(path: &Path) -> Result<()> {
+    SystemOpener.open(&containing_folder(path))
+}
+
+/// Open the folder holding `path` with `opener`, logging instead of failing.
+///
+/// Returns the message to show the user if it couldn't be opened.
+pub fn open_or_log(opener: &mut impl Opener, path: &Path) -> Result<(), String> {
+    let folder = containing_folder(path);
+    let action = format!("open {:?} in the file manager", folder);
+    try_or_warn(&action, opener.open(&folder))
+}
+
+#[cfg(test)]
+impl Opener for crate::desktop::RecordingDesktop {
+    fn open(&mut self, folder: &Path) -> Result<()> {
+        self.record(folder.to_string_lossy().into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::desktop::RecordingDesktop;
+
+    #[test]
+    fn test_opens_containing_folder() {
+        let mut opener = RecordingDesktop::default();
+        assert!(open_or_log(&mut opener, Path::new("/outputs/job-1.png")).is_ok());
+        assert!(open_or_log(&mut opener, Path::new("job-2.png")).is_ok());
+        assert_eq!(opener.received, vec!["/outputs", "."]);
+    }
+
+    #[cfg(not(feature = "file_manager"))]
+    #[test]
+    fn test_system_opener_needs_feature() {
+        assert!(open_in_file_manager(Path::new("/outputs/job-1.png")).is_err());
+    }
+}
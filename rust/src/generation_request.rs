@@ -0,0 +1,343 @@
+//! # Generation Requests
+//!
+//! Every submission path (Generation screen, comparisons, batch and variant
+//! jobs) builds its backend payload with [`GenerationRequestBuilder`], so
+//! prompts are assembled, defaults applied and values validated the same
+//! way everywhere. Fields left unset come from [`GenerationDefaults`]
+//! (the user's settings in the Bevy app).
+
+use anyhow::{bail, Result};
+
+use crate::comparison::{GenerationParams, ModelConfig};
+use crate::messages::{LoraWeight, Request};
+
+/// Values used for fields a request doesn't set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenerationDefaults {
+    pub model: String,
+    pub size: (u32, u32),
+    pub steps: u32,
+    pub cfg_scale: f32,
+    /// Prepended to every prompt (skipped when empty)
+    pub prompt_prefix: String,
+    /// Appended to every prompt (skipped when empty)
+    pub prompt_suffix: String,
+}
+
+impl Default for GenerationDefaults {
+    fn default() -> Self {
+        Self {
+            model: "sd_xl_base_1.0".to_string(),
+            size: (1024, 1024),
+            steps: 30,
+            cfg_scale: 7.5,
+            prompt_prefix: String::new(),
+            prompt_suffix: String::new(),
+        }
+    }
+}
+
+/// Builder for the canonical `Request::Generate` payload.
+#[derive(Debug, Clone, Default)]
+pub struct GenerationRequestBuilder {
+    id: Option<String>,
+    prompt: String,
+    negative_prompt: Option<String>,
+    model: Option<String>,
+    loras: Vec<LoraWeight>,
+    size: Option<(u32, u32)>,
+    steps: Option<u32>,
+    cfg_scale: Option<f32>,
+    seed: Option<u64>,
+    workflow: Option<String>,
+    priority: Option<u8>,
+}
+
+impl GenerationRequestBuilder {
+    /// Start a request for `prompt`.
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Job ID (a new `job-<uuid>` if unset).
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn negative_prompt(mut self, negative: impl Into<String>) -> Self {
+        self.negative_prompt = Some(negative.into());
+        self
+    }
+
+    /// Base model checkpoint.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Stack a LoRA at `strength` on the base model (applied in the order
+    /// added).
+    pub fn lora(mut self, name: impl Into<String>, strength: f32) -> Self {
+        self.loras.push(LoraWeight {
+            name: name.into(),
+            strength,
+        });
+        self
+    }
+
+    /// Base model (the default if `None`) and LoRAs with their strengths,
+    /// like the Models screen's active stack.
+    pub fn model_stack(mut self, base: Option<&str>, loras: &[(String, f32)]) -> Self {
+        if let Some(base) = base {
+            self = self.model(base);
+        }
+        loras
+            .iter()
+            .fold(self, |builder, (name, strength)| builder.lora(name.clone(), *strength))
+    }
+
+    /// Base model and LoRA from a comparison model config.
+    pub fn model_config(self, config: &ModelConfig) -> Self {
+        let builder = self.model(config.base.clone());
+        match &config.lora {
+            Some(lora) => builder.lora(lora.clone(), config.lora_strength),
+            None => builder,
+        }
+    }
+
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    pub fn steps(mut self, steps: u32) -> Self {
+        self.steps = Some(steps);
+        self
+    }
+
+    pub fn cfg_scale(mut self, cfg_scale: f32) -> Self {
+        self.cfg_scale = Some(cfg_scale);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Backend workflow file (the backend's default if unset).
+    pub fn workflow(mut self, workflow: impl Into<String>) -> Self {
+        self.workflow = Some(workflow.into());
+        self
+    }
+
+    /// Scheduling priority, higher first (the backend's default if unset).
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Negative prompt, seed, size, steps and CFG scale from shared
+    /// comparison parameters. The prompt is left as given to [`Self::new`].
+    pub fn params(mut self, params: &GenerationParams) -> Self {
+        self.negative_prompt = params.negative_prompt.clone();
+        self.seed = Some(params.seed);
+        self.size(params.width, params.height)
+            .steps(params.steps)
+            .cfg_scale(params.cfg_scale)
+    }
+
+    /// Build the request, filling unset fields from `defaults`.
+    ///
+    /// Fails if the prompt is empty or a parameter is out of range.
+    pub fn build(self, defaults: &GenerationDefaults) -> Result<Request> {
+        let prompt = [
+            defaults.prompt_prefix.trim(),
+            self.prompt.trim(),
+            defaults.prompt_suffix.trim(),
+        ]
+        .iter()
+        .filter(|part| !part.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(", ");
+        if self.prompt.trim().is_empty() {
+            bail!("Prompt is empty");
+        }
+
+        let (width, height) = self.size.unwrap_or(defaults.size);
+        if width == 0 || height == 0 || width % 8 != 0 || height % 8 != 0 {
+            bail!("Size must be a positive multiple of 8, got {}x{}", width, height);
+        }
+        let steps = self.steps.unwrap_or(defaults.steps);
+        if !(1..=150).contains(&steps) {
+            bail!("Steps must be between 1 and 150, got {}", steps);
+        }
+        let cfg_scale = self.cfg_scale.unwrap_or(defaults.cfg_scale);
+        if !(cfg_scale.is_finite() && cfg_scale > 0.0) {
+            bail!("CFG scale must be positive, got {}", cfg_scale);
+        }
+        if let Some(lora) = self.loras.iter().find(|l| !l.strength.is_finite()) {
+            bail!("LoRA strength must be a number, got {} for {}", lora.strength, lora.name);
+        }
+
+        let negative_prompt = self
+            .negative_prompt
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty());
+
+        Ok(Request::Generate {
+            id: self
+                .id
+                .unwrap_or_else(|| format!("job-{}", uuid::Uuid::new_v4())),
+            prompt,
+            model: self.model.unwrap_or_else(|| defaults.model.clone()),
+            lora: self.loras.first().map(|l| l.name.clone()),
+            loras: self.loras,
+            size: (width, height),
+            steps,
+            cfg_scale,
+            negative_prompt,
+            seed: self.seed,
+            workflow: self.workflow,
+            priority: self.priority,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equivalent_inputs_build_identical_payloads() {
+        let params = GenerationParams {
+            prompt: "ignored".to_string(),
+            negative_prompt: Some("blurry".to_string()),
+            seed: 7,
+            width: 512,
+            height: 768,
+            steps: 20,
+            cfg_scale: 6.0,
+        };
+        let model = ModelConfig {
+            base: "sdxl_pixel.safetensors".to_string(),
+            lora: Some("pixel_art".to_string()),
+            lora_strength: 0.8,
+            ..Default::default()
+        };
+        let defaults = GenerationDefaults::default();
+
+        let from_params = GenerationRequestBuilder::new("  knight sprite ")
+            .id("job-1")
+            .model_config(&model)
+            .params(&params)
+            .build(&defaults)
+            .unwrap();
+        let by_field = GenerationRequestBuilder::new("knight sprite")
+            .id("job-1")
+            .model("sdxl_pixel.safetensors")
+            .lora("pixel_art", 0.8)
+            .negative_prompt("blurry ")
+            .seed(7)
+            .size(512, 768)
+            .steps(20)
+            .cfg_scale(6.0)
+            .build(&defaults)
+            .unwrap();
+
+        assert_eq!(from_params, by_field);
+        assert_eq!(
+            crate::messages::serialize(&from_params).unwrap(),
+            crate::messages::serialize(&by_field).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unset_fields_use_defaults() {
+        let defaults = GenerationDefaults {
+            model: "SDXL Base 1.0".to_string(),
+            size: (768, 768),
+            steps: 40,
+            cfg_scale: 5.0,
+            prompt_prefix: "pixel art".to_string(),
+            prompt_suffix: String::new(),
+        };
+
+        let request = GenerationRequestBuilder::new("knight")
+            .id("job-2")
+            .build(&defaults)
+            .unwrap();
+        assert_eq!(
+            request,
+            Request::Generate {
+                id: "job-2".to_string(),
+                prompt: "pixel art, knight".to_string(),
+                model: "SDXL Base 1.0".to_string(),
+                lora: None,
+                loras: Vec::new(),
+                size: (768, 768),
+                steps: 40,
+                cfg_scale: 5.0,
+                negative_prompt: None,
+                seed: None,
+                workflow: None,
+                priority: None,
+            }
+        );
+
+        assert!(GenerationRequestBuilder::new("  ").build(&defaults).is_err());
+        assert!(GenerationRequestBuilder::new("knight")
+            .steps(0)
+            .build(&defaults)
+            .is_err());
+    }
+
+    #[test]
+    fn test_model_stack_applies_every_lora() {
+        let loras = vec![("pixel_art".to_string(), 0.8), ("outline".to_string(), 1.2)];
+        let request = GenerationRequestBuilder::new("knight")
+            .model_stack(Some("sdxl_pixel.safetensors"), &loras)
+            .build(&GenerationDefaults::default())
+            .unwrap();
+        let Request::Generate {
+            model,
+            lora,
+            loras,
+            ..
+        } = request
+        else {
+            panic!("expected a generate request");
+        };
+        assert_eq!(model, "sdxl_pixel.safetensors");
+        assert_eq!(lora.as_deref(), Some("pixel_art"));
+        assert_eq!(
+            loras,
+            vec![
+                LoraWeight {
+                    name: "pixel_art".to_string(),
+                    strength: 0.8
+                },
+                LoraWeight {
+                    name: "outline".to_string(),
+                    strength: 1.2
+                },
+            ]
+        );
+
+        // No active base keeps the configured default
+        let request = GenerationRequestBuilder::new("knight")
+            .model_stack(None, &[])
+            .build(&GenerationDefaults::default())
+            .unwrap();
+        assert!(matches!(
+            request,
+            Request::Generate { ref model, ref loras, .. }
+                if model == "sd_xl_base_1.0" && loras.is_empty()
+        ));
+    }
+}
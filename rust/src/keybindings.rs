@@ -0,0 +1,498 @@
+//! # Keybindings
+//!
+//! Keys for the app's shortcuts, loaded from `~/.config/dgx-pixels/keys.toml`
+//! (next to `config.toml`). Each entry maps an action name to one key or a
+//! list of keys; actions the file leaves out keep their default keys:
+//!
+//! ```toml
+//! generate = "ctrl+g"
+//! delete = ["d", "delete"]
+//! command_palette = "ctrl+k"
+//! ```
+//!
+//! A key is an optional `ctrl+`, `alt+` or `shift+` prefix followed by a
+//! character (`g`, `/`), or a key name (`enter`, `esc`, `tab`, `backspace`,
+//! `delete`, `home`, `end`, `pageup`, `pagedown`, arrow keys, `space`,
+//! `f1`-`f12`). Letters match either case.
+//!
+//! With the Bevy app these are the `Keybindings` resource the input systems
+//! consult. [`generate_keybinding_cheatsheet`] renders the effective keys as
+//! Markdown (`dgx-pixels-tui --print-keys`).
+
+use anyhow::{bail, Context, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::collections::HashMap;
+use std::fmt::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use tracing::{info, warn};
+
+/// A shortcut that can be rebound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAction {
+    Quit,
+    NextScreen,
+    PreviousScreen,
+    CommandPalette,
+    Generate,
+    CancelJob,
+    Delete,
+    UndoDelete,
+    AddTag,
+    FilterTag,
+    ToggleFavorite,
+    FavoritesView,
+    PromptPresets,
+    ToggleSeed,
+    Regenerate,
+    SeedSweep,
+    DismissNotifications,
+}
+
+impl KeyAction {
+    /// Every action, in the order they're documented.
+    pub const ALL: [KeyAction; 17] = [
+        KeyAction::Quit,
+        KeyAction::NextScreen,
+        KeyAction::PreviousScreen,
+        KeyAction::CommandPalette,
+        KeyAction::Generate,
+        KeyAction::CancelJob,
+        KeyAction::Delete,
+        KeyAction::UndoDelete,
+        KeyAction::AddTag,
+        KeyAction::FilterTag,
+        KeyAction::ToggleFavorite,
+        KeyAction::FavoritesView,
+        KeyAction::PromptPresets,
+        KeyAction::ToggleSeed,
+        KeyAction::Regenerate,
+        KeyAction::SeedSweep,
+        KeyAction::DismissNotifications,
+    ];
+
+    /// Name used in `keys.toml`.
+    pub fn name(self) -> &'static str {
+        match self {
+            KeyAction::Quit => "quit",
+            KeyAction::NextScreen => "next_screen",
+            KeyAction::PreviousScreen => "previous_screen",
+            KeyAction::CommandPalette => "command_palette",
+            KeyAction::Generate => "generate",
+            KeyAction::CancelJob => "cancel_job",
+            KeyAction::Delete => "delete",
+            KeyAction::UndoDelete => "undo_delete",
+            KeyAction::AddTag => "add_tag",
+            KeyAction::FilterTag => "filter_tag",
+            KeyAction::ToggleFavorite => "toggle_favorite",
+            KeyAction::FavoritesView => "favorites_view",
+            KeyAction::PromptPresets => "prompt_presets",
+            KeyAction::ToggleSeed => "toggle_seed",
+            KeyAction::Regenerate => "regenerate",
+            KeyAction::SeedSweep => "seed_sweep",
+            KeyAction::DismissNotifications => "dismiss_notifications",
+        }
+    }
+
+    /// What the action does, for the cheat-sheet.
+    pub fn description(self) -> &'static str {
+        match self {
+            KeyAction::Quit => "Quit application",
+            KeyAction::NextScreen => "Next screen",
+            KeyAction::PreviousScreen => "Previous screen",
+            KeyAction::CommandPalette => "Open the command palette",
+            KeyAction::Generate => "Submit job for generation",
+            KeyAction::CancelJob => "Cancel the running job",
+            KeyAction::Delete => "Delete current image",
+            KeyAction::UndoDelete => "Restore the last deleted image",
+            KeyAction::AddTag => "Add a tag to the image",
+            KeyAction::FilterTag => "Filter images by tag",
+            KeyAction::ToggleFavorite => "Mark or unmark the image as a favorite",
+            KeyAction::FavoritesView => "Show only favorites",
+            KeyAction::PromptPresets => "Pick a prompt preset",
+            KeyAction::ToggleSeed => "Switch between random and fixed seed",
+            KeyAction::Regenerate => "Regenerate the last job with a new seed",
+            KeyAction::SeedSweep => "Open the seed sweep dialog",
+            KeyAction::DismissNotifications => "Dismiss notifications",
+        }
+    }
+
+    /// Screen the action is used on ("Global" applies everywhere).
+    pub fn screen(self) -> &'static str {
+        match self {
+            KeyAction::Quit
+            | KeyAction::NextScreen
+            | KeyAction::PreviousScreen
+            | KeyAction::CommandPalette
+            | KeyAction::DismissNotifications => "Global",
+            KeyAction::Generate
+            | KeyAction::CancelJob
+            | KeyAction::PromptPresets
+            | KeyAction::ToggleSeed
+            | KeyAction::Regenerate
+            | KeyAction::SeedSweep => "Generation",
+            KeyAction::Delete
+            | KeyAction::UndoDelete
+            | KeyAction::AddTag
+            | KeyAction::FilterTag
+            | KeyAction::ToggleFavorite
+            | KeyAction::FavoritesView => "Gallery",
+        }
+    }
+
+    /// Keys bound to the action when `keys.toml` doesn't say otherwise.
+    pub fn default_keys(self) -> &'static [&'static str] {
+        match self {
+            KeyAction::Quit => &["q"],
+            KeyAction::NextScreen => &["tab"],
+            KeyAction::PreviousScreen => &["shift+tab"],
+            KeyAction::CommandPalette => &["ctrl+p"],
+            KeyAction::Generate => &["g"],
+            KeyAction::CancelJob => &["x"],
+            KeyAction::Delete => &["d", "delete"],
+            KeyAction::UndoDelete => &["u"],
+            KeyAction::AddTag => &["+"],
+            KeyAction::FilterTag => &["/"],
+            KeyAction::ToggleFavorite => &["*"],
+            KeyAction::FavoritesView => &["shift+f"],
+            KeyAction::PromptPresets => &["t"],
+            // Alt so prompts can start with any letter (like Alt+P for pin)
+            KeyAction::ToggleSeed => &["alt+s"],
+            KeyAction::Regenerate => &["alt+r"],
+            KeyAction::SeedSweep => &["alt+w"],
+            KeyAction::DismissNotifications => &["ctrl+x"],
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.name() == name)
+    }
+}
+
+/// A key plus the modifiers held with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeySpec {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeySpec {
+    /// Whether a key press is this key.
+    ///
+    /// Letters match either case, so Shift only counts for other keys (and
+    /// for letters bound with an explicit `shift+`).
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        if event.kind == KeyEventKind::Release {
+            return false;
+        }
+        let held =
+            event.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+        match (self.code, event.code) {
+            (KeyCode::Char(want), KeyCode::Char(got)) => {
+                let shifted = held.contains(KeyModifiers::SHIFT) || got.is_uppercase();
+                want.eq_ignore_ascii_case(&got)
+                    && held - KeyModifiers::SHIFT == self.modifiers - KeyModifiers::SHIFT
+                    && (!self.modifiers.contains(KeyModifiers::SHIFT) || shifted)
+            }
+            // Terminals report Shift+Tab as BackTab, with or without Shift
+            (KeyCode::BackTab, KeyCode::BackTab) => {
+                held - KeyModifiers::SHIFT == self.modifiers - KeyModifiers::SHIFT
+            }
+            (want, got) => want == got && held == self.modifiers,
+        }
+    }
+}
+
+impl FromStr for KeySpec {
+    type Err = anyhow::Error;
+
+    fn from_str(spec: &str) -> Result<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut parts: Vec<&str> = spec.split('+').collect();
+        // A trailing "+" is the plus key itself ("ctrl++")
+        let key = if spec.ends_with("++") || spec == "+" {
+            parts.truncate(parts.len().saturating_sub(2));
+            "+".to_string()
+        } else {
+            parts.pop().unwrap_or_default().to_lowercase()
+        };
+        for modifier in parts {
+            modifiers |= match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                other => bail!("Unknown modifier {:?} in key {:?}", other, spec),
+            };
+        }
+
+        let code = match key.as_str() {
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" => KeyCode::Insert,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "space" => KeyCode::Char(' '),
+            f if f.len() > 1 && f.starts_with('f') => match f[1..].parse::<u8>() {
+                Ok(n @ 1..=12) => KeyCode::F(n),
+                _ => bail!("Unknown key {:?}", spec),
+            },
+            c => {
+                let mut chars = c.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => bail!("Unknown key {:?}", spec),
+                }
+            }
+        };
+        Ok(Self { code, modifiers })
+    }
+}
+
+impl fmt::Display for KeySpec {
+    /// The key as written in `keys.toml` (e.g. `ctrl+p`, `shift+tab`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "ctrl+"),
+            (KeyModifiers::ALT, "alt+"),
+            (KeyModifiers::SHIFT, "shift+"),
+        ] {
+            // BackTab is written as shift+tab
+            if self.modifiers.contains(modifier)
+                && !(modifier == KeyModifiers::SHIFT && self.code == KeyCode::BackTab)
+            {
+                f.write_str(name)?;
+            }
+        }
+        match self.code {
+            KeyCode::Enter => f.write_str("enter"),
+            KeyCode::Esc => f.write_str("esc"),
+            KeyCode::Tab => f.write_str("tab"),
+            KeyCode::BackTab => f.write_str("shift+tab"),
+            KeyCode::Backspace => f.write_str("backspace"),
+            KeyCode::Delete => f.write_str("delete"),
+            KeyCode::Insert => f.write_str("insert"),
+            KeyCode::Home => f.write_str("home"),
+            KeyCode::End => f.write_str("end"),
+            KeyCode::PageUp => f.write_str("pageup"),
+            KeyCode::PageDown => f.write_str("pagedown"),
+            KeyCode::Up => f.write_str("up"),
+            KeyCode::Down => f.write_str("down"),
+            KeyCode::Left => f.write_str("left"),
+            KeyCode::Right => f.write_str("right"),
+            KeyCode::Char(' ') => f.write_str("space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "f{}", n),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+/// Action to key mapping consulted by the input systems.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "bevy_migration_foundation", derive(bevy::prelude::Resource))]
+pub struct Keybindings {
+    bindings: HashMap<KeyAction, Vec<KeySpec>>,
+}
+
+impl Default for Keybindings {
+    fn default() -> Self {
+        let bindings = KeyAction::ALL
+            .into_iter()
+            .map(|action| {
+                let keys = action
+                    .default_keys()
+                    .iter()
+                    .filter_map(|key| key.parse().ok())
+                    .collect();
+                (action, keys)
+            })
+            .collect();
+        Self { bindings }
+    }
+}
+
+impl Keybindings {
+    /// Path of the keybindings file.
+    pub fn keys_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from(".config"))
+            .join("dgx-pixels")
+            .join("keys.toml")
+    }
+
+    /// Load keybindings from the keybindings file.
+    pub fn load() -> Result<Self> {
+        Self::load_from(&Self::keys_path())
+    }
+
+    /// Load keybindings from `path`; a missing file gives the defaults.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            info!("Keybindings file not found, using default keys: {:?}", path);
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read keybindings file: {:?}", path))?;
+        Self::from_toml(&content)
+            .with_context(|| format!("Failed to parse keybindings file: {:?}", path))
+    }
+
+    /// Defaults overridden by the actions listed in a `keys.toml` document.
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let table: toml::Table = toml::from_str(content).context("Invalid TOML")?;
+        let mut keybindings = Self::default();
+
+        for (name, value) in table {
+            let Some(action) = KeyAction::from_name(&name) else {
+                warn!("Ignoring unknown keybinding action: {}", name);
+                continue;
+            };
+            let specs: Vec<&str> = match &value {
+                toml::Value::String(key) => vec![key.as_str()],
+                toml::Value::Array(keys) => keys
+                    .iter()
+                    .map(|key| {
+                        key.as_str()
+                            .with_context(|| format!("Keys for {} must be strings", name))
+                    })
+                    .collect::<Result<_>>()?,
+                _ => bail!("Keys for {} must be a string or a list of strings", name),
+            };
+            let keys = specs
+                .into_iter()
+                .map(|spec| spec.parse().with_context(|| format!("Invalid key for {}", name)))
+                .collect::<Result<_>>()?;
+            keybindings.bindings.insert(action, keys);
+        }
+
+        Ok(keybindings)
+    }
+
+    /// Keys bound to an action.
+    pub fn keys(&self, action: KeyAction) -> &[KeySpec] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether a key press triggers an action.
+    pub fn matches(&self, action: KeyAction, event: &KeyEvent) -> bool {
+        self.keys(action).iter().any(|key| key.matches(event))
+    }
+}
+
+/// Render keybindings as a Markdown cheat-sheet, one table per screen.
+pub fn generate_keybinding_cheatsheet(keys: &Keybindings) -> String {
+    let mut out = String::from("# DGX-Pixels Keybindings\n");
+
+    for screen in ["Global", "Generation", "Gallery"] {
+        let _ = write!(out, "\n## {}\n\n| Key | Action | Description |\n|---|---|---|\n", screen);
+        for action in KeyAction::ALL.into_iter().filter(|a| a.screen() == screen) {
+            let bound: Vec<String> =
+                keys.keys(action).iter().map(|key| format!("`{}`", key)).collect();
+            let _ = writeln!(
+                out,
+                "| {} | {} | {} |",
+                bound.join(" / "),
+                action.name(),
+                action.description()
+            );
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_key_spec_matching() {
+        let palette: KeySpec = "ctrl+p".parse().unwrap();
+        assert!(palette.matches(&press(KeyCode::Char('p'), KeyModifiers::CONTROL)));
+        let ctrl_shift = KeyModifiers::CONTROL | KeyModifiers::SHIFT;
+        assert!(palette.matches(&press(KeyCode::Char('P'), ctrl_shift)));
+        assert!(!palette.matches(&press(KeyCode::Char('p'), KeyModifiers::NONE)));
+        let ctrl_alt = KeyModifiers::CONTROL | KeyModifiers::ALT;
+        assert!(!palette.matches(&press(KeyCode::Char('p'), ctrl_alt)));
+
+        let generate: KeySpec = "g".parse().unwrap();
+        assert!(generate.matches(&press(KeyCode::Char('G'), KeyModifiers::SHIFT)));
+        assert!(!generate.matches(&press(KeyCode::Char('g'), KeyModifiers::CONTROL)));
+
+        let back: KeySpec = "shift+tab".parse().unwrap();
+        assert!(back.matches(&press(KeyCode::BackTab, KeyModifiers::SHIFT)));
+        assert_eq!("F5".parse::<KeySpec>().unwrap().code, KeyCode::F(5));
+        assert_eq!("ctrl++".parse::<KeySpec>().unwrap().code, KeyCode::Char('+'));
+        assert!("hyper+x".parse::<KeySpec>().is_err());
+        assert!("nosuchkey".parse::<KeySpec>().is_err());
+    }
+
+    #[test]
+    fn test_keys_file_overrides_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keys.toml");
+
+        // Missing file: defaults
+        let keys = Keybindings::load_from(&path).unwrap();
+        let plain_g = press(KeyCode::Char('g'), KeyModifiers::NONE);
+        assert!(keys.matches(KeyAction::Generate, &plain_g));
+
+        std::fs::write(
+            &path,
+            "generate = \"ctrl+g\"\ndelete = [\"x\", \"backspace\"]\nteleport = \"t\"\n",
+        )
+        .unwrap();
+        let keys = Keybindings::load_from(&path).unwrap();
+        let ctrl_g = press(KeyCode::Char('g'), KeyModifiers::CONTROL);
+        assert!(keys.matches(KeyAction::Generate, &ctrl_g));
+        assert!(!keys.matches(KeyAction::Generate, &plain_g));
+        assert!(keys.matches(KeyAction::Delete, &press(KeyCode::Backspace, KeyModifiers::NONE)));
+        assert!(!keys.matches(KeyAction::Delete, &press(KeyCode::Char('d'), KeyModifiers::NONE)));
+        // Actions the file doesn't list keep their defaults
+        assert_eq!(
+            keys.keys(KeyAction::CommandPalette),
+            Keybindings::default().keys(KeyAction::CommandPalette)
+        );
+        assert!(keys.matches(KeyAction::Quit, &press(KeyCode::Char('q'), KeyModifiers::NONE)));
+
+        std::fs::write(&path, "generate = \"ctrl+nosuchkey\"\n").unwrap();
+        assert!(Keybindings::load_from(&path).is_err());
+    }
+
+    #[test]
+    fn test_cheatsheet_lists_keys_toml_rebinds() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("keys.toml");
+        std::fs::write(&path, "generate = \"ctrl+g\"\ndelete = [\"x\", \"delete\"]\n").unwrap();
+
+        let keys = Keybindings::load_from(&path).unwrap();
+        let sheet = generate_keybinding_cheatsheet(&keys);
+
+        assert!(sheet.contains("## Generation"));
+        assert!(sheet.contains("| `ctrl+g` | generate |"));
+        assert!(sheet.contains("| `x` / `delete` | delete |"));
+        // Default keys stay in place for other actions
+        assert!(sheet.contains("| `shift+tab` | previous_screen |"));
+        assert!(sheet.contains("| `ctrl+p` | command_palette |"));
+        // Every action is listed once
+        for action in KeyAction::ALL {
+            assert_eq!(sheet.matches(&format!("| {} |", action.name())).count(), 1);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! # Last Prompt
+//!
+//! The last submitted prompt is kept in `~/.config/dgx-pixels/last_prompt.txt`
+//! (next to `config.toml`) and put back in the prompt box on startup, so a
+//! base prompt doesn't have to be retyped every session. Used by both the
+//! classic app and the Bevy `InputBuffer`.
+//!
+//! The Bevy `InputBuffer` also keeps the last [`PROMPT_HISTORY_CAP`] prompts,
+//! oldest first, as a JSON list in `prompt_history.json` beside it.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+/// Longest prompt stored, in bytes (longer prompts are truncated).
+pub const MAX_PROMPT_BYTES: usize = 4096;
+
+/// Most prompts kept in the history (older ones are dropped).
+pub const PROMPT_HISTORY_CAP: usize = 100;
+
+/// File name of the prompt history, in the same directory as the last prompt.
+pub const PROMPT_HISTORY_FILE: &str = "prompt_history.json";
+
+/// Path of the last-prompt file.
+pub fn last_prompt_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from(".config"))
+        .join("dgx-pixels")
+        .join("last_prompt.txt")
+}
+
+/// Read the prompt saved at `path`.
+///
+/// A missing, unreadable or empty file gives `None`.
+pub fn load_last_prompt(path: &Path) -> Option<String> {
+    match fs::read_to_string(path) {
+        Ok(content) => {
+            let prompt = truncate(content.trim()).to_string();
+            (!prompt.is_empty()).then_some(prompt)
+        }
+        Err(e) => {
+            debug!("No last prompt loaded from {:?}: {}", path, e);
+            None
+        }
+    }
+}
+
+/// Save `prompt` to `path`, truncated to [`MAX_PROMPT_BYTES`].
+pub fn save_last_prompt(path: &Path, prompt: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create config directory: {:?}", dir))?;
+    }
+    fs::write(path, truncate(prompt.trim()))
+        .with_context(|| format!("Failed to save last prompt: {:?}", path))
+}
+
+/// Read the prompt history saved at `path`, oldest first.
+///
+/// A missing or unreadable file gives an empty history.
+pub fn load_prompt_history(path: &Path) -> Vec<String> {
+    let history = fs::read_to_string(path)
+        .map_err(anyhow::Error::from)
+        .and_then(|content| Ok(serde_json::from_str::<Vec<String>>(&content)?));
+    match history {
+        Ok(mut history) => {
+            let excess = history.len().saturating_sub(PROMPT_HISTORY_CAP);
+            history.drain(..excess);
+            history
+        }
+        Err(e) => {
+            debug!("No prompt history loaded from {:?}: {}", path, e);
+            Vec::new()
+        }
+    }
+}
+
+/// Save the prompt history to `path`.
+pub fn save_prompt_history(path: &Path, history: &[String]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create config directory: {:?}", dir))?;
+    }
+    let json = serde_json::to_string(history).context("Failed to serialize prompt history")?;
+    fs::write(path, json).with_context(|| format!("Failed to save prompt history: {:?}", path))
+}
+
+/// `prompt` cut to at most [`MAX_PROMPT_BYTES`] on a character boundary.
+fn truncate(prompt: &str) -> &str {
+    let mut end = prompt.len().min(MAX_PROMPT_BYTES);
+    while !prompt.is_char_boundary(end) {
+        end -= 1;
+    }
+    &prompt[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_last_prompt_round_trip_and_edge_cases() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dgx-pixels").join("last_prompt.txt");
+
+        assert_eq!(load_last_prompt(&path), None);
+
+        save_last_prompt(&path, "pixel art knight\nfront view ").unwrap();
+        assert_eq!(
+            load_last_prompt(&path).as_deref(),
+            Some("pixel art knight\nfront view")
+        );
+
+        // Oversized prompts are capped without splitting a character
+        save_last_prompt(&path, &"é".repeat(MAX_PROMPT_BYTES)).unwrap();
+        let loaded = load_last_prompt(&path).unwrap();
+        assert_eq!(loaded.len(), MAX_PROMPT_BYTES);
+
+        // Not valid UTF-8
+        fs::write(&path, [0xff, 0xfe, 0x00]).unwrap();
+        assert_eq!(load_last_prompt(&path), None);
+    }
+}
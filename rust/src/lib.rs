@@ -5,11 +5,26 @@
 
 // Public modules
 pub mod app;
+pub mod backend;
+pub mod clipboard;
+pub mod comfyui_batch;
 pub mod comparison;
+pub mod demo;
+pub mod desktop;
 pub mod events;
+pub mod file_manager;
+pub mod generation_request;
+pub mod keybindings;
+pub mod last_prompt;
 pub mod messages;
+pub mod metadata;
+pub mod progress;
 pub mod reports;
+#[cfg(feature = "screenshot")]
+pub mod screenshot;
+pub mod shutdown;
 pub mod sixel;
+pub mod terminal_guard;
 pub mod ui;
 pub mod zmq_client;
 
@@ -23,8 +38,10 @@ use anyhow::Result;
 ///
 /// This is the original imperative event loop implementation that will be
 /// gradually replaced by the Bevy ECS architecture during the migration.
+/// With `demo` set, jobs go to a [`demo::DemoBackend`] instead of the
+/// ZeroMQ backend.
 #[tokio::main]
-pub async fn run_classic_app() -> Result<()> {
+pub async fn run_classic_app(demo: bool) -> Result<()> {
     use app::App;
     use crossterm::{
         execute,
@@ -35,7 +52,8 @@ pub async fn run_classic_app() -> Result<()> {
     use tracing::{info, warn};
     use zmq_client::ZmqClient;
 
-    // Setup terminal
+    // Setup terminal (restored on panic too)
+    terminal_guard::install_panic_hook();
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
@@ -51,21 +69,35 @@ pub async fn run_classic_app() -> Result<()> {
         app.preview_tab = 1; // Default to Logs tab in debug mode
     }
 
+    // Start with the prompt submitted last session
+    app.load_last_prompt(last_prompt::last_prompt_path());
+
+    // Past comparisons for the Comparison screen's history view
+    match comparison::ComparisonManager::load() {
+        Ok(manager) => app.comparison_state.comparison_manager = manager,
+        Err(e) => warn!("Failed to load comparison history: {:#}", e),
+    }
+
     // Load existing images from outputs directory
     app.load_gallery_from_outputs("../outputs");
     if !app.gallery_images.is_empty() {
         info!("Loaded {} existing images into gallery", app.gallery_images.len());
     }
 
-    // Initialize ZeroMQ client for backend communication
-    match ZmqClient::new_default() {
-        Ok(client) => {
-            info!("ZeroMQ client connected");
-            app.zmq_client = Some(client);
-        }
-        Err(e) => {
-            warn!("Failed to connect to backend: {}", e);
-            warn!("Generation features will be disabled");
+    // Initialize ZeroMQ client for backend communication (or the demo stand-in)
+    if demo {
+        info!("Demo mode: using the fake backend");
+        app.zmq_client = Some(Box::new(demo::DemoBackend::new(demo::DEMO_OUTPUT_DIR)));
+    } else {
+        match ZmqClient::with_config(&backend_zmq_config()) {
+            Ok(client) => {
+                info!("ZeroMQ client connected");
+                app.zmq_client = Some(Box::new(client));
+            }
+            Err(e) => {
+                warn!("Failed to connect to backend: {}", e);
+                warn!("Generation features will be disabled");
+            }
         }
     }
 
@@ -77,6 +109,18 @@ pub async fn run_classic_app() -> Result<()> {
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
 
+    // Persist session state
+    let state = shutdown::ShutdownState {
+        job_history: app.active_jobs.iter().map(Into::into).collect(),
+        comparisons: app
+            .comparison_state
+            .comparison_manager
+            .get_all_completed()
+            .to_vec(),
+        dirty_settings: None,
+    };
+    shutdown::shutdown(&state, &shutdown::ShutdownPaths::default());
+
     if let Err(err) = &result {
         eprintln!("Error: {}", err);
     }
@@ -91,12 +135,10 @@ async fn run_classic_event_loop<B: ratatui::backend::Backend>(
     app: &mut app::App,
 ) -> Result<()> {
     use crossterm::event::{self, Event};
-    use messages::{ProgressUpdate, Response};
     use std::fs::File;
     use std::io::{BufRead, BufReader};
-    use std::path::PathBuf;
     use std::time::Duration;
-    use tracing::{info, warn};
+    use tracing::{debug, info, warn};
 
     // Open backend log file if debug mode is enabled
     let mut log_reader = if app.debug_mode {
@@ -152,6 +194,18 @@ async fn run_classic_event_loop<B: ratatui::backend::Backend>(
 
         // Process preview results from async worker
         while let Some(preview_result) = app.preview_manager.try_recv_result() {
+            // Failed thumbnails stay requested and show their filename instead
+            if preview_result.thumbnail {
+                match preview_result.error {
+                    Some(error) => debug!(
+                        "Thumbnail failed for {:?}: {}",
+                        preview_result.path, error
+                    ),
+                    None => app.needs_redraw = true,
+                }
+                continue;
+            }
+
             // Remove from pending requests
             app.preview_manager.request_timestamps.remove(&preview_result.path);
 
@@ -167,111 +221,9 @@ async fn run_classic_event_loop<B: ratatui::backend::Backend>(
             }
         }
 
-        // Poll for ZMQ responses - collect first, then process
-        let mut responses = Vec::new();
-        let mut updates = Vec::new();
+        poll_backend(app);
 
-        if let Some(ref client) = app.zmq_client {
-            while let Some(response) = client.try_recv_response() {
-                responses.push(response);
-            }
-            while let Some(update) = client.try_recv_update() {
-                updates.push(update);
-            }
-        }
-
-        // Process responses
-        for response in responses {
-            match response {
-                Response::JobAccepted {
-                    job_id,
-                    estimated_time_s: _,
-                } => {
-                    info!("Job accepted: {}", job_id);
-                }
-                Response::JobComplete {
-                    job_id,
-                    image_path,
-                    duration_s,
-                } => {
-                    info!("Job complete: {}, output: {}", job_id, image_path);
-                    let path = PathBuf::from(&image_path);
-                    // Add to gallery
-                    app.add_to_gallery(path.clone());
-                    // Set as current preview
-                    app.current_preview = Some(path);
-                    // Update job status to complete
-                    app.update_job_status(
-                        &job_id,
-                        app::JobStatus::Complete {
-                            image_path: PathBuf::from(image_path),
-                            duration_s,
-                        },
-                    );
-                    app.needs_redraw = true;
-                }
-                Response::JobError { job_id, error } => {
-                    warn!("Job {} failed: {}", job_id, error);
-                }
-                Response::Error { message } => {
-                    warn!("Backend error: {}", message);
-                }
-                _ => {} // Ignore other response types
-            }
-        }
-
-        // Process progress updates
-        for update in updates {
-            match update {
-                ProgressUpdate::Progress {
-                    job_id,
-                    stage,
-                    percent,
-                    eta_s,
-                    ..
-                } => {
-                    let stage_str = format!("{:?}", stage);
-                    info!(
-                        "Job {} progress: {}% ({})",
-                        job_id, percent as u32, stage_str
-                    );
-                    app.update_job_status(
-                        &job_id,
-                        app::JobStatus::Running {
-                            stage: stage_str,
-                            progress: percent / 100.0,
-                            eta_s,
-                        },
-                    );
-                    app.needs_redraw = true;
-                }
-                ProgressUpdate::JobComplete {
-                    job_id,
-                    image_path,
-                    duration_s,
-                } => {
-                    info!(
-                        "Job {} completed in {:.1}s: {}",
-                        job_id, duration_s, image_path
-                    );
-                    let path = PathBuf::from(&image_path);
-                    // Add to gallery
-                    app.add_to_gallery(path.clone());
-                    // Set as current preview
-                    app.current_preview = Some(path);
-                    // Update job status to complete
-                    app.update_job_status(
-                        &job_id,
-                        app::JobStatus::Complete {
-                            image_path: PathBuf::from(image_path),
-                            duration_s,
-                        },
-                    );
-                    app.needs_redraw = true;
-                }
-                _ => {} // Ignore other update types
-            }
-        }
+        app.check_stalled_jobs(std::time::Instant::now());
 
         // Render UI
         ui::render(terminal, app)?;
@@ -302,3 +254,150 @@ async fn run_classic_event_loop<B: ratatui::backend::Backend>(
 
     Ok(())
 }
+
+/// Drain the backend's responses and progress updates into the classic app.
+///
+/// Completed jobs are marked complete and their images added to the gallery.
+pub(crate) fn poll_backend(app: &mut app::App) {
+    use messages::{ProgressUpdate, Response};
+    use std::path::PathBuf;
+    use tracing::{info, warn};
+
+    // Poll the backend - collect first, then process
+    let mut responses = Vec::new();
+    let mut updates = Vec::new();
+
+    if let Some(ref client) = app.zmq_client {
+        let state = client.connection_state();
+        if app.backend_state != Some(state) {
+            info!("Backend connection: {}", state);
+            app.backend_state = Some(state);
+            app.needs_redraw = true;
+        }
+
+        while let Some(response) = client.try_recv_response() {
+            responses.push(response);
+        }
+        while let Some(update) = client.try_recv_update() {
+            updates.push(update);
+        }
+    }
+
+    // Process responses
+    for response in responses {
+        match response {
+            Response::JobAccepted {
+                job_id,
+                estimated_time_s: _,
+            } => {
+                info!("Job accepted: {}", job_id);
+            }
+            Response::JobComplete {
+                job_id,
+                image_path,
+                duration_s,
+            } => {
+                info!("Job complete: {}, output: {}", job_id, image_path);
+                let path = PathBuf::from(&image_path);
+                if let Err(e) = app.save_job_metadata(&job_id, &path, duration_s) {
+                    warn!("Failed to write sidecar for {:?}: {:#}", path, e);
+                }
+                app.comparison_state.complete_job(&job_id, path.clone(), duration_s);
+                // Add to gallery
+                app.add_to_gallery(path.clone());
+                // Set as current preview
+                app.current_preview = Some(path);
+                // Update job status to complete
+                app.update_job_status(
+                    &job_id,
+                    app::JobStatus::Complete {
+                        image_path: PathBuf::from(image_path),
+                        duration_s,
+                    },
+                );
+                app.needs_redraw = true;
+            }
+            Response::JobError { job_id, error } => {
+                warn!("Job {} failed: {}", job_id, error);
+                if app.comparison_state.fail_job(&job_id, error) {
+                    app.needs_redraw = true;
+                }
+            }
+            Response::Error { message } => {
+                warn!("Backend error: {}", message);
+            }
+            _ => {} // Ignore other response types
+        }
+    }
+
+    // Process progress updates
+    for update in updates {
+        match update {
+            ProgressUpdate::Progress {
+                job_id,
+                stage,
+                percent,
+                eta_s,
+                ..
+            } => {
+                let stage_str = format!("{:?}", stage);
+                info!(
+                    "Job {} progress: {}% ({})",
+                    job_id, percent as u32, stage_str
+                );
+                app.record_job_progress(
+                    &job_id,
+                    stage_str,
+                    percent / 100.0,
+                    eta_s,
+                    std::time::Instant::now(),
+                );
+                if app.comparison_state.record_progress(&job_id, percent) {
+                    app.needs_redraw = true;
+                }
+            }
+            ProgressUpdate::JobComplete {
+                job_id,
+                image_path,
+                duration_s,
+            } => {
+                info!(
+                    "Job {} completed in {:.1}s: {}",
+                    job_id, duration_s, image_path
+                );
+                let path = PathBuf::from(&image_path);
+                if let Err(e) = app.save_job_metadata(&job_id, &path, duration_s) {
+                    warn!("Failed to write sidecar for {:?}: {:#}", path, e);
+                }
+                app.comparison_state.complete_job(&job_id, path.clone(), duration_s);
+                // Add to gallery
+                app.add_to_gallery(path.clone());
+                // Set as current preview
+                app.current_preview = Some(path);
+                // Update job status to complete
+                app.update_job_status(
+                    &job_id,
+                    app::JobStatus::Complete {
+                        image_path: PathBuf::from(image_path),
+                        duration_s,
+                    },
+                );
+                app.needs_redraw = true;
+            }
+            _ => {} // Ignore other update types
+        }
+    }
+}
+
+/// ZeroMQ topology from the backend section of config.toml.
+///
+/// Settings are part of the Bevy app, so builds without it (or with an
+/// unreadable config) use the default topology.
+fn backend_zmq_config() -> zmq_client::ZmqConfig {
+    #[cfg(feature = "bevy_migration_foundation")]
+    match bevy_app::resources::SettingsState::load() {
+        Ok(settings) => return settings.backend.zmq_config(),
+        Err(e) => tracing::warn!("Using default backend settings: {:#}", e),
+    }
+    zmq_client::ZmqConfig::default()
+}
@@ -7,15 +7,30 @@ use tracing::info;
 #[command(name = "dgx-pixels-tui")]
 #[command(about = "Terminal UI for AI pixel art generation", long_about = None)]
 struct Args {
-    /// Enable debug mode with live backend logs
+    /// Enable debug mode with live backend and TUI logs
     #[arg(short, long)]
     debug: bool,
+
+    /// Print the keybinding cheat-sheet (Markdown) and exit
+    #[arg(long)]
+    print_keys: bool,
+
+    /// Run without a GPU backend: jobs fake their progress and produce a placeholder image
+    #[arg(long)]
+    demo: bool,
 }
 
 fn main() -> Result<()> {
     // Parse command line arguments
     let args = Args::parse();
 
+    if args.print_keys {
+        // The keys the app uses: defaults with keys.toml applied
+        let keys = dgx_pixels_tui::keybindings::Keybindings::load()?;
+        print!("{}", dgx_pixels_tui::keybindings::generate_keybinding_cheatsheet(&keys));
+        return Ok(());
+    }
+
     // Initialize logging to file (not stdout, to avoid interfering with TUI)
     let log_file = std::fs::OpenOptions::new()
         .create(true)
@@ -31,35 +46,46 @@ fn main() -> Result<()> {
         .with_ansi(false) // No ANSI colors in log file
         .init();
 
-    info!("Starting DGX-Pixels TUI v0.1.0 (debug={})", args.debug);
+    info!(
+        "Starting DGX-Pixels TUI v0.1.0 (debug={}, demo={})",
+        args.debug, args.demo
+    );
+
+    // Both modes read the debug flag from the environment
+    if args.debug {
+        std::env::set_var("DGX_PIXELS_DEBUG", "1");
+    }
 
     // Run either Bevy-based or classic mode based on feature flag
     #[cfg(feature = "bevy_migration_foundation")]
     {
         info!("Starting Bevy-based DGX-Pixels TUI");
-        run_bevy_app()
+        run_bevy_app(args.demo)
     }
 
     #[cfg(not(feature = "bevy_migration_foundation"))]
     {
         info!("Starting classic ratatui DGX-Pixels TUI");
-        // Set debug mode in classic app if needed
-        if args.debug {
-            // Store debug flag for classic app
-            // Note: This is a temporary workaround until WS-02 (state migration)
-            // In the future, this will be stored in Bevy resources
-            std::env::set_var("DGX_PIXELS_DEBUG", "1");
-        }
-        dgx_pixels_tui::run_classic_app()
+        dgx_pixels_tui::run_classic_app(args.demo)
     }
 }
 
 #[cfg(feature = "bevy_migration_foundation")]
-fn run_bevy_app() -> Result<()> {
+fn run_bevy_app(demo: bool) -> Result<()> {
     use bevy::prelude::*;
+    use dgx_pixels_tui::bevy_app::systems::zmq::ZmqClientResource;
     use dgx_pixels_tui::bevy_app::DgxPixelsPlugin;
+    use dgx_pixels_tui::demo::{DemoBackend, DEMO_OUTPUT_DIR};
 
-    App::new().add_plugins(DgxPixelsPlugin).run();
+    // Covers startup; once running, bevy_ratatui's own hook also restores the terminal
+    dgx_pixels_tui::terminal_guard::install_panic_hook();
+
+    let mut app = App::new();
+    if demo {
+        // Inserted first so the plugin doesn't set up the ZeroMQ client
+        app.insert_resource(ZmqClientResource::new(DemoBackend::new(DEMO_OUTPUT_DIR)));
+    }
+    app.add_plugins(DgxPixelsPlugin).run();
 
     Ok(())
 }
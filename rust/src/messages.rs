@@ -19,11 +19,25 @@ pub enum Request {
         id: String,
         prompt: String,
         model: String,
+        /// First of `loras`, for backends that apply a single LoRA
         #[serde(skip_serializing_if = "Option::is_none")]
         lora: Option<String>,
+        /// LoRAs stacked on the base model, in order, with their strengths
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        loras: Vec<LoraWeight>,
         size: (u32, u32),
         steps: u32,
         cfg_scale: f32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        negative_prompt: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        seed: Option<u64>,
+        /// Workflow file (backend default if unset)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        workflow: Option<String>,
+        /// Scheduling priority, higher first (backend default if unset)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        priority: Option<u8>,
     },
 
     /// Cancel a running job
@@ -37,6 +51,19 @@ pub enum Request {
 
     /// Ping for health check
     Ping,
+
+    /// GPU utilization, VRAM and temperature (answered with `SystemStats`)
+    GetSystemStats,
+
+    /// Download a model (progress is published as `ModelDownload` updates)
+    DownloadModel { model: String },
+}
+
+/// A LoRA applied on top of the base model, and how strongly
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LoraWeight {
+    pub name: String,
+    pub strength: f32,
 }
 
 // ============================================================================
@@ -80,10 +107,23 @@ pub enum Response {
     /// Pong response
     Pong,
 
+    /// GPU and memory readings
+    SystemStats(SystemStats),
+
     /// Generic error
     Error { message: String },
 }
 
+/// GPU and memory readings reported by the backend
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SystemStats {
+    /// GPU utilization (0-100)
+    pub gpu_utilization: f32,
+    pub vram_used_mb: u64,
+    pub vram_total_mb: u64,
+    pub temperature_c: f32,
+}
+
 /// Model information
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ModelInfo {
@@ -143,6 +183,12 @@ pub enum ProgressUpdate {
         image_path: String,
         duration_s: f32,
     },
+
+    /// Model download progress (100 once the download is complete)
+    ModelDownload { model: String, percent: u8 },
+
+    /// Model download failed
+    ModelDownloadFailed { model: String, error: String },
 }
 
 /// Generation stage
@@ -204,9 +250,14 @@ mod tests {
             prompt: "16-bit knight sprite".to_string(),
             model: "sdxl-base".to_string(),
             lora: None,
+            loras: Vec::new(),
             size: (1024, 1024),
             steps: 30,
             cfg_scale: 7.5,
+            negative_prompt: None,
+            seed: None,
+            workflow: None,
+            priority: None,
         };
 
         let serialized = serialize(&req).expect("Failed to serialize");
@@ -257,6 +308,16 @@ mod tests {
         assert_eq!(req, deserialized);
     }
 
+    #[test]
+    fn test_serialize_download_model_request() {
+        let req = Request::DownloadModel {
+            model: "SDXL Refiner 1.0".to_string(),
+        };
+
+        let serialized = serialize(&req).expect("Failed to serialize");
+        assert_eq!(deserialize::<Request>(&serialized).unwrap(), req);
+    }
+
     #[test]
     fn test_serialize_model_list_response() {
         let resp = Response::ModelList {
@@ -282,6 +343,21 @@ mod tests {
         assert_eq!(resp, deserialized);
     }
 
+    #[test]
+    fn test_serialize_system_stats() {
+        let bytes = serialize(&Request::GetSystemStats).unwrap();
+        assert_eq!(deserialize::<Request>(&bytes).unwrap(), Request::GetSystemStats);
+
+        let resp = Response::SystemStats(SystemStats {
+            gpu_utilization: 87.5,
+            vram_used_mb: 40_960,
+            vram_total_mb: 131_072,
+            temperature_c: 64.0,
+        });
+        let bytes = serialize(&resp).unwrap();
+        assert_eq!(deserialize::<Response>(&bytes).unwrap(), resp);
+    }
+
     #[test]
     fn test_serialize_job_error_response() {
         let resp = Response::JobError {
@@ -387,9 +463,17 @@ mod tests {
             prompt: "A".repeat(500), // 500 char prompt
             model: "sdxl-base".to_string(),
             lora: Some("pixelart".to_string()),
+            loras: vec![LoraWeight {
+                name: "pixelart".to_string(),
+                strength: 0.8,
+            }],
             size: (1024, 1024),
             steps: 30,
             cfg_scale: 7.5,
+            negative_prompt: None,
+            seed: None,
+            workflow: None,
+            priority: None,
         };
 
         let serialized = serialize(&req).expect("Failed to serialize");
@@ -0,0 +1,132 @@
+//! # Generation Metadata
+//!
+//! Provenance for generated images, stored next to each image as
+//! `<image>.json` (e.g. `outputs/job-123.png` -> `outputs/job-123.json`).
+//! Both the classic app and the Bevy app write one when a job completes;
+//! the Bevy gallery shows it and edits it (see `ImageSidecar`).
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::messages::Request;
+
+/// Metadata recorded for a generated image.
+///
+/// All fields are optional so sidecars written by other tools (or older
+/// versions) still load.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GenerationMetadata {
+    /// Prompt the image was generated from
+    pub prompt: Option<String>,
+    /// Negative prompt, if one was used
+    pub negative_prompt: Option<String>,
+    /// Random seed
+    pub seed: Option<u64>,
+    /// Denoising steps
+    pub steps: Option<u32>,
+    /// CFG scale
+    pub cfg_scale: Option<f32>,
+    /// Base model checkpoint
+    pub model: Option<String>,
+    /// When the image was generated
+    pub timestamp: Option<DateTime<Utc>>,
+    /// Generation time in seconds
+    pub duration_s: Option<f32>,
+    /// User tags
+    pub tags: Vec<String>,
+    /// Seed sweep the image was generated in
+    pub sweep_id: Option<String>,
+}
+
+/// Sidecar path for an image.
+pub fn sidecar_path(image_path: &Path) -> PathBuf {
+    image_path.with_extension("json")
+}
+
+impl GenerationMetadata {
+    /// Metadata for the settings a generation request was sent with.
+    ///
+    /// `None` for requests other than `Request::Generate`.
+    pub fn from_request(request: &Request) -> Option<Self> {
+        match request {
+            Request::Generate {
+                prompt,
+                negative_prompt,
+                seed,
+                steps,
+                cfg_scale,
+                model,
+                ..
+            } => Some(Self {
+                prompt: Some(prompt.clone()),
+                negative_prompt: negative_prompt.clone(),
+                seed: *seed,
+                steps: Some(*steps),
+                cfg_scale: Some(*cfg_scale),
+                model: Some(model.clone()),
+                ..Default::default()
+            }),
+            _ => None,
+        }
+    }
+
+    /// Read the sidecar for the image at `image_path`.
+    ///
+    /// An image without a sidecar gives `Ok(None)`.
+    pub fn load(image_path: &Path) -> Result<Option<Self>> {
+        let path = sidecar_path(image_path);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read sidecar: {:?}", path))?;
+        let metadata = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse sidecar: {:?}", path))?;
+        Ok(Some(metadata))
+    }
+
+    /// Write this metadata as the sidecar for the image at `image_path`.
+    pub fn save(&self, image_path: &Path) -> Result<()> {
+        let path = sidecar_path(image_path);
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize sidecar")?;
+        fs::write(&path, json).with_context(|| format!("Failed to write sidecar: {:?}", path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = dir.path().join("job-1.png");
+        assert_eq!(GenerationMetadata::load(&image).unwrap(), None);
+
+        let request = crate::generation_request::GenerationRequestBuilder::new("pixel knight")
+            .negative_prompt("blurry")
+            .seed(42)
+            .build(&Default::default())
+            .unwrap();
+        let metadata = GenerationMetadata {
+            timestamp: Some(Utc::now()),
+            duration_s: Some(3.5),
+            ..GenerationMetadata::from_request(&request).unwrap()
+        };
+        assert_eq!(metadata.negative_prompt.as_deref(), Some("blurry"));
+        assert_eq!(metadata.seed, Some(42));
+        assert_eq!(metadata.steps, Some(30));
+
+        metadata.save(&image).unwrap();
+        assert!(dir.path().join("job-1.json").exists());
+        assert_eq!(GenerationMetadata::load(&image).unwrap(), Some(metadata));
+
+        fs::write(sidecar_path(&image), "not json").unwrap();
+        assert!(GenerationMetadata::load(&image).is_err());
+    }
+}
@@ -0,0 +1,66 @@
+//! # Job Progress
+//!
+//! Backend progress updates are jumpy and sometimes stop arriving. The ETA
+//! shown for a running job is an exponential moving average of the backend's
+//! estimates, and a running job that hasn't reported progress for
+//! [`STALL_TIMEOUT`] is flagged as possibly stalled. Used by both the classic
+//! app and the Bevy `Job` component.
+
+use std::time::{Duration, Instant};
+
+/// How long a running job can go without a progress update before it's
+/// flagged as stalled.
+pub const STALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Weight of each new ETA estimate in the moving average (0-1; higher
+/// follows the backend more closely).
+pub const ETA_SMOOTHING: f32 = 0.3;
+
+/// Blend a new backend ETA into the previously displayed one.
+///
+/// The first estimate (no previous ETA) is shown as-is.
+pub fn smooth_eta(previous: Option<f32>, raw_eta_s: f32) -> f32 {
+    match previous {
+        Some(previous) => previous + ETA_SMOOTHING * (raw_eta_s - previous),
+        None => raw_eta_s,
+    }
+}
+
+/// Whether a job last updated at `last_progress` has stalled by `now`.
+///
+/// A job that never reported progress hasn't started running, so it isn't
+/// stalled.
+pub fn is_stalled(last_progress: Option<Instant>, now: Instant) -> bool {
+    last_progress.is_some_and(|at| now.saturating_duration_since(at) > STALL_TIMEOUT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eta_smoothing_converges() {
+        assert_eq!(smooth_eta(None, 40.0), 40.0);
+
+        // A jump is damped rather than shown straight away...
+        let eta = smooth_eta(Some(40.0), 10.0);
+        assert!(eta > 30.0 && eta < 40.0);
+
+        // ...but a steady estimate is reached within a few updates
+        let mut eta = 40.0;
+        for _ in 0..20 {
+            eta = smooth_eta(Some(eta), 10.0);
+        }
+        assert!((eta - 10.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_stall_detection() {
+        let start = Instant::now();
+        assert!(!is_stalled(None, start + STALL_TIMEOUT * 2));
+        assert!(!is_stalled(Some(start), start + STALL_TIMEOUT));
+        assert!(is_stalled(Some(start), start + STALL_TIMEOUT + Duration::from_secs(1)));
+        // A clock behind the last update never counts as stalled
+        assert!(!is_stalled(Some(start + STALL_TIMEOUT * 2), start));
+    }
+}
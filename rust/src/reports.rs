@@ -1,10 +1,11 @@
 //! Comparison Report Export
 //!
-//! Export comparison results to CSV/JSON for analysis and training validation
+//! Export comparison results to CSV/JSON for analysis and training validation,
+//! or to a self-contained HTML page for sharing
 
 #![allow(dead_code)]
 
-use crate::comparison::ComparisonResult;
+use crate::comparison::{ComparisonResult, ComparisonStatistics};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs::File;
@@ -15,6 +16,9 @@ use std::path::Path;
 // Report Structures
 // ============================================================================
 
+/// Marker written by the exporters when a report has no comparisons
+pub const NO_DATA_NOTE: &str = "No data: no comparisons recorded";
+
 /// Full comparison report for export
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComparisonReport {
@@ -34,6 +38,9 @@ pub struct ReportMetadata {
     pub generated_at: String,
     pub version: String,
     pub total_comparisons: usize,
+    /// Set to [`NO_DATA_NOTE`] when the report is empty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
 }
 
 /// Exported comparison data
@@ -46,6 +53,9 @@ pub struct ComparisonExport {
     pub winner: Option<String>,
     pub notes: Option<String>,
     pub completed_at: String,
+    /// True when models were generated with different prompts
+    #[serde(default)]
+    pub prompts_differ: bool,
 }
 
 /// Exported model data
@@ -57,6 +67,9 @@ pub struct ModelExport {
     pub lora_strength: f32,
     pub generation_time_s: Option<f32>,
     pub image_path: Option<String>,
+    /// Prompt override used for this model (shared prompt if `None`)
+    #[serde(default)]
+    pub prompt: Option<String>,
 }
 
 /// Exported statistics
@@ -119,6 +132,7 @@ impl ReportBuilder {
                 generated_at: chrono::Utc::now().to_rfc3339(),
                 version: "1.0.0".to_string(),
                 total_comparisons: comparisons.len(),
+                note: comparisons.is_empty().then(|| NO_DATA_NOTE.to_string()),
             },
             comparisons,
             statistics: stats,
@@ -140,6 +154,7 @@ impl ReportBuilder {
                     .image_path
                     .as_ref()
                     .map(|p| p.to_string_lossy().to_string()),
+                prompt: r.prompt_override.clone(),
             })
             .collect();
 
@@ -153,6 +168,7 @@ impl ReportBuilder {
             winner,
             notes: comparison.notes.clone(),
             completed_at: comparison.completed_at.to_rfc3339(),
+            prompts_differ: comparison.prompts_differ(),
         }
     }
 
@@ -253,7 +269,7 @@ pub fn export_csv<P: AsRef<Path>>(report: &ComparisonReport, path: P) -> Result<
     // Write header
     writeln!(
         file,
-        "comparison_id,prompt,seed,model_name,base_model,lora,lora_strength,generation_time_s,winner,notes,completed_at"
+        "comparison_id,prompt,seed,model_name,base_model,lora,lora_strength,generation_time_s,winner,notes,completed_at,model_prompt"
     )?;
 
     // Write rows
@@ -263,7 +279,7 @@ pub fn export_csv<P: AsRef<Path>>(report: &ComparisonReport, path: P) -> Result<
 
             writeln!(
                 file,
-                "\"{}\",\"{}\",{},\"{}\",\"{}\",\"{}\",{},{},{},\"{}\",\"{}\"",
+                "\"{}\",\"{}\",{},\"{}\",\"{}\",\"{}\",{},{},{},\"{}\",\"{}\",\"{}\"",
                 comparison.comparison_id,
                 escape_csv(&comparison.prompt),
                 comparison.seed,
@@ -275,13 +291,119 @@ pub fn export_csv<P: AsRef<Path>>(report: &ComparisonReport, path: P) -> Result<
                 if is_winner { "1" } else { "0" },
                 comparison.notes.as_deref().unwrap_or(""),
                 comparison.completed_at,
+                escape_csv(model.prompt.as_deref().unwrap_or(&comparison.prompt)),
             )?;
         }
     }
 
+    // Single marker row (same column count) rather than an empty table
+    if report.comparisons.is_empty() {
+        writeln!(file, "\"{}\",,,,,,,,,,,", NO_DATA_NOTE)?;
+    }
+
     Ok(())
 }
 
+/// Largest thumbnail edge embedded in HTML reports, in pixels
+pub const HTML_THUMBNAIL_SIZE: u32 = 128;
+
+/// Export report to a self-contained HTML file
+///
+/// One table per comparison with each model's generation time and the
+/// winner highlighted. Images that can be read are embedded as small PNG
+/// thumbnails, so the page works without the original files; missing or
+/// unreadable images are left out.
+pub fn export_html<P: AsRef<Path>>(report: &ComparisonReport, path: P) -> Result<()> {
+    let mut html = String::from(concat!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n",
+        "<title>DGX-Pixels Comparison Report</title>\n<style>\n",
+        "body { font-family: sans-serif; margin: 2em; }\n",
+        "table { border-collapse: collapse; margin-bottom: 2em; }\n",
+        "th, td { border: 1px solid #ccc; padding: 0.4em 0.8em; text-align: left; }\n",
+        "tr.winner { background: #e3f6e3; font-weight: bold; }\n",
+        "</style>\n</head>\n<body>\n<h1>DGX-Pixels Comparison Report</h1>\n",
+    ));
+    html.push_str(&format!(
+        "<p>Generated {} &middot; {} comparison(s)</p>\n",
+        escape_html(&report.metadata.generated_at),
+        report.metadata.total_comparisons
+    ));
+
+    if report.comparisons.is_empty() {
+        html.push_str(&format!("<p>{}</p>\n", NO_DATA_NOTE));
+    }
+
+    for comparison in &report.comparisons {
+        html.push_str(&format!(
+            "<h2>{}</h2>\n<p>Seed: {} &middot; Completed: {}</p>\n",
+            escape_html(&comparison.prompt),
+            comparison.seed,
+            escape_html(&comparison.completed_at)
+        ));
+        html.push_str("<table>\n<tr><th>Model</th><th>LoRA</th><th>Time</th><th>Image</th></tr>\n");
+
+        for model in &comparison.models {
+            let is_winner = comparison.winner.as_ref() == Some(&model.name);
+            let mut name = escape_html(&model.name);
+            if is_winner {
+                name.push_str(" &#9733;");
+            }
+            if let Some(prompt) = &model.prompt {
+                name.push_str(&format!("<br><small>{}</small>", escape_html(prompt)));
+            }
+            let time = model
+                .generation_time_s
+                .map(|t| format!("{:.1}s", t))
+                .unwrap_or_else(|| "-".to_string());
+            let image = model
+                .image_path
+                .as_deref()
+                .and_then(|p| thumbnail_data_uri(Path::new(p)))
+                .map(|uri| format!("<img src=\"{}\" alt=\"\">", uri))
+                .unwrap_or_default();
+
+            html.push_str(&format!(
+                "<tr{}><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                if is_winner { " class=\"winner\"" } else { "" },
+                name,
+                escape_html(model.lora.as_deref().unwrap_or("-")),
+                time,
+                image
+            ));
+        }
+        html.push_str("</table>\n");
+
+        if let Some(notes) = &comparison.notes {
+            html.push_str(&format!("<p><em>{}</em></p>\n", escape_html(notes)));
+        }
+    }
+    html.push_str("</body>\n</html>\n");
+
+    std::fs::write(path.as_ref(), html).context("Failed to write HTML file")?;
+
+    Ok(())
+}
+
+/// PNG thumbnail of an image as a `data:` URI, if the image can be read
+fn thumbnail_data_uri(path: &Path) -> Option<String> {
+    use base64::Engine;
+
+    let thumbnail = image::open(path)
+        .map_err(|e| tracing::debug!("Skipping thumbnail for {:?}: {}", path, e))
+        .ok()?
+        .thumbnail(HTML_THUMBNAIL_SIZE, HTML_THUMBNAIL_SIZE);
+
+    let mut png = std::io::Cursor::new(Vec::new());
+    thumbnail
+        .write_to(&mut png, image::ImageOutputFormat::Png)
+        .ok()?;
+
+    Some(format!(
+        "data:image/png;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(png.into_inner())
+    ))
+}
+
 /// Export statistics to separate file
 pub fn export_statistics_csv<P: AsRef<Path>>(stats: &StatisticsExport, path: P) -> Result<()> {
     let mut file = File::create(path.as_ref()).context("Failed to create statistics CSV file")?;
@@ -300,14 +422,56 @@ pub fn export_statistics_csv<P: AsRef<Path>>(stats: &StatisticsExport, path: P)
         )?;
     }
 
+    if stats.model_wins.is_empty() {
+        writeln!(file, "\"{}\",,", NO_DATA_NOTE)?;
+    }
+
+    Ok(())
+}
+
+/// Write the plain-text statistics summary to a file
+pub fn export_statistics_summary<P: AsRef<Path>>(
+    stats: &ComparisonStatistics,
+    path: P,
+) -> Result<()> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create summary directory: {:?}", parent))?;
+    }
+
+    let mut file = File::create(path).context("Failed to create statistics summary file")?;
+    writeln!(file, "{}", stats.summary_text())?;
+
     Ok(())
 }
 
+/// Copy the statistics summary to the terminal clipboard (OSC 52)
+///
+/// See [`crate::clipboard`] for the terminals this works in.
+#[cfg(feature = "clipboard")]
+pub fn copy_statistics_summary(stats: &ComparisonStatistics) -> Result<()> {
+    crate::clipboard::copy_text(&stats.summary_text())
+}
+
 /// Helper to escape CSV values
 fn escape_csv(s: &str) -> String {
     s.replace('"', "\"\"")
 }
 
+/// Helper to escape text for HTML
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Helper to escape text inside a Markdown table cell
+fn escape_markdown_cell(s: &str) -> String {
+    s.replace('|', "\\|")
+}
+
 // ============================================================================
 // Training Validation Report
 // ============================================================================
@@ -376,6 +540,54 @@ pub struct TrainingValidationReport {
     pub conclusion: String,
 }
 
+/// Export a training validation report to a Markdown file
+///
+/// A summary table of wins (counts and share of all comparisons, one
+/// decimal place) followed by the conclusion as a note callout.
+pub fn export_validation_markdown<P: AsRef<Path>>(
+    report: &TrainingValidationReport,
+    path: P,
+) -> Result<()> {
+    let mut file = File::create(path.as_ref()).context("Failed to create Markdown file")?;
+
+    let share = |count: usize| {
+        if report.total_comparisons > 0 {
+            count as f32 / report.total_comparisons as f32 * 100.0
+        } else {
+            0.0
+        }
+    };
+
+    writeln!(file, "# Training Validation Report")?;
+    writeln!(file)?;
+    writeln!(file, "- Base model: {}", report.base_model)?;
+    writeln!(file, "- LoRA model: {}", report.lora_model)?;
+    writeln!(file, "- Comparisons: {}", report.total_comparisons)?;
+    writeln!(file)?;
+    writeln!(file, "| Result | Count | Share |")?;
+    writeln!(file, "| --- | ---: | ---: |")?;
+    for (label, count) in [
+        (format!("Base wins ({})", report.base_model), report.base_wins),
+        (format!("LoRA wins ({})", report.lora_model), report.lora_wins),
+        ("No preference".to_string(), report.no_preference),
+    ] {
+        writeln!(
+            file,
+            "| {} | {} | {:.1}% |",
+            escape_markdown_cell(&label),
+            count,
+            share(count)
+        )?;
+    }
+    writeln!(file)?;
+    writeln!(file, "**LoRA win rate: {:.1}%**", report.lora_win_rate)?;
+    writeln!(file)?;
+    writeln!(file, "> [!NOTE]")?;
+    writeln!(file, "> {}", report.conclusion)?;
+
+    Ok(())
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -410,6 +622,7 @@ mod tests {
                     duration_s: Some(3.5),
                     status: ModelResultStatus::Complete,
                     error: None,
+                    prompt_override: None,
                 },
                 ModelResult {
                     model: ModelConfig {
@@ -423,9 +636,12 @@ mod tests {
                     duration_s: Some(3.8),
                     status: ModelResultStatus::Complete,
                     error: None,
+                    prompt_override: None,
                 },
             ],
             user_preference: winner_idx,
+            voted_model: None,
+            voted_at: None,
             notes: Some("LoRA version has better pixel art style".to_string()),
             completed_at: chrono::Utc::now(),
         }
@@ -485,6 +701,70 @@ mod tests {
         assert!((validation.lora_win_rate - 66.67).abs() < 0.1);
     }
 
+    #[test]
+    fn test_export_validation_markdown() {
+        let comparisons = vec![
+            create_test_comparison(Some(1)),
+            create_test_comparison(Some(1)),
+            create_test_comparison(Some(0)),
+        ];
+        let validation =
+            generate_training_validation_report(&comparisons, "Base SDXL", "Pixel Art LoRA");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("validation.md");
+        export_validation_markdown(&validation, &path).expect("Export failed");
+
+        let markdown = std::fs::read_to_string(&path).unwrap();
+        assert!(markdown.contains("| Result | Count | Share |"));
+        assert!(markdown.contains("| LoRA wins (Pixel Art LoRA) | 2 | 66.7% |"));
+        assert!(markdown.contains("**LoRA win rate: 66.7%**"));
+        assert!(markdown.contains("> Training significantly improved quality"));
+    }
+
+    #[test]
+    fn test_export_html_embeds_readable_images() {
+        let dir = tempfile::tempdir().unwrap();
+        let image_path = dir.path().join("img1.png");
+        image::RgbImage::new(512, 512).save(&image_path).unwrap();
+
+        let mut comparison = create_test_comparison(Some(1));
+        comparison.results[0].image_path = Some(image_path);
+        comparison.results[1].image_path = Some(dir.path().join("missing.png"));
+        let mut builder = ReportBuilder::new();
+        builder.add_comparison(comparison);
+
+        let path = dir.path().join("report.html");
+        export_html(&builder.build(), &path).expect("Export failed");
+
+        let html = std::fs::read_to_string(&path).unwrap();
+        assert!(html.contains("16-bit knight sprite"));
+        assert!(html.contains("<table>"));
+        assert!(html.contains("<tr class=\"winner\"><td>Pixel Art LoRA"));
+        // Only the readable image is embedded
+        assert_eq!(html.matches("data:image/png;base64,").count(), 1);
+    }
+
+    #[test]
+    fn test_report_notes_differing_prompts() {
+        let mut comparison = create_test_comparison(Some(1));
+        let mut builder = ReportBuilder::new();
+        builder.add_comparison(comparison.clone());
+
+        comparison.results[1].prompt_override =
+            Some("16-bit knight sprite, pixelsprite".to_string());
+        builder.add_comparison(comparison);
+
+        let report = builder.build();
+        assert!(!report.comparisons[0].prompts_differ);
+        assert!(report.comparisons[1].prompts_differ);
+        assert_eq!(report.comparisons[1].models[0].prompt, None);
+        assert_eq!(
+            report.comparisons[1].models[1].prompt.as_deref(),
+            Some("16-bit knight sprite, pixelsprite")
+        );
+    }
+
     #[test]
     fn test_export_json() {
         let mut builder = ReportBuilder::new();
@@ -512,4 +792,51 @@ mod tests {
         // Verify file exists
         assert!(std::path::Path::new(temp_path).exists());
     }
+
+    #[test]
+    fn test_export_empty_report_marks_no_data() {
+        let report = ReportBuilder::new().build();
+        let dir = tempfile::tempdir().unwrap();
+
+        let json_path = dir.path().join("report.json");
+        export_json(&report, &json_path).expect("Export failed");
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+        assert_eq!(json["metadata"]["note"], NO_DATA_NOTE);
+        assert_eq!(json["comparisons"].as_array().unwrap().len(), 0);
+
+        let csv_path = dir.path().join("report.csv");
+        export_csv(&report, &csv_path).expect("Export failed");
+        let stats_path = dir.path().join("stats.csv");
+        export_statistics_csv(&report.statistics, &stats_path).expect("Export failed");
+
+        for path in [csv_path, stats_path] {
+            let csv = std::fs::read_to_string(&path).unwrap();
+            let lines: Vec<&str> = csv.lines().collect();
+            assert_eq!(lines.len(), 2, "{:?}", path);
+            assert!(lines[1].contains(NO_DATA_NOTE));
+            // Every row has the header's column count
+            assert_eq!(lines[0].matches(',').count(), lines[1].matches(',').count());
+        }
+    }
+
+    #[test]
+    fn test_export_statistics_summary() {
+        let mut model_wins = std::collections::HashMap::new();
+        model_wins.insert("Pixel Art LoRA".to_string(), 3);
+        let stats = ComparisonStatistics {
+            total_comparisons: 4,
+            comparisons_with_preference: 3,
+            model_wins,
+            elo_ratings: std::collections::HashMap::new(),
+        };
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("summary.txt");
+        export_statistics_summary(&stats, &path).expect("Export failed");
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("Total comparisons: 4"));
+        assert!(content.contains("Pixel Art LoRA: 3"));
+    }
 }
@@ -0,0 +1,386 @@
+//! # Screen Capture
+//!
+//! Renders a ratatui [`Buffer`] to a PNG for bug reports (F12 in the TUI).
+//! Each cell becomes a [`CELL_WIDTH`]x[`CELL_HEIGHT`] pixel block in its
+//! background color with the glyph drawn from a built-in 5x7 font. Box
+//! drawing and block characters are drawn as lines and fills.
+//!
+//! Sixel images bypass the buffer, so their cells (marked `skip`) are
+//! drawn as a hatched placeholder labelled "image area".
+
+use anyhow::{Context, Result};
+use image::{Rgb, RgbImage};
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::ui::theme::color_to_rgb;
+
+/// Width of one terminal cell in pixels.
+pub const CELL_WIDTH: u32 = 8;
+
+/// Height of one terminal cell in pixels.
+pub const CELL_HEIGHT: u32 = 16;
+
+/// Colors used where cells have `Color::Reset`.
+const DEFAULT_FG: [u8; 3] = [229, 229, 229];
+const DEFAULT_BG: [u8; 3] = [0, 0, 0];
+
+/// Placeholder colors for Sixel image areas.
+const IMAGE_AREA_BG: [u8; 3] = [40, 40, 48];
+const IMAGE_AREA_HATCH: [u8; 3] = [70, 70, 84];
+
+/// Printable ASCII (0x20..=0x7E), 5 columns per glyph, bit 0 = top row.
+const FONT_5X7: [[u8; 5]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+    [0x00, 0x00, 0x5F, 0x00, 0x00], // !
+    [0x00, 0x07, 0x00, 0x07, 0x00], // "
+    [0x14, 0x7F, 0x14, 0x7F, 0x14], // #
+    [0x24, 0x2A, 0x7F, 0x2A, 0x12], // $
+    [0x23, 0x13, 0x08, 0x64, 0x62], // %
+    [0x36, 0x49, 0x55, 0x22, 0x50], // &
+    [0x00, 0x05, 0x03, 0x00, 0x00], // '
+    [0x00, 0x1C, 0x22, 0x41, 0x00], // (
+    [0x00, 0x41, 0x22, 0x1C, 0x00], // )
+    [0x08, 0x2A, 0x1C, 0x2A, 0x08], // *
+    [0x08, 0x08, 0x3E, 0x08, 0x08], // +
+    [0x00, 0x50, 0x30, 0x00, 0x00], // ,
+    [0x08, 0x08, 0x08, 0x08, 0x08], // -
+    [0x00, 0x60, 0x60, 0x00, 0x00], // .
+    [0x20, 0x10, 0x08, 0x04, 0x02], // /
+    [0x3E, 0x51, 0x49, 0x45, 0x3E], // 0
+    [0x00, 0x42, 0x7F, 0x40, 0x00], // 1
+    [0x42, 0x61, 0x51, 0x49, 0x46], // 2
+    [0x21, 0x41, 0x45, 0x4B, 0x31], // 3
+    [0x18, 0x14, 0x12, 0x7F, 0x10], // 4
+    [0x27, 0x45, 0x45, 0x45, 0x39], // 5
+    [0x3C, 0x4A, 0x49, 0x49, 0x30], // 6
+    [0x01, 0x71, 0x09, 0x05, 0x03], // 7
+    [0x36, 0x49, 0x49, 0x49, 0x36], // 8
+    [0x06, 0x49, 0x49, 0x29, 0x1E], // 9
+    [0x00, 0x36, 0x36, 0x00, 0x00], // :
+    [0x00, 0x56, 0x36, 0x00, 0x00], // ;
+    [0x08, 0x14, 0x22, 0x41, 0x00], // <
+    [0x14, 0x14, 0x14, 0x14, 0x14], // =
+    [0x00, 0x41, 0x22, 0x14, 0x08], // >
+    [0x02, 0x01, 0x51, 0x09, 0x06], // ?
+    [0x32, 0x49, 0x79, 0x41, 0x3E], // @
+    [0x7E, 0x11, 0x11, 0x11, 0x7E], // A
+    [0x7F, 0x49, 0x49, 0x49, 0x36], // B
+    [0x3E, 0x41, 0x41, 0x41, 0x22], // C
+    [0x7F, 0x41, 0x41, 0x22, 0x1C], // D
+    [0x7F, 0x49, 0x49, 0x49, 0x41], // E
+    [0x7F, 0x09, 0x09, 0x09, 0x01], // F
+    [0x3E, 0x41, 0x49, 0x49, 0x7A], // G
+    [0x7F, 0x08, 0x08, 0x08, 0x7F], // H
+    [0x00, 0x41, 0x7F, 0x41, 0x00], // I
+    [0x20, 0x40, 0x41, 0x3F, 0x01], // J
+    [0x7F, 0x08, 0x14, 0x22, 0x41], // K
+    [0x7F, 0x40, 0x40, 0x40, 0x40], // L
+    [0x7F, 0x02, 0x0C, 0x02, 0x7F], // M
+    [0x7F, 0x04, 0x08, 0x10, 0x7F], // N
+    [0x3E, 0x41, 0x41, 0x41, 0x3E], // O
+    [0x7F, 0x09, 0x09, 0x09, 0x06], // P
+    [0x3E, 0x41, 0x51, 0x21, 0x5E], // Q
+    [0x7F, 0x09, 0x19, 0x29, 0x46], // R
+    [0x46, 0x49, 0x49, 0x49, 0x31], // S
+    [0x01, 0x01, 0x7F, 0x01, 0x01], // T
+    [0x3F, 0x40, 0x40, 0x40, 0x3F], // U
+    [0x1F, 0x20, 0x40, 0x20, 0x1F], // V
+    [0x3F, 0x40, 0x38, 0x40, 0x3F], // W
+    [0x63, 0x14, 0x08, 0x14, 0x63], // X
+    [0x07, 0x08, 0x70, 0x08, 0x07], // Y
+    [0x61, 0x51, 0x49, 0x45, 0x43], // Z
+    [0x00, 0x7F, 0x41, 0x41, 0x00], // [
+    [0x02, 0x04, 0x08, 0x10, 0x20], // \
+    [0x00, 0x41, 0x41, 0x7F, 0x00], // ]
+    [0x04, 0x02, 0x01, 0x02, 0x04], // ^
+    [0x40, 0x40, 0x40, 0x40, 0x40], // _
+    [0x00, 0x01, 0x02, 0x04, 0x00], // `
+    [0x20, 0x54, 0x54, 0x54, 0x78], // a
+    [0x7F, 0x48, 0x44, 0x44, 0x38], // b
+    [0x38, 0x44, 0x44, 0x44, 0x20], // c
+    [0x38, 0x44, 0x44, 0x48, 0x7F], // d
+    [0x38, 0x54, 0x54, 0x54, 0x18], // e
+    [0x08, 0x7E, 0x09, 0x01, 0x02], // f
+    [0x0C, 0x52, 0x52, 0x52, 0x3E], // g
+    [0x7F, 0x08, 0x04, 0x04, 0x78], // h
+    [0x00, 0x44, 0x7D, 0x40, 0x00], // i
+    [0x20, 0x40, 0x44, 0x3D, 0x00], // j
+    [0x7F, 0x10, 0x28, 0x44, 0x00], // k
+    [0x00, 0x41, 0x7F, 0x40, 0x00], // l
+    [0x7C, 0x04, 0x18, 0x04, 0x78], // m
+    [0x7C, 0x08, 0x04, 0x04, 0x78], // n
+    [0x38, 0x44, 0x44, 0x44, 0x38], // o
+    [0x7C, 0x14, 0x14, 0x14, 0x08], // p
+    [0x08, 0x14, 0x14, 0x18, 0x7C], // q
+    [0x7C, 0x08, 0x04, 0x04, 0x08], // r
+    [0x48, 0x54, 0x54, 0x54, 0x20], // s
+    [0x04, 0x3F, 0x44, 0x40, 0x20], // t
+    [0x3C, 0x40, 0x40, 0x20, 0x7C], // u
+    [0x1C, 0x20, 0x40, 0x20, 0x1C], // v
+    [0x3C, 0x40, 0x30, 0x40, 0x3C], // w
+    [0x44, 0x28, 0x10, 0x28, 0x44], // x
+    [0x0C, 0x50, 0x50, 0x50, 0x3C], // y
+    [0x44, 0x64, 0x54, 0x4C, 0x44], // z
+    [0x00, 0x08, 0x36, 0x41, 0x00], // {
+    [0x00, 0x00, 0x7F, 0x00, 0x00], // |
+    [0x00, 0x41, 0x36, 0x08, 0x00], // }
+    [0x08, 0x04, 0x08, 0x10, 0x08], // ~
+];
+
+/// Render `buffer` to a PNG at `out`.
+pub fn buffer_to_png(buffer: &Buffer, out: &Path) -> Result<()> {
+    let image = buffer_to_image(buffer);
+    image
+        .save_with_format(out, image::ImageFormat::Png)
+        .with_context(|| format!("Failed to write screenshot: {:?}", out))
+}
+
+/// Save `buffer` as `dgx-pixels-screenshot-<timestamp>.png` in `dir`.
+///
+/// Returns the path written.
+pub fn save_screenshot(buffer: &Buffer, dir: &Path) -> Result<PathBuf> {
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let out = dir.join(format!("dgx-pixels-screenshot-{}.png", timestamp));
+    buffer_to_png(buffer, &out)?;
+    info!("Screenshot saved to {:?}", out);
+    Ok(out)
+}
+
+/// Render `buffer` to an RGB image, one cell per `CELL_WIDTH`x`CELL_HEIGHT` block.
+pub fn buffer_to_image(buffer: &Buffer) -> RgbImage {
+    let area = buffer.area;
+    let mut image = RgbImage::new(
+        area.width as u32 * CELL_WIDTH,
+        area.height as u32 * CELL_HEIGHT,
+    );
+
+    for y in 0..area.height {
+        for x in 0..area.width {
+            let cell = &buffer[(area.x + x, area.y + y)];
+            let origin = (x as u32 * CELL_WIDTH, y as u32 * CELL_HEIGHT);
+
+            if cell.skip {
+                draw_image_area_cell(&mut image, origin);
+                continue;
+            }
+
+            let mut fg = resolve(cell.fg, DEFAULT_FG);
+            let mut bg = resolve(cell.bg, DEFAULT_BG);
+            if cell.modifier.contains(Modifier::REVERSED) {
+                std::mem::swap(&mut fg, &mut bg);
+            }
+
+            fill(&mut image, origin, (0, 0), (CELL_WIDTH, CELL_HEIGHT), bg);
+            let ch = cell.symbol().chars().next().unwrap_or(' ');
+            draw_glyph(
+                &mut image,
+                origin,
+                ch,
+                fg,
+                cell.modifier.contains(Modifier::BOLD),
+            );
+        }
+    }
+
+    label_image_areas(&mut image, buffer);
+    image
+}
+
+fn resolve(color: Color, default: [u8; 3]) -> [u8; 3] {
+    match color {
+        Color::Reset => default,
+        color => color_to_rgb(color),
+    }
+}
+
+/// Fill a rectangle at `offset` (size `size`) within the cell at `origin`.
+fn fill(
+    image: &mut RgbImage,
+    origin: (u32, u32),
+    offset: (u32, u32),
+    size: (u32, u32),
+    rgb: [u8; 3],
+) {
+    for dy in 0..size.1 {
+        for dx in 0..size.0 {
+            let (px, py) = (origin.0 + offset.0 + dx, origin.1 + offset.1 + dy);
+            if px < image.width() && py < image.height() {
+                image.put_pixel(px, py, Rgb(rgb));
+            }
+        }
+    }
+}
+
+fn draw_glyph(image: &mut RgbImage, origin: (u32, u32), ch: char, rgb: [u8; 3], bold: bool) {
+    if ch == ' ' {
+        return;
+    }
+
+    if let Some(glyph) = ch
+        .is_ascii_graphic()
+        .then(|| FONT_5X7[(ch as u8 - 0x20) as usize])
+    {
+        // 5x7 glyph doubled vertically, inset by one pixel
+        for (col, bits) in glyph.iter().enumerate() {
+            for row in 0..7 {
+                if bits & (1 << row) != 0 {
+                    let width = if bold { 2 } else { 1 };
+                    fill(
+                        image,
+                        origin,
+                        (1 + col as u32, 1 + row * 2),
+                        (width, 2),
+                        rgb,
+                    );
+                }
+            }
+        }
+        return;
+    }
+
+    // Box drawing: (left, right, up, down) segments from the cell center
+    let segments = match ch {
+        '─' | '━' | '═' => Some((true, true, false, false)),
+        '│' | '┃' | '║' => Some((false, false, true, true)),
+        '┌' | '╭' | '┏' | '╔' => Some((false, true, false, true)),
+        '┐' | '╮' | '┓' | '╗' => Some((true, false, false, true)),
+        '└' | '╰' | '┗' | '╚' => Some((false, true, true, false)),
+        '┘' | '╯' | '┛' | '╝' => Some((true, false, true, false)),
+        '├' | '┣' | '╠' => Some((false, true, true, true)),
+        '┤' | '┫' | '╣' => Some((true, false, true, true)),
+        '┬' | '┳' | '╦' => Some((true, true, false, true)),
+        '┴' | '┻' | '╩' => Some((true, true, true, false)),
+        '┼' | '╋' | '╬' => Some((true, true, true, true)),
+        _ => None,
+    };
+    let (cx, cy) = (CELL_WIDTH / 2, CELL_HEIGHT / 2);
+    if let Some((left, right, up, down)) = segments {
+        if left {
+            fill(image, origin, (0, cy), (cx + 1, 1), rgb);
+        }
+        if right {
+            fill(image, origin, (cx, cy), (CELL_WIDTH - cx, 1), rgb);
+        }
+        if up {
+            fill(image, origin, (cx, 0), (1, cy + 1), rgb);
+        }
+        if down {
+            fill(image, origin, (cx, cy), (1, CELL_HEIGHT - cy), rgb);
+        }
+        return;
+    }
+
+    match ch {
+        '█' => fill(image, origin, (0, 0), (CELL_WIDTH, CELL_HEIGHT), rgb),
+        '▀' => fill(image, origin, (0, 0), (CELL_WIDTH, cy), rgb),
+        '▄' => fill(image, origin, (0, cy), (CELL_WIDTH, CELL_HEIGHT - cy), rgb),
+        '░' | '▒' | '▓' => {
+            for dy in 0..CELL_HEIGHT {
+                for dx in (dy % 2..CELL_WIDTH).step_by(2) {
+                    fill(image, origin, (dx, dy), (1, 1), rgb);
+                }
+            }
+        }
+        // Anything else (arrows, emoji, ...) as a small box
+        _ => {
+            fill(image, origin, (1, 3), (CELL_WIDTH - 2, 1), rgb);
+            fill(
+                image,
+                origin,
+                (1, CELL_HEIGHT - 4),
+                (CELL_WIDTH - 2, 1),
+                rgb,
+            );
+            fill(image, origin, (1, 3), (1, CELL_HEIGHT - 6), rgb);
+            fill(
+                image,
+                origin,
+                (CELL_WIDTH - 2, 3),
+                (1, CELL_HEIGHT - 6),
+                rgb,
+            );
+        }
+    }
+}
+
+fn draw_image_area_cell(image: &mut RgbImage, origin: (u32, u32)) {
+    fill(
+        image,
+        origin,
+        (0, 0),
+        (CELL_WIDTH, CELL_HEIGHT),
+        IMAGE_AREA_BG,
+    );
+    // Diagonal hatching that continues across cells
+    for dy in 0..CELL_HEIGHT {
+        for dx in 0..CELL_WIDTH {
+            if (origin.0 + dx + origin.1 + dy) % 8 == 0 {
+                fill(image, origin, (dx, dy), (1, 1), IMAGE_AREA_HATCH);
+            }
+        }
+    }
+}
+
+/// Write "image area" at the start of each row's first run of skipped cells.
+fn label_image_areas(image: &mut RgbImage, buffer: &Buffer) {
+    const LABEL: &str = "image area";
+    let area = buffer.area;
+
+    for y in 0..area.height {
+        let is_skip = |x: u16| buffer[(area.x + x, area.y + y)].skip;
+        let above_skip = |x: u16| y > 0 && buffer[(area.x + x, area.y + y - 1)].skip;
+
+        let mut x = 0;
+        while x < area.width {
+            // Label only the top-left corner of each region
+            if is_skip(x) && !above_skip(x) && (x == 0 || !is_skip(x - 1)) {
+                let run = (x..area.width).take_while(|&cx| is_skip(cx)).count();
+                if run >= LABEL.len() {
+                    for (i, ch) in LABEL.chars().enumerate() {
+                        let origin = ((x as u32 + i as u32) * CELL_WIDTH, y as u32 * CELL_HEIGHT);
+                        draw_glyph(image, origin, ch, DEFAULT_FG, false);
+                    }
+                }
+                x += run as u16;
+            } else {
+                x += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    #[test]
+    fn test_buffer_to_png_writes_cell_grid() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+        buffer.set_string(0, 0, "Hi │", Style::default().fg(Color::Cyan));
+        for x in 0..12 {
+            buffer[(x, 2)].set_skip(true);
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("screen.png");
+        buffer_to_png(&buffer, &out).unwrap();
+
+        let png = image::open(&out).unwrap().to_rgb8();
+        assert_eq!(png.dimensions(), (12 * CELL_WIDTH, 3 * CELL_HEIGHT));
+
+        // 'H' has a full-height left stroke in the text color
+        assert_eq!(png.get_pixel(1, 5).0, color_to_rgb(Color::Cyan));
+        // Empty cells keep the default background
+        assert_eq!(png.get_pixel(CELL_WIDTH * 10, 0).0, DEFAULT_BG);
+        // Skipped (Sixel) cells are drawn as the image placeholder
+        assert_ne!(
+            png.get_pixel(CELL_WIDTH * 11 + 1, CELL_HEIGHT * 2 + 1).0,
+            DEFAULT_BG
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! # Graceful Shutdown
+//!
+//! Persists session state on normal exit. Shared by the classic event loop
+//! and the Bevy app, which each gather a [`ShutdownState`] and hand it to
+//! [`shutdown`]. Steps run in a fixed order:
+//!
+//! 1. Job history (merged into `job_history.json`)
+//! 2. Comparison results (merged into `comparisons.json`)
+//! 3. Settings (only if they changed this session)
+//!
+//! A failing step is logged and the remaining steps still run.
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+use crate::app::{ActiveJob, JobStatus};
+use crate::comparison::ComparisonResult;
+
+/// A job as recorded in the persisted history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobHistoryEntry {
+    pub job_id: String,
+    pub prompt: String,
+    /// Final status: "queued", "running", "complete", "failed", ...
+    pub status: String,
+    #[serde(default)]
+    pub image_path: Option<PathBuf>,
+    #[serde(default)]
+    pub duration_s: Option<f32>,
+}
+
+impl From<&ActiveJob> for JobHistoryEntry {
+    fn from(job: &ActiveJob) -> Self {
+        let (status, image_path, duration_s) = match &job.status {
+            JobStatus::Queued => ("queued", None, None),
+            JobStatus::Running { .. } => ("running", None, None),
+            JobStatus::Complete {
+                image_path,
+                duration_s,
+            } => ("complete", Some(image_path.clone()), Some(*duration_s)),
+            JobStatus::Failed { .. } => ("failed", None, None),
+        };
+
+        Self {
+            job_id: job.job_id.clone(),
+            prompt: job.prompt.clone(),
+            status: status.to_string(),
+            image_path,
+            duration_s,
+        }
+    }
+}
+
+/// State to persist on exit.
+#[derive(Debug, Clone, Default)]
+pub struct ShutdownState {
+    /// Jobs from this session
+    pub job_history: Vec<JobHistoryEntry>,
+    /// Comparisons completed this session
+    pub comparisons: Vec<ComparisonResult>,
+    /// Serialized settings, set only when they changed this session
+    pub dirty_settings: Option<String>,
+}
+
+/// Where [`shutdown`] writes each piece of state.
+#[derive(Debug, Clone)]
+pub struct ShutdownPaths {
+    pub job_history: PathBuf,
+    pub comparisons: PathBuf,
+    pub settings: PathBuf,
+}
+
+impl ShutdownPaths {
+    /// All files inside `dir`.
+    pub fn in_dir(dir: &Path) -> Self {
+        Self {
+            job_history: dir.join("job_history.json"),
+            comparisons: dir.join("comparisons.json"),
+            settings: dir.join("config.toml"),
+        }
+    }
+}
+
+impl Default for ShutdownPaths {
+    fn default() -> Self {
+        Self::in_dir(&data_dir())
+    }
+}
+
+/// Per-user data directory (`~/.local/share/dgx-pixels`).
+pub fn data_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".local/share/dgx-pixels")
+}
+
+/// Persist `state` to `paths`, returning the files written.
+pub fn shutdown(state: &ShutdownState, paths: &ShutdownPaths) -> Vec<PathBuf> {
+    let mut written = Vec::new();
+    let mut step = |name: &str, path: &Path, result: Result<bool>| match result {
+        Ok(true) => written.push(path.to_path_buf()),
+        Ok(false) => {}
+        Err(e) => warn!("Shutdown: failed to save {}: {:#}", name, e),
+    };
+
+    step(
+        "job history",
+        &paths.job_history,
+        merge_json(&paths.job_history, &state.job_history, |a, b| a.job_id == b.job_id),
+    );
+    step(
+        "comparison results",
+        &paths.comparisons,
+        merge_json(&paths.comparisons, &state.comparisons, |a, b| {
+            a.comparison_id == b.comparison_id
+        }),
+    );
+    step(
+        "settings",
+        &paths.settings,
+        match &state.dirty_settings {
+            Some(content) => write_file(&paths.settings, content).map(|_| true),
+            None => Ok(false),
+        },
+    );
+
+    info!("Shutdown: saved {} file(s)", written.len());
+    written
+}
+
+/// Append `items` to the JSON list at `path`, replacing entries that `same` matches.
+///
+/// Nothing is written when `items` is empty, so an idle session never
+/// clobbers earlier history.
+fn merge_json<T>(path: &Path, items: &[T], same: impl Fn(&T, &T) -> bool) -> Result<bool>
+where
+    T: Serialize + DeserializeOwned + Clone,
+{
+    if items.is_empty() {
+        return Ok(false);
+    }
+
+    let mut merged: Vec<T> = if path.exists() {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {:?}", path))?;
+        serde_json::from_str(&content).with_context(|| format!("Failed to parse {:?}", path))?
+    } else {
+        Vec::new()
+    };
+
+    merged.retain(|old| !items.iter().any(|new| same(old, new)));
+    merged.extend(items.iter().cloned());
+
+    let json = serde_json::to_string_pretty(&merged).context("Failed to serialize")?;
+    write_file(path, &json)?;
+    Ok(true)
+}
+
+/// Write `content` to `path`, creating parent directories.
+fn write_file(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {:?}", parent))?;
+    }
+    fs::write(path, content).with_context(|| format!("Failed to write {:?}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comparison::{ComparisonManager, GenerationParams, ModelConfig};
+
+    fn completed_comparison() -> ComparisonResult {
+        let mut manager = ComparisonManager::new();
+        let id = manager.create_comparison(
+            GenerationParams::default(),
+            vec![ModelConfig::default()],
+        );
+        manager.register_jobs(&id, vec!["job-1".to_string()]);
+        manager.complete_job("job-1", PathBuf::from("out.png"), 2.0);
+        manager.get_all_completed()[0].clone()
+    }
+
+    #[test]
+    fn test_shutdown_writes_populated_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = ShutdownPaths::in_dir(dir.path());
+
+        // Earlier session's history is kept, this session's job is appended
+        fs::write(
+            &paths.job_history,
+            r#"[{"job_id": "old", "prompt": "tree", "status": "complete"}]"#,
+        )
+        .unwrap();
+
+        let state = ShutdownState {
+            job_history: vec![JobHistoryEntry::from(&ActiveJob {
+                job_id: "job-1".to_string(),
+                prompt: "knight".to_string(),
+                status: JobStatus::Complete {
+                    image_path: PathBuf::from("out.png"),
+                    duration_s: 2.0,
+                },
+                preview_path: None,
+                last_progress_at: None,
+                stalled: false,
+                metadata: None,
+            })],
+            comparisons: vec![completed_comparison()],
+            dirty_settings: Some("[ui]\ntheme = \"dark\"\n".to_string()),
+        };
+
+        let written = shutdown(&state, &paths);
+        assert_eq!(
+            written,
+            vec![
+                paths.job_history.clone(),
+                paths.comparisons.clone(),
+                paths.settings.clone()
+            ]
+        );
+
+        let history: Vec<JobHistoryEntry> =
+            serde_json::from_str(&fs::read_to_string(&paths.job_history).unwrap()).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].job_id, "job-1");
+        assert_eq!(history[1].duration_s, Some(2.0));
+
+        let comparisons: Vec<ComparisonResult> =
+            serde_json::from_str(&fs::read_to_string(&paths.comparisons).unwrap()).unwrap();
+        assert_eq!(comparisons.len(), 1);
+
+        assert!(fs::read_to_string(&paths.settings).unwrap().contains("dark"));
+    }
+
+    #[test]
+    fn test_shutdown_skips_empty_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = ShutdownPaths::in_dir(dir.path());
+
+        assert!(shutdown(&ShutdownState::default(), &paths).is_empty());
+        assert!(!paths.job_history.exists());
+        assert!(!paths.settings.exists());
+    }
+}
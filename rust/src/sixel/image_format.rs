@@ -0,0 +1,97 @@
+//! Image formats for previews and the gallery
+//!
+//! Gallery scans pick files by extension, but decoding goes by the file's
+//! contents, so a misnamed image still loads and an unsupported or corrupt
+//! one comes back as an error instead of a panic.
+
+use anyhow::{anyhow, Context, Result};
+use image::{io::Reader as ImageReader, DynamicImage};
+use std::path::Path;
+
+/// Extensions (lowercase) the gallery picks up.
+///
+/// AVIF is listed only with the `avif` feature, which enables the AV1
+/// decoder. Builds without it can't decode AVIF, so those files are left
+/// out of the gallery rather than shown with a failed preview.
+#[cfg(feature = "avif")]
+pub const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "avif"];
+/// Extensions (lowercase) the gallery picks up.
+///
+/// AVIF isn't listed: this build has no AV1 decoder (see the `avif`
+/// feature).
+#[cfg(not(feature = "avif"))]
+pub const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp"];
+
+/// Whether `path` has one of the [`IMAGE_EXTENSIONS`].
+pub fn is_image_path(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| {
+        let ext = ext.to_string_lossy().to_lowercase();
+        IMAGE_EXTENSIONS.contains(&ext.as_str())
+    })
+}
+
+/// Decode the image at `path`, detecting its format from the contents.
+pub fn load_image(path: &Path) -> Result<DynamicImage> {
+    let reader = ImageReader::open(path)
+        .with_context(|| format!("Failed to open image: {:?}", path))?
+        .with_guessed_format()
+        .with_context(|| format!("Failed to read image: {:?}", path))?;
+    let format = reader
+        .format()
+        .ok_or_else(|| anyhow!("Unrecognized image format: {:?}", path))?;
+
+    reader
+        .decode()
+        .with_context(|| format!("Failed to decode {:?} image: {:?}", format, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::GenericImageView;
+
+    /// A 1x1 lossless WebP.
+    const WEBP_1X1: &[u8] = &[
+        0x52, 0x49, 0x46, 0x46, 0x1a, 0x00, 0x00, 0x00, 0x57, 0x45, 0x42, 0x50, 0x56, 0x50, 0x38,
+        0x4c, 0x0d, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x10, 0x07, 0x10, 0x11, 0x11, 0x88,
+        0x88, 0xfe, 0x07, 0x00,
+    ];
+
+    #[test]
+    fn test_image_extensions() {
+        assert!(is_image_path(Path::new("outputs/job-1.webp")));
+        assert_eq!(is_image_path(Path::new("outputs/job-2.AVIF")), cfg!(feature = "avif"));
+        assert!(is_image_path(Path::new("outputs/job-3.png")));
+        assert!(!is_image_path(Path::new("outputs/job-3.json")));
+        assert!(!is_image_path(Path::new("outputs/README")));
+    }
+
+    #[test]
+    fn test_load_image_detects_format_from_contents() {
+        let dir = tempfile::tempdir().unwrap();
+        let webp = dir.path().join("sprite.webp");
+        std::fs::write(&webp, WEBP_1X1).unwrap();
+        assert_eq!(load_image(&webp).unwrap().dimensions(), (1, 1));
+
+        // Misnamed files decode by their contents
+        let misnamed = dir.path().join("sprite.png");
+        std::fs::write(&misnamed, WEBP_1X1).unwrap();
+        assert_eq!(load_image(&misnamed).unwrap().dimensions(), (1, 1));
+
+        let corrupt = dir.path().join("corrupt.webp");
+        std::fs::write(&corrupt, &WEBP_1X1[..20]).unwrap();
+        let error = load_image(&corrupt).unwrap_err().to_string();
+        assert!(error.contains("Failed to decode WebP image"), "{}", error);
+
+        // Recognized, but this build has no AVIF decoder
+        let avif = dir.path().join("sprite.avif");
+        std::fs::write(&avif, b"\0\0\0\x1cftypavif\0\0\0\0").unwrap();
+        let error = format!("{:#}", load_image(&avif).unwrap_err());
+        assert!(error.contains("Failed to decode Avif image"), "{}", error);
+
+        let unknown = dir.path().join("notes");
+        std::fs::write(&unknown, b"not an image").unwrap();
+        let error = load_image(&unknown).unwrap_err().to_string();
+        assert!(error.contains("Unrecognized image format"), "{}", error);
+    }
+}
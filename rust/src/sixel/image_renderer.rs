@@ -8,6 +8,8 @@ use tempfile::NamedTempFile;
 use tracing::{debug, warn};
 use viuer::Config;
 
+use super::image_format::load_image;
+
 /// Options for image rendering
 #[derive(Debug, Clone)]
 pub struct RenderOptions {
@@ -68,8 +70,7 @@ impl ImageRenderer {
         debug!("Rendering image: {:?}", image_path);
 
         // Load image
-        let img = image::open(image_path)
-            .with_context(|| format!("Failed to load image: {:?}", image_path))?;
+        let img = load_image(image_path)?;
 
         // Resize if needed
         let img = self.resize_image(img, options)?;
@@ -86,7 +87,7 @@ impl ImageRenderer {
     pub fn render_to_stdout(&self, image_path: &Path, options: &RenderOptions) -> Result<()> {
         debug!("Rendering to stdout: {:?}", image_path);
 
-        let img = image::open(image_path)?;
+        let img = load_image(image_path)?;
         let img = self.resize_image(img, options)?;
 
         let config = viuer::Config {
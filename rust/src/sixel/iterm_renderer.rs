@@ -0,0 +1,77 @@
+//! iTerm2 inline image rendering
+//!
+//! iTerm2 (and WezTerm) display images sent as
+//! `ESC ] 1337 ; File=<args> : <base64 file contents> BEL`. The file is sent
+//! as-is (PNG, JPEG, ...) and the terminal scales it to the requested number
+//! of cells, so no re-encoding is needed.
+
+use anyhow::{Context, Result};
+use base64::Engine;
+use std::fs;
+use std::path::Path;
+use tracing::debug;
+
+/// Largest image file sent inline; bigger files fall back to a text preview.
+pub const MAX_ITERM_IMAGE_BYTES: usize = 8 * 1024 * 1024;
+
+/// Encode image file contents as an iTerm2 inline image `width`x`height` cells in size.
+pub fn encode_iterm_image(bytes: &[u8], width: u16, height: u16) -> Result<String> {
+    if bytes.len() > MAX_ITERM_IMAGE_BYTES {
+        anyhow::bail!(
+            "Image too large for inline preview ({} bytes, limit {})",
+            bytes.len(),
+            MAX_ITERM_IMAGE_BYTES
+        );
+    }
+
+    let data = base64::engine::general_purpose::STANDARD.encode(bytes);
+    Ok(format!(
+        "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=1:{}\x07",
+        bytes.len(),
+        width.max(1),
+        height.max(1),
+        data
+    ))
+}
+
+/// Read an image file and encode it as an iTerm2 inline image.
+pub fn render_iterm_image(path: &Path, width: u16, height: u16) -> Result<String> {
+    let size = fs::metadata(path)
+        .with_context(|| format!("Failed to read image: {:?}", path))?
+        .len();
+    if size > MAX_ITERM_IMAGE_BYTES as u64 {
+        anyhow::bail!(
+            "Image too large for inline preview ({} bytes, limit {})",
+            size,
+            MAX_ITERM_IMAGE_BYTES
+        );
+    }
+
+    let bytes = fs::read(path).with_context(|| format!("Failed to read image: {:?}", path))?;
+    debug!(
+        "Encoding {:?} as iTerm2 inline image ({} bytes, {}x{} cells)",
+        path,
+        bytes.len(),
+        width,
+        height
+    );
+    encode_iterm_image(&bytes, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_iterm_image() {
+        let escape = encode_iterm_image(b"png", 20, 0).unwrap();
+        assert_eq!(
+            escape,
+            "\x1b]1337;File=inline=1;size=3;width=20;height=1;preserveAspectRatio=1:cG5n\x07"
+        );
+
+        let too_large = vec![0u8; MAX_ITERM_IMAGE_BYTES + 1];
+        assert!(encode_iterm_image(&too_large, 20, 10).is_err());
+        assert!(render_iterm_image(Path::new("/nonexistent/image.png"), 20, 10).is_err());
+    }
+}
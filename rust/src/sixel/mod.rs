@@ -1,15 +1,20 @@
 //! Sixel image rendering module
 //!
 //! Provides real-time image preview using Sixel graphics protocol
-//! for terminals that support it (kitty, WezTerm, iTerm2, xterm).
+//! for terminals that support it (kitty, WezTerm, xterm), and iTerm2's
+//! inline image protocol on iTerm2.
 
+mod image_format;
 mod image_renderer;
+mod iterm_renderer;
 mod preview_manager;
 mod terminal_detection;
 
+pub use image_format::{is_image_path, load_image, IMAGE_EXTENSIONS};
 pub use image_renderer::RenderOptions;
-pub use preview_manager::PreviewManager;
-pub use terminal_detection::{detect_sixel_support, TerminalCapability};
+pub use iterm_renderer::{encode_iterm_image, render_iterm_image, MAX_ITERM_IMAGE_BYTES};
+pub use preview_manager::{PreviewManager, THUMBNAIL_OPTIONS};
+pub use terminal_detection::{capability_from_env, detect_sixel_support, TerminalCapability};
 
 /// Maximum preview cache size in MB
 pub const MAX_CACHE_SIZE_MB: usize = 50;
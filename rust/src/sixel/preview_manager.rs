@@ -1,4 +1,9 @@
 //! Preview management with caching and async loading
+//!
+//! Full-size previews and gallery grid thumbnails are cached separately, both
+//! keyed by path, so a thumbnail never replaces the large preview of the same
+//! image (or the other way round). On iTerm2, inline image escapes are cached
+//! by path and size instead.
 
 use anyhow::{Context, Result};
 use dashmap::DashMap;
@@ -6,14 +11,26 @@ use image::GenericImageView;
 use parking_lot::RwLock;
 use std::fmt;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 use tokio::sync::mpsc;
 use tracing::{debug, info, warn};
 
+use super::image_format::load_image;
 use super::image_renderer::{ImageRenderer, RenderOptions};
+use super::iterm_renderer::render_iterm_image;
 use super::MAX_CACHE_SIZE_MB;
 
+/// Render options for gallery grid thumbnails (fixed, so one render per path
+/// serves every cell).
+pub const THUMBNAIL_OPTIONS: RenderOptions = RenderOptions {
+    width: 16,
+    height: 6,
+    preserve_aspect: true,
+    high_quality: false,
+};
+
 /// Preview entry with metadata
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -37,10 +54,18 @@ pub struct PreviewManager {
     renderer: Arc<ImageRenderer>,
     /// Preview cache (path -> PreviewEntry)
     cache: Arc<DashMap<PathBuf, PreviewEntry>>,
+    /// Thumbnail cache (path -> PreviewEntry rendered with THUMBNAIL_OPTIONS)
+    thumbnails: Arc<DashMap<PathBuf, PreviewEntry>>,
+    /// iTerm2 inline image cache ((path, width, height) -> escape)
+    iterm: DashMap<(PathBuf, u16, u16), String>,
     /// Current cache size in bytes
     cache_size: Arc<RwLock<usize>>,
     /// Maximum cache size in bytes
     max_cache_size: usize,
+    /// Previews found in the cache
+    hits: AtomicU64,
+    /// Previews that had to be rendered
+    misses: AtomicU64,
     /// Channel for async preview requests
     request_tx: mpsc::UnboundedSender<PreviewRequest>,
     /// Channel for preview results
@@ -49,6 +74,9 @@ pub struct PreviewManager {
     pub request_timestamps: Arc<DashMap<PathBuf, Instant>>,
     /// Track preview errors for display
     pub preview_errors: Arc<DashMap<PathBuf, String>>,
+    /// Thumbnails requested so far (kept after a failure, so it isn't retried
+    /// every frame)
+    pub thumbnail_requests: Arc<DashMap<PathBuf, Instant>>,
 }
 
 // Manual Debug implementation for PreviewManager
@@ -56,6 +84,8 @@ impl fmt::Debug for PreviewManager {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("PreviewManager")
             .field("cache_entries", &self.cache.len())
+            .field("thumbnail_entries", &self.thumbnails.len())
+            .field("iterm_entries", &self.iterm.len())
             .field("cache_size", &*self.cache_size.read())
             .field("max_cache_size", &self.max_cache_size)
             .finish()
@@ -67,6 +97,7 @@ impl fmt::Debug for PreviewManager {
 struct PreviewRequest {
     path: PathBuf,
     options: RenderOptions,
+    thumbnail: bool,
 }
 
 /// Result of preview rendering
@@ -77,6 +108,8 @@ pub struct PreviewResult {
     pub entry: Option<PreviewEntry>,
     #[allow(dead_code)]
     pub error: Option<String>,
+    /// A grid thumbnail rather than a full-size preview
+    pub thumbnail: bool,
 }
 
 impl PreviewManager {
@@ -88,6 +121,7 @@ impl PreviewManager {
         let renderer = Arc::new(ImageRenderer::new()
             .expect("Failed to initialize image renderer - img2sixel not found"));
         let cache = Arc::new(DashMap::new());
+        let thumbnails = Arc::new(DashMap::new());
         let cache_size = Arc::new(RwLock::new(0));
         let request_timestamps = Arc::new(DashMap::new());
         let preview_errors = Arc::new(DashMap::new());
@@ -95,6 +129,7 @@ impl PreviewManager {
         // Spawn worker task for async preview rendering
         let worker_renderer = Arc::clone(&renderer);
         let worker_cache = Arc::clone(&cache);
+        let worker_thumbnails = Arc::clone(&thumbnails);
         let worker_cache_size = Arc::clone(&cache_size);
 
         tokio::spawn(async move {
@@ -103,6 +138,7 @@ impl PreviewManager {
                 result_tx,
                 worker_renderer,
                 worker_cache,
+                worker_thumbnails,
                 worker_cache_size,
             )
             .await;
@@ -116,12 +152,17 @@ impl PreviewManager {
         Self {
             renderer,
             cache,
+            thumbnails,
+            iterm: DashMap::new(),
             cache_size,
             max_cache_size: MAX_CACHE_SIZE_MB * 1024 * 1024,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
             request_tx,
             result_rx: Arc::new(RwLock::new(result_rx)),
             request_timestamps,
             preview_errors,
+            thumbnail_requests: Arc::new(DashMap::new()),
         }
     }
 
@@ -129,35 +170,101 @@ impl PreviewManager {
     pub fn request_preview(&self, path: PathBuf, options: RenderOptions) -> Result<()> {
         debug!("Requesting preview for path: {:?}", path);
         // Check cache first
-        if let Some(entry) = self.cache.get(&path) {
+        // Clone out of the map first: inserting while holding the entry deadlocks
+        let cached = self.cache.get(&path).map(|entry| entry.clone());
+        if let Some(mut entry) = cached {
             debug!("Preview cache hit: {:?}", path);
+            self.hits.fetch_add(1, Ordering::Relaxed);
             // Update access time
-            let mut entry = entry.clone();
             entry.last_access = Instant::now();
             self.cache.insert(path, entry);
             return Ok(());
         }
 
-        // Track request timestamp
-        self.request_timestamps.insert(path.clone(), Instant::now());
+        // Track request timestamp (a repeat while rendering isn't another miss)
+        if self
+            .request_timestamps
+            .insert(path.clone(), Instant::now())
+            .is_none()
+        {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
 
         // Send async request
         self.request_tx
-            .send(PreviewRequest { path, options })
+            .send(PreviewRequest {
+                path,
+                options,
+                thumbnail: false,
+            })
             .context("Failed to send preview request")
     }
 
+    /// Request a grid thumbnail (async, returns immediately)
+    ///
+    /// Does nothing if the thumbnail is cached or was already requested.
+    pub fn request_thumbnail(&self, path: PathBuf) -> Result<()> {
+        if self.thumbnails.contains_key(&path) || self.thumbnail_requests.contains_key(&path) {
+            return Ok(());
+        }
+        debug!("Requesting thumbnail for path: {:?}", path);
+        self.thumbnail_requests.insert(path.clone(), Instant::now());
+
+        self.request_tx
+            .send(PreviewRequest {
+                path,
+                options: THUMBNAIL_OPTIONS,
+                thumbnail: true,
+            })
+            .context("Failed to send thumbnail request")
+    }
+
+    /// Try to get a thumbnail from cache (non-blocking)
+    pub fn get_thumbnail(&self, path: &Path) -> Option<PreviewEntry> {
+        self.thumbnails.get(path).map(|entry| entry.clone())
+    }
+
+    /// Check if a thumbnail was requested (whether or not it's ready)
+    pub fn thumbnail_requested(&self, path: &Path) -> bool {
+        self.thumbnail_requests.contains_key(path)
+    }
+
     /// Try to get a preview from cache (non-blocking)
     pub fn get_preview(&self, path: &Path) -> Option<PreviewEntry> {
         debug!("Attempting to get preview from cache for path: {:?}", path);
         self.cache.get(path).map(|entry| {
             debug!("Preview found in cache for path: {:?}", path);
+            self.hits.fetch_add(1, Ordering::Relaxed);
             let mut entry = entry.clone();
             entry.last_access = Instant::now();
             entry
         })
     }
 
+    /// Get or encode the iTerm2 inline image of `path`, `width`x`height`
+    /// cells in size.
+    ///
+    /// Escapes are cached per size, so redrawing doesn't re-read and
+    /// re-encode the file every frame. The cache is emptied when it would
+    /// outgrow the preview cache's limit.
+    pub fn get_or_render_iterm(&self, path: &Path, width: u16, height: u16) -> Result<String> {
+        let key = (path.to_path_buf(), width, height);
+        if let Some(escape) = self.iterm.get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(escape.clone());
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        let escape = render_iterm_image(path, width, height)?;
+        let cached: usize = self.iterm.iter().map(|entry| entry.value().len()).sum();
+        if cached + escape.len() > self.max_cache_size {
+            debug!("Clearing iTerm2 image cache ({} bytes)", cached);
+            self.iterm.clear();
+        }
+        self.iterm.insert(key, escape.clone());
+        Ok(escape)
+    }
+
     /// Check if preview is available in cache
     pub fn has_preview(&self, path: &Path) -> bool {
         self.cache.contains_key(path)
@@ -173,6 +280,9 @@ impl PreviewManager {
     pub fn clear_cache(&self) {
         info!("Clearing preview cache");
         self.cache.clear();
+        self.thumbnails.clear();
+        self.thumbnail_requests.clear();
+        self.iterm.clear();
         *self.cache_size.write() = 0;
     }
 
@@ -182,6 +292,8 @@ impl PreviewManager {
             entries: self.cache.len(),
             size_bytes: *self.cache_size.read(),
             max_size_bytes: self.max_cache_size,
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
         }
     }
 
@@ -236,6 +348,7 @@ impl PreviewManager {
         result_tx: mpsc::UnboundedSender<PreviewResult>,
         renderer: Arc<ImageRenderer>,
         cache: Arc<DashMap<PathBuf, PreviewEntry>>,
+        thumbnails: Arc<DashMap<PathBuf, PreviewEntry>>,
         cache_size: Arc<RwLock<usize>>,
     ) {
         info!("Preview worker started");
@@ -257,13 +370,15 @@ impl PreviewManager {
                 Ok(Ok(entry)) => {
                     // Add to cache
                     let size = entry.size_bytes;
-                    cache.insert(request.path.clone(), entry.clone());
+                    let target = if request.thumbnail { &thumbnails } else { &cache };
+                    target.insert(request.path.clone(), entry.clone());
                     *cache_size.write() += size;
 
                     PreviewResult {
                         path: request.path,
                         entry: Some(entry),
                         error: None,
+                        thumbnail: request.thumbnail,
                     }
                 }
                 Ok(Err(e)) => {
@@ -272,6 +387,7 @@ impl PreviewManager {
                         path: request.path,
                         entry: None,
                         error: Some(e.to_string()),
+                        thumbnail: request.thumbnail,
                     }
                 }
                 Err(e) => {
@@ -280,6 +396,7 @@ impl PreviewManager {
                         path: request.path,
                         entry: None,
                         error: Some(format!("Task panic: {}", e)),
+                        thumbnail: request.thumbnail,
                     }
                 }
             };
@@ -302,7 +419,7 @@ impl PreviewManager {
         let start = Instant::now();
 
         // Load and get dimensions
-        let img = image::open(path).context("Failed to open image")?;
+        let img = load_image(path)?;
         let (width, height) = img.dimensions();
         let dimensions = (width, height);
 
@@ -338,8 +455,11 @@ impl Default for PreviewManager {
 pub struct CacheStats {
     pub entries: usize,
     pub size_bytes: usize,
-    #[allow(dead_code)]
     pub max_size_bytes: usize,
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that needed a render
+    pub misses: u64,
 }
 
 impl CacheStats {
@@ -357,6 +477,21 @@ impl CacheStats {
     pub fn size_mb(&self) -> f32 {
         self.size_bytes as f32 / (1024.0 * 1024.0)
     }
+
+    /// Get the size limit in MB
+    pub fn max_size_mb(&self) -> f32 {
+        self.max_size_bytes as f32 / (1024.0 * 1024.0)
+    }
+
+    /// Fraction of lookups answered from the cache (0.0 before any lookup)
+    pub fn hit_rate(&self) -> f32 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f32 / lookups as f32
+        }
+    }
 }
 
 #[cfg(test)]
@@ -377,12 +512,78 @@ mod tests {
             entries: 10,
             size_bytes: 25 * 1024 * 1024,     // 25 MB
             max_size_bytes: 50 * 1024 * 1024, // 50 MB
+            hits: 0,
+            misses: 0,
         };
 
         assert_eq!(stats.usage_percent(), 50.0);
         assert!((stats.size_mb() - 25.0).abs() < 0.1);
     }
 
+    #[tokio::test]
+    async fn test_thumbnail_requested_once_and_kept_apart() {
+        let manager = PreviewManager::new();
+        let path = PathBuf::from("/nonexistent/image.png");
+
+        manager.request_thumbnail(path.clone()).unwrap();
+        let requested_at = *manager.thumbnail_requests.get(&path).unwrap();
+        manager.request_thumbnail(path.clone()).unwrap();
+
+        // The repeat request is ignored, and the full-size preview is untouched
+        assert_eq!(*manager.thumbnail_requests.get(&path).unwrap(), requested_at);
+        assert!(manager.thumbnail_requested(&path));
+        assert!(!manager.request_timestamps.contains_key(&path));
+        assert!(!manager.has_preview(&path));
+    }
+
+    #[tokio::test]
+    async fn test_hit_rate_counts_hits_and_misses() {
+        let manager = PreviewManager::new();
+        let cached = PathBuf::from("/test/cached.png");
+        manager.cache.insert(
+            cached.clone(),
+            PreviewEntry {
+                path: cached.clone(),
+                sixel_data: String::new(),
+                size_bytes: 0,
+                last_access: Instant::now(),
+                dimensions: (1, 1),
+            },
+        );
+        assert_eq!(manager.cache_stats().hit_rate(), 0.0);
+
+        for _ in 0..3 {
+            assert!(manager.get_preview(&cached).is_some());
+        }
+        manager.request_preview(cached, RenderOptions::default()).unwrap();
+        // A miss per image rendered, however often it's asked for meanwhile
+        let missing = PathBuf::from("/nonexistent/image.png");
+        assert!(manager.get_preview(&missing).is_none());
+        manager.request_preview(missing.clone(), RenderOptions::default()).unwrap();
+        manager.request_preview(missing, RenderOptions::default()).unwrap();
+
+        let stats = manager.cache_stats();
+        assert_eq!((stats.hits, stats.misses), (4, 1));
+        assert_eq!(stats.hit_rate(), 0.8);
+    }
+
+    #[tokio::test]
+    async fn test_iterm_images_cached_per_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("knight.png");
+        std::fs::write(&path, b"png").unwrap();
+        let manager = PreviewManager::new();
+
+        let escape = manager.get_or_render_iterm(&path, 20, 10).unwrap();
+        assert!(escape.contains("width=20;height=10"));
+        // Cached: the file is gone, but the same size still renders
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(manager.get_or_render_iterm(&path, 20, 10).unwrap(), escape);
+        assert!(manager.get_or_render_iterm(&path, 16, 6).is_err());
+        let stats = manager.cache_stats();
+        assert_eq!((stats.hits, stats.misses), (1, 2));
+    }
+
     #[tokio::test]
     async fn test_has_preview_empty() {
         let manager = PreviewManager::new();
@@ -1,4 +1,4 @@
-//! Terminal capability detection for Sixel support
+//! Terminal capability detection for Sixel and iTerm2 inline image support
 
 use std::env;
 use tracing::{debug, info, warn};
@@ -8,14 +8,30 @@ use tracing::{debug, info, warn};
 pub enum TerminalCapability {
     /// Full Sixel support
     Sixel,
+    /// iTerm2 inline image protocol (`ESC ] 1337 ; File=...`)
+    Iterm,
     /// No Sixel support (fallback to text)
     TextOnly,
 }
 
-/// Detect if the terminal supports Sixel graphics
+/// Detect how the terminal can show images
 pub fn detect_sixel_support() -> TerminalCapability {
+    capability_from_env(|name| env::var(name).ok())
+}
+
+/// Detect image support from environment variables looked up with `var`
+pub fn capability_from_env(var: impl Fn(&str) -> Option<String>) -> TerminalCapability {
+    // iTerm2 sets $TERM_PROGRAM, which tmux/ssh replace; $LC_TERMINAL survives them
+    let term_program = var("TERM_PROGRAM");
+    if term_program.as_deref() == Some("iTerm.app")
+        || var("LC_TERMINAL").is_some_and(|t| t.starts_with("iTerm"))
+    {
+        info!("Terminal supports iTerm2 inline images");
+        return TerminalCapability::Iterm;
+    }
+
     // Check $TERM environment variable
-    if let Ok(term) = env::var("TERM") {
+    if let Some(term) = var("TERM") {
         debug!("Detected TERM: {}", term);
 
         // Known Sixel-capable terminals
@@ -28,15 +44,10 @@ pub fn detect_sixel_support() -> TerminalCapability {
     }
 
     // Check $TERM_PROGRAM for known terminal emulators
-    if let Ok(term_program) = env::var("TERM_PROGRAM") {
+    if let Some(term_program) = term_program {
         debug!("Detected TERM_PROGRAM: {}", term_program);
 
         match term_program.as_str() {
-            "iTerm.app" => {
-                // iTerm2 supports Sixel in recent versions
-                info!("Terminal supports Sixel (iTerm2)");
-                return TerminalCapability::Sixel;
-            }
             "WezTerm" => {
                 info!("Terminal supports Sixel (WezTerm)");
                 return TerminalCapability::Sixel;
@@ -50,7 +61,7 @@ pub fn detect_sixel_support() -> TerminalCapability {
     }
 
     // Check for kitty terminal
-    if env::var("KITTY_WINDOW_ID").is_ok() {
+    if var("KITTY_WINDOW_ID").is_some() {
         info!("Terminal supports Sixel (kitty)");
         return TerminalCapability::Sixel;
     }
@@ -69,6 +80,30 @@ mod tests {
     #[test]
     fn test_detect_capability_returns_valid() {
         let cap = detect_sixel_support();
-        assert!(cap == TerminalCapability::Sixel || cap == TerminalCapability::TextOnly);
+        assert!(matches!(
+            cap,
+            TerminalCapability::Sixel | TerminalCapability::Iterm | TerminalCapability::TextOnly
+        ));
+    }
+
+    #[test]
+    fn test_detect_iterm() {
+        let detect = |vars: &[(&str, &str)]| {
+            capability_from_env(|name| {
+                vars.iter()
+                    .find(|(n, _)| *n == name)
+                    .map(|(_, v)| v.to_string())
+            })
+        };
+
+        assert_eq!(detect(&[("TERM_PROGRAM", "iTerm.app")]), TerminalCapability::Iterm);
+        // Inside tmux: TERM_PROGRAM is tmux but LC_TERMINAL is still set
+        assert_eq!(
+            detect(&[("TERM_PROGRAM", "tmux"), ("LC_TERMINAL", "iTerm2")]),
+            TerminalCapability::Iterm
+        );
+        assert_eq!(detect(&[("TERM_PROGRAM", "WezTerm")]), TerminalCapability::Sixel);
+        assert_eq!(detect(&[("TERM", "xterm-256color")]), TerminalCapability::Sixel);
+        assert_eq!(detect(&[("TERM", "dumb")]), TerminalCapability::TextOnly);
     }
 }
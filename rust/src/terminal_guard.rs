@@ -0,0 +1,62 @@
+//! Terminal restore on panic.
+//!
+//! Both entry points put the terminal in raw mode on the alternate screen. A
+//! panic skips their normal cleanup and would leave the shell garbled, so
+//! [`install_panic_hook`] puts the terminal back before the panic message is
+//! printed by the hook that was installed before it.
+
+use crossterm::{
+    cursor::Show,
+    execute,
+    terminal::{disable_raw_mode, LeaveAlternateScreen},
+};
+use std::io;
+
+/// Leave raw mode and the alternate screen and show the cursor.
+///
+/// Each step is attempted even if an earlier one fails; the first error is
+/// returned.
+pub fn restore_terminal() -> io::Result<()> {
+    let raw_mode = disable_raw_mode();
+    let screen = execute!(io::stdout(), LeaveAlternateScreen, Show);
+    raw_mode.and(screen)
+}
+
+/// Restore the terminal whenever the app panics.
+pub fn install_panic_hook() {
+    install_panic_hook_with(|| {
+        // Nothing more can be done about a terminal that won't restore
+        let _ = restore_terminal();
+    });
+}
+
+/// Run `restore` on panic, then the previously installed hook (which prints
+/// the panic).
+pub fn install_panic_hook_with(restore: impl Fn() + Send + Sync + 'static) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore();
+        previous(info);
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_panic_restores_terminal_before_unwinding() {
+        let restored = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&restored);
+        install_panic_hook_with(move || flag.store(true, Ordering::SeqCst));
+
+        let result = std::panic::catch_unwind(|| panic!("render failed"));
+
+        // Back to the default hook for the rest of the test run
+        drop(std::panic::take_hook());
+        assert!(result.is_err());
+        assert!(restored.load(Ordering::SeqCst));
+    }
+}
@@ -8,12 +8,29 @@ use ratatui::{backend::Backend, Frame, Terminal};
 
 /// Main render function - dispatches to appropriate screen
 pub fn render<B: Backend>(terminal: &mut Terminal<B>, app: &App) -> anyhow::Result<()> {
-    terminal.draw(|f| {
+    let frame = terminal.draw(|f| {
         ui(f, app);
     })?;
+
+    if app.screenshot_requested {
+        capture_screenshot(frame.buffer);
+    }
     Ok(())
 }
 
+/// Save a rendered frame to the working directory for bug reports
+#[cfg(feature = "screenshot")]
+fn capture_screenshot(buffer: &ratatui::buffer::Buffer) {
+    if let Err(e) = crate::screenshot::save_screenshot(buffer, std::path::Path::new(".")) {
+        tracing::warn!("Screenshot failed: {:#}", e);
+    }
+}
+
+#[cfg(not(feature = "screenshot"))]
+fn capture_screenshot(_buffer: &ratatui::buffer::Buffer) {
+    tracing::warn!("Screenshots need the `screenshot` feature");
+}
+
 /// UI rendering logic
 fn ui(f: &mut Frame, app: &App) {
     use crate::app::Screen;
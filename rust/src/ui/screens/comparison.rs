@@ -4,7 +4,12 @@
 //! to validate training improvements.
 
 use crate::app::App;
-use crate::comparison::{ComparisonManager, GenerationParams, ModelConfig};
+use crate::backend::Backend;
+use crate::comparison::{
+    ComparisonManager, ComparisonResult, ComparisonStatus, GenerationParams, ModelConfig,
+    ModelResult, ModelResultStatus,
+};
+use crate::generation_request::{GenerationDefaults, GenerationRequestBuilder};
 use crate::ui::screens::{create_block, create_header, create_status_bar};
 use crate::ui::{layout::create_layout, theme::Theme};
 use ratatui::{
@@ -14,6 +19,9 @@ use ratatui::{
     widgets::{Block, Borders, Gauge, List, ListItem, Paragraph},
     Frame,
 };
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Comparison screen state
 #[derive(Debug, Clone, PartialEq)]
@@ -27,6 +35,8 @@ pub enum ComparisonMode {
     Running { comparison_id: String },
     /// Viewing results
     Results { comparison_id: String },
+    /// Browsing past comparisons (`selected` indexes [`ComparisonState::history`])
+    History { selected: usize },
 }
 
 /// UI state for comparison screen
@@ -42,6 +52,10 @@ pub struct ComparisonState {
     /// Shared generation parameters
     pub params: GenerationParams,
 
+    /// Prompts used instead of `params.prompt`, parallel to `selected_models`
+    /// (`None` uses the shared prompt)
+    pub prompt_overrides: Vec<Option<String>>,
+
     /// Use same seed for all models (fair comparison)
     pub use_same_seed: bool,
 
@@ -54,6 +68,9 @@ pub struct ComparisonState {
     /// Comparison manager
     pub comparison_manager: ComparisonManager,
 
+    /// Latest progress (0-100) of each comparison job, by job ID
+    pub progress: HashMap<String, f32>,
+
     /// Currently viewing result index
     #[allow(dead_code)]
     pub viewing_result_index: usize,
@@ -71,10 +88,12 @@ impl ComparisonState {
             mode: ComparisonMode::Setup,
             selected_models: vec![None, None, None], // 3 slots
             params: GenerationParams::default(),
+            prompt_overrides: vec![None, None, None],
             use_same_seed: true,
             available_models: Self::default_models(),
             picker_index: 0,
             comparison_manager: ComparisonManager::new(),
+            progress: HashMap::new(),
             viewing_result_index: 0,
         }
     }
@@ -112,6 +131,242 @@ impl ComparisonState {
     pub fn can_compare(&self) -> bool {
         self.selected_count() >= 2 && !self.params.prompt.is_empty()
     }
+
+    /// Submit a job per selected model and show the comparison's progress
+    ///
+    /// Each job uses its slot's prompt override, if any, and the shared seed
+    /// (or consecutive seeds from it without `use_same_seed`). Fails without
+    /// two models and a prompt, or if a request can't be built or sent.
+    pub fn start_comparison(&mut self, backend: &dyn Backend) -> Result<String> {
+        if !self.can_compare() {
+            bail!("Select at least two models and enter a prompt to compare");
+        }
+
+        let (models, overrides): (Vec<ModelConfig>, Vec<Option<String>>) = self
+            .selected_models
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, model)| {
+                let prompt = self.prompt_overrides.get(slot).cloned().flatten();
+                Some((model.clone()?, prompt))
+            })
+            .unzip();
+
+        let mut requests = Vec::new();
+        for (index, (model, prompt)) in models.iter().zip(&overrides).enumerate() {
+            let prompt = prompt.as_deref().unwrap_or(&self.params.prompt);
+            let seed = match self.use_same_seed {
+                true => self.params.seed,
+                false => self.params.seed.wrapping_add(index as u64),
+            };
+            let job_id = format!("job-{}", uuid::Uuid::new_v4());
+            let request = GenerationRequestBuilder::new(prompt)
+                .id(job_id.clone())
+                .params(&self.params)
+                .seed(seed)
+                .model_config(model)
+                .build(&GenerationDefaults::default())
+                .with_context(|| format!("Cannot generate with {}", model.name))?;
+            requests.push((job_id, request));
+        }
+
+        let manager = &mut self.comparison_manager;
+        let comparison_id = manager.create_comparison(self.params.clone(), models);
+        manager.set_prompt_overrides(&comparison_id, overrides);
+        let job_ids = requests.iter().map(|(id, _)| id.clone()).collect();
+        manager.register_jobs(&comparison_id, job_ids);
+        for (job_id, request) in requests {
+            if let Err(e) = backend.send_request(request) {
+                manager.fail_job(&job_id, format!("{:#}", e));
+                return Err(e.context("Failed to send comparison job"));
+            }
+        }
+
+        self.progress.clear();
+        self.mode = ComparisonMode::Running {
+            comparison_id: comparison_id.clone(),
+        };
+        Ok(comparison_id)
+    }
+
+    /// Record a finished job; shows the results once the running comparison
+    /// has them all
+    ///
+    /// Returns `false` if the job isn't part of a comparison.
+    pub fn complete_job(&mut self, job_id: &str, image_path: PathBuf, duration_s: f32) -> bool {
+        if self.comparison_manager.job_slot(job_id).is_none() {
+            return false;
+        }
+        self.comparison_manager.complete_job(job_id, image_path, duration_s);
+        if let Some(comparison_id) = self.running_id().map(str::to_string) {
+            if self.comparison_manager.get_completed(&comparison_id).is_some() {
+                self.mode = ComparisonMode::Results { comparison_id };
+            }
+        }
+        true
+    }
+
+    /// Record a failed job
+    ///
+    /// Returns `false` if the job isn't part of a comparison.
+    pub fn fail_job(&mut self, job_id: &str, error: String) -> bool {
+        if self.comparison_manager.job_slot(job_id).is_none() {
+            return false;
+        }
+        self.comparison_manager.fail_job(job_id, error);
+        true
+    }
+
+    /// Record a progress update (0-100) for a job
+    ///
+    /// Returns `false` if the job isn't part of a running comparison.
+    pub fn record_progress(&mut self, job_id: &str, percent: f32) -> bool {
+        if self.comparison_manager.job_slot(job_id).is_none() {
+            return false;
+        }
+        self.comparison_manager.update_job_progress(job_id, percent);
+        self.progress.insert(job_id.to_string(), percent);
+        true
+    }
+
+    /// Progress (0-100) of the model in `slot` of a running comparison
+    pub fn slot_progress(&self, comparison_id: &str, slot: usize) -> f32 {
+        self.comparison_manager
+            .get_active(comparison_id)
+            .and_then(|job| job.job_ids.get(slot))
+            .and_then(|job_id| self.progress.get(job_id))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// ID of the comparison being run, if any
+    fn running_id(&self) -> Option<&str> {
+        match &self.mode {
+            ComparisonMode::Running { comparison_id } => Some(comparison_id),
+            _ => None,
+        }
+    }
+
+    /// Progress (0-100) of the running comparison as a whole: the mean of its
+    /// models' progress
+    ///
+    /// A finished comparison is at 100; with none running, 0.
+    pub fn overall_progress(&self) -> f32 {
+        let Some(comparison_id) = self.running_id() else {
+            return 0.0;
+        };
+        let Some(job) = self.comparison_manager.get_active(comparison_id) else {
+            return match self.comparison_manager.get_completed(comparison_id) {
+                Some(_) => 100.0,
+                None => 0.0,
+            };
+        };
+        if job.job_ids.is_empty() {
+            return 0.0;
+        }
+        let total: f32 = (0..job.job_ids.len())
+            .map(|slot| self.slot_progress(comparison_id, slot))
+            .sum();
+        total / job.job_ids.len() as f32
+    }
+
+    /// How many models of the running comparison have finished
+    pub fn completed_count(&self) -> usize {
+        let Some(comparison_id) = self.running_id() else {
+            return 0;
+        };
+        match self.comparison_manager.get_active(comparison_id) {
+            Some(job) => match job.status {
+                ComparisonStatus::Running { completed, .. } => completed,
+                ComparisonStatus::Complete => job.job_ids.len(),
+                _ => 0,
+            },
+            None => self
+                .comparison_manager
+                .get_completed(comparison_id)
+                .map_or(0, |result| result.results.len()),
+        }
+    }
+
+    /// How many models the running comparison was started with
+    pub fn job_count(&self) -> usize {
+        let Some(comparison_id) = self.running_id() else {
+            return 0;
+        };
+        match self.comparison_manager.get_active(comparison_id) {
+            Some(job) => job.models.len(),
+            None => self
+                .comparison_manager
+                .get_completed(comparison_id)
+                .map_or(0, |result| result.results.len()),
+        }
+    }
+
+    /// Past comparisons, newest first
+    pub fn history(&self) -> Vec<&ComparisonResult> {
+        let mut history: Vec<_> = self.comparison_manager.get_all_completed().iter().collect();
+        history.sort_by_key(|r| std::cmp::Reverse(r.completed_at));
+        history
+    }
+
+    /// Open the history view, or close it back to setup
+    pub fn toggle_history(&mut self) {
+        self.mode = match self.mode {
+            ComparisonMode::History { .. } => ComparisonMode::Setup,
+            _ => ComparisonMode::History { selected: 0 },
+        };
+    }
+
+    /// Move the history selection by `delta`, clamped to the list
+    pub fn move_history_selection(&mut self, delta: isize) {
+        let len = self.comparison_manager.get_all_completed().len();
+        if let ComparisonMode::History { selected } = &mut self.mode {
+            *selected = selected.saturating_add_signed(delta).min(len.saturating_sub(1));
+        }
+    }
+
+    /// Vote for the model in `model_index` of the results being shown,
+    /// saving the history straight away
+    ///
+    /// Returns `false` if no results are showing or there's no such model.
+    pub fn vote(&mut self, model_index: usize) -> bool {
+        let ComparisonMode::Results { comparison_id } = &self.mode else {
+            return false;
+        };
+        match self.comparison_manager.vote(comparison_id, model_index) {
+            Ok(voted) => voted,
+            Err(e) => {
+                // The vote still counts for this session
+                tracing::warn!("Failed to save comparison vote: {:#}", e);
+                true
+            }
+        }
+    }
+
+    /// Re-open the results of the highlighted past comparison
+    ///
+    /// Returns `false` if nothing is highlighted.
+    pub fn open_selected_history(&mut self) -> bool {
+        let ComparisonMode::History { selected } = self.mode else {
+            return false;
+        };
+        let Some(result) = self.history().get(selected).map(|r| (*r).clone()) else {
+            return false;
+        };
+
+        self.params = result.params;
+        self.selected_models = result
+            .results
+            .into_iter()
+            .map(|r| Some(r.model))
+            .chain(std::iter::repeat(None))
+            .take(3)
+            .collect();
+        self.mode = ComparisonMode::Results {
+            comparison_id: result.comparison_id,
+        };
+        true
+    }
 }
 
 /// Render the comparison screen
@@ -133,6 +388,9 @@ pub fn render(f: &mut Frame, app: &App, state: &ComparisonState) {
         ComparisonMode::Results { comparison_id } => {
             render_results_mode(f, chunks[1], state, comparison_id);
         }
+        ComparisonMode::History { selected } => {
+            render_history_mode(f, chunks[1], state, *selected);
+        }
     }
 
     // Status bar
@@ -154,6 +412,12 @@ pub fn render(f: &mut Frame, app: &App, state: &ComparisonState) {
         ComparisonMode::Results { .. } => {
             "Results | [1-3] Vote for model [R] Run Again [ESC] Back".to_string()
         }
+        ComparisonMode::History { .. } => {
+            format!(
+                "History: {} comparisons | [↑↓] Navigate [Enter] Open Results [H] Close",
+                state.comparison_manager.get_all_completed().len()
+            )
+        }
     };
     let status_bar = create_status_bar(&status);
     f.render_widget(status_bar, chunks[2]);
@@ -206,6 +470,7 @@ fn render_running_mode(
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Status
+            Constraint::Length(3), // Overall progress
             Constraint::Min(10),   // Progress grid
         ])
         .margin(1)
@@ -219,8 +484,26 @@ fn render_running_mode(
     let status_para = Paragraph::new(status_text).block(create_block(" Comparison in Progress "));
     f.render_widget(status_para, body_chunks[0]);
 
+    render_overall_progress(f, body_chunks[1], state);
+
     // Progress for each model
-    render_progress_grid(f, body_chunks[1], state, comparison_id);
+    render_progress_grid(f, body_chunks[2], state, comparison_id);
+}
+
+/// Render the whole comparison's progress and how many models are done
+fn render_overall_progress(f: &mut Frame, area: ratatui::layout::Rect, state: &ComparisonState) {
+    let progress = state.overall_progress();
+    let gauge = Gauge::default()
+        .block(create_block(" Overall "))
+        .gauge_style(Style::default().fg(Color::Green))
+        .percent((progress as u16).min(100))
+        .label(format!(
+            "{:.1}% - {}/{} complete",
+            progress,
+            state.completed_count(),
+            state.job_count()
+        ));
+    f.render_widget(gauge, area);
 }
 
 /// Render results mode (side-by-side comparison)
@@ -230,12 +513,17 @@ fn render_results_mode(
     state: &ComparisonState,
     comparison_id: &str,
 ) {
+    // A bar per result, inside a border
+    let results = state.comparison_manager.get_completed(comparison_id);
+    let results = results.map_or(&[][..], |r| &r.results);
+    let chart_height = results.len() as u16 + 2;
     let body_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Prompt display
-            Constraint::Min(15),   // Side-by-side results
-            Constraint::Length(5), // Voting/preference section
+            Constraint::Length(3),            // Prompt display
+            Constraint::Min(15),              // Side-by-side results
+            Constraint::Length(chart_height), // Generation times
+            Constraint::Length(5),            // Voting/preference section
         ])
         .margin(1)
         .split(area);
@@ -251,8 +539,56 @@ fn render_results_mode(
     // Side-by-side results
     render_side_by_side(f, body_chunks[1], state, comparison_id);
 
+    // Generation time chart
+    render_time_chart(f, body_chunks[2], results);
+
     // Voting section
-    render_voting_section(f, body_chunks[2], state, comparison_id);
+    render_voting_section(f, body_chunks[3], state, comparison_id);
+}
+
+/// Render the list of past comparisons
+fn render_history_mode(
+    f: &mut Frame,
+    area: ratatui::layout::Rect,
+    state: &ComparisonState,
+    selected: usize,
+) {
+    let history = state.history();
+    let block = create_block(" Comparison History ");
+
+    if history.is_empty() {
+        let para = Paragraph::new(Span::styled("No past comparisons yet", Theme::muted()))
+            .block(block)
+            .alignment(Alignment::Center);
+        f.render_widget(para, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = history
+        .iter()
+        .enumerate()
+        .map(|(i, result)| {
+            let (marker, style) = if i == selected {
+                ("> ", Theme::highlight())
+            } else {
+                ("  ", Theme::text())
+            };
+            let winner = result
+                .winner()
+                .map_or_else(|| "no vote".to_string(), |m| format!("winner: {}", m.name));
+            ListItem::new(Line::from(vec![
+                Span::styled(marker, style),
+                Span::styled(
+                    result.completed_at.format("%Y-%m-%d %H:%M  ").to_string(),
+                    Theme::muted(),
+                ),
+                Span::styled(&result.params.prompt, style),
+                Span::styled(format!("  ({})", winner), Theme::muted()),
+            ]))
+        })
+        .collect();
+
+    f.render_widget(List::new(items).block(block), area);
 }
 
 /// Render prompt input field
@@ -442,7 +778,7 @@ fn render_progress_grid(
     f: &mut Frame,
     area: ratatui::layout::Rect,
     state: &ComparisonState,
-    _comparison_id: &str,
+    comparison_id: &str,
 ) {
     let model_count = state.selected_count();
     let constraints = match model_count {
@@ -460,10 +796,10 @@ fn render_progress_grid(
         .constraints(constraints)
         .split(area);
 
-    for (i, model_opt) in state.selected_models.iter().enumerate() {
-        if let Some(model) = model_opt {
-            render_model_progress(f, progress_chunks[i], model, 45.0); // TODO: Real progress
-        }
+    // Comparison jobs are created for the filled slots, in order
+    for (i, model) in state.selected_models.iter().flatten().enumerate() {
+        let progress = state.slot_progress(comparison_id, i);
+        render_model_progress(f, progress_chunks[i], model, progress);
     }
 }
 
@@ -600,6 +936,79 @@ fn render_model_result(
     f.render_widget(block, area);
 }
 
+/// Width of the model name column in the generation time chart
+const TIME_CHART_LABEL_WIDTH: u16 = 18;
+
+/// Width of the time/status column in the generation time chart
+const TIME_CHART_VALUE_WIDTH: u16 = 14;
+
+/// Bar lengths (in cells, at most `width`) proportional to each duration.
+///
+/// The slowest model fills `width`; a missing duration is an empty bar.
+fn time_bar_lengths(durations: &[Option<f32>], width: u16) -> Vec<u16> {
+    let longest = durations.iter().flatten().copied().fold(0.0_f32, f32::max);
+    durations
+        .iter()
+        .map(|duration| match duration {
+            Some(d) if longest > 0.0 => ((d / longest) * width as f32).round() as u16,
+            _ => 0,
+        })
+        .collect()
+}
+
+/// Render a horizontal bar chart of each model's generation time
+fn render_time_chart(f: &mut Frame, area: ratatui::layout::Rect, results: &[ModelResult]) {
+    let block = create_block(" Generation Time ");
+    let inner = block.inner(area);
+    let bar_width = inner
+        .width
+        .saturating_sub(TIME_CHART_LABEL_WIDTH + TIME_CHART_VALUE_WIDTH + 2);
+    let durations: Vec<Option<f32>> = results.iter().map(|r| r.duration_s).collect();
+    let bars = time_bar_lengths(&durations, bar_width);
+
+    let lines: Vec<Line> = results
+        .iter()
+        .zip(bars)
+        .map(|(result, bar)| {
+            // Unfinished and failed models get a status instead of a time
+            let (value, style) = match (&result.status, result.duration_s) {
+                (ModelResultStatus::Complete, Some(d)) => {
+                    (format!("{:.1}s", d), Style::default().fg(Theme::primary()))
+                }
+                (ModelResultStatus::Complete, None) => ("no time".to_string(), Theme::text()),
+                (ModelResultStatus::Pending, _) => ("pending".to_string(), Theme::muted().into()),
+                (ModelResultStatus::Generating { progress_percent }, _) => (
+                    format!("generating {:.0}%", progress_percent),
+                    Style::default().fg(Theme::secondary()),
+                ),
+                (ModelResultStatus::Failed, _) => {
+                    ("failed".to_string(), Style::default().fg(Theme::error()))
+                }
+                (ModelResultStatus::Cancelled, _) => {
+                    ("cancelled".to_string(), Theme::muted().into())
+                }
+            };
+            let name: String = result
+                .model
+                .name
+                .chars()
+                .take(TIME_CHART_LABEL_WIDTH as usize - 1)
+                .collect();
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}", name, width = TIME_CHART_LABEL_WIDTH as usize),
+                    Theme::text(),
+                ),
+                Span::styled("█".repeat(bar as usize), style),
+                Span::raw(" ".repeat(bar_width.saturating_sub(bar) as usize + 1)),
+                Span::styled(value, style),
+            ])
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
 /// Render voting/preference section
 fn render_voting_section(
     f: &mut Frame,
@@ -611,13 +1020,20 @@ fn render_voting_section(
 
     let content = if let Some(result) = result {
         if let Some(winner_idx) = result.user_preference {
+            let winner = match &result.voted_model {
+                Some(name) => format!("Model {} ({})", winner_idx + 1, name),
+                None => format!("Model {}", winner_idx + 1),
+            };
             vec![
                 Line::from(vec![
                     Span::styled("Your preference: ", Theme::text()),
-                    Span::styled(format!("Model {}", winner_idx + 1), Theme::success()),
+                    Span::styled(winner, Theme::success()),
                 ]),
                 Line::from(""),
-                Line::from(Span::styled("[R] Run comparison again", Theme::button())),
+                Line::from(Span::styled(
+                    "[1-3] Change vote  [R] Run comparison again",
+                    Theme::button(),
+                )),
             ]
         } else {
             vec![
@@ -684,4 +1100,223 @@ mod tests {
         state.params.prompt = "test".to_string();
         assert!(state.can_compare());
     }
+
+    #[test]
+    fn test_start_comparison_submits_each_model_with_its_prompt() {
+        let dir = tempfile::tempdir().unwrap();
+        let backend = crate::demo::DemoBackend::new(dir.path());
+        let mut state = ComparisonState::new();
+        state.selected_models[0] = Some(ModelConfig::default());
+        state.selected_models[2] = Some(state.available_models[1].clone());
+        assert!(state.start_comparison(&backend).is_err());
+
+        state.params.prompt = "knight".to_string();
+        state.prompt_overrides[2] = Some("pixel art knight".to_string());
+        let id = state.start_comparison(&backend).unwrap();
+        assert_eq!(state.mode, ComparisonMode::Running { comparison_id: id.clone() });
+        let job = state.comparison_manager.get_active(&id).unwrap();
+        assert_eq!(job.prompt_overrides, vec![None, Some("pixel art knight".to_string())]);
+        let job_ids = job.job_ids.clone();
+        assert_eq!(job_ids.len(), 2);
+
+        // Each job was sent (the demo backend accepts them)
+        let accepted: Vec<_> = std::iter::from_fn(|| backend.try_recv_response()).collect();
+        assert_eq!(accepted.len(), 2);
+
+        for job_id in &job_ids {
+            assert!(state.complete_job(job_id, PathBuf::from("/tmp/a.png"), 1.0));
+        }
+        assert!(!state.complete_job("other-job", PathBuf::from("/tmp/b.png"), 1.0));
+        assert_eq!(state.mode, ComparisonMode::Results { comparison_id: id.clone() });
+        let result = state.comparison_manager.get_completed(&id).unwrap();
+        assert_eq!(result.results[1].prompt_override.as_deref(), Some("pixel art knight"));
+    }
+
+    #[test]
+    fn test_progress_grid_shows_job_progress() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut state = ComparisonState::new();
+        state.selected_models[0] = Some(ModelConfig::default());
+        state.selected_models[1] = Some(ModelConfig::default());
+        let models = state.selected_models.iter().flatten().cloned().collect();
+        let manager = &mut state.comparison_manager;
+        let id = manager.create_comparison(GenerationParams::default(), models);
+        manager.register_jobs(&id, vec!["job-a".to_string(), "job-b".to_string()]);
+
+        assert!(state.record_progress("job-b", 70.0));
+        assert!(state.record_progress("job-a", 30.0));
+        assert!(!state.record_progress("other-job", 50.0));
+        assert_eq!(state.slot_progress(&id, 0), 30.0);
+        assert_eq!(state.slot_progress(&id, 1), 70.0);
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 12)).unwrap();
+        terminal
+            .draw(|f| render_progress_grid(f, f.area(), &state, &id))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect()
+            })
+            .collect();
+        let gauges = rows.iter().find(|row| row.contains('%')).unwrap();
+        let (left, right) = (gauges.find("30.0%").unwrap(), gauges.find("70.0%").unwrap());
+        assert!(left < right);
+    }
+
+    #[test]
+    fn test_overall_progress_is_mean_of_models() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let mut state = ComparisonState::new();
+        for slot in 0..3 {
+            state.selected_models[slot] = Some(ModelConfig::default());
+        }
+        let models = state.selected_models.iter().flatten().cloned().collect();
+        let manager = &mut state.comparison_manager;
+        let id = manager.create_comparison(GenerationParams::default(), models);
+        let jobs = ["job-a", "job-b", "job-c"].map(String::from);
+        manager.register_jobs(&id, jobs.to_vec());
+        state.mode = ComparisonMode::Running {
+            comparison_id: id.clone(),
+        };
+        assert_eq!(state.overall_progress(), 0.0);
+
+        state.record_progress("job-a", 30.0);
+        state.record_progress("job-b", 60.0);
+        assert_eq!(state.overall_progress(), 30.0);
+        assert_eq!(state.completed_count(), 0);
+
+        state.record_progress("job-a", 100.0);
+        let manager = &mut state.comparison_manager;
+        manager.complete_job("job-a", std::path::PathBuf::from("/tmp/a.png"), 1.0);
+        assert_eq!(state.completed_count(), 1);
+        state.record_progress("job-b", 100.0);
+        let manager = &mut state.comparison_manager;
+        manager.complete_job("job-b", std::path::PathBuf::from("/tmp/b.png"), 1.0);
+        assert_eq!(state.completed_count(), 2);
+
+        let mut terminal = Terminal::new(TestBackend::new(60, 3)).unwrap();
+        terminal
+            .draw(|f| render_overall_progress(f, f.area(), &state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row: String = (0..60).map(|x| buffer[(x, 1)].symbol()).collect();
+        assert!(row.contains("66.7% - 2/3 complete"), "{}", row);
+
+        state.record_progress("job-c", 100.0);
+        let manager = &mut state.comparison_manager;
+        manager.complete_job("job-c", std::path::PathBuf::from("/tmp/c.png"), 1.0);
+        assert_eq!(state.completed_count(), 3);
+        assert_eq!(state.overall_progress(), 100.0);
+    }
+
+    #[test]
+    fn test_overall_progress_counts_the_comparisons_models() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        // Three slots filled, but the comparison was started with two models
+        let mut state = ComparisonState::new();
+        for slot in 0..3 {
+            state.selected_models[slot] = Some(ModelConfig::default());
+        }
+        let models = vec![ModelConfig::default(); 2];
+        let id = state.comparison_manager.create_comparison(GenerationParams::default(), models);
+        state.mode = ComparisonMode::Running { comparison_id: id };
+        assert_eq!(state.job_count(), 2);
+
+        let mut terminal = Terminal::new(TestBackend::new(60, 3)).unwrap();
+        terminal
+            .draw(|f| render_overall_progress(f, f.area(), &state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row: String = (0..60).map(|x| buffer[(x, 1)].symbol()).collect();
+        assert!(row.contains("0/2 complete"), "{}", row);
+    }
+
+    #[test]
+    fn test_time_bars_proportional() {
+        assert_eq!(
+            time_bar_lengths(&[Some(2.0), Some(4.0), Some(1.0)], 20),
+            vec![10, 20, 5]
+        );
+        assert_eq!(time_bar_lengths(&[None, Some(3.0)], 12), vec![0, 12]);
+        assert_eq!(time_bar_lengths(&[None, None], 12), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_time_chart_marks_unfinished_models() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let result = |name: &str, duration_s, status| ModelResult {
+            model: ModelConfig {
+                name: name.to_string(),
+                ..ModelConfig::default()
+            },
+            job_id: format!("job-{}", name),
+            image_path: None,
+            duration_s,
+            status,
+            error: None,
+            prompt_override: None,
+        };
+        let results = vec![
+            result("SDXL", Some(4.0), ModelResultStatus::Complete),
+            result("Pixel LoRA", Some(2.0), ModelResultStatus::Complete),
+            result("Broken", None, ModelResultStatus::Failed),
+        ];
+
+        // 18 label + 14 value + 2 spacing leaves 40 cells for bars
+        let mut terminal = Terminal::new(TestBackend::new(76, 5)).unwrap();
+        terminal
+            .draw(|f| render_time_chart(f, f.area(), &results))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let rows: Vec<String> = (1..4)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        let bar = |row: &String| row.matches('█').count();
+
+        assert_eq!(bar(&rows[0]), 40);
+        assert!(rows[0].contains("4.0s"));
+        assert_eq!(bar(&rows[1]), 20);
+        assert_eq!(bar(&rows[2]), 0);
+        assert!(rows[2].contains("failed"));
+    }
+
+    #[test]
+    fn test_history_reopens_results() {
+        let mut state = ComparisonState::new();
+        let models = vec![ModelConfig::default(), ModelConfig::default()];
+        let mut ids = Vec::new();
+        for prompt in ["older", "newer"] {
+            let params = GenerationParams {
+                prompt: prompt.to_string(),
+                ..Default::default()
+            };
+            let manager = &mut state.comparison_manager;
+            let id = manager.create_comparison(params, models.clone());
+            let (a, b) = (format!("{}-a", prompt), format!("{}-b", prompt));
+            manager.register_jobs(&id, vec![a.clone(), b.clone()]);
+            manager.complete_job(&a, std::path::PathBuf::from("/tmp/a.png"), 1.0);
+            manager.complete_job(&b, std::path::PathBuf::from("/tmp/b.png"), 1.0);
+            ids.push(id);
+        }
+        let older = state.comparison_manager.get_completed_mut(&ids[0]).unwrap();
+        older.completed_at -= chrono::Duration::minutes(5);
+
+        state.toggle_history();
+        assert_eq!(state.history()[0].params.prompt, "newer");
+        state.move_history_selection(5); // Clamped to the last entry
+        assert_eq!(state.mode, ComparisonMode::History { selected: 1 });
+
+        assert!(state.open_selected_history());
+        assert_eq!(state.mode, ComparisonMode::Results { comparison_id: ids[0].clone() });
+        assert_eq!(state.params.prompt, "older");
+        assert_eq!(state.selected_count(), 2);
+        assert!(!state.open_selected_history());
+    }
 }
@@ -1,5 +1,5 @@
-use crate::app::App;
-use crate::sixel::{RenderOptions, TerminalCapability};
+use crate::app::{App, GALLERY_GRID_COLUMNS};
+use crate::sixel::{RenderOptions, TerminalCapability, THUMBNAIL_OPTIONS};
 use crate::ui::screens::{create_block, create_header, create_status_bar};
 use crate::ui::{layout::create_layout, theme::Theme};
 use ratatui::{
@@ -9,9 +9,14 @@ use ratatui::{
     Frame,
 };
 use crate::ui::widgets::sixel_image::SixelImage;
+use std::ops::Range;
+use std::path::Path;
 use std::time::{Duration, Instant};
 use tracing::debug;
 
+/// Height of a grid cell: the thumbnail plus its border.
+const GRID_CELL_HEIGHT: u16 = THUMBNAIL_OPTIONS.height + 2;
+
 pub fn render(f: &mut Frame, app: &App) {
     let chunks = create_layout(f.area());
 
@@ -35,6 +40,11 @@ fn render_gallery_body(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
         return;
     }
 
+    if app.gallery_grid_view {
+        render_thumbnail_grid(f, area, app);
+        return;
+    }
+
     // Split into preview and thumbnail list
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -117,6 +127,16 @@ fn render_main_preview(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                     render_loading(f, inner);
                 }
             }
+            TerminalCapability::Iterm => {
+                match app.preview_manager.get_or_render_iterm(
+                    selected_path,
+                    inner.width.saturating_sub(4),
+                    inner.height.saturating_sub(4),
+                ) {
+                    Ok(image) => render_sixel_large_preview(f, inner, &image, selected_path),
+                    Err(e) => render_preview_error(f, inner, &format!("{:#}", e)),
+                }
+            }
             TerminalCapability::TextOnly => {
                 debug!("Terminal capability is TextOnly.");
                 render_text_only_info(f, inner, selected_path);
@@ -172,6 +192,97 @@ fn render_thumbnail_list(f: &mut Frame, area: ratatui::layout::Rect, app: &App)
     f.render_widget(paragraph, inner);
 }
 
+/// Images shown by a grid `rows` high, scrolled so `selected` is on screen.
+fn grid_visible_range(selected: usize, len: usize, rows: usize) -> Range<usize> {
+    let rows = rows.max(1);
+    let first_row = (selected / GALLERY_GRID_COLUMNS).saturating_sub(rows - 1);
+    let start = first_row * GALLERY_GRID_COLUMNS;
+    start.min(len)..(start + rows * GALLERY_GRID_COLUMNS).min(len)
+}
+
+/// Render the gallery as a grid of thumbnails.
+///
+/// Thumbnails for the cells on screen are requested in the background and
+/// drawn once cached; until then (or without an image protocol) a cell shows
+/// the filename.
+fn render_thumbnail_grid(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let block = create_block(" Images - Grid ");
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+
+    let rows = (inner.height / GRID_CELL_HEIGHT) as usize;
+    let visible = grid_visible_range(app.selected_gallery_index, app.gallery_images.len(), rows);
+    let cell_width = inner.width / GALLERY_GRID_COLUMNS as u16;
+
+    for (offset, idx) in visible.clone().enumerate() {
+        let path = &app.gallery_images[idx];
+        let row = (offset / GALLERY_GRID_COLUMNS) as u16;
+        let col = (offset % GALLERY_GRID_COLUMNS) as u16;
+        let y = inner.y + row * GRID_CELL_HEIGHT;
+        let cell = ratatui::layout::Rect::new(
+            inner.x + col * cell_width,
+            y,
+            cell_width,
+            GRID_CELL_HEIGHT.min(inner.bottom().saturating_sub(y)),
+        );
+        render_grid_cell(f, cell, app, path, idx == app.selected_gallery_index);
+    }
+}
+
+/// Render one grid cell: a bordered thumbnail, or the filename.
+fn render_grid_cell(
+    f: &mut Frame,
+    cell: ratatui::layout::Rect,
+    app: &App,
+    path: &Path,
+    is_selected: bool,
+) {
+    let filename = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("unknown");
+    let style = if is_selected {
+        Theme::highlight()
+    } else {
+        Theme::border()
+    };
+    let block = ratatui::widgets::Block::default()
+        .borders(ratatui::widgets::Borders::ALL)
+        .border_style(style);
+    let inner = block.inner(cell);
+    f.render_widget(block, cell);
+
+    let thumbnail = match app.terminal_capability {
+        TerminalCapability::Sixel => {
+            let _ = app.preview_manager.request_thumbnail(path.to_path_buf());
+            app.preview_manager.get_thumbnail(path).map(|entry| entry.sixel_data)
+        }
+        TerminalCapability::Iterm => app
+            .preview_manager
+            .get_or_render_iterm(path, THUMBNAIL_OPTIONS.width, THUMBNAIL_OPTIONS.height)
+            .ok(),
+        TerminalCapability::TextOnly => None,
+    };
+
+    match thumbnail {
+        Some(image) => f.render_widget(SixelImage::new(&image), inner),
+        None => {
+            let text_style = if is_selected {
+                Theme::highlight()
+            } else {
+                Theme::text()
+            };
+            let label = Paragraph::new(Line::from(Span::styled(filename, text_style)))
+                .alignment(ratatui::layout::Alignment::Center)
+                .wrap(ratatui::widgets::Wrap { trim: true });
+            f.render_widget(label, inner);
+        }
+    }
+}
+
 fn render_sixel_large_preview(
     f: &mut Frame,
     area: ratatui::layout::Rect,
@@ -301,4 +412,55 @@ mod tests {
         let result = terminal.draw(|f| render(f, &app));
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_grid_requests_thumbnails_for_visible_cells() {
+        // 80x24 leaves room for two rows of three cells
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut app = App::new();
+        app.terminal_capability = TerminalCapability::Sixel;
+        app.gallery_grid_view = true;
+        for n in 0..12 {
+            app.add_to_gallery(PathBuf::from(format!("/test/img{:02}.png", n)));
+        }
+        // Bottom-right cell of the third row: rows two and three are shown
+        app.selected_gallery_index = 8;
+
+        terminal.draw(|f| render(f, &app)).unwrap();
+
+        let requested: Vec<bool> = app
+            .gallery_images
+            .iter()
+            .map(|path| app.preview_manager.thumbnail_requested(path))
+            .collect();
+        let expected: Vec<bool> = (0..12).map(|n| (3..9).contains(&n)).collect();
+        assert_eq!(requested, expected);
+        // Not cached yet: the cells fall back to filenames
+        let buffer = terminal.backend().buffer();
+        let screen: String = buffer.content.iter().map(|c| c.symbol()).collect();
+        assert!(screen.contains("img08.png"));
+        assert!(!screen.contains("img09"));
+    }
+
+    #[tokio::test]
+    async fn test_grid_reuses_cached_iterm_thumbnails() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        let mut app = App::new();
+        app.terminal_capability = TerminalCapability::Iterm;
+        app.gallery_grid_view = true;
+        for n in 0..2 {
+            let path = dir.path().join(format!("img{}.png", n));
+            std::fs::write(&path, b"png").unwrap();
+            app.add_to_gallery(path);
+        }
+
+        terminal.draw(|f| render(f, &app)).unwrap();
+        let encoded = app.preview_manager.cache_stats().misses;
+        assert!(encoded >= 2);
+        terminal.draw(|f| render(f, &app)).unwrap();
+        let stats = app.preview_manager.cache_stats();
+        assert_eq!((stats.hits, stats.misses), (encoded, encoded));
+    }
 }
@@ -1,6 +1,7 @@
 use crate::app::{App, JobStatus};
 use crate::ui::screens::{create_block, create_header, create_status_bar};
 use crate::ui::{layout::create_layout, theme::Theme};
+use crate::zmq_client::ConnectionState;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
     text::{Line, Span},
@@ -20,40 +21,62 @@ pub fn render(f: &mut Frame, app: &App) {
     // Body
     render_body(f, chunks[1], app);
 
-    // Status bar
+    // Status bar (flags a backend that stopped answering)
     let cache_stats = app.preview_manager.cache_stats();
+    let backend = match app.backend_state {
+        Some(state @ (ConnectionState::Reconnecting | ConnectionState::Disconnected)) => {
+            format!("Backend: {}", state)
+        }
+        _ => "GPU: Ready".to_string(),
+    };
     let status_text = format!(
-        "GPU: Ready | Memory: 104GB free | Cache: {:.1}MB ({} previews)",
+        "{} | Memory: 104GB free | Cache: {:.1}MB/{:.0}MB ({:.0}% hit)",
+        backend,
         cache_stats.size_mb(),
-        cache_stats.entries
+        cache_stats.max_size_mb(),
+        cache_stats.hit_rate() * 100.0
     );
     let status = create_status_bar(&status_text);
     f.render_widget(status, chunks[2]);
 }
 
 fn render_body(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    // Negative prompt box appears once focused (Ctrl+N) or filled in
+    let show_negative = app.negative_focused || !app.negative_buffer.is_empty();
+
+    let mut constraints = vec![Constraint::Length(5)]; // Prompt input
+    if show_negative {
+        constraints.push(Constraint::Length(3)); // Negative prompt
+    }
+    constraints.extend([
+        Constraint::Length(3), // Options row
+        Constraint::Min(8),    // Preview/options area
+        Constraint::Length(6), // Recent generations
+    ]);
+
     let body_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(5), // Prompt input
-            Constraint::Length(3), // Options row
-            Constraint::Min(8),    // Preview/options area
-            Constraint::Length(6), // Recent generations
-        ])
+        .constraints(constraints)
         .margin(1)
         .split(area);
 
     // Prompt input
     render_prompt_input(f, body_chunks[0], app);
 
+    if show_negative {
+        render_negative_prompt_input(f, body_chunks[1], app);
+    }
+    // Chunks after the prompt (and negative prompt, when shown)
+    let body = if show_negative { 2 } else { 1 };
+
     // Options row (model, LoRA, size)
-    render_options_row(f, body_chunks[1]);
+    render_options_row(f, body_chunks[body]);
 
     // Main content area
-    render_main_content(f, body_chunks[2], app);
+    render_main_content(f, body_chunks[body + 1], app);
 
     // Recent generations
-    render_recent_generations(f, body_chunks[3], app);
+    render_recent_generations(f, body_chunks[body + 2], app);
 }
 
 fn render_prompt_input(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
@@ -71,11 +94,28 @@ fn render_prompt_input(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
     f.render_widget(paragraph, area);
 
     // Show cursor if input is active
-    if !app.input_buffer.is_empty() || app.current_screen == crate::app::Screen::Generation {
+    let active = !app.input_buffer.is_empty() || app.current_screen == crate::app::Screen::Generation;
+    if active && !app.negative_focused {
         f.set_cursor_position((inner.x + app.cursor_pos as u16, inner.y));
     }
 }
 
+fn render_negative_prompt_input(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
+    let block = create_block(" Negative Prompt [Ctrl+N] ");
+    let inner = block.inner(area);
+
+    let text = if app.negative_buffer.is_empty() {
+        Span::styled("Things to avoid (e.g. blurry, text)...", Theme::muted())
+    } else {
+        Span::styled(&app.negative_buffer, Theme::text())
+    };
+    f.render_widget(Paragraph::new(text).block(block), area);
+
+    if app.negative_focused {
+        f.set_cursor_position((inner.x + app.negative_cursor_pos as u16, inner.y));
+    }
+}
+
 fn render_options_row(f: &mut Frame, area: ratatui::layout::Rect) {
     let options_text = " Model: [SDXL Base ▼]  LoRA: [None ▼]  Size: [1024x1024]  Steps: [30] ";
     let paragraph = Paragraph::new(options_text).style(Theme::text()).block(
@@ -145,11 +185,15 @@ fn render_controls(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                     Span::raw("Stage: "),
                     Span::styled(stage, Theme::text()),
                 ]));
-                lines.push(Line::from(vec![
+                let mut progress_line = vec![
                     Span::raw("Progress: "),
                     Span::styled(format!("{:.0}%", progress * 100.0), Theme::highlight()),
                     Span::raw(format!(" (ETA: {:.1}s)", eta_s)),
-                ]));
+                ];
+                if job.stalled {
+                    progress_line.push(Span::styled(" stalled?", Theme::secondary()));
+                }
+                lines.push(Line::from(progress_line));
             }
             JobStatus::Complete { duration_s, .. } => {
                 lines.push(Line::from(vec![
@@ -234,6 +278,17 @@ fn render_preview(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                     render_loading_preview(f, inner);
                 }
             }
+            crate::sixel::TerminalCapability::Iterm => {
+                match app.preview_manager.get_or_render_iterm(
+                    preview_path,
+                    inner.width.saturating_sub(4),
+                    inner.height.saturating_sub(4),
+                ) {
+                    Ok(image) => render_sixel_preview(f, inner, &image, preview_path),
+                    // Unreadable or too large: fall back to the text preview
+                    Err(_) => render_text_preview_info(f, inner, preview_path),
+                }
+            }
             crate::sixel::TerminalCapability::TextOnly => {
                 // Show text-only preview info
                 render_text_preview_info(f, inner, preview_path);
@@ -90,6 +90,59 @@ impl Theme {
     }
 }
 
+/// RGB value of a terminal color (named colors use the xterm palette).
+pub fn color_to_rgb(color: Color) -> [u8; 3] {
+    const ANSI: [[u8; 3]; 16] = [
+        [0, 0, 0],
+        [205, 0, 0],
+        [0, 205, 0],
+        [205, 205, 0],
+        [0, 0, 238],
+        [205, 0, 205],
+        [0, 205, 205],
+        [229, 229, 229],
+        [127, 127, 127],
+        [255, 0, 0],
+        [0, 255, 0],
+        [255, 255, 0],
+        [92, 92, 255],
+        [255, 0, 255],
+        [0, 255, 255],
+        [255, 255, 255],
+    ];
+
+    match color {
+        Color::Rgb(r, g, b) => [r, g, b],
+        Color::Indexed(i @ 0..=15) => ANSI[i as usize],
+        Color::Indexed(i @ 16..=231) => {
+            // 6x6x6 color cube
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let i = i - 16;
+            [level(i / 36), level((i / 6) % 6), level(i % 6)]
+        }
+        Color::Indexed(i) => {
+            let gray = 8 + (i - 232) * 10;
+            [gray, gray, gray]
+        }
+        Color::Reset | Color::Black => ANSI[0],
+        Color::Red => ANSI[1],
+        Color::Green => ANSI[2],
+        Color::Yellow => ANSI[3],
+        Color::Blue => ANSI[4],
+        Color::Magenta => ANSI[5],
+        Color::Cyan => ANSI[6],
+        Color::Gray => ANSI[7],
+        Color::DarkGray => ANSI[8],
+        Color::LightRed => ANSI[9],
+        Color::LightGreen => ANSI[10],
+        Color::LightYellow => ANSI[11],
+        Color::LightBlue => ANSI[12],
+        Color::LightMagenta => ANSI[13],
+        Color::LightCyan => ANSI[14],
+        Color::White => ANSI[15],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
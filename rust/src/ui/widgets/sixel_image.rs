@@ -17,8 +17,16 @@ impl<'a> SixelImage<'a> {
 }
 
 impl<'a> Widget for SixelImage<'a> {
-    fn render(self, area: Rect, _buf: &mut Buffer) {
+    fn render(self, area: Rect, buf: &mut Buffer) {
         debug!("SixelImage widget rendering. Area: {:?}", area);
+
+        // The image is written straight to stdout; mark its cells so the
+        // terminal backend leaves them alone (and screenshots can tell)
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                buf[(x, y)].set_skip(true);
+            }
+        }
         debug!("Sixel data length: {}", self.sixel_data.len());
 
         let mut stdout = io::stdout();
@@ -1,22 +1,40 @@
 //! ZeroMQ client for communicating with Python backend
 //!
-//! Implements REQ-REP pattern for request/response
-//! and SUB pattern for progress updates
+//! Implements REQ-REP (or DEALER-ROUTER) pattern for request/response
+//! and SUB pattern for progress updates. Sockets either connect to the
+//! backend (default) or bind and let the backend connect.
+//!
+//! A request that gets no reply is retried on a fresh socket with
+//! exponential backoff (the "lazy pirate" pattern), since a REQ socket
+//! that lost its reply can't send again. The SUB socket reconnects on its
+//! own.
 
 #![allow(dead_code)]
 
 use crate::messages::*;
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
 use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 use tracing::{debug, error, info, warn};
 
+/// Delay before the first reconnection attempt (doubles on each retry)
+pub const RECONNECT_BASE_DELAY: Duration = Duration::from_millis(250);
+
+/// Longest delay between reconnection attempts
+pub const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(10);
+
 /// ZeroMQ client for backend communication
 pub struct ZmqClient {
     req_sender: Sender<ClientRequest>,
     resp_receiver: Receiver<Response>,
     update_receiver: Receiver<ProgressUpdate>,
+    state: SharedState,
+    /// Requests sent to the worker thread and not yet answered
+    outstanding: Arc<AtomicUsize>,
     _req_thread: thread::JoinHandle<()>,
     _sub_thread: thread::JoinHandle<()>,
 }
@@ -24,41 +42,257 @@ pub struct ZmqClient {
 impl std::fmt::Debug for ZmqClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ZmqClient")
-            .field("connected", &true)
+            .field("state", &self.connection_state())
             .finish()
     }
 }
 
+/// Health of the request connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// The last request was answered (or none has failed yet)
+    Connected,
+    /// A request went unanswered and is being retried on a new socket
+    Reconnecting,
+    /// All retries of the last request failed; the next request tries again
+    Disconnected,
+}
+
+impl std::fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConnectionState::Connected => write!(f, "Connected"),
+            ConnectionState::Reconnecting => write!(f, "Reconnecting"),
+            ConnectionState::Disconnected => write!(f, "Disconnected"),
+        }
+    }
+}
+
+/// Connection state shared with the request thread
+#[derive(Debug, Clone)]
+struct SharedState(Arc<AtomicU8>);
+
+impl SharedState {
+    fn new(state: ConnectionState) -> Self {
+        let shared = Self(Arc::new(AtomicU8::new(0)));
+        shared.set(state);
+        shared
+    }
+
+    fn get(&self) -> ConnectionState {
+        match self.0.load(Ordering::Relaxed) {
+            0 => ConnectionState::Connected,
+            1 => ConnectionState::Reconnecting,
+            _ => ConnectionState::Disconnected,
+        }
+    }
+
+    fn set(&self, state: ConnectionState) {
+        let value = match state {
+            ConnectionState::Connected => 0,
+            ConnectionState::Reconnecting => 1,
+            ConnectionState::Disconnected => 2,
+        };
+        self.0.store(value, Ordering::Relaxed);
+    }
+}
+
+/// Delay before retry number `attempt` (0-based): doubles from
+/// [`RECONNECT_BASE_DELAY`] up to [`RECONNECT_MAX_DELAY`]
+pub fn backoff_delay(attempt: u32) -> Duration {
+    RECONNECT_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(RECONNECT_MAX_DELAY)
+}
+
 /// Internal message for request thread
 enum ClientRequest {
-    Send(Request),
+    Send(Box<Request>),
     Shutdown,
 }
 
+/// Socket pattern used for requests (progress updates always use SUB)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequestPattern {
+    /// REQ socket talking to a REP (or REQ-compatible ROUTER) backend
+    #[default]
+    ReqRep,
+    /// DEALER socket talking to a ROUTER backend
+    DealerRouter,
+}
+
+/// Whether the client connects to the backend or binds for it to connect
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SocketMode {
+    #[default]
+    Connect,
+    Bind,
+}
+
+/// ZeroMQ topology for a client
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZmqConfig {
+    /// Request endpoint
+    pub req_addr: String,
+    /// Progress update endpoint
+    pub pub_addr: String,
+    /// Request socket pattern
+    pub pattern: RequestPattern,
+    /// Bind or connect both sockets
+    pub mode: SocketMode,
+    /// How long to wait for each reply
+    pub timeout: Duration,
+    /// Retries (on a fresh socket) after a request goes unanswered
+    pub retry_attempts: u32,
+}
+
+impl Default for ZmqConfig {
+    fn default() -> Self {
+        Self {
+            req_addr: DEFAULT_REQ_REP_ADDR.to_string(),
+            pub_addr: DEFAULT_PUB_SUB_ADDR.to_string(),
+            pattern: RequestPattern::default(),
+            mode: SocketMode::default(),
+            // Same as the Settings screen defaults
+            timeout: Duration::from_secs(30),
+            retry_attempts: 3,
+        }
+    }
+}
+
+impl ZmqConfig {
+    /// Check that the endpoints make sense for the chosen mode
+    pub fn validate(&self) -> Result<()> {
+        for (name, addr) in [("request", &self.req_addr), ("update", &self.pub_addr)] {
+            let Some((transport, rest)) = addr.split_once("://") else {
+                bail!("Invalid {} endpoint {:?}: expected transport://address", name, addr);
+            };
+            if !matches!(transport, "tcp" | "ipc" | "inproc") {
+                bail!("Unsupported transport {:?} in {} endpoint", transport, name);
+            }
+            if rest.is_empty() {
+                bail!("Empty address in {} endpoint {:?}", name, addr);
+            }
+            if self.mode == SocketMode::Connect && rest.contains('*') {
+                bail!(
+                    "Wildcard {} endpoint {:?} can only be bound, not connected to",
+                    name,
+                    addr
+                );
+            }
+        }
+
+        if self.mode == SocketMode::Bind && self.req_addr == self.pub_addr {
+            bail!("Request and update sockets cannot bind the same endpoint {:?}", self.req_addr);
+        }
+
+        Ok(())
+    }
+}
+
+/// Bind or connect a socket according to the configured mode
+fn attach(socket: &zmq::Socket, mode: SocketMode, addr: &str) -> Result<()> {
+    debug!("{:?} socket endpoint: {}", mode, addr);
+    match mode {
+        SocketMode::Connect => socket
+            .connect(addr)
+            .with_context(|| format!("Failed to connect to {}", addr)),
+        SocketMode::Bind => socket
+            .bind(addr)
+            .with_context(|| format!("Failed to bind {}", addr)),
+    }
+}
+
+/// Create the request socket for the configured pattern and attach it
+fn open_req_socket(context: &zmq::Context, config: &ZmqConfig) -> Result<zmq::Socket> {
+    let socket = context.socket(match config.pattern {
+        RequestPattern::ReqRep => zmq::REQ,
+        RequestPattern::DealerRouter => zmq::DEALER,
+    })?;
+    socket.set_rcvtimeo(config.timeout.as_millis().min(i32::MAX as u128) as i32)?;
+    socket.set_linger(0)?;
+    attach(&socket, config.mode, &config.req_addr)?;
+    Ok(socket)
+}
+
+/// Send one request and wait for its reply
+///
+/// DEALER sockets add the empty delimiter frame a REQ socket would, so
+/// ROUTER backends see the same envelope either way.
+fn exchange(socket: &zmq::Socket, pattern: RequestPattern, request: &[u8]) -> Result<Vec<u8>> {
+    match pattern {
+        RequestPattern::ReqRep => socket.send(request, 0),
+        RequestPattern::DealerRouter => socket.send_multipart([&[][..], request], 0),
+    }
+    .context("Failed to send request")?;
+
+    // Last frame carries the payload
+    let reply = match pattern {
+        RequestPattern::ReqRep => socket.recv_bytes(0),
+        RequestPattern::DealerRouter => socket
+            .recv_multipart(0)
+            .map(|frames| frames.into_iter().last().unwrap_or_default()),
+    };
+    match reply {
+        Ok(data) => Ok(data),
+        Err(zmq::Error::EAGAIN) => bail!("Timed out waiting for reply"),
+        Err(e) => Err(e).context("Failed to receive reply"),
+    }
+}
+
 impl ZmqClient {
     /// Create a new ZeroMQ client
     pub fn new(req_addr: &str, pub_addr: &str) -> Result<Self> {
+        Self::with_config(&ZmqConfig {
+            req_addr: req_addr.to_string(),
+            pub_addr: pub_addr.to_string(),
+            ..ZmqConfig::default()
+        })
+    }
+
+    /// Create a new ZeroMQ client with an explicit topology
+    ///
+    /// Sockets are created and bound/connected before the worker threads
+    /// start, so invalid endpoints are reported here.
+    pub fn with_config(config: &ZmqConfig) -> Result<Self> {
         info!("Initializing ZeroMQ client");
-        debug!("REQ-REP address: {}", req_addr);
-        debug!("PUB-SUB address: {}", pub_addr);
+        debug!("Request address: {} ({:?}, {:?})", config.req_addr, config.pattern, config.mode);
+        debug!("PUB-SUB address: {}", config.pub_addr);
+
+        config.validate()?;
+
+        let context = zmq::Context::new();
+        let req_socket = open_req_socket(&context, config)?;
+
+        let sub_socket = context.socket(zmq::SUB)?;
+        sub_socket.set_subscribe(b"")?; // Subscribe to all messages
+        sub_socket.set_rcvtimeo(1000)?; // 1 second receive timeout
+        sub_socket.set_linger(0)?;
+        attach(&sub_socket, config.mode, &config.pub_addr)?;
 
         // Create channels for cross-thread communication
         let (req_send, req_recv) = channel::<ClientRequest>();
         let (resp_send, resp_recv) = channel::<Response>();
         let (update_send, update_recv) = channel::<ProgressUpdate>();
 
-        // Spawn REQ-REP thread
-        let req_addr = req_addr.to_string();
-        let req_thread = thread::spawn(move || {
-            if let Err(e) = Self::req_rep_loop(&req_addr, req_recv, resp_send) {
-                error!("REQ-REP thread error: {}", e);
-            }
-        });
+        let state = SharedState::new(ConnectionState::Connected);
+        let outstanding = Arc::new(AtomicUsize::new(0));
+
+        // Spawn request thread
+        let worker = ReqWorker {
+            context,
+            config: config.clone(),
+            socket: Some(req_socket),
+            state: state.clone(),
+            outstanding: outstanding.clone(),
+        };
+        let req_thread = thread::spawn(move || worker.run(req_recv, resp_send));
 
         // Spawn SUB thread
-        let pub_addr = pub_addr.to_string();
         let sub_thread = thread::spawn(move || {
-            if let Err(e) = Self::pub_sub_loop(&pub_addr, update_send) {
+            if let Err(e) = Self::pub_sub_loop(sub_socket, update_send) {
                 error!("PUB-SUB thread error: {}", e);
             }
         });
@@ -67,6 +301,8 @@ impl ZmqClient {
             req_sender: req_send,
             resp_receiver: resp_recv,
             update_receiver: update_recv,
+            state,
+            outstanding,
             _req_thread: req_thread,
             _sub_thread: sub_thread,
         })
@@ -79,9 +315,36 @@ impl ZmqClient {
 
     /// Send a request to the backend
     pub fn send_request(&self, request: Request) -> Result<()> {
+        self.outstanding.fetch_add(1, Ordering::Relaxed);
         self.req_sender
-            .send(ClientRequest::Send(request))
-            .context("Failed to send request to worker thread")
+            .send(ClientRequest::Send(Box::new(request)))
+            .map_err(|_| {
+                self.outstanding.fetch_sub(1, Ordering::Relaxed);
+                self.state.set(ConnectionState::Disconnected);
+                anyhow::anyhow!("Failed to send request to worker thread")
+            })
+    }
+
+    /// Health of the request connection, for status indicators
+    pub fn connection_state(&self) -> ConnectionState {
+        self.state.get()
+    }
+
+    /// Requests sent but not yet answered (or failed)
+    pub fn outstanding_requests(&self) -> usize {
+        self.outstanding.load(Ordering::Relaxed)
+    }
+
+    /// Ask the backend for GPU/memory stats (answered with `Response::SystemStats`)
+    pub fn request_system_stats(&self) -> Result<()> {
+        self.send_request(Request::GetSystemStats)
+    }
+
+    /// Ask the backend to stop a queued or running job
+    pub fn cancel_job(&self, job_id: &str) -> Result<()> {
+        self.send_request(Request::Cancel {
+            job_id: job_id.to_string(),
+        })
     }
 
     /// Try to receive a response (non-blocking)
@@ -101,20 +364,55 @@ impl ZmqClient {
         self.update_receiver.try_recv().ok()
     }
 
-    /// REQ-REP loop (runs in separate thread)
-    fn req_rep_loop(
-        addr: &str,
-        req_recv: Receiver<ClientRequest>,
-        resp_send: Sender<Response>,
-    ) -> Result<()> {
-        info!("Starting REQ-REP thread");
+    /// PUB-SUB loop (runs in separate thread)
+    fn pub_sub_loop(socket: zmq::Socket, update_send: Sender<ProgressUpdate>) -> Result<()> {
+        info!("Starting PUB-SUB thread");
 
-        let context = zmq::Context::new();
-        let socket = context.socket(zmq::REQ)?;
-        socket.connect(addr)?;
-        socket.set_rcvtimeo(5000)?; // 5 second receive timeout
+        loop {
+            match socket.recv_bytes(0) {
+                Ok(data) => match deserialize::<ProgressUpdate>(&data) {
+                    Ok(update) => {
+                        debug!("Received update: {:?}", update);
 
-        info!("Connected to REQ-REP endpoint: {}", addr);
+                        if update_send.send(update).is_err() {
+                            info!("Main thread disconnected, shutting down PUB-SUB");
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Failed to deserialize update: {}", e);
+                    }
+                },
+                Err(zmq::Error::EAGAIN) => {
+                    // Timeout, continue
+                    continue;
+                }
+                Err(e) => {
+                    error!("Error receiving update: {}", e);
+                    // Keep trying
+                    std::thread::sleep(Duration::from_millis(100));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Request thread state: owns the request socket and replaces it when a
+/// request goes unanswered
+struct ReqWorker {
+    context: zmq::Context,
+    config: ZmqConfig,
+    socket: Option<zmq::Socket>,
+    state: SharedState,
+    outstanding: Arc<AtomicUsize>,
+}
+
+impl ReqWorker {
+    /// Request loop (runs in separate thread)
+    fn run(mut self, req_recv: Receiver<ClientRequest>, resp_send: Sender<Response>) {
+        info!("Starting REQ-REP thread ({:?})", self.config.pattern);
 
         loop {
             // Wait for request from main thread
@@ -122,28 +420,20 @@ impl ZmqClient {
                 Ok(ClientRequest::Send(request)) => {
                     debug!("Sending request: {:?}", request);
 
-                    // Serialize and send
-                    let serialized = serialize(&request)?;
-                    socket.send(&serialized, 0)?;
-
-                    // Wait for response
-                    match socket.recv_bytes(0) {
-                        Ok(data) => {
-                            let response: Response = deserialize(&data)?;
-                            debug!("Received response: {:?}", response);
+                    let response = self.request(&request);
+                    self.outstanding.fetch_sub(1, Ordering::Relaxed);
 
-                            if resp_send.send(response).is_err() {
-                                warn!("Failed to send response to main thread");
-                                break;
-                            }
-                        }
-                        Err(e) => {
-                            error!("Error receiving response: {}", e);
-                            // Send error response
-                            let _ = resp_send.send(Response::Error {
-                                message: format!("Communication error: {}", e),
-                            });
+                    let response = response.unwrap_or_else(|e| {
+                        error!("Request failed: {:#}", e);
+                        Response::Error {
+                            message: format!("Communication error: {:#}", e),
                         }
+                    });
+                    debug!("Received response: {:?}", response);
+
+                    if resp_send.send(response).is_err() {
+                        warn!("Failed to send response to main thread");
+                        break;
                     }
                 }
                 Ok(ClientRequest::Shutdown) => {
@@ -160,50 +450,62 @@ impl ZmqClient {
                 }
             }
         }
-
-        Ok(())
     }
 
-    /// PUB-SUB loop (runs in separate thread)
-    fn pub_sub_loop(addr: &str, update_send: Sender<ProgressUpdate>) -> Result<()> {
-        info!("Starting PUB-SUB thread");
-
-        let context = zmq::Context::new();
-        let socket = context.socket(zmq::SUB)?;
-        socket.connect(addr)?;
-        socket.set_subscribe(b"")?; // Subscribe to all messages
-        socket.set_rcvtimeo(1000)?; // 1 second receive timeout
-
-        info!("Connected to PUB-SUB endpoint: {}", addr);
+    /// Serialize, send and decode the reply to one request
+    fn request(&mut self, request: &Request) -> Result<Response> {
+        let data = serialize(request).context("Failed to serialize request")?;
+        let reply = self.send_with_retry(&data)?;
+        deserialize(&reply).context("Failed to deserialize response")
+    }
 
-        loop {
-            match socket.recv_bytes(0) {
-                Ok(data) => match deserialize::<ProgressUpdate>(&data) {
-                    Ok(update) => {
-                        debug!("Received update: {:?}", update);
+    /// Send a request, retrying on a fresh socket with exponential backoff
+    fn send_with_retry(&mut self, request: &[u8]) -> Result<Vec<u8>> {
+        let mut last_error = None;
+
+        for attempt in 0..=self.config.retry_attempts {
+            if attempt > 0 {
+                let delay = backoff_delay(attempt - 1);
+                self.state.set(ConnectionState::Reconnecting);
+                warn!(
+                    "Reconnecting to backend in {:?} (attempt {}/{})",
+                    delay, attempt, self.config.retry_attempts
+                );
+                thread::sleep(delay);
+            }
 
-                        if update_send.send(update).is_err() {
-                            info!("Main thread disconnected, shutting down PUB-SUB");
-                            break;
-                        }
-                    }
+            let socket = match self.socket.take() {
+                Some(socket) => socket,
+                None => match open_req_socket(&self.context, &self.config) {
+                    Ok(socket) => socket,
                     Err(e) => {
-                        warn!("Failed to deserialize update: {}", e);
+                        last_error = Some(e);
+                        continue;
                     }
                 },
-                Err(zmq::Error::EAGAIN) => {
-                    // Timeout, continue
-                    continue;
-                }
-                Err(e) => {
-                    error!("Error receiving update: {}", e);
-                    // Keep trying
-                    std::thread::sleep(Duration::from_millis(100));
+            };
+
+            match exchange(&socket, self.config.pattern, request) {
+                Ok(reply) => {
+                    if self.state.get() != ConnectionState::Connected {
+                        info!("Reconnected to backend");
+                    }
+                    self.state.set(ConnectionState::Connected);
+                    self.socket = Some(socket);
+                    return Ok(reply);
                 }
+                // The socket is dropped: a REQ socket that lost its reply
+                // can't send again
+                Err(e) => last_error = Some(e),
             }
         }
 
-        Ok(())
+        self.state.set(ConnectionState::Disconnected);
+        let error = last_error.unwrap_or_else(|| anyhow::anyhow!("No attempts made"));
+        Err(error.context(format!(
+            "Backend unreachable after {} attempts",
+            self.config.retry_attempts + 1
+        )))
     }
 }
 
@@ -236,4 +538,167 @@ mod tests {
         let deserialized: Request = deserialize(&serialized).unwrap();
         assert_eq!(request, deserialized);
     }
+
+    /// Answer a single Ping with Pong, acting as the backend side of `pattern`
+    fn spawn_mock_server(
+        pattern: RequestPattern,
+        client_mode: SocketMode,
+        addr: String,
+    ) -> thread::JoinHandle<()> {
+        let context = zmq::Context::new();
+        let socket = context
+            .socket(match pattern {
+                RequestPattern::ReqRep => zmq::REP,
+                RequestPattern::DealerRouter => zmq::ROUTER,
+            })
+            .unwrap();
+        socket.set_rcvtimeo(5000).unwrap();
+        match client_mode {
+            SocketMode::Connect => socket.bind(&addr).unwrap(),
+            SocketMode::Bind => socket.connect(&addr).unwrap(),
+        }
+
+        thread::spawn(move || {
+            let mut frames = socket.recv_multipart(0).unwrap();
+            let request: Request = deserialize(&frames.pop().unwrap()).unwrap();
+            assert_eq!(request, Request::Ping);
+
+            // ROUTER replies through the [identity, delimiter] envelope
+            frames.push(serialize(&Response::Pong).unwrap());
+            socket.send_multipart(frames, 0).unwrap();
+        })
+    }
+
+    #[test]
+    fn test_client_modes_against_mock_server() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let modes = [
+            (RequestPattern::ReqRep, SocketMode::Connect),
+            (RequestPattern::ReqRep, SocketMode::Bind),
+            (RequestPattern::DealerRouter, SocketMode::Connect),
+            (RequestPattern::DealerRouter, SocketMode::Bind),
+        ];
+
+        for (i, (pattern, mode)) in modes.into_iter().enumerate() {
+            let config = ZmqConfig {
+                req_addr: format!("ipc://{}/req-{}.sock", dir.path().display(), i),
+                pub_addr: format!("ipc://{}/pub-{}.sock", dir.path().display(), i),
+                pattern,
+                mode,
+                ..ZmqConfig::default()
+            };
+
+            // Bound clients must exist before the server connects
+            let (client, server) = match mode {
+                SocketMode::Connect => {
+                    let server = spawn_mock_server(pattern, mode, config.req_addr.clone());
+                    (ZmqClient::with_config(&config).unwrap(), server)
+                }
+                SocketMode::Bind => {
+                    let client = ZmqClient::with_config(&config).unwrap();
+                    (client, spawn_mock_server(pattern, mode, config.req_addr.clone()))
+                }
+            };
+
+            client.send_request(Request::Ping).unwrap();
+            let response = client
+                .recv_response_timeout(Duration::from_secs(5))
+                .unwrap_or_else(|e| panic!("{:?}/{:?}: {}", pattern, mode, e));
+            assert_eq!(response, Response::Pong, "{:?}/{:?}", pattern, mode);
+            server.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(ZmqConfig::default().validate().is_ok());
+
+        let wildcard = ZmqConfig {
+            req_addr: "tcp://*:5555".to_string(),
+            ..ZmqConfig::default()
+        };
+        assert!(wildcard.validate().is_err());
+        assert!(ZmqConfig {
+            mode: SocketMode::Bind,
+            ..wildcard
+        }
+        .validate()
+        .is_ok());
+
+        let shared = ZmqConfig {
+            pub_addr: DEFAULT_REQ_REP_ADDR.to_string(),
+            mode: SocketMode::Bind,
+            ..ZmqConfig::default()
+        };
+        assert!(shared.validate().is_err());
+
+        let bad_transport = ZmqConfig {
+            req_addr: "http://localhost:5555".to_string(),
+            ..ZmqConfig::default()
+        };
+        assert!(ZmqClient::with_config(&bad_transport).is_err());
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_cap() {
+        assert_eq!(backoff_delay(0), RECONNECT_BASE_DELAY);
+        assert_eq!(backoff_delay(1), RECONNECT_BASE_DELAY * 2);
+        assert_eq!(backoff_delay(2), RECONNECT_BASE_DELAY * 4);
+        assert_eq!(backoff_delay(30), RECONNECT_MAX_DELAY);
+    }
+
+    /// Wait (up to 5s) for the client to reach `state`
+    fn wait_for_state(client: &ZmqClient, state: ConnectionState) {
+        let deadline = std::time::Instant::now() + Duration::from_secs(5);
+        while client.connection_state() != state {
+            assert!(std::time::Instant::now() < deadline, "never reached {:?}", state);
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn test_reconnects_after_backend_restarts() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = ZmqConfig {
+            req_addr: format!("ipc://{}/req.sock", dir.path().display()),
+            pub_addr: format!("ipc://{}/pub.sock", dir.path().display()),
+            timeout: Duration::from_millis(200),
+            retry_attempts: 3,
+            ..ZmqConfig::default()
+        };
+
+        let serve_once =
+            || spawn_mock_server(RequestPattern::ReqRep, SocketMode::Connect, config.req_addr.clone());
+        let pong = |client: &ZmqClient| {
+            let response = client.recv_response_timeout(Duration::from_secs(5)).unwrap();
+            assert_eq!(response, Response::Pong);
+        };
+
+        // Backend answers once, then goes away
+        let server = serve_once();
+        let client = ZmqClient::with_config(&config).unwrap();
+        client.send_request(Request::Ping).unwrap();
+        pong(&client);
+        server.join().unwrap();
+        assert_eq!(client.connection_state(), ConnectionState::Connected);
+
+        // Unanswered request: the client retries while the backend is down...
+        client.send_request(Request::Ping).unwrap();
+        wait_for_state(&client, ConnectionState::Reconnecting);
+        assert_eq!(client.outstanding_requests(), 1);
+
+        // ...and gets through once it's back
+        let server = serve_once();
+        pong(&client);
+        server.join().unwrap();
+        assert_eq!(client.connection_state(), ConnectionState::Connected);
+        assert_eq!(client.outstanding_requests(), 0);
+
+        // With the backend gone for good, retries run out
+        client.send_request(Request::Ping).unwrap();
+        let response = client.recv_response_timeout(Duration::from_secs(10)).unwrap();
+        assert!(matches!(response, Response::Error { .. }), "{:?}", response);
+        assert_eq!(client.connection_state(), ConnectionState::Disconnected);
+    }
 }
@@ -102,9 +102,14 @@ mod tests {
             prompt: "test".to_string(),
             model: "sdxl".to_string(),
             lora: None,
+            loras: Vec::new(),
             size: (512, 512),
             steps: 20,
             cfg_scale: 7.5,
+            negative_prompt: None,
+            seed: None,
+            workflow: None,
+            priority: None,
         };
 
         client.send_request(req.clone()).unwrap();
@@ -113,6 +113,37 @@ async fn test_get_preview_returns_entry() {
     }
 }
 
+#[tokio::test]
+async fn test_webp_preview_produces_entry() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("sprite.webp");
+    // A 1x1 lossless WebP
+    let webp: &[u8] = &[
+        0x52, 0x49, 0x46, 0x46, 0x1a, 0x00, 0x00, 0x00, 0x57, 0x45, 0x42, 0x50, 0x56, 0x50, 0x38,
+        0x4c, 0x0d, 0x00, 0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x10, 0x07, 0x10, 0x11, 0x11, 0x88,
+        0x88, 0xfe, 0x07, 0x00,
+    ];
+    std::fs::write(&path, webp).unwrap();
+
+    let manager = PreviewManager::new();
+    manager.request_preview(path.clone(), RenderOptions::default()).unwrap();
+
+    let mut result = None;
+    for _ in 0..20 {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        result = manager.try_recv_result();
+        if result.is_some() {
+            break;
+        }
+    }
+
+    let result = result.expect("Should receive a result");
+    assert!(result.error.is_none(), "WebP should render: {:?}", result.error);
+    let entry = manager.get_preview(&path).expect("WebP preview should be cached");
+    assert_eq!(entry.dimensions, (1, 1));
+    assert!(!entry.sixel_data.is_empty());
+}
+
 // ============================================================================
 // Preview Request/Response Cycle Tests
 // ============================================================================
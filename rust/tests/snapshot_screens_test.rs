@@ -51,6 +51,51 @@ async fn test_generation_with_prompt_snapshot() {
     insta::assert_snapshot!("generation_with_prompt", format!("{:?}", buffer));
 }
 
+/// Test generation screen with the negative prompt focused but empty
+#[tokio::test]
+async fn test_generation_negative_prompt_empty_snapshot() {
+    let _guard = TestModeGuard::new();
+    let mut terminal = create_standard_terminal();
+    let mut app = App::new();
+
+    for c in "pixel art wizard".chars() {
+        app.input_char(c);
+    }
+    app.toggle_negative_focus();
+
+    terminal
+        .draw(|f| generation::render(f, &app))
+        .expect("Failed to draw");
+
+    let buffer = terminal.backend().buffer().clone();
+    insta::assert_snapshot!("generation_negative_prompt_empty", format!("{:?}", buffer));
+}
+
+/// Test generation screen with a negative prompt entered
+#[tokio::test]
+async fn test_generation_negative_prompt_populated_snapshot() {
+    let _guard = TestModeGuard::new();
+    let mut terminal = create_standard_terminal();
+    let mut app = App::new();
+
+    for c in "pixel art wizard".chars() {
+        app.input_char(c);
+    }
+    app.toggle_negative_focus();
+    for c in "blurry, text".chars() {
+        app.input_char(c);
+    }
+    // Focus back on the prompt: the negative prompt stays visible
+    app.toggle_negative_focus();
+
+    terminal
+        .draw(|f| generation::render(f, &app))
+        .expect("Failed to draw");
+
+    let buffer = terminal.backend().buffer().clone();
+    insta::assert_snapshot!("generation_negative_prompt_populated", format!("{:?}", buffer));
+}
+
 /// Test generation screen with active job (queued)
 #[tokio::test]
 async fn test_generation_with_queued_job_snapshot() {